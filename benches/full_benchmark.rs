@@ -8,7 +8,7 @@
 //! 5. Mixed Workload (80% read, 20% write)
 
 use samyama::{GraphStore, Label, PropertyValue, QueryEngine, DistanceMetric};
-use samyama::algo::{build_view, page_rank, weakly_connected_components, bfs, PageRankConfig};
+use samyama::algo::{build_view, Directedness, page_rank, weakly_connected_components, bfs, PageRankConfig};
 use samyama::persistence::TenantManager;
 use std::time::Instant;
 use std::sync::Arc;
@@ -61,7 +61,7 @@ async fn main() {
     let v_idx = Arc::clone(&store.vector_index);
     let p_idx = Arc::clone(&store.property_index);
     tokio::spawn(async move {
-        GraphStore::start_background_indexer(rx, v_idx, p_idx, tenant_manager).await;
+        GraphStore::start_background_indexer(rx, v_idx, p_idx, tenant_manager, std::sync::Weak::new(), std::sync::Weak::new()).await;
     });
 
     // 1. Ingestion Benchmark
@@ -261,7 +261,7 @@ fn benchmark_graph_algorithms(store: &GraphStore) {
 
     println!("  Building graph view...");
     let view_start = Instant::now();
-    let view = build_view(store, Some("Entity"), Some("LINKS_TO"), None);
+    let view = build_view(store, Some("Entity"), Some("LINKS_TO"), None, Directedness::Directed);
     let view_time = view_start.elapsed();
     println!("  View built in {:?} ({} nodes, {} edges)",
         view_time, view.node_count,
@@ -1,7 +1,8 @@
 use criterion::{criterion_group, criterion_main, Criterion, BenchmarkId};
-use samyama::graph::{GraphStore, Label, PropertyValue};
+use samyama::graph::{EdgeType, GraphStore, Label, PropertyValue};
 use samyama::query::parser::parse_query;
 use samyama::query::executor::QueryExecutor;
+use samyama::query::QueryEngine;
 
 /// Benchmark node insertion throughput
 fn bench_node_insertion(c: &mut Criterion) {
@@ -141,6 +142,85 @@ fn bench_where_filter(c: &mut Criterion) {
     group.finish();
 }
 
+/// Benchmark typed expansion from a hot node with many mixed-type edges:
+/// filtering the full adjacency versus the typed adjacency accessor
+fn bench_typed_adjacency(c: &mut Criterion) {
+    let mut group = c.benchmark_group("typed_adjacency");
+
+    let mut store = GraphStore::new();
+    let hub = store.create_node("Hub");
+    let types = ["KNOWS", "LIKES", "FOLLOWS", "BLOCKS", "MENTIONS"];
+    for i in 0..10_000 {
+        let leaf = store.create_node("Leaf");
+        store.create_edge(hub, leaf, types[i % types.len()]).unwrap();
+    }
+    let knows = EdgeType::new("KNOWS");
+
+    group.bench_function("filter_outgoing", |b| {
+        b.iter(|| {
+            let edges: Vec<_> = store
+                .get_outgoing_edges(hub)
+                .into_iter()
+                .filter(|e| e.edge_type == knows)
+                .collect();
+            criterion::black_box(edges.len());
+        });
+    });
+
+    group.bench_function("outgoing_of_type", |b| {
+        b.iter(|| {
+            criterion::black_box(store.get_outgoing_edges_of_type(hub, &knows).len());
+        });
+    });
+
+    group.finish();
+}
+
+/// Benchmark loading 1M edges one `create_edge` call at a time versus a
+/// single `create_edges_bulk` call
+fn bench_edge_loading(c: &mut Criterion) {
+    let mut group = c.benchmark_group("edge_loading");
+    group.sample_size(10);
+
+    const NODES: u64 = 100_000;
+    const EDGES: u64 = 1_000_000;
+    let setup = || {
+        let mut store = GraphStore::new();
+        let ids: Vec<_> = (0..NODES).map(|_| store.create_node("Person")).collect();
+        let knows = EdgeType::new("KNOWS");
+        let triples: Vec<_> = (0..EDGES)
+            .map(|i| (ids[(i % NODES) as usize], ids[((i * 7919 + 13) % NODES) as usize], knows.clone()))
+            .collect();
+        (store, triples)
+    };
+
+    group.bench_function("individual", |b| {
+        b.iter_batched(
+            setup,
+            |(mut store, triples)| {
+                for (s, t, ty) in triples {
+                    store.create_edge(s, t, ty).unwrap();
+                }
+                store
+            },
+            criterion::BatchSize::LargeInput,
+        );
+    });
+
+    group.bench_function("bulk", |b| {
+        b.iter_batched(
+            setup,
+            |(mut store, triples)| {
+                store.create_edges_bulk(&triples).unwrap();
+                store
+            },
+            criterion::BatchSize::LargeInput,
+        );
+    });
+
+    group.finish();
+}
+
 /// Benchmark Cypher parse time
 fn bench_cypher_parse(c: &mut Criterion) {
     let mut group = c.benchmark_group("cypher_parse");
@@ -178,12 +258,47 @@ fn bench_cypher_parse(c: &mut Criterion) {
     group.finish();
 }
 
+/// Benchmark repeated execution of one query: parse every time vs the
+/// QueryEngine AST cache
+fn bench_query_cache(c: &mut Criterion) {
+    let mut group = c.benchmark_group("query_cache");
+
+    let mut store = GraphStore::new();
+    for i in 0..100 {
+        let id = store.create_node("Person");
+        if let Some(node) = store.get_node_mut(id) {
+            node.set_property("name", format!("Person{}", i));
+            node.set_property("age", (i % 50) as i64);
+        }
+    }
+    let query = "MATCH (n:Person) WHERE n.age > 30 AND n.name <> 'Person7' RETURN n.name, n.age ORDER BY n.age DESC LIMIT 5";
+
+    group.bench_function("uncached", |b| {
+        b.iter(|| {
+            let parsed = parse_query(query).unwrap();
+            criterion::black_box(QueryExecutor::new(&store).execute(&parsed).unwrap());
+        });
+    });
+
+    let engine = QueryEngine::new();
+    group.bench_function("cached", |b| {
+        b.iter(|| {
+            criterion::black_box(engine.execute(query, &store).unwrap());
+        });
+    });
+
+    group.finish();
+}
+
 criterion_group!(
     benches,
     bench_node_insertion,
     bench_label_scan,
     bench_traversal,
     bench_where_filter,
+    bench_typed_adjacency,
+    bench_edge_loading,
     bench_cypher_parse,
+    bench_query_cache,
 );
 criterion_main!(benches);
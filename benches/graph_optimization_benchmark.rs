@@ -155,7 +155,8 @@ fn main() {
 
     let config = SolverConfig {
         population_size: 50,
-        max_iterations: 100, // Reduced iterations for quicker benchmark of multiple algos
+        max_iterations: 100, // Reduced iterations for quicker benchmark of multiple algos,
+        ..Default::default()
     };
 
     println!("\n[2/4] Benchmarking Algorithms...");
@@ -5,12 +5,46 @@
 //! 2. Lightweight traversal (get_outgoing_edge_targets - no clone)
 //! 3. Cypher 1-hop query via QueryEngine (full pipeline)
 //! 4. Cypher 2-hop query via QueryEngine (full pipeline)
+//! 5-7. Cypher `RETURN n` vs `RETURN n.prop`, including heap bytes allocated per query
 
 use samyama::{GraphStore, Label, PropertyValue, QueryEngine};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::time::Instant;
 use std::collections::HashSet;
 use rand::Rng;
 
+/// System allocator that counts bytes allocated, for the per-query memory figures
+struct CountingAlloc;
+
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATED.fetch_add(layout.size(), Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+/// Average wall time and heap bytes allocated per run of `query`
+fn measure(engine: &QueryEngine, store: &GraphStore, query: &str, iterations: usize) -> (std::time::Duration, usize) {
+    let bytes_before = ALLOCATED.load(Ordering::Relaxed);
+    let start = Instant::now();
+    for _ in 0..iterations {
+        let _ = engine.execute(query, store);
+    }
+    let duration = start.elapsed() / iterations as u32;
+    let bytes = (ALLOCATED.load(Ordering::Relaxed) - bytes_before) / iterations;
+    (duration, bytes)
+}
+
 #[path = "bench_setup.rs"]
 mod bench_setup;
 
@@ -204,5 +238,32 @@ fn main() {
     }
     println!();
 
+    // ── Benchmark 7: knowledge-graph demo shape, 200 wide Document nodes ──
+    println!("┌──────────────────────────────────────────────────────────────────┐");
+    println!("│ 7. 200 Documents: RETURN n.title vs RETURN n (time, heap bytes)  │");
+    println!("└──────────────────────────────────────────────────────────────────┘");
+    {
+        // Same properties as examples/knowledge_graph_demo.rs, including the 128-d embedding
+        let mut docs = GraphStore::new();
+        for i in 0..200 {
+            let id = docs.create_node("Document");
+            docs.set_node_property("default", id, "title", format!("Document {} design guide", i)).unwrap();
+            docs.set_node_property("default", id, "department", "Engineering").unwrap();
+            docs.set_node_property("default", id, "date", "2025-01-15").unwrap();
+            docs.set_node_property("default", id, "content_summary",
+                "Step-by-step migration from monolith to microservices using Kubernetes and gRPC").unwrap();
+            let embedding: Vec<f32> = (0..128).map(|d| ((i * 31 + d) % 97) as f32 / 97.0).collect();
+            docs.set_node_property("default", id, "embedding", PropertyValue::Vector(embedding)).unwrap();
+        }
+
+        let engine = QueryEngine::new();
+        let iterations = 1000;
+        for query in ["MATCH (n:Document) RETURN n.title", "MATCH (n:Document) RETURN n"] {
+            let (avg, bytes) = measure(&engine, &docs, query, iterations);
+            println!("    {:<36} Avg: {:>10.2?}  |  Heap: {:>9} B/query", query, avg, bytes);
+        }
+    }
+    println!();
+
     println!("Done.");
 }
@@ -21,6 +21,10 @@ use samyama_sdk::{EmbeddedClient, SamyamaClient};
 #[path = "bench_setup.rs"]
 mod bench_setup;
 
+// Shared with the ldbc_loader example; its unit tests don't run under a
+// harness-less bench, so their imports go unused here.
+#[allow(unused_imports)]
+#[path = "../examples/ldbc_common/mod.rs"]
 mod ldbc_common;
 use ldbc_common::{format_duration, format_num};
 
@@ -615,7 +619,7 @@ async fn main() -> Result<(), Error> {
     let load_start = Instant::now();
     let load_result = {
         let mut graph = client.store_write().await;
-        ldbc_common::load_dataset(&mut graph, &data_dir)?
+        ldbc_common::load_dataset(&mut graph, &data_dir, ldbc_common::DEFAULT_MAX_SKIP_PCT)?
     };
     let load_time = load_start.elapsed();
 
@@ -586,7 +586,7 @@ async fn main() -> Result<(), Error> {
     let load_start = Instant::now();
     let load_result = {
         let mut graph = client.store_write().await;
-        ldbc_bi_common::load_dataset(&mut graph, &data_dir)?
+        ldbc_bi_common::load_dataset(&mut graph, &data_dir, ldbc_bi_common::ldbc_common::DEFAULT_MAX_SKIP_PCT)?
     };
     let load_time = load_start.elapsed();
 
@@ -7,7 +7,8 @@
 
 // Re-export the full dataset loader and formatting helpers from ldbc_common.
 // The BI benchmark loads the same SNB SF1 graph as the interactive benchmark.
-#[path = "../ldbc_common/mod.rs"]
+#[allow(unused_imports)]
+#[path = "../../examples/ldbc_common/mod.rs"]
 pub mod ldbc_common;
 
 pub use ldbc_common::{format_duration, format_num, load_dataset};
@@ -107,7 +107,7 @@ impl MultiObjectiveProblem for DTLZ1 {
 fn bench_single_obj(c: &mut Criterion) {
     let mut group = c.benchmark_group("rao_family_single_obj_sphere_10d");
     group.sample_size(10);
-    let cfg = SolverConfig { population_size: 30, max_iterations: 100 };
+    let cfg = SolverConfig { population_size: 30, max_iterations: 100, ..Default::default() };
     let p = make_problem(sphere, 10, -10.0, 10.0);
 
     group.bench_function("BMR", |b| b.iter(|| black_box(BMRSolver::new(cfg.clone()).solve(&p))));
@@ -125,7 +125,7 @@ fn bench_single_obj(c: &mut Criterion) {
 fn bench_rastrigin(c: &mut Criterion) {
     let mut group = c.benchmark_group("rao_family_rastrigin_10d");
     group.sample_size(10);
-    let cfg = SolverConfig { population_size: 50, max_iterations: 200 };
+    let cfg = SolverConfig { population_size: 50, max_iterations: 200, ..Default::default() };
     let p = make_problem(rastrigin, 10, -5.12, 5.12);
     group.bench_function("BMWR", |b| b.iter(|| black_box(BMWRSolver::new(cfg.clone()).solve(&p))));
     group.bench_function("EHR-Jaya", |b| b.iter(|| black_box(EHRJayaSolver::new(cfg.clone()).solve(&p))));
@@ -136,7 +136,7 @@ fn bench_rastrigin(c: &mut Criterion) {
 fn bench_ackley(c: &mut Criterion) {
     let mut group = c.benchmark_group("rao_family_ackley_10d");
     group.sample_size(10);
-    let cfg = SolverConfig { population_size: 50, max_iterations: 200 };
+    let cfg = SolverConfig { population_size: 50, max_iterations: 200, ..Default::default() };
     let p = make_problem(ackley, 10, -32.768, 32.768);
     group.bench_function("BMWR", |b| b.iter(|| black_box(BMWRSolver::new(cfg.clone()).solve(&p))));
     group.bench_function("QO-Rao", |b| b.iter(|| black_box(QORaoSolver::new(cfg.clone(), RaoVariant::Rao1).solve(&p))));
@@ -146,7 +146,7 @@ fn bench_ackley(c: &mut Criterion) {
 fn bench_mo_zdt1(c: &mut Criterion) {
     let mut group = c.benchmark_group("rao_family_mo_zdt1_30d");
     group.sample_size(10);
-    let cfg = SolverConfig { population_size: 50, max_iterations: 100 };
+    let cfg = SolverConfig { population_size: 50, max_iterations: 100, ..Default::default() };
     let p = ZDT { variant: 1, dim: 30 };
     group.bench_function("MO-BMR",   |b| b.iter(|| black_box(MOBMWRSolver::new(cfg.clone(), MOBMWRVariant::MOBMR).solve(&p))));
     group.bench_function("MO-BWR",   |b| b.iter(|| black_box(MOBMWRSolver::new(cfg.clone(), MOBMWRVariant::MOBWR).solve(&p))));
@@ -158,7 +158,7 @@ fn bench_mo_zdt1(c: &mut Criterion) {
 fn bench_mo_zdt2(c: &mut Criterion) {
     let mut group = c.benchmark_group("rao_family_mo_zdt2_30d");
     group.sample_size(10);
-    let cfg = SolverConfig { population_size: 50, max_iterations: 100 };
+    let cfg = SolverConfig { population_size: 50, max_iterations: 100, ..Default::default() };
     let p = ZDT { variant: 2, dim: 30 };
     group.bench_function("MO-BMWR", |b| b.iter(|| black_box(MOBMWRSolver::new(cfg.clone(), MOBMWRVariant::MOBMWR).solve(&p))));
     group.bench_function("MO-Rao+DE", |b| b.iter(|| black_box(MORaoDESolver::new(cfg.clone()).solve(&p))));
@@ -168,7 +168,7 @@ fn bench_mo_zdt2(c: &mut Criterion) {
 fn bench_mo_zdt3(c: &mut Criterion) {
     let mut group = c.benchmark_group("rao_family_mo_zdt3_30d");
     group.sample_size(10);
-    let cfg = SolverConfig { population_size: 50, max_iterations: 100 };
+    let cfg = SolverConfig { population_size: 50, max_iterations: 100, ..Default::default() };
     let p = ZDT { variant: 3, dim: 30 };
     group.bench_function("MO-BMWR", |b| b.iter(|| black_box(MOBMWRSolver::new(cfg.clone(), MOBMWRVariant::MOBMWR).solve(&p))));
     group.finish();
@@ -177,7 +177,7 @@ fn bench_mo_zdt3(c: &mut Criterion) {
 fn bench_mo_dtlz1(c: &mut Criterion) {
     let mut group = c.benchmark_group("rao_family_mo_dtlz1_3obj");
     group.sample_size(10);
-    let cfg = SolverConfig { population_size: 60, max_iterations: 100 };
+    let cfg = SolverConfig { population_size: 60, max_iterations: 100, ..Default::default() };
     let p = DTLZ1 { dim: 7, m: 3 };
     group.bench_function("MO-BMR",  |b| b.iter(|| black_box(MOBMWRSolver::new(cfg.clone(), MOBMWRVariant::MOBMR).solve(&p))));
     group.bench_function("MO-BMWR", |b| b.iter(|| black_box(MOBMWRSolver::new(cfg.clone(), MOBMWRVariant::MOBMWR).solve(&p))));
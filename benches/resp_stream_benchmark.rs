@@ -0,0 +1,98 @@
+//! Micro-benchmark: server memory for a large GRAPH.QUERY reply
+//!
+//! Compares the peak heap held while answering `MATCH (n:Item) RETURN n.i, n.filler`:
+//! 1. Buffered (the pre-streaming path): `execute` → `encode_result_set` → bytes
+//! 2. Streamed to a RESP3 client (`stream_result_set`, `*?` rows)
+//! 3. Streamed to a RESP2 client (`stream_result_set`, sized rows held until the end)
+//!
+//! Replies are written to `tokio::io::sink()`, so socket buffers are not counted.
+
+use samyama::protocol::result_set::{encode_result_set, stream_result_set, QueryStatistics};
+use samyama::{GraphStore, QueryEngine};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Instant;
+use tokio::io::AsyncWriteExt;
+
+/// System allocator that tracks live heap bytes and their high-water mark
+struct PeakAlloc;
+
+static CURRENT: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for PeakAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let now = CURRENT.fetch_add(layout.size(), Ordering::Relaxed) + layout.size();
+        PEAK.fetch_max(now, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        CURRENT.fetch_sub(layout.size(), Ordering::Relaxed);
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: PeakAlloc = PeakAlloc;
+
+#[path = "bench_setup.rs"]
+mod bench_setup;
+
+const NUM_ROWS: i64 = 200_000;
+const QUERY: &str = "MATCH (n:Item) RETURN n.i, n.filler";
+
+/// Peak heap bytes above the starting level while `run` executes, and its wall time
+async fn measure<F: std::future::Future<Output = ()>>(run: F) -> (usize, std::time::Duration) {
+    let baseline = CURRENT.load(Ordering::Relaxed);
+    PEAK.store(baseline, Ordering::Relaxed);
+    let start = Instant::now();
+    run.await;
+    (PEAK.load(Ordering::Relaxed) - baseline, start.elapsed())
+}
+
+#[tokio::main(flavor = "current_thread")]
+async fn main() {
+    bench_setup::init();
+
+    println!("╔══════════════════════════════════════════════════════════════════╗");
+    println!("║   RESP Streaming Reply Benchmark                                 ║");
+    println!("╚══════════════════════════════════════════════════════════════════╝");
+
+    let mut store = GraphStore::new();
+    let filler = "x".repeat(64);
+    for i in 0..NUM_ROWS {
+        let id = store.create_node("Item");
+        store.set_node_property("default", id, "i", i).unwrap();
+        store.set_node_property("default", id, "filler", filler.as_str()).unwrap();
+    }
+    let engine = QueryEngine::new();
+    println!("\n{} rows: {}\n", NUM_ROWS, QUERY);
+
+    let (buffered, buffered_time) = measure(async {
+        let batch = engine.execute(QUERY, &store).unwrap();
+        let reply = encode_result_set(&batch, &store, &QueryStatistics::default(), false);
+        let mut bytes = Vec::new();
+        reply.encode(&mut bytes).unwrap();
+        tokio::io::sink().write_all(&bytes).await.unwrap();
+    })
+    .await;
+
+    let mut streamed = Vec::new();
+    for resp3 in [true, false] {
+        streamed.push(
+            measure(async {
+                let mut cursor = engine.execute_cursor(QUERY, &store).unwrap();
+                let mut out = tokio::io::sink();
+                stream_result_set(&mut cursor, &store, false, resp3, Instant::now(), &mut out).await.unwrap();
+            })
+            .await,
+        );
+    }
+
+    let mb = |bytes: usize| bytes as f64 / (1024.0 * 1024.0);
+    println!("  {:<28} {:>12} {:>12}", "Path", "Peak heap", "Time");
+    println!("  {:<28} {:>9.1} MB {:>9.0} ms", "Buffered (before)", mb(buffered), buffered_time.as_secs_f64() * 1e3);
+    println!("  {:<28} {:>9.1} MB {:>9.0} ms", "Streamed, RESP3", mb(streamed[0].0), streamed[0].1.as_secs_f64() * 1e3);
+    println!("  {:<28} {:>9.1} MB {:>9.0} ms", "Streamed, RESP2", mb(streamed[1].0), streamed[1].1.as_secs_f64() * 1e3);
+}
@@ -82,8 +82,8 @@ fn benchmark_distance_metrics(num_vectors: usize, dim: usize, k: usize) {
 
     let metrics = [
         ("Cosine", DistanceMetric::Cosine),
-        ("L2", DistanceMetric::L2),
-        ("InnerProduct", DistanceMetric::InnerProduct),
+        ("Euclidean", DistanceMetric::Euclidean),
+        ("DotProduct", DistanceMetric::DotProduct),
     ];
 
     let mut rng = rand::thread_rng();
@@ -0,0 +1,426 @@
+//! Bulk CSV import for the `load` subcommand
+//!
+//! Node files need an `id` column (or `:ID`) and may carry a `labels` column
+//! (or `:LABEL`) holding `;`-separated labels. Edge files need `source`,
+//! `target` and `type` columns (or `:START_ID`, `:END_ID`, `:TYPE`). Every
+//! other column becomes a property; empty cells are left unset. Cells that
+//! look like integers or floats are stored as numbers, so `id` values typed in
+//! the node file match the same values in the edge file.
+//!
+//! Rows are sent in batches of CREATE statements. A batch that fails is
+//! retried row by row so each skipped row is reported with its own reason
+//! instead of being dropped silently. Edge endpoints are matched by id
+//! without a label, so an edge batch is only sent as one statement when
+//! every endpoint id in it matches exactly one node.
+
+use samyama_sdk::SamyamaClient;
+use std::fmt;
+use std::path::Path;
+
+/// Settings for a bulk import
+pub struct LoadOptions {
+    /// Graph/tenant to load into
+    pub graph: String,
+    /// Rows per CREATE statement
+    pub batch_size: usize,
+    /// Node property holding the CSV id, matched by edge endpoints
+    pub id_property: String,
+}
+
+/// A row that was not imported
+#[derive(Debug)]
+pub struct SkippedRow {
+    /// File the row came from
+    pub file: String,
+    /// 1-based line number of the row
+    pub line: usize,
+    /// Why the row was skipped
+    pub reason: String,
+}
+
+impl fmt::Display for SkippedRow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}: {}", self.file, self.line, self.reason)
+    }
+}
+
+/// Outcome of an import
+#[derive(Debug, Default)]
+pub struct LoadReport {
+    pub nodes_loaded: usize,
+    pub edges_loaded: usize,
+    pub skipped: Vec<SkippedRow>,
+}
+
+/// A parsed row ready to become part of a CREATE statement
+struct Row {
+    line: usize,
+    /// Cypher fragments: a node pattern, or the MATCH and CREATE halves of an edge
+    fragments: Vec<String>,
+}
+
+/// Import the node file, then the edge file
+pub async fn load<C: SamyamaClient + ?Sized>(
+    client: &C,
+    nodes: Option<&Path>,
+    edges: Option<&Path>,
+    options: &LoadOptions,
+) -> Result<LoadReport, Box<dyn std::error::Error>> {
+    if !is_identifier(&options.id_property) {
+        return Err(format!("'{}' is not a valid property name", options.id_property).into());
+    }
+    let mut report = LoadReport::default();
+    let batch_size = options.batch_size.max(1);
+
+    if let Some(path) = nodes {
+        let file = path.display().to_string();
+        let rows = node_rows(&std::fs::read_to_string(path)?, &file, options, &mut report.skipped)?;
+        for batch in rows.chunks(batch_size) {
+            let statement = format!("CREATE {}", join(batch, 0, ", "));
+            if client.query(&options.graph, &statement).await.is_ok() {
+                report.nodes_loaded += batch.len();
+                continue;
+            }
+            for row in batch {
+                match client.query(&options.graph, &format!("CREATE {}", row.fragments[0])).await {
+                    Ok(_) => report.nodes_loaded += 1,
+                    Err(e) => report.skip(&file, row.line, e.to_string()),
+                }
+            }
+        }
+    }
+
+    if let Some(path) = edges {
+        let file = path.display().to_string();
+        let rows = edge_rows(&std::fs::read_to_string(path)?, &file, options, &mut report.skipped)?;
+        for batch in rows.chunks(batch_size) {
+            // Variables are numbered per row so one statement can link many pairs.
+            // The batched MATCH is a cartesian product of every row's endpoints:
+            // unless it yields exactly one row (each endpoint id matched one
+            // node), a batched CREATE would repeat every row's edge once per
+            // combination, so such batches, like those with a missing
+            // endpoint, are loaded row by row instead.
+            let probe = format!("MATCH {} RETURN count(*)", join(batch, 0, ", "));
+            let single = serde_json::Value::from(1);
+            if matches!(client.query_readonly(&options.graph, &probe).await, Ok(r) if r.records == [[single]]) {
+                let statement = format!("MATCH {} CREATE {}", join(batch, 0, ", "), join(batch, 1, ", "));
+                if client.query(&options.graph, &statement).await.is_ok() {
+                    report.edges_loaded += batch.len();
+                    continue;
+                }
+            }
+            for row in batch {
+                let statement = format!("MATCH {} CREATE {}", row.fragments[0], row.fragments[1]);
+                match client.query(&options.graph, &statement).await {
+                    Ok(r) if !r.is_empty() => report.edges_loaded += 1,
+                    Ok(_) => report.skip(&file, row.line, "source or target node not found".to_string()),
+                    Err(e) => report.skip(&file, row.line, e.to_string()),
+                }
+            }
+        }
+    }
+
+    Ok(report)
+}
+
+impl LoadReport {
+    fn skip(&mut self, file: &str, line: usize, reason: String) {
+        self.skipped.push(SkippedRow { file: file.to_string(), line, reason });
+    }
+}
+
+fn join(rows: &[Row], fragment: usize, separator: &str) -> String {
+    rows.iter().map(|r| r.fragments[fragment].as_str()).collect::<Vec<_>>().join(separator)
+}
+
+/// Build one `(:Label {props})` pattern per node row
+fn node_rows(
+    text: &str,
+    file: &str,
+    options: &LoadOptions,
+    skipped: &mut Vec<SkippedRow>,
+) -> Result<Vec<Row>, Box<dyn std::error::Error>> {
+    let mut records = parse_csv(text).into_iter();
+    let Some((_, header)) = records.next() else { return Ok(Vec::new()) };
+    let id_col = column(&header, &["id", ":ID"], file)?;
+    let labels_col = header.iter().position(|h| h == "labels" || h == ":LABEL");
+    let props = property_columns(&header, &[Some(id_col), labels_col], file)?;
+
+    let mut rows = Vec::new();
+    for (line, fields) in records {
+        let result = (|| {
+            let id = cell(&fields, id_col).ok_or("missing id")?;
+            let mut labels = String::new();
+            for label in labels_col.and_then(|c| cell(&fields, c)).unwrap_or("").split(';') {
+                let label = label.trim();
+                if label.is_empty() {
+                    continue;
+                }
+                if !is_identifier(label) {
+                    return Err(format!("invalid label '{}'", label));
+                }
+                labels.push(':');
+                labels.push_str(label);
+            }
+            if !labels.is_empty() {
+                labels.push(' ');
+            }
+            let map = property_map(&fields, &props, Some((&options.id_property, id)))?;
+            Ok(format!("({}{})", labels, map))
+        })();
+        match result {
+            Ok(pattern) => rows.push(Row { line, fragments: vec![pattern] }),
+            Err(reason) => skipped.push(SkippedRow { file: file.to_string(), line, reason }),
+        }
+    }
+    Ok(rows)
+}
+
+/// Build the MATCH and CREATE fragments for each edge row
+fn edge_rows(
+    text: &str,
+    file: &str,
+    options: &LoadOptions,
+    skipped: &mut Vec<SkippedRow>,
+) -> Result<Vec<Row>, Box<dyn std::error::Error>> {
+    let mut records = parse_csv(text).into_iter();
+    let Some((_, header)) = records.next() else { return Ok(Vec::new()) };
+    let source_col = column(&header, &["source", ":START_ID"], file)?;
+    let target_col = column(&header, &["target", ":END_ID"], file)?;
+    let type_col = column(&header, &["type", ":TYPE"], file)?;
+    let props = property_columns(&header, &[Some(source_col), Some(target_col), Some(type_col)], file)?;
+    let key = &options.id_property;
+
+    let mut rows = Vec::new();
+    for (i, (line, fields)) in records.enumerate() {
+        let result = (|| {
+            let source = literal(cell(&fields, source_col).ok_or("missing source")?)?;
+            let target = literal(cell(&fields, target_col).ok_or("missing target")?)?;
+            let edge_type = cell(&fields, type_col).ok_or("missing type")?;
+            if !is_identifier(edge_type) {
+                return Err(format!("invalid relationship type '{}'", edge_type));
+            }
+            let map = property_map(&fields, &props, None)?;
+            Ok(vec![
+                format!("(s{i} {{{key}: {source}}}), (t{i} {{{key}: {target}}})"),
+                format!("(s{i})-[:{edge_type} {map}]->(t{i})"),
+            ])
+        })();
+        match result {
+            Ok(fragments) => rows.push(Row { line, fragments }),
+            Err(reason) => skipped.push(SkippedRow { file: file.to_string(), line, reason }),
+        }
+    }
+    Ok(rows)
+}
+
+fn column(header: &[String], names: &[&str], file: &str) -> Result<usize, String> {
+    header
+        .iter()
+        .position(|h| names.contains(&h.as_str()))
+        .ok_or_else(|| format!("{}: header has no '{}' column", file, names[0]))
+}
+
+/// Remaining columns, which become properties
+fn property_columns(
+    header: &[String],
+    reserved: &[Option<usize>],
+    file: &str,
+) -> Result<Vec<(usize, String)>, String> {
+    let mut props = Vec::new();
+    for (i, name) in header.iter().enumerate() {
+        if reserved.contains(&Some(i)) {
+            continue;
+        }
+        if !is_identifier(name) {
+            return Err(format!("{}: column '{}' is not a valid property name", file, name));
+        }
+        props.push((i, name.clone()));
+    }
+    Ok(props)
+}
+
+fn cell(fields: &[String], col: usize) -> Option<&str> {
+    fields.get(col).map(|s| s.trim()).filter(|s| !s.is_empty())
+}
+
+/// `{key: value, ...}` for the non-empty property cells, plus an optional extra entry
+fn property_map(
+    fields: &[String],
+    props: &[(usize, String)],
+    extra: Option<(&str, &str)>,
+) -> Result<String, String> {
+    let mut entries = Vec::new();
+    if let Some((key, raw)) = extra {
+        entries.push(format!("{}: {}", key, literal(raw)?));
+    }
+    for (col, key) in props {
+        if let Some(raw) = cell(fields, *col) {
+            entries.push(format!("{}: {}", key, literal(raw)?));
+        }
+    }
+    Ok(format!("{{{}}}", entries.join(", ")))
+}
+
+/// Cypher literal for a cell: integers and floats stay numeric, everything else is quoted
+///
+/// Numbers with a leading zero (e.g. zip codes) are kept as strings. Cypher
+/// string literals have no escapes here, so a value containing both quote
+/// characters cannot be written.
+fn literal(raw: &str) -> Result<String, String> {
+    let digits = raw.strip_prefix('-').unwrap_or(raw);
+    let leading_zero = digits.len() > 1 && digits.starts_with('0') && !digits.starts_with("0.");
+    if !leading_zero {
+        if let Ok(i) = raw.parse::<i64>() {
+            return Ok(i.to_string());
+        }
+        if let Ok(f) = raw.parse::<f64>() {
+            if f.is_finite() && raw.bytes().any(|b| b.is_ascii_digit()) {
+                return Ok(format!("{:?}", f));
+            }
+        }
+    }
+    if !raw.contains('\'') {
+        Ok(format!("'{}'", raw))
+    } else if !raw.contains('"') {
+        Ok(format!("\"{}\"", raw))
+    } else {
+        Err(format!("value contains both quote characters: {}", raw))
+    }
+}
+
+fn is_identifier(s: &str) -> bool {
+    let mut chars = s.chars();
+    matches!(chars.next(), Some(c) if c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// Split CSV text into records tagged with their starting line number
+///
+/// Fields may be double-quoted, with `""` for a literal quote and embedded
+/// commas or newlines. Blank lines are ignored.
+fn parse_csv(text: &str) -> Vec<(usize, Vec<String>)> {
+    let mut records = Vec::new();
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut line = 1;
+    let mut start = 1;
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match (c, in_quotes) {
+            ('"', true) if chars.peek() == Some(&'"') => {
+                chars.next();
+                field.push('"');
+            }
+            ('"', true) => in_quotes = false,
+            ('"', false) if field.is_empty() => in_quotes = true,
+            (',', false) => fields.push(std::mem::take(&mut field)),
+            ('\r', false) => {}
+            ('\n', false) => {
+                fields.push(std::mem::take(&mut field));
+                if fields.len() > 1 || !fields[0].is_empty() {
+                    records.push((start, std::mem::take(&mut fields)));
+                }
+                fields.clear();
+                line += 1;
+                start = line;
+            }
+            (c, _) => {
+                if c == '\n' {
+                    line += 1;
+                }
+                field.push(c);
+            }
+        }
+    }
+    fields.push(field);
+    if fields.len() > 1 || !fields[0].is_empty() {
+        records.push((start, fields));
+    }
+    records
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use samyama_sdk::EmbeddedClient;
+
+    fn options() -> LoadOptions {
+        LoadOptions { graph: "default".to_string(), batch_size: 2, id_property: "id".to_string() }
+    }
+
+    #[test]
+    fn test_parse_csv_quoting() {
+        let records = parse_csv("a,b\n\"x, y\",\"say \"\"hi\"\"\"\n\n\"multi\nline\",2\n");
+        assert_eq!(records.len(), 3);
+        assert_eq!(records[1], (2, vec!["x, y".to_string(), "say \"hi\"".to_string()]));
+        assert_eq!(records[2], (4, vec!["multi\nline".to_string(), "2".to_string()]));
+    }
+
+    #[test]
+    fn test_literal_typing() {
+        assert_eq!(literal("42").unwrap(), "42");
+        assert_eq!(literal("-1.5").unwrap(), "-1.5");
+        assert_eq!(literal("1e3").unwrap(), "1000.0");
+        assert_eq!(literal("02139").unwrap(), "'02139'");
+        assert_eq!(literal("inf").unwrap(), "'inf'");
+        assert_eq!(literal("O'Brien").unwrap(), "\"O'Brien\"");
+        assert!(literal("'both\"").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_load_reports_loaded_and_skipped_rows() {
+        let dir = std::env::temp_dir().join(format!("samyama_cli_load_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let nodes = dir.join("nodes.csv");
+        let edges = dir.join("edges.csv");
+        std::fs::write(&nodes, "id,labels,name,age\n1,Person,Alice,30\n2,Person;Admin,Bob,\n3,Bad-Label,Eve,1\n4,City,Pune,\n").unwrap();
+        std::fs::write(&edges, "source,target,type,since\n1,2,KNOWS,2020\n1,4,LIVES_IN,\n2,99,KNOWS,\n4,1,\n").unwrap();
+
+        let client = EmbeddedClient::new();
+        let report = load(&client, Some(&nodes), Some(&edges), &options()).await.unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(report.nodes_loaded, 3);
+        assert_eq!(report.edges_loaded, 2);
+        let lines: Vec<(usize, &str)> =
+            report.skipped.iter().map(|s| (s.line, s.reason.as_str())).collect();
+        assert_eq!(lines.len(), 3, "{:?}", report.skipped);
+        assert!(lines.contains(&(4, "invalid label 'Bad-Label'")));
+        assert!(lines.contains(&(5, "missing type")));
+        assert!(lines.contains(&(4, "source or target node not found")));
+
+        let result = client
+            .query_readonly("default", "MATCH (a:Person)-[r:KNOWS]->(b:Admin) RETURN a.age, r.since, b.name")
+            .await
+            .unwrap();
+        assert_eq!(result.records, vec![vec![serde_json::json!(30), serde_json::json!(2020), serde_json::json!("Bob")]]);
+    }
+
+    #[tokio::test]
+    async fn test_batched_edges_match_row_by_row_with_ambiguous_ids() {
+        let dir = std::env::temp_dir().join(format!("samyama_cli_load_dup_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let nodes = dir.join("nodes.csv");
+        let edges = dir.join("edges.csv");
+        // Id 1 is used by both a Person and a City; endpoints are matched without labels
+        std::fs::write(&nodes, "id,labels\n1,Person\n1,City\n2,Person\n3,Person\n4,Person\n").unwrap();
+        std::fs::write(&edges, "source,target,type\n1,2,LINK\n3,4,LINK\n").unwrap();
+
+        let client = EmbeddedClient::new();
+        let report = load(&client, Some(&nodes), Some(&edges), &options()).await.unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(report.edges_loaded, 2, "{:?}", report.skipped);
+
+        let count = |cypher: &'static str| {
+            let client = &client;
+            async move { client.query_readonly("default", cypher).await.unwrap().records[0][0].clone() }
+        };
+        // Row 1 links both nodes with id 1 to node 2, as a lone MATCH would; row 2 links once
+        assert_eq!(count("MATCH (a)-[:LINK]->(b {id: 2}) RETURN count(a)").await, serde_json::json!(2));
+        assert_eq!(count("MATCH (a {id: 3})-[:LINK]->(b) RETURN count(b)").await, serde_json::json!(1));
+        assert_eq!(count("MATCH ()-[r:LINK]->() RETURN count(r)").await, serde_json::json!(3));
+    }
+}
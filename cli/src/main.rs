@@ -2,9 +2,18 @@
 //!
 //! Uses the samyama-sdk RemoteClient to connect to a running server.
 
+mod load;
+mod params;
+
 use clap::{Parser, Subcommand};
 use comfy_table::{Table, ContentArrangement};
-use samyama_sdk::{RemoteClient, SamyamaClient};
+use rustyline::error::ReadlineError;
+use samyama_sdk::{PropertyValue, RemoteClient, RemoteClientConfig, SamyamaClient};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use load::LoadOptions;
 
 #[derive(Parser)]
 #[command(name = "samyama", version, about = "Samyama Graph Database CLI")]
@@ -17,6 +26,10 @@ struct Cli {
     #[arg(long, default_value = "table", global = true)]
     format: OutputFormat,
 
+    /// Request timeout in seconds
+    #[arg(long, default_value_t = 30, global = true)]
+    timeout: u64,
+
     #[command(subcommand)]
     command: Commands,
 }
@@ -42,11 +55,63 @@ enum Commands {
         /// Use read-only mode
         #[arg(long)]
         readonly: bool,
+
+        /// Query parameter, referenced as `$key` in the query (repeatable)
+        #[arg(long = "param", value_name = "KEY=VALUE", value_parser = params::parse_param)]
+        params: Vec<(String, PropertyValue)>,
     },
     /// Get server status
     Status,
+    /// Show per-label and per-edge-type counts and indexes
+    Stats {
+        /// Graph/tenant name
+        #[arg(long, default_value = "default")]
+        graph: String,
+    },
     /// Ping the server
     Ping,
+    /// Bulk-import nodes and edges from CSV files
+    Load {
+        /// Node CSV: `id`, optional `labels` (`;`-separated), other columns as properties
+        #[arg(long)]
+        nodes: Option<PathBuf>,
+
+        /// Edge CSV: `source`, `target`, `type`, other columns as properties
+        #[arg(long)]
+        edges: Option<PathBuf>,
+
+        /// Graph/tenant name
+        #[arg(long, default_value = "default")]
+        graph: String,
+
+        /// Rows per CREATE statement
+        #[arg(long, default_value_t = 500)]
+        batch_size: usize,
+
+        /// Node property that stores the CSV `id` and is matched by edge endpoints
+        #[arg(long, default_value = "id")]
+        id_property: String,
+    },
+    /// Back up the graph to a snapshot file
+    Dump {
+        /// Graph/tenant name (snapshots support only "default")
+        #[arg(long, default_value = "default")]
+        graph: String,
+
+        /// Snapshot file to write
+        #[arg(long)]
+        out: PathBuf,
+    },
+    /// Restore a snapshot file into the graph
+    Restore {
+        /// Graph/tenant name (snapshots support only "default")
+        #[arg(long, default_value = "default")]
+        graph: String,
+
+        /// Snapshot file to read
+        #[arg(long = "in")]
+        input: PathBuf,
+    },
     /// Start an interactive REPL
     Shell {
         /// Graph/tenant name
@@ -58,14 +123,32 @@ enum Commands {
 #[tokio::main]
 async fn main() {
     let cli = Cli::parse();
-    let client = RemoteClient::new(&cli.url);
+    let config = RemoteClientConfig {
+        request_timeout: Duration::from_secs(cli.timeout),
+        ..Default::default()
+    };
+    let client = match RemoteClient::with_config(&cli.url, config) {
+        Ok(client) => client,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            std::process::exit(1);
+        }
+    };
 
     let result = match cli.command {
-        Commands::Query { cypher, graph, readonly } => {
-            run_query(&client, &graph, &cypher, readonly, &cli.format).await
+        Commands::Query { cypher, graph, readonly, params } => {
+            let params: HashMap<String, PropertyValue> = params.into_iter().collect();
+            run_query(&client, &graph, &cypher, &params, readonly, &cli.format).await
         }
         Commands::Status => run_status(&client, &cli.format).await,
+        Commands::Stats { graph } => run_stats(&client, &graph, &cli.format).await,
         Commands::Ping => run_ping(&client).await,
+        Commands::Load { nodes, edges, graph, batch_size, id_property } => {
+            let options = LoadOptions { graph, batch_size, id_property };
+            run_load(&client, nodes.as_deref(), edges.as_deref(), &options).await
+        }
+        Commands::Dump { graph, out } => run_dump(&client, &graph, &out).await,
+        Commands::Restore { graph, input } => run_restore(&client, &graph, &input).await,
         Commands::Shell { graph } => run_shell(&client, &graph, &cli.format).await,
     };
 
@@ -79,11 +162,24 @@ async fn run_query(
     client: &RemoteClient,
     graph: &str,
     cypher: &str,
+    params: &HashMap<String, PropertyValue>,
     readonly: bool,
     format: &OutputFormat,
 ) -> Result<(), Box<dyn std::error::Error>> {
+    let missing = params::missing_params(cypher, params);
+    if !missing.is_empty() {
+        let names: Vec<String> = missing.iter().map(|name| format!("${}", name)).collect();
+        return Err(format!(
+            "no value supplied for {} (pass --param {}=<value>)",
+            names.join(", "),
+            missing[0]
+        ).into());
+    }
+
     let result = if readonly {
-        client.query_readonly(graph, cypher).await?
+        client.query_readonly_with_params(graph, cypher, params).await?
+    } else if !params.is_empty() {
+        client.query_with_params(graph, cypher, params).await?
     } else {
         client.query(graph, cypher).await?
     };
@@ -145,63 +241,320 @@ async fn run_status(
     Ok(())
 }
 
+async fn run_stats(
+    client: &RemoteClient,
+    graph: &str,
+    format: &OutputFormat,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let stats = client.stats(graph).await?;
+
+    match format {
+        OutputFormat::Json => {
+            println!("{}", serde_json::to_string_pretty(&stats)?);
+        }
+        OutputFormat::Csv => {
+            println!("kind,name,count");
+            for entry in &stats.labels {
+                println!("label,{},{}", entry.name, entry.count);
+            }
+            for entry in &stats.edge_types {
+                println!("edge_type,{},{}", entry.name, entry.count);
+            }
+        }
+        OutputFormat::Table => {
+            println!("Nodes: {}", stats.nodes);
+            println!("Edges: {}", stats.edges);
+
+            let mut table = Table::new();
+            table.set_content_arrangement(ContentArrangement::Dynamic);
+            table.set_header(vec!["Label", "Nodes"]);
+            for entry in &stats.labels {
+                table.add_row(vec![entry.name.clone(), entry.count.to_string()]);
+            }
+            println!("{}", table);
+
+            let mut table = Table::new();
+            table.set_content_arrangement(ContentArrangement::Dynamic);
+            table.set_header(vec!["Edge type", "Edges"]);
+            for entry in &stats.edge_types {
+                table.add_row(vec![entry.name.clone(), entry.count.to_string()]);
+            }
+            println!("{}", table);
+
+            if !stats.indexes.is_empty() || !stats.vector_indexes.is_empty() {
+                let mut table = Table::new();
+                table.set_content_arrangement(ContentArrangement::Dynamic);
+                table.set_header(vec!["Index", "Label", "Property"]);
+                for index in &stats.indexes {
+                    table.add_row(vec!["property".to_string(), index.label.clone(), index.property.clone()]);
+                }
+                for index in &stats.vector_indexes {
+                    table.add_row(vec!["vector".to_string(), index.label.clone(), index.property.clone()]);
+                }
+                println!("{}", table);
+            }
+        }
+    }
+
+    Ok(())
+}
+
 async fn run_ping(client: &RemoteClient) -> Result<(), Box<dyn std::error::Error>> {
     let result = client.ping().await?;
     println!("{}", result);
     Ok(())
 }
 
+/// Maximum skipped rows listed individually
+const MAX_SKIPPED_SHOWN: usize = 20;
+
+async fn run_load(
+    client: &RemoteClient,
+    nodes: Option<&Path>,
+    edges: Option<&Path>,
+    options: &LoadOptions,
+) -> Result<(), Box<dyn std::error::Error>> {
+    if nodes.is_none() && edges.is_none() {
+        return Err("nothing to load: pass --nodes and/or --edges".into());
+    }
+
+    let report = load::load(client, nodes, edges, options).await?;
+
+    println!("Nodes loaded: {}", report.nodes_loaded);
+    println!("Edges loaded: {}", report.edges_loaded);
+    println!("Rows skipped: {}", report.skipped.len());
+    for skipped in report.skipped.iter().take(MAX_SKIPPED_SHOWN) {
+        eprintln!("  {}", skipped);
+    }
+    if report.skipped.len() > MAX_SKIPPED_SHOWN {
+        eprintln!("  ... and {} more", report.skipped.len() - MAX_SKIPPED_SHOWN);
+    }
+
+    Ok(())
+}
+
+async fn run_dump(client: &RemoteClient, graph: &str, out: &Path) -> Result<(), Box<dyn std::error::Error>> {
+    let mut progress = Progress::new("Downloaded");
+    let bytes = client.export_snapshot(graph, out, |n| progress.update(n)).await?;
+    progress.finish(bytes);
+
+    let status = client.status().await?;
+    println!(
+        "Wrote {} ({} nodes, {} edges)",
+        out.display(), status.storage.nodes, status.storage.edges
+    );
+    Ok(())
+}
+
+async fn run_restore(client: &RemoteClient, graph: &str, input: &Path) -> Result<(), Box<dyn std::error::Error>> {
+    let total = std::fs::metadata(input)?.len();
+    let mut progress = Progress::new("Uploaded");
+    let summary = client.import_snapshot(graph, input, move |n| progress.update(n)).await?;
+    eprintln!("\rUploaded {}", format_bytes(total));
+
+    println!(
+        "Restored {} ({} nodes, {} edges, {} merged)",
+        input.display(), summary.nodes_imported, summary.edges_imported, summary.nodes_merged
+    );
+    Ok(())
+}
+
+/// Single-line byte counter on stderr, redrawn at most once per MiB
+struct Progress {
+    verb: &'static str,
+    next_redraw: u64,
+}
+
+impl Progress {
+    const STEP: u64 = 1 << 20;
+
+    fn new(verb: &'static str) -> Self {
+        Self { verb, next_redraw: 0 }
+    }
+
+    fn update(&mut self, bytes: u64) {
+        if bytes >= self.next_redraw {
+            eprint!("\r{} {}", self.verb, format_bytes(bytes));
+            self.next_redraw = bytes + Self::STEP;
+        }
+    }
+
+    fn finish(&self, bytes: u64) {
+        eprintln!("\r{} {}", self.verb, format_bytes(bytes));
+    }
+}
+
+fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{} B", bytes)
+    } else {
+        format!("{:.1} {}", value, UNITS[unit])
+    }
+}
+
+/// Number of statements kept in the shell history file.
+const HISTORY_SIZE: usize = 1000;
+
+/// Location of the persistent shell history: `$SAMYAMA_HISTORY`, falling back
+/// to `~/.samyama_history`.
+fn history_path() -> Option<PathBuf> {
+    if let Some(path) = std::env::var_os("SAMYAMA_HISTORY") {
+        return Some(PathBuf::from(path));
+    }
+    std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".samyama_history"))
+}
+
+/// Whether the buffered shell input forms a complete statement.
+///
+/// A line ending in `\` always continues, a trailing `;` always terminates,
+/// and otherwise the statement is complete once every bracket and string
+/// literal opened so far has been closed.
+fn is_complete(buffer: &str) -> bool {
+    let trimmed = buffer.trim_end();
+    if trimmed.ends_with('\\') {
+        return false;
+    }
+    if trimmed.ends_with(';') {
+        return true;
+    }
+
+    let mut depth = 0i32;
+    let mut quote: Option<char> = None;
+    for c in trimmed.chars() {
+        match quote {
+            Some(q) if c == q => quote = None,
+            Some(_) => {}
+            None => match c {
+                '\'' | '"' | '`' => quote = Some(c),
+                '(' | '[' | '{' => depth += 1,
+                ')' | ']' | '}' => depth -= 1,
+                _ => {}
+            },
+        }
+    }
+    quote.is_none() && depth <= 0
+}
+
+/// Joins buffered shell lines into a single statement, dropping `\`
+/// continuation markers and the terminating `;`.
+fn statement(buffer: &str) -> String {
+    let joined = buffer
+        .lines()
+        .map(|line| {
+            let line = line.trim_end();
+            line.strip_suffix('\\').unwrap_or(line).trim_end()
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    joined.trim().trim_end_matches(';').trim_end().to_string()
+}
+
 async fn run_shell(
     client: &RemoteClient,
     graph: &str,
     format: &OutputFormat,
 ) -> Result<(), Box<dyn std::error::Error>> {
     println!("Samyama Interactive Shell (graph: {})", graph);
-    println!("Type Cypher queries, or :help for commands. :quit to exit.\n");
+    println!("Type Cypher queries, or :help for commands. :quit to exit.");
+    println!("End a line with \\ to continue it, or ; to run a multi-line query.\n");
+
+    let config = rustyline::Config::builder()
+        .max_history_size(HISTORY_SIZE)?
+        .history_ignore_dups(true)?
+        .history_ignore_space(true)
+        .build();
+    let mut editor = rustyline::DefaultEditor::with_config(config)?;
+    let history = history_path();
+    if let Some(path) = &history {
+        // A missing history file just means this is the first session.
+        let _ = editor.load_history(path);
+    }
 
-    let stdin = std::io::stdin();
-    let mut line = String::new();
+    let mut buffer = String::new();
 
     loop {
-        eprint!("samyama> ");
+        let prompt = if buffer.is_empty() { "samyama> " } else { "      -> " };
+        let line = match editor.readline(prompt) {
+            Ok(line) => line,
+            Err(ReadlineError::Interrupted) => {
+                // Ctrl-C abandons the statement being composed.
+                buffer.clear();
+                continue;
+            }
+            Err(ReadlineError::Eof) => break,
+            Err(e) => return Err(e.into()),
+        };
+
+        if buffer.is_empty() && line.trim().is_empty() {
+            continue;
+        }
+        if !buffer.is_empty() {
+            buffer.push('\n');
+        }
+        buffer.push_str(&line);
 
-        line.clear();
-        if stdin.read_line(&mut line)? == 0 {
-            break; // EOF
+        // Meta-commands are always single-line.
+        let meta = buffer.trim_start().starts_with(':');
+        if !meta && !is_complete(&buffer) {
+            continue;
         }
 
-        let trimmed = line.trim();
-        if trimmed.is_empty() {
+        let input = if meta { buffer.trim().to_string() } else { statement(&buffer) };
+        buffer.clear();
+        if input.is_empty() {
             continue;
         }
+        let _ = editor.add_history_entry(input.as_str());
 
-        match trimmed {
+        match input.as_str() {
             ":quit" | ":exit" | ":q" => break,
             ":help" | ":h" => {
                 println!("Commands:");
                 println!("  :status   — Show server status");
+                println!("  :stats    — Show label and edge type counts");
                 println!("  :ping     — Ping server");
                 println!("  :quit     — Exit shell");
                 println!("  <cypher>  — Execute a Cypher query");
+                println!();
+                println!("End a line with \\ to continue on the next line; a trailing ;");
+                println!("ends a statement. Up/down arrows recall previous statements.");
             }
             ":status" => {
                 if let Err(e) = run_status(client, format).await {
                     eprintln!("Error: {}", e);
                 }
             }
+            ":stats" => {
+                if let Err(e) = run_stats(client, graph, format).await {
+                    eprintln!("Error: {}", e);
+                }
+            }
             ":ping" => {
                 if let Err(e) = run_ping(client).await {
                     eprintln!("Error: {}", e);
                 }
             }
             cypher => {
-                if let Err(e) = run_query(client, graph, cypher, false, format).await {
+                if let Err(e) = run_query(client, graph, cypher, &HashMap::new(), false, format).await {
                     eprintln!("Error: {}", e);
                 }
             }
         }
     }
 
+    if let Some(path) = &history {
+        if let Err(e) = editor.save_history(path) {
+            eprintln!("Warning: could not save history to {}: {}", path.display(), e);
+        }
+    }
+
     println!("Bye!");
     Ok(())
 }
@@ -246,3 +599,34 @@ fn format_csv_value(v: &serde_json::Value) -> String {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(3 << 30), "3.0 GiB");
+    }
+
+    #[test]
+    fn test_shell_statement_completion() {
+        assert!(is_complete("MATCH (n) RETURN n"));
+        assert!(!is_complete("MATCH (n) \\"));
+        assert!(!is_complete("MATCH (n {name: 'a"));
+        assert!(!is_complete("CREATE (n:Person {"));
+        assert!(is_complete("CREATE (n:Person {\nname: 'Alice'})"));
+        assert!(is_complete("MATCH (n) WHERE n.name = '(' RETURN n;"));
+    }
+
+    #[test]
+    fn test_shell_statement_joins_lines() {
+        assert_eq!(
+            statement("MATCH (n) \\\nWITH n \\\nRETURN n;"),
+            "MATCH (n)\nWITH n\nRETURN n"
+        );
+        assert_eq!(statement("  RETURN 1 ;  "), "RETURN 1");
+    }
+}
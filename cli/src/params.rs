@@ -0,0 +1,118 @@
+//! Query parameters supplied on the command line as `--param key=value`
+
+use samyama_sdk::PropertyValue;
+use std::collections::HashMap;
+
+/// Parse one `key=value` argument, inferring the value's type
+pub fn parse_param(arg: &str) -> Result<(String, PropertyValue), String> {
+    let (key, raw) = arg
+        .split_once('=')
+        .ok_or_else(|| format!("expected key=value, got '{}'", arg))?;
+    let key = key.trim().strip_prefix('$').unwrap_or(key.trim());
+    if !is_identifier(key) {
+        return Err(format!("invalid parameter name '{}'", key));
+    }
+    Ok((key.to_string(), infer_value(raw)))
+}
+
+/// `true`/`false` become booleans and numbers become integers or floats;
+/// anything else is a string
+///
+/// Numbers with a leading zero (e.g. zip codes) are kept as strings, and a
+/// value wrapped in single or double quotes is always a string.
+fn infer_value(raw: &str) -> PropertyValue {
+    for quote in ['\'', '"'] {
+        if raw.len() >= 2 && raw.starts_with(quote) && raw.ends_with(quote) {
+            return PropertyValue::String(raw[1..raw.len() - 1].to_string());
+        }
+    }
+    match raw {
+        "true" => return PropertyValue::Boolean(true),
+        "false" => return PropertyValue::Boolean(false),
+        _ => {}
+    }
+
+    let digits = raw.strip_prefix('-').unwrap_or(raw);
+    let leading_zero = digits.len() > 1 && digits.starts_with('0') && !digits.starts_with("0.");
+    if !leading_zero {
+        if let Ok(i) = raw.parse::<i64>() {
+            return PropertyValue::Integer(i);
+        }
+        if let Ok(f) = raw.parse::<f64>() {
+            if f.is_finite() && raw.bytes().any(|b| b.is_ascii_digit()) {
+                return PropertyValue::Float(f);
+            }
+        }
+    }
+    PropertyValue::String(raw.to_string())
+}
+
+/// Names of the `$param` references in `cypher` that have no supplied value,
+/// in order of first appearance
+///
+/// References inside string literals are ignored.
+pub fn missing_params(cypher: &str, params: &HashMap<String, PropertyValue>) -> Vec<String> {
+    let mut missing: Vec<String> = Vec::new();
+    let mut quote: Option<char> = None;
+    let mut chars = cypher.char_indices().peekable();
+
+    while let Some((i, c)) = chars.next() {
+        match quote {
+            Some(q) if c == q => quote = None,
+            Some(_) => {}
+            None if c == '\'' || c == '"' || c == '`' => quote = Some(c),
+            None if c == '$' => {
+                let start = i + 1;
+                let mut end = start;
+                while let Some(&(j, n)) = chars.peek() {
+                    if !(n.is_ascii_alphanumeric() || n == '_') {
+                        break;
+                    }
+                    end = j + n.len_utf8();
+                    chars.next();
+                }
+                let name = &cypher[start..end];
+                if !name.is_empty() && !params.contains_key(name) && !missing.iter().any(|m| m == name) {
+                    missing.push(name.to_string());
+                }
+            }
+            None => {}
+        }
+    }
+    missing
+}
+
+fn is_identifier(s: &str) -> bool {
+    let mut chars = s.chars();
+    matches!(chars.next(), Some(c) if c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_param_infers_types() {
+        assert_eq!(parse_param("min=30").unwrap(), ("min".to_string(), PropertyValue::Integer(30)));
+        assert_eq!(parse_param("ratio=-0.5").unwrap().1, PropertyValue::Float(-0.5));
+        assert_eq!(parse_param("active=true").unwrap().1, PropertyValue::Boolean(true));
+        assert_eq!(parse_param("name=Alice Smith").unwrap().1, PropertyValue::String("Alice Smith".to_string()));
+        assert_eq!(parse_param("zip=02139").unwrap().1, PropertyValue::String("02139".to_string()));
+        assert_eq!(parse_param("id='42'").unwrap().1, PropertyValue::String("42".to_string()));
+        assert_eq!(parse_param("expr=a=b").unwrap().1, PropertyValue::String("a=b".to_string()));
+        assert_eq!(parse_param("$min=1").unwrap().0, "min");
+        assert!(parse_param("min").is_err());
+        assert!(parse_param("1x=2").is_err());
+    }
+
+    #[test]
+    fn test_missing_params() {
+        let mut params = HashMap::new();
+        params.insert("min".to_string(), PropertyValue::Integer(30));
+        let cypher = "MATCH (n) WHERE n.age > $min AND n.name = $name AND n.tag <> '$literal' RETURN $name";
+        assert_eq!(missing_params(cypher, &params), vec!["name".to_string()]);
+        params.insert("name".to_string(), PropertyValue::String("Alice".to_string()));
+        assert!(missing_params(cypher, &params).is_empty());
+    }
+}
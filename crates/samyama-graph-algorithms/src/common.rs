@@ -7,7 +7,18 @@ use std::collections::HashMap;
 /// Node Identifier type (u64)
 pub type NodeId = u64;
 
+/// Whether a view follows edge direction or treats every edge as two-way
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Directedness {
+    /// Edges are followed from source to target only
+    #[default]
+    Directed,
+    /// Every edge is traversable in both directions
+    Undirected,
+}
+
 /// A dense, integer-indexed view of the graph topology using Compressed Sparse Row (CSR) format.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GraphView {
     /// Number of nodes
     pub node_count: usize,
@@ -66,6 +77,67 @@ impl GraphView {
         })
     }
 
+    /// Write this view to `path` (bincode of the CSR arrays and id mappings)
+    ///
+    /// Lets a view built once for a (label, edge type) slice be reloaded by
+    /// later runs. The file is not tied to the store it came from, so the
+    /// caller decides when it is stale.
+    #[cfg(feature = "serde")]
+    pub fn save(&self, path: impl AsRef<std::path::Path>) -> std::io::Result<()> {
+        let mut writer = std::io::BufWriter::new(std::fs::File::create(path)?);
+        bincode::serialize_into(&mut writer, self)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+        std::io::Write::flush(&mut writer)
+    }
+
+    /// Read a view written by [`save`](Self::save)
+    #[cfg(feature = "serde")]
+    pub fn load(path: impl AsRef<std::path::Path>) -> std::io::Result<Self> {
+        let reader = std::io::BufReader::new(std::fs::File::open(path)?);
+        bincode::deserialize_from(reader)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))
+    }
+
+    /// Return a symmetrized copy of this view
+    ///
+    /// Every edge u→v also appears as v→u, so successors and predecessors are
+    /// the same set. Parallel edges collapse to one, keeping the smallest weight,
+    /// which lets direction-following algorithms (SCC, PageRank, betweenness,
+    /// shortest paths) compute their undirected variants.
+    pub fn to_undirected(&self) -> GraphView {
+        let n = self.node_count;
+        let mut adjacency: Vec<Vec<(usize, f64)>> = vec![Vec::new(); n];
+        for u in 0..n {
+            let weights = self.weights(u);
+            for (i, &v) in self.successors(u).iter().enumerate() {
+                let w = weights.map_or(1.0, |w| w[i]);
+                adjacency[u].push((v, w));
+                if u != v {
+                    adjacency[v].push((u, w));
+                }
+            }
+        }
+
+        let mut neighbors = Vec::with_capacity(n);
+        let mut edge_weights = Vec::with_capacity(n);
+        for mut adj in adjacency {
+            adj.sort_by(|a, b| a.0.cmp(&b.0).then(a.1.total_cmp(&b.1)));
+            adj.dedup_by_key(|(v, _)| *v);
+            let (vs, ws): (Vec<usize>, Vec<f64>) = adj.into_iter().unzip();
+            neighbors.push(vs);
+            edge_weights.push(ws);
+        }
+
+        GraphView::from_adjacency_list(
+            n,
+            self.index_to_node.clone(),
+            self.node_to_index.clone(),
+            neighbors.clone(),
+            neighbors,
+            self.weights.as_ref().map(|_| edge_weights),
+        )
+    }
+
     /// Helper to create GraphView from adjacency lists (legacy/test support)
     pub fn from_adjacency_list(
         node_count: usize,
@@ -111,3 +183,33 @@ impl GraphView {
         }
     }
 }
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_save_load_round_trip() {
+        let index_to_node: Vec<NodeId> = vec![10, 20, 30];
+        let node_to_index = index_to_node.iter().enumerate().map(|(i, &id)| (id, i)).collect();
+        let outgoing = vec![vec![1, 2], vec![2], vec![]];
+        let incoming = vec![vec![], vec![0], vec![0, 1]];
+        let weights = vec![vec![1.5, 2.0], vec![0.25], vec![]];
+        let view = GraphView::from_adjacency_list(3, index_to_node, node_to_index, outgoing, incoming, Some(weights));
+
+        let path = std::env::temp_dir().join(format!("samyama_view_{}.bin", std::process::id()));
+        view.save(&path).unwrap();
+        let loaded = GraphView::load(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(loaded.node_count, 3);
+        assert_eq!(loaded.index_to_node, view.index_to_node);
+        assert_eq!(loaded.node_to_index, view.node_to_index);
+        for idx in 0..3 {
+            assert_eq!(loaded.successors(idx), view.successors(idx));
+            assert_eq!(loaded.predecessors(idx), view.predecessors(idx));
+            assert_eq!(loaded.weights(idx), view.weights(idx));
+        }
+        assert!(GraphView::load(std::env::temp_dir().join("samyama_view_missing.bin")).is_err());
+    }
+}
@@ -1,6 +1,7 @@
 //! Community detection algorithms
 //!
-//! Implements REQ-ALGO-004 (Weakly Connected Components)
+//! Implements REQ-ALGO-004 (Weakly Connected Components), strongly connected
+//! components and Louvain modularity clustering
 
 use super::common::{GraphView, NodeId};
 use std::collections::HashMap;
@@ -159,6 +160,214 @@ pub fn strongly_connected_components(view: &GraphView) -> SccResult {
     }
 }
 
+/// Result of Louvain community detection
+#[derive(Debug, Clone)]
+pub struct LouvainResult {
+    /// Map of Community ID -> List of NodeIds
+    pub communities: HashMap<usize, Vec<NodeId>>,
+    /// Map of NodeId -> Community ID
+    pub node_community: HashMap<NodeId, usize>,
+    /// Modularity of the final partition, in [-0.5, 1]
+    pub modularity: f64,
+}
+
+/// Undirected weighted graph of one Louvain level
+struct LouvainLevel {
+    /// Weights to other nodes (symmetric)
+    adjacency: Vec<Vec<(usize, f64)>>,
+    /// Self-loop weight, i.e. edges internal to an aggregated node
+    loops: Vec<f64>,
+}
+
+impl LouvainLevel {
+    fn from_view(view: &GraphView) -> Self {
+        let n = view.node_count;
+        let mut neighbours: Vec<HashMap<usize, f64>> = vec![HashMap::new(); n];
+        let mut loops = vec![0.0; n];
+        for u in 0..n {
+            let weights = view.weights(u);
+            for (i, &v) in view.successors(u).iter().enumerate() {
+                let w = weights.map_or(1.0, |w| w[i]);
+                if u == v {
+                    loops[u] += w;
+                } else {
+                    *neighbours[u].entry(v).or_insert(0.0) += w;
+                    *neighbours[v].entry(u).or_insert(0.0) += w;
+                }
+            }
+        }
+        Self::from_maps(neighbours, loops)
+    }
+
+    fn from_maps(neighbours: Vec<HashMap<usize, f64>>, loops: Vec<f64>) -> Self {
+        let adjacency = neighbours
+            .into_iter()
+            .map(|m| {
+                let mut row: Vec<(usize, f64)> = m.into_iter().collect();
+                row.sort_by_key(|&(v, _)| v);
+                row
+            })
+            .collect();
+        LouvainLevel { adjacency, loops }
+    }
+
+    fn len(&self) -> usize {
+        self.loops.len()
+    }
+
+    /// Weighted degree; a self-loop counts twice
+    fn degree(&self, u: usize) -> f64 {
+        self.adjacency[u].iter().map(|&(_, w)| w).sum::<f64>() + 2.0 * self.loops[u]
+    }
+
+    /// Local moving phase: greedily move nodes to the neighbouring community
+    /// with the largest modularity gain until no move helps. Returns the
+    /// community of each node and whether anything moved.
+    fn local_moves(&self, total_weight: f64) -> (Vec<usize>, bool) {
+        let n = self.len();
+        let degree: Vec<f64> = (0..n).map(|u| self.degree(u)).collect();
+        let mut community: Vec<usize> = (0..n).collect();
+        let mut community_degree = degree.clone();
+
+        // Dense scratch buffer of weights to neighbouring communities, plus the
+        // communities touched, so candidates are visited in a deterministic order
+        let mut weight_to = vec![0.0; n];
+        let mut touched = Vec::new();
+
+        let mut moved_any = false;
+        for _ in 0..100 {
+            let mut moved = false;
+            for u in 0..n {
+                let current = community[u];
+                community_degree[current] -= degree[u];
+
+                for &(v, w) in &self.adjacency[u] {
+                    let c = community[v];
+                    if weight_to[c] == 0.0 {
+                        touched.push(c);
+                    }
+                    weight_to[c] += w;
+                }
+
+                let gain = |c: usize, weight_to: &[f64]| {
+                    weight_to[c] - community_degree[c] * degree[u] / total_weight
+                };
+                let mut best = current;
+                let mut best_gain = gain(current, &weight_to);
+                for &c in &touched {
+                    let g = gain(c, &weight_to);
+                    if g > best_gain + 1e-12 {
+                        best = c;
+                        best_gain = g;
+                    }
+                }
+
+                for c in touched.drain(..) {
+                    weight_to[c] = 0.0;
+                }
+
+                community_degree[best] += degree[u];
+                if best != current {
+                    community[u] = best;
+                    moved = true;
+                    moved_any = true;
+                }
+            }
+            if !moved {
+                break;
+            }
+        }
+        (community, moved_any)
+    }
+
+    /// Collapse each community into a single node
+    fn aggregate(&self, community: &[usize], count: usize) -> Self {
+        let mut neighbours: Vec<HashMap<usize, f64>> = vec![HashMap::new(); count];
+        let mut loops = vec![0.0; count];
+        for u in 0..self.len() {
+            let cu = community[u];
+            loops[cu] += self.loops[u];
+            for &(v, w) in &self.adjacency[u] {
+                let cv = community[v];
+                if cu == cv {
+                    // Each internal edge is seen from both ends
+                    loops[cu] += w / 2.0;
+                } else {
+                    *neighbours[cu].entry(cv).or_insert(0.0) += w;
+                }
+            }
+        }
+        Self::from_maps(neighbours, loops)
+    }
+}
+
+/// Louvain community detection
+///
+/// Maximises modularity by alternating local node moves with aggregation of
+/// communities into single nodes (Blondel et al., 2008). Edge direction is
+/// ignored; edges use `GraphView.weights` when present, otherwise weight 1.
+/// Community IDs are dense, numbered from 0.
+pub fn louvain(view: &GraphView) -> LouvainResult {
+    let n = view.node_count;
+    let mut level = LouvainLevel::from_view(view);
+    let total_weight: f64 = (0..n).map(|u| level.degree(u)).sum();
+
+    // Community of each original node, refined level by level
+    let mut membership: Vec<usize> = (0..n).collect();
+    if total_weight > 0.0 {
+        loop {
+            let (community, moved) = level.local_moves(total_weight);
+            if !moved {
+                break;
+            }
+            // Renumber densely in order of first appearance
+            let mut renumber = HashMap::new();
+            let community: Vec<usize> = community
+                .into_iter()
+                .map(|c| {
+                    let next = renumber.len();
+                    *renumber.entry(c).or_insert(next)
+                })
+                .collect();
+            // Nodes that moved and moved back leave every node alone: no progress
+            if renumber.len() == level.len() {
+                break;
+            }
+            for m in membership.iter_mut() {
+                *m = community[*m];
+            }
+            level = level.aggregate(&community, renumber.len());
+        }
+    }
+
+    // Modularity of the final partition: each final node is one community
+    let modularity = if total_weight > 0.0 {
+        (0..level.len())
+            .map(|c| {
+                let internal = 2.0 * level.loops[c] / total_weight;
+                let share = level.degree(c) / total_weight;
+                internal - share * share
+            })
+            .sum()
+    } else {
+        0.0
+    };
+
+    let mut communities = HashMap::new();
+    let mut node_community = HashMap::new();
+    for (i, &c) in membership.iter().enumerate() {
+        let node_id = view.index_to_node[i];
+        communities.entry(c).or_insert_with(Vec::new).push(node_id);
+        node_community.insert(node_id, c);
+    }
+
+    LouvainResult {
+        communities,
+        node_community,
+        modularity,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -203,4 +412,82 @@ mod tests {
         assert_eq!(c2, c3);
         assert_ne!(c1, c4);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_undirected_view_of_directed_chain() {
+        // 1 -> 2 -> 3 -> 4: four SCCs when directed, one when symmetrized
+        let index_to_node: Vec<NodeId> = vec![1, 2, 3, 4];
+        let node_to_index = index_to_node.iter().enumerate().map(|(i, &id)| (id, i)).collect();
+        let outgoing = vec![vec![1], vec![2], vec![3], vec![]];
+        let incoming = vec![vec![], vec![0], vec![1], vec![2]];
+        let view = GraphView::from_adjacency_list(4, index_to_node, node_to_index, outgoing, incoming, None);
+        assert_eq!(strongly_connected_components(&view).components.len(), 4);
+
+        let undirected = view.to_undirected();
+        assert_eq!(undirected.successors(1), &[0, 2]);
+        assert_eq!(undirected.predecessors(1), &[0, 2]);
+        assert_eq!(weakly_connected_components(&undirected).components.len(), 1);
+        assert_eq!(strongly_connected_components(&undirected).components.len(), 1);
+    }
+
+    fn undirected_view(node_count: usize, edges: &[(usize, usize, f64)]) -> GraphView {
+        let index_to_node: Vec<NodeId> = (1..=node_count as u64).collect();
+        let node_to_index = index_to_node.iter().enumerate().map(|(i, &id)| (id, i)).collect();
+        let mut outgoing = vec![vec![]; node_count];
+        let mut incoming = vec![vec![]; node_count];
+        let mut weights = vec![vec![]; node_count];
+        for &(u, v, w) in edges {
+            outgoing[u].push(v);
+            incoming[v].push(u);
+            weights[u].push(w);
+        }
+        GraphView::from_adjacency_list(node_count, index_to_node, node_to_index, outgoing, incoming, Some(weights))
+    }
+
+    #[test]
+    fn test_louvain_two_cliques() {
+        // Two 4-cliques {1,2,3,4} and {5,6,7,8} joined by the bridge 4-5
+        let mut edges = Vec::new();
+        for offset in [0, 4] {
+            for i in 0..4 {
+                for j in i + 1..4 {
+                    edges.push((offset + i, offset + j, 1.0));
+                }
+            }
+        }
+        edges.push((3, 4, 1.0));
+        let view = undirected_view(8, &edges);
+
+        let result = louvain(&view);
+        assert_eq!(result.communities.len(), 2);
+        for clique in [[1, 2, 3, 4], [5, 6, 7, 8]] {
+            let c = result.node_community[&clique[0]];
+            assert!(clique.iter().all(|n| result.node_community[n] == c));
+        }
+        assert_ne!(result.node_community[&1], result.node_community[&5]);
+
+        // Q = 2 * (6/13 - (13/26)^2)
+        let expected = 2.0 * (6.0 / 13.0 - 0.25);
+        assert!((result.modularity - expected).abs() < 1e-9, "modularity {}", result.modularity);
+    }
+
+    #[test]
+    fn test_louvain_uses_weights() {
+        // Square 1-2-3-4-1 with heavy edges 1-2 and 3-4
+        let view = undirected_view(4, &[(0, 1, 10.0), (1, 2, 1.0), (2, 3, 10.0), (3, 0, 1.0)]);
+        let result = louvain(&view);
+        assert_eq!(result.communities.len(), 2);
+        assert_eq!(result.node_community[&1], result.node_community[&2]);
+        assert_eq!(result.node_community[&3], result.node_community[&4]);
+        assert_ne!(result.node_community[&1], result.node_community[&3]);
+        assert!(result.modularity > 0.0);
+    }
+
+    #[test]
+    fn test_louvain_edgeless_graph() {
+        let view = undirected_view(3, &[]);
+        let result = louvain(&view);
+        assert_eq!(result.communities.len(), 3);
+        assert_eq!(result.modularity, 0.0);
+    }
+}
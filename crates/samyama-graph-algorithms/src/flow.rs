@@ -7,12 +7,21 @@ use std::collections::{HashMap, VecDeque};
 
 pub struct FlowResult {
     pub max_flow: f64,
+    /// Edges of a minimum cut as (source, target, capacity); capacities sum to `max_flow`
+    pub cut_edges: Vec<(NodeId, NodeId, f64)>,
+    /// Nodes still reachable from the source in the final residual graph
+    pub source_side: Vec<NodeId>,
+    /// All other nodes, including the sink
+    pub sink_side: Vec<NodeId>,
 }
 
 /// Edmonds-Karp Algorithm for Max Flow
 ///
 /// Assumes `view.weights` represents capacity.
 /// If weights are missing, assumes capacity 1.0.
+/// Also reports the minimum cut separating the residual-reachable side of the
+/// source from the rest; its edges are the bottleneck links. Parallel edges
+/// between the same pair are merged, so a cut edge carries their total capacity.
 pub fn edmonds_karp(view: &GraphView, source: NodeId, sink: NodeId) -> Option<FlowResult> {
     let s_idx = *view.node_to_index.get(&source)?;
     let t_idx = *view.node_to_index.get(&sink)?;
@@ -39,7 +48,9 @@ pub fn edmonds_karp(view: &GraphView, source: NodeId, sink: NodeId) -> Option<Fl
         }
     }
 
+    let capacity = residual.clone();
     let mut total_flow = 0.0;
+    let mut visited;
 
     loop {
         // Find path using BFS
@@ -53,7 +64,7 @@ pub fn edmonds_karp(view: &GraphView, source: NodeId, sink: NodeId) -> Option<Fl
         
         // Special marker for source parent to distinguish from unvisited
         // Actually, just use a visited bitset or map
-        let mut visited = vec![false; n];
+        visited = vec![false; n];
         visited[s_idx] = true;
 
         while let Some(u) = queue.pop_front() {
@@ -101,7 +112,21 @@ pub fn edmonds_karp(view: &GraphView, source: NodeId, sink: NodeId) -> Option<Fl
         total_flow += path_flow;
     }
 
-    Some(FlowResult { max_flow: total_flow })
+    // The last BFS exhausted everything reachable from the source: that is the
+    // source side of a minimum cut, and saturated edges leaving it form the cut
+    let mut cut_edges = Vec::new();
+    for u in (0..n).filter(|&u| visited[u]) {
+        for (&v, &cap) in &capacity[u] {
+            if !visited[v] && cap > 0.0 {
+                cut_edges.push((view.index_to_node[u], view.index_to_node[v], cap));
+            }
+        }
+    }
+    cut_edges.sort_by_key(|&(u, v, _)| (u, v));
+    let (source_side, sink_side): (Vec<NodeId>, Vec<NodeId>) =
+        (0..n).map(|i| view.index_to_node[i]).partition(|id| visited[view.node_to_index[id]]);
+
+    Some(FlowResult { max_flow: total_flow, cut_edges, source_side, sink_side })
 }
 
 #[cfg(test)]
@@ -170,5 +195,35 @@ mod tests {
 
         let result = edmonds_karp(&view, 1, 4).unwrap();
         assert_eq!(result.max_flow, 150.0);
+        // Both edges out of S are saturated, so S alone is the source side
+        assert_eq!(result.cut_edges, vec![(1, 2, 100.0), (1, 3, 50.0)]);
+        assert_eq!(result.source_side, vec![1]);
+        assert_eq!(result.sink_side, vec![2, 3, 4]);
+    }
+
+    #[test]
+    fn test_edmonds_karp_min_cut() {
+        // CLRS flow network: s=1, v1..v4 = 2..5, t=6, max flow 23
+        let edges = [
+            (0, 1, 16.0), (0, 2, 13.0), (2, 1, 4.0), (1, 3, 12.0), (3, 2, 9.0),
+            (2, 4, 14.0), (4, 3, 7.0), (3, 5, 20.0), (4, 5, 4.0),
+        ];
+        let index_to_node: Vec<NodeId> = (1..=6).collect();
+        let node_to_index = index_to_node.iter().enumerate().map(|(i, &id)| (id, i)).collect();
+        let mut outgoing = vec![vec![]; 6];
+        let mut weights = vec![vec![]; 6];
+        for &(u, v, w) in &edges {
+            outgoing[u].push(v);
+            weights[u].push(w);
+        }
+        let view = GraphView::from_adjacency_list(6, index_to_node, node_to_index, outgoing, vec![vec![]; 6], Some(weights));
+
+        let result = edmonds_karp(&view, 1, 6).unwrap();
+        assert_eq!(result.max_flow, 23.0);
+        let cut_capacity: f64 = result.cut_edges.iter().map(|&(_, _, c)| c).sum();
+        assert_eq!(cut_capacity, result.max_flow);
+        assert_eq!(result.cut_edges, vec![(2, 4, 12.0), (5, 4, 7.0), (5, 6, 4.0)]);
+        assert_eq!(result.source_side, vec![1, 2, 3, 5]);
+        assert_eq!(result.sink_side, vec![4, 6]);
     }
 }
@@ -13,13 +13,13 @@ pub mod cdlp;
 pub mod lcc;
 pub mod pca;
 
-pub use common::{GraphView, NodeId};
-pub use pagerank::{page_rank, PageRankConfig};
-pub use community::{weakly_connected_components, WccResult, strongly_connected_components, SccResult};
-pub use pathfinding::{bfs, dijkstra, bfs_all_shortest_paths, PathResult};
+pub use common::{Directedness, GraphView, NodeId};
+pub use pagerank::{page_rank, page_rank_with_stats, PageRankConfig, PageRankResult};
+pub use community::{weakly_connected_components, WccResult, strongly_connected_components, SccResult, louvain, LouvainResult};
+pub use pathfinding::{bfs, dijkstra, dijkstra_path, astar, bfs_all_shortest_paths, PathResult};
 pub use flow::{edmonds_karp, FlowResult};
 pub use mst::{prim_mst, MSTResult};
-pub use topology::count_triangles;
+pub use topology::{count_triangles, betweenness_centrality};
 pub use cdlp::{cdlp, CdlpResult, CdlpConfig};
 pub use lcc::{local_clustering_coefficient, local_clustering_coefficient_directed, LccResult};
 pub use pca::{pca, PcaConfig, PcaResult, PcaSolver};
\ No newline at end of file
@@ -31,6 +31,19 @@ impl Default for PageRankConfig {
     }
 }
 
+/// PageRank scores with convergence information
+#[derive(Debug, Clone)]
+pub struct PageRankResult {
+    /// Mapping from NodeId to score
+    pub scores: HashMap<NodeId, f64>,
+    /// Number of iterations run
+    pub iterations: usize,
+    /// L1 distance between the last two score vectors
+    pub final_delta: f64,
+    /// Whether the delta fell below the tolerance before the iteration cap
+    pub converged: bool,
+}
+
 /// Calculate PageRank for the graph view
 pub fn page_rank(
     view: &GraphView,
@@ -78,6 +91,30 @@ pub fn page_rank(
         }
     }
 
+    page_rank_with_stats(view, config, None).scores
+}
+
+/// Calculate PageRank, reporting how many iterations ran and whether it converged
+///
+/// `on_iteration`, if given, is called after every iteration with the 1-based
+/// iteration number and that iteration's L1 delta. Always runs on the CPU, as the
+/// GPU kernel does not report per-iteration deltas.
+pub fn page_rank_with_stats(
+    view: &GraphView,
+    config: PageRankConfig,
+    mut on_iteration: Option<&mut dyn FnMut(usize, f64)>,
+) -> PageRankResult {
+    let n = view.node_count;
+
+    if n == 0 {
+        return PageRankResult {
+            scores: HashMap::new(),
+            iterations: 0,
+            final_delta: 0.0,
+            converged: true,
+        };
+    }
+
     // 2. Initialize scores
     // LDBC Graphalytics spec: initial score is 1/N
     let initial_score = 1.0 / n as f64;
@@ -92,7 +129,10 @@ pub fn page_rank(
     // Use parallel iteration for graphs with 1000+ nodes
     let use_parallel = n >= 1000;
 
-    for _ in 0..config.iterations {
+    let mut iterations = 0;
+    let mut final_delta = 0.0;
+    let mut converged = false;
+    for iteration in 1..=config.iterations {
         // Compute dangling node mass if enabled
         let dangling_contrib = if config.dangling_redistribution {
             let dangling_sum: f64 = if use_parallel {
@@ -142,8 +182,15 @@ pub fn page_rank(
         // Swap buffers
         scores.copy_from_slice(&next_scores);
 
+        iterations = iteration;
+        final_delta = total_diff;
+        if let Some(callback) = on_iteration.as_mut() {
+            callback(iteration, total_diff);
+        }
+
         // Check convergence
         if total_diff < config.tolerance {
+            converged = true;
             break;
         }
     }
@@ -154,7 +201,12 @@ pub fn page_rank(
         result.insert(view.index_to_node[idx], score);
     }
 
-    result
+    PageRankResult {
+        scores: result,
+        iterations,
+        final_delta,
+        converged,
+    }
 }
 
 #[cfg(test)]
@@ -298,6 +350,42 @@ mod tests {
             "100 iterations should be closer to target than 1: diff_1={}, diff_100={}", diff_1, diff_100);
     }
 
+    #[test]
+    fn test_pagerank_with_stats_reports_convergence() {
+        let view = build_star_graph();
+        let mut deltas = Vec::new();
+        let mut record = |iteration: usize, delta: f64| deltas.push((iteration, delta));
+        let result = page_rank_with_stats(&view, PageRankConfig {
+            damping_factor: 0.85,
+            iterations: 100,
+            tolerance: 1e-6,
+            dangling_redistribution: true,
+        }, Some(&mut record));
+
+        assert!(result.converged);
+        assert!(result.iterations < 100);
+        assert!(result.final_delta < 1e-6);
+        assert_eq!(deltas.len(), result.iterations);
+        assert_eq!(deltas.last().unwrap(), &(result.iterations, result.final_delta));
+        assert_eq!(result.scores, page_rank(&view, PageRankConfig {
+            damping_factor: 0.85,
+            iterations: 100,
+            tolerance: 1e-6,
+            dangling_redistribution: true,
+        }));
+
+        // Hitting the iteration cap is reported as not converged
+        let capped = page_rank_with_stats(&view, PageRankConfig {
+            damping_factor: 0.85,
+            iterations: 2,
+            tolerance: 1e-6,
+            dangling_redistribution: true,
+        }, None);
+        assert!(!capped.converged);
+        assert_eq!(capped.iterations, 2);
+        assert!(capped.final_delta >= 1e-6);
+    }
+
     #[test]
     fn test_pagerank_dangling_redistribution_flag() {
         let view = build_star_graph(); // Nodes 2,3,4 are dangling
@@ -1,6 +1,6 @@
 //! Pathfinding algorithms
 //!
-//! Implements REQ-ALGO-002 (BFS) and REQ-ALGO-003 (Dijkstra)
+//! Implements REQ-ALGO-002 (BFS) and REQ-ALGO-003 (Dijkstra), plus A* search
 
 use super::common::{GraphView, NodeId};
 use std::collections::{HashMap, VecDeque, BinaryHeap};
@@ -88,6 +88,8 @@ impl PartialOrd for State {
 /// Dijkstra's Algorithm (Weighted Shortest Path)
 ///
 /// Uses edge weights from GraphView if available, otherwise assumes 1.0.
+/// Negative-weight edges are skipped. Stops as soon as the target is settled;
+/// `path` holds the node sequence from source to target inclusive.
 pub fn dijkstra(
     view: &GraphView,
     source: NodeId,
@@ -150,6 +152,93 @@ pub fn dijkstra(
     None
 }
 
+/// Shortest weighted route from source to target as (ordered NodeIds, total cost)
+///
+/// `None` when either node is missing from the view or the target is unreachable.
+/// The cost is the sum of the weights of consecutive edges along the path.
+pub fn dijkstra_path(
+    view: &GraphView,
+    source: NodeId,
+    target: NodeId,
+) -> Option<(Vec<NodeId>, f64)> {
+    dijkstra(view, source, target).map(|result| (result.path, result.cost))
+}
+
+/// A* search (Weighted Shortest Path guided by a heuristic)
+///
+/// `heuristic(node)` estimates the remaining cost from `node` to the target, e.g.
+/// the great-circle distance between two ports. The returned path is optimal as
+/// long as the heuristic never overestimates (is admissible); nodes are re-expanded
+/// when a cheaper route to them appears, so consistency is not required. Negative,
+/// NaN or infinite estimates are treated as 0, and a heuristic of 0 everywhere
+/// makes this Dijkstra. Like [`dijkstra`], edge weights default to 1.0 and
+/// negative-weight edges are skipped.
+pub fn astar(
+    view: &GraphView,
+    source: NodeId,
+    target: NodeId,
+    heuristic: impl Fn(NodeId) -> f64,
+) -> Option<PathResult> {
+    let source_idx = *view.node_to_index.get(&source)?;
+    let target_idx = *view.node_to_index.get(&target)?;
+
+    let mut estimates: HashMap<usize, f64> = HashMap::new();
+    let mut estimate = |idx: usize| {
+        *estimates.entry(idx).or_insert_with(|| {
+            let h = heuristic(view.index_to_node[idx]);
+            if h.is_finite() { h.max(0.0) } else { 0.0 }
+        })
+    };
+
+    let mut dist = HashMap::new();
+    let mut parent = HashMap::new();
+    let mut heap = BinaryHeap::new();
+
+    dist.insert(source_idx, 0.0);
+    // State.cost holds the f-score: cost so far plus estimated remainder
+    heap.push(State { cost: estimate(source_idx), node_idx: source_idx });
+
+    while let Some(State { cost: f_score, node_idx }) = heap.pop() {
+        let cost = dist[&node_idx];
+        if f_score > cost + estimate(node_idx) {
+            continue; // stale entry
+        }
+
+        if node_idx == target_idx {
+            let mut path = Vec::new();
+            let mut curr = Some(target_idx);
+            while let Some(idx) = curr {
+                path.push(view.index_to_node[idx]);
+                curr = parent.get(&idx).cloned().flatten();
+            }
+            path.reverse();
+            return Some(PathResult {
+                source,
+                target,
+                path,
+                cost,
+            });
+        }
+
+        let edges = view.successors(node_idx);
+        let weights = view.weights(node_idx);
+
+        for (i, &next_idx) in edges.iter().enumerate() {
+            let weight = weights.map_or(1.0, |w| w[i]);
+            if weight < 0.0 { continue; }
+
+            let next_cost = cost + weight;
+            if next_cost < *dist.get(&next_idx).unwrap_or(&f64::INFINITY) {
+                dist.insert(next_idx, next_cost);
+                parent.insert(next_idx, Some(node_idx));
+                heap.push(State { cost: next_cost + estimate(next_idx), node_idx: next_idx });
+            }
+        }
+    }
+
+    None
+}
+
 /// BFS that returns ALL shortest paths between source and target
 pub fn bfs_all_shortest_paths(
     view: &GraphView,
@@ -315,6 +404,106 @@ mod tests {
         assert_eq!(result.cost, 15.0);
     }
 
+    /// Weight of the cheapest u -> v edge
+    fn edge_weight(view: &GraphView, u: NodeId, v: NodeId) -> f64 {
+        let u_idx = view.node_to_index[&u];
+        let v_idx = view.node_to_index[&v];
+        view.successors(u_idx)
+            .iter()
+            .zip(view.weights(u_idx).unwrap())
+            .filter(|(&next, _)| next == v_idx)
+            .map(|(_, &w)| w)
+            .fold(f64::INFINITY, f64::min)
+    }
+
+    #[test]
+    fn test_dijkstra_path_cost_matches_edges() {
+        // Ports 1..=5; the direct lane 1->5 is dearer than the detour 1->2->4->5
+        let index_to_node = vec![1, 2, 3, 4, 5];
+        let node_to_index: HashMap<NodeId, usize> =
+            index_to_node.iter().enumerate().map(|(i, &id)| (id, i)).collect();
+        let edges = [(0, 4, 20.0), (0, 1, 3.0), (0, 2, 2.0), (1, 3, 4.0), (2, 3, 7.0), (3, 4, 6.5), (2, 4, 18.0)];
+        let mut outgoing = vec![vec![]; 5];
+        let mut incoming = vec![vec![]; 5];
+        let mut weights = vec![vec![]; 5];
+        for &(u, v, w) in &edges {
+            outgoing[u].push(v);
+            incoming[v].push(u);
+            weights[u].push(w);
+        }
+        let view = GraphView::from_adjacency_list(5, index_to_node, node_to_index, outgoing, incoming, Some(weights));
+
+        let (path, cost) = dijkstra_path(&view, 1, 5).unwrap();
+        assert_eq!(path, vec![1, 2, 4, 5]);
+        assert_eq!(cost, 13.5);
+        let summed: f64 = path.windows(2).map(|hop| edge_weight(&view, hop[0], hop[1])).sum();
+        assert_eq!(summed, cost);
+
+        assert_eq!(dijkstra_path(&view, 3, 3), Some((vec![3], 0.0)));
+        assert_eq!(dijkstra_path(&view, 5, 1), None);
+        assert_eq!(dijkstra_path(&view, 1, 99), None);
+    }
+
+    /// 6x6 grid with edges both ways between neighbours, weights 1..=5.
+    /// Node id is `row * 10 + col`, so the heuristic can recover coordinates.
+    fn weighted_grid() -> GraphView {
+        let size = 6;
+        let index_to_node: Vec<NodeId> = (0..size * size).map(|i| (i / size * 10 + i % size) as NodeId).collect();
+        let node_to_index = index_to_node.iter().enumerate().map(|(i, &id)| (id, i)).collect();
+        let mut outgoing = vec![vec![]; size * size];
+        let mut incoming = vec![vec![]; size * size];
+        let mut weights = vec![vec![]; size * size];
+        for row in 0..size {
+            for col in 0..size {
+                let u = row * size + col;
+                let mut neighbours = Vec::new();
+                if col + 1 < size { neighbours.push(u + 1); }
+                if row + 1 < size { neighbours.push(u + size); }
+                for v in neighbours {
+                    let w = 1.0 + ((u * 7 + v * 13) % 5) as f64;
+                    for (a, b) in [(u, v), (v, u)] {
+                        outgoing[a].push(b);
+                        incoming[b].push(a);
+                        weights[a].push(w);
+                    }
+                }
+            }
+        }
+        GraphView::from_adjacency_list(size * size, index_to_node, node_to_index, outgoing, incoming, Some(weights))
+    }
+
+    #[test]
+    fn test_astar_matches_dijkstra_on_grid() {
+        let view = weighted_grid();
+        let target: NodeId = 55;
+        // Manhattan distance times the minimum edge weight never overestimates
+        let manhattan = |node: NodeId| {
+            let (row, col) = ((node / 10) as f64, (node % 10) as f64);
+            (5.0 - row).abs() + (5.0 - col).abs()
+        };
+
+        for source in [0, 3, 20, 41, 54] {
+            let expected = dijkstra(&view, source, target).unwrap();
+            let guided = astar(&view, source, target, manhattan).unwrap();
+            let blind = astar(&view, source, target, |_| 0.0).unwrap();
+            assert_eq!(guided.cost, expected.cost, "from {}", source);
+            assert_eq!(blind.cost, expected.cost, "from {}", source);
+            assert_eq!(guided.path.first(), Some(&source));
+            assert_eq!(guided.path.last(), Some(&target));
+            let summed: f64 = guided.path.windows(2).map(|hop| edge_weight(&view, hop[0], hop[1])).sum();
+            assert_eq!(summed, guided.cost);
+        }
+    }
+
+    #[test]
+    fn test_astar_tolerates_bad_heuristics() {
+        let view = weighted_grid();
+        let expected = dijkstra(&view, 0, 55).unwrap().cost;
+        assert_eq!(astar(&view, 0, 55, |_| -3.0).unwrap().cost, expected);
+        assert_eq!(astar(&view, 0, 55, |_| f64::NAN).unwrap().cost, expected);
+        assert!(astar(&view, 0, 99, |_| 0.0).is_none());
+    }
+
     #[test]
     fn test_bfs_all_shortest_paths() {
         // Diamond: 1->2, 1->3, 2->4, 3->4
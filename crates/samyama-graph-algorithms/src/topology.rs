@@ -1,9 +1,9 @@
 //! Graph topology analysis algorithms
 //!
-//! Implements REQ-ALGO-005 (Triangle Counting)
+//! Implements REQ-ALGO-005 (Triangle Counting) and betweenness centrality
 
-use super::common::GraphView;
-use std::collections::HashSet;
+use super::common::{GraphView, NodeId};
+use std::collections::{HashMap, HashSet, VecDeque};
 use rayon::prelude::*;
 
 /// Triangle Counting
@@ -84,6 +84,87 @@ pub fn count_triangles(view: &GraphView) -> usize {
     }
 }
 
+/// Betweenness centrality (Brandes' algorithm)
+///
+/// For every node, sums over ordered pairs (s, t) the fraction of shortest s→t
+/// paths that pass through it. Paths follow edge direction and are unweighted
+/// (hop count); a graph stored with edges in both directions is treated as
+/// undirected, each unordered pair then counting twice. Pairs with no path
+/// contribute nothing, so disconnected graphs are fine. Scores are not normalized.
+/// Use [`GraphView::to_undirected`] for undirected betweenness.
+pub fn betweenness_centrality(view: &GraphView) -> HashMap<NodeId, f64> {
+    let n = view.node_count;
+
+    let scores = if n >= 1000 {
+        (0..n)
+            .into_par_iter()
+            .fold(
+                || vec![0.0; n],
+                |mut acc, s| {
+                    accumulate_dependencies(view, s, &mut acc);
+                    acc
+                },
+            )
+            .reduce(
+                || vec![0.0; n],
+                |mut a, b| {
+                    a.iter_mut().zip(b).for_each(|(x, y)| *x += y);
+                    a
+                },
+            )
+    } else {
+        let mut acc = vec![0.0; n];
+        for s in 0..n {
+            accumulate_dependencies(view, s, &mut acc);
+        }
+        acc
+    };
+
+    scores
+        .into_iter()
+        .enumerate()
+        .map(|(idx, score)| (view.index_to_node[idx], score))
+        .collect()
+}
+
+/// Add the dependencies of source `s` on every other node to `centrality`
+fn accumulate_dependencies(view: &GraphView, s: usize, centrality: &mut [f64]) {
+    let n = view.node_count;
+    let mut order = Vec::with_capacity(n);
+    let mut predecessors: Vec<Vec<usize>> = vec![Vec::new(); n];
+    let mut sigma = vec![0.0; n];
+    let mut distance = vec![usize::MAX; n];
+    sigma[s] = 1.0;
+    distance[s] = 0;
+
+    // BFS counting shortest paths
+    let mut queue = VecDeque::from([s]);
+    while let Some(v) = queue.pop_front() {
+        order.push(v);
+        for &w in view.successors(v) {
+            if distance[w] == usize::MAX {
+                distance[w] = distance[v] + 1;
+                queue.push_back(w);
+            }
+            if distance[w] == distance[v] + 1 {
+                sigma[w] += sigma[v];
+                predecessors[w].push(v);
+            }
+        }
+    }
+
+    // Back-propagate dependencies in order of decreasing distance
+    let mut delta = vec![0.0; n];
+    while let Some(w) = order.pop() {
+        for &v in &predecessors[w] {
+            delta[v] += sigma[v] / sigma[w] * (1.0 + delta[w]);
+        }
+        if w != s {
+            centrality[w] += delta[w];
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -117,4 +198,66 @@ mod tests {
         let count = count_triangles(&view);
         assert_eq!(count, 4);
     }
+
+    fn view_from_edges(node_count: usize, edges: &[(usize, usize)]) -> GraphView {
+        let index_to_node: Vec<NodeId> = (1..=node_count as u64).collect();
+        let node_to_index = index_to_node.iter().enumerate().map(|(i, &id)| (id, i)).collect();
+        let mut outgoing = vec![vec![]; node_count];
+        let mut incoming = vec![vec![]; node_count];
+        for &(u, v) in edges {
+            outgoing[u].push(v);
+            incoming[v].push(u);
+        }
+        GraphView::from_adjacency_list(node_count, index_to_node, node_to_index, outgoing, incoming, None)
+    }
+
+    #[test]
+    fn test_betweenness_path_graph() {
+        // Undirected path 1 - 2 - 3 - 4 - 5, stored in both directions
+        let mut edges = Vec::new();
+        for i in 0..4 {
+            edges.push((i, i + 1));
+            edges.push((i + 1, i));
+        }
+        let scores = betweenness_centrality(&view_from_edges(5, &edges));
+
+        // Node 3 lies on 2*2 unordered pairs, counted in both directions
+        assert_eq!(scores[&3], 8.0);
+        assert_eq!(scores[&2], 6.0);
+        assert_eq!(scores[&4], 6.0);
+        assert_eq!(scores[&1], 0.0);
+        let max = scores.iter().max_by(|a, b| a.1.total_cmp(b.1)).unwrap();
+        assert_eq!(*max.0, 3);
+    }
+
+    #[test]
+    fn test_betweenness_directed_and_disconnected() {
+        // 1 -> 2 -> 3, plus 4 -> 2 and an isolated node 5
+        let scores = betweenness_centrality(&view_from_edges(5, &[(0, 1), (1, 2), (3, 1)]));
+        // 2 relays 1->3 and 4->3
+        assert_eq!(scores[&2], 2.0);
+        assert_eq!(scores[&5], 0.0);
+
+        // Reversing the second hop leaves no path through 2
+        let scores = betweenness_centrality(&view_from_edges(3, &[(0, 1), (2, 1)]));
+        assert_eq!(scores[&2], 0.0);
+    }
+
+    #[test]
+    fn test_betweenness_on_undirected_view() {
+        // Directed path 1 -> 2 -> 3 -> 4 -> 5 symmetrized matches the two-way path
+        let directed = view_from_edges(5, &[(0, 1), (1, 2), (2, 3), (3, 4)]);
+        assert_eq!(betweenness_centrality(&directed)[&3], 4.0);
+        let scores = betweenness_centrality(&directed.to_undirected());
+        assert_eq!(scores[&3], 8.0);
+        assert_eq!(scores[&2], 6.0);
+    }
+
+    #[test]
+    fn test_betweenness_splits_between_equal_paths() {
+        // Diamond 1 -> {2, 3} -> 4: each middle node carries half of 1->4
+        let scores = betweenness_centrality(&view_from_edges(4, &[(0, 1), (0, 2), (1, 3), (2, 3)]));
+        assert_eq!(scores[&2], 0.5);
+        assert_eq!(scores[&3], 0.5);
+    }
 }
@@ -3,7 +3,7 @@
 //! Usage:
 //!   cargo run -p samyama-optimization --release --example run_baseline_suite -- \
 //!       --out /tmp/p8 [--seeds 30] [--dim 30] [--pop 50] [--iters 500] \
-//!       [--so-only|--mo-only]
+//!       [--base-seed 42] [--so-only|--mo-only]
 //!
 //! Writes:
 //!   <out>/so_results.csv
@@ -26,13 +26,14 @@ struct Args {
     dim: usize,
     pop: usize,
     iters: usize,
+    base_seed: Option<u64>,
     so: bool,
     mo: bool,
 }
 
 impl Default for Args {
     fn default() -> Self {
-        Self { out: PathBuf::from("/tmp/p8"), seeds: 5, dim: 30, pop: 50, iters: 200, so: true, mo: true }
+        Self { out: PathBuf::from("/tmp/p8"), seeds: 5, dim: 30, pop: 50, iters: 200, base_seed: None, so: true, mo: true }
     }
 }
 
@@ -47,6 +48,7 @@ fn parse_args() -> Args {
             "--dim" => { a.dim = argv[i + 1].parse().unwrap(); i += 2; }
             "--pop" => { a.pop = argv[i + 1].parse().unwrap(); i += 2; }
             "--iters" => { a.iters = argv[i + 1].parse().unwrap(); i += 2; }
+            "--base-seed" => { a.base_seed = Some(argv[i + 1].parse().unwrap()); i += 2; }
             "--so-only" => { a.mo = false; i += 1; }
             "--mo-only" => { a.so = false; i += 1; }
             other => { eprintln!("unknown arg: {}", other); std::process::exit(2); }
@@ -58,7 +60,12 @@ fn parse_args() -> Args {
 fn main() {
     let a = parse_args();
     std::fs::create_dir_all(&a.out).unwrap();
-    let cfg = SolverConfig { population_size: a.pop, max_iterations: a.iters };
+    let cfg = SolverConfig {
+        population_size: a.pop,
+        max_iterations: a.iters,
+        seed: a.base_seed,
+        ..Default::default()
+    };
 
     if a.so {
         let problems = so_suite(a.dim);
@@ -100,14 +107,15 @@ fn main() {
     let manifest = format!(
         r#"{{
   "args": {{
-    "out": "{}", "seeds": {}, "dim": {}, "pop": {}, "iters": {}, "so": {}, "mo": {}
+    "out": "{}", "seeds": {}, "dim": {}, "pop": {}, "iters": {}, "base_seed": {}, "so": {}, "mo": {}
   }},
   "git_sha": "{}",
   "timestamp_epoch": {},
   "host": "{}"
 }}
 "#,
-        a.out.display(), a.seeds, a.dim, a.pop, a.iters, a.so, a.mo,
+        a.out.display(), a.seeds, a.dim, a.pop, a.iters,
+        a.base_seed.map_or("null".to_string(), |s| s.to_string()), a.so, a.mo,
         git_sha, epoch, std::env::var("HOSTNAME").unwrap_or_default()
     );
     let mpath = a.out.join("manifest.json");
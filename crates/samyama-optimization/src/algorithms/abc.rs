@@ -1,6 +1,7 @@
-use crate::common::{Individual, OptimizationResult, Problem, SolverConfig};
+use crate::common::{report_progress, ConvergenceTracker, Individual, OptimizationResult, Problem, SolverConfig};
 use ndarray::Array1;
 use rand::prelude::*;
+use std::ops::ControlFlow;
 
 pub struct ABCSolver {
     pub config: SolverConfig,
@@ -17,7 +18,18 @@ impl ABCSolver {
     }
 
     pub fn solve<P: Problem>(&self, problem: &P) -> OptimizationResult {
-        let mut rng = thread_rng();
+        self.solve_with_progress(problem, |_, _| ControlFlow::Continue(()))
+    }
+
+    /// Runs like [`solve`](Self::solve), calling `on_iteration(iteration, best)`
+    /// after each iteration; returning `ControlFlow::Break` stops the run and
+    /// returns the best found so far.
+    pub fn solve_with_progress<P, F>(&self, problem: &P, mut on_iteration: F) -> OptimizationResult
+    where
+        P: Problem,
+        F: FnMut(usize, f64) -> ControlFlow<()>,
+    {
+        let mut rng = self.config.rng();
         let dim = problem.dim();
         let (lower, upper) = problem.bounds();
         let pop_size = self.config.population_size;
@@ -30,7 +42,7 @@ impl ABCSolver {
                 for i in 0..dim {
                     vars[i] = rng.gen_range(lower[i]..upper[i]);
                 }
-                let fitness = problem.fitness(&vars);
+                let fitness = problem.evaluate(&mut vars);
                 Individual::new(vars, fitness)
             })
             .collect();
@@ -48,8 +60,12 @@ impl ABCSolver {
 
         let mut history = Vec::with_capacity(self.config.max_iterations);
 
+        let mut convergence = ConvergenceTracker::new(&self.config);
         for _iter in 0..self.config.max_iterations {
             history.push(best_fitness);
+            if report_progress(&mut on_iteration, &history) || convergence.converged(best_fitness) {
+                break;
+            }
 
             // 2. Employed Bees Phase
             for i in 0..pop_size {
@@ -64,7 +80,7 @@ impl ABCSolver {
                 let phi: f64 = rng.gen_range(-1.0..1.0);
                 new_vars[j] = (foods[i].variables[j] + phi * (foods[i].variables[j] - foods[k].variables[j])).clamp(lower[j], upper[j]);
 
-                let new_fitness = problem.fitness(&new_vars);
+                let new_fitness = problem.evaluate(&mut new_vars);
                 if new_fitness < foods[i].fitness {
                     foods[i] = Individual::new(new_vars, new_fitness);
                     trial_counters[i] = 0;
@@ -108,7 +124,7 @@ impl ABCSolver {
                     let phi: f64 = rng.gen_range(-1.0..1.0);
                     new_vars[j] = (foods[i].variables[j] + phi * (foods[i].variables[j] - foods[k].variables[j])).clamp(lower[j], upper[j]);
 
-                    let new_fitness = problem.fitness(&new_vars);
+                    let new_fitness = problem.evaluate(&mut new_vars);
                     if new_fitness < foods[i].fitness {
                         foods[i] = Individual::new(new_vars, new_fitness);
                         trial_counters[i] = 0;
@@ -127,7 +143,7 @@ impl ABCSolver {
                     for j in 0..dim {
                         vars[j] = rng.gen_range(lower[j]..upper[j]);
                     }
-                    let fitness = problem.fitness(&vars);
+                    let fitness = problem.evaluate(&mut vars);
                     foods[i] = Individual::new(vars, fitness);
                     trial_counters[i] = 0;
                 }
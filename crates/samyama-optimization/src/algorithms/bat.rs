@@ -1,6 +1,7 @@
-use crate::common::{Individual, OptimizationResult, Problem, SolverConfig};
+use crate::common::{report_progress, ConvergenceTracker, Individual, OptimizationResult, Problem, SolverConfig};
 use ndarray::Array1;
 use rand::prelude::*;
+use std::ops::ControlFlow;
 
 pub struct BatSolver {
     pub config: SolverConfig,
@@ -22,7 +23,18 @@ impl BatSolver {
     }
 
     pub fn solve<P: Problem>(&self, problem: &P) -> OptimizationResult {
-        let mut rng = thread_rng();
+        self.solve_with_progress(problem, |_, _| ControlFlow::Continue(()))
+    }
+
+    /// Runs like [`solve`](Self::solve), calling `on_iteration(iteration, best)`
+    /// after each iteration; returning `ControlFlow::Break` stops the run and
+    /// returns the best found so far.
+    pub fn solve_with_progress<P, F>(&self, problem: &P, mut on_iteration: F) -> OptimizationResult
+    where
+        P: Problem,
+        F: FnMut(usize, f64) -> ControlFlow<()>,
+    {
+        let mut rng = self.config.rng();
         let dim = problem.dim();
         let (lower, upper) = problem.bounds();
 
@@ -33,7 +45,7 @@ impl BatSolver {
                 for i in 0..dim {
                     vars[i] = rng.gen_range(lower[i]..upper[i]);
                 }
-                let fitness = problem.fitness(&vars);
+                let fitness = problem.evaluate(&mut vars);
                 Individual::new(vars, fitness)
             })
             .collect();
@@ -59,8 +71,12 @@ impl BatSolver {
 
         let mut history = Vec::with_capacity(self.config.max_iterations);
 
+        let mut convergence = ConvergenceTracker::new(&self.config);
         for iter in 0..self.config.max_iterations {
             history.push(best_fitness);
+            if report_progress(&mut on_iteration, &history) || convergence.converged(best_fitness) {
+                break;
+            }
 
             for i in 0..self.config.population_size {
                 // Update frequency
@@ -82,7 +98,7 @@ impl BatSolver {
                         temp_vars[j] = (temp_vars[j] + epsilon * avg_loudness).clamp(lower[j], upper[j]);
                     }
                     
-                    let temp_fitness = problem.fitness(&temp_vars);
+                    let temp_fitness = problem.evaluate(&mut temp_vars);
                     
                     // Accept new solution
                     if temp_fitness < population[i].fitness && rng.gen::<f64>() < loudnesses[i] {
@@ -94,7 +110,7 @@ impl BatSolver {
                         emission_rates[i] = r0 * (1.0 - (-self.gamma * (iter as f64)).exp());
                     }
                 } else {
-                    population[i].fitness = problem.fitness(&population[i].variables);
+                    population[i].fitness = problem.evaluate(&mut population[i].variables);
                 }
 
                 // Update global best
@@ -1,7 +1,10 @@
-use crate::common::{Individual, OptimizationResult, Problem, SolverConfig};
+use crate::common::{
+    report_progress, split_seeds, ConvergenceTracker, Individual, OptimizationResult, Problem, SolverConfig,
+};
 use ndarray::Array1;
 use rand::prelude::*;
 use rayon::prelude::*;
+use std::ops::ControlFlow;
 
 pub struct BMRSolver {
     pub config: SolverConfig,
@@ -13,7 +16,18 @@ impl BMRSolver {
     }
 
     pub fn solve<P: Problem>(&self, problem: &P) -> OptimizationResult {
-        let mut rng = thread_rng();
+        self.solve_with_progress(problem, |_, _| ControlFlow::Continue(()))
+    }
+
+    /// Runs like [`solve`](Self::solve), calling `on_iteration(iteration, best)`
+    /// after each iteration; returning `ControlFlow::Break` stops the run and
+    /// returns the best found so far.
+    pub fn solve_with_progress<P, F>(&self, problem: &P, mut on_iteration: F) -> OptimizationResult
+    where
+        P: Problem,
+        F: FnMut(usize, f64) -> ControlFlow<()>,
+    {
+        let mut rng = self.config.rng();
         let dim = problem.dim();
         let (lower, upper) = problem.bounds();
 
@@ -23,13 +37,14 @@ impl BMRSolver {
                 for i in 0..dim {
                     vars[i] = rng.gen_range(lower[i]..upper[i]);
                 }
-                let fitness = problem.fitness(&vars);
+                let fitness = problem.evaluate(&mut vars);
                 Individual::new(vars, fitness)
             })
             .collect();
 
         let mut history = Vec::with_capacity(self.config.max_iterations);
 
+        let mut convergence = ConvergenceTracker::new(&self.config);
         for iter in 0..self.config.max_iterations {
             if iter % 10 == 0 {
                 println!("BMR Solver: Iteration {}/{}", iter, self.config.max_iterations);
@@ -40,11 +55,16 @@ impl BMRSolver {
             let best_fitness = population[best_idx].fitness;
 
             history.push(best_fitness);
+            if report_progress(&mut on_iteration, &history) || convergence.converged(best_fitness) {
+                break;
+            }
 
+            let seeds = split_seeds(&mut rng, population.len());
             population = population
                 .into_par_iter()
-                .map(|mut ind| {
-                    let mut local_rng = thread_rng();
+                .zip(seeds)
+                .map(|(mut ind, seed)| {
+                    let mut local_rng = StdRng::seed_from_u64(seed);
                     let mut new_vars = Array1::zeros(dim);
 
                     let r1: f64 = local_rng.gen();
@@ -77,7 +97,7 @@ impl BMRSolver {
                         }
                     }
 
-                    let new_fitness = problem.fitness(&new_vars);
+                    let new_fitness = problem.evaluate(&mut new_vars);
                     if new_fitness < ind.fitness {
                         ind.variables = new_vars;
                         ind.fitness = new_fitness;
@@ -16,10 +16,13 @@
 //!
 //! Greedy acceptance: keep V' iff fitness improves.
 
-use crate::common::{Individual, OptimizationResult, Problem, SolverConfig};
+use crate::common::{
+    report_progress, split_seeds, ConvergenceTracker, Individual, OptimizationResult, Problem, SolverConfig,
+};
 use ndarray::Array1;
 use rand::prelude::*;
 use rayon::prelude::*;
+use std::ops::ControlFlow;
 
 pub struct BMWRSolver {
     pub config: SolverConfig,
@@ -31,7 +34,18 @@ impl BMWRSolver {
     }
 
     pub fn solve<P: Problem>(&self, problem: &P) -> OptimizationResult {
-        let mut rng = thread_rng();
+        self.solve_with_progress(problem, |_, _| ControlFlow::Continue(()))
+    }
+
+    /// Runs like [`solve`](Self::solve), calling `on_iteration(iteration, best)`
+    /// after each iteration; returning `ControlFlow::Break` stops the run and
+    /// returns the best found so far.
+    pub fn solve_with_progress<P, F>(&self, problem: &P, mut on_iteration: F) -> OptimizationResult
+    where
+        P: Problem,
+        F: FnMut(usize, f64) -> ControlFlow<()>,
+    {
+        let mut rng = self.config.rng();
         let dim = problem.dim();
         let (lower, upper) = problem.bounds();
         let pop_size = self.config.population_size;
@@ -42,13 +56,14 @@ impl BMWRSolver {
                 for i in 0..dim {
                     vars[i] = rng.gen_range(lower[i]..upper[i]);
                 }
-                let fitness = problem.fitness(&vars);
+                let fitness = problem.evaluate(&mut vars);
                 Individual::new(vars, fitness)
             })
             .collect();
 
         let mut history = Vec::with_capacity(self.config.max_iterations);
 
+        let mut convergence = ConvergenceTracker::new(&self.config);
         for iter in 0..self.config.max_iterations {
             if iter % 10 == 0 {
                 println!("BMWR Solver: Iteration {}/{}", iter, self.config.max_iterations);
@@ -59,16 +74,20 @@ impl BMWRSolver {
             let mean_vars = mean_vec(&population, dim);
             let best_fitness = population[best_idx].fitness;
             history.push(best_fitness);
+            if report_progress(&mut on_iteration, &history) || convergence.converged(best_fitness) {
+                break;
+            }
 
             // Snapshot variables for population-based random pick (paper's intent).
             let snapshot: Vec<Array1<f64>> =
                 population.iter().map(|ind| ind.variables.clone()).collect();
 
+            let seeds = split_seeds(&mut rng, population.len());
             population = population
                 .into_par_iter()
                 .enumerate()
                 .map(|(k, mut ind)| {
-                    let mut local_rng = thread_rng();
+                    let mut local_rng = StdRng::seed_from_u64(seeds[k]);
                     let r1: f64 = local_rng.gen();
                     let r2: f64 = local_rng.gen();
                     let r3: f64 = local_rng.gen();
@@ -97,7 +116,7 @@ impl BMWRSolver {
                         }
                     }
 
-                    let new_fitness = problem.fitness(&new_vars);
+                    let new_fitness = problem.evaluate(&mut new_vars);
                     if new_fitness < ind.fitness {
                         ind.variables = new_vars;
                         ind.fitness = new_fitness;
@@ -1,8 +1,9 @@
-use crate::common::{Individual, OptimizationResult, Problem, SolverConfig};
+use crate::common::{report_progress, ConvergenceTracker, Individual, OptimizationResult, Problem, SolverConfig};
 use ndarray::Array1;
 use rand::prelude::*;
 use rand_distr::Distribution;
 use std::f64::consts::PI;
+use std::ops::ControlFlow;
 
 pub struct CuckooSolver {
     pub config: SolverConfig,
@@ -22,7 +23,7 @@ impl CuckooSolver {
     }
 
     /// Levy flight random walk
-    fn levy_flight(&self, dim: usize) -> Array1<f64> {
+    fn levy_flight<R: Rng>(&self, dim: usize, rng: &mut R) -> Array1<f64> {
         // Mantegna's algorithm for Levy flights
         let beta = 1.5;
         let sigma_u = ((gamma(1.0 + beta) * (PI * beta / 2.0).sin()) / 
@@ -31,13 +32,12 @@ impl CuckooSolver {
         let sigma_v = 1.0;
 
         let mut step = Array1::zeros(dim);
-        let mut rng = thread_rng();
 
         for i in 0..dim {
             let _u: f64 = rng.gen_range(0.0..1.0) * sigma_u; // Standard normal * sigma_u? No, usually Gaussian(0, sigma_u^2)
             // Simulating Normal distribution
-            let u_n: f64 = rand_distr::Normal::new(0.0, sigma_u).unwrap().sample(&mut rng);
-            let v_n: f64 = rand_distr::Normal::new(0.0, sigma_v).unwrap().sample(&mut rng);
+            let u_n: f64 = rand_distr::Normal::new(0.0, sigma_u).unwrap().sample(rng);
+            let v_n: f64 = rand_distr::Normal::new(0.0, sigma_v).unwrap().sample(rng);
             
             let s = u_n / v_n.abs().powf(1.0 / beta);
             step[i] = s;
@@ -46,7 +46,18 @@ impl CuckooSolver {
     }
 
     pub fn solve<P: Problem>(&self, problem: &P) -> OptimizationResult {
-        let mut rng = thread_rng();
+        self.solve_with_progress(problem, |_, _| ControlFlow::Continue(()))
+    }
+
+    /// Runs like [`solve`](Self::solve), calling `on_iteration(iteration, best)`
+    /// after each iteration; returning `ControlFlow::Break` stops the run and
+    /// returns the best found so far.
+    pub fn solve_with_progress<P, F>(&self, problem: &P, mut on_iteration: F) -> OptimizationResult
+    where
+        P: Problem,
+        F: FnMut(usize, f64) -> ControlFlow<()>,
+    {
+        let mut rng = self.config.rng();
         let dim = problem.dim();
         let (lower, upper) = problem.bounds();
 
@@ -57,7 +68,7 @@ impl CuckooSolver {
                 for i in 0..dim {
                     vars[i] = rng.gen_range(lower[i]..upper[i]);
                 }
-                let fitness = problem.fitness(&vars);
+                let fitness = problem.evaluate(&mut vars);
                 Individual::new(vars, fitness)
             })
             .collect();
@@ -73,13 +84,17 @@ impl CuckooSolver {
 
         let mut history = Vec::with_capacity(self.config.max_iterations);
 
+        let mut convergence = ConvergenceTracker::new(&self.config);
         for _iter in 0..self.config.max_iterations {
             history.push(best_ind.fitness);
+            if report_progress(&mut on_iteration, &history) || convergence.converged(best_ind.fitness) {
+                break;
+            }
 
             // 2. Generate new solutions via Levy Flights
             for i in 0..self.config.population_size {
                 let step_size = 0.01; // Step scale
-                let levy = self.levy_flight(dim);
+                let levy = self.levy_flight(dim, &mut rng);
                 let current_vars = &nests[i].variables;
                 
                 let mut new_vars = Array1::zeros(dim);
@@ -98,7 +113,7 @@ impl CuckooSolver {
                     new_vars[j] = (current_vars[j] + delta_simple).clamp(lower[j], upper[j]);
                 }
 
-                let new_fitness = problem.fitness(&new_vars);
+                let new_fitness = problem.evaluate(&mut new_vars);
                 
                 // Random selection of nest to replace?
                 // Standard: Pick a random nest j, replace if new is better.
@@ -134,7 +149,7 @@ impl CuckooSolver {
                     vars[j] = (nests[i].variables[j] + step).clamp(lower[j], upper[j]);
                 }
                 
-                let fitness = problem.fitness(&vars);
+                let fitness = problem.evaluate(&mut vars);
                 nests[i] = Individual::new(vars, fitness);
                 
                 if fitness < best_ind.fitness {
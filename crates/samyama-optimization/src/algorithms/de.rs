@@ -1,7 +1,10 @@
-use crate::common::{Individual, OptimizationResult, Problem, SolverConfig};
+use crate::common::{
+    report_progress, split_seeds, ConvergenceTracker, Individual, OptimizationResult, Problem, SolverConfig,
+};
 use ndarray::Array1;
 use rand::prelude::*;
 use rayon::prelude::*;
+use std::ops::ControlFlow;
 
 pub struct DESolver {
     pub config: SolverConfig,
@@ -19,7 +22,18 @@ impl DESolver {
     }
 
     pub fn solve<P: Problem>(&self, problem: &P) -> OptimizationResult {
-        let mut rng = thread_rng();
+        self.solve_with_progress(problem, |_, _| ControlFlow::Continue(()))
+    }
+
+    /// Runs like [`solve`](Self::solve), calling `on_iteration(iteration, best)`
+    /// after each iteration; returning `ControlFlow::Break` stops the run and
+    /// returns the best found so far.
+    pub fn solve_with_progress<P, F>(&self, problem: &P, mut on_iteration: F) -> OptimizationResult
+    where
+        P: Problem,
+        F: FnMut(usize, f64) -> ControlFlow<()>,
+    {
+        let mut rng = self.config.rng();
         let dim = problem.dim();
         let (lower, upper) = problem.bounds();
 
@@ -29,29 +43,34 @@ impl DESolver {
                 for i in 0..dim {
                     vars[i] = rng.gen_range(lower[i]..upper[i]);
                 }
-                let fitness = problem.fitness(&vars);
+                let fitness = problem.evaluate(&mut vars);
                 Individual::new(vars, fitness)
             })
             .collect();
 
         let mut history = Vec::with_capacity(self.config.max_iterations);
 
+        let mut convergence = ConvergenceTracker::new(&self.config);
         for iter in 0..self.config.max_iterations {
             if iter % 10 == 0 {
                 println!("DE Solver: Iteration {}/{}", iter, self.config.max_iterations);
             }
             let best_idx = self.find_best(&population);
             history.push(population[best_idx].fitness);
+            if report_progress(&mut on_iteration, &history) || convergence.converged(population[best_idx].fitness) {
+                break;
+            }
 
             // Create new generation
             // Read-only access to old population for mutation
             let old_pop = population.clone();
 
+            let seeds = split_seeds(&mut rng, population.len());
             population = population
                 .into_par_iter()
                 .enumerate()
                 .map(|(i, mut target)| {
-                    let mut local_rng = thread_rng();
+                    let mut local_rng = StdRng::seed_from_u64(seeds[i]);
                     
                     // Pick a, b, c distinct from i
                     let mut idxs = [0; 3];
@@ -83,7 +102,7 @@ impl DESolver {
                     }
 
                     // Selection
-                    let trial_fitness = problem.fitness(&trial_vars);
+                    let trial_fitness = problem.evaluate(&mut trial_vars);
                     if trial_fitness < target.fitness {
                         target.variables = trial_vars;
                         target.fitness = trial_fitness;
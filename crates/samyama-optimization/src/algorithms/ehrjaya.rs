@@ -9,10 +9,13 @@
 //!       V' = V + r1·(V_best − |V|) − r2·(V_worst − |V|)
 //! Greedy acceptance.
 
-use crate::common::{Individual, OptimizationResult, Problem, SolverConfig};
+use crate::common::{
+    report_progress, split_seeds, ConvergenceTracker, Individual, OptimizationResult, Problem, SolverConfig,
+};
 use ndarray::Array1;
 use rand::prelude::*;
 use rayon::prelude::*;
+use std::ops::ControlFlow;
 
 pub struct EHRJayaSolver {
     pub config: SolverConfig,
@@ -24,7 +27,18 @@ impl EHRJayaSolver {
     }
 
     pub fn solve<P: Problem>(&self, problem: &P) -> OptimizationResult {
-        let mut rng = thread_rng();
+        self.solve_with_progress(problem, |_, _| ControlFlow::Continue(()))
+    }
+
+    /// Runs like [`solve`](Self::solve), calling `on_iteration(iteration, best)`
+    /// after each iteration; returning `ControlFlow::Break` stops the run and
+    /// returns the best found so far.
+    pub fn solve_with_progress<P, F>(&self, problem: &P, mut on_iteration: F) -> OptimizationResult
+    where
+        P: Problem,
+        F: FnMut(usize, f64) -> ControlFlow<()>,
+    {
+        let mut rng = self.config.rng();
         let dim = problem.dim();
         let (lower, upper) = problem.bounds();
         let pop_size = self.config.population_size;
@@ -35,13 +49,14 @@ impl EHRJayaSolver {
                 for i in 0..dim {
                     vars[i] = rng.gen_range(lower[i]..upper[i]);
                 }
-                let fitness = problem.fitness(&vars);
+                let fitness = problem.evaluate(&mut vars);
                 Individual::new(vars, fitness)
             })
             .collect();
 
         let mut history = Vec::with_capacity(self.config.max_iterations);
 
+        let mut convergence = ConvergenceTracker::new(&self.config);
         for iter in 0..self.config.max_iterations {
             if iter % 10 == 0 {
                 println!(
@@ -54,13 +69,17 @@ impl EHRJayaSolver {
             let best_vars = population[0].variables.clone();
             let worst_vars = population[pop_size - 1].variables.clone();
             history.push(population[0].fitness);
+            if report_progress(&mut on_iteration, &history) || convergence.converged(population[0].fitness) {
+                break;
+            }
 
             let half = pop_size / 2;
+            let seeds = split_seeds(&mut rng, population.len());
             population = population
                 .into_par_iter()
                 .enumerate()
                 .map(|(rank, mut ind)| {
-                    let mut local_rng = thread_rng();
+                    let mut local_rng = StdRng::seed_from_u64(seeds[rank]);
                     let r1: f64 = local_rng.gen();
                     let r2: f64 = local_rng.gen();
                     let mut new_vars = Array1::zeros(dim);
@@ -82,7 +101,7 @@ impl EHRJayaSolver {
                         }
                     }
 
-                    let new_fitness = problem.fitness(&new_vars);
+                    let new_fitness = problem.evaluate(&mut new_vars);
                     if new_fitness < ind.fitness {
                         ind.variables = new_vars;
                         ind.fitness = new_fitness;
@@ -1,7 +1,10 @@
-use crate::common::{Individual, OptimizationResult, Problem, SolverConfig};
+use crate::common::{
+    report_progress, split_seeds, ConvergenceTracker, Individual, OptimizationResult, Problem, SolverConfig,
+};
 use ndarray::Array1;
 use rand::prelude::*;
 use rayon::prelude::*;
+use std::ops::ControlFlow;
 
 pub struct FireflySolver {
     pub config: SolverConfig,
@@ -25,7 +28,18 @@ impl FireflySolver {
     }
 
     pub fn solve<P: Problem>(&self, problem: &P) -> OptimizationResult {
-        let mut rng = thread_rng();
+        self.solve_with_progress(problem, |_, _| ControlFlow::Continue(()))
+    }
+
+    /// Runs like [`solve`](Self::solve), calling `on_iteration(iteration, best)`
+    /// after each iteration; returning `ControlFlow::Break` stops the run and
+    /// returns the best found so far.
+    pub fn solve_with_progress<P, F>(&self, problem: &P, mut on_iteration: F) -> OptimizationResult
+    where
+        P: Problem,
+        F: FnMut(usize, f64) -> ControlFlow<()>,
+    {
+        let mut rng = self.config.rng();
         let dim = problem.dim();
         let (lower, upper) = problem.bounds();
 
@@ -36,7 +50,7 @@ impl FireflySolver {
                 for i in 0..dim {
                     vars[i] = rng.gen_range(lower[i]..upper[i]);
                 }
-                let fitness = problem.fitness(&vars);
+                let fitness = problem.evaluate(&mut vars);
                 Individual::new(vars, fitness)
             })
             .collect();
@@ -51,8 +65,12 @@ impl FireflySolver {
             }
         }
 
+        let mut convergence = ConvergenceTracker::new(&self.config);
         for _iter in 0..self.config.max_iterations {
             history.push(population[best_idx].fitness);
+            if report_progress(&mut on_iteration, &history) || convergence.converged(population[best_idx].fitness) {
+                break;
+            }
 
             // Firefly algorithm loop: move i towards j if j is brighter (better fitness)
             // Note: Parallelizing this double loop is tricky due to mutable updates.
@@ -62,8 +80,9 @@ impl FireflySolver {
             let pop_size = self.config.population_size;
 
             // We can parallelize the outer loop (i)
+            let seeds = split_seeds(&mut rng, pop_size);
             let new_positions: Vec<Option<Array1<f64>>> = (0..pop_size).into_par_iter().map(|i| {
-                let mut rng = thread_rng();
+                let mut rng = StdRng::seed_from_u64(seeds[i]);
                 let mut moved = false;
                 let mut new_vars = old_population[i].variables.clone();
                 let fitness_i = old_population[i].fitness;
@@ -107,8 +126,8 @@ impl FireflySolver {
 
             // Apply updates
             for (i, new_pos) in new_positions.into_iter().enumerate() {
-                if let Some(vars) = new_pos {
-                    let new_fitness = problem.fitness(&vars);
+                if let Some(mut vars) = new_pos {
+                    let new_fitness = problem.evaluate(&mut vars);
                     // Selection: greedy acceptance? Standard FA moves anyway.
                     // We'll accept if better or just move. 
                     // Standard FA just moves. But ensuring elitism is good.
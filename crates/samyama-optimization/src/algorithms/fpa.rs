@@ -1,8 +1,9 @@
-use crate::common::{Individual, OptimizationResult, Problem, SolverConfig};
+use crate::common::{report_progress, ConvergenceTracker, Individual, OptimizationResult, Problem, SolverConfig};
 use ndarray::Array1;
 use rand::prelude::*;
 use rand_distr::Distribution;
 use std::f64::consts::PI;
+use std::ops::ControlFlow;
 
 pub struct FPASolver {
     pub config: SolverConfig,
@@ -18,7 +19,7 @@ impl FPASolver {
     }
 
     /// Levy flight random walk
-    fn levy_flight(&self, dim: usize) -> Array1<f64> {
+    fn levy_flight<R: Rng>(&self, dim: usize, rng: &mut R) -> Array1<f64> {
         let beta = 1.5;
         let sigma_u = ((gamma(1.0 + beta) * (PI * beta / 2.0).sin()) / 
                       (gamma((1.0 + beta) / 2.0) * beta * 2.0f64.powf((beta - 1.0) / 2.0)))
@@ -26,11 +27,10 @@ impl FPASolver {
         let sigma_v = 1.0;
 
         let mut step = Array1::zeros(dim);
-        let mut rng = thread_rng();
 
         for i in 0..dim {
-            let u_n: f64 = rand_distr::Normal::new(0.0, sigma_u).unwrap().sample(&mut rng);
-            let v_n: f64 = rand_distr::Normal::new(0.0, sigma_v).unwrap().sample(&mut rng);
+            let u_n: f64 = rand_distr::Normal::new(0.0, sigma_u).unwrap().sample(rng);
+            let v_n: f64 = rand_distr::Normal::new(0.0, sigma_v).unwrap().sample(rng);
             let s = u_n / v_n.abs().powf(1.0 / beta);
             step[i] = s;
         }
@@ -38,7 +38,18 @@ impl FPASolver {
     }
 
     pub fn solve<P: Problem>(&self, problem: &P) -> OptimizationResult {
-        let mut rng = thread_rng();
+        self.solve_with_progress(problem, |_, _| ControlFlow::Continue(()))
+    }
+
+    /// Runs like [`solve`](Self::solve), calling `on_iteration(iteration, best)`
+    /// after each iteration; returning `ControlFlow::Break` stops the run and
+    /// returns the best found so far.
+    pub fn solve_with_progress<P, F>(&self, problem: &P, mut on_iteration: F) -> OptimizationResult
+    where
+        P: Problem,
+        F: FnMut(usize, f64) -> ControlFlow<()>,
+    {
+        let mut rng = self.config.rng();
         let dim = problem.dim();
         let (lower, upper) = problem.bounds();
         let pop_size = self.config.population_size;
@@ -50,7 +61,7 @@ impl FPASolver {
                 for i in 0..dim {
                     vars[i] = rng.gen_range(lower[i]..upper[i]);
                 }
-                let fitness = problem.fitness(&vars);
+                let fitness = problem.evaluate(&mut vars);
                 Individual::new(vars, fitness)
             })
             .collect();
@@ -67,15 +78,19 @@ impl FPASolver {
 
         let mut history = Vec::with_capacity(self.config.max_iterations);
 
+        let mut convergence = ConvergenceTracker::new(&self.config);
         for _iter in 0..self.config.max_iterations {
             history.push(best_fitness);
+            if report_progress(&mut on_iteration, &history) || convergence.converged(best_fitness) {
+                break;
+            }
 
             for i in 0..pop_size {
                 let mut new_vars = population[i].variables.clone();
 
                 if rng.gen::<f64>() < self.p {
                     // Global Pollination (Levy Flight)
-                    let levy = self.levy_flight(dim);
+                    let levy = self.levy_flight(dim, &mut rng);
                     for j in 0..dim {
                         let step = levy[j] * (population[i].variables[j] - best_vars[j]);
                         new_vars[j] = (population[i].variables[j] + step).clamp(lower[j], upper[j]);
@@ -97,7 +112,7 @@ impl FPASolver {
                     }
                 }
 
-                let new_fitness = problem.fitness(&new_vars);
+                let new_fitness = problem.evaluate(&mut new_vars);
                 if new_fitness < population[i].fitness {
                     population[i] = Individual::new(new_vars, new_fitness);
                     if new_fitness < best_fitness {
@@ -1,6 +1,7 @@
-use crate::common::{Individual, OptimizationResult, Problem, SolverConfig};
+use crate::common::{report_progress, ConvergenceTracker, Individual, OptimizationResult, Problem, SolverConfig};
 use ndarray::Array1;
 use rand::prelude::*;
+use std::ops::ControlFlow;
 
 pub struct GASolver {
     pub config: SolverConfig,
@@ -18,7 +19,18 @@ impl GASolver {
     }
 
     pub fn solve<P: Problem>(&self, problem: &P) -> OptimizationResult {
-        let mut rng = thread_rng();
+        self.solve_with_progress(problem, |_, _| ControlFlow::Continue(()))
+    }
+
+    /// Runs like [`solve`](Self::solve), calling `on_iteration(iteration, best)`
+    /// after each iteration; returning `ControlFlow::Break` stops the run and
+    /// returns the best found so far.
+    pub fn solve_with_progress<P, F>(&self, problem: &P, mut on_iteration: F) -> OptimizationResult
+    where
+        P: Problem,
+        F: FnMut(usize, f64) -> ControlFlow<()>,
+    {
+        let mut rng = self.config.rng();
         let dim = problem.dim();
         let (lower, upper) = problem.bounds();
 
@@ -29,13 +41,14 @@ impl GASolver {
                 for i in 0..dim {
                     vars[i] = rng.gen_range(lower[i]..upper[i]);
                 }
-                let fitness = problem.fitness(&vars);
+                let fitness = problem.evaluate(&mut vars);
                 Individual::new(vars, fitness)
             })
             .collect();
 
         let mut history = Vec::with_capacity(self.config.max_iterations);
 
+        let mut convergence = ConvergenceTracker::new(&self.config);
         for _iter in 0..self.config.max_iterations {
             // Find best for history
             let mut best_idx = 0;
@@ -45,6 +58,9 @@ impl GASolver {
                 }
             }
             history.push(population[best_idx].fitness);
+            if report_progress(&mut on_iteration, &history) || convergence.converged(population[best_idx].fitness) {
+                break;
+            }
 
             // 2. Evolution
             let mut new_population = Vec::with_capacity(self.config.population_size);
@@ -54,26 +70,26 @@ impl GASolver {
 
             while new_population.len() < self.config.population_size {
                 // Selection (Tournament)
-                let p1 = self.select(&population);
-                let p2 = self.select(&population);
+                let p1 = self.select(&population, &mut rng);
+                let p2 = self.select(&population, &mut rng);
 
                 // Crossover
                 let (mut c1_vars, mut c2_vars) = if rng.gen::<f64>() < self.crossover_rate {
-                    self.crossover(&p1.variables, &p2.variables)
+                    self.crossover(&p1.variables, &p2.variables, &mut rng)
                 } else {
                     (p1.variables.clone(), p2.variables.clone())
                 };
 
                 // Mutation
-                self.mutate(&mut c1_vars, &lower, &upper);
-                self.mutate(&mut c2_vars, &lower, &upper);
+                self.mutate(&mut c1_vars, &lower, &upper, &mut rng);
+                self.mutate(&mut c2_vars, &lower, &upper, &mut rng);
 
                 // Add to new population
-                let f1 = problem.fitness(&c1_vars);
+                let f1 = problem.evaluate(&mut c1_vars);
                 new_population.push(Individual::new(c1_vars, f1));
                 
                 if new_population.len() < self.config.population_size {
-                    let f2 = problem.fitness(&c2_vars);
+                    let f2 = problem.evaluate(&mut c2_vars);
                     new_population.push(Individual::new(c2_vars, f2));
                 }
             }
@@ -95,8 +111,7 @@ impl GASolver {
         }
     }
 
-    fn select<'a>(&self, population: &'a [Individual]) -> &'a Individual {
-        let mut rng = thread_rng();
+    fn select<'a, R: Rng>(&self, population: &'a [Individual], rng: &mut R) -> &'a Individual {
         let i1 = rng.gen_range(0..population.len());
         let i2 = rng.gen_range(0..population.len());
         
@@ -107,8 +122,7 @@ impl GASolver {
         }
     }
 
-    fn crossover(&self, p1: &Array1<f64>, p2: &Array1<f64>) -> (Array1<f64>, Array1<f64>) {
-        let mut rng = thread_rng();
+    fn crossover<R: Rng>(&self, p1: &Array1<f64>, p2: &Array1<f64>, rng: &mut R) -> (Array1<f64>, Array1<f64>) {
         let dim = p1.len();
         let mut c1 = p1.clone();
         let mut c2 = p2.clone();
@@ -122,8 +136,7 @@ impl GASolver {
         (c1, c2)
     }
 
-    fn mutate(&self, vars: &mut Array1<f64>, lower: &Array1<f64>, upper: &Array1<f64>) {
-        let mut rng = thread_rng();
+    fn mutate<R: Rng>(&self, vars: &mut Array1<f64>, lower: &Array1<f64>, upper: &Array1<f64>, rng: &mut R) {
         let dim = vars.len();
 
         for i in 0..dim {
@@ -131,7 +144,7 @@ impl GASolver {
                 // Small Gaussian mutation or random reset?
                 // Let's use Gaussian mutation for continuous space
                 let range = upper[i] - lower[i];
-                let delta = rand_distr::Normal::new(0.0, range * 0.1).unwrap().sample(&mut rng);
+                let delta = rand_distr::Normal::new(0.0, range * 0.1).unwrap().sample(rng);
                 vars[i] = (vars[i] + delta).clamp(lower[i], upper[i]);
             }
         }
@@ -1,7 +1,10 @@
-use crate::common::{Individual, OptimizationResult, Problem, SolverConfig};
+use crate::common::{
+    report_progress, split_seeds, ConvergenceTracker, Individual, OptimizationResult, Problem, SolverConfig,
+};
 use ndarray::Array1;
 use rand::prelude::*;
 use rayon::prelude::*;
+use std::ops::ControlFlow;
 
 pub struct GOTLBOSolver {
     pub config: SolverConfig,
@@ -13,7 +16,18 @@ impl GOTLBOSolver {
     }
 
     pub fn solve<P: Problem>(&self, problem: &P) -> OptimizationResult {
-        let mut rng = thread_rng();
+        self.solve_with_progress(problem, |_, _| ControlFlow::Continue(()))
+    }
+
+    /// Runs like [`solve`](Self::solve), calling `on_iteration(iteration, best)`
+    /// after each iteration; returning `ControlFlow::Break` stops the run and
+    /// returns the best found so far.
+    pub fn solve_with_progress<P, F>(&self, problem: &P, mut on_iteration: F) -> OptimizationResult
+    where
+        P: Problem,
+        F: FnMut(usize, f64) -> ControlFlow<()>,
+    {
+        let mut rng = self.config.rng();
         let dim = problem.dim();
         let (lower, upper) = problem.bounds();
 
@@ -24,13 +38,14 @@ impl GOTLBOSolver {
                 for i in 0..dim {
                     vars[i] = rng.gen_range(lower[i]..upper[i]);
                 }
-                let fitness = problem.fitness(&vars);
+                let fitness = problem.evaluate(&mut vars);
                 Individual::new(vars, fitness)
             })
             .collect();
 
         let mut history = Vec::with_capacity(self.config.max_iterations);
 
+        let mut convergence = ConvergenceTracker::new(&self.config);
         for iter in 0..self.config.max_iterations {
             if iter % 10 == 0 {
                 // Optional logging
@@ -41,12 +56,17 @@ impl GOTLBOSolver {
             let mean_vars = self.calculate_mean(&population, dim);
 
             history.push(best_fitness);
+            if report_progress(&mut on_iteration, &history) || convergence.converged(best_fitness) {
+                break;
+            }
 
             // 1. Teacher Phase with Opposition
+            let seeds = split_seeds(&mut rng, population.len());
             population = population
                 .into_par_iter()
-                .map(|mut ind| {
-                    let mut local_rng = thread_rng();
+                .zip(seeds)
+                .map(|(mut ind, seed)| {
+                    let mut local_rng = StdRng::seed_from_u64(seed);
                     let tf: f64 = local_rng.gen_range(1..3) as f64; // Teaching Factor (1 or 2)
                     let mut new_vars = Array1::zeros(dim);
 
@@ -57,7 +77,7 @@ impl GOTLBOSolver {
                         new_vars[j] = (ind.variables[j] + delta).clamp(lower[j], upper[j]);
                     }
 
-                    let new_fitness = problem.fitness(&new_vars);
+                    let new_fitness = problem.evaluate(&mut new_vars);
                     
                     // Accept if better
                     if new_fitness < ind.fitness {
@@ -71,7 +91,7 @@ impl GOTLBOSolver {
                         // O = a + b - X
                         opp_vars[j] = (lower[j] + upper[j] - ind.variables[j]).clamp(lower[j], upper[j]);
                     }
-                    let opp_fitness = problem.fitness(&opp_vars);
+                    let opp_fitness = problem.evaluate(&mut opp_vars);
 
                     if opp_fitness < ind.fitness {
                         ind.variables = opp_vars;
@@ -105,7 +125,7 @@ impl GOTLBOSolver {
                     new_vars[k] = (ind_i.variables[k] + delta).clamp(lower[k], upper[k]);
                 }
 
-                let new_fitness = problem.fitness(&new_vars);
+                let new_fitness = problem.evaluate(&mut new_vars);
                 if new_fitness < population[i].fitness {
                     population[i].variables = new_vars;
                     population[i].fitness = new_fitness;
@@ -117,7 +137,7 @@ impl GOTLBOSolver {
                 for k in 0..dim {
                     opp_vars[k] = (lower[k] + upper[k] - ind_i_curr.variables[k]).clamp(lower[k], upper[k]);
                 }
-                let opp_fitness = problem.fitness(&opp_vars);
+                let opp_fitness = problem.evaluate(&mut opp_vars);
 
                 if opp_fitness < population[i].fitness {
                     population[i].variables = opp_vars;
@@ -1,6 +1,7 @@
-use crate::common::{Individual, OptimizationResult, Problem, SolverConfig};
+use crate::common::{report_progress, ConvergenceTracker, Individual, OptimizationResult, Problem, SolverConfig};
 use ndarray::Array1;
 use rand::prelude::*;
+use std::ops::ControlFlow;
 
 pub struct GSASolver {
     pub config: SolverConfig,
@@ -18,7 +19,18 @@ impl GSASolver {
     }
 
     pub fn solve<P: Problem>(&self, problem: &P) -> OptimizationResult {
-        let mut rng = thread_rng();
+        self.solve_with_progress(problem, |_, _| ControlFlow::Continue(()))
+    }
+
+    /// Runs like [`solve`](Self::solve), calling `on_iteration(iteration, best)`
+    /// after each iteration; returning `ControlFlow::Break` stops the run and
+    /// returns the best found so far.
+    pub fn solve_with_progress<P, F>(&self, problem: &P, mut on_iteration: F) -> OptimizationResult
+    where
+        P: Problem,
+        F: FnMut(usize, f64) -> ControlFlow<()>,
+    {
+        let mut rng = self.config.rng();
         let dim = problem.dim();
         let (lower, upper) = problem.bounds();
         let n = self.config.population_size;
@@ -30,7 +42,7 @@ impl GSASolver {
                 for i in 0..dim {
                     vars[i] = rng.gen_range(lower[i]..upper[i]);
                 }
-                let fitness = problem.fitness(&vars);
+                let fitness = problem.evaluate(&mut vars);
                 Individual::new(vars, fitness)
             })
             .collect();
@@ -44,6 +56,7 @@ impl GSASolver {
         let mut best_vars = population[0].variables.clone();
         let mut best_fitness = population[0].fitness;
 
+        let mut convergence = ConvergenceTracker::new(&self.config);
         for iter in 0..self.config.max_iterations {
             // Update fitness and find best/worst
             let mut worst_fitness = population[0].fitness;
@@ -57,6 +70,9 @@ impl GSASolver {
                 }
             }
             history.push(best_fitness);
+            if report_progress(&mut on_iteration, &history) || convergence.converged(best_fitness) {
+                break;
+            }
 
             // 2. Calculate Masses
             let mut masses = vec![0.0; n];
@@ -105,7 +121,7 @@ impl GSASolver {
                     velocities[i][k] = rng.gen::<f64>() * velocities[i][k] + accelerations[i][k];
                     population[i].variables[k] = (population[i].variables[k] + velocities[i][k]).clamp(lower[k], upper[k]);
                 }
-                population[i].fitness = problem.fitness(&population[i].variables);
+                population[i].fitness = problem.evaluate(&mut population[i].variables);
             }
         }
 
@@ -1,6 +1,7 @@
-use crate::common::{Individual, OptimizationResult, Problem, SolverConfig};
+use crate::common::{report_progress, ConvergenceTracker, Individual, OptimizationResult, Problem, SolverConfig};
 use ndarray::Array1;
 use rand::prelude::*;
+use std::ops::ControlFlow;
 
 pub struct GWOSolver {
     pub config: SolverConfig,
@@ -12,7 +13,18 @@ impl GWOSolver {
     }
 
     pub fn solve<P: Problem>(&self, problem: &P) -> OptimizationResult {
-        let mut rng = thread_rng();
+        self.solve_with_progress(problem, |_, _| ControlFlow::Continue(()))
+    }
+
+    /// Runs like [`solve`](Self::solve), calling `on_iteration(iteration, best)`
+    /// after each iteration; returning `ControlFlow::Break` stops the run and
+    /// returns the best found so far.
+    pub fn solve_with_progress<P, F>(&self, problem: &P, mut on_iteration: F) -> OptimizationResult
+    where
+        P: Problem,
+        F: FnMut(usize, f64) -> ControlFlow<()>,
+    {
+        let mut rng = self.config.rng();
         let dim = problem.dim();
         let (lower, upper) = problem.bounds();
 
@@ -23,7 +35,7 @@ impl GWOSolver {
                 for i in 0..dim {
                     vars[i] = rng.gen_range(lower[i]..upper[i]);
                 }
-                let fitness = problem.fitness(&vars);
+                let fitness = problem.evaluate(&mut vars);
                 Individual::new(vars, fitness)
             })
             .collect();
@@ -40,6 +52,7 @@ impl GWOSolver {
 
         let mut history = Vec::with_capacity(self.config.max_iterations);
 
+        let mut convergence = ConvergenceTracker::new(&self.config);
         for iter in 0..self.config.max_iterations {
             // Update Alpha, Beta, Delta
             for ind in &population {
@@ -57,6 +70,9 @@ impl GWOSolver {
             }
 
             history.push(alpha.fitness);
+            if report_progress(&mut on_iteration, &history) || convergence.converged(alpha.fitness) {
+                break;
+            }
 
             let a = 2.0 - 2.0 * (iter as f64 / self.config.max_iterations as f64); // linearly decreases from 2 to 0
 
@@ -91,7 +107,7 @@ impl GWOSolver {
                 }
 
                 population[i].variables = new_vars;
-                population[i].fitness = problem.fitness(&population[i].variables);
+                population[i].fitness = problem.evaluate(&mut population[i].variables);
             }
         }
 
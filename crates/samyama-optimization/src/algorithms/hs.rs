@@ -1,6 +1,7 @@
-use crate::common::{Individual, OptimizationResult, Problem, SolverConfig};
+use crate::common::{report_progress, ConvergenceTracker, Individual, OptimizationResult, Problem, SolverConfig};
 use ndarray::Array1;
 use rand::prelude::*;
+use std::ops::ControlFlow;
 
 pub struct HSSolver {
     pub config: SolverConfig,
@@ -20,7 +21,18 @@ impl HSSolver {
     }
 
     pub fn solve<P: Problem>(&self, problem: &P) -> OptimizationResult {
-        let mut rng = thread_rng();
+        self.solve_with_progress(problem, |_, _| ControlFlow::Continue(()))
+    }
+
+    /// Runs like [`solve`](Self::solve), calling `on_iteration(iteration, best)`
+    /// after each iteration; returning `ControlFlow::Break` stops the run and
+    /// returns the best found so far.
+    pub fn solve_with_progress<P, F>(&self, problem: &P, mut on_iteration: F) -> OptimizationResult
+    where
+        P: Problem,
+        F: FnMut(usize, f64) -> ControlFlow<()>,
+    {
+        let mut rng = self.config.rng();
         let dim = problem.dim();
         let (lower, upper) = problem.bounds();
 
@@ -31,13 +43,14 @@ impl HSSolver {
                 for i in 0..dim {
                     vars[i] = rng.gen_range(lower[i]..upper[i]);
                 }
-                let fitness = problem.fitness(&vars);
+                let fitness = problem.evaluate(&mut vars);
                 Individual::new(vars, fitness)
             })
             .collect();
 
         let mut history = Vec::with_capacity(self.config.max_iterations);
 
+        let mut convergence = ConvergenceTracker::new(&self.config);
         for _iter in 0..self.config.max_iterations {
             // Find current best for history
             let mut best_idx = 0;
@@ -51,6 +64,9 @@ impl HSSolver {
                 }
             }
             history.push(hm[best_idx].fitness);
+            if report_progress(&mut on_iteration, &history) || convergence.converged(hm[best_idx].fitness) {
+                break;
+            }
 
             // 2. Improvise a New Harmony
             let mut new_vars = Array1::zeros(dim);
@@ -72,7 +88,7 @@ impl HSSolver {
                 }
             }
 
-            let new_fitness = problem.fitness(&new_vars);
+            let new_fitness = problem.evaluate(&mut new_vars);
 
             // 3. Update Harmony Memory
             if new_fitness < hm[worst_idx].fitness {
@@ -1,7 +1,10 @@
-use crate::common::{Individual, OptimizationResult, Problem, SolverConfig};
+use crate::common::{
+    report_progress, split_seeds, ConvergenceTracker, Individual, OptimizationResult, Problem, SolverConfig,
+};
 use ndarray::Array1;
 use rand::prelude::*;
 use rayon::prelude::*;
+use std::ops::ControlFlow;
 
 pub struct ITLBOSolver {
     pub config: SolverConfig,
@@ -15,7 +18,18 @@ impl ITLBOSolver {
     }
 
     pub fn solve<P: Problem>(&self, problem: &P) -> OptimizationResult {
-        let mut rng = thread_rng();
+        self.solve_with_progress(problem, |_, _| ControlFlow::Continue(()))
+    }
+
+    /// Runs like [`solve`](Self::solve), calling `on_iteration(iteration, best)`
+    /// after each iteration; returning `ControlFlow::Break` stops the run and
+    /// returns the best found so far.
+    pub fn solve_with_progress<P, F>(&self, problem: &P, mut on_iteration: F) -> OptimizationResult
+    where
+        P: Problem,
+        F: FnMut(usize, f64) -> ControlFlow<()>,
+    {
+        let mut rng = self.config.rng();
         let dim = problem.dim();
         let (lower, upper) = problem.bounds();
 
@@ -26,13 +40,14 @@ impl ITLBOSolver {
                 for i in 0..dim {
                     vars[i] = rng.gen_range(lower[i]..upper[i]);
                 }
-                let fitness = problem.fitness(&vars);
+                let fitness = problem.evaluate(&mut vars);
                 Individual::new(vars, fitness)
             })
             .collect();
 
         let mut history = Vec::with_capacity(self.config.max_iterations);
 
+        let mut convergence = ConvergenceTracker::new(&self.config);
         for iter in 0..self.config.max_iterations {
             if iter % 10 == 0 {
                 println!("ITLBO Solver: Iteration {}/{}", iter, self.config.max_iterations);
@@ -48,12 +63,17 @@ impl ITLBOSolver {
             let mean_vars = self.calculate_mean(&population, dim);
 
             history.push(best_fitness);
+            if report_progress(&mut on_iteration, &history) || convergence.converged(best_fitness) {
+                break;
+            }
 
             // 1. Teacher Phase
+            let seeds = split_seeds(&mut rng, population.len());
             population = population
                 .into_par_iter()
-                .map(|mut ind| {
-                    let mut local_rng = thread_rng();
+                .zip(seeds)
+                .map(|(mut ind, seed)| {
+                    let mut local_rng = StdRng::seed_from_u64(seed);
                     // Adaptive TF: Usually between 1 and 2. 
                     let tf: f64 = local_rng.gen_range(1.0..2.0); 
                     
@@ -64,7 +84,7 @@ impl ITLBOSolver {
                         new_vars[j] = (ind.variables[j] + delta).clamp(lower[j], upper[j]);
                     }
 
-                    let new_fitness = problem.fitness(&new_vars);
+                    let new_fitness = problem.evaluate(&mut new_vars);
                     if new_fitness < ind.fitness {
                         ind.variables = new_vars;
                         ind.fitness = new_fitness;
@@ -80,11 +100,12 @@ impl ITLBOSolver {
             // We'll clone the "old" population for reading to allow parallel updates.
             let old_population = population.clone();
             
+            let seeds = split_seeds(&mut rng, population.len());
             population = population
                 .into_par_iter()
                 .enumerate()
                 .map(|(i, mut ind)| {
-                    let mut local_rng = thread_rng();
+                    let mut local_rng = StdRng::seed_from_u64(seeds[i]);
                     
                     let mut learner_j_idx;
                     loop {
@@ -104,7 +125,7 @@ impl ITLBOSolver {
                         new_vars[k] = (ind.variables[k] + delta).clamp(lower[k], upper[k]);
                     }
 
-                    let new_fitness = problem.fitness(&new_vars);
+                    let new_fitness = problem.evaluate(&mut new_vars);
                     if new_fitness < ind.fitness {
                         ind.variables = new_vars;
                         ind.fitness = new_fitness;
@@ -13,12 +13,14 @@
 //! Total complexity: O(I·(M·c² + c·(m + tf + tp))) per the paper.
 
 use crate::common::{
-    MultiObjectiveIndividual, MultiObjectiveProblem, MultiObjectiveResult, SolverConfig,
+    report_progress, ConvergenceTracker, MultiObjectiveIndividual, MultiObjectiveProblem, MultiObjectiveResult,
+    SolverConfig,
 };
 use crate::moo::{evaluate_population, hypervolume_2d};
 use ndarray::Array1;
 use rand::prelude::*;
 use rand_distr::{Distribution, Normal};
+use std::ops::ControlFlow;
 
 #[derive(Debug, Clone, Copy)]
 pub enum MOBMWRVariant {
@@ -47,7 +49,18 @@ impl MOBMWRSolver {
     }
 
     pub fn solve<P: MultiObjectiveProblem>(&self, problem: &P) -> MultiObjectiveResult {
-        let mut rng = thread_rng();
+        self.solve_with_progress(problem, |_, _| ControlFlow::Continue(()))
+    }
+
+    /// Runs like [`solve`](Self::solve), calling `on_iteration(iteration, best)`
+    /// after each iteration; returning `ControlFlow::Break` stops the run and
+    /// returns the best found so far.
+    pub fn solve_with_progress<P, F>(&self, problem: &P, mut on_iteration: F) -> MultiObjectiveResult
+    where
+        P: MultiObjectiveProblem,
+        F: FnMut(usize, f64) -> ControlFlow<()>,
+    {
+        let mut rng = self.config.rng();
         let dim = problem.dim();
         let (lower, upper) = problem.bounds();
         let pop_size = self.config.population_size;
@@ -58,6 +71,7 @@ impl MOBMWRSolver {
                 for i in 0..dim {
                     vars[i] = rng.gen_range(lower[i]..upper[i]);
                 }
+                problem.snap(&mut vars);
                 let fitness = problem.objectives(&vars);
                 let viol: f64 = problem.penalties(&vars).iter().sum();
                 MultiObjectiveIndividual::new(vars, fitness, viol)
@@ -67,6 +81,7 @@ impl MOBMWRSolver {
 
         let mut history = Vec::with_capacity(self.config.max_iterations);
 
+        let mut convergence = ConvergenceTracker::new(&self.config);
         for iter in 0..self.config.max_iterations {
             if iter % 10 == 0 {
                 println!(
@@ -103,17 +118,16 @@ impl MOBMWRSolver {
             // --- Generate offspring via base update.
             let mut offspring: Vec<MultiObjectiveIndividual> = Vec::with_capacity(pop_size);
             for k in 0..pop_size {
-                let mut local_rng = thread_rng();
-                let r1: f64 = local_rng.gen();
-                let r2: f64 = local_rng.gen();
-                let r3: f64 = local_rng.gen();
-                let r4: f64 = local_rng.gen();
-                let r5: f64 = local_rng.gen();
-                let t: f64 = local_rng.gen_range(1..3) as f64;
+                let r1: f64 = rng.gen();
+                let r2: f64 = rng.gen();
+                let r3: f64 = rng.gen();
+                let r4: f64 = rng.gen();
+                let r5: f64 = rng.gen();
+                let t: f64 = rng.gen_range(1..3) as f64;
 
                 let mut new_vars = Array1::zeros(dim);
                 if r4 > 0.5 {
-                    let mut rk = local_rng.gen_range(0..pop_size);
+                    let mut rk = rng.gen_range(0..pop_size);
                     if rk == k && pop_size > 1 {
                         rk = (rk + 1) % pop_size;
                     }
@@ -146,6 +160,7 @@ impl MOBMWRSolver {
 
                 // Constraint repair: clip to bounds (already done) — for box constraints
                 // this is full repair. For inequality penalties, fall through to penalty.
+                problem.snap(&mut new_vars);
                 let fit = problem.objectives(&new_vars);
                 let viol: f64 = problem.penalties(&new_vars).iter().sum();
                 offspring.push(MultiObjectiveIndividual::new(new_vars, fit, viol));
@@ -161,6 +176,7 @@ impl MOBMWRSolver {
                     new_vars[j] = (new_vars[j] + sigma * normal.sample(&mut rng))
                         .clamp(lower[j], upper[j]);
                 }
+                problem.snap(&mut new_vars);
                 let fit = problem.objectives(&new_vars);
                 let viol: f64 = problem.penalties(&new_vars).iter().sum();
                 offspring.push(MultiObjectiveIndividual::new(new_vars, fit, viol));
@@ -185,6 +201,7 @@ impl MOBMWRSolver {
                         new_vars[j] = (new_vars[j] + sigma * normal.sample(&mut rng))
                             .clamp(lower[j], upper[j]);
                     }
+                    problem.snap(&mut new_vars);
                     let fit = problem.objectives(&new_vars);
                     let viol: f64 = problem.penalties(&new_vars).iter().sum();
                     offspring.push(MultiObjectiveIndividual::new(new_vars, fit, viol));
@@ -231,6 +248,12 @@ impl MOBMWRSolver {
                     .fold(f64::INFINITY, f64::min)
             };
             history.push(hist_val);
+            // Hypervolume grows as the front improves; the tracker expects a
+            // value to minimize.
+            let progress = if problem.num_objectives() == 2 { -hist_val } else { hist_val };
+            if report_progress(&mut on_iteration, &history) || convergence.converged(progress) {
+                break;
+            }
         }
 
         MultiObjectiveResult {
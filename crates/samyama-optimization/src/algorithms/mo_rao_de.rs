@@ -6,11 +6,13 @@
 //! best and worst-rank worst. Selection by NSGA-II-style FNDS + crowding.
 
 use crate::common::{
-    MultiObjectiveIndividual, MultiObjectiveProblem, MultiObjectiveResult, SolverConfig,
+    report_progress, ConvergenceTracker, MultiObjectiveIndividual, MultiObjectiveProblem, MultiObjectiveResult,
+    SolverConfig,
 };
 use crate::moo::evaluate_population;
 use ndarray::Array1;
 use rand::prelude::*;
+use std::ops::ControlFlow;
 
 pub struct MORaoDESolver {
     pub config: SolverConfig,
@@ -33,7 +35,18 @@ impl MORaoDESolver {
     }
 
     pub fn solve<P: MultiObjectiveProblem>(&self, problem: &P) -> MultiObjectiveResult {
-        let mut rng = thread_rng();
+        self.solve_with_progress(problem, |_, _| ControlFlow::Continue(()))
+    }
+
+    /// Runs like [`solve`](Self::solve), calling `on_iteration(iteration, best)`
+    /// after each iteration; returning `ControlFlow::Break` stops the run and
+    /// returns the best found so far.
+    pub fn solve_with_progress<P, F>(&self, problem: &P, mut on_iteration: F) -> MultiObjectiveResult
+    where
+        P: MultiObjectiveProblem,
+        F: FnMut(usize, f64) -> ControlFlow<()>,
+    {
+        let mut rng = self.config.rng();
         let dim = problem.dim();
         let (lower, upper) = problem.bounds();
         let pop_size = self.config.population_size;
@@ -44,6 +57,7 @@ impl MORaoDESolver {
                 for i in 0..dim {
                     vars[i] = rng.gen_range(lower[i]..upper[i]);
                 }
+                problem.snap(&mut vars);
                 let fitness = problem.objectives(&vars);
                 let viol: f64 = problem.penalties(&vars).iter().sum();
                 MultiObjectiveIndividual::new(vars, fitness, viol)
@@ -53,6 +67,7 @@ impl MORaoDESolver {
 
         let mut history = Vec::with_capacity(self.config.max_iterations);
 
+        let mut convergence = ConvergenceTracker::new(&self.config);
         for iter in 0..self.config.max_iterations {
             if iter % 10 == 0 {
                 println!(
@@ -105,6 +120,7 @@ impl MORaoDESolver {
                         new_vars[j] = val.clamp(lower[j], upper[j]);
                     }
                 }
+                problem.snap(&mut new_vars);
                 let fit = problem.objectives(&new_vars);
                 let viol: f64 = problem.penalties(&new_vars).iter().sum();
                 offspring.push(MultiObjectiveIndividual::new(new_vars, fit, viol));
@@ -123,13 +139,15 @@ impl MORaoDESolver {
             });
             population.truncate(pop_size);
 
-            history.push(
-                population
-                    .iter()
-                    .filter(|i| i.rank == 0)
-                    .map(|i| i.fitness[0])
-                    .fold(f64::INFINITY, f64::min),
-            );
+            let best_first = population
+                .iter()
+                .filter(|i| i.rank == 0)
+                .map(|i| i.fitness[0])
+                .fold(f64::INFINITY, f64::min);
+            history.push(best_first);
+            if report_progress(&mut on_iteration, &history) || convergence.converged(best_first) {
+                break;
+            }
         }
 
         MultiObjectiveResult {
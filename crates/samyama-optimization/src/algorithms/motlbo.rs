@@ -1,6 +1,7 @@
-use crate::common::{MultiObjectiveIndividual, MultiObjectiveProblem, MultiObjectiveResult, SolverConfig};
+use crate::common::{report_progress, ConvergenceTracker, MultiObjectiveIndividual, MultiObjectiveProblem, MultiObjectiveResult, SolverConfig};
 use ndarray::Array1;
 use rand::prelude::*;
+use std::ops::ControlFlow;
 
 pub struct MOTLBOSolver {
     pub config: SolverConfig,
@@ -12,7 +13,18 @@ impl MOTLBOSolver {
     }
 
     pub fn solve<P: MultiObjectiveProblem>(&self, problem: &P) -> MultiObjectiveResult {
-        let mut rng = thread_rng();
+        self.solve_with_progress(problem, |_, _| ControlFlow::Continue(()))
+    }
+
+    /// Runs like [`solve`](Self::solve), calling `on_iteration(iteration, best)`
+    /// after each iteration; returning `ControlFlow::Break` stops the run and
+    /// returns the best found so far.
+    pub fn solve_with_progress<P, F>(&self, problem: &P, mut on_iteration: F) -> MultiObjectiveResult
+    where
+        P: MultiObjectiveProblem,
+        F: FnMut(usize, f64) -> ControlFlow<()>,
+    {
+        let mut rng = self.config.rng();
         let dim = problem.dim();
         let (lower, upper) = problem.bounds();
         let pop_size = self.config.population_size;
@@ -24,6 +36,7 @@ impl MOTLBOSolver {
                 for i in 0..dim {
                     vars[i] = rng.gen_range(lower[i]..upper[i]);
                 }
+                problem.snap(&mut vars);
                 let fitness = problem.objectives(&vars);
                 let penalties = problem.penalties(&vars);
                 let violation: f64 = penalties.iter().sum();
@@ -35,23 +48,24 @@ impl MOTLBOSolver {
 
         let mut history = Vec::with_capacity(self.config.max_iterations);
 
+        let mut convergence = ConvergenceTracker::new(&self.config);
         for _iter in 0..self.config.max_iterations {
             let mut offspring = Vec::with_capacity(pop_size * 2);
 
             // Teacher Phase
-            let teacher_idx = self.select_teacher(&population);
+            let teacher_idx = self.select_teacher(&population, &mut rng);
             let teacher_vars = population[teacher_idx].variables.clone();
             let mean_vars = self.calculate_mean(&population, dim);
 
             for ind in &population {
-                let mut local_rng = thread_rng();
-                let tf: f64 = local_rng.gen_range(1..3) as f64;
+                let tf: f64 = rng.gen_range(1..3) as f64;
                 let mut new_vars = Array1::zeros(dim);
                 for j in 0..dim {
-                    let r: f64 = local_rng.gen();
+                    let r: f64 = rng.gen();
                     new_vars[j] = (ind.variables[j] + r * (teacher_vars[j] - tf * mean_vars[j])).clamp(lower[j], upper[j]);
                 }
                 
+                problem.snap(&mut new_vars);
                 let fitness = problem.objectives(&new_vars);
                 let penalties = problem.penalties(&new_vars);
                 let violation: f64 = penalties.iter().sum();
@@ -60,10 +74,9 @@ impl MOTLBOSolver {
 
             // Learner Phase
             for i in 0..pop_size {
-                let mut local_rng = thread_rng();
                 let mut j;
                 loop {
-                    j = local_rng.gen_range(0..pop_size);
+                    j = rng.gen_range(0..pop_size);
                     if j != i { break; }
                 }
 
@@ -78,7 +91,7 @@ impl MOTLBOSolver {
                 );
 
                 for k in 0..dim {
-                    let r: f64 = local_rng.gen();
+                    let r: f64 = rng.gen();
                     if dominates_i_j {
                         new_vars[k] = (population[i].variables[k] + r * (population[i].variables[k] - population[j].variables[k])).clamp(lower[k], upper[k]);
                     } else if dominates_j_i {
@@ -89,6 +102,7 @@ impl MOTLBOSolver {
                     }
                 }
                 
+                problem.snap(&mut new_vars);
                 let fitness = problem.objectives(&new_vars);
                 let penalties = problem.penalties(&new_vars);
                 let violation: f64 = penalties.iter().sum();
@@ -113,6 +127,9 @@ impl MOTLBOSolver {
             population = combined;
             
             history.push(population[0].fitness[0]);
+            if report_progress(&mut on_iteration, &history) || convergence.converged(population[0].fitness[0]) {
+                break;
+            }
         }
 
         MultiObjectiveResult {
@@ -121,15 +138,14 @@ impl MOTLBOSolver {
         }
     }
 
-    fn select_teacher(&self, population: &[MultiObjectiveIndividual]) -> usize {
+    fn select_teacher<R: Rng>(&self, population: &[MultiObjectiveIndividual], rng: &mut R) -> usize {
         // Teacher is chosen from the first rank (best non-dominated front)
         let first_rank: Vec<usize> = population.iter().enumerate()
             .filter(|(_, ind)| ind.rank == 0)
             .map(|(i, _)| i)
             .collect();
         
-        let mut rng = thread_rng();
-        *first_rank.choose(&mut rng).unwrap_or(&0)
+        *first_rank.choose(rng).unwrap_or(&0)
     }
 
     fn calculate_mean(&self, population: &[MultiObjectiveIndividual], dim: usize) -> Array1<f64> {
@@ -1,6 +1,7 @@
-use crate::common::{MultiObjectiveIndividual, MultiObjectiveProblem, MultiObjectiveResult, SolverConfig};
+use crate::common::{report_progress, ConvergenceTracker, MultiObjectiveIndividual, MultiObjectiveProblem, MultiObjectiveResult, SolverConfig};
 use ndarray::Array1;
 use rand::prelude::*;
+use std::ops::ControlFlow;
 
 pub struct NSGA2Solver {
     pub config: SolverConfig,
@@ -16,7 +17,18 @@ impl NSGA2Solver {
     }
 
     pub fn solve<P: MultiObjectiveProblem>(&self, problem: &P) -> MultiObjectiveResult {
-        let mut rng = thread_rng();
+        self.solve_with_progress(problem, |_, _| ControlFlow::Continue(()))
+    }
+
+    /// Runs like [`solve`](Self::solve), calling `on_iteration(iteration, best)`
+    /// after each iteration; returning `ControlFlow::Break` stops the run and
+    /// returns the best found so far.
+    pub fn solve_with_progress<P, F>(&self, problem: &P, mut on_iteration: F) -> MultiObjectiveResult
+    where
+        P: MultiObjectiveProblem,
+        F: FnMut(usize, f64) -> ControlFlow<()>,
+    {
+        let mut rng = self.config.rng();
         let dim = problem.dim();
         let (lower, upper) = problem.bounds();
         let pop_size = self.config.population_size;
@@ -28,6 +40,7 @@ impl NSGA2Solver {
                 for i in 0..dim {
                     vars[i] = rng.gen_range(lower[i]..upper[i]);
                 }
+                problem.snap(&mut vars);
                 let fitness = problem.objectives(&vars);
                 let penalties = problem.penalties(&vars);
                 let violation: f64 = penalties.iter().sum();
@@ -39,22 +52,25 @@ impl NSGA2Solver {
 
         let mut history = Vec::with_capacity(self.config.max_iterations);
 
+        let mut convergence = ConvergenceTracker::new(&self.config);
         for _iter in 0..self.config.max_iterations {
             // 2. Create Offspring (Crossover + Mutation)
             let mut offspring = Vec::with_capacity(pop_size);
             while offspring.len() < pop_size {
-                let p1 = self.tournament_select(&population);
-                let p2 = self.tournament_select(&population);
+                let p1 = self.tournament_select(&population, &mut rng);
+                let p2 = self.tournament_select(&population, &mut rng);
                 
-                let (mut c1_vars, mut c2_vars) = self.crossover(&p1.variables, &p2.variables);
-                self.mutate(&mut c1_vars, &lower, &upper);
-                self.mutate(&mut c2_vars, &lower, &upper);
+                let (mut c1_vars, mut c2_vars) = self.crossover(&p1.variables, &p2.variables, &mut rng);
+                self.mutate(&mut c1_vars, &lower, &upper, &mut rng);
+                self.mutate(&mut c2_vars, &lower, &upper, &mut rng);
                 
+                problem.snap(&mut c1_vars);
                 let f1 = problem.objectives(&c1_vars);
                 let v1 = problem.penalties(&c1_vars).iter().sum();
                 offspring.push(MultiObjectiveIndividual::new(c1_vars.clone(), f1, v1));
 
                 if offspring.len() < pop_size {
+                    problem.snap(&mut c2_vars);
                     let f2 = problem.objectives(&c2_vars);
                     let v2 = problem.penalties(&c2_vars).iter().sum();
                     offspring.push(MultiObjectiveIndividual::new(c2_vars.clone(), f2, v2));
@@ -82,6 +98,9 @@ impl NSGA2Solver {
             population = combined;
             
             history.push(population[0].fitness[0]); // Track first objective of best-ranked
+            if report_progress(&mut on_iteration, &history) || convergence.converged(population[0].fitness[0]) {
+                break;
+            }
         }
 
         MultiObjectiveResult {
@@ -204,8 +223,7 @@ impl NSGA2Solver {
         }
     }
 
-    fn tournament_select<'a>(&self, population: &'a [MultiObjectiveIndividual]) -> &'a MultiObjectiveIndividual {
-        let mut rng = thread_rng();
+    fn tournament_select<'a, R: Rng>(&self, population: &'a [MultiObjectiveIndividual], rng: &mut R) -> &'a MultiObjectiveIndividual {
         let i1 = rng.gen_range(0..population.len());
         let i2 = rng.gen_range(0..population.len());
         
@@ -218,8 +236,7 @@ impl NSGA2Solver {
         else { p2 }
     }
 
-    fn crossover(&self, p1: &Array1<f64>, p2: &Array1<f64>) -> (Array1<f64>, Array1<f64>) {
-        let mut rng = thread_rng();
+    fn crossover<R: Rng>(&self, p1: &Array1<f64>, p2: &Array1<f64>, rng: &mut R) -> (Array1<f64>, Array1<f64>) {
         let dim = p1.len();
         let mut c1 = p1.clone();
         let mut c2 = p2.clone();
@@ -244,8 +261,7 @@ impl NSGA2Solver {
         (c1, c2)
     }
 
-    fn mutate(&self, vars: &mut Array1<f64>, lower: &Array1<f64>, upper: &Array1<f64>) {
-        let mut rng = thread_rng();
+    fn mutate<R: Rng>(&self, vars: &mut Array1<f64>, lower: &Array1<f64>, upper: &Array1<f64>, rng: &mut R) {
         for i in 0..vars.len() {
             if rng.gen::<f64>() < self.mutation_rate {
                 let range = upper[i] - lower[i];
@@ -1,7 +1,10 @@
-use crate::common::{Individual, OptimizationResult, Problem, SolverConfig};
+use crate::common::{
+    report_progress, split_seeds, ConvergenceTracker, Individual, OptimizationResult, Problem, SolverConfig,
+};
 use ndarray::Array1;
 use rand::prelude::*;
 use rayon::prelude::*;
+use std::ops::ControlFlow;
 
 pub struct PSOSolver {
     pub config: SolverConfig,
@@ -21,7 +24,18 @@ impl PSOSolver {
     }
 
     pub fn solve<P: Problem>(&self, problem: &P) -> OptimizationResult {
-        let mut rng = thread_rng();
+        self.solve_with_progress(problem, |_, _| ControlFlow::Continue(()))
+    }
+
+    /// Runs like [`solve`](Self::solve), calling `on_iteration(iteration, best)`
+    /// after each iteration; returning `ControlFlow::Break` stops the run and
+    /// returns the best found so far.
+    pub fn solve_with_progress<P, F>(&self, problem: &P, mut on_iteration: F) -> OptimizationResult
+    where
+        P: Problem,
+        F: FnMut(usize, f64) -> ControlFlow<()>,
+    {
+        let mut rng = self.config.rng();
         let dim = problem.dim();
         let (lower, upper) = problem.bounds();
 
@@ -32,7 +46,7 @@ impl PSOSolver {
                 for i in 0..dim {
                     vars[i] = rng.gen_range(lower[i]..upper[i]);
                 }
-                let fitness = problem.fitness(&vars);
+                let fitness = problem.evaluate(&mut vars);
                 Individual::new(vars, fitness)
             })
             .collect();
@@ -51,12 +65,16 @@ impl PSOSolver {
 
         let mut history = Vec::with_capacity(self.config.max_iterations);
 
+        let mut convergence = ConvergenceTracker::new(&self.config);
         for iter in 0..self.config.max_iterations {
             if iter % 10 == 0 {
                 println!("PSO Solver: Iteration {}/{}", iter, self.config.max_iterations);
             }
             
             history.push(gbest.fitness);
+            if report_progress(&mut on_iteration, &history) || convergence.converged(gbest.fitness) {
+                break;
+            }
 
             // Update swarm
             // Note: In parallel, we need to collect updates then apply? 
@@ -65,9 +83,10 @@ impl PSOSolver {
             // Updating positions requires mutable access to swarm[i].
             
             // We'll compute new state in parallel and then replace.
-            let results: Vec<(Individual, Array1<f64>, Individual)> = swarm.par_iter().zip(velocities.par_iter()).zip(pbests.par_iter())
-                .map(|((particle, velocity), pbest)| {
-                    let mut local_rng = thread_rng();
+            let seeds = split_seeds(&mut rng, swarm.len());
+            let results: Vec<(Individual, Array1<f64>, Individual)> = swarm.par_iter().zip(velocities.par_iter()).zip(pbests.par_iter()).zip(seeds)
+                .map(|(((particle, velocity), pbest), seed)| {
+                    let mut local_rng = StdRng::seed_from_u64(seed);
                     let mut new_vel = Array1::zeros(dim);
                     let mut new_vars = Array1::zeros(dim);
 
@@ -83,7 +102,7 @@ impl PSOSolver {
                         new_vars[j] = (particle.variables[j] + v).clamp(lower[j], upper[j]);
                     }
 
-                    let new_fitness = problem.fitness(&new_vars);
+                    let new_fitness = problem.evaluate(&mut new_vars);
                     let new_ind = Individual::new(new_vars, new_fitness);
                     
                     let new_pbest = if new_fitness < pbest.fitness {
@@ -12,11 +12,14 @@
 //! Reference: Rao & Saroj (2020) "Quasi-oppositional-based Rao algorithms for
 //! multi-objective design optimization of selected heat sinks" (JCDE).
 
-use crate::common::{Individual, OptimizationResult, Problem, SolverConfig};
+use crate::common::{
+    report_progress, split_seeds, ConvergenceTracker, Individual, OptimizationResult, Problem, SolverConfig,
+};
 use super::rao::RaoVariant;
 use ndarray::Array1;
 use rand::prelude::*;
 use rayon::prelude::*;
+use std::ops::ControlFlow;
 
 pub struct QORaoSolver {
     pub config: SolverConfig,
@@ -29,7 +32,18 @@ impl QORaoSolver {
     }
 
     pub fn solve<P: Problem>(&self, problem: &P) -> OptimizationResult {
-        let mut rng = thread_rng();
+        self.solve_with_progress(problem, |_, _| ControlFlow::Continue(()))
+    }
+
+    /// Runs like [`solve`](Self::solve), calling `on_iteration(iteration, best)`
+    /// after each iteration; returning `ControlFlow::Break` stops the run and
+    /// returns the best found so far.
+    pub fn solve_with_progress<P, F>(&self, problem: &P, mut on_iteration: F) -> OptimizationResult
+    where
+        P: Problem,
+        F: FnMut(usize, f64) -> ControlFlow<()>,
+    {
+        let mut rng = self.config.rng();
         let dim = problem.dim();
         let (lower, upper) = problem.bounds();
         let pop_size = self.config.population_size;
@@ -40,18 +54,20 @@ impl QORaoSolver {
                 for i in 0..dim {
                     vars[i] = rng.gen_range(lower[i]..upper[i]);
                 }
-                let fitness = problem.fitness(&vars);
+                let fitness = problem.evaluate(&mut vars);
                 Individual::new(vars, fitness)
             })
             .collect();
 
         // Initial QOBL seeding: combine population + quasi-opposite, keep best N.
+        let seeds = split_seeds(&mut rng, population.len());
         let qo_init: Vec<Individual> = population
             .par_iter()
-            .map(|ind| {
-                let mut local_rng = thread_rng();
-                let qo_vars = quasi_oppose(&ind.variables, &lower, &upper, &mut local_rng);
-                let fitness = problem.fitness(&qo_vars);
+            .zip(seeds)
+            .map(|(ind, seed)| {
+                let mut local_rng = StdRng::seed_from_u64(seed);
+                let mut qo_vars = quasi_oppose(&ind.variables, &lower, &upper, &mut local_rng);
+                let fitness = problem.evaluate(&mut qo_vars);
                 Individual::new(qo_vars, fitness)
             })
             .collect();
@@ -61,6 +77,7 @@ impl QORaoSolver {
 
         let mut history = Vec::with_capacity(self.config.max_iterations);
 
+        let mut convergence = ConvergenceTracker::new(&self.config);
         for iter in 0..self.config.max_iterations {
             if iter % 10 == 0 {
                 println!(
@@ -72,11 +89,16 @@ impl QORaoSolver {
             let best_vars = population[best_idx].variables.clone();
             let worst_vars = population[worst_idx].variables.clone();
             history.push(population[best_idx].fitness);
+            if report_progress(&mut on_iteration, &history) || convergence.converged(population[best_idx].fitness) {
+                break;
+            }
 
+            let seeds = split_seeds(&mut rng, population.len());
             population = population
                 .into_par_iter()
-                .map(|mut ind| {
-                    let mut local_rng = thread_rng();
+                .zip(seeds)
+                .map(|(mut ind, seed)| {
+                    let mut local_rng = StdRng::seed_from_u64(seed);
                     let r1: f64 = local_rng.gen();
                     let r2: f64 = local_rng.gen();
 
@@ -90,7 +112,7 @@ impl QORaoSolver {
                         }
                     }
                     let rand_fitness = if need_rand {
-                        problem.fitness(&rand_vars)
+                        problem.evaluate(&mut rand_vars)
                     } else {
                         0.0
                     };
@@ -121,15 +143,15 @@ impl QORaoSolver {
                         };
                         new_vars[j] = (ind.variables[j] + delta).clamp(lower[j], upper[j]);
                     }
-                    let rao_fitness = problem.fitness(&new_vars);
+                    let rao_fitness = problem.evaluate(&mut new_vars);
                     if rao_fitness < ind.fitness {
                         ind.variables = new_vars;
                         ind.fitness = rao_fitness;
                     }
 
                     // 2. QOBL on the (possibly updated) individual
-                    let qo_vars = quasi_oppose(&ind.variables, &lower, &upper, &mut local_rng);
-                    let qo_fitness = problem.fitness(&qo_vars);
+                    let mut qo_vars = quasi_oppose(&ind.variables, &lower, &upper, &mut local_rng);
+                    let qo_fitness = problem.evaluate(&mut qo_vars);
                     if qo_fitness < ind.fitness {
                         ind.variables = qo_vars;
                         ind.fitness = qo_fitness;
@@ -1,7 +1,10 @@
-use crate::common::{Individual, OptimizationResult, Problem, SolverConfig};
+use crate::common::{
+    report_progress, split_seeds, ConvergenceTracker, Individual, OptimizationResult, Problem, SolverConfig,
+};
 use ndarray::Array1;
 use rand::prelude::*;
 use rayon::prelude::*;
+use std::ops::ControlFlow;
 
 pub struct QOJayaSolver {
     pub config: SolverConfig,
@@ -13,7 +16,18 @@ impl QOJayaSolver {
     }
 
     pub fn solve<P: Problem>(&self, problem: &P) -> OptimizationResult {
-        let mut rng = thread_rng();
+        self.solve_with_progress(problem, |_, _| ControlFlow::Continue(()))
+    }
+
+    /// Runs like [`solve`](Self::solve), calling `on_iteration(iteration, best)`
+    /// after each iteration; returning `ControlFlow::Break` stops the run and
+    /// returns the best found so far.
+    pub fn solve_with_progress<P, F>(&self, problem: &P, mut on_iteration: F) -> OptimizationResult
+    where
+        P: Problem,
+        F: FnMut(usize, f64) -> ControlFlow<()>,
+    {
+        let mut rng = self.config.rng();
         let dim = problem.dim();
         let (lower, upper) = problem.bounds();
 
@@ -24,16 +38,17 @@ impl QOJayaSolver {
                 for i in 0..dim {
                     vars[i] = rng.gen_range(lower[i]..upper[i]);
                 }
-                let fitness = problem.fitness(&vars);
+                let fitness = problem.evaluate(&mut vars);
                 Individual::new(vars, fitness)
             })
             .collect();
 
         // Apply Quasi-Oppositional Based Learning (QOBL) to initial population
         // Generate QO population and pick best N
-        let mut qo_population: Vec<Individual> = population.par_iter().map(|ind| {
+        let seeds = split_seeds(&mut rng, population.len());
+        let mut qo_population: Vec<Individual> = population.par_iter().zip(seeds).map(|(ind, seed)| {
             let mut new_vars = Array1::zeros(dim);
-            let mut local_rng = thread_rng();
+            let mut local_rng = StdRng::seed_from_u64(seed);
             
             for j in 0..dim {
                 // Center point c = (a+b)/2
@@ -51,7 +66,7 @@ impl QOJayaSolver {
                 new_vars[j] = xqo.clamp(lower[j], upper[j]);
             }
             
-            let fitness = problem.fitness(&new_vars);
+            let fitness = problem.evaluate(&mut new_vars);
             Individual::new(new_vars, fitness)
         }).collect();
         
@@ -61,6 +76,7 @@ impl QOJayaSolver {
 
         let mut history = Vec::with_capacity(self.config.max_iterations);
 
+        let mut convergence = ConvergenceTracker::new(&self.config);
         for iter in 0..self.config.max_iterations {
             if iter % 10 == 0 {
                 println!("QOJaya Solver: Iteration {}/{}", iter, self.config.max_iterations);
@@ -71,12 +87,17 @@ impl QOJayaSolver {
             let best_fitness = population[best_idx].fitness;
 
             history.push(best_fitness);
+            if report_progress(&mut on_iteration, &history) || convergence.converged(best_fitness) {
+                break;
+            }
 
             // Jaya Update + QOBL
+            let seeds = split_seeds(&mut rng, population.len());
             population = population
                 .into_par_iter()
-                .map(|mut ind| {
-                    let mut local_rng = thread_rng();
+                .zip(seeds)
+                .map(|(mut ind, seed)| {
+                    let mut local_rng = StdRng::seed_from_u64(seed);
                     let mut new_vars = Array1::zeros(dim);
 
                     let r1: f64 = local_rng.gen();
@@ -90,7 +111,7 @@ impl QOJayaSolver {
                         new_vars[j] = val.clamp(lower[j], upper[j]);
                     }
 
-                    let jaya_fitness = problem.fitness(&new_vars);
+                    let jaya_fitness = problem.evaluate(&mut new_vars);
                     if jaya_fitness < ind.fitness {
                         ind.variables = new_vars.clone();
                         ind.fitness = jaya_fitness;
@@ -113,7 +134,7 @@ impl QOJayaSolver {
                         qo_vars[j] = xqo.clamp(lower[j], upper[j]);
                     }
                     
-                    let qo_fitness = problem.fitness(&qo_vars);
+                    let qo_fitness = problem.evaluate(&mut qo_vars);
                     if qo_fitness < ind.fitness {
                         ind.variables = qo_vars;
                         ind.fitness = qo_fitness;
@@ -1,6 +1,7 @@
-use crate::common::{OptimizationResult, Problem, SolverConfig};
+use crate::common::{report_progress, ConvergenceTracker, OptimizationResult, Problem, SolverConfig};
 use ndarray::Array1;
 use rand::prelude::*;
+use std::ops::ControlFlow;
 
 pub struct SASolver {
     pub config: SolverConfig,
@@ -18,7 +19,18 @@ impl SASolver {
     }
 
     pub fn solve<P: Problem>(&self, problem: &P) -> OptimizationResult {
-        let mut rng = thread_rng();
+        self.solve_with_progress(problem, |_, _| ControlFlow::Continue(()))
+    }
+
+    /// Runs like [`solve`](Self::solve), calling `on_iteration(iteration, best)`
+    /// after each iteration; returning `ControlFlow::Break` stops the run and
+    /// returns the best found so far.
+    pub fn solve_with_progress<P, F>(&self, problem: &P, mut on_iteration: F) -> OptimizationResult
+    where
+        P: Problem,
+        F: FnMut(usize, f64) -> ControlFlow<()>,
+    {
+        let mut rng = self.config.rng();
         let dim = problem.dim();
         let (lower, upper) = problem.bounds();
 
@@ -27,7 +39,7 @@ impl SASolver {
         for i in 0..dim {
             current_vars[i] = rng.gen_range(lower[i]..upper[i]);
         }
-        let mut current_fitness = problem.fitness(&current_vars);
+        let mut current_fitness = problem.evaluate(&mut current_vars);
 
         let mut best_vars = current_vars.clone();
         let mut best_fitness = current_fitness;
@@ -43,8 +55,12 @@ impl SASolver {
         
         let total_steps = self.config.max_iterations;
 
+        let mut convergence = ConvergenceTracker::new(&self.config);
         for _step in 0..total_steps {
             history.push(best_fitness);
+            if report_progress(&mut on_iteration, &history) || convergence.converged(best_fitness) {
+                break;
+            }
 
             // Generate neighbor
             let mut next_vars = current_vars.clone();
@@ -55,7 +71,7 @@ impl SASolver {
                 next_vars[i] = (next_vars[i] + delta).clamp(lower[i], upper[i]);
             }
 
-            let next_fitness = problem.fitness(&next_vars);
+            let next_fitness = problem.evaluate(&mut next_vars);
 
             // Acceptance probability
             let delta_e = next_fitness - current_fitness;
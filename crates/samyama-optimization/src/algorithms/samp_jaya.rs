@@ -7,10 +7,13 @@
 //!   - else → decrease m by 1 (more exploitation, larger sub-pops)
 //! Bounds: m ∈ [1, m_max] where m_max defaults to floor(pop/4).
 
-use crate::common::{Individual, OptimizationResult, Problem, SolverConfig};
+use crate::common::{
+    report_progress, split_seeds, ConvergenceTracker, Individual, OptimizationResult, Problem, SolverConfig,
+};
 use ndarray::Array1;
 use rand::prelude::*;
 use rayon::prelude::*;
+use std::ops::ControlFlow;
 
 pub struct SAMPJayaSolver {
     pub config: SolverConfig,
@@ -23,7 +26,18 @@ impl SAMPJayaSolver {
     }
 
     pub fn solve<P: Problem>(&self, problem: &P) -> OptimizationResult {
-        let mut rng = thread_rng();
+        self.solve_with_progress(problem, |_, _| ControlFlow::Continue(()))
+    }
+
+    /// Runs like [`solve`](Self::solve), calling `on_iteration(iteration, best)`
+    /// after each iteration; returning `ControlFlow::Break` stops the run and
+    /// returns the best found so far.
+    pub fn solve_with_progress<P, F>(&self, problem: &P, mut on_iteration: F) -> OptimizationResult
+    where
+        P: Problem,
+        F: FnMut(usize, f64) -> ControlFlow<()>,
+    {
+        let mut rng = self.config.rng();
         let dim = problem.dim();
         let (lower, upper) = problem.bounds();
         let pop_size = self.config.population_size;
@@ -35,7 +49,7 @@ impl SAMPJayaSolver {
                 for i in 0..dim {
                     vars[i] = rng.gen_range(lower[i]..upper[i]);
                 }
-                let fitness = problem.fitness(&vars);
+                let fitness = problem.evaluate(&mut vars);
                 Individual::new(vars, fitness)
             })
             .collect();
@@ -47,6 +61,7 @@ impl SAMPJayaSolver {
             .map(|ind| ind.fitness)
             .fold(f64::INFINITY, f64::min);
 
+        let mut convergence = ConvergenceTracker::new(&self.config);
         for iter in 0..self.config.max_iterations {
             if iter % 10 == 0 {
                 println!(
@@ -64,10 +79,12 @@ impl SAMPJayaSolver {
                 let (sb, sw) = find_best_worst(sub);
                 let sb_vars = sub[sb].variables.clone();
                 let sw_vars = sub[sw].variables.clone();
+                let seeds = split_seeds(&mut rng, sub.len());
                 let updated: Vec<Individual> = sub
                     .par_iter()
-                    .map(|ind| {
-                        let mut local_rng = thread_rng();
+                    .zip(seeds)
+                    .map(|(ind, seed)| {
+                        let mut local_rng = StdRng::seed_from_u64(seed);
                         let r1: f64 = local_rng.gen();
                         let r2: f64 = local_rng.gen();
                         let mut new_vars = Array1::zeros(dim);
@@ -77,7 +94,7 @@ impl SAMPJayaSolver {
                                 - r2 * (sw_vars[j] - ind.variables[j].abs());
                             new_vars[j] = val.clamp(lower[j], upper[j]);
                         }
-                        let new_fitness = problem.fitness(&new_vars);
+                        let new_fitness = problem.evaluate(&mut new_vars);
                         if new_fitness < ind.fitness {
                             Individual::new(new_vars, new_fitness)
                         } else {
@@ -94,6 +111,9 @@ impl SAMPJayaSolver {
                 .map(|ind| ind.fitness)
                 .fold(f64::INFINITY, f64::min);
             history.push(global_best);
+            if report_progress(&mut on_iteration, &history) || convergence.converged(global_best) {
+                break;
+            }
 
             // Self-adaptation
             if global_best < last_global_best {
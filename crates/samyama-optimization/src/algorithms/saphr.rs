@@ -7,10 +7,13 @@
 //! Single-objective; supports constraint penalty.
 
 use crate::algorithms::rao::RaoVariant;
-use crate::common::{Individual, OptimizationResult, Problem, SolverConfig};
+use crate::common::{
+    report_progress, split_seeds, ConvergenceTracker, Individual, OptimizationResult, Problem, SolverConfig,
+};
 use ndarray::Array1;
 use rand::prelude::*;
 use rayon::prelude::*;
+use std::ops::ControlFlow;
 
 pub struct SAPHRSolver {
     pub config: SolverConfig,
@@ -23,7 +26,18 @@ impl SAPHRSolver {
     }
 
     pub fn solve<P: Problem>(&self, problem: &P) -> OptimizationResult {
-        let mut rng = thread_rng();
+        self.solve_with_progress(problem, |_, _| ControlFlow::Continue(()))
+    }
+
+    /// Runs like [`solve`](Self::solve), calling `on_iteration(iteration, best)`
+    /// after each iteration; returning `ControlFlow::Break` stops the run and
+    /// returns the best found so far.
+    pub fn solve_with_progress<P, F>(&self, problem: &P, mut on_iteration: F) -> OptimizationResult
+    where
+        P: Problem,
+        F: FnMut(usize, f64) -> ControlFlow<()>,
+    {
+        let mut rng = self.config.rng();
         let dim = problem.dim();
         let (lower, upper) = problem.bounds();
         let pop_size = self.config.population_size;
@@ -34,7 +48,7 @@ impl SAPHRSolver {
                 for i in 0..dim {
                     vars[i] = rng.gen_range(lower[i]..upper[i]);
                 }
-                let fitness = problem.fitness(&vars);
+                let fitness = problem.evaluate(&mut vars);
                 Individual::new(vars, fitness)
             })
             .collect();
@@ -43,6 +57,7 @@ impl SAPHRSolver {
         let mut success: [f64; 3] = [1.0, 1.0, 1.0]; // Laplace-smoothed
         let mut history = Vec::with_capacity(self.config.max_iterations);
 
+        let mut convergence = ConvergenceTracker::new(&self.config);
         for iter in 0..self.config.max_iterations {
             if iter % 10 == 0 {
                 println!(
@@ -54,6 +69,9 @@ impl SAPHRSolver {
             let best_vars = population[best_idx].variables.clone();
             let worst_vars = population[worst_idx].variables.clone();
             history.push(population[best_idx].fitness);
+            if report_progress(&mut on_iteration, &history) || convergence.converged(population[best_idx].fitness) {
+                break;
+            }
 
             // Sample variant by softmax over success counts (probability table).
             let total: f64 = success.iter().sum();
@@ -63,10 +81,12 @@ impl SAPHRSolver {
             ];
 
             // Updates per individual + count successes per variant.
+            let seeds = split_seeds(&mut rng, population.len());
             let updates: Vec<(Individual, usize, bool)> = population
                 .par_iter()
-                .map(|ind| {
-                    let mut local_rng = thread_rng();
+                .zip(seeds)
+                .map(|(ind, seed)| {
+                    let mut local_rng = StdRng::seed_from_u64(seed);
                     let pick: f64 = local_rng.gen();
                     let chosen = if local_rng.gen::<f64>() < self.epsilon {
                         local_rng.gen_range(0..3)
@@ -93,7 +113,7 @@ impl SAPHRSolver {
                         }
                     }
                     let rand_fit = if need_rand {
-                        problem.fitness(&rand_vars)
+                        problem.evaluate(&mut rand_vars)
                     } else {
                         0.0
                     };
@@ -123,7 +143,7 @@ impl SAPHRSolver {
                         };
                         new_vars[j] = (ind.variables[j] + delta).clamp(lower[j], upper[j]);
                     }
-                    let new_fit = problem.fitness(&new_vars);
+                    let new_fit = problem.evaluate(&mut new_vars);
                     let improved = new_fit < ind.fitness;
                     let updated = if improved {
                         Individual::new(new_vars, new_fit)
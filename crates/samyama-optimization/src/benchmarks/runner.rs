@@ -104,6 +104,15 @@ fn solve_mo(name: &str, cfg: &SolverConfig, spec: &MOProblemSpec) -> MultiObject
     panic!("unknown MO problem {}", spec.name)
 }
 
+/// Config for the `index`-th replicate. A seeded base config gives replicate
+/// `i` the seed `seed + i`, so whole suites are reproducible.
+fn replicate_config(cfg: &SolverConfig, index: usize) -> SolverConfig {
+    SolverConfig {
+        seed: cfg.seed.map(|s| s.wrapping_add(index as u64)),
+        ..cfg.clone()
+    }
+}
+
 pub fn run_so_suite(
     solvers: &[&str],
     problems: &[SOProblemSpec],
@@ -115,7 +124,7 @@ pub fn run_so_suite(
         for spec in problems {
             for seed in 0..seeds {
                 let t0 = Instant::now();
-                let r = solve_so(solver, cfg, spec);
+                let r = solve_so(solver, &replicate_config(cfg, seed), spec);
                 let wall_ms = t0.elapsed().as_millis();
                 out.push(SORecord {
                     solver: solver.to_string(),
@@ -145,7 +154,7 @@ pub fn run_mo_suite(
         for spec in problems {
             for seed in 0..seeds {
                 let t0 = Instant::now();
-                let mut r = solve_mo(solver, cfg, spec);
+                let mut r = solve_mo(solver, &replicate_config(cfg, seed), spec);
                 let wall_ms = t0.elapsed().as_millis();
                 fast_non_dominated_sort(&mut r.pareto_front);
                 let front: Vec<&[f64]> = r.pareto_front.iter()
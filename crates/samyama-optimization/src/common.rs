@@ -1,5 +1,8 @@
 use ndarray::Array1;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 use serde::{Deserialize, Serialize};
+use std::ops::ControlFlow;
 
 /// Represents a candidate solution in the optimization space.
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -14,6 +17,40 @@ impl Individual {
     }
 }
 
+/// The domain of a single decision variable.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum VariableType {
+    /// Any real value within bounds.
+    Continuous,
+    /// Whole numbers within bounds. Bounds should themselves be integers.
+    Integer,
+    /// One of a fixed set of values.
+    Categorical(Vec<f64>),
+}
+
+impl VariableType {
+    /// Maps a raw candidate value onto this variable's domain.
+    pub fn snap(&self, value: f64) -> f64 {
+        match self {
+            VariableType::Continuous => value,
+            VariableType::Integer => value.round(),
+            VariableType::Categorical(values) => values
+                .iter()
+                .copied()
+                .min_by(|a, b| (a - value).abs().total_cmp(&(b - value).abs()))
+                .unwrap_or(value),
+        }
+    }
+}
+
+/// Snaps each variable with a declared type onto its domain. Variables past
+/// the end of `types` are treated as continuous.
+pub fn snap_variables(types: &[VariableType], variables: &mut Array1<f64>) {
+    for (x, ty) in variables.iter_mut().zip(types) {
+        *x = ty.snap(*x);
+    }
+}
+
 /// Defines the optimization problem.
 pub trait Problem: Send + Sync {
     /// The objective function to minimize.
@@ -34,6 +71,23 @@ pub trait Problem: Send + Sync {
 
     /// Lower and upper bounds for each variable.
     fn bounds(&self) -> (Array1<f64>, Array1<f64>);
+
+    /// Per-variable domains. Empty (the default) means all continuous.
+    fn variable_types(&self) -> &[VariableType] {
+        &[]
+    }
+
+    /// Snaps a candidate onto the declared variable domains.
+    fn snap(&self, variables: &mut Array1<f64>) {
+        snap_variables(self.variable_types(), variables);
+    }
+
+    /// Snaps a candidate in place, then returns its fitness. Solvers use this
+    /// so the stored variables are always the point that was evaluated.
+    fn evaluate(&self, variables: &mut Array1<f64>) -> f64 {
+        self.snap(variables);
+        self.fitness(variables)
+    }
 }
 
 /// Represents a candidate solution in a multi-objective space.
@@ -76,6 +130,16 @@ pub trait MultiObjectiveProblem: Send + Sync {
     
     /// Number of objectives.
     fn num_objectives(&self) -> usize;
+
+    /// Per-variable domains. Empty (the default) means all continuous.
+    fn variable_types(&self) -> &[VariableType] {
+        &[]
+    }
+
+    /// Snaps a candidate onto the declared variable domains.
+    fn snap(&self, variables: &mut Array1<f64>) {
+        snap_variables(self.variable_types(), variables);
+    }
 }
 
 /// The result of a multi-objective optimization run (Pareto Front).
@@ -90,6 +154,21 @@ pub struct MultiObjectiveResult {
 pub struct SolverConfig {
     pub population_size: usize,
     pub max_iterations: usize,
+    /// Minimum improvement in best fitness that counts as progress.
+    /// `None` disables early stopping.
+    #[serde(default)]
+    pub tolerance: Option<f64>,
+    /// Consecutive iterations without progress before the solver stops.
+    #[serde(default = "default_patience")]
+    pub patience: usize,
+    /// Seed for the solver's RNG. Runs with the same seed and config are
+    /// reproducible; `None` seeds from OS entropy.
+    #[serde(default)]
+    pub seed: Option<u64>,
+}
+
+fn default_patience() -> usize {
+    10
 }
 
 impl Default for SolverConfig {
@@ -97,10 +176,79 @@ impl Default for SolverConfig {
         Self {
             population_size: 50,
             max_iterations: 100,
+            tolerance: None,
+            patience: default_patience(),
+            seed: None,
         }
     }
 }
 
+impl SolverConfig {
+    /// The master RNG for a solver run.
+    pub fn rng(&self) -> StdRng {
+        match self.seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_entropy(),
+        }
+    }
+}
+
+/// Draws `n` seeds from `rng`, one per work item of a parallel step, so each
+/// item gets its own RNG independent of thread scheduling.
+pub fn split_seeds<R: Rng>(rng: &mut R, n: usize) -> Vec<u64> {
+    (0..n).map(|_| rng.gen()).collect()
+}
+
+/// Tracks best-fitness progress across iterations for early stopping.
+#[derive(Clone, Debug)]
+pub struct ConvergenceTracker {
+    tolerance: Option<f64>,
+    patience: usize,
+    best: Option<f64>,
+    stale: usize,
+}
+
+impl ConvergenceTracker {
+    pub fn new(config: &SolverConfig) -> Self {
+        Self {
+            tolerance: config.tolerance,
+            patience: config.patience.max(1),
+            best: None,
+            stale: 0,
+        }
+    }
+
+    /// Records this iteration's best fitness and returns true once it has
+    /// improved by less than `tolerance` for `patience` consecutive iterations.
+    pub fn converged(&mut self, fitness: f64) -> bool {
+        let Some(tolerance) = self.tolerance else {
+            return false;
+        };
+        match self.best {
+            Some(best) if best - fitness >= tolerance => self.stale = 0,
+            Some(_) => self.stale += 1,
+            None => {}
+        }
+        if self.best.is_none_or(|best| fitness < best) {
+            self.best = Some(fitness);
+        }
+        self.stale >= self.patience
+    }
+}
+
+/// Passes the iteration just pushed onto `history` (its index and value) to a
+/// solver's progress callback, and returns true if the callback asked the run
+/// to stop.
+pub fn report_progress<F>(on_iteration: &mut F, history: &[f64]) -> bool
+where
+    F: FnMut(usize, f64) -> ControlFlow<()>,
+{
+    match history.last() {
+        Some(&best) => on_iteration(history.len() - 1, best).is_break(),
+        None => false,
+    }
+}
+
 /// The result of an optimization run.
 #[derive(Debug, Serialize, Deserialize)]
 pub struct OptimizationResult {
@@ -19,7 +19,7 @@ impl Problem for SphereProblem {
 #[test]
 fn test_jaya_sphere() {
     let problem = SphereProblem;
-    let config = SolverConfig { population_size: 50, max_iterations: 500 };
+    let config = SolverConfig { population_size: 50, max_iterations: 500, ..Default::default() };
     let solver = JayaSolver::new(config);
     let result = solver.solve(&problem);
     
@@ -29,7 +29,7 @@ fn test_jaya_sphere() {
 #[test]
 fn test_qojaya_sphere() {
     let problem = SphereProblem;
-    let config = SolverConfig { population_size: 50, max_iterations: 500 };
+    let config = SolverConfig { population_size: 50, max_iterations: 500, ..Default::default() };
     let solver = QOJayaSolver::new(config);
     let result = solver.solve(&problem);
     
@@ -39,7 +39,7 @@ fn test_qojaya_sphere() {
 #[test]
 fn test_itlbo_sphere() {
     let problem = SphereProblem;
-    let config = SolverConfig { population_size: 50, max_iterations: 500 };
+    let config = SolverConfig { population_size: 50, max_iterations: 500, ..Default::default() };
     let solver = ITLBOSolver::new(config);
     let result = solver.solve(&problem);
     
@@ -49,7 +49,7 @@ fn test_itlbo_sphere() {
 #[test]
 fn test_rao3_sphere() {
     let problem = SphereProblem;
-    let config = SolverConfig { population_size: 100, max_iterations: 1000 };
+    let config = SolverConfig { population_size: 100, max_iterations: 1000, ..Default::default() };
     let solver = RaoSolver::new(config, RaoVariant::Rao3);
     let result = solver.solve(&problem);
     
@@ -59,7 +59,7 @@ fn test_rao3_sphere() {
 #[test]
 fn test_tlbo_sphere() {
     let problem = SphereProblem;
-    let config = SolverConfig { population_size: 50, max_iterations: 500 };
+    let config = SolverConfig { population_size: 50, max_iterations: 500, ..Default::default() };
     let solver = TLBOSolver::new(config);
     let result = solver.solve(&problem);
     
@@ -69,7 +69,7 @@ fn test_tlbo_sphere() {
 #[test]
 fn test_bmr_sphere() {
     let problem = SphereProblem;
-    let config = SolverConfig { population_size: 50, max_iterations: 500 };
+    let config = SolverConfig { population_size: 50, max_iterations: 500, ..Default::default() };
     let solver = BMRSolver::new(config);
     let result = solver.solve(&problem);
     
@@ -79,7 +79,7 @@ fn test_bmr_sphere() {
 #[test]
 fn test_bwr_sphere() {
     let problem = SphereProblem;
-    let config = SolverConfig { population_size: 50, max_iterations: 500 };
+    let config = SolverConfig { population_size: 50, max_iterations: 500, ..Default::default() };
     let solver = BWRSolver::new(config);
     let result = solver.solve(&problem);
     
@@ -89,7 +89,7 @@ fn test_bwr_sphere() {
 #[test]
 fn test_pso_sphere() {
     let problem = SphereProblem;
-    let config = SolverConfig { population_size: 50, max_iterations: 500 };
+    let config = SolverConfig { population_size: 50, max_iterations: 500, ..Default::default() };
     let solver = PSOSolver::new(config);
     let result = solver.solve(&problem);
     
@@ -99,7 +99,7 @@ fn test_pso_sphere() {
 #[test]
 fn test_de_sphere() {
     let problem = SphereProblem;
-    let config = SolverConfig { population_size: 50, max_iterations: 500 };
+    let config = SolverConfig { population_size: 50, max_iterations: 500, ..Default::default() };
     let solver = DESolver::new(config);
     let result = solver.solve(&problem);
 
@@ -109,7 +109,7 @@ fn test_de_sphere() {
 #[test]
 fn test_gotlbo_sphere() {
     let problem = SphereProblem;
-    let config = SolverConfig { population_size: 50, max_iterations: 500 };
+    let config = SolverConfig { population_size: 50, max_iterations: 500, ..Default::default() };
     let solver = GOTLBOSolver::new(config);
     let result = solver.solve(&problem);
 
@@ -119,7 +119,7 @@ fn test_gotlbo_sphere() {
 #[test]
 fn test_firefly_sphere() {
     let problem = SphereProblem;
-    let config = SolverConfig { population_size: 50, max_iterations: 500 };
+    let config = SolverConfig { population_size: 50, max_iterations: 500, ..Default::default() };
     let solver = FireflySolver::new(config);
     let result = solver.solve(&problem);
 
@@ -129,7 +129,7 @@ fn test_firefly_sphere() {
 #[test]
 fn test_cuckoo_sphere() {
     let problem = SphereProblem;
-    let config = SolverConfig { population_size: 50, max_iterations: 500 };
+    let config = SolverConfig { population_size: 50, max_iterations: 500, ..Default::default() };
     let solver = CuckooSolver::new(config);
     let result = solver.solve(&problem);
 
@@ -139,7 +139,7 @@ fn test_cuckoo_sphere() {
 #[test]
 fn test_gwo_sphere() {
     let problem = SphereProblem;
-    let config = SolverConfig { population_size: 50, max_iterations: 500 };
+    let config = SolverConfig { population_size: 50, max_iterations: 500, ..Default::default() };
     let solver = GWOSolver::new(config);
     let result = solver.solve(&problem);
 
@@ -149,7 +149,7 @@ fn test_gwo_sphere() {
 #[test]
 fn test_ga_sphere() {
     let problem = SphereProblem;
-    let config = SolverConfig { population_size: 50, max_iterations: 500 };
+    let config = SolverConfig { population_size: 50, max_iterations: 500, ..Default::default() };
     let solver = GASolver::new(config);
     let result = solver.solve(&problem);
 
@@ -159,7 +159,7 @@ fn test_ga_sphere() {
 #[test]
 fn test_sa_sphere() {
     let problem = SphereProblem;
-    let config = SolverConfig { population_size: 50, max_iterations: 500 };
+    let config = SolverConfig { population_size: 50, max_iterations: 500, ..Default::default() };
     let solver = SASolver::new(config);
     let result = solver.solve(&problem);
 
@@ -169,7 +169,7 @@ fn test_sa_sphere() {
 #[test]
 fn test_bat_sphere() {
     let problem = SphereProblem;
-    let config = SolverConfig { population_size: 50, max_iterations: 500 };
+    let config = SolverConfig { population_size: 50, max_iterations: 500, ..Default::default() };
     let solver = BatSolver::new(config);
     let result = solver.solve(&problem);
 
@@ -179,7 +179,7 @@ fn test_bat_sphere() {
 #[test]
 fn test_abc_sphere() {
     let problem = SphereProblem;
-    let config = SolverConfig { population_size: 50, max_iterations: 500 };
+    let config = SolverConfig { population_size: 50, max_iterations: 500, ..Default::default() };
     let solver = ABCSolver::new(config);
     let result = solver.solve(&problem);
 
@@ -189,7 +189,7 @@ fn test_abc_sphere() {
 #[test]
 fn test_gsa_sphere() {
     let problem = SphereProblem;
-    let config = SolverConfig { population_size: 50, max_iterations: 500 };
+    let config = SolverConfig { population_size: 50, max_iterations: 500, ..Default::default() };
     let solver = GSASolver::new(config);
     let result = solver.solve(&problem);
 
@@ -199,7 +199,7 @@ fn test_gsa_sphere() {
 #[test]
 fn test_hs_sphere() {
     let problem = SphereProblem;
-    let config = SolverConfig { population_size: 50, max_iterations: 500 };
+    let config = SolverConfig { population_size: 50, max_iterations: 500, ..Default::default() };
     let solver = HSSolver::new(config);
     let result = solver.solve(&problem);
 
@@ -209,7 +209,7 @@ fn test_hs_sphere() {
 #[test]
 fn test_fpa_sphere() {
     let problem = SphereProblem;
-    let config = SolverConfig { population_size: 50, max_iterations: 500 };
+    let config = SolverConfig { population_size: 50, max_iterations: 500, ..Default::default() };
     let solver = FPASolver::new(config);
     let result = solver.solve(&problem);
 
@@ -219,7 +219,7 @@ fn test_fpa_sphere() {
 #[test]
 fn test_bmwr_sphere() {
     let problem = SphereProblem;
-    let config = SolverConfig { population_size: 50, max_iterations: 500 };
+    let config = SolverConfig { population_size: 50, max_iterations: 500, ..Default::default() };
     let solver = BMWRSolver::new(config);
     let result = solver.solve(&problem);
     assert!(result.best_fitness < 0.1, "BMWR failed: fitness {}", result.best_fitness);
@@ -228,7 +228,7 @@ fn test_bmwr_sphere() {
 #[test]
 fn test_samp_jaya_sphere() {
     let problem = SphereProblem;
-    let config = SolverConfig { population_size: 50, max_iterations: 500 };
+    let config = SolverConfig { population_size: 50, max_iterations: 500, ..Default::default() };
     let solver = SAMPJayaSolver::new(config);
     let result = solver.solve(&problem);
     assert!(result.best_fitness < 0.1, "SAMP-Jaya failed: fitness {}", result.best_fitness);
@@ -237,7 +237,7 @@ fn test_samp_jaya_sphere() {
 #[test]
 fn test_ehrjaya_sphere() {
     let problem = SphereProblem;
-    let config = SolverConfig { population_size: 50, max_iterations: 500 };
+    let config = SolverConfig { population_size: 50, max_iterations: 500, ..Default::default() };
     let solver = EHRJayaSolver::new(config);
     let result = solver.solve(&problem);
     assert!(result.best_fitness < 0.1, "EHR-Jaya failed: fitness {}", result.best_fitness);
@@ -246,7 +246,7 @@ fn test_ehrjaya_sphere() {
 #[test]
 fn test_qo_rao_sphere() {
     let problem = SphereProblem;
-    let config = SolverConfig { population_size: 50, max_iterations: 500 };
+    let config = SolverConfig { population_size: 50, max_iterations: 500, ..Default::default() };
     let solver = QORaoSolver::new(config, RaoVariant::Rao1);
     let result = solver.solve(&problem);
     assert!(result.best_fitness < 0.1, "QO-Rao failed: fitness {}", result.best_fitness);
@@ -277,7 +277,7 @@ impl MultiObjectiveProblem for BiObjectiveProblem {
 #[test]
 fn test_nsga2_biobjective() {
     let problem = BiObjectiveProblem;
-    let config = SolverConfig { population_size: 50, max_iterations: 100 };
+    let config = SolverConfig { population_size: 50, max_iterations: 100, ..Default::default() };
     let solver = NSGA2Solver::new(config);
     let result = solver.solve(&problem);
 
@@ -296,7 +296,7 @@ fn test_nsga2_biobjective() {
 #[test]
 fn test_motlbo_biobjective() {
     let problem = BiObjectiveProblem;
-    let config = SolverConfig { population_size: 50, max_iterations: 100 };
+    let config = SolverConfig { population_size: 50, max_iterations: 100, ..Default::default() };
     let solver = MOTLBOSolver::new(config);
     let result = solver.solve(&problem);
 
@@ -309,7 +309,7 @@ fn test_motlbo_biobjective() {
 #[test]
 fn test_mo_bmr_biobjective() {
     let problem = BiObjectiveProblem;
-    let config = SolverConfig { population_size: 50, max_iterations: 100 };
+    let config = SolverConfig { population_size: 50, max_iterations: 100, ..Default::default() };
     let solver = MOBMWRSolver::new(config, MOBMWRVariant::MOBMR);
     let result = solver.solve(&problem);
     assert!(!result.pareto_front.is_empty());
@@ -319,7 +319,7 @@ fn test_mo_bmr_biobjective() {
 #[test]
 fn test_mo_bwr_biobjective() {
     let problem = BiObjectiveProblem;
-    let config = SolverConfig { population_size: 50, max_iterations: 100 };
+    let config = SolverConfig { population_size: 50, max_iterations: 100, ..Default::default() };
     let solver = MOBMWRSolver::new(config, MOBMWRVariant::MOBWR);
     let result = solver.solve(&problem);
     assert!(!result.pareto_front.is_empty());
@@ -328,7 +328,7 @@ fn test_mo_bwr_biobjective() {
 #[test]
 fn test_mo_bmwr_biobjective() {
     let problem = BiObjectiveProblem;
-    let config = SolverConfig { population_size: 50, max_iterations: 100 };
+    let config = SolverConfig { population_size: 50, max_iterations: 100, ..Default::default() };
     let solver = MOBMWRSolver::new(config, MOBMWRVariant::MOBMWR);
     let result = solver.solve(&problem);
     assert!(!result.pareto_front.is_empty());
@@ -337,7 +337,7 @@ fn test_mo_bmwr_biobjective() {
 #[test]
 fn test_mo_rao_de_biobjective() {
     let problem = BiObjectiveProblem;
-    let config = SolverConfig { population_size: 50, max_iterations: 100 };
+    let config = SolverConfig { population_size: 50, max_iterations: 100, ..Default::default() };
     let solver = MORaoDESolver::new(config);
     let result = solver.solve(&problem);
     assert!(!result.pareto_front.is_empty());
@@ -346,7 +346,7 @@ fn test_mo_rao_de_biobjective() {
 #[test]
 fn test_saphr_sphere() {
     let problem = SphereProblem;
-    let config = SolverConfig { population_size: 50, max_iterations: 500 };
+    let config = SolverConfig { population_size: 50, max_iterations: 500, ..Default::default() };
     let solver = SAPHRSolver::new(config);
     let result = solver.solve(&problem);
     assert!(result.best_fitness < 0.5, "SAPHR failed: {}", result.best_fitness);
@@ -358,7 +358,7 @@ fn test_saphr_sphere() {
 fn test_solver_history_decreasing() {
     // Verify that best fitness generally decreases over iterations
     let problem = SphereProblem;
-    let config = SolverConfig { population_size: 50, max_iterations: 200 };
+    let config = SolverConfig { population_size: 50, max_iterations: 200, ..Default::default() };
     let solver = JayaSolver::new(config);
     let result = solver.solve(&problem);
 
@@ -372,7 +372,7 @@ fn test_solver_history_decreasing() {
 #[test]
 fn test_result_variables_in_bounds() {
     let problem = SphereProblem;
-    let config = SolverConfig { population_size: 50, max_iterations: 100 };
+    let config = SolverConfig { population_size: 50, max_iterations: 100, ..Default::default() };
     let solver = PSOSolver::new(config);
     let result = solver.solve(&problem);
 
@@ -396,7 +396,7 @@ fn test_simple_problem_closure() {
         upper: array![5.0, 5.0, 5.0],
     };
 
-    let config = SolverConfig { population_size: 30, max_iterations: 200 };
+    let config = SolverConfig { population_size: 30, max_iterations: 200, ..Default::default() };
     let solver = DESolver::new(config);
     let result = solver.solve(&problem);
 
@@ -424,7 +424,7 @@ impl Problem for Rastrigin10D {
 #[test]
 fn test_de_rastrigin_10d() {
     let problem = Rastrigin10D;
-    let config = SolverConfig { population_size: 100, max_iterations: 1000 };
+    let config = SolverConfig { population_size: 100, max_iterations: 1000, ..Default::default() };
     let solver = DESolver::new(config);
     let result = solver.solve(&problem);
 
@@ -461,7 +461,7 @@ impl Problem for ConstrainedSphere {
 #[test]
 fn test_constrained_problem() {
     let problem = ConstrainedSphere;
-    let config = SolverConfig { population_size: 50, max_iterations: 500 };
+    let config = SolverConfig { population_size: 50, max_iterations: 500, ..Default::default() };
     let solver = JayaSolver::new(config);
     let result = solver.solve(&problem);
 
@@ -469,3 +469,205 @@ fn test_constrained_problem() {
     assert!(result.best_variables[0] + result.best_variables[1] >= 0.9,
         "Constraint violated: x0+x1 = {}", result.best_variables[0] + result.best_variables[1]);
 }
+
+// --- Early Stopping Tests ---
+
+#[test]
+fn test_early_stopping_on_converged_problem() {
+    // A flat objective is converged from the first iteration.
+    let problem = SimpleProblem {
+        objective_func: |_x: &Array1<f64>| 1.0,
+        dim: 2,
+        lower: array![-5.0, -5.0],
+        upper: array![5.0, 5.0],
+    };
+
+    let config = SolverConfig {
+        population_size: 10,
+        max_iterations: 500,
+        tolerance: Some(1e-9),
+        patience: 5,
+        ..Default::default()
+    };
+    let jaya = JayaSolver::new(config.clone()).solve(&problem);
+    assert_eq!(jaya.history.len(), 6, "Jaya ran {} iterations", jaya.history.len());
+
+    let de = DESolver::new(config.clone()).solve(&problem);
+    assert_eq!(de.history.len(), 6, "DE ran {} iterations", de.history.len());
+
+    let sa = SASolver::new(config).solve(&problem);
+    assert_eq!(sa.history.len(), 6, "SA ran {} iterations", sa.history.len());
+}
+
+#[test]
+fn test_no_tolerance_runs_all_iterations() {
+    let problem = SphereProblem;
+    let config = SolverConfig { population_size: 10, max_iterations: 40, ..Default::default() };
+    let result = JayaSolver::new(config).solve(&problem);
+    assert_eq!(result.history.len(), 40);
+}
+
+// --- Reproducibility Tests ---
+
+#[test]
+fn test_seeded_runs_are_reproducible() {
+    let problem = SphereProblem;
+    let config = SolverConfig { population_size: 20, max_iterations: 30, seed: Some(42), ..Default::default() };
+
+    let runs: Vec<(&str, Box<dyn Fn() -> OptimizationResult>)> = vec![
+        ("Jaya", Box::new(|| JayaSolver::new(config.clone()).solve(&problem))),
+        ("Rao3", Box::new(|| RaoSolver::new(config.clone(), RaoVariant::Rao3).solve(&problem))),
+        ("TLBO", Box::new(|| TLBOSolver::new(config.clone()).solve(&problem))),
+        ("PSO", Box::new(|| PSOSolver::new(config.clone()).solve(&problem))),
+        ("DE", Box::new(|| DESolver::new(config.clone()).solve(&problem))),
+        ("GA", Box::new(|| GASolver::new(config.clone()).solve(&problem))),
+        ("Cuckoo", Box::new(|| CuckooSolver::new(config.clone()).solve(&problem))),
+    ];
+    for (name, run) in runs {
+        let a = run();
+        let b = run();
+        assert_eq!(a.best_fitness, b.best_fitness, "{} best_fitness differs", name);
+        assert_eq!(a.history, b.history, "{} history differs", name);
+    }
+
+    let mo_config = SolverConfig { population_size: 20, max_iterations: 10, seed: Some(7), ..Default::default() };
+    let a = NSGA2Solver::new(mo_config.clone()).solve(&BiObjectiveProblem);
+    let b = NSGA2Solver::new(mo_config).solve(&BiObjectiveProblem);
+    assert_eq!(a.history, b.history);
+}
+
+#[test]
+fn test_different_seeds_diverge() {
+    let problem = SphereProblem;
+    let run = |seed| {
+        let config = SolverConfig { population_size: 20, max_iterations: 10, seed: Some(seed), ..Default::default() };
+        JayaSolver::new(config).solve(&problem)
+    };
+    assert_ne!(run(1).history, run(2).history);
+}
+
+// --- Discrete Variable Tests ---
+
+/// Integer knapsack: up to 3 copies of each item, capacity 11. The optimum
+/// takes one of item 0 and three of item 2 (value 22, weight 11).
+struct IntegerKnapsack {
+    values: [f64; 3],
+    weights: [f64; 3],
+    capacity: f64,
+    types: Vec<VariableType>,
+}
+
+impl Problem for IntegerKnapsack {
+    fn objective(&self, x: &Array1<f64>) -> f64 {
+        -(0..3).map(|i| self.values[i] * x[i]).sum::<f64>()
+    }
+
+    fn penalty(&self, x: &Array1<f64>) -> f64 {
+        let weight: f64 = (0..3).map(|i| self.weights[i] * x[i]).sum();
+        (weight - self.capacity).max(0.0) * 100.0
+    }
+
+    fn dim(&self) -> usize { 3 }
+
+    fn bounds(&self) -> (Array1<f64>, Array1<f64>) {
+        (array![0.0, 0.0, 0.0], array![3.0, 3.0, 3.0])
+    }
+
+    fn variable_types(&self) -> &[VariableType] {
+        &self.types
+    }
+}
+
+#[test]
+fn test_integer_knapsack() {
+    let problem = IntegerKnapsack {
+        values: [10.0, 7.0, 4.0],
+        weights: [5.0, 4.0, 2.0],
+        capacity: 11.0,
+        types: vec![VariableType::Integer; 3],
+    };
+    let config = SolverConfig { population_size: 30, max_iterations: 200, seed: Some(3), ..Default::default() };
+    let result = DESolver::new(config).solve(&problem);
+
+    for x in result.best_variables.iter() {
+        assert_eq!(*x, x.round(), "non-integer variable {}", x);
+    }
+    assert_eq!(result.best_variables, array![1.0, 0.0, 3.0]);
+    assert_eq!(result.best_fitness, -22.0);
+}
+
+#[test]
+fn test_categorical_variable() {
+    struct Pick {
+        types: Vec<VariableType>,
+    }
+    impl Problem for Pick {
+        fn objective(&self, x: &Array1<f64>) -> f64 {
+            (x[0] - 1.2).powi(2) + (x[1] - 2.4).powi(2)
+        }
+        fn dim(&self) -> usize { 2 }
+        fn bounds(&self) -> (Array1<f64>, Array1<f64>) {
+            (array![0.0, 0.0], array![5.0, 5.0])
+        }
+        fn variable_types(&self) -> &[VariableType] {
+            &self.types
+        }
+    }
+
+    let problem = Pick {
+        types: vec![VariableType::Categorical(vec![0.5, 1.5, 4.0]), VariableType::Integer],
+    };
+    let config = SolverConfig { population_size: 20, max_iterations: 50, seed: Some(1), ..Default::default() };
+    let result = JayaSolver::new(config).solve(&problem);
+    assert_eq!(result.best_variables, array![1.5, 2.0]);
+}
+
+#[test]
+fn test_variable_type_snap() {
+    assert_eq!(VariableType::Continuous.snap(1.37), 1.37);
+    assert_eq!(VariableType::Integer.snap(1.5), 2.0);
+    assert_eq!(VariableType::Integer.snap(-0.4), 0.0);
+    assert_eq!(VariableType::Categorical(vec![1.0, 10.0, 100.0]).snap(40.0), 10.0);
+    assert_eq!(VariableType::Categorical(vec![]).snap(4.2), 4.2);
+}
+
+// --- Progress Callback Tests ---
+
+#[test]
+fn test_progress_callback_reports_each_iteration() {
+    let problem = SphereProblem;
+    let config = SolverConfig { population_size: 20, max_iterations: 25, seed: Some(5), ..Default::default() };
+    let mut seen = Vec::new();
+    let result = TLBOSolver::new(config).solve_with_progress(&problem, |iter, best| {
+        seen.push((iter, best));
+        std::ops::ControlFlow::Continue(())
+    });
+
+    assert_eq!(seen.len(), 25);
+    let iters: Vec<usize> = seen.iter().map(|(i, _)| *i).collect();
+    assert_eq!(iters, (0..25).collect::<Vec<_>>());
+    let reported: Vec<f64> = seen.iter().map(|(_, b)| *b).collect();
+    assert_eq!(reported, result.history);
+}
+
+#[test]
+fn test_progress_callback_cancels_with_best_so_far() {
+    use std::ops::ControlFlow;
+
+    let problem = SphereProblem;
+    let config = SolverConfig { population_size: 20, max_iterations: 10_000, ..Default::default() };
+    let result = PSOSolver::new(config).solve_with_progress(&problem, |iter, _| {
+        if iter == 4 { ControlFlow::Break(()) } else { ControlFlow::Continue(()) }
+    });
+    assert_eq!(result.history.len(), 5);
+    assert!(result.best_fitness.is_finite());
+    assert!(result.best_fitness <= result.history[4]);
+    assert_eq!(problem.fitness(&result.best_variables), result.best_fitness);
+
+    let mo_config = SolverConfig { population_size: 20, max_iterations: 10_000, ..Default::default() };
+    let mo = NSGA2Solver::new(mo_config).solve_with_progress(&BiObjectiveProblem, |iter, _| {
+        if iter == 2 { ControlFlow::Break(()) } else { ControlFlow::Continue(()) }
+    });
+    assert_eq!(mo.history.len(), 3);
+    assert!(!mo.pareto_front.is_empty());
+}
@@ -8,12 +8,14 @@ use async_trait::async_trait;
 use std::collections::HashMap;
 
 use samyama::algo::{
-    build_view, page_rank, weakly_connected_components, strongly_connected_components,
+    build_view, Directedness, page_rank, weakly_connected_components, strongly_connected_components,
     bfs, dijkstra, bfs_all_shortest_paths, edmonds_karp, prim_mst, count_triangles,
+    betweenness_centrality,
     cdlp, local_clustering_coefficient, pca,
     PageRankConfig, PathResult, WccResult, SccResult, FlowResult, MSTResult,
     CdlpConfig, CdlpResult, LccResult, PcaConfig, PcaResult, PcaSolver,
 };
+use samyama::graph::NodeId;
 use samyama_graph_algorithms::GraphView;
 
 use crate::embedded::EmbeddedClient;
@@ -26,22 +28,39 @@ pub trait AlgorithmClient {
     /// Build a `GraphView` projection for algorithm execution.
     ///
     /// Optionally filter by node label, edge type, and extract edge weights.
+    /// `Directedness::Undirected` symmetrizes the edges.
     async fn build_view(
         &self,
         label: Option<&str>,
         edge_type: Option<&str>,
         weight_prop: Option<&str>,
+        directedness: Directedness,
     ) -> GraphView;
 
     /// Run PageRank on the graph (or a subgraph filtered by label/edge_type).
+    ///
+    /// With `Directedness::Undirected` rank flows both ways along every edge.
     async fn page_rank(
         &self,
         config: PageRankConfig,
         label: Option<&str>,
         edge_type: Option<&str>,
+        directedness: Directedness,
     ) -> HashMap<u64, f64>;
 
+    /// Run PageRank over the nodes of `label`, returning store `NodeId`s sorted
+    /// by descending score (ties by ascending id).
+    async fn page_rank_labeled(
+        &self,
+        label: &str,
+        edge_type: Option<&str>,
+        config: PageRankConfig,
+        directedness: Directedness,
+    ) -> Vec<(NodeId, f64)>;
+
     /// Detect weakly connected components.
+    ///
+    /// Components ignore edge direction, so there is no `Directedness` argument.
     async fn weakly_connected_components(
         &self,
         label: Option<&str>,
@@ -65,6 +84,9 @@ pub trait AlgorithmClient {
     ) -> Option<PathResult>;
 
     /// Dijkstra's shortest path from source to target (weighted).
+    ///
+    /// Edge weights come from the numeric `weight_prop` edge property; without
+    /// it, or on edges lacking a numeric value, each edge weighs 1.0.
     async fn dijkstra(
         &self,
         source: u64,
@@ -84,6 +106,8 @@ pub trait AlgorithmClient {
     ) -> Option<FlowResult>;
 
     /// Prim's minimum spanning tree.
+    ///
+    /// Weighted by the `weight_prop` edge property, like [`dijkstra`](Self::dijkstra).
     async fn prim_mst(
         &self,
         label: Option<&str>,
@@ -98,6 +122,16 @@ pub trait AlgorithmClient {
         edge_type: Option<&str>,
     ) -> usize;
 
+    /// Betweenness centrality of every node.
+    ///
+    /// Shortest paths follow edge direction unless `directedness` is `Undirected`.
+    async fn betweenness_centrality(
+        &self,
+        label: Option<&str>,
+        edge_type: Option<&str>,
+        directedness: Directedness,
+    ) -> HashMap<u64, f64>;
+
     /// Find all shortest paths between source and target (BFS).
     async fn bfs_all_shortest_paths(
         &self,
@@ -141,9 +175,10 @@ impl AlgorithmClient for EmbeddedClient {
         label: Option<&str>,
         edge_type: Option<&str>,
         weight_prop: Option<&str>,
+        directedness: Directedness,
     ) -> GraphView {
         let store = self.store.read().await;
-        build_view(&store, label, edge_type, weight_prop)
+        build_view(&store, label, edge_type, weight_prop, directedness)
     }
 
     async fn page_rank(
@@ -151,19 +186,36 @@ impl AlgorithmClient for EmbeddedClient {
         config: PageRankConfig,
         label: Option<&str>,
         edge_type: Option<&str>,
+        directedness: Directedness,
     ) -> HashMap<u64, f64> {
         let store = self.store.read().await;
-        let view = build_view(&store, label, edge_type, None);
+        let view = build_view(&store, label, edge_type, None, directedness);
         page_rank(&view, config)
     }
 
+    async fn page_rank_labeled(
+        &self,
+        label: &str,
+        edge_type: Option<&str>,
+        config: PageRankConfig,
+        directedness: Directedness,
+    ) -> Vec<(NodeId, f64)> {
+        let scores = self.page_rank(config, Some(label), edge_type, directedness).await;
+        let mut ranked: Vec<(NodeId, f64)> = scores
+            .into_iter()
+            .map(|(id, score)| (NodeId::new(id), score))
+            .collect();
+        ranked.sort_by(|a, b| b.1.total_cmp(&a.1).then(a.0.cmp(&b.0)));
+        ranked
+    }
+
     async fn weakly_connected_components(
         &self,
         label: Option<&str>,
         edge_type: Option<&str>,
     ) -> WccResult {
         let store = self.store.read().await;
-        let view = build_view(&store, label, edge_type, None);
+        let view = build_view(&store, label, edge_type, None, Directedness::Directed);
         weakly_connected_components(&view)
     }
 
@@ -173,7 +225,7 @@ impl AlgorithmClient for EmbeddedClient {
         edge_type: Option<&str>,
     ) -> SccResult {
         let store = self.store.read().await;
-        let view = build_view(&store, label, edge_type, None);
+        let view = build_view(&store, label, edge_type, None, Directedness::Directed);
         strongly_connected_components(&view)
     }
 
@@ -185,7 +237,7 @@ impl AlgorithmClient for EmbeddedClient {
         edge_type: Option<&str>,
     ) -> Option<PathResult> {
         let store = self.store.read().await;
-        let view = build_view(&store, label, edge_type, None);
+        let view = build_view(&store, label, edge_type, None, Directedness::Directed);
         bfs(&view, source, target)
     }
 
@@ -198,7 +250,7 @@ impl AlgorithmClient for EmbeddedClient {
         weight_prop: Option<&str>,
     ) -> Option<PathResult> {
         let store = self.store.read().await;
-        let view = build_view(&store, label, edge_type, weight_prop);
+        let view = build_view(&store, label, edge_type, weight_prop, Directedness::Directed);
         dijkstra(&view, source, target)
     }
 
@@ -210,7 +262,7 @@ impl AlgorithmClient for EmbeddedClient {
         edge_type: Option<&str>,
     ) -> Option<FlowResult> {
         let store = self.store.read().await;
-        let view = build_view(&store, label, edge_type, None);
+        let view = build_view(&store, label, edge_type, None, Directedness::Directed);
         edmonds_karp(&view, source, sink)
     }
 
@@ -221,7 +273,7 @@ impl AlgorithmClient for EmbeddedClient {
         weight_prop: Option<&str>,
     ) -> MSTResult {
         let store = self.store.read().await;
-        let view = build_view(&store, label, edge_type, weight_prop);
+        let view = build_view(&store, label, edge_type, weight_prop, Directedness::Directed);
         prim_mst(&view)
     }
 
@@ -231,10 +283,21 @@ impl AlgorithmClient for EmbeddedClient {
         edge_type: Option<&str>,
     ) -> usize {
         let store = self.store.read().await;
-        let view = build_view(&store, label, edge_type, None);
+        let view = build_view(&store, label, edge_type, None, Directedness::Directed);
         count_triangles(&view)
     }
 
+    async fn betweenness_centrality(
+        &self,
+        label: Option<&str>,
+        edge_type: Option<&str>,
+        directedness: Directedness,
+    ) -> HashMap<u64, f64> {
+        let store = self.store.read().await;
+        let view = build_view(&store, label, edge_type, None, directedness);
+        betweenness_centrality(&view)
+    }
+
     async fn bfs_all_shortest_paths(
         &self,
         source: u64,
@@ -243,7 +306,7 @@ impl AlgorithmClient for EmbeddedClient {
         edge_type: Option<&str>,
     ) -> Vec<PathResult> {
         let store = self.store.read().await;
-        let view = build_view(&store, label, edge_type, None);
+        let view = build_view(&store, label, edge_type, None, Directedness::Directed);
         bfs_all_shortest_paths(&view, source, target)
     }
 
@@ -254,7 +317,7 @@ impl AlgorithmClient for EmbeddedClient {
         edge_type: Option<&str>,
     ) -> CdlpResult {
         let store = self.store.read().await;
-        let view = build_view(&store, label, edge_type, None);
+        let view = build_view(&store, label, edge_type, None, Directedness::Directed);
         cdlp(&view, &config)
     }
 
@@ -264,7 +327,7 @@ impl AlgorithmClient for EmbeddedClient {
         edge_type: Option<&str>,
     ) -> LccResult {
         let store = self.store.read().await;
-        let view = build_view(&store, label, edge_type, None);
+        let view = build_view(&store, label, edge_type, None, Directedness::Directed);
         local_clustering_coefficient(&view)
     }
 
@@ -340,7 +403,7 @@ mod tests {
             r#"MATCH (a:Person {name: "Alice"}), (c:Person {name: "Carol"}) CREATE (a)-[:KNOWS]->(c)"#
         ).await.unwrap();
 
-        let scores = client.page_rank(PageRankConfig::default(), Some("Person"), Some("KNOWS")).await;
+        let scores = client.page_rank(PageRankConfig::default(), Some("Person"), Some("KNOWS"), Directedness::Directed).await;
         assert_eq!(scores.len(), 3);
         // Carol should have highest PageRank (most incoming links)
         let max_node = scores.iter().max_by(|a, b| a.1.partial_cmp(b.1).unwrap()).unwrap();
@@ -348,6 +411,33 @@ mod tests {
         assert!(*max_node.1 > 0.0);
     }
 
+    #[tokio::test]
+    async fn test_page_rank_labeled() {
+        let client = EmbeddedClient::new();
+
+        // Two documents cite "Hub"; the unrelated Person must not be ranked
+        client.query("default", r#"CREATE (h:Doc {title: "Hub"})"#).await.unwrap();
+        client.query("default", r#"CREATE (p:Person {name: "Alice"})"#).await.unwrap();
+        for title in ["A", "B"] {
+            client.query("default", &format!(r#"CREATE (d:Doc {{title: "{}"}})"#, title)).await.unwrap();
+            client.query("default", &format!(
+                r#"MATCH (d:Doc {{title: "{}"}}), (h:Doc {{title: "Hub"}}) CREATE (d)-[:CITES]->(h)"#, title
+            )).await.unwrap();
+        }
+
+        let ranked = client.page_rank_labeled("Doc", Some("CITES"), PageRankConfig::default(), Directedness::Directed).await;
+        assert_eq!(ranked.len(), 3);
+        assert!(ranked.windows(2).all(|w| w[0].1 >= w[1].1));
+
+        let store = client.store().read().await;
+        for (id, _) in &ranked {
+            let node = store.get_node(*id).expect("ranked NodeId resolves in the store");
+            assert!(node.has_label(&samyama::graph::Label::new("Doc")));
+        }
+        let top = store.get_node(ranked[0].0).unwrap();
+        assert_eq!(top.get_property("title"), Some(&"Hub".into()));
+    }
+
     #[tokio::test]
     async fn test_wcc() {
         let client = EmbeddedClient::new();
@@ -360,6 +450,76 @@ mod tests {
         assert_eq!(wcc.components.len(), 2);
     }
 
+    #[tokio::test]
+    async fn test_betweenness_centrality() {
+        let client = EmbeddedClient::new();
+
+        // Ports chain: Shanghai -> Singapore -> Rotterdam
+        client.query("default",
+            r#"CREATE (a:Port {name: "Shanghai"})-[:ROUTE]->(b:Port {name: "Singapore"})-[:ROUTE]->(c:Port {name: "Rotterdam"})"#
+        ).await.unwrap();
+
+        let store = client.store().read().await;
+        let hub = store.all_nodes().iter()
+            .find(|n| n.get_property("name") == Some(&"Singapore".into()))
+            .map(|n| n.id.as_u64())
+            .unwrap();
+        drop(store);
+
+        let scores = client.betweenness_centrality(Some("Port"), Some("ROUTE"), Directedness::Directed).await;
+        assert_eq!(scores.len(), 3);
+        assert_eq!(scores[&hub], 1.0);
+        assert_eq!(scores.values().filter(|&&s| s == 0.0).count(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_betweenness_centrality_undirected() {
+        let client = EmbeddedClient::new();
+
+        // Both routes end at the hub, so no directed path passes through it
+        client.query("default",
+            r#"CREATE (a:Port {name: "Shanghai"}), (b:Port {name: "Singapore"}), (c:Port {name: "Rotterdam"})"#
+        ).await.unwrap();
+        client.query("default",
+            r#"MATCH (a:Port {name: "Shanghai"}), (b:Port {name: "Singapore"}), (c:Port {name: "Rotterdam"}) CREATE (a)-[:ROUTE]->(b), (c)-[:ROUTE]->(b)"#
+        ).await.unwrap();
+
+        let directed = client.betweenness_centrality(Some("Port"), Some("ROUTE"), Directedness::Directed).await;
+        assert!(directed.values().all(|&s| s == 0.0));
+        let undirected = client.betweenness_centrality(Some("Port"), Some("ROUTE"), Directedness::Undirected).await;
+        assert_eq!(undirected.values().filter(|&&s| s > 0.0).count(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_dijkstra_weight_property() {
+        let client = EmbeddedClient::new();
+
+        // Direct lane Shanghai -> Rotterdam is one hop but long; via Singapore is shorter
+        client.query("default",
+            r#"CREATE (a:Port {name: "Shanghai"}), (b:Port {name: "Singapore"}), (c:Port {name: "Rotterdam"})"#
+        ).await.unwrap();
+        client.query("default",
+            r#"MATCH (a:Port {name: "Shanghai"}), (b:Port {name: "Singapore"}), (c:Port {name: "Rotterdam"})
+               CREATE (a)-[:ROUTE {distance: 100}]->(c), (a)-[:ROUTE {distance: 30}]->(b), (b)-[:ROUTE {distance: 40.5}]->(c)"#
+        ).await.unwrap();
+
+        let store = client.store().read().await;
+        let id_of = |name: &str| store.all_nodes().iter()
+            .find(|n| n.get_property("name") == Some(&name.into()))
+            .map(|n| n.id.as_u64())
+            .unwrap();
+        let (shanghai, singapore, rotterdam) = (id_of("Shanghai"), id_of("Singapore"), id_of("Rotterdam"));
+        drop(store);
+
+        let hops = client.dijkstra(shanghai, rotterdam, Some("Port"), Some("ROUTE"), None).await.unwrap();
+        assert_eq!(hops.path, vec![shanghai, rotterdam]);
+        assert_eq!(hops.cost, 1.0);
+
+        let shortest = client.dijkstra(shanghai, rotterdam, Some("Port"), Some("ROUTE"), Some("distance")).await.unwrap();
+        assert_eq!(shortest.path, vec![shanghai, singapore, rotterdam]);
+        assert_eq!(shortest.cost, 70.5);
+    }
+
     #[tokio::test]
     async fn test_bfs() {
         let client = EmbeddedClient::new();
@@ -1,8 +1,11 @@
 //! SamyamaClient trait — the unified interface for embedded and remote modes
 
+use std::collections::HashMap;
+
 use async_trait::async_trait;
-use crate::error::SamyamaResult;
-use crate::models::{QueryResult, ServerStatus};
+use samyama::graph::PropertyValue;
+use crate::error::{SamyamaError, SamyamaResult};
+use crate::models::{GraphStats, QueryResult, SchemaInfo, ServerStatus};
 
 /// Unified client interface for the Samyama graph database.
 ///
@@ -14,18 +17,69 @@ pub trait SamyamaClient: Send + Sync {
     /// Execute a read-write Cypher query
     async fn query(&self, graph: &str, cypher: &str) -> SamyamaResult<QueryResult>;
 
+    /// Execute a read-write Cypher query, binding `$name` references to `params`
+    ///
+    /// The default implementation only accepts an empty `params`.
+    async fn query_with_params(
+        &self,
+        graph: &str,
+        cypher: &str,
+        params: &HashMap<String, PropertyValue>,
+    ) -> SamyamaResult<QueryResult> {
+        if !params.is_empty() {
+            return Err(SamyamaError::QueryError("query parameters are not supported by this client".to_string()));
+        }
+        self.query(graph, cypher).await
+    }
+
     /// Execute a read-only Cypher query
     async fn query_readonly(&self, graph: &str, cypher: &str) -> SamyamaResult<QueryResult>;
 
+    /// Execute a read-only Cypher query, binding `$name` references to `params`
+    ///
+    /// The default implementation only accepts an empty `params`.
+    async fn query_readonly_with_params(
+        &self,
+        graph: &str,
+        cypher: &str,
+        params: &HashMap<String, PropertyValue>,
+    ) -> SamyamaResult<QueryResult> {
+        if !params.is_empty() {
+            return Err(SamyamaError::QueryError("query parameters are not supported by this client".to_string()));
+        }
+        self.query_readonly(graph, cypher).await
+    }
+
     /// Delete a graph
     async fn delete_graph(&self, graph: &str) -> SamyamaResult<()>;
 
+    /// Deep-copy graph `src` (data, indexes and constraints) into a new graph `dst`
+    ///
+    /// The default implementation reports that copying is unavailable.
+    async fn copy_graph(&self, _src: &str, _dst: &str) -> SamyamaResult<()> {
+        Err(SamyamaError::QueryError("copying graphs is not supported by this client".to_string()))
+    }
+
     /// List all graphs
     async fn list_graphs(&self) -> SamyamaResult<Vec<String>>;
 
     /// Get server status
     async fn status(&self) -> SamyamaResult<ServerStatus>;
 
+    /// Get per-label and per-edge-type counts and the index list of a graph
+    ///
+    /// The default implementation reports that stats are unavailable.
+    async fn stats(&self, _graph: &str) -> SamyamaResult<GraphStats> {
+        Err(SamyamaError::QueryError("stats are not supported by this client".to_string()))
+    }
+
+    /// Get the labels, property types and edge types (with endpoint labels) of a graph
+    ///
+    /// The default implementation reports that the schema is unavailable.
+    async fn schema(&self, _graph: &str) -> SamyamaResult<SchemaInfo> {
+        Err(SamyamaError::QueryError("schema is not supported by this client".to_string()))
+    }
+
     /// Ping the server
     async fn ping(&self) -> SamyamaResult<String>;
 }
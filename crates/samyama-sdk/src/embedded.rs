@@ -3,16 +3,19 @@
 //! Uses GraphStore and QueryEngine directly, no network needed.
 
 use async_trait::async_trait;
+use futures::Stream;
+use std::pin::Pin;
 use std::sync::Arc;
-use tokio::sync::RwLock;
+use std::task::{Context, Poll};
+use tokio::sync::{OwnedRwLockReadGuard, RwLock};
 use std::collections::HashMap;
 
-use samyama::graph::GraphStore;
-use samyama::query::{QueryEngine, Value, RecordBatch};
+use samyama::graph::{GraphStore, PropertyValue};
+use samyama::query::{QueryEngine, Value, Record, RecordBatch, RecordCursor};
 
 use crate::client::SamyamaClient;
 use crate::error::{SamyamaError, SamyamaResult};
-use crate::models::{QueryResult, SdkNode, SdkEdge, ServerStatus, StorageStats};
+use crate::models::{GraphStats, QueryResult, SchemaInfo, SdkNode, SdkEdge, ServerStatus, StorageStats};
 
 /// In-process client that wraps a GraphStore directly.
 ///
@@ -21,6 +24,8 @@ use crate::models::{QueryResult, SdkNode, SdkEdge, ServerStatus, StorageStats};
 pub struct EmbeddedClient {
     pub(crate) store: Arc<RwLock<GraphStore>>,
     engine: QueryEngine,
+    /// Graphs created by `copy_graph`, by name; every other name uses `store`
+    graphs: std::sync::Mutex<HashMap<String, Arc<RwLock<GraphStore>>>>,
 }
 
 impl EmbeddedClient {
@@ -29,6 +34,7 @@ impl EmbeddedClient {
         Self {
             store: Arc::new(RwLock::new(GraphStore::new())),
             engine: QueryEngine::new(),
+            graphs: std::sync::Mutex::new(HashMap::new()),
         }
     }
 
@@ -37,9 +43,15 @@ impl EmbeddedClient {
         Self {
             store,
             engine: QueryEngine::new(),
+            graphs: std::sync::Mutex::new(HashMap::new()),
         }
     }
 
+    /// The store backing `graph`: its copy if `copy_graph` made one, else the main store
+    fn graph_store(&self, graph: &str) -> Arc<RwLock<GraphStore>> {
+        self.graphs.lock().unwrap().get(graph).cloned().unwrap_or_else(|| Arc::clone(&self.store))
+    }
+
     /// Get a reference to the underlying store (for direct graph manipulation)
     pub fn store(&self) -> &Arc<RwLock<GraphStore>> {
         &self.store
@@ -59,6 +71,92 @@ impl EmbeddedClient {
         self.store.write().await
     }
 
+    /// Execute several Cypher statements under a single store write lock.
+    ///
+    /// Statements run in slice order, each seeing the effects of the ones before
+    /// it, and no other client can interleave reads or writes until the batch
+    /// finishes. The batch is **not** atomic: a failing statement does not roll
+    /// back earlier ones, and later statements still run; use
+    /// [`transaction`](Self::transaction) for all-or-nothing writes. The result
+    /// at index `i` belongs to `statements[i]`.
+    ///
+    /// Use this for bulk loading (e.g. thousands of `CREATE`s) to avoid
+    /// re-acquiring the lock per statement.
+    pub async fn execute_batch(&self, graph: &str, statements: &[&str]) -> Vec<SamyamaResult<QueryResult>> {
+        let store = self.graph_store(graph);
+        let mut store_guard = store.write().await;
+        statements
+            .iter()
+            .map(|cypher| {
+                let batch = if is_write_query(cypher) {
+                    self.engine.execute_mut(cypher, &mut store_guard, graph)
+                } else {
+                    self.engine.execute(cypher, &store_guard)
+                };
+                batch
+                    .map(|batch| record_batch_to_query_result(&batch, &store_guard))
+                    .map_err(|e| SamyamaError::QueryError(e.to_string()))
+            })
+            .collect()
+    }
+
+    /// Run `body` as one atomic write under a single store write lock.
+    ///
+    /// Statements issued through the [`Transaction`] see each other's writes.
+    /// If `body` returns `Ok`, all of them are kept; if it returns `Err` (for
+    /// example because a statement failed and was propagated with `?`), every
+    /// write made inside it is rolled back and the error is returned. A panic
+    /// in `body` rolls the writes back too before it unwinds.
+    ///
+    /// ```no_run
+    /// # use samyama_sdk::EmbeddedClient;
+    /// # async fn transfer(client: &EmbeddedClient) -> samyama_sdk::SamyamaResult<()> {
+    /// client.transaction("default", |tx| {
+    ///     tx.query("MATCH (a:Account {id: 1}) SET a.balance = a.balance - 30")?;
+    ///     tx.query("MATCH (b:Account {id: 2}) SET b.balance = b.balance + 30")?;
+    ///     Ok(())
+    /// }).await
+    /// # }
+    /// ```
+    pub async fn transaction<T>(
+        &self,
+        graph: &str,
+        body: impl FnOnce(&mut Transaction<'_>) -> SamyamaResult<T>,
+    ) -> SamyamaResult<T> {
+        let store = self.graph_store(graph);
+        let mut store_guard = store.write().await;
+        store_guard.begin_write_batch()
+            .map_err(|e| SamyamaError::QueryError(e.to_string()))?;
+        let batch = WriteBatchGuard(&mut store_guard);
+        let mut tx = Transaction { store: &mut *batch.0, engine: &self.engine, graph };
+        let result = body(&mut tx);
+        let end = if result.is_ok() {
+            batch.0.commit_write_batch().map(|_| ())
+        } else {
+            batch.0.rollback_write_batch()
+        };
+        end.map_err(|e| SamyamaError::QueryError(e.to_string()))?;
+        result
+    }
+
+    /// Execute a read-only Cypher query, streaming its rows instead of buffering them.
+    ///
+    /// Rows are pulled from the query's operator tree one at a time as the stream
+    /// is polled, in the same JSON form as [`QueryResult::records`], so exporting
+    /// a huge `MATCH (n) RETURN n` holds only the row being consumed (plus
+    /// whatever blocking operators like `ORDER BY` must buffer). Parse and
+    /// planning errors are returned immediately; errors raised while producing
+    /// rows end the stream with an `Err` item.
+    ///
+    /// The stream holds a store read lock until it is dropped, so writers wait
+    /// for it to finish.
+    pub async fn query_stream(&self, graph: &str, cypher: &str) -> SamyamaResult<RecordStream> {
+        let store = self.graph_store(graph).read_owned().await;
+        let cursor = self.engine.execute_cursor(cypher, &store)
+            .map_err(|e| SamyamaError::QueryError(e.to_string()))?;
+        Ok(RecordStream { store, cursor, done: false })
+    }
+
     /// Create an NLQ pipeline for natural language → Cypher translation.
     pub fn nlq_pipeline(
         &self,
@@ -144,6 +242,74 @@ impl Default for EmbeddedClient {
     }
 }
 
+/// Rolls back a write batch that is still open when dropped, i.e. when the
+/// transaction body panicked before it could be committed or rolled back
+struct WriteBatchGuard<'a>(&'a mut GraphStore);
+
+impl Drop for WriteBatchGuard<'_> {
+    fn drop(&mut self) {
+        if self.0.in_write_batch() {
+            let _ = self.0.rollback_write_batch();
+        }
+    }
+}
+
+/// Statements of an [`EmbeddedClient::transaction`], committed or rolled back together
+pub struct Transaction<'a> {
+    store: &'a mut GraphStore,
+    engine: &'a QueryEngine,
+    graph: &'a str,
+}
+
+impl Transaction<'_> {
+    /// Execute a Cypher statement inside the transaction
+    pub fn query(&mut self, cypher: &str) -> SamyamaResult<QueryResult> {
+        let batch = if is_write_query(cypher) {
+            self.engine.execute_mut(cypher, self.store, self.graph)
+        } else {
+            self.engine.execute(cypher, self.store)
+        };
+        batch
+            .map(|batch| record_batch_to_query_result(&batch, self.store))
+            .map_err(|e| SamyamaError::QueryError(e.to_string()))
+    }
+}
+
+/// Rows of a query executed by [`EmbeddedClient::query_stream`], produced lazily.
+pub struct RecordStream {
+    store: OwnedRwLockReadGuard<GraphStore>,
+    cursor: RecordCursor,
+    done: bool,
+}
+
+impl RecordStream {
+    /// Column names, in RETURN order
+    pub fn columns(&self) -> &[String] {
+        self.cursor.columns()
+    }
+}
+
+impl Stream for RecordStream {
+    type Item = SamyamaResult<Vec<serde_json::Value>>;
+
+    fn poll_next(mut self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        if self.done {
+            return Poll::Ready(None);
+        }
+        let this = &mut *self;
+        let item = match this.cursor.next(&this.store) {
+            Ok(Some(record)) => {
+                let (mut nodes, mut edges) = (HashMap::new(), HashMap::new());
+                Some(Ok(record_to_row(&record, this.cursor.columns(), &this.store, &mut nodes, &mut edges)))
+            }
+            Ok(None) => None,
+            Err(e) => Some(Err(SamyamaError::QueryError(e.to_string()))),
+        };
+        this.done = !matches!(item, Some(Ok(_)));
+        Poll::Ready(item)
+    }
+}
+
 /// Convert a RecordBatch from the query engine into an SDK QueryResult.
 fn record_batch_to_query_result(batch: &RecordBatch, store: &GraphStore) -> QueryResult {
     let mut nodes_map: HashMap<String, SdkNode> = HashMap::new();
@@ -151,135 +317,146 @@ fn record_batch_to_query_result(batch: &RecordBatch, store: &GraphStore) -> Quer
     let mut records = Vec::new();
 
     for record in &batch.records {
-        let mut row = Vec::new();
-        for col in &batch.columns {
-            let val = match record.get(col) {
-                Some(v) => v,
-                None => {
-                    row.push(serde_json::Value::Null);
-                    continue;
-                }
-            };
-
-            match val {
-                Value::Node(id, node) => {
-                    let mut properties = serde_json::Map::new();
-                    for (k, v) in &node.properties {
-                        properties.insert(k.clone(), v.to_json());
-                    }
-                    let id_str = id.as_u64().to_string();
-                    let labels: Vec<String> = node.labels.iter().map(|l| l.as_str().to_string()).collect();
-
-                    let node_json = serde_json::json!({
-                        "id": id_str,
-                        "labels": labels,
-                        "properties": properties,
-                    });
+        records.push(record_to_row(record, &batch.columns, store, &mut nodes_map, &mut edges_map));
+    }
 
-                    nodes_map.entry(id_str.clone()).or_insert_with(|| SdkNode {
-                        id: id_str,
-                        labels,
-                        properties: properties.into_iter().collect(),
-                    });
+    QueryResult {
+        nodes: nodes_map.into_values().collect(),
+        edges: edges_map.into_values().collect(),
+        columns: batch.columns.clone(),
+        records,
+    }
+}
 
-                    row.push(node_json);
-                }
-                Value::NodeRef(id) => {
-                    let id_str = id.as_u64().to_string();
-                    // Try to resolve from store
-                    let (labels, properties, node_json) = if let Some(node) = store.get_node(*id) {
-                        let mut props = serde_json::Map::new();
-                        for (k, v) in &node.properties {
-                            props.insert(k.clone(), v.to_json());
-                        }
-                        let lbls: Vec<String> = node.labels.iter().map(|l| l.as_str().to_string()).collect();
-                        let json = serde_json::json!({
-                            "id": id_str,
-                            "labels": lbls,
-                            "properties": props,
-                        });
-                        (lbls, props.into_iter().collect(), json)
-                    } else {
-                        let json = serde_json::json!({ "id": id_str, "labels": [], "properties": {} });
-                        (vec![], HashMap::new(), json)
-                    };
-
-                    nodes_map.entry(id_str.clone()).or_insert_with(|| SdkNode {
-                        id: id_str,
-                        labels,
-                        properties,
-                    });
+/// Convert one engine record into a JSON row, collecting the nodes and edges it references.
+fn record_to_row(
+    record: &Record,
+    columns: &[String],
+    store: &GraphStore,
+    nodes_map: &mut HashMap<String, SdkNode>,
+    edges_map: &mut HashMap<String, SdkEdge>,
+) -> Vec<serde_json::Value> {
+    let mut row = Vec::new();
+    for col in columns {
+        let val = match record.get(col) {
+            Some(v) => v,
+            None => {
+                row.push(serde_json::Value::Null);
+                continue;
+            }
+        };
 
-                    row.push(node_json);
+        match val {
+            Value::Node(id, node) => {
+                let mut properties = serde_json::Map::new();
+                for (k, v) in &node.properties {
+                    properties.insert(k.clone(), v.to_json());
                 }
-                Value::Edge(id, edge) => {
-                    let mut properties = serde_json::Map::new();
-                    for (k, v) in &edge.properties {
-                        properties.insert(k.clone(), v.to_json());
+                let id_str = id.as_u64().to_string();
+                let labels: Vec<String> = node.labels.iter().map(|l| l.as_str().to_string()).collect();
+
+                let node_json = serde_json::json!({
+                    "id": id_str,
+                    "labels": labels,
+                    "properties": properties,
+                });
+
+                nodes_map.entry(id_str.clone()).or_insert_with(|| SdkNode {
+                    id: id_str,
+                    labels,
+                    properties: properties.into_iter().collect(),
+                });
+
+                row.push(node_json);
+            }
+            Value::NodeRef(id) => {
+                let id_str = id.as_u64().to_string();
+                // Try to resolve from store
+                let (labels, properties, node_json) = if let Some(node) = store.get_node(*id) {
+                    let mut props = serde_json::Map::new();
+                    for (k, v) in &node.properties {
+                        props.insert(k.clone(), v.to_json());
                     }
-                    let id_str = id.as_u64().to_string();
-                    let edge_json = serde_json::json!({
-                        "id": id_str,
-                        "source": edge.source.as_u64().to_string(),
-                        "target": edge.target.as_u64().to_string(),
-                        "type": edge.edge_type.as_str(),
-                        "properties": properties,
-                    });
-
-                    edges_map.entry(id_str.clone()).or_insert_with(|| SdkEdge {
-                        id: id_str,
-                        source: edge.source.as_u64().to_string(),
-                        target: edge.target.as_u64().to_string(),
-                        edge_type: edge.edge_type.as_str().to_string(),
-                        properties: properties.into_iter().collect(),
-                    });
-
-                    row.push(edge_json);
-                }
-                Value::EdgeRef(id, src, tgt, et) => {
-                    let id_str = id.as_u64().to_string();
-                    let edge_json = serde_json::json!({
+                    let lbls: Vec<String> = node.labels.iter().map(|l| l.as_str().to_string()).collect();
+                    let json = serde_json::json!({
                         "id": id_str,
-                        "source": src.as_u64().to_string(),
-                        "target": tgt.as_u64().to_string(),
-                        "type": et.as_str(),
-                        "properties": {},
-                    });
-
-                    edges_map.entry(id_str.clone()).or_insert_with(|| SdkEdge {
-                        id: id_str,
-                        source: src.as_u64().to_string(),
-                        target: tgt.as_u64().to_string(),
-                        edge_type: et.as_str().to_string(),
-                        properties: HashMap::new(),
+                        "labels": lbls,
+                        "properties": props,
                     });
-
-                    row.push(edge_json);
-                }
-                Value::Property(p) => {
-                    row.push(p.to_json());
-                }
-                Value::Path { nodes: path_nodes, edges: path_edges } => {
-                    row.push(serde_json::json!({
-                        "nodes": path_nodes.iter().map(|n| n.as_u64().to_string()).collect::<Vec<_>>(),
-                        "edges": path_edges.iter().map(|e| e.as_u64().to_string()).collect::<Vec<_>>(),
-                        "length": path_edges.len(),
-                    }));
-                }
-                Value::Null => {
-                    row.push(serde_json::Value::Null);
+                    (lbls, props.into_iter().collect(), json)
+                } else {
+                    let json = serde_json::json!({ "id": id_str, "labels": [], "properties": {} });
+                    (vec![], HashMap::new(), json)
+                };
+
+                nodes_map.entry(id_str.clone()).or_insert_with(|| SdkNode {
+                    id: id_str,
+                    labels,
+                    properties,
+                });
+
+                row.push(node_json);
+            }
+            Value::Edge(id, edge) => {
+                let mut properties = serde_json::Map::new();
+                for (k, v) in &edge.properties {
+                    properties.insert(k.clone(), v.to_json());
                 }
+                let id_str = id.as_u64().to_string();
+                let edge_json = serde_json::json!({
+                    "id": id_str,
+                    "source": edge.source.as_u64().to_string(),
+                    "target": edge.target.as_u64().to_string(),
+                    "type": edge.edge_type.as_str(),
+                    "properties": properties,
+                });
+
+                edges_map.entry(id_str.clone()).or_insert_with(|| SdkEdge {
+                    id: id_str,
+                    source: edge.source.as_u64().to_string(),
+                    target: edge.target.as_u64().to_string(),
+                    edge_type: edge.edge_type.as_str().to_string(),
+                    properties: properties.into_iter().collect(),
+                });
+
+                row.push(edge_json);
+            }
+            Value::EdgeRef(id, src, tgt, et) => {
+                let id_str = id.as_u64().to_string();
+                let edge_json = serde_json::json!({
+                    "id": id_str,
+                    "source": src.as_u64().to_string(),
+                    "target": tgt.as_u64().to_string(),
+                    "type": et.as_str(),
+                    "properties": {},
+                });
+
+                edges_map.entry(id_str.clone()).or_insert_with(|| SdkEdge {
+                    id: id_str,
+                    source: src.as_u64().to_string(),
+                    target: tgt.as_u64().to_string(),
+                    edge_type: et.as_str().to_string(),
+                    properties: HashMap::new(),
+                });
+
+                row.push(edge_json);
+            }
+            Value::Property(p) => {
+                row.push(p.to_json());
+            }
+            Value::Path { nodes: path_nodes, edges: path_edges } => {
+                row.push(serde_json::json!({
+                    "nodes": path_nodes.iter().map(|n| n.as_u64().to_string()).collect::<Vec<_>>(),
+                    "edges": path_edges.iter().map(|e| e.as_u64().to_string()).collect::<Vec<_>>(),
+                    "length": path_edges.len(),
+                }));
+            }
+            Value::Null => {
+                row.push(serde_json::Value::Null);
             }
         }
-        records.push(row);
-    }
-
-    QueryResult {
-        nodes: nodes_map.into_values().collect(),
-        edges: edges_map.into_values().collect(),
-        columns: batch.columns.clone(),
-        records,
     }
+    row
 }
 
 fn is_write_query(cypher: &str) -> bool {
@@ -299,34 +476,84 @@ fn is_write_query(cypher: &str) -> bool {
 #[async_trait]
 impl SamyamaClient for EmbeddedClient {
     async fn query(&self, graph: &str, cypher: &str) -> SamyamaResult<QueryResult> {
+        self.query_with_params(graph, cypher, &HashMap::new()).await
+    }
+
+    async fn query_with_params(
+        &self,
+        graph: &str,
+        cypher: &str,
+        params: &HashMap<String, PropertyValue>,
+    ) -> SamyamaResult<QueryResult> {
+        let store = self.graph_store(graph);
         if is_write_query(cypher) {
-            let mut store_guard = self.store.write().await;
-            let batch = self.engine.execute_mut(cypher, &mut *store_guard, graph)
+            let mut store_guard = store.write().await;
+            let batch = self.engine.execute_mut_with_params(cypher, &mut *store_guard, graph, params.clone())
                 .map_err(|e| SamyamaError::QueryError(e.to_string()))?;
             Ok(record_batch_to_query_result(&batch, &*store_guard))
         } else {
-            let store_guard = self.store.read().await;
-            let batch = self.engine.execute(cypher, &*store_guard)
+            let store_guard = store.read().await;
+            let batch = self.engine.execute_with_params(cypher, &*store_guard, params.clone())
                 .map_err(|e| SamyamaError::QueryError(e.to_string()))?;
             Ok(record_batch_to_query_result(&batch, &*store_guard))
         }
     }
 
-    async fn query_readonly(&self, _graph: &str, cypher: &str) -> SamyamaResult<QueryResult> {
-        let store_guard = self.store.read().await;
+    async fn query_readonly(&self, graph: &str, cypher: &str) -> SamyamaResult<QueryResult> {
+        let store = self.graph_store(graph);
+        let store_guard = store.read().await;
         let batch = self.engine.execute(cypher, &*store_guard)
             .map_err(|e| SamyamaError::QueryError(e.to_string()))?;
         Ok(record_batch_to_query_result(&batch, &*store_guard))
     }
 
-    async fn delete_graph(&self, _graph: &str) -> SamyamaResult<()> {
+    async fn query_readonly_with_params(
+        &self,
+        graph: &str,
+        cypher: &str,
+        params: &HashMap<String, PropertyValue>,
+    ) -> SamyamaResult<QueryResult> {
+        let store = self.graph_store(graph);
+        let store_guard = store.read().await;
+        let batch = self.engine.execute_with_params(cypher, &store_guard, params.clone())
+            .map_err(|e| SamyamaError::QueryError(e.to_string()))?;
+        Ok(record_batch_to_query_result(&batch, &store_guard))
+    }
+
+    async fn delete_graph(&self, graph: &str) -> SamyamaResult<()> {
+        if self.graphs.lock().unwrap().remove(graph).is_some() {
+            return Ok(());
+        }
         let mut store_guard = self.store.write().await;
         store_guard.clear();
         Ok(())
     }
 
+    async fn copy_graph(&self, src: &str, dst: &str) -> SamyamaResult<()> {
+        if dst == src || dst == "default" || self.graphs.lock().unwrap().contains_key(dst) {
+            return Err(SamyamaError::QueryError(format!("graph '{}' already exists", dst)));
+        }
+        // Holding the source's read lock keeps writers out for a point-in-time copy
+        let src_store = self.graph_store(src);
+        let src_guard = src_store.read().await;
+        let (copied, _) = samyama::snapshot::copy_tenant(&src_guard)
+            .map_err(|e| SamyamaError::QueryError(e.to_string()))?;
+        drop(src_guard);
+
+        let mut graphs = self.graphs.lock().unwrap();
+        if graphs.contains_key(dst) {
+            return Err(SamyamaError::QueryError(format!("graph '{}' already exists", dst)));
+        }
+        graphs.insert(dst.to_string(), Arc::new(RwLock::new(copied)));
+        Ok(())
+    }
+
     async fn list_graphs(&self) -> SamyamaResult<Vec<String>> {
-        Ok(vec!["default".to_string()])
+        let mut names = vec!["default".to_string()];
+        let mut copies: Vec<String> = self.graphs.lock().unwrap().keys().cloned().collect();
+        copies.sort();
+        names.extend(copies);
+        Ok(names)
     }
 
     async fn status(&self) -> SamyamaResult<ServerStatus> {
@@ -341,6 +568,14 @@ impl SamyamaClient for EmbeddedClient {
         })
     }
 
+    async fn stats(&self, graph: &str) -> SamyamaResult<GraphStats> {
+        Ok(self.graph_store(graph).read().await.stats())
+    }
+
+    async fn schema(&self, graph: &str) -> SamyamaResult<SchemaInfo> {
+        Ok(self.graph_store(graph).read().await.schema_info())
+    }
+
     async fn ping(&self) -> SamyamaResult<String> {
         Ok("PONG".to_string())
     }
@@ -386,6 +621,21 @@ mod tests {
         assert_eq!(status.storage.nodes, 2);
     }
 
+    #[tokio::test]
+    async fn test_embedded_query_readonly_with_params() {
+        let client = EmbeddedClient::new();
+        client.query("default", r#"CREATE (n:Person {name: "Alice"})"#).await.unwrap();
+
+        let mut params = HashMap::new();
+        params.insert("name".to_string(), PropertyValue::String("Alice".to_string()));
+        let result = client.query_readonly_with_params("default", "MATCH (n:Person) WHERE n.name = $name RETURN n.name", &params)
+            .await.unwrap();
+        assert_eq!(result.records.len(), 1);
+
+        assert!(client.query_readonly_with_params("default", "CREATE (n:Person) RETURN $name AS name", &params).await.is_err());
+        assert_eq!(client.status().await.unwrap().storage.nodes, 1);
+    }
+
     #[tokio::test]
     async fn test_embedded_delete_graph() {
         let client = EmbeddedClient::new();
@@ -402,6 +652,154 @@ mod tests {
         assert_eq!(status.storage.nodes, 0);
     }
 
+    #[tokio::test]
+    async fn test_embedded_copy_graph() {
+        let client = EmbeddedClient::new();
+        client.query("default", "CREATE INDEX ON :Person(name)").await.unwrap();
+        client.query("default", r#"CREATE (a:Person {name: "Alice"})-[:KNOWS]->(b:Person {name: "Bob"})"#)
+            .await.unwrap();
+
+        client.copy_graph("default", "copy").await.unwrap();
+        let (src, copy) = (client.stats("default").await.unwrap(), client.stats("copy").await.unwrap());
+        assert_eq!((copy.nodes, copy.edges, copy.indexes.len()), (src.nodes, src.edges, src.indexes.len()));
+        assert_eq!(client.list_graphs().await.unwrap(), vec!["default", "copy"]);
+
+        // Writes to the copy don't reach the source
+        client.query("copy", r#"CREATE (n:Person {name: "Carol"})"#).await.unwrap();
+        let result = client.query_readonly("copy", "MATCH (n:Person) RETURN n.name").await.unwrap();
+        assert_eq!(result.records.len(), 3);
+        assert_eq!(client.stats("default").await.unwrap().nodes, 2);
+
+        assert!(client.copy_graph("default", "copy").await.is_err());
+        client.delete_graph("copy").await.unwrap();
+        assert_eq!(client.list_graphs().await.unwrap(), vec!["default"]);
+        assert_eq!(client.stats("default").await.unwrap().nodes, 2);
+    }
+
+    #[tokio::test]
+    async fn test_copy_graph_routes_batch_transaction_and_stream() {
+        use futures::StreamExt;
+
+        let client = EmbeddedClient::new();
+        client.query("default", r#"CREATE (n:Person {name: "Alice"})"#).await.unwrap();
+        client.copy_graph("default", "copy").await.unwrap();
+
+        client.transaction("copy", |tx| {
+            tx.query(r#"CREATE (n:Person {name: "Bob"})"#)?;
+            Ok(())
+        }).await.unwrap();
+        let results = client.execute_batch("copy", &[r#"CREATE (n:Person {name: "Carol"})"#]).await;
+        assert!(results[0].is_ok());
+
+        assert_eq!(client.stats("copy").await.unwrap().nodes, 3);
+        assert_eq!(client.stats("default").await.unwrap().nodes, 1);
+        let copy_rows = client.query_stream("copy", "MATCH (n:Person) RETURN n.name").await.unwrap();
+        assert_eq!(copy_rows.count().await, 3);
+        let default_rows = client.query_stream("default", "MATCH (n:Person) RETURN n.name").await.unwrap();
+        assert_eq!(default_rows.count().await, 1);
+    }
+
+    #[tokio::test]
+    async fn test_execute_batch_is_ordered_and_best_effort() {
+        let client = EmbeddedClient::new();
+
+        let results = client.execute_batch("default", &[
+            r#"CREATE (n:Person {name: "Alice"})"#,
+            "THIS IS NOT CYPHER",
+            r#"CREATE (n:Person {name: "Bob"})"#,
+            "MATCH (n:Person) RETURN n.name",
+        ]).await;
+
+        assert_eq!(results.len(), 4);
+        assert!(results[0].is_ok());
+        assert!(matches!(results[1], Err(SamyamaError::QueryError(_))));
+        assert!(results[2].is_ok());
+        // The read sees both creates, including the one after the failure
+        assert_eq!(results[3].as_ref().unwrap().records.len(), 2);
+
+        let status = client.status().await.unwrap();
+        assert_eq!(status.storage.nodes, 2);
+    }
+
+    #[tokio::test]
+    async fn test_transaction_rolls_back_on_error() {
+        let client = EmbeddedClient::new();
+        client.query("default", "CREATE (a:Account {id: 1, balance: 100}), (b:Account {id: 2, balance: 50})").await.unwrap();
+
+        let result = client.transaction("default", |tx| {
+            tx.query("MATCH (a:Account {id: 1}) SET a.balance = 70")?;
+            tx.query("THIS IS NOT CYPHER")?;
+            tx.query("MATCH (b:Account {id: 2}) SET b.balance = 80")?;
+            Ok(())
+        }).await;
+        assert!(matches!(result, Err(SamyamaError::QueryError(_))));
+
+        let balances = client.query("default", "MATCH (a:Account) RETURN a.balance ORDER BY a.balance").await.unwrap();
+        assert_eq!(balances.records, vec![vec![serde_json::json!(50)], vec![serde_json::json!(100)]]);
+
+        let moved = client.transaction("default", |tx| {
+            tx.query("MATCH (a:Account {id: 1}) SET a.balance = 70")?;
+            tx.query("MATCH (b:Account {id: 2}) SET b.balance = 80")?;
+            let check = tx.query("MATCH (a:Account) WHERE a.balance > 60 RETURN a")?;
+            Ok(check.records.len())
+        }).await.unwrap();
+        assert_eq!(moved, 2);
+        assert!(!client.store_read().await.in_write_batch());
+    }
+
+    #[tokio::test]
+    async fn test_transaction_rolls_back_on_panic() {
+        use futures::FutureExt;
+
+        let client = EmbeddedClient::new();
+        client.query("default", "CREATE (a:Account {id: 1, balance: 100})").await.unwrap();
+
+        let unwound = std::panic::AssertUnwindSafe(client.transaction("default", |tx| {
+            tx.query("MATCH (a:Account {id: 1}) SET a.balance = 70")?;
+            tx.query("CREATE (:Account {id: 2, balance: 30})")?;
+            panic!("body failed mid-transaction");
+            #[allow(unreachable_code)]
+            Ok(())
+        }))
+        .catch_unwind()
+        .await;
+        assert!(unwound.is_err());
+
+        assert!(!client.store_read().await.in_write_batch());
+        let balances = client.query("default", "MATCH (a:Account) RETURN a.balance").await.unwrap();
+        assert_eq!(balances.records, vec![vec![serde_json::json!(100)]]);
+    }
+
+    #[tokio::test]
+    async fn test_query_stream_yields_rows_lazily() {
+        use futures::StreamExt;
+
+        let client = EmbeddedClient::new();
+        {
+            let mut store = client.store_write().await;
+            for i in 0..100i64 {
+                let id = store.create_node("Item");
+                store.get_node_mut(id).unwrap().set_property("n", i);
+            }
+        }
+
+        let mut stream = client.query_stream("default", "MATCH (i:Item) RETURN i.n").await.unwrap();
+        assert_eq!(stream.columns(), ["i.n"]);
+        let first: Vec<_> = stream.by_ref().take(3).collect().await;
+        assert_eq!(first.len(), 3);
+        assert!(first.iter().all(|row| row.as_ref().unwrap()[0].is_i64()));
+        assert_eq!(stream.count().await, 97);
+
+        // Writers proceed once the stream (and its read lock) is dropped
+        client.query("default", "CREATE (i:Item {n: 100})").await.unwrap();
+
+        let mut rows = client.query_stream("default", "MATCH (i:Item) RETURN i ORDER BY i.n DESC").await.unwrap();
+        let top = rows.next().await.unwrap().unwrap();
+        assert_eq!(top[0]["properties"]["n"], 100);
+
+        assert!(client.query_stream("default", "NOT CYPHER").await.is_err());
+    }
+
     #[tokio::test]
     async fn test_embedded_list_graphs() {
         let client = EmbeddedClient::new();
@@ -47,10 +47,13 @@ pub mod vector_ext;
 // ============================================================
 
 pub use client::SamyamaClient;
-pub use embedded::EmbeddedClient;
-pub use remote::RemoteClient;
+pub use embedded::{EmbeddedClient, RecordStream, Transaction};
+pub use remote::{RemoteClient, RemoteClientConfig};
 pub use error::{SamyamaError, SamyamaResult};
-pub use models::{QueryResult, SdkNode, SdkEdge, ServerStatus, StorageStats};
+pub use models::{
+    EdgeTypeSchema, GraphStats, IndexedProperty, LabelSchema, NameCount, QueryResult, SchemaInfo,
+    SdkNode, SdkEdge, ServerStatus, SnapshotImport, StorageStats,
+};
 
 // ============================================================
 // Extension traits (EmbeddedClient only)
@@ -64,7 +67,7 @@ pub use vector_ext::VectorClient;
 // ============================================================
 
 pub use samyama::graph::{
-    GraphStore, Node, Edge, NodeId, EdgeId, EdgeType, Label,
+    GraphStore, Node, Edge, NodeId, EdgeId, EdgeType, EdgeDirection, Label,
     PropertyValue, PropertyMap,
     GraphError, GraphResult,
 };
@@ -75,7 +78,7 @@ pub use samyama::query::{QueryEngine, RecordBatch, CacheStats};
 // ============================================================
 
 pub use samyama::algo::{
-    build_view, page_rank, weakly_connected_components, strongly_connected_components,
+    build_view, Directedness, page_rank, weakly_connected_components, strongly_connected_components,
     bfs, dijkstra, edmonds_karp, prim_mst, count_triangles, pca,
     PageRankConfig, PathResult, WccResult, SccResult, FlowResult, MSTResult,
     PcaConfig, PcaResult, PcaSolver,
@@ -88,7 +91,7 @@ pub use samyama_graph_algorithms::GraphView;
 
 pub use samyama::vector::{
     DistanceMetric, VectorIndex, VectorIndexManager, IndexKey,
-    VectorError, VectorResult,
+    VectorError, VectorResult, VectorFilter, HnswConfig, recall_at_k,
 };
 
 // ============================================================
@@ -124,6 +127,7 @@ pub use samyama::persistence::tenant::{AgentConfig, ToolConfig};
 pub use samyama_optimization::{
     SolverConfig, OptimizationResult, Individual, SimpleProblem,
     Problem, MultiObjectiveProblem, MultiObjectiveIndividual, MultiObjectiveResult,
+    VariableType,
 };
 pub use samyama_optimization::algorithms::{
     JayaSolver, CuckooSolver, NSGA2Solver,
@@ -69,6 +69,12 @@ pub struct ServerStatus {
     pub storage: StorageStats,
 }
 
+/// Per-label and per-type breakdown of a graph
+pub use samyama::graph::{IndexedProperty, NameCount, StoreStats as GraphStats};
+
+/// Labels, property types and edge types of a graph
+pub use samyama::graph::{EdgeTypeSchema, LabelSchema, SchemaInfo};
+
 /// Storage statistics
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StorageStats {
@@ -77,3 +83,21 @@ pub struct StorageStats {
     /// Number of edges
     pub edges: u64,
 }
+
+/// Outcome of restoring a snapshot on a server
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SnapshotImport {
+    /// Nodes created from the snapshot
+    pub nodes_imported: u64,
+    /// Snapshot nodes merged into existing nodes by dedup key
+    #[serde(default)]
+    pub nodes_merged: u64,
+    /// Edges created from the snapshot
+    pub edges_imported: u64,
+    /// Labels present in the snapshot
+    #[serde(default)]
+    pub labels: Vec<String>,
+    /// Edge types present in the snapshot
+    #[serde(default)]
+    pub edge_types: Vec<String>,
+}
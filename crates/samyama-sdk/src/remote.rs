@@ -1,24 +1,75 @@
 //! RemoteClient — network client for a running Samyama server
 //!
-//! Connects via HTTP to the Samyama HTTP API.
+//! Connects via HTTP to the Samyama HTTP API. Connections are pooled and every
+//! request is bounded by the timeouts in [`RemoteClientConfig`], so a dead or
+//! unreachable server produces an error instead of a hang.
 
 use async_trait::async_trait;
-use reqwest::Client;
+use futures::stream::{self, Stream};
+use reqwest::multipart::{Form, Part};
+use reqwest::{Body, Client, RequestBuilder, Response};
+use samyama::graph::PropertyValue;
+use std::collections::{HashMap, VecDeque};
+use std::path::Path;
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
 
 use crate::client::SamyamaClient;
 use crate::error::{SamyamaError, SamyamaResult};
-use crate::models::{QueryResult, ServerStatus};
+use crate::models::{GraphStats, QueryResult, SchemaInfo, ServerStatus, SnapshotImport};
+
+/// Connection settings for a [`RemoteClient`].
+///
+/// The defaults are 8 pooled connections, a 5 second connect timeout, a
+/// 30 second request timeout and 2 retries.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RemoteClientConfig {
+    /// Maximum number of idle connections kept open for reuse
+    pub pool_size: usize,
+    /// Time allowed to establish a TCP connection
+    pub connect_timeout: Duration,
+    /// Time allowed for a whole request, from connecting to reading the response body.
+    /// Snapshot transfers use a 24 hour limit instead.
+    pub request_timeout: Duration,
+    /// Extra attempts for idempotent reads (`query_readonly`, `status`, paged
+    /// queries) that fail to connect or time out. Writes are never retried.
+    pub retries: u32,
+}
+
+impl Default for RemoteClientConfig {
+    fn default() -> Self {
+        Self {
+            pool_size: 8,
+            connect_timeout: Duration::from_secs(5),
+            request_timeout: Duration::from_secs(30),
+            retries: 2,
+        }
+    }
+}
+
+/// Delay before the first retry; doubles on each further attempt
+const RETRY_BACKOFF: Duration = Duration::from_millis(100);
+
+/// Timeout for snapshot transfers, which replaces the request timeout because
+/// multi-gigabyte snapshots take far longer than a query
+const SNAPSHOT_TIMEOUT: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Bytes read from disk per chunk of a snapshot upload
+const UPLOAD_CHUNK_SIZE: usize = 1 << 20;
 
 /// Network client that connects to a running Samyama server.
 ///
 /// Uses HTTP transport for `/api/query` and `/api/status` endpoints.
+/// A single pooled HTTP client is shared by all requests.
 pub struct RemoteClient {
     http_base_url: String,
     http_client: Client,
+    config: RemoteClientConfig,
 }
 
 impl RemoteClient {
-    /// Create a new RemoteClient connecting to the given HTTP base URL.
+    /// Create a new RemoteClient connecting to the given HTTP base URL,
+    /// using [`RemoteClientConfig::default`].
     ///
     /// # Example
     /// ```no_run
@@ -26,54 +77,272 @@ impl RemoteClient {
     /// let client = RemoteClient::new("http://localhost:8080");
     /// ```
     pub fn new(http_base_url: &str) -> Self {
-        Self {
+        Self::with_config(http_base_url, RemoteClientConfig::default())
+            .expect("default HTTP client configuration is valid")
+    }
+
+    /// Create a RemoteClient with explicit pooling, timeout and retry settings.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use samyama_sdk::{RemoteClient, RemoteClientConfig};
+    /// # use std::time::Duration;
+    /// let config = RemoteClientConfig {
+    ///     request_timeout: Duration::from_secs(5),
+    ///     ..Default::default()
+    /// };
+    /// let client = RemoteClient::with_config("http://localhost:8080", config).unwrap();
+    /// ```
+    pub fn with_config(http_base_url: &str, config: RemoteClientConfig) -> SamyamaResult<Self> {
+        let http_client = Client::builder()
+            .pool_max_idle_per_host(config.pool_size)
+            .connect_timeout(config.connect_timeout)
+            .timeout(config.request_timeout)
+            .build()?;
+        Ok(Self {
             http_base_url: http_base_url.trim_end_matches('/').to_string(),
-            http_client: Client::new(),
+            http_client,
+            config,
+        })
+    }
+
+    /// The settings this client was created with
+    pub fn config(&self) -> &RemoteClientConfig {
+        &self.config
+    }
+
+    /// Stream the rows of a read-only query, fetching them `page_size` at a time.
+    ///
+    /// The remote counterpart of [`EmbeddedClient::query_stream`](crate::EmbeddedClient::query_stream):
+    /// each page is requested by appending `SKIP <offset> LIMIT <page_size>` to
+    /// `cypher`, and the next page is only fetched once the current one has been
+    /// consumed, so at most one page is buffered. The query must not carry its own
+    /// `SKIP`/`LIMIT`, and should `ORDER BY` a unique key so pages do not overlap.
+    /// The stream ends after the first short page or the first error.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `page_size` is zero.
+    pub fn query_paged<'a>(
+        &'a self,
+        graph: &'a str,
+        cypher: &'a str,
+        page_size: usize,
+    ) -> impl Stream<Item = SamyamaResult<Vec<serde_json::Value>>> + 'a {
+        assert!(page_size > 0, "page_size must be positive");
+        let state = (0usize, VecDeque::new(), false);
+        stream::unfold(state, move |(offset, mut rows, exhausted)| async move {
+            if rows.is_empty() && !exhausted {
+                match self.post_query(graph, &paged_cypher(cypher, offset, page_size), &HashMap::new(), true).await {
+                    Ok(page) => {
+                        let fetched = page.records.len();
+                        rows.extend(page.records);
+                        return rows
+                            .pop_front()
+                            .map(|row| (Ok(row), (offset + fetched, rows, fetched < page_size)));
+                    }
+                    Err(e) => return Some((Err(e), (offset, rows, true))),
+                }
+            }
+            rows.pop_front().map(|row| (Ok(row), (offset, rows, exhausted)))
+        })
+    }
+
+    /// Download a snapshot of the server's graph to `path`.
+    ///
+    /// The response body is written to disk chunk by chunk, so the snapshot is
+    /// never held in client memory; `on_progress` receives the bytes written so
+    /// far after each chunk. Returns the snapshot size in bytes. The HTTP
+    /// snapshot API serves a single graph, so any `graph` other than
+    /// `"default"` is rejected before a request is sent.
+    pub async fn export_snapshot(
+        &self,
+        graph: &str,
+        path: &Path,
+        mut on_progress: impl FnMut(u64),
+    ) -> SamyamaResult<u64> {
+        require_default_graph(graph)?;
+        let url = format!("{}/api/snapshot/export", self.http_base_url);
+        let mut response = self.send(|| self.http_client.post(&url).timeout(SNAPSHOT_TIMEOUT), true).await?;
+        if !response.status().is_success() {
+            return Err(SamyamaError::PersistenceError(error_message(response).await));
+        }
+
+        let mut file = tokio::fs::File::create(path).await?;
+        let mut written = 0u64;
+        while let Some(chunk) = response.chunk().await? {
+            file.write_all(&chunk).await?;
+            written += chunk.len() as u64;
+            on_progress(written);
         }
+        file.flush().await?;
+        Ok(written)
     }
 
-    /// Execute a POST request to /api/query
-    async fn post_query(&self, graph: &str, cypher: &str) -> SamyamaResult<QueryResult> {
-        let url = format!("{}/api/query", self.http_base_url);
-        let body = serde_json::json!({ "query": cypher, "graph": graph });
+    /// Upload the snapshot at `path` into the server's graph.
+    ///
+    /// The file is streamed as a chunked multipart upload rather than read into
+    /// memory first; `on_progress` receives the bytes sent so far. Uploads are
+    /// never retried. Like [`export_snapshot`](Self::export_snapshot), only
+    /// the `"default"` graph is accepted.
+    pub async fn import_snapshot(
+        &self,
+        graph: &str,
+        path: &Path,
+        on_progress: impl FnMut(u64) + Send + 'static,
+    ) -> SamyamaResult<SnapshotImport> {
+        require_default_graph(graph)?;
+        let url = format!("{}/api/snapshot/import", self.http_base_url);
+        let file = tokio::fs::File::open(path).await?;
+        let chunks = stream::unfold(Some((file, 0u64, on_progress)), |state| async move {
+            let (mut file, sent, mut on_progress) = state?;
+            let mut buf = vec![0u8; UPLOAD_CHUNK_SIZE];
+            match file.read(&mut buf).await {
+                Ok(0) => None,
+                Ok(n) => {
+                    buf.truncate(n);
+                    let sent = sent + n as u64;
+                    on_progress(sent);
+                    Some((Ok(buf), Some((file, sent, on_progress))))
+                }
+                Err(e) => Some((Err(e), None)),
+            }
+        });
+        let file_name = path.file_name().map(|n| n.to_string_lossy().into_owned()).unwrap_or_default();
+        let form = Form::new().part("file", Part::stream(Body::wrap_stream(chunks)).file_name(file_name));
 
         let response = self.http_client.post(&url)
-            .json(&body)
+            .timeout(SNAPSHOT_TIMEOUT)
+            .multipart(form)
             .send()
             .await?;
+        if response.status().is_success() {
+            Ok(response.json().await?)
+        } else {
+            Err(SamyamaError::PersistenceError(error_message(response).await))
+        }
+    }
+
+    /// Send a request, retrying connection failures and timeouts when `idempotent`
+    async fn send(&self, request: impl Fn() -> RequestBuilder, idempotent: bool) -> SamyamaResult<Response> {
+        let retries = if idempotent { self.config.retries } else { 0 };
+        let mut backoff = RETRY_BACKOFF;
+        let mut attempt = 0;
+        loop {
+            match request().send().await {
+                Err(e) if attempt < retries && (e.is_connect() || e.is_timeout()) => {
+                    attempt += 1;
+                    tokio::time::sleep(backoff).await;
+                    backoff *= 2;
+                }
+                result => return Ok(result?),
+            }
+        }
+    }
+
+    /// Execute a POST request to /api/query
+    ///
+    /// `readonly` queries run on the server's read-only path, which rejects
+    /// writes, so they are safe to retry.
+    async fn post_query(
+        &self,
+        graph: &str,
+        cypher: &str,
+        params: &HashMap<String, PropertyValue>,
+        readonly: bool,
+    ) -> SamyamaResult<QueryResult> {
+        let url = format!("{}/api/query", self.http_base_url);
+        let mut body = serde_json::json!({ "query": cypher, "graph": graph });
+        if readonly {
+            body["readonly"] = serde_json::Value::Bool(true);
+        }
+        if !params.is_empty() {
+            let params: serde_json::Map<String, serde_json::Value> = params.iter()
+                .map(|(k, v)| (k.clone(), v.to_json()))
+                .collect();
+            body["params"] = serde_json::Value::Object(params);
+        }
+
+        let response = self.send(|| self.http_client.post(&url).json(&body), readonly).await?;
 
         if response.status().is_success() {
             let result: QueryResult = response.json().await?;
             Ok(result)
         } else {
-            let error_body: serde_json::Value = response.json().await
-                .unwrap_or_else(|_| serde_json::json!({"error": "Unknown error"}));
-            let msg = error_body.get("error")
-                .and_then(|v| v.as_str())
-                .unwrap_or("Unknown error")
-                .to_string();
-            Err(SamyamaError::QueryError(msg))
+            Err(SamyamaError::QueryError(error_message(response).await))
         }
     }
 }
 
+/// The `error` field of a failed response's JSON body
+/// The HTTP snapshot endpoints always act on the server's default graph
+fn require_default_graph(graph: &str) -> SamyamaResult<()> {
+    if graph == "default" {
+        Ok(())
+    } else {
+        Err(SamyamaError::PersistenceError(format!(
+            "snapshots are only available for the default graph, not '{}'",
+            graph
+        )))
+    }
+}
+
+async fn error_message(response: Response) -> String {
+    let error_body: serde_json::Value = response.json().await
+        .unwrap_or_else(|_| serde_json::json!({"error": "Unknown error"}));
+    error_body.get("error")
+        .and_then(|v| v.as_str())
+        .unwrap_or("Unknown error")
+        .to_string()
+}
+
+/// Append the pagination clauses for one page of `cypher`.
+fn paged_cypher(cypher: &str, skip: usize, limit: usize) -> String {
+    format!("{} SKIP {} LIMIT {}", cypher.trim_end().trim_end_matches(';'), skip, limit)
+}
+
 #[async_trait]
 impl SamyamaClient for RemoteClient {
     async fn query(&self, graph: &str, cypher: &str) -> SamyamaResult<QueryResult> {
-        self.post_query(graph, cypher).await
+        self.post_query(graph, cypher, &HashMap::new(), false).await
+    }
+
+    async fn query_with_params(
+        &self,
+        graph: &str,
+        cypher: &str,
+        params: &HashMap<String, PropertyValue>,
+    ) -> SamyamaResult<QueryResult> {
+        self.post_query(graph, cypher, params, false).await
     }
 
     async fn query_readonly(&self, graph: &str, cypher: &str) -> SamyamaResult<QueryResult> {
-        self.post_query(graph, cypher).await
+        self.post_query(graph, cypher, &HashMap::new(), true).await
+    }
+
+    async fn query_readonly_with_params(
+        &self,
+        graph: &str,
+        cypher: &str,
+        params: &HashMap<String, PropertyValue>,
+    ) -> SamyamaResult<QueryResult> {
+        self.post_query(graph, cypher, params, true).await
     }
 
     async fn delete_graph(&self, graph: &str) -> SamyamaResult<()> {
         // The HTTP API doesn't expose GRAPH.DELETE directly.
         // We can execute a Cypher that deletes all nodes/edges.
-        self.post_query(graph, "MATCH (n) DELETE n").await?;
+        self.post_query(graph, "MATCH (n) DELETE n", &HashMap::new(), false).await?;
         Ok(())
     }
 
+    async fn copy_graph(&self, _src: &str, _dst: &str) -> SamyamaResult<()> {
+        // The HTTP API has no copy endpoint; copies are made over RESP
+        Err(SamyamaError::QueryError(
+            "copy_graph is not supported over HTTP; use GRAPH.COPY over RESP".to_string()
+        ))
+    }
+
     async fn list_graphs(&self) -> SamyamaResult<Vec<String>> {
         // Single-graph mode in OSS
         Ok(vec!["default".to_string()])
@@ -81,9 +350,7 @@ impl SamyamaClient for RemoteClient {
 
     async fn status(&self) -> SamyamaResult<ServerStatus> {
         let url = format!("{}/api/status", self.http_base_url);
-        let response = self.http_client.get(&url)
-            .send()
-            .await?;
+        let response = self.send(|| self.http_client.get(&url), true).await?;
 
         if response.status().is_success() {
             let status: ServerStatus = response.json().await?;
@@ -95,6 +362,34 @@ impl SamyamaClient for RemoteClient {
         }
     }
 
+    async fn stats(&self, _graph: &str) -> SamyamaResult<GraphStats> {
+        // Single-graph mode in OSS
+        let url = format!("{}/api/stats", self.http_base_url);
+        let response = self.send(|| self.http_client.get(&url), true).await?;
+
+        if response.status().is_success() {
+            Ok(response.json().await?)
+        } else {
+            Err(SamyamaError::ConnectionError(
+                format!("Stats endpoint returned {}", response.status())
+            ))
+        }
+    }
+
+    async fn schema(&self, _graph: &str) -> SamyamaResult<SchemaInfo> {
+        // Single-graph mode in OSS
+        let url = format!("{}/api/schema", self.http_base_url);
+        let response = self.send(|| self.http_client.get(&url), true).await?;
+
+        if response.status().is_success() {
+            Ok(response.json().await?)
+        } else {
+            Err(SamyamaError::ConnectionError(
+                format!("Schema endpoint returned {}", response.status())
+            ))
+        }
+    }
+
     async fn ping(&self) -> SamyamaResult<String> {
         let status = self.status().await?;
         if status.status == "healthy" {
@@ -106,3 +401,175 @@ impl SamyamaClient for RemoteClient {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn quick_config() -> RemoteClientConfig {
+        RemoteClientConfig {
+            connect_timeout: Duration::from_millis(200),
+            request_timeout: Duration::from_millis(300),
+            retries: 1,
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_silent_server_times_out() {
+        // Accepted by the kernel backlog but never answered
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let client = RemoteClient::with_config(&url, quick_config()).unwrap();
+
+        let start = std::time::Instant::now();
+        let err = client.status().await.unwrap_err();
+        let elapsed = start.elapsed();
+
+        assert!(matches!(&err, SamyamaError::HttpError(e) if e.is_timeout()), "{err}");
+        // One retry: two request timeouts plus the backoff, well short of a hang
+        assert!(elapsed >= Duration::from_millis(600), "{elapsed:?}");
+        assert!(elapsed < Duration::from_secs(5), "{elapsed:?}");
+    }
+
+    #[tokio::test]
+    async fn test_unreachable_port_fails_fast() {
+        let port = std::net::TcpListener::bind("127.0.0.1:0").unwrap().local_addr().unwrap().port();
+        let client = RemoteClient::with_config(&format!("http://127.0.0.1:{port}"), quick_config()).unwrap();
+
+        let start = std::time::Instant::now();
+        assert!(client.query_readonly("default", "MATCH (n) RETURN n").await.is_err());
+        assert!(client.query("default", "CREATE (n)").await.is_err());
+        assert!(start.elapsed() < Duration::from_secs(5));
+    }
+
+    #[tokio::test]
+    async fn test_snapshot_rejects_other_graphs() {
+        let client = RemoteClient::with_config("http://127.0.0.1:1", quick_config()).unwrap();
+        let path = std::env::temp_dir().join("samyama-sdk-other-graph.sgsnap");
+
+        let err = client.export_snapshot("other", &path, |_| {}).await.unwrap_err();
+        assert!(matches!(&err, SamyamaError::PersistenceError(m) if m.contains("'other'")), "{err}");
+        let err = client.import_snapshot("other", &path, |_| {}).await.unwrap_err();
+        assert!(matches!(&err, SamyamaError::PersistenceError(m) if m.contains("'other'")), "{err}");
+        assert!(!path.exists());
+    }
+
+    /// Serve `store` over HTTP on a free port and wait until it answers
+    async fn serve(store: std::sync::Arc<tokio::sync::RwLock<samyama::graph::GraphStore>>) -> RemoteClient {
+        let port = std::net::TcpListener::bind("127.0.0.1:0").unwrap().local_addr().unwrap().port();
+        tokio::spawn(async move {
+            let _ = samyama::http::HttpServer::new(store, port).start().await;
+        });
+        let client = RemoteClient::new(&format!("http://127.0.0.1:{port}"));
+        for _ in 0..100 {
+            if client.ping().await.is_ok() {
+                return client;
+            }
+            tokio::time::sleep(Duration::from_millis(50)).await;
+        }
+        panic!("server on port {port} did not start");
+    }
+
+    #[tokio::test]
+    async fn test_query_with_params() {
+        let embedded = crate::EmbeddedClient::new();
+        embedded.query("default", r#"CREATE (a:Person {name: "Alice", age: 30})"#).await.unwrap();
+        embedded.query("default", r#"CREATE (b:Person {name: "Bob", age: 20})"#).await.unwrap();
+        let client = serve(embedded.store().clone()).await;
+
+        let cypher = "MATCH (n:Person) WHERE n.age > $min RETURN n.name";
+        let mut params = HashMap::new();
+        params.insert("min".to_string(), PropertyValue::Integer(25));
+        let result = client.query_with_params("default", cypher, &params).await.unwrap();
+        assert_eq!(result.records, vec![vec![serde_json::json!("Alice")]]);
+
+        let err = client.query("default", cypher).await.unwrap_err();
+        assert!(err.to_string().contains("$min"));
+
+        let result = client.query_readonly_with_params("default", cypher, &params).await.unwrap();
+        assert_eq!(result.records, vec![vec![serde_json::json!("Alice")]]);
+        let mut name = HashMap::new();
+        name.insert("name".to_string(), PropertyValue::String("Carol".to_string()));
+        assert!(client.query_readonly_with_params("default", "CREATE (n:Person) RETURN $name AS name", &name).await.is_err());
+        assert_eq!(embedded.store().read().await.node_count(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_snapshot_round_trip() {
+        let source = crate::EmbeddedClient::new();
+        source.query("default", r#"CREATE (a:Person {name: "Alice"})"#).await.unwrap();
+        source.query("default", r#"CREATE (b:Person {name: "Bob"})"#).await.unwrap();
+        source.query("default", "MATCH (a:Person {name: \"Alice\"}), (b:Person {name: \"Bob\"}) CREATE (a)-[:KNOWS]->(b)").await.unwrap();
+
+        let path = std::env::temp_dir().join(format!("samyama_sdk_snapshot_{}.sgsnap", std::process::id()));
+        let mut progress = Vec::new();
+        let from = serve(source.store().clone()).await;
+        let bytes = from.export_snapshot("default", &path, |n| progress.push(n)).await.unwrap();
+        assert!(bytes > 0);
+        assert_eq!(std::fs::metadata(&path).unwrap().len(), bytes);
+        assert_eq!(progress.last(), Some(&bytes));
+
+        let target = crate::EmbeddedClient::new();
+        let to = serve(target.store().clone()).await;
+        let sent = std::sync::Arc::new(std::sync::atomic::AtomicU64::new(0));
+        let counter = sent.clone();
+        let summary = to
+            .import_snapshot("default", &path, move |n| counter.store(n, std::sync::atomic::Ordering::Relaxed))
+            .await
+            .unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!((summary.nodes_imported, summary.edges_imported), (2, 1));
+        assert_eq!(sent.load(std::sync::atomic::Ordering::Relaxed), bytes);
+        let status = target.status().await.unwrap();
+        assert_eq!((status.storage.nodes, status.storage.edges), (2, 1));
+    }
+
+    #[tokio::test]
+    async fn test_stats() {
+        let embedded = crate::EmbeddedClient::new();
+        embedded.query("default", r#"CREATE (a:Person {name: "Alice"})-[:WORKS_AT]->(c:Company {name: "Acme"})"#).await.unwrap();
+        embedded.query("default", r#"CREATE (b:Person {name: "Bob"})"#).await.unwrap();
+        let client = serve(embedded.store().clone()).await;
+
+        let stats = client.stats("default").await.unwrap();
+        assert_eq!(stats, embedded.stats("default").await.unwrap());
+        assert_eq!((stats.nodes, stats.edges), (3, 1));
+        assert_eq!(stats.labels[0], crate::NameCount { name: "Person".to_string(), count: 2 });
+        assert_eq!(stats.edge_types, vec![crate::NameCount { name: "WORKS_AT".to_string(), count: 1 }]);
+    }
+
+    #[tokio::test]
+    async fn test_schema() {
+        let embedded = crate::EmbeddedClient::new();
+        embedded.query("default", r#"CREATE (a:Person {name: "Alice", age: 30})-[:KNOWS]->(b:Person {name: "Bob", age: 25})"#).await.unwrap();
+        let client = serve(embedded.store().clone()).await;
+
+        let schema = client.schema("default").await.unwrap();
+        assert_eq!(schema, embedded.schema("default").await.unwrap());
+        assert_eq!(schema.labels.len(), 1);
+        assert_eq!(schema.labels[0].label, "Person");
+        assert_eq!(schema.labels[0].count, 2);
+        assert_eq!(schema.labels[0].properties["age"], "Integer");
+        assert_eq!(schema.edge_types[0].edge_type, "KNOWS");
+        assert_eq!(schema.edge_types[0].source_labels, vec!["Person".to_string()]);
+        assert_eq!(schema.edge_types[0].target_labels, vec!["Person".to_string()]);
+    }
+
+    #[test]
+    fn test_default_config() {
+        let client = RemoteClient::new("http://localhost:8080/");
+        assert_eq!(client.http_base_url, "http://localhost:8080");
+        assert_eq!(client.config().request_timeout, Duration::from_secs(30));
+        assert_eq!(client.config().connect_timeout, Duration::from_secs(5));
+    }
+
+    #[test]
+    fn test_paged_cypher() {
+        assert_eq!(
+            paged_cypher("MATCH (n) RETURN n ORDER BY id(n);  ", 200, 100),
+            "MATCH (n) RETURN n ORDER BY id(n) SKIP 200 LIMIT 100"
+        );
+    }
+}
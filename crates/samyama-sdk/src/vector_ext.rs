@@ -7,7 +7,7 @@
 use async_trait::async_trait;
 
 use samyama::graph::NodeId;
-use samyama::vector::DistanceMetric;
+use samyama::vector::{DistanceMetric, HnswConfig};
 
 use crate::embedded::EmbeddedClient;
 use crate::error::{SamyamaError, SamyamaResult};
@@ -26,6 +26,16 @@ pub trait VectorClient {
         metric: DistanceMetric,
     ) -> SamyamaResult<()>;
 
+    /// Create a vector index with explicit HNSW parameters.
+    async fn create_vector_index_with_config(
+        &self,
+        label: &str,
+        property: &str,
+        dimensions: usize,
+        metric: DistanceMetric,
+        config: HnswConfig,
+    ) -> SamyamaResult<()>;
+
     /// Add a vector to the index for a given node.
     async fn add_vector(
         &self,
@@ -43,6 +53,28 @@ pub trait VectorClient {
         query_vec: &[f32],
         k: usize,
     ) -> SamyamaResult<Vec<(NodeId, f32)>>;
+
+    /// k-NN search with a per-query `ef_search`; higher values raise recall at the cost of latency.
+    async fn vector_search_with_ef(
+        &self,
+        label: &str,
+        property: &str,
+        query_vec: &[f32],
+        k: usize,
+        ef_search: usize,
+    ) -> SamyamaResult<Vec<(NodeId, f32)>>;
+
+    /// Exact k nearest neighbors by brute-force scan.
+    ///
+    /// Ground truth for evaluating `vector_search`; compare the two with
+    /// [`recall_at_k`](crate::recall_at_k) when tuning the index.
+    async fn exact_vector_search(
+        &self,
+        label: &str,
+        property: &str,
+        query_vec: &[f32],
+        k: usize,
+    ) -> SamyamaResult<Vec<(NodeId, f32)>>;
 }
 
 #[async_trait]
@@ -59,6 +91,19 @@ impl VectorClient for EmbeddedClient {
             .map_err(|e| SamyamaError::VectorError(e.to_string()))
     }
 
+    async fn create_vector_index_with_config(
+        &self,
+        label: &str,
+        property: &str,
+        dimensions: usize,
+        metric: DistanceMetric,
+        config: HnswConfig,
+    ) -> SamyamaResult<()> {
+        let store = self.store.read().await;
+        store.create_vector_index_with_config(label, property, dimensions, metric, config)
+            .map_err(|e| SamyamaError::VectorError(e.to_string()))
+    }
+
     async fn add_vector(
         &self,
         label: &str,
@@ -83,6 +128,31 @@ impl VectorClient for EmbeddedClient {
         store.vector_search(label, property, query_vec, k)
             .map_err(|e| SamyamaError::VectorError(e.to_string()))
     }
+
+    async fn vector_search_with_ef(
+        &self,
+        label: &str,
+        property: &str,
+        query_vec: &[f32],
+        k: usize,
+        ef_search: usize,
+    ) -> SamyamaResult<Vec<(NodeId, f32)>> {
+        let store = self.store.read().await;
+        store.vector_search_with_ef(label, property, query_vec, k, Some(ef_search))
+            .map_err(|e| SamyamaError::VectorError(e.to_string()))
+    }
+
+    async fn exact_vector_search(
+        &self,
+        label: &str,
+        property: &str,
+        query_vec: &[f32],
+        k: usize,
+    ) -> SamyamaResult<Vec<(NodeId, f32)>> {
+        let store = self.store.read().await;
+        store.vector_search_exact(label, property, query_vec, k)
+            .map_err(|e| SamyamaError::VectorError(e.to_string()))
+    }
 }
 
 #[cfg(test)]
@@ -117,5 +187,13 @@ mod tests {
         assert!(!results.is_empty());
         // First result should be closest to query
         assert_eq!(results[0].0, nodes[0]);
+
+        let exact = client.exact_vector_search("Doc", "embedding", &[1.0, 0.1, 0.0, 0.0], 2).await.unwrap();
+        assert_eq!(exact.len(), 2);
+        assert_eq!(exact[0].0, nodes[0]);
+        assert_eq!(crate::recall_at_k(&exact[..1], &results), 1.0);
+
+        let wide = client.vector_search_with_ef("Doc", "embedding", &[1.0, 0.1, 0.0, 0.0], 2, 128).await.unwrap();
+        assert_eq!(wide[0].0, nodes[0]);
     }
 }
@@ -12,7 +12,7 @@ use std::io::{self, BufRead, BufReader, IsTerminal};
 use std::path::Path;
 use std::time::Instant;
 
-use samyama_sdk::{GraphStore, NodeId, PropertyValue};
+use samyama_sdk::{EdgeType, GraphStore, NodeId, PropertyValue};
 
 pub type Error = Box<dyn std::error::Error>;
 
@@ -1095,12 +1095,11 @@ fn load_browse_conditions(
     // Build reverse map: trial NodeId -> list of Condition NodeIds
     // by scanning existing STUDIES edges
     let mut trial_to_conditions: HashMap<NodeId, Vec<NodeId>> = HashMap::new();
+    let studies = EdgeType::new("STUDIES");
     for (_nct_str, &trial_node) in &ids.trial {
-        // get_outgoing_edges returns Vec<&Edge>
         let targets: Vec<NodeId> = graph
-            .get_outgoing_edges(trial_node)
+            .get_outgoing_edges_of_type(trial_node, &studies)
             .into_iter()
-            .filter(|edge| edge.edge_type.as_str() == "STUDIES")
             .map(|edge| edge.target)
             .collect();
         if !targets.is_empty() {
@@ -54,6 +54,8 @@ async fn main() {
         system_prompt: Some(
             "You are a Cypher query expert for a pharmaceutical knowledge graph.".to_string(),
         ),
+        retry: Default::default(),
+        fallback: None,
     };
 
     // Agent config — generates enrichment CREATE statements
@@ -612,6 +612,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         max_storage_bytes: Some(20 * 1024 * 1024 * 1024),
         max_connections: Some(500),
         max_query_time_ms: Some(60_000),
+        max_queries_per_sec: None,
     };
     persist_mgr.tenants().create_tenant(
         "retail_banking".to_string(),
@@ -628,6 +629,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         max_storage_bytes: Some(50 * 1024 * 1024 * 1024),
         max_connections: Some(100),
         max_query_time_ms: Some(120_000),
+        max_queries_per_sec: None,
     };
     persist_mgr.tenants().create_tenant(
         "corporate_banking".to_string(),
@@ -644,6 +646,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         max_storage_bytes: Some(10 * 1024 * 1024 * 1024),
         max_connections: Some(50),
         max_query_time_ms: Some(180_000),
+        max_queries_per_sec: None,
     };
     persist_mgr.tenants().create_tenant(
         "wealth_management".to_string(),
@@ -879,7 +882,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let all_transactions = graph.get_nodes_by_label(&Label::new("Transaction"));
     let mut structuring_suspects: Vec<(String, f64)> = Vec::new();
     for tx_node in &all_transactions {
-        if let Some(amount) = tx_node.get_property("amount").and_then(|v| v.as_float()) {
+        if let Some(amount) = tx_node.get_property_as::<f64>("amount") {
             if amount >= 9000.0 && amount < 10000.0 {
                 let tx_id = tx_node.get_property("transaction_id")
                     .and_then(|v| v.as_string())
@@ -911,7 +914,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         for edge in &acc_edges {
             if edge.edge_type.as_str() == "HAS_TRANSACTION" {
                 if let Some(tx_node) = graph.get_node(edge.target) {
-                    if let Some(amount) = tx_node.get_property("amount").and_then(|v| v.as_float()) {
+                    if let Some(amount) = tx_node.get_property_as::<f64>("amount") {
                         if amount > 5000.0 {
                             large_tx_count += 1;
                             large_tx_total += amount;
@@ -1175,6 +1178,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             api_key: None,
             api_base_url: None,
             system_prompt: Some("You are a Cypher query expert for a banking fraud detection knowledge graph.".to_string()),
+            retry: Default::default(),
+            fallback: None,
         };
 
         let schema_summary = "Node labels: Branch, Customer, Account, Transaction\n\
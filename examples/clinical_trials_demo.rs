@@ -16,7 +16,7 @@ use samyama_sdk::{
     EmbeddedClient, SamyamaClient, AlgorithmClient,
     EdgeType, NodeId,
     NLQConfig, LLMProvider, AgentConfig,
-    PageRankConfig,
+    PageRankConfig, Directedness,
     NSGA2Solver, SolverConfig, MultiObjectiveProblem, Array1,
 };
 use std::collections::HashMap;
@@ -625,8 +625,8 @@ async fn main() {
         println!("    ├────┼────────────────────────────────────────────────────────┼────────┤");
         for (rank, (nid, score)) in scored.iter().enumerate() {
             if let Some(node) = store.get_node(*nid) {
-                let nct = node.get_property("nct_id").map(|v| v.as_string().unwrap_or_default()).unwrap_or_default();
-                let title = node.get_property("title").map(|v| v.as_string().unwrap_or_default()).unwrap_or_default();
+                let nct = node.get_property_as::<String>("nct_id").unwrap_or_default();
+                let title = node.get_property_as::<String>("title").unwrap_or_default();
                 let display = format!("{} {}", nct, title);
                 let truncated = if display.len() > 54 { format!("{}...", &display[..51]) } else { display.clone() };
                 println!("    │ {:>2} │ {:<54} │ {:.4} │", rank + 1, truncated, score);
@@ -720,6 +720,7 @@ async fn main() {
     let solver = NSGA2Solver::new(SolverConfig {
         population_size: 50,
         max_iterations: 100,
+        ..Default::default()
     });
 
     let result = solver.solve(&problem);
@@ -793,6 +794,7 @@ async fn main() {
         },
         Some("Drug"),
         Some("INTERACTS_WITH"),
+        Directedness::Directed,
     ).await;
 
     // Map algo NodeId (u64) back to graph NodeId and sort
@@ -1057,6 +1059,8 @@ async fn main() {
             api_key: None,
             api_base_url: None,
             system_prompt: Some("You are a Cypher query expert for a clinical trials knowledge graph.".to_string()),
+            retry: Default::default(),
+            fallback: None,
         };
 
         let schema_summary = "Node labels: Trial, Drug, Condition, Site, Patient\n\
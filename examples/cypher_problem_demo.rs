@@ -52,7 +52,7 @@ fn main() {
     let v = problem.objective(&Array1::from(vec![2.0, 1.0]));
     println!("sanity SSE at (a=2, b=1): {:.6}", v);
 
-    let cfg = SolverConfig { population_size: 30, max_iterations: 200 };
+    let cfg = SolverConfig { population_size: 30, max_iterations: 200, ..Default::default() };
     let t0 = Instant::now();
     let r = BMWRSolver::new(cfg).solve(&problem);
     let wall = t0.elapsed();
@@ -250,7 +250,7 @@ fn main() {
         ("Rao-1",     |c, p| RaoSolver::new(c, RaoVariant::Rao1).solve(p)),
     ];
 
-    let cfg = SolverConfig { population_size: 30, max_iterations: 100 };
+    let cfg = SolverConfig { population_size: 30, max_iterations: 100, ..Default::default() };
     let csv_path = a.out.join("results.csv");
     use std::io::Write;
     let mut csv = std::fs::File::create(&csv_path).unwrap();
@@ -16,7 +16,7 @@
 
 use samyama_sdk::{
     EmbeddedClient, SamyamaClient, AlgorithmClient, VectorClient,
-    EdgeType, Label, NodeId, PageRankConfig,
+    EdgeType, Label, NodeId, PageRankConfig, Directedness,
     NLQConfig, LLMProvider, AgentConfig,
     DistanceMetric,
 };
@@ -84,6 +84,8 @@ async fn main() {
         api_key: None,
         api_base_url: None,
         system_prompt: Some("You are a Cypher query expert for a cybersecurity knowledge graph.".to_string()),
+        retry: Default::default(),
+        fallback: None,
     };
 
     // Create vector index for threat signature matching (128-dim)
@@ -765,7 +767,7 @@ async fn main() {
     subsection("5a. Critical Asset Identification (PageRank)");
     println!("    Running PageRank across all servers to identify most-connected assets...");
 
-    let pr_scores = client.page_rank(PageRankConfig::default(), Some("Server"), None).await;
+    let pr_scores = client.page_rank(PageRankConfig::default(), Some("Server"), None, Directedness::Directed).await;
 
     // Sort by score descending, map back to server names
     let mut scored_servers: Vec<(NodeId, f64)> = pr_scores.iter()
@@ -303,7 +303,7 @@ fn main() {
         ("EHR-Jaya",  |c, p| EHRJayaSolver::new(c).solve(p)),
         ("Rao-1",     |c, p| RaoSolver::new(c, RaoVariant::Rao1).solve(p)),
     ];
-    let cfg = SolverConfig { population_size: 60, max_iterations: 500 };
+    let cfg = SolverConfig { population_size: 60, max_iterations: 500, ..Default::default() };
 
     let csv_path = a.out.join("results.csv");
     let mut csv = File::create(&csv_path).unwrap();
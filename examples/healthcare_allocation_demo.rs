@@ -195,7 +195,7 @@ fn main() {
         ("EHR-Jaya",  |c, p| EHRJayaSolver::new(c).solve(p)),
         ("Rao-1",     |c, p| RaoSolver::new(c, RaoVariant::Rao1).solve(p)),
     ];
-    let cfg = SolverConfig { population_size: 30, max_iterations: 200 };
+    let cfg = SolverConfig { population_size: 30, max_iterations: 200, ..Default::default() };
 
     let csv_path = a.out.join("results.csv");
     use std::io::Write;
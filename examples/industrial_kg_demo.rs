@@ -13,7 +13,7 @@
 
 use samyama_sdk::{
     EmbeddedClient, SamyamaClient, AlgorithmClient, VectorClient,
-    PropertyValue, PageRankConfig, DistanceMetric,
+    PropertyValue, PageRankConfig, Directedness, DistanceMetric,
     NLQConfig, LLMProvider,
     NSGA2Solver, SolverConfig, MultiObjectiveProblem,
     Array1,
@@ -594,13 +594,13 @@ async fn main() {
     println!("  Running PageRank on full plant graph to identify critical equipment...");
 
     let start = Instant::now();
-    let _view = client.build_view(None, None, None).await;
+    let _view = client.build_view(None, None, None, Directedness::Directed).await;
     let scores = client.page_rank(PageRankConfig {
         damping_factor: 0.85,
         iterations: 30,
         tolerance: 0.0001,
         ..Default::default()
-    }, None, None).await;
+    }, None, None, Directedness::Directed).await;
     let pr_time = start.elapsed();
 
     // Collect equipment scores
@@ -723,6 +723,7 @@ async fn main() {
     let solver = NSGA2Solver::new(SolverConfig {
         population_size: 80,
         max_iterations: 150,
+        ..Default::default()
     });
     let maint_result = solver.solve(&maint_problem);
     let maint_time = start.elapsed();
@@ -783,6 +784,8 @@ async fn main() {
             system_prompt: Some(
                 "You are a Cypher query expert for an industrial asset knowledge graph.".to_string()
             ),
+            retry: Default::default(),
+            fallback: None,
         };
 
         let schema_summary = "Node labels: Site, Location, Equipment, Sensor, FailureMode\n\
@@ -10,8 +10,8 @@
 
 use samyama_sdk::{
     EmbeddedClient, SamyamaClient, AlgorithmClient, VectorClient,
-    Label, PropertyValue, NodeId, EdgeType,
-    DistanceMetric, PageRankConfig,
+    Label, PropertyValue, NodeId, EdgeType, EdgeDirection,
+    DistanceMetric, PageRankConfig, Directedness,
     LLMProvider, NLQConfig, AgentConfig,
 };
 use std::collections::HashMap;
@@ -984,23 +984,21 @@ async fn main() {
             let doc_title = doc_node.get_property("title").unwrap().as_string().unwrap();
             let relevance = (1.0 - *score) as f64;
 
-            // Find who authored this doc: traverse incoming AUTHORED edges
-            let incoming = store.get_incoming_edges(*nid);
-            for edge in &incoming {
-                if edge.edge_type == EdgeType::new("AUTHORED") {
-                    if let Some(author_node) = store.get_node(edge.source) {
-                        let author_name = author_node
-                            .get_property("name")
-                            .unwrap()
-                            .as_string()
-                            .unwrap()
-                            .to_string();
-                        let entry = expert_scores
-                            .entry(author_name.clone())
-                            .or_insert((0.0, Vec::new()));
-                        entry.0 += relevance;
-                        entry.1.push(doc_title.to_string());
-                    }
+            // Find who authored this doc: one hop back along AUTHORED edges
+            let authors = store.neighbors(*nid, 1, Some(&[EdgeType::new("AUTHORED")][..]), EdgeDirection::Incoming);
+            for author in authors {
+                if let Some(author_node) = store.get_node(author) {
+                    let author_name = author_node
+                        .get_property("name")
+                        .unwrap()
+                        .as_string()
+                        .unwrap()
+                        .to_string();
+                    let entry = expert_scores
+                        .entry(author_name.clone())
+                        .or_insert((0.0, Vec::new()));
+                    entry.0 += relevance;
+                    entry.1.push(doc_title.to_string());
                 }
             }
         }
@@ -1040,10 +1038,9 @@ async fn main() {
         if let Some((top_expert, _, _)) = experts.first() {
             if let Some(&emp_raw) = name_to_emp_id.get(top_expert) {
                 let emp_nid = NodeId::new(emp_raw);
-                let outgoing = store.get_outgoing_edges(emp_nid);
+                let outgoing = store.get_outgoing_edges_of_type(emp_nid, &EdgeType::new("EXPERT_IN"));
                 let tech_skills: Vec<String> = outgoing
                     .iter()
-                    .filter(|e| e.edge_type == EdgeType::new("EXPERT_IN"))
                     .filter_map(|e| {
                         store
                             .get_node(e.target)
@@ -1116,10 +1113,9 @@ async fn main() {
         println!("  └────┴──────────────────────────────────────────────────────────┴──────────────┘");
 
         // Find documents this document REFERENCES (outgoing REFERENCES edges)
-        let outgoing_refs = store.get_outgoing_edges(root_doc_nid);
+        let outgoing_refs = store.get_outgoing_edges_of_type(root_doc_nid, &EdgeType::new("REFERENCES"));
         let referenced_docs: Vec<(&str, &str)> = outgoing_refs
             .iter()
-            .filter(|e| e.edge_type == EdgeType::new("REFERENCES"))
             .filter_map(|e| {
                 store.get_node(e.target).map(|n| {
                     let t = n.get_property("title").unwrap().as_string().unwrap();
@@ -1164,28 +1160,26 @@ async fn main() {
 
         let mut cross_domain_links: Vec<(String, String, String)> = Vec::new();
         for legal_node in &legal_docs {
-            let outgoing = store.get_outgoing_edges(legal_node.id);
+            let outgoing = store.get_outgoing_edges_of_type(legal_node.id, &EdgeType::new("REFERENCES"));
             for edge in &outgoing {
-                if edge.edge_type == EdgeType::new("REFERENCES") {
-                    if let Some(target) = store.get_node(edge.target) {
-                        let target_dept = target
-                            .get_property("department")
-                            .and_then(|p| p.as_string())
-                            .unwrap_or("Unknown");
-                        if target_dept != "Legal" {
-                            let legal_title = legal_node
-                                .get_property("title")
-                                .unwrap()
-                                .as_string()
-                                .unwrap();
-                            let target_title =
-                                target.get_property("title").unwrap().as_string().unwrap();
-                            cross_domain_links.push((
-                                legal_title.to_string(),
-                                target_title.to_string(),
-                                target_dept.to_string(),
-                            ));
-                        }
+                if let Some(target) = store.get_node(edge.target) {
+                    let target_dept = target
+                        .get_property("department")
+                        .and_then(|p| p.as_string())
+                        .unwrap_or("Unknown");
+                    if target_dept != "Legal" {
+                        let legal_title = legal_node
+                            .get_property("title")
+                            .unwrap()
+                            .as_string()
+                            .unwrap();
+                        let target_title =
+                            target.get_property("title").unwrap().as_string().unwrap();
+                        cross_domain_links.push((
+                            legal_title.to_string(),
+                            target_title.to_string(),
+                            target_dept.to_string(),
+                        ));
                     }
                 }
             }
@@ -1307,17 +1301,15 @@ async fn main() {
     println!("  Finding the most referenced and influential documents...");
     println!();
 
-    let pr_scores = client.page_rank(PageRankConfig::default(), Some("Document"), Some("REFERENCES")).await;
+    let pr_ranked = client.page_rank_labeled("Document", Some("REFERENCES"), PageRankConfig::default(), Directedness::Directed).await;
 
-    // Map PageRank scores back to document info
-    let mut doc_pr: Vec<(String, String, f64)>;
+    // Map PageRank scores back to document info (already sorted by score)
+    let doc_pr: Vec<(String, String, f64)>;
     {
         let store = client.store_read().await;
-        doc_pr = pr_scores
+        doc_pr = pr_ranked
             .iter()
-            .map(|(&node_id, &score)| {
-                // node_id is already the original NodeId (u64)
-                let nid = NodeId::new(node_id);
+            .map(|&(nid, score)| {
                 let node = store.get_node(nid).unwrap();
                 let title = node
                     .get_property("title")
@@ -1334,8 +1326,6 @@ async fn main() {
             .collect();
     }
 
-    doc_pr.sort_by(|a, b| b.2.partial_cmp(&a.2).unwrap());
-
     println!("  Top 15 Knowledge Hubs (by PageRank score):");
     println!("  ┌────┬──────────────────────────────────────────────────────────┬──────────────┬───────────┐");
     println!("  │ #  │ Document Title                                           │ Department   │ PageRank  │");
@@ -1364,19 +1354,8 @@ async fn main() {
             println!("  Top Knowledge Hub: \"{}\"", hub_title);
             println!("    Department: {}, PageRank: {:.6}", hub_dept, hub_score);
 
-            // Find the corresponding NodeId
-            let hub_nid_opt = store
-                .get_nodes_by_label(&Label::new("Document"))
-                .into_iter()
-                .find(|n| {
-                    n.get_property("title")
-                        .and_then(|p| p.as_string())
-                        .map(|s| s == hub_title.as_str())
-                        .unwrap_or(false)
-                });
-
-            if let Some(hub_node) = hub_nid_opt {
-                let incoming = store.get_incoming_edges(hub_node.id);
+            if let Some(&(hub_nid, _)) = pr_ranked.first() {
+                let incoming = store.get_incoming_edges(hub_nid);
                 let ref_count = incoming
                     .iter()
                     .filter(|e| e.edge_type == EdgeType::new("REFERENCES"))
@@ -1389,16 +1368,14 @@ async fn main() {
 
     // Also run PageRank on projects to find critical infrastructure
     println!("  Project Dependency Analysis (PageRank on DEPENDS_ON):");
-    let proj_pr = client.page_rank(PageRankConfig::default(), Some("Project"), Some("DEPENDS_ON")).await;
+    let proj_pr = client.page_rank_labeled("Project", Some("DEPENDS_ON"), PageRankConfig::default(), Directedness::Directed).await;
 
-    let mut proj_pr_sorted: Vec<(String, f64)>;
+    let proj_pr_sorted: Vec<(String, f64)>;
     {
         let store = client.store_read().await;
         proj_pr_sorted = proj_pr
             .iter()
-            .map(|(&node_id, &score)| {
-                // node_id is already the original NodeId (u64)
-                let nid = NodeId::new(node_id);
+            .map(|&(nid, score)| {
                 let node = store.get_node(nid).unwrap();
                 let name = node
                     .get_property("name")
@@ -1409,7 +1386,6 @@ async fn main() {
             })
             .collect();
     }
-    proj_pr_sorted.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap());
 
     println!("  ┌────┬──────────────────────────────────┬───────────┐");
     println!("  │ #  │ Project                          │ PageRank  │");
@@ -1444,6 +1420,8 @@ async fn main() {
             api_key: None,
             api_base_url: None,
             system_prompt: Some("You are a Cypher query expert for an enterprise knowledge graph.".to_string()),
+            retry: Default::default(),
+            fallback: None,
         };
 
         let schema_summary = "Node labels: Document, Employee, Project, Technology\n\
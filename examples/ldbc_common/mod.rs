@@ -106,8 +106,54 @@ where
     Ok(count)
 }
 
+/// Outcome of loading one relationship file.
+#[derive(Debug, Clone)]
+pub struct EdgeFileReport {
+    pub file: String,
+    pub edge_type: String,
+    pub loaded: usize,
+    pub skipped: usize,
+}
+
+impl EdgeFileReport {
+    /// Append this report to `reports` and return the loaded count.
+    pub fn record(self, reports: &mut Vec<EdgeFileReport>) -> usize {
+        let loaded = self.loaded;
+        reports.push(self);
+        loaded
+    }
+
+    /// Describe why this file looks like a silent data loss, if it does: every
+    /// row skipped, or (for files over 100 rows) more than `max_skip_pct`
+    /// percent skipped.
+    pub fn integrity_issue(&self, max_skip_pct: usize) -> Option<String> {
+        let total = self.loaded + self.skipped;
+        if self.exceeds_skip_pct(max_skip_pct) {
+            return Some(format!(
+                "{} ({}): skipped {}/{} rows ({}%), over the {}% threshold",
+                self.file, self.edge_type, format_num(self.skipped), format_num(total),
+                self.skipped * 100 / total, max_skip_pct
+            ));
+        }
+        if self.loaded == 0 && self.skipped > 0 {
+            return Some(format!(
+                "{} ({}): loaded 0 rows, skipped {}",
+                self.file, self.edge_type, format_num(self.skipped)
+            ));
+        }
+        None
+    }
+
+    /// Whether a file over 100 rows skipped more than `max_skip_pct` percent.
+    pub fn exceeds_skip_pct(&self, max_skip_pct: usize) -> bool {
+        let total = self.loaded + self.skipped;
+        total > 100 && self.skipped * 100 / total > max_skip_pct
+    }
+}
+
 /// Load edges from a pipe-delimited CSV file.
 /// `parse_props` receives (headers, fields) and returns edge properties (may be empty).
+/// Unparseable or dangling rows are skipped and counted in the report.
 pub fn load_edges<F>(
     path: &Path,
     edge_type: &str,
@@ -115,13 +161,19 @@ pub fn load_edges<F>(
     src_map: &HashMap<i64, NodeId>,
     tgt_map: &HashMap<i64, NodeId>,
     parse_props: F,
-) -> Result<usize, Error>
+) -> Result<EdgeFileReport, Error>
 where
     F: Fn(&[&str], &[&str]) -> Vec<(&'static str, PropertyValue)>,
 {
+    let mut report = EdgeFileReport {
+        file: path.file_name().unwrap_or_default().to_string_lossy().into_owned(),
+        edge_type: edge_type.to_string(),
+        loaded: 0,
+        skipped: 0,
+    };
     if !path.exists() {
         eprintln!("  WARNING: {} not found, skipping", path.display());
-        return Ok(0);
+        return Ok(report);
     }
 
     let file = File::open(path)?;
@@ -133,6 +185,7 @@ where
 
     let mut count = 0usize;
     let mut skipped = 0usize;
+    let mut float_ids_reported = false;
     for line_result in lines {
         let line = line_result?;
         if line.is_empty() { continue; }
@@ -140,6 +193,17 @@ where
         let fields: Vec<&str> = line.split('|').collect();
         if fields.len() < 2 { continue; }
 
+        if !float_ids_reported {
+            if let Some(raw) = fields[..2].iter().find(|f| f.contains('.')) {
+                eprintln!(
+                    "  NOTE: {} has float-formatted ids (e.g. {:?}); parsing as integers",
+                    report.file,
+                    raw.trim()
+                );
+                float_ids_reported = true;
+            }
+        }
+
         let src_id = match parse_ldbc_id(fields[0]) {
             Some(v) => v,
             None => { skipped += 1; continue; }
@@ -182,16 +246,9 @@ where
         eprintln!("  (skipped {} rows for {})", format_num(skipped), edge_type);
     }
 
-    let total = count + skipped;
-    if total > 100 && skipped * 100 / total > 5 {
-        return Err(format!(
-            "loader integrity: skipped {}/{} rows for {} ({}%) — exceeds 5% threshold; \
-             likely a parser/format mismatch (see tolerant `parse_ldbc_id`)",
-            skipped, total, edge_type, skipped * 100 / total
-        ).into());
-    }
-
-    Ok(count)
+    report.loaded = count;
+    report.skipped = skipped;
+    Ok(report)
 }
 
 /// Parse an LDBC id field. Some LDBC CSV exports (CsvBasic-LongDateFormatter)
@@ -217,47 +274,6 @@ pub fn parse_ldbc_id(s: &str) -> Option<i64> {
     int_part.parse::<i64>().ok()
 }
 
-#[cfg(test)]
-mod tests {
-    use super::parse_ldbc_id;
-
-    #[test]
-    fn plain_int() {
-        assert_eq!(parse_ldbc_id("123"), Some(123));
-        assert_eq!(parse_ldbc_id("0"), Some(0));
-        assert_eq!(parse_ldbc_id("-7"), Some(-7));
-        assert_eq!(parse_ldbc_id("2336462209434"), Some(2336462209434));
-    }
-
-    #[test]
-    fn float_with_zero_fraction() {
-        // Real CSV data: comment_replyOf_*.csv writes target ids as floats.
-        assert_eq!(parse_ldbc_id("2336462209434.0"), Some(2336462209434));
-        assert_eq!(parse_ldbc_id("123.000"), Some(123));
-        assert_eq!(parse_ldbc_id("0.0"), Some(0));
-    }
-
-    #[test]
-    fn float_with_nonzero_fraction_rejected() {
-        assert!(parse_ldbc_id("123.5").is_none());
-        assert!(parse_ldbc_id("1.001").is_none());
-    }
-
-    #[test]
-    fn whitespace_tolerated() {
-        assert_eq!(parse_ldbc_id("  123  "), Some(123));
-        assert_eq!(parse_ldbc_id(" 123.0 "), Some(123));
-    }
-
-    #[test]
-    fn garbage_rejected() {
-        assert!(parse_ldbc_id("").is_none());
-        assert!(parse_ldbc_id("abc").is_none());
-        assert!(parse_ldbc_id(".5").is_none());
-        assert!(parse_ldbc_id("1.2.3").is_none());
-    }
-}
-
 // ============================================================================
 // PROPERTY PARSERS
 // ============================================================================
@@ -413,7 +429,7 @@ pub fn load_multi_value_attrs(
             if line.is_empty() { continue; }
             let parts: Vec<&str> = line.split('|').collect();
             if parts.len() < 2 { continue; }
-            if let Ok(pid) = parts[0].parse::<i64>() {
+            if let Some(pid) = parse_ldbc_id(parts[0]) {
                 email_map.entry(pid).or_default().push(parts[1].to_string());
             }
         }
@@ -447,7 +463,7 @@ pub fn load_multi_value_attrs(
             if line.is_empty() { continue; }
             let parts: Vec<&str> = line.split('|').collect();
             if parts.len() < 2 { continue; }
-            if let Ok(pid) = parts[0].parse::<i64>() {
+            if let Some(pid) = parse_ldbc_id(parts[0]) {
                 lang_map.entry(pid).or_default().push(parts[1].to_string());
             }
         }
@@ -503,18 +519,37 @@ pub fn print_done(msg: &str) {
 // FULL DATASET LOADER
 // ============================================================================
 
+/// Skip percentage above which a relationship file fails the load.
+pub const DEFAULT_MAX_SKIP_PCT: usize = 5;
+
 /// Load result with node/edge counts.
 pub struct LoadResult {
     pub total_nodes: usize,
     pub total_edges: usize,
+    pub edge_files: Vec<EdgeFileReport>,
+}
+
+impl LoadResult {
+    /// Relationship files that loaded nothing or skipped more than
+    /// `max_skip_pct` percent of their rows.
+    pub fn integrity_issues(&self, max_skip_pct: usize) -> Vec<String> {
+        self.edge_files
+            .iter()
+            .filter_map(|r| r.integrity_issue(max_skip_pct))
+            .collect()
+    }
 }
 
 /// Load the entire LDBC SF1 dataset into the given GraphStore.
-/// Returns total node and edge counts.
-pub fn load_dataset(graph: &mut GraphStore, data_dir: &Path) -> Result<LoadResult, Error> {
+/// Returns total node and edge counts plus a report per relationship file.
+/// Fails if a relationship file skips more than `max_skip_pct` percent of its
+/// rows (`DEFAULT_MAX_SKIP_PCT` unless the caller overrides it); other suspect
+/// files, such as a small file whose rows were all skipped, only warn.
+pub fn load_dataset(graph: &mut GraphStore, data_dir: &Path, max_skip_pct: usize) -> Result<LoadResult, Error> {
     let mut ids = IdMaps::new();
     let mut total_nodes = 0usize;
     let mut total_edges = 0usize;
+    let mut edge_files = Vec::new();
 
     let static_dir = data_dir.join("static");
     let dynamic_dir = data_dir.join("dynamic");
@@ -581,121 +616,223 @@ pub fn load_dataset(graph: &mut GraphStore, data_dir: &Path) -> Result<LoadResul
     // --- Static edges ---
 
     let t = std::time::Instant::now();
-    let n = load_edges(&static_dir.join("place_isPartOf_place_0_0.csv"), "IS_PART_OF", graph, &ids.place, &ids.place, no_props)?;
+    let n = load_edges(&static_dir.join("place_isPartOf_place_0_0.csv"), "IS_PART_OF", graph, &ids.place, &ids.place, no_props)?.record(&mut edge_files);
     print_done(&format!("  IS_PART_OF (Place->Place):             {:>12} edges ({})", format_num(n), format_duration(t.elapsed())));
     total_edges += n;
 
     let t = std::time::Instant::now();
-    let n = load_edges(&static_dir.join("organisation_isLocatedIn_place_0_0.csv"), "IS_LOCATED_IN", graph, &ids.organisation, &ids.place, no_props)?;
+    let n = load_edges(&static_dir.join("organisation_isLocatedIn_place_0_0.csv"), "IS_LOCATED_IN", graph, &ids.organisation, &ids.place, no_props)?.record(&mut edge_files);
     print_done(&format!("  IS_LOCATED_IN (Org->Place):            {:>12} edges ({})", format_num(n), format_duration(t.elapsed())));
     total_edges += n;
 
     let t = std::time::Instant::now();
-    let n = load_edges(&static_dir.join("tag_hasType_tagclass_0_0.csv"), "HAS_TYPE", graph, &ids.tag, &ids.tagclass, no_props)?;
+    let n = load_edges(&static_dir.join("tag_hasType_tagclass_0_0.csv"), "HAS_TYPE", graph, &ids.tag, &ids.tagclass, no_props)?.record(&mut edge_files);
     print_done(&format!("  HAS_TYPE (Tag->TagClass):              {:>12} edges ({})", format_num(n), format_duration(t.elapsed())));
     total_edges += n;
 
     let t = std::time::Instant::now();
-    let n = load_edges(&static_dir.join("tagclass_isSubclassOf_tagclass_0_0.csv"), "IS_SUBCLASS_OF", graph, &ids.tagclass, &ids.tagclass, no_props)?;
+    let n = load_edges(&static_dir.join("tagclass_isSubclassOf_tagclass_0_0.csv"), "IS_SUBCLASS_OF", graph, &ids.tagclass, &ids.tagclass, no_props)?.record(&mut edge_files);
     print_done(&format!("  IS_SUBCLASS_OF (TagClass->TagClass):   {:>12} edges ({})", format_num(n), format_duration(t.elapsed())));
     total_edges += n;
 
     // --- Dynamic edges ---
 
     let t = std::time::Instant::now();
-    let n = load_edges(&dynamic_dir.join("person_isLocatedIn_place_0_0.csv"), "IS_LOCATED_IN", graph, &ids.person, &ids.place, no_props)?;
+    let n = load_edges(&dynamic_dir.join("person_isLocatedIn_place_0_0.csv"), "IS_LOCATED_IN", graph, &ids.person, &ids.place, no_props)?.record(&mut edge_files);
     print_done(&format!("  IS_LOCATED_IN (Person->Place):         {:>12} edges ({})", format_num(n), format_duration(t.elapsed())));
     total_edges += n;
 
     let t = std::time::Instant::now();
-    let n = load_edges(&dynamic_dir.join("person_knows_person_0_0.csv"), "KNOWS", graph, &ids.person, &ids.person, props_creation_date)?;
+    let n = load_edges(&dynamic_dir.join("person_knows_person_0_0.csv"), "KNOWS", graph, &ids.person, &ids.person, props_creation_date)?.record(&mut edge_files);
     print_done(&format!("  KNOWS (Person->Person):                {:>12} edges ({})", format_num(n), format_duration(t.elapsed())));
     total_edges += n;
 
     let t = std::time::Instant::now();
-    let n = load_edges(&dynamic_dir.join("person_hasInterest_tag_0_0.csv"), "HAS_INTEREST", graph, &ids.person, &ids.tag, no_props)?;
+    let n = load_edges(&dynamic_dir.join("person_hasInterest_tag_0_0.csv"), "HAS_INTEREST", graph, &ids.person, &ids.tag, no_props)?.record(&mut edge_files);
     print_done(&format!("  HAS_INTEREST (Person->Tag):            {:>12} edges ({})", format_num(n), format_duration(t.elapsed())));
     total_edges += n;
 
     let t = std::time::Instant::now();
-    let n = load_edges(&dynamic_dir.join("person_studyAt_organisation_0_0.csv"), "STUDY_AT", graph, &ids.person, &ids.organisation, props_class_year)?;
+    let n = load_edges(&dynamic_dir.join("person_studyAt_organisation_0_0.csv"), "STUDY_AT", graph, &ids.person, &ids.organisation, props_class_year)?.record(&mut edge_files);
     print_done(&format!("  STUDY_AT (Person->Org):                {:>12} edges ({})", format_num(n), format_duration(t.elapsed())));
     total_edges += n;
 
     let t = std::time::Instant::now();
-    let n = load_edges(&dynamic_dir.join("person_workAt_organisation_0_0.csv"), "WORK_AT", graph, &ids.person, &ids.organisation, props_work_from)?;
+    let n = load_edges(&dynamic_dir.join("person_workAt_organisation_0_0.csv"), "WORK_AT", graph, &ids.person, &ids.organisation, props_work_from)?.record(&mut edge_files);
     print_done(&format!("  WORK_AT (Person->Org):                 {:>12} edges ({})", format_num(n), format_duration(t.elapsed())));
     total_edges += n;
 
     let t = std::time::Instant::now();
-    let n = load_edges(&dynamic_dir.join("person_likes_post_0_0.csv"), "LIKES", graph, &ids.person, &ids.post, props_creation_date)?;
+    let n = load_edges(&dynamic_dir.join("person_likes_post_0_0.csv"), "LIKES", graph, &ids.person, &ids.post, props_creation_date)?.record(&mut edge_files);
     print_done(&format!("  LIKES (Person->Post):                  {:>12} edges ({})", format_num(n), format_duration(t.elapsed())));
     total_edges += n;
 
     let t = std::time::Instant::now();
-    let n = load_edges(&dynamic_dir.join("person_likes_comment_0_0.csv"), "LIKES", graph, &ids.person, &ids.comment, props_creation_date)?;
+    let n = load_edges(&dynamic_dir.join("person_likes_comment_0_0.csv"), "LIKES", graph, &ids.person, &ids.comment, props_creation_date)?.record(&mut edge_files);
     print_done(&format!("  LIKES (Person->Comment):               {:>12} edges ({})", format_num(n), format_duration(t.elapsed())));
     total_edges += n;
 
     let t = std::time::Instant::now();
-    let n = load_edges(&dynamic_dir.join("forum_hasModerator_person_0_0.csv"), "HAS_MODERATOR", graph, &ids.forum, &ids.person, no_props)?;
+    let n = load_edges(&dynamic_dir.join("forum_hasModerator_person_0_0.csv"), "HAS_MODERATOR", graph, &ids.forum, &ids.person, no_props)?.record(&mut edge_files);
     print_done(&format!("  HAS_MODERATOR (Forum->Person):         {:>12} edges ({})", format_num(n), format_duration(t.elapsed())));
     total_edges += n;
 
     let t = std::time::Instant::now();
-    let n = load_edges(&dynamic_dir.join("forum_hasMember_person_0_0.csv"), "HAS_MEMBER", graph, &ids.forum, &ids.person, props_join_date)?;
+    let n = load_edges(&dynamic_dir.join("forum_hasMember_person_0_0.csv"), "HAS_MEMBER", graph, &ids.forum, &ids.person, props_join_date)?.record(&mut edge_files);
     print_done(&format!("  HAS_MEMBER (Forum->Person):            {:>12} edges ({})", format_num(n), format_duration(t.elapsed())));
     total_edges += n;
 
     let t = std::time::Instant::now();
-    let n = load_edges(&dynamic_dir.join("forum_hasTag_tag_0_0.csv"), "HAS_TAG", graph, &ids.forum, &ids.tag, no_props)?;
+    let n = load_edges(&dynamic_dir.join("forum_hasTag_tag_0_0.csv"), "HAS_TAG", graph, &ids.forum, &ids.tag, no_props)?.record(&mut edge_files);
     print_done(&format!("  HAS_TAG (Forum->Tag):                  {:>12} edges ({})", format_num(n), format_duration(t.elapsed())));
     total_edges += n;
 
     let t = std::time::Instant::now();
-    let n = load_edges(&dynamic_dir.join("forum_containerOf_post_0_0.csv"), "CONTAINER_OF", graph, &ids.forum, &ids.post, no_props)?;
+    let n = load_edges(&dynamic_dir.join("forum_containerOf_post_0_0.csv"), "CONTAINER_OF", graph, &ids.forum, &ids.post, no_props)?.record(&mut edge_files);
     print_done(&format!("  CONTAINER_OF (Forum->Post):            {:>12} edges ({})", format_num(n), format_duration(t.elapsed())));
     total_edges += n;
 
     let t = std::time::Instant::now();
-    let n = load_edges(&dynamic_dir.join("post_hasCreator_person_0_0.csv"), "HAS_CREATOR", graph, &ids.post, &ids.person, no_props)?;
+    let n = load_edges(&dynamic_dir.join("post_hasCreator_person_0_0.csv"), "HAS_CREATOR", graph, &ids.post, &ids.person, no_props)?.record(&mut edge_files);
     print_done(&format!("  HAS_CREATOR (Post->Person):            {:>12} edges ({})", format_num(n), format_duration(t.elapsed())));
     total_edges += n;
 
     let t = std::time::Instant::now();
-    let n = load_edges(&dynamic_dir.join("post_hasTag_tag_0_0.csv"), "HAS_TAG", graph, &ids.post, &ids.tag, no_props)?;
+    let n = load_edges(&dynamic_dir.join("post_hasTag_tag_0_0.csv"), "HAS_TAG", graph, &ids.post, &ids.tag, no_props)?.record(&mut edge_files);
     print_done(&format!("  HAS_TAG (Post->Tag):                   {:>12} edges ({})", format_num(n), format_duration(t.elapsed())));
     total_edges += n;
 
     let t = std::time::Instant::now();
-    let n = load_edges(&dynamic_dir.join("post_isLocatedIn_place_0_0.csv"), "IS_LOCATED_IN", graph, &ids.post, &ids.place, no_props)?;
+    let n = load_edges(&dynamic_dir.join("post_isLocatedIn_place_0_0.csv"), "IS_LOCATED_IN", graph, &ids.post, &ids.place, no_props)?.record(&mut edge_files);
     print_done(&format!("  IS_LOCATED_IN (Post->Place):           {:>12} edges ({})", format_num(n), format_duration(t.elapsed())));
     total_edges += n;
 
     let t = std::time::Instant::now();
-    let n = load_edges(&dynamic_dir.join("comment_hasCreator_person_0_0.csv"), "HAS_CREATOR", graph, &ids.comment, &ids.person, no_props)?;
+    let n = load_edges(&dynamic_dir.join("comment_hasCreator_person_0_0.csv"), "HAS_CREATOR", graph, &ids.comment, &ids.person, no_props)?.record(&mut edge_files);
     print_done(&format!("  HAS_CREATOR (Comment->Person):         {:>12} edges ({})", format_num(n), format_duration(t.elapsed())));
     total_edges += n;
 
     let t = std::time::Instant::now();
-    let n = load_edges(&dynamic_dir.join("comment_hasTag_tag_0_0.csv"), "HAS_TAG", graph, &ids.comment, &ids.tag, no_props)?;
+    let n = load_edges(&dynamic_dir.join("comment_hasTag_tag_0_0.csv"), "HAS_TAG", graph, &ids.comment, &ids.tag, no_props)?.record(&mut edge_files);
     print_done(&format!("  HAS_TAG (Comment->Tag):                {:>12} edges ({})", format_num(n), format_duration(t.elapsed())));
     total_edges += n;
 
     let t = std::time::Instant::now();
-    let n = load_edges(&dynamic_dir.join("comment_isLocatedIn_place_0_0.csv"), "IS_LOCATED_IN", graph, &ids.comment, &ids.place, no_props)?;
+    let n = load_edges(&dynamic_dir.join("comment_isLocatedIn_place_0_0.csv"), "IS_LOCATED_IN", graph, &ids.comment, &ids.place, no_props)?.record(&mut edge_files);
     print_done(&format!("  IS_LOCATED_IN (Comment->Place):        {:>12} edges ({})", format_num(n), format_duration(t.elapsed())));
     total_edges += n;
 
     let t = std::time::Instant::now();
-    let n = load_edges(&dynamic_dir.join("comment_replyOf_comment_0_0.csv"), "REPLY_OF", graph, &ids.comment, &ids.comment, no_props)?;
+    let n = load_edges(&dynamic_dir.join("comment_replyOf_comment_0_0.csv"), "REPLY_OF", graph, &ids.comment, &ids.comment, no_props)?.record(&mut edge_files);
     print_done(&format!("  REPLY_OF (Comment->Comment):           {:>12} edges ({})", format_num(n), format_duration(t.elapsed())));
     total_edges += n;
 
     let t = std::time::Instant::now();
-    let n = load_edges(&dynamic_dir.join("comment_replyOf_post_0_0.csv"), "REPLY_OF", graph, &ids.comment, &ids.post, no_props)?;
+    let n = load_edges(&dynamic_dir.join("comment_replyOf_post_0_0.csv"), "REPLY_OF", graph, &ids.comment, &ids.post, no_props)?.record(&mut edge_files);
     print_done(&format!("  REPLY_OF (Comment->Post):              {:>12} edges ({})", format_num(n), format_duration(t.elapsed())));
     total_edges += n;
 
-    Ok(LoadResult { total_nodes, total_edges })
+    let result = LoadResult { total_nodes, total_edges, edge_files };
+    for report in &result.edge_files {
+        let Some(issue) = report.integrity_issue(max_skip_pct) else { continue };
+        if report.exceeds_skip_pct(max_skip_pct) {
+            return Err(format!(
+                "loader integrity: {} — likely a parser/format mismatch (see tolerant `parse_ldbc_id`)",
+                issue
+            ).into());
+        }
+        eprintln!("  WARNING: {} — likely a parser/format mismatch", issue);
+    }
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        load_edges, no_props, parse_ldbc_id, EdgeFileReport, GraphStore, HashMap, NodeId,
+        DEFAULT_MAX_SKIP_PCT,
+    };
+
+    #[test]
+    fn plain_int() {
+        assert_eq!(parse_ldbc_id("123"), Some(123));
+        assert_eq!(parse_ldbc_id("0"), Some(0));
+        assert_eq!(parse_ldbc_id("-7"), Some(-7));
+        assert_eq!(parse_ldbc_id("2336462209434"), Some(2336462209434));
+    }
+
+    #[test]
+    fn float_with_zero_fraction() {
+        // Real CSV data: comment_replyOf_*.csv writes target ids as floats.
+        assert_eq!(parse_ldbc_id("2336462209434.0"), Some(2336462209434));
+        assert_eq!(parse_ldbc_id("123.000"), Some(123));
+        assert_eq!(parse_ldbc_id("0.0"), Some(0));
+    }
+
+    #[test]
+    fn float_with_nonzero_fraction_rejected() {
+        assert!(parse_ldbc_id("123.5").is_none());
+        assert!(parse_ldbc_id("1.001").is_none());
+    }
+
+    #[test]
+    fn whitespace_tolerated() {
+        assert_eq!(parse_ldbc_id("  123  "), Some(123));
+        assert_eq!(parse_ldbc_id(" 123.0 "), Some(123));
+    }
+
+    #[test]
+    fn garbage_rejected() {
+        assert!(parse_ldbc_id("").is_none());
+        assert!(parse_ldbc_id("abc").is_none());
+        assert!(parse_ldbc_id(".5").is_none());
+        assert!(parse_ldbc_id("1.2.3").is_none());
+    }
+
+    #[test]
+    fn affected_relationship_files_load_every_row() {
+        // Header and id formatting as written by the CsvBasic exporter for the
+        // files that used to drop rows: float targets, integer sources.
+        let files = [
+            ("comment_replyOf_comment_0_0.csv", "Comment.id|Comment.id", "REPLY_OF"),
+            ("comment_replyOf_post_0_0.csv", "Comment.id|Post.id", "REPLY_OF"),
+            ("place_isPartOf_place_0_0.csv", "Place.id|Place.id", "IS_PART_OF"),
+        ];
+        let dir = tempfile::tempdir().unwrap();
+        let mut graph = GraphStore::new();
+        let ids: HashMap<i64, NodeId> = [2336462209434, 2336462209435, 2336462209436]
+            .into_iter()
+            .map(|id| (id, graph.create_node("Entity")))
+            .collect();
+
+        for (name, header, edge_type) in files {
+            let path = dir.path().join(name);
+            std::fs::write(
+                &path,
+                format!("{header}\n2336462209435|2336462209434.0\n2336462209436|2336462209435.0\n"),
+            )
+            .unwrap();
+            let report = load_edges(&path, edge_type, &mut graph, &ids, &ids, no_props).unwrap();
+            assert_eq!(report.loaded, 2, "{name}");
+            assert_eq!(report.skipped, 0, "{name}");
+        }
+        assert_eq!(graph.edge_count(), 6);
+    }
+
+    #[test]
+    fn integrity_flags_empty_and_lossy_files() {
+        let report = |loaded, skipped| EdgeFileReport {
+            file: "comment_replyOf_post_0_0.csv".to_string(),
+            edge_type: "REPLY_OF".to_string(),
+            loaded,
+            skipped,
+        };
+        // A small file that loaded nothing is still flagged.
+        assert!(report(0, 3).integrity_issue(DEFAULT_MAX_SKIP_PCT).is_some());
+        assert!(report(900, 100).integrity_issue(DEFAULT_MAX_SKIP_PCT).is_some());
+        assert!(report(900, 100).integrity_issue(20).is_none());
+        assert!(report(990, 10).integrity_issue(DEFAULT_MAX_SKIP_PCT).is_none());
+        // Missing files (nothing loaded, nothing skipped) are not flagged.
+        assert!(report(0, 0).integrity_issue(DEFAULT_MAX_SKIP_PCT).is_none());
+    }
 }
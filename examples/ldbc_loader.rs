@@ -11,6 +11,12 @@
 //!   cargo run --release --example ldbc_loader
 //!   cargo run --release --example ldbc_loader -- --data-dir /path/to/ldbc-sf1/social_network-sf1-CsvBasic-LongDateFormatter
 //!   cargo run --release --example ldbc_loader -- --query   # drop into query loop after loading
+//!   cargo run --release --example ldbc_loader -- --strict  # also fail on files that loaded nothing
+//!
+//! Rows that fail to parse or reference unknown nodes are skipped. The load
+//! fails if a relationship file skips more than `--max-skip-pct` percent
+//! (default 5) of its rows. A file that loaded 0 rows while skipping some is
+//! only a warning, unless `--strict` is given.
 
 use std::io::{self, BufRead, Write};
 use std::path::PathBuf;
@@ -35,6 +41,13 @@ async fn main() -> Result<(), Error> {
     };
 
     let query_mode = args.iter().any(|a| a == "--query");
+    let strict = args.iter().any(|a| a == "--strict");
+    let max_skip_pct = match args.iter().position(|a| a == "--max-skip-pct") {
+        Some(pos) => args.get(pos + 1)
+            .and_then(|v| v.parse().ok())
+            .expect("--max-skip-pct requires an integer percentage"),
+        None => ldbc_common::DEFAULT_MAX_SKIP_PCT,
+    };
 
     if !data_dir.exists() {
         eprintln!("ERROR: Data directory not found: {}", data_dir.display());
@@ -51,7 +64,7 @@ async fn main() -> Result<(), Error> {
 
     let result = {
         let mut graph = client.store_write().await;
-        ldbc_common::load_dataset(&mut graph, &data_dir)?
+        ldbc_common::load_dataset(&mut graph, &data_dir, max_skip_pct)?
     };
 
     let total_elapsed = total_start.elapsed();
@@ -61,6 +74,18 @@ async fn main() -> Result<(), Error> {
     eprintln!("Graph ready. Nodes: {}, Edges: {}", format_num(result.total_nodes), format_num(result.total_edges));
     eprintln!("========================================");
 
+    if strict {
+        let issues = result.integrity_issues(max_skip_pct);
+        if !issues.is_empty() {
+            eprintln!();
+            eprintln!("ERROR: --strict: {} relationship file(s) failed integrity checks:", issues.len());
+            for issue in &issues {
+                eprintln!("  {}", issue);
+            }
+            std::process::exit(1);
+        }
+    }
+
     // ========================================================================
     // OPTIONAL: Interactive query mode
     // ========================================================================
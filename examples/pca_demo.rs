@@ -275,7 +275,7 @@ async fn main() {
     println!("Step 4: Building HNSW Index with 3D PCA Vectors");
     separator();
 
-    client.create_vector_index("Paper", "pca_embedding", n_components, DistanceMetric::L2)
+    client.create_vector_index("Paper", "pca_embedding", n_components, DistanceMetric::Euclidean)
         .await
         .expect("Failed to create PCA vector index");
 
@@ -337,7 +337,7 @@ async fn main() {
     separator();
 
     // Build full-dimensional HNSW index for comparison
-    client.create_vector_index("Paper", "full_embedding", FEATURES.len(), DistanceMetric::L2)
+    client.create_vector_index("Paper", "full_embedding", FEATURES.len(), DistanceMetric::Euclidean)
         .await
         .expect("Failed to create full vector index");
 
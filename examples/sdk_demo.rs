@@ -12,7 +12,7 @@
 
 use samyama_sdk::{
     EmbeddedClient, SamyamaClient, AlgorithmClient, VectorClient,
-    GraphStore, Label, NodeId, PageRankConfig, DistanceMetric,
+    GraphStore, Label, NodeId, PageRankConfig, Directedness, DistanceMetric,
 };
 use std::sync::Arc;
 use tokio::sync::RwLock;
@@ -112,7 +112,7 @@ async fn main() {
 
     // --- NEW: Algorithm Extension Trait ---
     println!("--- PageRank (via AlgorithmClient) ---");
-    let scores = client.page_rank(PageRankConfig::default(), Some("Person"), None).await;
+    let scores = client.page_rank(PageRankConfig::default(), Some("Person"), None, Directedness::Directed).await;
     let mut ranked: Vec<_> = scores.iter().collect();
     ranked.sort_by(|a, b| b.1.partial_cmp(a.1).unwrap());
     {
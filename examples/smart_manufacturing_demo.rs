@@ -10,7 +10,7 @@
 
 use samyama_sdk::{
     EmbeddedClient, SamyamaClient, AlgorithmClient,
-    Label, PropertyValue, PageRankConfig,
+    Label, PropertyValue, PageRankConfig, Directedness,
     NLQConfig, LLMProvider,
     CuckooSolver, JayaSolver, SolverConfig, Problem,
     Array1,
@@ -373,7 +373,7 @@ async fn main() {
     };
 
     let start = Instant::now();
-    let solver = CuckooSolver::new(SolverConfig { population_size: 50, max_iterations: 200 });
+    let solver = CuckooSolver::new(SolverConfig { population_size: 50, max_iterations: 200, ..Default::default() });
     let result = solver.solve(&schedule_problem);
     let sched_time = start.elapsed();
 
@@ -576,7 +576,7 @@ async fn main() {
     };
 
     let start = Instant::now();
-    let jaya_solver = JayaSolver::new(SolverConfig { population_size: 30, max_iterations: 100 });
+    let jaya_solver = JayaSolver::new(SolverConfig { population_size: 30, max_iterations: 100, ..Default::default() });
     let energy_result = jaya_solver.solve(&energy_problem);
     let energy_time = start.elapsed();
 
@@ -705,13 +705,13 @@ async fn main() {
     println!("  Running PageRank on factory graph to identify critical machines...");
 
     let start = Instant::now();
-    let view = client.build_view(None, None, None).await;
+    let view = client.build_view(None, None, None, Directedness::Directed).await;
     let scores = client.page_rank(PageRankConfig {
         damping_factor: 0.85,
         iterations: 30,
         tolerance: 0.0001,
         ..Default::default()
-    }, None, None).await;
+    }, None, None, Directedness::Directed).await;
     let pr_time = start.elapsed();
 
     // Collect machine scores and sort by criticality
@@ -772,6 +772,8 @@ async fn main() {
             api_key: None,
             api_base_url: None,
             system_prompt: Some("You are a Cypher query expert for a smart manufacturing knowledge graph.".to_string()),
+            retry: Default::default(),
+            fallback: None,
         };
 
         let schema_summary = "Node labels: ProductionLine, Machine, Product, Material\n\
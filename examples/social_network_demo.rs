@@ -7,7 +7,7 @@
 
 use samyama_sdk::{
     EmbeddedClient, SamyamaClient, AlgorithmClient,
-    Label, PropertyValue, NodeId, PageRankConfig,
+    Label, PropertyValue, NodeId, PageRankConfig, Directedness,
     NLQConfig, LLMProvider,
 };
 use std::fs::File;
@@ -223,7 +223,7 @@ async fn main() {
     println!("Step 2: Influencer Identification (PageRank)");
     println!("------------------------------------------------------------------------");
 
-    let scores = client.page_rank(PageRankConfig::default(), None, None).await;
+    let scores = client.page_rank(PageRankConfig::default(), None, None, Directedness::Directed).await;
 
     // Sort by PageRank score descending
     let mut ranked: Vec<(u64, f64)> = scores.iter().map(|(&id, &s)| (id, s)).collect();
@@ -270,7 +270,7 @@ async fn main() {
     println!("Step 3: Community Detection (Weakly Connected Components)");
     println!("------------------------------------------------------------------------");
 
-    let view = client.build_view(None, None, None).await;
+    let view = client.build_view(None, None, None, Directedness::Directed).await;
     let wcc = client.weakly_connected_components(None, None).await;
     let num_wcc = wcc.components.len();
 
@@ -760,6 +760,8 @@ async fn main() {
             api_key: None,
             api_base_url: None,
             system_prompt: Some("You are a Cypher query expert for a social network graph.".to_string()),
+            retry: Default::default(),
+            fallback: None,
         };
 
         let schema_summary = "Node labels: User\n\
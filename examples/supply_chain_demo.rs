@@ -16,10 +16,10 @@
 
 use samyama_sdk::{
     EmbeddedClient, SamyamaClient, AlgorithmClient, VectorClient,
-    Label, PropertyValue, PropertyMap,
+    Label, EdgeType, PropertyValue, PropertyMap,
     AgentConfig, LLMProvider, NLQConfig,
-    DistanceMetric, PageRankConfig,
-    JayaSolver, SolverConfig, Problem, Array1,
+    DistanceMetric, PageRankConfig, Directedness,
+    JayaSolver, SolverConfig, Problem, VariableType, Array1,
 };
 use std::collections::HashMap;
 use std::time::Instant;
@@ -852,9 +852,8 @@ async fn main() {
     {
         let store = client.store_read().await;
         for &pi in &alt_ports {
-            let edges = store.get_outgoing_edges(port_ids[pi]);
+            let edges = store.get_outgoing_edges_of_type(port_ids[pi], &EdgeType::new("ROUTES_THROUGH"));
             let connections: Vec<String> = edges.iter()
-                .filter(|e| e.edge_type.as_str() == "ROUTES_THROUGH")
                 .filter_map(|e| {
                     store.get_node(e.target)
                         .and_then(|n| n.get_property("name"))
@@ -934,13 +933,13 @@ async fn main() {
     let start = Instant::now();
 
     // PageRank on the full graph
-    let view = client.build_view(None, None, None).await;
+    let view = client.build_view(None, None, None, Directedness::Directed).await;
     let pr_scores = client.page_rank(PageRankConfig {
         damping_factor: 0.85,
         iterations: 30,
         tolerance: 0.0001,
         ..Default::default()
-    }, None, None).await;
+    }, None, None, Directedness::Directed).await;
     let pr_time = start.elapsed();
 
     println!("  PageRank computed in {:.2?} ({} nodes, {} edges in view)",
@@ -1027,6 +1026,7 @@ async fn main() {
         base_costs: [f64; 3],
         congestion_factors: [f64; 3],
         port_capacities: [f64; 3],
+        variable_types: Vec<VariableType>,
     }
 
     impl Problem for RerouteProblem {
@@ -1038,6 +1038,11 @@ async fn main() {
             (lower, upper)
         }
 
+        // Containers are whole units.
+        fn variable_types(&self) -> &[VariableType] {
+            &self.variable_types
+        }
+
         fn objective(&self, x: &Array1<f64>) -> f64 {
             let mut cost = 0.0;
             for i in 0..3 {
@@ -1063,12 +1068,14 @@ async fn main() {
         base_costs: [45.0, 52.0, 65.0],              // $/container base
         congestion_factors: [0.15, 0.10, 0.05],       // quadratic congestion
         port_capacities: [80.0, 60.0, 40.0],          // max containers
+        variable_types: vec![VariableType::Integer; 3],
     };
 
     let start = Instant::now();
     let jaya_solver = JayaSolver::new(SolverConfig {
         population_size: 30,
         max_iterations: 100,
+        ..Default::default()
     });
     let opt_result = jaya_solver.solve(&reroute_problem);
     let opt_time = start.elapsed();
@@ -1202,6 +1209,8 @@ async fn main() {
             api_key: None,
             api_base_url: None,
             system_prompt: Some("You are a Cypher query expert for a pharmaceutical supply chain knowledge graph.".to_string()),
+            retry: Default::default(),
+            fallback: None,
         };
 
         let schema_summary = "Node labels: Port, Supplier, Product, ShippingLine, Shipment\n\
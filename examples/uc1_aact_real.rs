@@ -210,6 +210,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let solver = NSGA2Solver::new(SolverConfig {
         population_size: 40,
         max_iterations: 40,
+        ..Default::default()
     });
     println!("\n[solve] NSGA-II pop=40 iter=40, 2 objectives (time-to-enrol, cost)");
     let p = problem.clone();
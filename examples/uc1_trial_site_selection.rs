@@ -185,6 +185,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let solver = NSGA2Solver::new(SolverConfig {
         population_size: 40,
         max_iterations: 40,
+        ..Default::default()
     });
 
     println!(
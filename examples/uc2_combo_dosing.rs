@@ -204,6 +204,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let solver = NSGA2Solver::new(SolverConfig {
         population_size: 50,
         max_iterations: 60,
+        ..Default::default()
     });
 
     println!(
@@ -153,6 +153,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let solver = NSGA2Solver::new(SolverConfig {
         population_size: 50,
         max_iterations: 60,
+        ..Default::default()
     });
     println!("\n[solve] NSGA-II pop=50 iter=60, 3 objectives (-efficacy, risk, total_dose)");
     let p = problem.clone();
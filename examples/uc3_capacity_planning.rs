@@ -240,6 +240,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         let solver = BMRSolver::new(SolverConfig {
             population_size: 40,
             max_iterations: 60,
+            ..Default::default()
         });
         println!("\n[solve] BMR pop=40 iter=60, budget=${:.1}M", budget / 1e6);
         let p = problem.clone();
@@ -245,6 +245,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         let solver = QOJayaSolver::new(SolverConfig {
             population_size: 20,
             max_iterations: 40,
+            ..Default::default()
         });
         let p = problem.clone();
         let res = tokio::task::spawn_blocking(move || solver.solve(&*p))
@@ -269,6 +269,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let solver = QOJayaSolver::new(SolverConfig {
         population_size: 14,
         max_iterations: 30,
+        ..Default::default()
     });
     println!("\n[solve] QO-Jaya pop=14 iter=30");
     let p = problem.clone();
@@ -349,6 +349,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let solver = NSGA2Solver::new(SolverConfig {
         population_size: 40,
         max_iterations: 30,
+        ..Default::default()
     });
     println!("\n[solve] NSGA-II pop=40 iter=30 on live telemetry");
     let p = problem.clone();
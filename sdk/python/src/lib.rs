@@ -3,12 +3,12 @@
 //! Exposes SamyamaClient with both embedded and remote modes to Python.
 
 use pyo3::prelude::*;
-use pyo3::exceptions::PyRuntimeError;
+use pyo3::exceptions::{PyRuntimeError, PyValueError};
 use pyo3::types::PyDict;
 use samyama_sdk::{
     EmbeddedClient, RemoteClient, SamyamaClient as SamyamaClientTrait,
     QueryResult as SdkQueryResult,
-    AlgorithmClient, PageRankConfig, PcaConfig,
+    AlgorithmClient, Directedness, PageRankConfig, PathResult, PcaConfig,
     VectorClient, DistanceMetric, NodeId,
 };
 use std::collections::HashMap;
@@ -51,6 +51,33 @@ impl QueryResult {
         ))
     }
 
+    /// Rows as a list of `{column: value}` dicts, e.g. for `pd.DataFrame(result.to_dict_records())`.
+    /// Node and edge values are nested dicts with their id, labels/type and properties.
+    fn to_dict_records(&self, py: Python<'_>) -> PyResult<PyObject> {
+        let rows = self.records_json.iter()
+            .map(|row| {
+                let dict = PyDict::new_bound(py);
+                for (col, value) in self.columns.iter().zip(row) {
+                    dict.set_item(col, json_to_py(py, value)?)?;
+                }
+                Ok(dict.to_object(py))
+            })
+            .collect::<PyResult<Vec<PyObject>>>()?;
+        Ok(rows.to_object(py))
+    }
+
+    /// Columns as a `{column: [values...]}` dict, e.g. for `pd.DataFrame(result.to_columns())`.
+    fn to_columns(&self, py: Python<'_>) -> PyResult<PyObject> {
+        let dict = PyDict::new_bound(py);
+        for (i, col) in self.columns.iter().enumerate() {
+            let values = self.records_json.iter()
+                .map(|row| json_to_py(py, row.get(i).unwrap_or(&serde_json::Value::Null)))
+                .collect::<PyResult<Vec<PyObject>>>()?;
+            dict.set_item(col, values)?;
+        }
+        Ok(dict.to_object(py))
+    }
+
     #[getter]
     fn nodes(&self, py: Python<'_>) -> PyResult<PyObject> {
         json_to_py(py, &serde_json::Value::Array(self.nodes_json.clone()))
@@ -145,6 +172,31 @@ fn json_to_py(py: Python<'_>, value: &serde_json::Value) -> PyResult<PyObject> {
     }
 }
 
+/// Convert a component map to `{"components": {...}, "component_count": n}`
+fn components_to_py(py: Python<'_>, components: &HashMap<usize, Vec<u64>>) -> PyResult<PyObject> {
+    let dict = PyDict::new_bound(py);
+    let components_dict = PyDict::new_bound(py);
+    for (k, v) in components {
+        components_dict.set_item(k, v.to_object(py))?;
+    }
+    dict.set_item("components", components_dict)?;
+    dict.set_item("component_count", components.len())?;
+    Ok(dict.to_object(py))
+}
+
+/// Convert an optional path to `{"path": [...], "cost": c}` or None
+fn path_to_py(py: Python<'_>, result: Option<PathResult>) -> PyResult<PyObject> {
+    match result {
+        Some(path) => {
+            let dict = PyDict::new_bound(py);
+            dict.set_item("path", path.path.to_object(py))?;
+            dict.set_item("cost", path.cost)?;
+            Ok(dict.to_object(py))
+        }
+        None => Ok(py.None()),
+    }
+}
+
 /// Internal enum to hold either embedded or remote client
 enum ClientInner {
     Embedded(EmbeddedClient),
@@ -256,6 +308,16 @@ impl SamyamaClient {
         result.map_err(|e| PyRuntimeError::new_err(e.to_string()))
     }
 
+    /// Deep-copy graph `src` into a new graph `dst`
+    fn copy_graph(&self, src: &str, dst: &str) -> PyResult<()> {
+        let rt = get_runtime();
+        let result = match &*self.inner {
+            ClientInner::Embedded(c) => rt.block_on(c.copy_graph(src, dst)),
+            ClientInner::Remote(c) => rt.block_on(c.copy_graph(src, dst)),
+        };
+        result.map_err(|e| PyRuntimeError::new_err(e.to_string()))
+    }
+
     /// List graphs
     fn list_graphs(&self) -> PyResult<Vec<String>> {
         let rt = get_runtime();
@@ -271,8 +333,9 @@ impl SamyamaClient {
     // ========================================================================
 
     /// Run PageRank on the graph.
+    /// Pass directed=False to let rank flow both ways along every edge.
     /// Returns dict mapping node_id -> score.
-    #[pyo3(signature = (label=None, edge_type=None, damping=0.85, iterations=20, tolerance=1e-6))]
+    #[pyo3(signature = (label=None, edge_type=None, damping=0.85, iterations=20, tolerance=1e-6, directed=true))]
     fn page_rank(
         &self,
         py: Python<'_>,
@@ -281,6 +344,7 @@ impl SamyamaClient {
         damping: f64,
         iterations: usize,
         tolerance: f64,
+        directed: bool,
     ) -> PyResult<PyObject> {
         let client = self.require_embedded()?;
         let rt = get_runtime();
@@ -290,7 +354,9 @@ impl SamyamaClient {
             tolerance,
             ..Default::default()
         };
-        let scores: HashMap<u64, f64> = rt.block_on(client.page_rank(config, label, edge_type));
+        let directedness = if directed { Directedness::Directed } else { Directedness::Undirected };
+        let scores: HashMap<u64, f64> =
+            py.allow_threads(|| rt.block_on(client.page_rank(config, label, edge_type, directedness)));
         let dict = PyDict::new_bound(py);
         for (k, v) in &scores {
             dict.set_item(k, v)?;
@@ -304,16 +370,8 @@ impl SamyamaClient {
     fn wcc(&self, py: Python<'_>, label: Option<&str>, edge_type: Option<&str>) -> PyResult<PyObject> {
         let client = self.require_embedded()?;
         let rt = get_runtime();
-        let result = rt.block_on(client.weakly_connected_components(label, edge_type));
-        let dict = PyDict::new_bound(py);
-        let component_count = result.components.len();
-        let components_dict = PyDict::new_bound(py);
-        for (k, v) in &result.components {
-            components_dict.set_item(k, v.to_object(py))?;
-        }
-        dict.set_item("components", components_dict)?;
-        dict.set_item("component_count", component_count)?;
-        Ok(dict.to_object(py))
+        let result = py.allow_threads(|| rt.block_on(client.weakly_connected_components(label, edge_type)));
+        components_to_py(py, &result.components)
     }
 
     /// Detect strongly connected components.
@@ -322,16 +380,39 @@ impl SamyamaClient {
     fn scc(&self, py: Python<'_>, label: Option<&str>, edge_type: Option<&str>) -> PyResult<PyObject> {
         let client = self.require_embedded()?;
         let rt = get_runtime();
-        let result = rt.block_on(client.strongly_connected_components(label, edge_type));
-        let dict = PyDict::new_bound(py);
-        let component_count = result.components.len();
-        let components_dict = PyDict::new_bound(py);
-        for (k, v) in &result.components {
-            components_dict.set_item(k, v.to_object(py))?;
+        let result = py.allow_threads(|| rt.block_on(client.strongly_connected_components(label, edge_type)));
+        components_to_py(py, &result.components)
+    }
+
+    /// Detect weakly connected components (same result as `wcc`).
+    /// Returns dict with 'components' (dict of component_id -> list of node IDs) and 'component_count'.
+    #[pyo3(signature = (label=None, edge_type=None))]
+    fn weakly_connected_components(
+        &self,
+        py: Python<'_>,
+        label: Option<&str>,
+        edge_type: Option<&str>,
+    ) -> PyResult<PyObject> {
+        self.wcc(py, label, edge_type)
+    }
+
+    /// Shortest path from source to target.
+    /// Uses Dijkstra over `weight_property` when given, otherwise BFS (hop count).
+    /// Returns dict with 'path' (list of node IDs) and 'cost', or None if no path.
+    #[pyo3(signature = (source, target, label=None, edge_type=None, weight_property=None))]
+    fn shortest_path(
+        &self,
+        py: Python<'_>,
+        source: u64,
+        target: u64,
+        label: Option<&str>,
+        edge_type: Option<&str>,
+        weight_property: Option<&str>,
+    ) -> PyResult<PyObject> {
+        match weight_property {
+            Some(_) => self.dijkstra(py, source, target, label, edge_type, weight_property),
+            None => self.bfs(py, source, target, label, edge_type),
         }
-        dict.set_item("components", components_dict)?;
-        dict.set_item("component_count", component_count)?;
-        Ok(dict.to_object(py))
     }
 
     /// Breadth-first search from source to target.
@@ -347,16 +428,8 @@ impl SamyamaClient {
     ) -> PyResult<PyObject> {
         let client = self.require_embedded()?;
         let rt = get_runtime();
-        let result = rt.block_on(client.bfs(source, target, label, edge_type));
-        match result {
-            Some(path) => {
-                let dict = PyDict::new_bound(py);
-                dict.set_item("path", path.path.to_object(py))?;
-                dict.set_item("cost", path.cost)?;
-                Ok(dict.to_object(py))
-            }
-            None => Ok(py.None()),
-        }
+        let result = py.allow_threads(|| rt.block_on(client.bfs(source, target, label, edge_type)));
+        path_to_py(py, result)
     }
 
     /// Dijkstra's shortest path from source to target (weighted).
@@ -373,16 +446,8 @@ impl SamyamaClient {
     ) -> PyResult<PyObject> {
         let client = self.require_embedded()?;
         let rt = get_runtime();
-        let result = rt.block_on(client.dijkstra(source, target, label, edge_type, weight_property));
-        match result {
-            Some(path) => {
-                let dict = PyDict::new_bound(py);
-                dict.set_item("path", path.path.to_object(py))?;
-                dict.set_item("cost", path.cost)?;
-                Ok(dict.to_object(py))
-            }
-            None => Ok(py.None()),
-        }
+        let result = py.allow_threads(|| rt.block_on(client.dijkstra(source, target, label, edge_type, weight_property)));
+        path_to_py(py, result)
     }
 
     /// Run PCA on node numeric properties.
@@ -403,7 +468,7 @@ impl SamyamaClient {
             ..PcaConfig::default()
         };
         let props_refs: Vec<&str> = properties.iter().map(|s| s.as_str()).collect();
-        let result = rt.block_on(client.pca(label, &props_refs, config));
+        let result = py.allow_threads(|| rt.block_on(client.pca(label, &props_refs, config)));
         let dict = PyDict::new_bound(py);
         // Convert components (Vec<Vec<f64>>) to list of lists
         let components: Vec<Vec<f64>> = result.components;
@@ -419,10 +484,10 @@ impl SamyamaClient {
 
     /// Count triangles in the graph.
     #[pyo3(signature = (label=None, edge_type=None))]
-    fn triangle_count(&self, label: Option<&str>, edge_type: Option<&str>) -> PyResult<usize> {
+    fn triangle_count(&self, py: Python<'_>, label: Option<&str>, edge_type: Option<&str>) -> PyResult<usize> {
         let client = self.require_embedded()?;
         let rt = get_runtime();
-        Ok(rt.block_on(client.count_triangles(label, edge_type)))
+        Ok(py.allow_threads(|| rt.block_on(client.count_triangles(label, edge_type))))
     }
 
     // ========================================================================
@@ -430,7 +495,7 @@ impl SamyamaClient {
     // ========================================================================
 
     /// Create a vector index for a label/property.
-    /// metric: "cosine", "l2", or "dot"
+    /// metric: "cosine", "euclidean" (alias "l2"), or "dot_product" (aliases "dot", "inner_product")
     #[pyo3(signature = (label, property, dimensions, metric="cosine"))]
     fn create_vector_index(
         &self,
@@ -441,17 +506,23 @@ impl SamyamaClient {
     ) -> PyResult<()> {
         let client = self.require_embedded()?;
         let rt = get_runtime();
-        let dist = match metric {
-            "l2" => DistanceMetric::L2,
-            "dot" | "inner_product" => DistanceMetric::InnerProduct,
-            _ => DistanceMetric::Cosine,
+        let dist = match metric.to_ascii_lowercase().as_str() {
+            "cosine" => DistanceMetric::Cosine,
+            "euclidean" | "l2" => DistanceMetric::Euclidean,
+            "dot_product" | "dot" | "inner_product" => DistanceMetric::DotProduct,
+            other => {
+                return Err(PyValueError::new_err(format!(
+                    "unknown metric '{}'; expected cosine, euclidean, or dot_product", other
+                )))
+            }
         };
         rt.block_on(client.create_vector_index(label, property, dimensions, dist))
             .map_err(|e| PyRuntimeError::new_err(e.to_string()))
     }
 
-    /// Add a vector for a node in a vector index.
-    fn add_vector(
+    /// Insert (or replace) the vector of a node in a vector index.
+    /// `vector` may be a list of floats or a 1-D numpy array.
+    fn insert_vector(
         &self,
         label: &str,
         property: &str,
@@ -464,20 +535,46 @@ impl SamyamaClient {
             .map_err(|e| PyRuntimeError::new_err(e.to_string()))
     }
 
-    /// Search for k nearest neighbors. Returns list of (node_id, distance) tuples.
-    #[pyo3(signature = (label, property, query_vector, k=10))]
+    /// Add a vector for a node in a vector index (alias of `insert_vector`).
+    fn add_vector(
+        &self,
+        label: &str,
+        property: &str,
+        node_id: u64,
+        vector: Vec<f32>,
+    ) -> PyResult<()> {
+        self.insert_vector(label, property, node_id, vector)
+    }
+
+    /// Search for the k nearest neighbors of `query_vector` (a list or 1-D numpy array).
+    /// Returns a list of (node_id, distance) tuples, best match first.
+    /// With `similarity=True` the second element is instead a score that is higher
+    /// for closer vectors: the dot product itself for "dot_product" indexes,
+    /// 1 / (1 + distance) otherwise.
+    #[pyo3(signature = (label, property, query_vector, k=10, similarity=false))]
     fn vector_search(
         &self,
         label: &str,
         property: &str,
         query_vector: Vec<f32>,
         k: usize,
+        similarity: bool,
     ) -> PyResult<Vec<(u64, f32)>> {
         let client = self.require_embedded()?;
         let rt = get_runtime();
-        let results = rt.block_on(client.vector_search(label, property, &query_vector, k))
-            .map_err(|e| PyRuntimeError::new_err(e.to_string()))?;
-        Ok(results.into_iter().map(|(nid, dist)| (nid.0, dist)).collect())
+        rt.block_on(async {
+            let results = client.vector_search(label, property, &query_vector, k).await
+                .map_err(|e| PyRuntimeError::new_err(e.to_string()))?;
+            if !similarity {
+                return Ok(results.into_iter().map(|(nid, dist)| (nid.0, dist)).collect());
+            }
+            let metric = client.store_read().await
+                .vector_index
+                .get_index(label, property)
+                .map(|index| index.read().unwrap().metric())
+                .unwrap_or(DistanceMetric::Cosine);
+            Ok(results.into_iter().map(|(nid, dist)| (nid.0, metric.score(dist))).collect())
+        })
     }
 
     fn __repr__(&self) -> String {
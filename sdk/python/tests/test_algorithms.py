@@ -0,0 +1,53 @@
+"""Tests for graph algorithm methods in the Samyama Python SDK."""
+
+import pytest
+
+from samyama import SamyamaClient
+
+
+@pytest.fixture
+def client():
+    """Chain A -> B -> C with a costly shortcut A -> C, plus an isolated D."""
+    client = SamyamaClient.embedded()
+    for name in ["A", "B", "C", "D"]:
+        client.query(f"CREATE (n:City {{name: '{name}'}})")
+    for src, dst, km in [("A", "B", 1.0), ("B", "C", 1.0), ("A", "C", 5.0)]:
+        client.query(
+            f"MATCH (a:City {{name: '{src}'}}), (b:City {{name: '{dst}'}}) "
+            f"CREATE (a)-[:ROAD {{km: {km}}}]->(b)"
+        )
+    return client
+
+
+def ids(client):
+    result = client.query_readonly("MATCH (n:City) RETURN n.name, id(n)")
+    return {name: nid for name, nid in result.records}
+
+
+def test_page_rank(client):
+    scores = client.page_rank(label="City", edge_type="ROAD")
+    city = ids(client)
+    assert set(scores) == set(city.values())
+    assert scores[city["C"]] == max(scores.values())
+
+
+def test_weakly_connected_components(client):
+    result = client.weakly_connected_components(label="City", edge_type="ROAD")
+    assert result["component_count"] == 2
+    sizes = sorted(len(members) for members in result["components"].values())
+    assert sizes == [1, 3]
+    assert result == client.wcc(label="City", edge_type="ROAD")
+
+
+def test_shortest_path(client):
+    city = ids(client)
+    hops = client.shortest_path(city["A"], city["C"], label="City", edge_type="ROAD")
+    assert hops["path"] == [city["A"], city["C"]]
+
+    weighted = client.shortest_path(
+        city["A"], city["C"], label="City", edge_type="ROAD", weight_property="km"
+    )
+    assert weighted["path"] == [city["A"], city["B"], city["C"]]
+    assert weighted["cost"] == pytest.approx(2.0)
+
+    assert client.shortest_path(city["A"], city["D"], label="City", edge_type="ROAD") is None
@@ -57,3 +57,21 @@ def test_delete_graph():
 
     client.delete_graph()
     assert client.status().nodes == 0
+
+
+def test_dict_records_and_columns():
+    """Test pandas-friendly result conversions."""
+    client = samyama.SamyamaClient.embedded()
+    client.query('CREATE (n:Person {name: "Alice", age: 30})')
+    client.query('CREATE (n:Person {name: "Bob", age: 25})')
+
+    result = client.query_readonly("MATCH (n:Person) RETURN n.name, n.age ORDER BY n.age")
+    assert result.to_dict_records() == [
+        {"n.name": "Bob", "n.age": 25},
+        {"n.name": "Alice", "n.age": 30},
+    ]
+    assert result.to_columns() == {"n.name": ["Bob", "Alice"], "n.age": [25, 30]}
+
+    node = client.query_readonly('MATCH (n:Person {name: "Alice"}) RETURN n').to_dict_records()[0]["n"]
+    assert node["labels"] == ["Person"]
+    assert node["properties"] == {"name": "Alice", "age": 30}
@@ -1,5 +1,7 @@
 """Tests for vector search methods in the Samyama Python SDK."""
 
+import pytest
+
 from samyama import SamyamaClient
 
 
@@ -36,3 +38,46 @@ def test_vector_search_l2_metric():
     results = client.vector_search("Item", "vec", [1.0, 2.0, 3.0], 1)
     assert len(results) == 1
     assert results[0][0] == nid
+
+
+def test_insert_vector_and_scores():
+    client = SamyamaClient.embedded()
+    client.create_vector_index("Doc", "embedding", 3, "euclidean")
+    client.query("CREATE (d:Doc {title: 'Near'})")
+    client.query("CREATE (d:Doc {title: 'Far'})")
+    result = client.query_readonly("MATCH (d:Doc) RETURN id(d) ORDER BY d.title DESC")
+    near, far = [row[0] for row in result.records]
+
+    client.insert_vector("Doc", "embedding", near, [1.0, 0.0, 0.0])
+    client.insert_vector("Doc", "embedding", far, [5.0, 0.0, 0.0])
+
+    # By default the second element is the distance
+    results = client.vector_search("Doc", "embedding", [1.0, 0.0, 0.0], 2)
+    assert [nid for nid, _ in results] == [near, far]
+    assert abs(results[0][1]) < 1e-6
+    assert results[0][1] < results[1][1]
+
+    # similarity=True: an exact match scores 1.0 and farther vectors lower
+    results = client.vector_search("Doc", "embedding", [1.0, 0.0, 0.0], 2, similarity=True)
+    assert [nid for nid, _ in results] == [near, far]
+    assert abs(results[0][1] - 1.0) < 1e-6
+    assert results[0][1] > results[1][1]
+
+
+def test_numpy_vectors():
+    np = pytest.importorskip("numpy")
+    client = SamyamaClient.embedded()
+    client.create_vector_index("Doc", "embedding", 4, "dot_product")
+    client.query("CREATE (d:Doc {title: 'Alpha'})")
+    nid = client.query_readonly("MATCH (d:Doc) RETURN id(d)").records[0][0]
+
+    client.insert_vector("Doc", "embedding", nid, np.array([1.0, 2.0, 0.0, 0.0], dtype=np.float32))
+    results = client.vector_search("Doc", "embedding", np.array([1.0, 1.0, 0.0, 0.0]), 1, similarity=True)
+    assert results[0][0] == nid
+    assert abs(results[0][1] - 3.0) < 1e-6
+
+
+def test_unknown_metric_rejected():
+    client = SamyamaClient.embedded()
+    with pytest.raises(ValueError):
+        client.create_vector_index("Doc", "embedding", 4, "manhattan")
@@ -42,10 +42,15 @@ pub trait Tool: Send + Sync {
     async fn execute(&self, args: Value) -> AgentResult<Value>;
 }
 
+/// Default bound on LLM round trips in [`AgentRuntime::process_trigger`]
+pub const DEFAULT_MAX_STEPS: usize = 5;
+
 /// Runtime for executing agents
 pub struct AgentRuntime {
     config: AgentConfig,
     tools: HashMap<String, Arc<dyn Tool>>,
+    /// Maximum LLM calls per trigger before giving up on a final answer
+    max_steps: usize,
     /// Optional graph store for telemetry writes from plan execution.
     /// When set, `execute_plan` / `plan_and_execute` record each tool
     /// call as a `(:Question)-[:USED_TOOL]->(:Tool)` edge.
@@ -57,10 +62,17 @@ impl AgentRuntime {
         Self {
             config,
             tools: HashMap::new(),
+            max_steps: DEFAULT_MAX_STEPS,
             store: None,
         }
     }
 
+    /// Bound the number of LLM calls made by `process_trigger`.
+    pub fn with_max_steps(mut self, max_steps: usize) -> Self {
+        self.max_steps = max_steps.max(1);
+        self
+    }
+
     /// Attach a graph store handle so plan execution emits telemetry edges.
     pub fn with_store(mut self, store: Arc<RwLock<GraphStore>>) -> Self {
         self.store = Some(store);
@@ -80,19 +92,88 @@ impl AgentRuntime {
             api_key: config.api_key.clone(),
             api_base_url: config.api_base_url.clone(),
             system_prompt: config.system_prompt.clone(),
+            retry: Default::default(),
+            fallback: None,
         }
     }
 
     /// Process a trigger (e.g., "Enrich Company node X")
-    pub async fn process_trigger(&self, prompt: &str, _context: &str) -> AgentResult<String> {
+    ///
+    /// Runs a ReAct-style loop: the LLM either answers directly or replies
+    /// `TOOL: <name> ARGUMENTS: <json>`, in which case the tool is executed
+    /// and its result appended to the conversation for the next call. Returns
+    /// the first reply that requests no tool. With no tools registered this
+    /// is a single LLM call on `prompt`.
+    pub async fn process_trigger(&self, prompt: &str, context: &str) -> AgentResult<String> {
         let nlq_config = Self::to_nlq_config(&self.config);
         let client =
             NLQClient::new(&nlq_config).map_err(|e| AgentError::ConfigError(e.to_string()))?;
-        let response = client
-            .generate_cypher(prompt)
-            .await
-            .map_err(|e| AgentError::LLMError(e.to_string()))?;
-        Ok(response)
+        self.run_tool_loop(prompt, context, |transcript| {
+            let client = &client;
+            async move {
+                client
+                    .generate_cypher(&transcript)
+                    .await
+                    .map_err(|e| AgentError::LLMError(e.to_string()))
+            }
+        })
+        .await
+    }
+
+    /// Drive the tool-use loop with `llm` standing in for the model.
+    async fn run_tool_loop<F, Fut>(&self, prompt: &str, context: &str, mut llm: F) -> AgentResult<String>
+    where
+        F: FnMut(String) -> Fut,
+        Fut: std::future::Future<Output = AgentResult<String>>,
+    {
+        if self.tools.is_empty() {
+            return llm(prompt.to_string()).await;
+        }
+
+        let mut transcript = self.tool_loop_prompt(prompt, context);
+        for _ in 0..self.max_steps {
+            let response = llm(transcript.clone()).await?;
+            let Some((name, args)) = parse_tool_call(&response) else {
+                return Ok(response.trim().to_string());
+            };
+
+            let result = match (self.tools.get(&name), args) {
+                (None, _) => format!("ERROR: tool '{}' is not available", name),
+                (Some(_), Err(e)) => format!("ERROR: invalid arguments: {}", e),
+                (Some(tool), Ok(args)) => match tool.execute(args).await {
+                    Ok(value) => value.to_string(),
+                    Err(e) => format!("ERROR: {}", e),
+                },
+            };
+            transcript.push_str(&format!("\n\n{}\nRESULT: {}", response.trim(), result));
+        }
+
+        Err(AgentError::ExecutionError(format!(
+            "agent did not produce a final answer within {} steps",
+            self.max_steps
+        )))
+    }
+
+    /// Initial prompt for the tool-use loop, listing the registered tools.
+    fn tool_loop_prompt(&self, prompt: &str, context: &str) -> String {
+        let mut names: Vec<&String> = self.tools.keys().collect();
+        names.sort();
+        let catalog: Vec<String> = names
+            .into_iter()
+            .map(|name| {
+                let tool = &self.tools[name];
+                format!("- {}: {} Parameters: {}", name, tool.description(), tool.parameters())
+            })
+            .collect();
+        format!(
+            "You can use these tools:\n{}\n\n\
+To call a tool, reply with exactly one line `TOOL: <name> ARGUMENTS: <json object>` and nothing else. \
+The tool's result will be appended as `RESULT: ...`. When no further tool is needed, reply with the final answer.\n\n\
+Context: {}\n\nTask: {}",
+            catalog.join("\n"),
+            context,
+            prompt,
+        )
     }
 
     /// Execute a pre-built plan against the registered tools, writing
@@ -152,6 +233,32 @@ Tools: {}\n\nContext: {}\n\nPrompt: {}",
     }
 }
 
+/// Parse a `TOOL: <name> ARGUMENTS: <json>` request out of an LLM reply.
+///
+/// Returns `None` when the reply requests no tool. Missing arguments
+/// default to `{}`; malformed JSON is reported so it can be fed back.
+fn parse_tool_call(response: &str) -> Option<(String, Result<Value, String>)> {
+    let start = response.find("TOOL:")?;
+    let rest = &response[start + "TOOL:".len()..];
+    let (name, args) = match rest.find("ARGUMENTS:") {
+        Some(i) => (&rest[..i], Some(&rest[i + "ARGUMENTS:".len()..])),
+        None => (rest.lines().next().unwrap_or(""), None),
+    };
+    let name = name.trim().trim_matches('`').to_string();
+    if name.is_empty() {
+        return None;
+    }
+    let args = match args {
+        None => Ok(Value::Object(Default::default())),
+        Some(raw) => serde_json::Deserializer::from_str(raw.trim())
+            .into_iter::<Value>()
+            .next()
+            .unwrap_or_else(|| Ok(Value::Object(Default::default())))
+            .map_err(|e| e.to_string()),
+    };
+    Some((name, args))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -197,6 +304,91 @@ mod tests {
         assert_eq!(nlq_config.model, "mock-model");
     }
 
+    /// Writes `args.property` onto every node of `args.label`
+    struct EnrichTool {
+        store: Arc<RwLock<GraphStore>>,
+    }
+
+    #[async_trait]
+    impl Tool for EnrichTool {
+        fn name(&self) -> &str { "enrich" }
+        fn description(&self) -> &str { "Set a property on nodes." }
+        fn parameters(&self) -> Value { serde_json::json!({"type": "object"}) }
+        async fn execute(&self, args: Value) -> AgentResult<Value> {
+            let label = args["label"].as_str().ok_or_else(|| AgentError::ToolError("missing label".into()))?;
+            let mut store = self.store.write().await;
+            let ids: Vec<_> = store.get_nodes_by_label(&crate::graph::Label::new(label))
+                .iter().map(|n| n.id).collect();
+            for id in &ids {
+                store.get_node_mut(*id).unwrap().set_property("industry", args["industry"].as_str().unwrap_or(""));
+            }
+            Ok(serde_json::json!({"updated": ids.len()}))
+        }
+    }
+
+    #[test]
+    fn test_parse_tool_call() {
+        let (name, args) = parse_tool_call("TOOL: web_search ARGUMENTS: {\"query\": \"acme\"}").unwrap();
+        assert_eq!(name, "web_search");
+        assert_eq!(args.unwrap()["query"], "acme");
+
+        let (name, args) = parse_tool_call("Thinking...\nTOOL: ping\n").unwrap();
+        assert_eq!(name, "ping");
+        assert_eq!(args.unwrap(), serde_json::json!({}));
+
+        assert!(parse_tool_call("TOOL: x ARGUMENTS: {oops").unwrap().1.is_err());
+        assert!(parse_tool_call("Acme is a manufacturer.").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_tool_loop_executes_tools() {
+        let store = Arc::new(RwLock::new(GraphStore::new()));
+        store.write().await.create_node("Company");
+        let mut runtime = AgentRuntime::new(mock_agent_config());
+        runtime.register_tool(Arc::new(EnrichTool { store: store.clone() }));
+
+        let replies = std::sync::Mutex::new(vec![
+            "TOOL: enrich ARGUMENTS: {\"label\": \"Company\", \"industry\": \"Pharma\"}".to_string(),
+            "TOOL: missing ARGUMENTS: {}".to_string(),
+            "Enriched 1 company.".to_string(),
+        ]);
+        let prompts = std::sync::Mutex::new(Vec::new());
+        let answer = runtime
+            .run_tool_loop("Enrich companies", "Node: Company", |transcript| {
+                prompts.lock().unwrap().push(transcript);
+                let reply = replies.lock().unwrap().remove(0);
+                async move { Ok(reply) }
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(answer, "Enriched 1 company.");
+        let prompts = prompts.into_inner().unwrap();
+        assert_eq!(prompts.len(), 3);
+        assert!(prompts[0].contains("- enrich: Set a property on nodes."));
+        assert!(prompts[1].contains("RESULT: {\"updated\":1}"));
+        assert!(prompts[2].contains("ERROR: tool 'missing' is not available"));
+
+        let guard = store.read().await;
+        let company = &guard.get_nodes_by_label(&crate::graph::Label::new("Company"))[0];
+        assert_eq!(company.get_property("industry"), Some(&crate::graph::PropertyValue::String("Pharma".into())));
+    }
+
+    #[tokio::test]
+    async fn test_tool_loop_step_bound() {
+        let mut runtime = AgentRuntime::new(mock_agent_config()).with_max_steps(3);
+        runtime.register_tool(Arc::new(tools::WebSearchTool::new("test-key".to_string())));
+        let calls = std::sync::atomic::AtomicUsize::new(0);
+        let result = runtime
+            .run_tool_loop("Loop forever", "", |_| {
+                calls.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+                async { Ok("TOOL: web_search ARGUMENTS: {\"query\": \"again\"}".to_string()) }
+            })
+            .await;
+        assert!(matches!(result, Err(AgentError::ExecutionError(_))));
+        assert_eq!(calls.into_inner(), 3);
+    }
+
     #[tokio::test]
     async fn test_process_trigger_mock() {
         let config = mock_agent_config();
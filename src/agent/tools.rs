@@ -10,14 +10,18 @@ use tokio::sync::RwLock;
 /// Cypher read-only query tool. Executes the `query` arg against the
 /// provided graph store and returns `{records: [[...]], headers: [...]}`.
 /// Unlike WebSearchTool below this is not a stub — it wires straight
-/// to the same QueryEngine the RESP/HTTP layers use.
-pub struct CypherTool {
+/// to the same QueryEngine the RESP/HTTP layers use. Queries containing
+/// write or schema clauses are refused before execution.
+pub struct CypherQueryTool {
     engine: Arc<QueryEngine>,
     store: Arc<RwLock<GraphStore>>,
     tenant: String,
 }
 
-impl CypherTool {
+/// Earlier name of [`CypherQueryTool`].
+pub type CypherTool = CypherQueryTool;
+
+impl CypherQueryTool {
     pub fn new(engine: Arc<QueryEngine>, store: Arc<RwLock<GraphStore>>) -> Self {
         Self { engine, store, tenant: "default".to_string() }
     }
@@ -29,7 +33,7 @@ impl CypherTool {
 }
 
 #[async_trait]
-impl Tool for CypherTool {
+impl Tool for CypherQueryTool {
     fn name(&self) -> &str { "cypher" }
     fn description(&self) -> &str {
         "Run a read-only Cypher query against the graph and return matching records."
@@ -47,6 +51,13 @@ impl Tool for CypherTool {
         let query = args.get("query").and_then(|v| v.as_str()).ok_or_else(|| {
             AgentError::ToolError("missing 'query' parameter".into())
         })?;
+        let read_only = self
+            .engine
+            .is_read_only(query)
+            .map_err(|e| AgentError::ToolError(format!("cypher: {e}")))?;
+        if !read_only {
+            return Err(AgentError::ToolError("cypher: only read-only queries are allowed".into()));
+        }
         let store = self.store.read().await;
         let batch = self
             .engine
@@ -190,6 +201,52 @@ mod tests {
         assert!(value["results"].is_array());
     }
 
+    fn people_store() -> Arc<RwLock<GraphStore>> {
+        let mut store = GraphStore::new();
+        for (name, age) in [("Alice", 30i64), ("Bob", 25)] {
+            let id = store.create_node("Person");
+            store.get_node_mut(id).unwrap().set_property("name", name);
+            store.get_node_mut(id).unwrap().set_property("age", age);
+        }
+        Arc::new(RwLock::new(store))
+    }
+
+    #[tokio::test]
+    async fn test_cypher_query_tool_execute() {
+        let store = people_store();
+        let mut runtime = crate::agent::AgentRuntime::new(crate::persistence::tenant::AgentConfig {
+            enabled: true,
+            provider: crate::persistence::tenant::LLMProvider::Mock,
+            model: "mock".to_string(),
+            api_key: None,
+            api_base_url: None,
+            system_prompt: None,
+            tools: vec![],
+            policies: std::collections::HashMap::new(),
+        });
+        let tool: Arc<dyn Tool> = Arc::new(CypherQueryTool::new(Arc::new(QueryEngine::new()), store));
+        runtime.register_tool(tool.clone());
+        assert!(runtime.tools.contains_key("cypher"));
+
+        assert_eq!(tool.parameters()["required"], json!(["query"]));
+        let result = tool
+            .execute(json!({"query": "MATCH (n:Person) WHERE n.age > 26 RETURN n.name"}))
+            .await
+            .unwrap();
+        assert_eq!(result["headers"], json!(["n.name"]));
+        assert_eq!(result["records"], json!([["Alice"]]));
+    }
+
+    #[tokio::test]
+    async fn test_cypher_query_tool_rejects_writes() {
+        let store = people_store();
+        let tool = CypherQueryTool::new(Arc::new(QueryEngine::new()), store.clone());
+        assert!(tool.execute(json!({"query": "MATCH (n:Person) DELETE n"})).await.is_err());
+        assert!(tool.execute(json!({"query": "CREATE (n:Person {name: 'Eve'})"})).await.is_err());
+        assert!(tool.execute(json!({})).await.is_err());
+        assert_eq!(store.read().await.node_count(), 2);
+    }
+
     #[tokio::test]
     async fn test_web_search_tool_missing_query() {
         let tool = WebSearchTool::new("test-key".to_string());
@@ -10,24 +10,30 @@ use std::collections::HashMap;
 
 // Re-export algorithms
 pub use samyama_graph_algorithms::{
-    page_rank, PageRankConfig,
+    Directedness,
+    page_rank, page_rank_with_stats, PageRankConfig, PageRankResult,
     weakly_connected_components, WccResult,
     strongly_connected_components, SccResult,
-    bfs, dijkstra, bfs_all_shortest_paths, PathResult,
+    louvain, LouvainResult,
+    bfs, dijkstra, dijkstra_path, astar, bfs_all_shortest_paths, PathResult,
     edmonds_karp, FlowResult,
     prim_mst, MSTResult,
-    count_triangles,
+    count_triangles, betweenness_centrality,
     cdlp, CdlpResult, CdlpConfig,
     local_clustering_coefficient, local_clustering_coefficient_directed, LccResult,
     pca, PcaConfig, PcaResult, PcaSolver,
 };
 
 /// Build a GraphView from the store for algorithm execution
+///
+/// With `Directedness::Undirected` the view is symmetrized, so every edge can
+/// be traversed both ways by the algorithms run on it.
 pub fn build_view(
     store: &GraphStore,
     node_label: Option<&str>,
     edge_type: Option<&str>,
     weight_property: Option<&str>,
+    directedness: Directedness,
 ) -> GraphView {
     // 1. Collect relevant nodes
     let nodes: Vec<AlgoNodeId> = if let Some(label_str) = node_label {
@@ -124,7 +130,7 @@ pub fn build_view(
         in_offsets.push(in_sources.len());
     }
 
-    GraphView {
+    let view = GraphView {
         node_count,
         index_to_node,
         node_to_index,
@@ -133,5 +139,9 @@ pub fn build_view(
         in_offsets,
         in_sources,
         weights,
+    };
+    match directedness {
+        Directedness::Directed => view,
+        Directedness::Undirected => view.to_undirected(),
     }
 }
\ No newline at end of file
@@ -230,6 +230,7 @@ mod tests {
             chunk_size: 512,
             chunk_overlap: 64,
             vector_dimension: 64,
+            metric: crate::vector::DistanceMetric::Cosine,
             embedding_policies: HashMap::new(),
         }
     }
@@ -265,6 +266,7 @@ mod tests {
             chunk_size: 512,
             chunk_overlap: 64,
             vector_dimension: 1536,
+            metric: crate::vector::DistanceMetric::Cosine,
             embedding_policies: HashMap::new(),
         };
         let client = EmbeddingClient::new(&config);
@@ -281,6 +283,7 @@ mod tests {
             chunk_size: 512,
             chunk_overlap: 64,
             vector_dimension: 768,
+            metric: crate::vector::DistanceMetric::Cosine,
             embedding_policies: HashMap::new(),
         };
         let client = EmbeddingClient::new(&config);
@@ -307,6 +310,7 @@ mod tests {
             chunk_size: 512,
             chunk_overlap: 64,
             vector_dimension: 768,
+            metric: crate::vector::DistanceMetric::Cosine,
             embedding_policies: HashMap::new(),
         };
         let client = EmbeddingClient::new(&config);
@@ -323,6 +327,7 @@ mod tests {
             chunk_size: 512,
             chunk_overlap: 64,
             vector_dimension: 768,
+            metric: crate::vector::DistanceMetric::Cosine,
             embedding_policies: HashMap::new(),
         };
         let client = EmbeddingClient::new(&config);
@@ -339,6 +344,7 @@ mod tests {
             chunk_size: 512,
             chunk_overlap: 64,
             vector_dimension: 1536,
+            metric: crate::vector::DistanceMetric::Cosine,
             embedding_policies: HashMap::new(),
         };
         let client = EmbeddingClient::new(&config);
@@ -356,6 +362,7 @@ mod tests {
             chunk_size: 512,
             chunk_overlap: 64,
             vector_dimension: 1536,
+            metric: crate::vector::DistanceMetric::Cosine,
             embedding_policies: HashMap::new(),
         };
         let client = EmbeddingClient::new(&config);
@@ -372,6 +379,7 @@ mod tests {
             chunk_size: 512,
             chunk_overlap: 64,
             vector_dimension: 768,
+            metric: crate::vector::DistanceMetric::Cosine,
             embedding_policies: HashMap::new(),
         };
         let client = EmbeddingClient::new(&config);
@@ -420,6 +428,7 @@ mod tests {
             chunk_size: 512,
             chunk_overlap: 64,
             vector_dimension: 768,
+            metric: crate::vector::DistanceMetric::Cosine,
             embedding_policies: HashMap::new(),
         };
         let client = EmbeddingClient::new(&config).unwrap();
@@ -447,6 +456,7 @@ mod tests {
             chunk_size: 512,
             chunk_overlap: 64,
             vector_dimension: 1536,
+            metric: crate::vector::DistanceMetric::Cosine,
             embedding_policies: HashMap::new(),
         };
         let client = EmbeddingClient::new(&config);
@@ -525,6 +535,7 @@ mod tests {
             chunk_size: 512,
             chunk_overlap: 64,
             vector_dimension: 768,
+            metric: crate::vector::DistanceMetric::Cosine,
             embedding_policies: HashMap::new(),
         };
         let client = EmbeddingClient::new(&config).unwrap();
@@ -539,6 +550,7 @@ mod tests {
             chunk_size: 512,
             chunk_overlap: 64,
             vector_dimension: 768,
+            metric: crate::vector::DistanceMetric::Cosine,
             embedding_policies: HashMap::new(),
         };
         let client_ollama = EmbeddingClient::new(&config_ollama).unwrap();
@@ -553,6 +565,7 @@ mod tests {
             chunk_size: 512,
             chunk_overlap: 64,
             vector_dimension: 768,
+            metric: crate::vector::DistanceMetric::Cosine,
             embedding_policies: HashMap::new(),
         };
         let client_gemini = EmbeddingClient::new(&config_gemini).unwrap();
@@ -567,6 +580,7 @@ mod tests {
             chunk_size: 512,
             chunk_overlap: 64,
             vector_dimension: 768,
+            metric: crate::vector::DistanceMetric::Cosine,
             embedding_policies: HashMap::new(),
         };
         let client_anthropic = EmbeddingClient::new(&config_anthropic).unwrap();
@@ -581,6 +595,7 @@ mod tests {
             chunk_size: 512,
             chunk_overlap: 64,
             vector_dimension: 768,
+            metric: crate::vector::DistanceMetric::Cosine,
             embedding_policies: HashMap::new(),
         };
         let client_cc = EmbeddingClient::new(&config_cc).unwrap();
@@ -601,6 +616,7 @@ mod tests {
             chunk_size: 512,
             chunk_overlap: 64,
             vector_dimension: 768,
+            metric: crate::vector::DistanceMetric::Cosine,
             embedding_policies: HashMap::new(),
         };
         let client = EmbeddingClient::new(&config).unwrap();
@@ -617,6 +633,7 @@ mod tests {
             chunk_size: 512,
             chunk_overlap: 64,
             vector_dimension: 768,
+            metric: crate::vector::DistanceMetric::Cosine,
             embedding_policies: HashMap::new(),
         };
         let client = EmbeddingClient::new(&config).unwrap();
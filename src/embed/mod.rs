@@ -31,6 +31,12 @@ pub enum EmbedError {
 
 pub type EmbedResult<T> = Result<T, EmbedError>;
 
+/// Name of the `Vector` property that auto-embed writes for the text
+/// property `text_key` (`content` -> `content_embedding`)
+pub fn embedding_property(text_key: &str) -> String {
+    format!("{}_embedding", text_key)
+}
+
 /// A chunk of text with its embedding
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TextChunk {
@@ -79,28 +85,109 @@ impl EmbedPipeline {
         Ok(chunks)
     }
 
-    /// Simple character-based text splitter (place holder for more advanced recursive splitter)
+    /// Split text into chunks of at most `chunk_size` characters
+    ///
+    /// Prefers paragraph breaks, then sentence ends, then spaces, and only
+    /// cuts between arbitrary characters when a single word is too long.
+    /// Consecutive chunks share up to `chunk_overlap` characters of context.
     fn split_text(&self, text: &str) -> Vec<String> {
-        if text.len() <= self.config.chunk_size {
+        let chunk_size = self.config.chunk_size.max(1);
+        if text.chars().count() <= chunk_size {
             return vec![text.to_string()];
         }
 
-        let mut chunks = Vec::new();
-        let mut start = 0;
-        
-        while start < text.len() {
-            let end = std::cmp::min(start + self.config.chunk_size, text.len());
-            chunks.push(text[start..end].to_string());
-            
-            if end == text.len() {
-                break;
+        let overlap = self.config.chunk_overlap.min(chunk_size - 1);
+        let mut pieces = Vec::new();
+        split_recursive(text, chunk_size, 0, &mut pieces);
+        merge_pieces(&pieces, chunk_size, overlap)
+    }
+}
+
+/// Boundaries tried in order by [`split_recursive`]
+#[derive(Clone, Copy)]
+enum Boundary {
+    Paragraph,
+    Sentence,
+    Word,
+    Char,
+}
+
+const BOUNDARIES: [Boundary; 4] = [Boundary::Paragraph, Boundary::Sentence, Boundary::Word, Boundary::Char];
+
+/// Break `text` into pieces of at most `chunk_size` characters, using the
+/// coarsest boundary that works. Pieces keep their trailing separator, so
+/// concatenating them reproduces `text`.
+fn split_recursive<'a>(text: &'a str, chunk_size: usize, level: usize, out: &mut Vec<&'a str>) {
+    if text.chars().count() <= chunk_size {
+        out.push(text);
+        return;
+    }
+    let parts: Vec<&str> = match BOUNDARIES[level] {
+        Boundary::Paragraph => text.split_inclusive("\n\n").collect(),
+        Boundary::Sentence => split_sentences(text),
+        Boundary::Word => text.split_inclusive(char::is_whitespace).collect(),
+        Boundary::Char => {
+            // One piece per char, so a multi-byte char is never sliced and
+            // the overlap still applies at character granularity
+            out.extend(text.char_indices().map(|(i, c)| &text[i..i + c.len_utf8()]));
+            return;
+        }
+    };
+    for part in parts {
+        split_recursive(part, chunk_size, level + 1, out);
+    }
+}
+
+/// Split after sentence-ending punctuation (including CJK full stops) and
+/// any whitespace that follows it
+fn split_sentences(text: &str) -> Vec<&str> {
+    let mut sentences = Vec::new();
+    let mut start = 0;
+    let mut after_terminator = false;
+    for (i, c) in text.char_indices() {
+        if after_terminator && !c.is_whitespace() {
+            sentences.push(&text[start..i]);
+            start = i;
+        }
+        after_terminator = matches!(c, '.' | '!' | '?' | '。' | '！' | '？')
+            || (after_terminator && c.is_whitespace());
+    }
+    sentences.push(&text[start..]);
+    sentences
+}
+
+/// Greedily pack pieces into chunks of at most `chunk_size` characters,
+/// starting each new chunk with trailing pieces of the previous one that fit
+/// within `overlap`
+fn merge_pieces(pieces: &[&str], chunk_size: usize, overlap: usize) -> Vec<String> {
+    let mut chunks = Vec::new();
+    let mut window: std::collections::VecDeque<(&str, usize)> = std::collections::VecDeque::new();
+    let mut total = 0;
+
+    let mut emit = |window: &std::collections::VecDeque<(&str, usize)>| {
+        let chunk: String = window.iter().map(|(piece, _)| *piece).collect();
+        let chunk = chunk.trim();
+        if !chunk.is_empty() {
+            chunks.push(chunk.to_string());
+        }
+    };
+
+    for &piece in pieces {
+        let len = piece.chars().count();
+        if total + len > chunk_size && !window.is_empty() {
+            emit(&window);
+            while total > overlap || (total + len > chunk_size && total > 0) {
+                let (_, front) = window.pop_front().unwrap();
+                total -= front;
             }
-            
-            start += self.config.chunk_size - self.config.chunk_overlap;
         }
-        
-        chunks
+        window.push_back((piece, len));
+        total += len;
+    }
+    if !window.is_empty() {
+        emit(&window);
     }
+    chunks
 }
 
 #[cfg(test)]
@@ -117,6 +204,7 @@ mod tests {
             chunk_size: 100,
             chunk_overlap: 20,
             vector_dimension: 64,
+            metric: crate::vector::DistanceMetric::Cosine,
             embedding_policies: HashMap::new(),
         }
     }
@@ -152,6 +240,71 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_split_text_prefers_paragraphs_and_words() {
+        let mut config = mock_config();
+        config.chunk_size = 40;
+        config.chunk_overlap = 0;
+        let pipeline = EmbedPipeline::new(config).unwrap();
+        let text = "The first paragraph is short.\n\nThe second one is short too.\n\nThird paragraph here.";
+        let chunks = pipeline.split_text(text);
+        assert_eq!(chunks, vec![
+            "The first paragraph is short.",
+            "The second one is short too.",
+            "Third paragraph here.",
+        ]);
+
+        let text = "alpha bravo charlie delta echo foxtrot golf hotel india juliet kilo lima";
+        for chunk in pipeline.split_text(text) {
+            assert!(chunk.chars().count() <= 40);
+            assert!(chunk.split(' ').all(|word| text.split(' ').any(|w| w == word)), "cut mid-word: {chunk}");
+        }
+    }
+
+    #[test]
+    fn test_split_text_multibyte() {
+        let mut config = mock_config();
+        config.chunk_size = 12;
+        config.chunk_overlap = 0;
+        let pipeline = EmbedPipeline::new(config).unwrap();
+        let text = "日本語の文章です。これは二番目の文です。そして三番目の文章があります。";
+        let chunks = pipeline.split_text(text);
+        assert_eq!(chunks[0], "日本語の文章です。");
+        assert_eq!(chunks.concat(), text);
+        for chunk in &chunks {
+            assert!(chunk.chars().count() <= 12);
+        }
+
+        // A single long word falls back to character windows
+        let word = "é🙂ü".repeat(10);
+        let chunks = pipeline.split_text(&word);
+        assert_eq!(chunks.len(), 3);
+        assert_eq!(chunks.concat(), word);
+    }
+
+    #[test]
+    fn test_split_text_overlap() {
+        let mut config = mock_config();
+        config.chunk_size = 20;
+        config.chunk_overlap = 8;
+        let pipeline = EmbedPipeline::new(config).unwrap();
+        let text = "one two three four five six seven eight nine ten eleven twelve";
+        let chunks = pipeline.split_text(text);
+        assert!(chunks.len() > 2);
+        for pair in chunks.windows(2) {
+            let last_word = pair[0].rsplit(' ').next().unwrap();
+            assert!(pair[1].starts_with(last_word), "{:?} does not overlap {:?}", pair[1], pair[0]);
+        }
+        assert!(chunks.last().unwrap().ends_with("twelve"));
+
+        let word = "ä".repeat(30);
+        let chunks = pipeline.split_text(&word);
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(chunks[0].chars().count(), 20);
+        // The second chunk repeats the last 8 chars of the first
+        assert_eq!(chunks[1].chars().count(), 8 + 10);
+    }
+
     #[tokio::test]
     async fn test_process_text_mock() {
         let config = mock_config();
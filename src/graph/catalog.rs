@@ -155,6 +155,51 @@ impl GraphCatalog {
         self.generation += 1;
     }
 
+    /// Notify the catalog that a batch of edges was created
+    ///
+    /// Same result as calling [`on_edge_created`](Self::on_edge_created) per
+    /// edge, but each affected triple's derived stats are recomputed once.
+    pub fn on_edges_created<'a>(
+        &mut self,
+        edges: impl IntoIterator<Item = (NodeId, &'a [Label], &'a EdgeType, NodeId, &'a [Label])>,
+    ) {
+        // Pattern → highest source degree reached in this batch
+        let mut touched: HashMap<TriplePattern, usize> = HashMap::new();
+        for (source_id, src_labels, edge_type, target_id, tgt_labels) in edges {
+            for src_label in src_labels {
+                for tgt_label in tgt_labels {
+                    let pattern = TriplePattern::new(src_label.clone(), edge_type.clone(), tgt_label.clone());
+                    let src_degree = self.source_degrees
+                        .entry(pattern.clone())
+                        .or_default()
+                        .entry(source_id)
+                        .or_insert(0);
+                    *src_degree += 1;
+                    let new_src_degree = *src_degree;
+                    *self.target_degrees
+                        .entry(pattern.clone())
+                        .or_default()
+                        .entry(target_id)
+                        .or_insert(0) += 1;
+                    self.triple_stats.entry(pattern.clone()).or_insert_with(TripleStats::new).count += 1;
+
+                    let max = touched.entry(pattern).or_insert(0);
+                    *max = (*max).max(new_src_degree);
+                }
+            }
+        }
+
+        for (pattern, max_src_degree) in touched {
+            let stats = self.triple_stats.get_mut(&pattern).unwrap();
+            stats.distinct_sources = self.source_degrees[&pattern].len();
+            stats.distinct_targets = self.target_degrees[&pattern].len();
+            stats.avg_out_degree = stats.count as f64 / stats.distinct_sources as f64;
+            stats.avg_in_degree = stats.count as f64 / stats.distinct_targets as f64;
+            stats.max_out_degree = stats.max_out_degree.max(max_src_degree);
+        }
+        self.generation += 1;
+    }
+
     /// Notify the catalog that an edge was deleted
     pub fn on_edge_deleted(
         &mut self,
@@ -536,6 +581,44 @@ mod tests {
         assert_eq!(stats.distinct_sources, 2); // p1 and p2
     }
 
+    #[test]
+    fn test_batch_edge_created_matches_incremental() {
+        let person = [Label::new("Person")];
+        let employee = [Label::new("Person"), Label::new("Employee")];
+        let knows = EdgeType::new("KNOWS");
+        let (p1, p2, p3) = (NodeId::new(1), NodeId::new(2), NodeId::new(3));
+        let edges: Vec<(NodeId, &[Label], &EdgeType, NodeId, &[Label])> = vec![
+            (p1, &person, &knows, p2, &employee),
+            (p1, &person, &knows, p3, &person),
+            (p2, &employee, &knows, p3, &person),
+            (p1, &person, &knows, p2, &employee),
+        ];
+
+        let mut incremental = GraphCatalog::new();
+        incremental.on_edge_created(p3, &person, &knows, p1, &person);
+        let mut batch = incremental.clone();
+        for &(s, sl, ty, t, tl) in &edges {
+            incremental.on_edge_created(s, sl, ty, t, tl);
+        }
+        batch.on_edges_created(edges.iter().copied());
+
+        for pattern in [
+            TriplePattern::new("Person", "KNOWS", "Person"),
+            TriplePattern::new("Person", "KNOWS", "Employee"),
+            TriplePattern::new("Employee", "KNOWS", "Person"),
+        ] {
+            let (a, b) = (incremental.get_triple_stats(&pattern).unwrap(), batch.get_triple_stats(&pattern).unwrap());
+            assert_eq!(
+                (a.count, a.distinct_sources, a.distinct_targets, a.max_out_degree),
+                (b.count, b.distinct_sources, b.distinct_targets, b.max_out_degree)
+            );
+            assert_eq!(a.avg_out_degree, b.avg_out_degree);
+            assert_eq!(a.avg_in_degree, b.avg_in_degree);
+        }
+        // p1 has three outgoing KNOWS edges (two to p2, one to p3)
+        assert_eq!(batch.get_triple_stats(&TriplePattern::new("Person", "KNOWS", "Person")).unwrap().max_out_degree, 3);
+    }
+
     #[test]
     fn test_estimate_expand_out() {
         let mut catalog = GraphCatalog::new();
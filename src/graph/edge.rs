@@ -37,7 +37,7 @@
 //! - REQ-GRAPH-007: Directed edges
 //! - REQ-GRAPH-008: Multiple edges between same nodes
 
-use super::property::{PropertyMap, PropertyValue};
+use super::property::{FromProperty, PropertyMap, PropertyValue};
 use super::types::{EdgeId, EdgeType, NodeId};
 use serde::{Deserialize, Serialize};
 
@@ -136,6 +136,12 @@ impl Edge {
         self.properties.get(key)
     }
 
+    /// Get a property as `T`; `None` when it is missing or does not convert
+    /// (see [`FromProperty`] for the coercion rules)
+    pub fn get_property_as<T: FromProperty>(&self, key: &str) -> Option<T> {
+        self.get_property(key).and_then(|value| value.coerce())
+    }
+
     /// Remove a property
     pub fn remove_property(&mut self, key: &str) -> Option<PropertyValue> {
         self.properties.remove(key)
@@ -1,8 +1,12 @@
 //! Graph events for async processing
 //!
 //! Captures changes to the graph for indexing, replication, etc.
+//!
+//! [`IndexEvent`] feeds the internal indexer; [`ChangeEvent`] is the public
+//! change-data-capture feed returned by
+//! [`GraphStore::subscribe_changes`](super::GraphStore::subscribe_changes).
 
-use super::types::{Label, NodeId};
+use super::types::{EdgeId, EdgeType, Label, NodeId};
 use super::property::{PropertyMap, PropertyValue};
 
 #[derive(Debug, Clone)]
@@ -33,4 +37,60 @@ pub enum IndexEvent {
         label: Label,
         properties: PropertyMap,
     },
+    LabelRemoved {
+        tenant_id: String,
+        id: NodeId,
+        label: Label,
+        properties: PropertyMap,
+    },
+}
+
+/// A committed change to the graph, as seen by change-feed subscribers
+///
+/// Property removals are reported as updates whose `new_value` is `Null`.
+#[derive(Debug, Clone)]
+pub enum ChangeEvent {
+    NodeCreated {
+        tenant_id: String,
+        id: NodeId,
+        labels: Vec<Label>,
+        properties: PropertyMap,
+    },
+    NodeUpdated {
+        tenant_id: String,
+        id: NodeId,
+        key: String,
+        old_value: Option<PropertyValue>,
+        new_value: PropertyValue,
+    },
+    NodeLabelAdded {
+        tenant_id: String,
+        id: NodeId,
+        label: Label,
+    },
+    NodeDeleted {
+        tenant_id: String,
+        id: NodeId,
+        labels: Vec<Label>,
+        properties: PropertyMap,
+    },
+    EdgeCreated {
+        id: EdgeId,
+        source: NodeId,
+        target: NodeId,
+        edge_type: EdgeType,
+        properties: PropertyMap,
+    },
+    EdgeUpdated {
+        id: EdgeId,
+        key: String,
+        old_value: Option<PropertyValue>,
+        new_value: PropertyValue,
+    },
+    EdgeDeleted {
+        id: EdgeId,
+        source: NodeId,
+        target: NodeId,
+        edge_type: EdgeType,
+    },
 }
@@ -64,9 +64,9 @@ pub mod storage;
 // Re-export main types
 pub use edge::{Edge, EdgeView};
 pub use node::Node;
-pub use property::{PropertyMap, PropertyValue};
-pub use store::{GraphError, GraphResult, GraphStore, GraphStatistics, PropertyStats, IsolationLevel, TxnId, TxnStatus, Transaction};
+pub use property::{FromProperty, PropertyMap, PropertyValue};
+pub use store::{EdgeDirection, GraphError, GraphResult, GraphStore, GraphStatistics, PropertyStats, StoreStats, NameCount, IndexedProperty, SchemaInfo, LabelSchema, EdgeTypeSchema, Inconsistency, IsolationLevel, TxnId, TxnStatus, Transaction, WriteCounts, WriteSummary};
 pub use types::{EdgeId, EdgeType, Label, NodeId};
 pub use catalog::GraphCatalog;
-pub use event::IndexEvent;
+pub use event::{ChangeEvent, IndexEvent};
 pub use storage::{Column, ColumnStore};
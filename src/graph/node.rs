@@ -37,7 +37,7 @@
 //! - REQ-GRAPH-004: Properties on nodes
 //! - REQ-GRAPH-006: Multiple labels per node
 
-use super::property::{PropertyMap, PropertyValue};
+use super::property::{FromProperty, PropertyMap, PropertyValue};
 use super::types::{Label, NodeId};
 use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
@@ -174,6 +174,12 @@ impl Node {
         self.properties.get(key)
     }
 
+    /// Get a property as `T`; `None` when it is missing or does not convert
+    /// (see [`FromProperty`] for the coercion rules)
+    pub fn get_property_as<T: FromProperty>(&self, key: &str) -> Option<T> {
+        self.get_property(key).and_then(|value| value.coerce())
+    }
+
     /// Remove a property
     pub fn remove_property(&mut self, key: &str) -> Option<PropertyValue> {
         let removed = self.properties.remove(key);
@@ -319,6 +325,11 @@ mod tests {
         assert_eq!(node.get_property("name").unwrap().as_string(), Some("Bob"));
         assert_eq!(node.get_property("age").unwrap().as_integer(), Some(25));
         assert_eq!(node.get_property("score").unwrap().as_float(), Some(95.5));
+
+        assert_eq!(node.get_property_as::<String>("name"), Some("Bob".to_string()));
+        assert_eq!(node.get_property_as::<f64>("age"), Some(25.0));
+        assert_eq!(node.get_property_as::<i64>("score"), None);
+        assert_eq!(node.get_property_as::<i64>("missing"), None);
     }
 
     #[test]
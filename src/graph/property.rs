@@ -303,6 +303,34 @@ impl PropertyValue {
         }
     }
 
+    /// Parse a date or timestamp string into a `DateTime` value
+    ///
+    /// Accepts RFC 3339 (`2025-03-01T09:30:00+05:30`), a naive timestamp
+    /// (`2025-03-01T09:30:00`, taken as UTC) or a plain date (`2025-03-01`,
+    /// midnight UTC).
+    pub fn parse_datetime(s: &str) -> Option<PropertyValue> {
+        let s = s.trim();
+        let millis = if let Ok(dt) = chrono::DateTime::parse_from_rfc3339(s) {
+            dt.timestamp_millis()
+        } else if let Ok(dt) = chrono::NaiveDateTime::parse_from_str(s, "%Y-%m-%dT%H:%M:%S") {
+            dt.and_utc().timestamp_millis()
+        } else {
+            chrono::NaiveDate::parse_from_str(s, "%Y-%m-%d")
+                .ok()?
+                .and_hms_opt(0, 0, 0)?
+                .and_utc()
+                .timestamp_millis()
+        };
+        Some(PropertyValue::DateTime(millis))
+    }
+
+    /// Format a `DateTime` value as an RFC 3339 string in UTC
+    pub fn to_rfc3339(&self) -> Option<String> {
+        let millis = self.as_datetime()?;
+        chrono::DateTime::from_timestamp_millis(millis)
+            .map(|dt| dt.to_rfc3339_opts(chrono::SecondsFormat::AutoSi, true))
+    }
+
     /// Get array value if this is an array
     pub fn as_array(&self) -> Option<&Vec<PropertyValue>> {
         match self {
@@ -327,6 +355,26 @@ impl PropertyValue {
         }
     }
 
+    /// Integer value; a Float is never truncated
+    pub fn as_i64(&self) -> Option<i64> {
+        self.coerce()
+    }
+
+    /// Numeric value as a float; an Integer widens to `f64` if exactly representable
+    pub fn as_f64(&self) -> Option<f64> {
+        self.coerce()
+    }
+
+    /// Boolean value; other types are not read as truthy or falsy
+    pub fn as_bool(&self) -> Option<bool> {
+        self.coerce()
+    }
+
+    /// Read the value as `T`, following the coercion rules of [`FromProperty`]
+    pub fn coerce<T: FromProperty>(&self) -> Option<T> {
+        T::from_property(self)
+    }
+
     /// Get type name as string
     pub fn type_name(&self) -> &'static str {
         match self {
@@ -374,6 +422,28 @@ impl PropertyValue {
             PropertyValue::Null => serde_json::Value::Null,
         }
     }
+
+    /// Convert a plain JSON value (e.g. a query parameter) into a property value.
+    ///
+    /// Whole numbers become `Integer`, other numbers `Float`, arrays `Array`
+    /// and objects `Map`.
+    pub fn from_json(value: &serde_json::Value) -> PropertyValue {
+        match value {
+            serde_json::Value::Null => PropertyValue::Null,
+            serde_json::Value::Bool(b) => PropertyValue::Boolean(*b),
+            serde_json::Value::Number(n) => match n.as_i64() {
+                Some(i) => PropertyValue::Integer(i),
+                None => n.as_f64().map(PropertyValue::Float).unwrap_or(PropertyValue::Null),
+            },
+            serde_json::Value::String(s) => PropertyValue::String(s.clone()),
+            serde_json::Value::Array(arr) => {
+                PropertyValue::Array(arr.iter().map(PropertyValue::from_json).collect())
+            }
+            serde_json::Value::Object(obj) => PropertyValue::Map(
+                obj.iter().map(|(k, v)| (k.clone(), PropertyValue::from_json(v))).collect(),
+            ),
+        }
+    }
 }
 
 impl fmt::Display for PropertyValue {
@@ -482,6 +552,42 @@ impl From<Vec<PropertyValue>> for PropertyValue {
     }
 }
 
+impl From<chrono::DateTime<chrono::Utc>> for PropertyValue {
+    fn from(dt: chrono::DateTime<chrono::Utc>) -> Self {
+        PropertyValue::DateTime(dt.timestamp_millis())
+    }
+}
+
+impl From<Vec<String>> for PropertyValue {
+    fn from(items: Vec<String>) -> Self {
+        PropertyValue::Array(items.into_iter().map(PropertyValue::String).collect())
+    }
+}
+
+impl From<Vec<&str>> for PropertyValue {
+    fn from(items: Vec<&str>) -> Self {
+        PropertyValue::Array(items.into_iter().map(PropertyValue::from).collect())
+    }
+}
+
+impl From<Vec<i64>> for PropertyValue {
+    fn from(items: Vec<i64>) -> Self {
+        PropertyValue::Array(items.into_iter().map(PropertyValue::Integer).collect())
+    }
+}
+
+impl From<Vec<f64>> for PropertyValue {
+    fn from(items: Vec<f64>) -> Self {
+        PropertyValue::Array(items.into_iter().map(PropertyValue::Float).collect())
+    }
+}
+
+impl From<Vec<bool>> for PropertyValue {
+    fn from(items: Vec<bool>) -> Self {
+        PropertyValue::Array(items.into_iter().map(PropertyValue::Boolean).collect())
+    }
+}
+
 impl From<HashMap<String, PropertyValue>> for PropertyValue {
     fn from(map: HashMap<String, PropertyValue>) -> Self {
         PropertyValue::Map(map)
@@ -497,10 +603,94 @@ impl From<Vec<f32>> for PropertyValue {
 /// Property map for storing node and edge properties
 pub type PropertyMap = HashMap<String, PropertyValue>;
 
+/// Rust types a [`PropertyValue`] can be read as
+///
+/// Conversions are lossless and never parse:
+/// - each type reads its own variant (`i64` from Integer, `String` from
+///   String, `Vec<f32>` from Vector, ...)
+/// - an Integer widens to `f64` when the float holds it exactly (always
+///   within ±2^53), and to `i32` when it fits
+/// - everything else is `None`: a Float is not truncated to an integer, a
+///   String is not parsed as a number, and Null reads as nothing
+pub trait FromProperty: Sized {
+    fn from_property(value: &PropertyValue) -> Option<Self>;
+}
+
+impl FromProperty for i64 {
+    fn from_property(value: &PropertyValue) -> Option<Self> {
+        value.as_integer()
+    }
+}
+
+impl FromProperty for i32 {
+    fn from_property(value: &PropertyValue) -> Option<Self> {
+        value.as_integer().and_then(|i| i32::try_from(i).ok())
+    }
+}
+
+impl FromProperty for f64 {
+    fn from_property(value: &PropertyValue) -> Option<Self> {
+        match value {
+            PropertyValue::Float(f) => Some(*f),
+            // i128 comparison: `as i64` would saturate and hide the rounding of i64::MAX
+            PropertyValue::Integer(i) => Some(*i as f64).filter(|f| *f as i128 == *i as i128),
+            _ => None,
+        }
+    }
+}
+
+impl FromProperty for bool {
+    fn from_property(value: &PropertyValue) -> Option<Self> {
+        value.as_boolean()
+    }
+}
+
+impl FromProperty for String {
+    fn from_property(value: &PropertyValue) -> Option<Self> {
+        value.as_string().map(str::to_string)
+    }
+}
+
+impl FromProperty for Vec<f32> {
+    fn from_property(value: &PropertyValue) -> Option<Self> {
+        value.as_vector().cloned()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_typed_getters() {
+        let int = PropertyValue::Integer(42);
+        assert_eq!(int.as_i64(), Some(42));
+        // Integer widens to float, and narrows to i32 only when it fits
+        assert_eq!(int.as_f64(), Some(42.0));
+        assert_eq!(int.coerce::<i32>(), Some(42));
+        assert_eq!(PropertyValue::Integer(i64::MAX).coerce::<i32>(), None);
+        // ...and widens to f64 only when no precision is lost
+        assert_eq!(PropertyValue::Integer(1 << 53).as_f64(), Some(9007199254740992.0));
+        assert_eq!(PropertyValue::Integer((1 << 53) + 1).as_f64(), None);
+        assert_eq!(PropertyValue::Integer(1 << 60).as_f64(), Some((1u64 << 60) as f64));
+        assert_eq!(PropertyValue::Integer(i64::MAX).as_f64(), None);
+        assert_eq!(PropertyValue::Integer(i64::MIN).as_f64(), Some(-9223372036854775808.0));
+
+        // Floats are not truncated and strings are not parsed
+        assert_eq!(PropertyValue::Float(2.5).as_i64(), None);
+        assert_eq!(PropertyValue::Float(2.5).as_f64(), Some(2.5));
+        assert_eq!(PropertyValue::String("42".to_string()).as_i64(), None);
+        assert_eq!(PropertyValue::String("42".to_string()).as_f64(), None);
+        assert_eq!(PropertyValue::String("true".to_string()).as_bool(), None);
+        assert_eq!(PropertyValue::Integer(1).as_bool(), None);
+        assert_eq!(int.coerce::<String>(), None);
+
+        assert_eq!(PropertyValue::Boolean(true).as_bool(), Some(true));
+        assert_eq!(PropertyValue::String("Alice".to_string()).coerce::<String>(), Some("Alice".to_string()));
+        assert_eq!(PropertyValue::Vector(vec![0.5, 1.0]).coerce::<Vec<f32>>(), Some(vec![0.5, 1.0]));
+        assert_eq!(PropertyValue::Null.as_f64(), None);
+    }
+
     #[test]
     fn test_property_value_types() {
         // Test all property types (REQ-GRAPH-005)
@@ -537,6 +727,27 @@ mod tests {
 
         let vector_prop: PropertyValue = vec![1.0f32, 2.0f32].into();
         assert_eq!(vector_prop.as_vector(), Some(&vec![1.0f32, 2.0f32]));
+
+        let tags: PropertyValue = vec!["a", "b"].into();
+        assert_eq!(tags, PropertyValue::Array(vec!["a".into(), "b".into()]));
+        assert_eq!(PropertyValue::from_json(&tags.to_json()), tags);
+
+        let scores: PropertyValue = vec![0.5f64, 1.5].into();
+        assert_eq!(scores, PropertyValue::Array(vec![0.5.into(), 1.5.into()]));
+    }
+
+    #[test]
+    fn test_parse_datetime() {
+        let date = PropertyValue::parse_datetime("2025-03-01").unwrap();
+        assert_eq!(date.to_rfc3339().as_deref(), Some("2025-03-01T00:00:00Z"));
+        let naive = PropertyValue::parse_datetime("2025-03-01T09:30:00").unwrap();
+        let offset = PropertyValue::parse_datetime("2025-03-01T15:00:00+05:30").unwrap();
+        assert_eq!(naive, offset);
+        assert!(date < naive);
+        assert_eq!(PropertyValue::parse_datetime("March 1st"), None);
+
+        let now = chrono::Utc::now();
+        assert_eq!(PropertyValue::from(now).as_datetime(), Some(now.timestamp_millis()));
     }
 
     #[test]
@@ -1058,6 +1269,21 @@ mod tests {
         assert_eq!(json["nanos"], serde_json::json!(4));
     }
 
+    #[test]
+    fn test_from_json_round_trip() {
+        let json = serde_json::json!({"age": 30, "score": 1.5, "tags": ["a", true], "nick": null});
+        let value = PropertyValue::from_json(&json);
+        let map = value.as_map().unwrap();
+        assert_eq!(map["age"], PropertyValue::Integer(30));
+        assert_eq!(map["score"], PropertyValue::Float(1.5));
+        assert_eq!(map["tags"], PropertyValue::Array(vec![
+            PropertyValue::String("a".to_string()),
+            PropertyValue::Boolean(true),
+        ]));
+        assert_eq!(map["nick"], PropertyValue::Null);
+        assert_eq!(value.to_json(), json);
+    }
+
     // ========== is_null tests ==========
 
     #[test]
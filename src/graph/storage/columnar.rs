@@ -25,17 +25,29 @@ impl Column {
     pub fn new_bool() -> Self { Column::Bool(HashMap::new()) }
 
     pub fn set(&mut self, idx: usize, value: PropertyValue) {
-        match (self, value) {
+        match (&mut *self, value) {
             (Column::Int(m), PropertyValue::Integer(val)) => { m.insert(idx, val); }
             (Column::Float(m), PropertyValue::Float(val)) => { m.insert(idx, val); }
             (Column::String(m), PropertyValue::String(val)) => { m.insert(idx, val); }
             (Column::Bool(m), PropertyValue::Boolean(val)) => { m.insert(idx, val); }
             _ => {
-                // Type mismatch or unsupported columnar type (Map/Array/Vector)
+                // Null, type mismatch or unsupported columnar type (Map/Array/Vector):
+                // drop the stale entry so reads fall through to the row value.
+                self.remove(idx);
             }
         }
     }
 
+    /// Remove the value at the given index, if any.
+    pub fn remove(&mut self, idx: usize) {
+        match self {
+            Column::Int(m) => { m.remove(&idx); }
+            Column::Float(m) => { m.remove(&idx); }
+            Column::String(m) => { m.remove(&idx); }
+            Column::Bool(m) => { m.remove(&idx); }
+        }
+    }
+
     pub fn get(&self, idx: usize) -> PropertyValue {
         match self {
             Column::Int(m) => m.get(&idx).map(|&v| PropertyValue::Integer(v)).unwrap_or(PropertyValue::Null),
@@ -95,6 +107,13 @@ impl ColumnStore {
         }
     }
 
+    /// Clear a property for the given index (REMOVE).
+    pub fn remove_property(&mut self, idx: usize, key: &str) {
+        if let Some(col) = self.columns.get_mut(key) {
+            col.remove(idx);
+        }
+    }
+
     pub fn get_property(&self, idx: usize, key: &str) -> PropertyValue {
         self.columns.get(key).map(|col| col.get(idx)).unwrap_or(PropertyValue::Null)
     }
@@ -171,4 +190,21 @@ mod tests {
         let keys99 = store.get_property_keys(99);
         assert!(keys99.is_empty());
     }
+
+    #[test]
+    fn test_null_or_retyped_value_clears_column_entry() {
+        let mut store = ColumnStore::new();
+        store.set_property(1, "x", PropertyValue::Integer(1));
+        store.set_property(2, "x", PropertyValue::Integer(2));
+        store.set_property(3, "x", PropertyValue::Integer(3));
+
+        store.set_property(1, "x", PropertyValue::Null);
+        store.set_property(2, "x", PropertyValue::String("two".to_string()));
+        store.remove_property(3, "x");
+
+        for idx in 1..=3 {
+            assert_eq!(store.get_property(idx, "x"), PropertyValue::Null);
+            assert!(store.get_property_keys(idx).is_empty());
+        }
+    }
 }
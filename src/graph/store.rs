@@ -82,12 +82,15 @@ use super::edge::{Edge, EdgeView};
 use super::node::Node;
 use super::property::{PropertyMap, PropertyValue};
 use super::types::{EdgeId, EdgeType, Label, NodeId};
-use crate::vector::{VectorIndexManager, DistanceMetric, VectorResult};
-use crate::index::IndexManager;
+use crate::vector::{VectorIndexManager, DistanceMetric, HnswConfig, VectorFilter, VectorResult};
+use crate::index::{ConstraintKind, IndexManager};
 use crate::graph::storage::ColumnStore;
 use tokio::sync::mpsc::{UnboundedSender, unbounded_channel};
-use std::collections::{HashMap, HashSet};
+use tokio::sync::broadcast;
+use super::event::ChangeEvent;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 use thiserror::Error;
 use crate::agent::{AgentRuntime, tools::WebSearchTool};
@@ -141,10 +144,31 @@ pub enum GraphError {
 
     #[error("Write conflict: {0}")]
     WriteConflict(String),
+
+    #[error("Constraint violation: {0}")]
+    ConstraintViolation(String),
+
+    #[error("A write batch is already open")]
+    WriteBatchActive,
+
+    #[error("No write batch is open")]
+    NoWriteBatch,
 }
 
 pub type GraphResult<T> = Result<T, GraphError>;
 
+/// `:Label(property) IS UNIQUE`, as used in constraint violation messages
+fn constraint_name(label: &Label, property: &str, kind: ConstraintKind) -> String {
+    format!(":{}({}) IS {}", label.as_str(), property, kind.as_str())
+}
+
+fn unique_violation(label: &Label, property: &str, value: &PropertyValue, holder: NodeId) -> GraphError {
+    GraphError::ConstraintViolation(format!(
+        "{} but node {} already has value {}",
+        constraint_name(label, property, ConstraintKind::Unique), holder, value
+    ))
+}
+
 /// Statistics about graph contents for **cost-based query optimization**.
 ///
 /// # What is cardinality estimation?
@@ -201,6 +225,83 @@ pub struct PropertyStats {
     pub selectivity: f64,
 }
 
+/// Per-label and per-type breakdown of a store, reported by `GRAPH.STATS`
+/// and `GET /api/stats`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StoreStats {
+    /// Total number of nodes
+    pub nodes: u64,
+    /// Total number of edges
+    pub edges: u64,
+    /// Node count per label, largest first
+    pub labels: Vec<NameCount>,
+    /// Edge count per type, largest first
+    pub edge_types: Vec<NameCount>,
+    /// Property indexes
+    pub indexes: Vec<IndexedProperty>,
+    /// Vector (HNSW) indexes
+    pub vector_indexes: Vec<IndexedProperty>,
+}
+
+/// A label or edge type with its cardinality
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NameCount {
+    pub name: String,
+    pub count: u64,
+}
+
+/// The (label, property) pair an index is defined on
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IndexedProperty {
+    pub label: String,
+    pub property: String,
+}
+
+/// Labels with their properties and the edge types between them, reported
+/// by `GET /api/schema`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SchemaInfo {
+    /// Node labels, by name
+    #[serde(rename = "node_types")]
+    pub labels: Vec<LabelSchema>,
+    /// Edge types, by name
+    pub edge_types: Vec<EdgeTypeSchema>,
+}
+
+/// A label, its node count and the types of its properties
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LabelSchema {
+    pub label: String,
+    pub count: u64,
+    /// Property name to inferred type (see [`PropertyValue::type_name`]),
+    /// or `"Mixed"` when sampled nodes disagree
+    pub properties: BTreeMap<String, String>,
+}
+
+/// An edge type, its edge count and the labels found at either end
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EdgeTypeSchema {
+    #[serde(rename = "type")]
+    pub edge_type: String,
+    pub count: u64,
+    pub source_labels: Vec<String>,
+    pub target_labels: Vec<String>,
+}
+
+/// Nodes sampled per label when inferring property types for [`SchemaInfo`]
+const SCHEMA_SAMPLE_SIZE: usize = 100;
+
+/// Which edges of a node [`GraphStore::neighbors`] follows
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EdgeDirection {
+    /// Edges leaving the node
+    Outgoing,
+    /// Edges arriving at the node
+    Incoming,
+    /// Both, ignoring edge direction
+    Both,
+}
+
 impl GraphStatistics {
     /// Estimate the number of rows from a label scan
     pub fn estimate_label_scan(&self, label: &Label) -> usize {
@@ -260,6 +361,7 @@ impl GraphStatistics {
 /// | `incoming` | `Vec<Vec<(NodeId, EdgeId)>>` | Sorted adjacency list per node (incoming) | O(log d) binary search |
 /// | `label_index` | `HashMap<Label, HashSet<NodeId>>` | Secondary index: label -> node set | O(1) lookup, O(n) scan |
 /// | `edge_type_index` | `HashMap<EdgeType, HashSet<EdgeId>>` | Secondary index: type -> edge set | O(1) lookup, O(n) scan |
+/// | `outgoing_by_type` | `HashMap<(NodeId, u16), Vec<EdgeId>>` | Typed adjacency: (source, type) -> edges | O(1) lookup, O(k) scan |
 /// | `node_columns` | `ColumnStore` | Columnar property storage for late materialization | O(1) per cell |
 /// | `catalog` | `GraphCatalog` | Triple-level statistics for graph-native planning (ADR-015) | O(1) |
 ///
@@ -287,6 +389,21 @@ pub struct AdjacencyStats {
     pub bytes_saved_estimate: usize,
 }
 
+/// A structural inconsistency reported by [`GraphStore::validate`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Inconsistency {
+    /// An edge whose source node no longer exists
+    MissingSource { edge: EdgeId, source: NodeId },
+    /// An edge whose target node no longer exists
+    MissingTarget { edge: EdgeId, target: NodeId },
+    /// An outgoing adjacency entry of `node` naming an edge that is absent
+    /// or does not start at `node`
+    DanglingOutgoing { node: NodeId, edge: EdgeId },
+    /// An incoming adjacency entry of `node` naming an edge that is absent
+    /// or does not end at `node`
+    DanglingIncoming { node: NodeId, edge: EdgeId },
+}
+
 /// Multi-segment frozen CSR — holds one or more immutable CSR segments.
 /// Each `compact_adjacency()` call appends a new segment (no merge, no memory spike).
 /// `neighbors()` iterates all segments.
@@ -499,6 +616,54 @@ pub struct EdgeVersionEntry {
     pub properties: PropertyMap,
 }
 
+/// Inverse of one write made inside a write batch, replayed by
+/// [`GraphStore::rollback_write_batch`]
+#[derive(Debug)]
+enum UndoEntry {
+    CreateNode { tenant_id: String, id: NodeId },
+    NodeProperty { tenant_id: String, id: NodeId, key: String, old: Option<PropertyValue> },
+    AddLabel { tenant_id: String, id: NodeId, label: Label },
+    DeleteNode { tenant_id: String, node: Node },
+    CreateEdge(EdgeId),
+    EdgeProperty { id: EdgeId, key: String, old: Option<PropertyValue> },
+    DeleteEdge(Edge),
+}
+
+/// Writes of an open write batch: how to undo them, and the change-feed
+/// events held back until commit
+#[derive(Debug, Default)]
+struct WriteBatch {
+    undo: Vec<UndoEntry>,
+    changes: Vec<ChangeEvent>,
+}
+
+/// What a committed write batch changed, net of writes it undid itself
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct WriteSummary {
+    /// Nodes created by the batch and still present
+    pub nodes_created: Vec<NodeId>,
+    /// Nodes that existed before the batch and had properties or labels changed
+    pub nodes_updated: Vec<NodeId>,
+    /// Nodes that existed before the batch and were deleted by it
+    pub nodes_deleted: Vec<NodeId>,
+    /// Edges created by the batch and still present
+    pub edges_created: Vec<EdgeId>,
+    /// Edges that existed before the batch and had properties changed
+    pub edges_updated: Vec<EdgeId>,
+    /// Edges that existed before the batch and were deleted by it
+    pub edges_deleted: Vec<EdgeId>,
+}
+
+/// Nodes and relationships created and deleted by part of a write batch,
+/// counted per write rather than as a net change in totals
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct WriteCounts {
+    pub nodes_created: usize,
+    pub nodes_deleted: usize,
+    pub relationships_created: usize,
+    pub relationships_deleted: usize,
+}
+
 #[derive(Debug)]
 pub struct GraphStore {
     /// Node storage (Arena with versioning: NodeId -> [Versions])
@@ -535,6 +700,15 @@ pub struct GraphStore {
     /// Frozen incoming adjacency (CSR): bulk-loaded data, immutable, compact
     frozen_incoming: FrozenAdjacencyStore,
 
+    /// Per-node count of deleted edges still listed in `frozen_outgoing`
+    frozen_deleted_outgoing: HashMap<NodeId, usize>,
+
+    /// Per-node count of deleted edges still listed in `frozen_incoming`
+    frozen_deleted_incoming: HashMap<NodeId, usize>,
+
+    /// Per-node count of live self-loops, so `degree` can count each once
+    self_loops: HashMap<NodeId, usize>,
+
     /// Current global version for MVCC (monotonically increasing)
     pub current_version: u64,
 
@@ -561,6 +735,15 @@ pub struct GraphStore {
     /// Edge type index for fast lookups
     edge_type_index: HashMap<EdgeType, HashSet<EdgeId>>,
 
+    /// Typed adjacency: (source, interned edge type) → outgoing edges of that
+    /// type. Covers both the frozen tier and the write buffer, so typed
+    /// traversal from a dense node never touches edges of other types.
+    outgoing_by_type: HashMap<(NodeId, u16), Vec<EdgeId>>,
+
+    /// Live edge count per interned edge type, indexed like `edge_type_table`.
+    /// Unlike `edge_type_index` this also counts stub edges.
+    edge_type_counts: Vec<usize>,
+
     /// Vector indices manager
     pub vector_index: Arc<VectorIndexManager>,
 
@@ -576,6 +759,12 @@ pub struct GraphStore {
     /// Async index event sender
     pub index_sender: Option<UnboundedSender<crate::graph::event::IndexEvent>>,
 
+    /// Public change feed; see [`subscribe_changes`](Self::subscribe_changes)
+    change_feed: broadcast::Sender<ChangeEvent>,
+
+    /// Open write batch, if any; see [`begin_write_batch`](Self::begin_write_batch)
+    write_batch: Option<WriteBatch>,
+
     /// Next node ID
     next_node_id: u64,
 
@@ -606,6 +795,9 @@ impl GraphStore {
             incoming: Vec::with_capacity(1024),
             frozen_outgoing: FrozenAdjacencyStore::new(),
             frozen_incoming: FrozenAdjacencyStore::new(),
+            frozen_deleted_outgoing: HashMap::new(),
+            frozen_deleted_incoming: HashMap::new(),
+            self_loops: HashMap::new(),
             current_version: 1,
             next_txn_id: 1,
             active_transactions: HashMap::new(),
@@ -615,11 +807,15 @@ impl GraphStore {
             free_edge_ids: Vec::new(),
             label_index: HashMap::new(),
             edge_type_index: HashMap::new(),
+            outgoing_by_type: HashMap::new(),
+            edge_type_counts: Vec::new(),
             vector_index: Arc::new(VectorIndexManager::new()),
             property_index: Arc::new(IndexManager::new()),
             node_columns: ColumnStore::new(),
             edge_columns: ColumnStore::new(),
             index_sender: None,
+            change_feed: broadcast::channel(Self::CHANGE_FEED_CAPACITY).0,
+            write_batch: None,
             next_node_id: 1,
             next_edge_id: 1,
             catalog: GraphCatalog::new(),
@@ -627,6 +823,45 @@ impl GraphStore {
         }
     }
 
+    /// Events buffered per change-feed subscriber before it starts lagging
+    pub const CHANGE_FEED_CAPACITY: usize = 4096;
+
+    /// Subscribe to node and edge create/update/delete events.
+    ///
+    /// The feed is independent of the internal indexer channel and sees only
+    /// changes made after subscribing. Each subscriber buffers up to
+    /// [`CHANGE_FEED_CAPACITY`](Self::CHANGE_FEED_CAPACITY) events; writers never
+    /// block on it. A subscriber that falls further behind loses the oldest
+    /// events and its next `recv`/`try_recv` returns `Lagged(n)` with the number
+    /// skipped, after which it resumes from the oldest retained event.
+    ///
+    /// Stub and recovery loaders (`create_node_stub`, `create_edge_stub`,
+    /// `insert_recovered_*`) and `clear` do not publish events.
+    pub fn subscribe_changes(&self) -> broadcast::Receiver<ChangeEvent> {
+        self.change_feed.subscribe()
+    }
+
+    /// Publish to the change feed, building the event only if someone is listening.
+    /// Inside a write batch the event is held until commit.
+    fn publish_change(&mut self, event: impl FnOnce() -> ChangeEvent) {
+        if self.change_feed.receiver_count() == 0 {
+            return;
+        }
+        match &mut self.write_batch {
+            Some(batch) => batch.changes.push(event()),
+            None => {
+                let _ = self.change_feed.send(event());
+            }
+        }
+    }
+
+    /// Remember how to undo a write, if a write batch is open
+    fn record_undo(&mut self, entry: impl FnOnce() -> UndoEntry) {
+        if let Some(batch) = &mut self.write_batch {
+            batch.undo.push(entry());
+        }
+    }
+
     /// Create a new GraphStore with async indexing enabled
     pub fn with_async_indexing() -> (Self, tokio::sync::mpsc::UnboundedReceiver<crate::graph::event::IndexEvent>) {
         let (tx, rx) = unbounded_channel();
@@ -641,6 +876,8 @@ impl GraphStore {
         vector_index: Arc<VectorIndexManager>,
         property_index: Arc<IndexManager>,
         tenant_manager: Arc<crate::persistence::TenantManager>,
+        store: std::sync::Weak<tokio::sync::RwLock<GraphStore>>,
+        persistence: std::sync::Weak<crate::persistence::PersistenceManager>,
     ) {
         use crate::graph::event::IndexEvent::*;
         
@@ -656,37 +893,8 @@ impl GraphStore {
                         for label in &labels {
                             property_index.index_insert(label, key, value.clone(), id);
                         }
-                        
-                        // Auto-Embed check
-                        if let PropertyValue::String(text) = value {
-                            if let Ok(tenant) = tenant_manager.get_tenant(&tenant_id) {
-                                if let Some(config) = tenant.embed_config {
-                                    for label in &labels {
-                                        if let Some(keys) = config.embedding_policies.get(label.as_str()) {
-                                            if keys.contains(key) {
-                                                // Trigger Auto-Embed
-                                                let vector_index_clone = Arc::clone(&vector_index);
-                                                let label_str = label.as_str().to_string();
-                                                let key_clone = key.clone();
-                                                let text_clone = text.clone();
-                                                let config_clone = config.clone();
-                                                
-                                                tokio::spawn(async move {
-                                                    if let Ok(pipeline) = crate::embed::EmbedPipeline::new(config_clone) {
-                                                        if let Ok(chunks) = pipeline.process_text(&text_clone).await {
-                                                            for chunk in chunks {
-                                                                let _ = vector_index_clone.add_vector(&label_str, &key_clone, id, &chunk.embedding);
-                                                            }
-                                                        }
-                                                    }
-                                                });
-                                            }
-                                        }
-                                    }
-                                }
-                            }
-                        }
                     }
+                    Self::spawn_auto_embed(&store, &persistence, &tenant_manager, &tenant_id, id, &labels, &properties);
 
                     // Agentic Enrichment Trigger
                     if let Ok(tenant) = tenant_manager.get_tenant(&tenant_id) {
@@ -726,50 +934,35 @@ NodeDeleted { tenant_id: _, id, labels, properties } => {
                             property_index.index_remove(label, &key, &value, id);
                         }
                     }
+                    for label in &labels {
+                        vector_index.remove_node(label.as_str(), id);
+                    }
                 }
                 PropertySet { tenant_id, id, labels, key, old_value, new_value } => {
+                    // The old value's vector (set directly or auto-embedded from text)
+                    // is replaced, not kept alongside the new one
+                    if old_value.is_some() || new_value.is_null() {
+                        for label in &labels {
+                            vector_index.remove_vector(label.as_str(), &key, id);
+                        }
+                    }
                     if let Some(old) = old_value {
                         for label in &labels {
                             property_index.index_remove(label, &key, &old, id);
                         }
                     }
-                    for label in &labels {
-                        property_index.index_insert(label, &key, new_value.clone(), id);
+                    if !new_value.is_null() {
+                        for label in &labels {
+                            property_index.index_insert(label, &key, new_value.clone(), id);
+                        }
                     }
                     if let PropertyValue::Vector(vec) = &new_value {
                         for label in &labels {
                             let _ = vector_index.add_vector(label.as_str(), &key, id, vec);
                         }
                     }
-                    
-                    // Auto-Embed check
-                    if let PropertyValue::String(text) = &new_value {
-                        if let Ok(tenant) = tenant_manager.get_tenant(&tenant_id) {
-                            if let Some(config) = tenant.embed_config {
-                                for label in &labels {
-                                    if let Some(keys) = config.embedding_policies.get(label.as_str()) {
-                                        if keys.contains(&key) {
-                                            let vector_index_clone = Arc::clone(&vector_index);
-                                            let label_str = label.as_str().to_string();
-                                            let key_clone = key.clone();
-                                            let text_clone = text.clone();
-                                            let config_clone = config.clone();
-                                            
-                                            tokio::spawn(async move {
-                                                if let Ok(pipeline) = crate::embed::EmbedPipeline::new(config_clone) {
-                                                    if let Ok(chunks) = pipeline.process_text(&text_clone).await {
-                                                        if let Some(first) = chunks.first() {
-                                                            let _ = vector_index_clone.add_vector(&label_str, &key_clone, id, &first.embedding);
-                                                        }
-                                                    }
-                                                }
-                                            });
-                                        }
-                                    }
-                                }
-                            }
-                        }
-                    }
+
+                    Self::spawn_auto_embed(&store, &persistence, &tenant_manager, &tenant_id, id, &labels, [(&key, &new_value)]);
 
                     // Agentic Enrichment Trigger (PropertySet)
                     if let Ok(tenant) = tenant_manager.get_tenant(&tenant_id) {
@@ -800,41 +993,103 @@ NodeDeleted { tenant_id: _, id, labels, properties } => {
                     }
                 }
                 LabelAdded { tenant_id, id, label, properties } => {
+                    Self::spawn_auto_embed(&store, &persistence, &tenant_manager, &tenant_id, id, std::slice::from_ref(&label), &properties);
                     for (key, value) in properties {
                         if let PropertyValue::Vector(vec) = &value {
                             let _ = vector_index.add_vector(label.as_str(), &key, id, vec);
                         }
                         property_index.index_insert(&label, &key, value.clone(), id);
-                        
-                        // Auto-Embed check
-                        if let PropertyValue::String(text) = &value {
-                            if let Ok(tenant) = tenant_manager.get_tenant(&tenant_id) {
-                                if let Some(config) = tenant.embed_config {
-                                    if let Some(keys) = config.embedding_policies.get(label.as_str()) {
-                                        if keys.contains(&key) {
-                                            let vector_index_clone = Arc::clone(&vector_index);
-                                            let label_str = label.as_str().to_string();
-                                            let key_clone = key.clone();
-                                            let text_clone = text.clone();
-                                            let config_clone = config.clone();
-                                            
-                                            tokio::spawn(async move {
-                                                if let Ok(pipeline) = crate::embed::EmbedPipeline::new(config_clone) {
-                                                    if let Ok(chunks) = pipeline.process_text(&text_clone).await {
-                                                        if let Some(first) = chunks.first() {
-                                                            let _ = vector_index_clone.add_vector(&label_str, &key_clone, id, &first.embedding);
-                                                        }
-                                                    }
-                                                }
-                                            });
-                                        }
-                                    }
-                                }
-                            }
-                        }
                     }
                 }
+                LabelRemoved { tenant_id: _, id, label, properties } => {
+                    for (key, value) in &properties {
+                        property_index.index_remove(&label, key, value, id);
+                    }
+                    vector_index.remove_node(label.as_str(), id);
+                }
+            }
+        }
+    }
+
+    /// Embed the tenant's configured text properties among `properties` in
+    /// the background, then store each embedding on the node as a `Vector`
+    /// property named by [`crate::embed::embedding_property`]
+    ///
+    /// Tenants without an `embed_config` are skipped. The embedding is indexed
+    /// under the text property itself, so `vector_search("Document", "content")`
+    /// finds documents embedded from `content`; that index is created on first
+    /// use with the config's `metric` unless one already exists. The property
+    /// write goes to the WAL through `persistence` like any other update.
+    fn spawn_auto_embed<'a>(
+        store: &std::sync::Weak<tokio::sync::RwLock<GraphStore>>,
+        persistence: &std::sync::Weak<crate::persistence::PersistenceManager>,
+        tenant_manager: &crate::persistence::TenantManager,
+        tenant_id: &str,
+        id: NodeId,
+        labels: &[Label],
+        properties: impl IntoIterator<Item = (&'a String, &'a PropertyValue)>,
+    ) {
+        let Some(config) = tenant_manager.get_tenant(tenant_id).ok().and_then(|t| t.embed_config) else {
+            return;
+        };
+        for (key, value) in properties {
+            let PropertyValue::String(text) = value else { continue };
+            let embedded_labels: Vec<Label> = labels
+                .iter()
+                .filter(|label| config.embedding_policies.get(label.as_str()).is_some_and(|keys| keys.contains(key)))
+                .cloned()
+                .collect();
+            if embedded_labels.is_empty() {
+                continue;
             }
+            let Some(store) = store.upgrade() else { return };
+            let persistence = persistence.clone();
+            let (config, tenant_id, key, text) = (config.clone(), tenant_id.to_string(), key.clone(), text.clone());
+
+            tokio::spawn(async move {
+                let metric = config.metric;
+                let chunks = match crate::embed::EmbedPipeline::new(config) {
+                    Ok(pipeline) => pipeline.process_text(&text).await,
+                    Err(e) => Err(e),
+                };
+                let embedding = match chunks {
+                    Ok(chunks) => match chunks.into_iter().next() {
+                        Some(chunk) => chunk.embedding,
+                        None => return,
+                    },
+                    Err(e) => {
+                        eprintln!("[auto-embed] node {} property {}: {}", id.as_u64(), key, e);
+                        return;
+                    }
+                };
+
+                let mut store = store.write().await;
+                // The text may have been overwritten while it was being embedded
+                let current = store.get_node(id).and_then(|node| node.get_property(&key));
+                if current != Some(&PropertyValue::String(text)) {
+                    return;
+                }
+                for label in &embedded_labels {
+                    if store.vector_index.get_index(label.as_str(), &key).is_none() {
+                        let _ = store.vector_index.create_index(label.as_str(), &key, embedding.len(), metric);
+                    }
+                    store.vector_index.remove_vector(label.as_str(), &key, id);
+                    if let Err(e) = store.vector_index.add_vector(label.as_str(), &key, id, &embedding) {
+                        eprintln!("[auto-embed] node {} property {}: {}", id.as_u64(), key, e);
+                    }
+                }
+                let target = crate::embed::embedding_property(&key);
+                let value = PropertyValue::Vector(embedding);
+                if store.set_node_property(&tenant_id, id, target.clone(), value.clone()).is_err() {
+                    return;
+                }
+                if let Some(persist_mgr) = persistence.upgrade() {
+                    let update = PropertyMap::from([(target, value)]);
+                    if let Err(e) = persist_mgr.persist_update_node_properties(&tenant_id, id.as_u64(), &update) {
+                        eprintln!("[auto-embed] failed to persist node {}: {}", id.as_u64(), e);
+                    }
+                }
+            });
         }
     }
 
@@ -871,6 +1126,15 @@ NodeDeleted { tenant_id: _, id, labels, properties } => {
             self.incoming.resize(idx + 1, Vec::new());
         }
 
+        self.publish_change(|| ChangeEvent::NodeCreated {
+            tenant_id: "default".to_string(),
+            id: node_id,
+            labels: node.labels.iter().cloned().collect(),
+            properties: node.properties.clone(),
+        });
+
+        self.record_undo(|| UndoEntry::CreateNode { tenant_id: "default".to_string(), id: node_id });
+
         let event = crate::graph::event::IndexEvent::NodeCreated {
             tenant_id: "default".to_string(),
             id: node_id,
@@ -889,6 +1153,9 @@ NodeDeleted { tenant_id: _, id, labels, properties } => {
     }
 
     /// Create a node with multiple labels and properties
+    ///
+    /// Property constraints are not checked here; callers handling user input
+    /// validate with [`check_node_constraints`](Self::check_node_constraints) first.
     pub fn create_node_with_properties(
         &mut self,
         tenant_id: &str,
@@ -922,6 +1189,9 @@ NodeDeleted { tenant_id: _, id, labels, properties } => {
                 .insert(node_id);
             // Update catalog label count
             self.catalog.on_label_added(label);
+            for (key, value) in &node.properties {
+                self.property_index.constraint_insert(label, key, value.clone(), node_id);
+            }
         }
 
         // Ensure storage capacity
@@ -931,6 +1201,15 @@ NodeDeleted { tenant_id: _, id, labels, properties } => {
             self.incoming.resize(idx + 1, Vec::new());
         }
 
+        self.publish_change(|| ChangeEvent::NodeCreated {
+            tenant_id: tenant_id.to_string(),
+            id: node_id,
+            labels: node.labels.iter().cloned().collect(),
+            properties: node.properties.clone(),
+        });
+
+        self.record_undo(|| UndoEntry::CreateNode { tenant_id: tenant_id.to_string(), id: node_id });
+
         let event = crate::graph::event::IndexEvent::NodeCreated {
             tenant_id: tenant_id.to_string(),
             id: node_id,
@@ -1049,6 +1328,27 @@ NodeDeleted { tenant_id: _, id, labels, properties } => {
         id
     }
 
+    /// Record a new edge in the typed adjacency, per-type and self-loop counts
+    fn track_typed_edge(&mut self, source: NodeId, target: NodeId, type_id: u16, edge_id: EdgeId) {
+        self.outgoing_by_type.entry((source, type_id)).or_default().push(edge_id);
+        if source == target {
+            *self.self_loops.entry(source).or_insert(0) += 1;
+        }
+        let slot = type_id as usize;
+        if slot >= self.edge_type_counts.len() {
+            self.edge_type_counts.resize(slot + 1, 0);
+        }
+        self.edge_type_counts[slot] += 1;
+    }
+
+    /// Outgoing edges of `node_id` with the given interned type id
+    fn outgoing_ids_of_type_id(&self, node_id: NodeId, type_id: u16) -> &[EdgeId] {
+        self.outgoing_by_type
+            .get(&(node_id, type_id))
+            .map(|v| v.as_slice())
+            .unwrap_or(&[])
+    }
+
     /// Get edge type for an edge — checks compact type array first, then full Edge store.
     /// Works for both stub-loaded and fully-loaded edges.
     /// Sentinel value for unset entries in edge_type_ids.
@@ -1079,6 +1379,12 @@ NodeDeleted { tenant_id: _, id, labels, properties } => {
         let val = value.into();
         let idx = node_id.as_u64() as usize;
 
+        let constrained = self.property_index.has_constraints();
+        if constrained {
+            let node = self.nodes.get(idx).and_then(|v| v.last()).ok_or(GraphError::NodeNotFound(node_id))?;
+            self.check_property_write(node_id, &node.labels, &key_str, &val)?;
+        }
+
         // Update columnar storage (always latest)
         self.node_columns.set_property(idx, &key_str, val.clone());
 
@@ -1101,10 +1407,38 @@ NodeDeleted { tenant_id: _, id, labels, properties } => {
             old_val = node.set_property(key_str.clone(), val.clone());
         }
 
+        if constrained {
+            for label in &versions.last().unwrap().labels {
+                if let Some(old) = &old_val {
+                    self.property_index.constraint_remove(label, &key_str, old, node_id);
+                }
+                if !val.is_null() {
+                    self.property_index.constraint_insert(label, &key_str, val.clone(), node_id);
+                }
+            }
+        }
+
+        let labels = versions.last().unwrap().labels.iter().cloned().collect();
+
+        self.publish_change(|| ChangeEvent::NodeUpdated {
+            tenant_id: tenant_id.to_string(),
+            id: node_id,
+            key: key_str.clone(),
+            old_value: old_val.clone(),
+            new_value: val.clone(),
+        });
+
+        self.record_undo(|| UndoEntry::NodeProperty {
+            tenant_id: tenant_id.to_string(),
+            id: node_id,
+            key: key_str.clone(),
+            old: old_val.clone(),
+        });
+
         let event = crate::graph::event::IndexEvent::PropertySet {
             tenant_id: tenant_id.to_string(),
             id: node_id,
-            labels: versions.last().unwrap().labels.iter().cloned().collect(),
+            labels,
             key: key_str,
             old_value: old_val,
             new_value: val,
@@ -1137,10 +1471,12 @@ NodeDeleted { tenant_id: _, id, labels, properties } => {
         let val = value.into();
         let idx = edge_id.as_u64() as usize;
 
+        let old_val = self.edge_properties.get(&edge_id).and_then(|props| props.get(&key_str)).cloned();
+
         // Apply the mutation to the live stores first so the snapshot below
         // captures the new state.
         self.edge_columns.set_property(idx, &key_str, val.clone());
-        self.set_edge_property_sparse(edge_id, key_str, val);
+        self.set_edge_property_sparse(edge_id, key_str.clone(), val.clone());
 
         // Record the POST-mutation properties in the version log.
         let post_props = self
@@ -1162,6 +1498,13 @@ NodeDeleted { tenant_id: _, id, labels, properties } => {
             }
         }
 
+        self.publish_change(|| ChangeEvent::EdgeUpdated {
+            id: edge_id,
+            key: key_str,
+            old_value: old_val,
+            new_value: val,
+        });
+
         Ok(())
     }
 
@@ -1192,8 +1535,18 @@ NodeDeleted { tenant_id: _, id, labels, properties } => {
                 node_set.remove(&id);
             }
             self.catalog.on_label_removed(label);
+            for (key, value) in &latest_node.properties {
+                self.property_index.constraint_remove(label, key, value, id);
+            }
         }
 
+        self.publish_change(|| ChangeEvent::NodeDeleted {
+            tenant_id: tenant_id.to_string(),
+            id,
+            labels: latest_node.labels.iter().cloned().collect(),
+            properties: latest_node.properties.clone(),
+        });
+
         let event = crate::graph::event::IndexEvent::NodeDeleted {
             tenant_id: tenant_id.to_string(),
             id,
@@ -1227,6 +1580,9 @@ NodeDeleted { tenant_id: _, id, labels, properties } => {
             let _ = self.delete_edge(*edge_id);
         }
 
+        // Recorded after the edge deletions so rollback restores the node before its edges
+        self.record_undo(|| UndoEntry::DeleteNode { tenant_id: tenant_id.to_string(), node: node.clone() });
+
         Ok(node)
     }
 
@@ -1245,8 +1601,21 @@ NodeDeleted { tenant_id: _, id, labels, properties } => {
         let label = label.into();
         let idx = node_id.as_u64() as usize;
 
+        // Unique constraints on the new label must hold for the node's current values.
+        // Not-null constraints are left to the caller, which may still be setting properties.
+        if self.property_index.has_constraints() {
+            let node = self.nodes.get(idx).and_then(|v| v.last()).ok_or(GraphError::NodeNotFound(node_id))?;
+            for (key, value) in &node.properties {
+                self.check_unique(node_id, &label, key, value)?;
+            }
+            for (key, value) in &node.properties {
+                self.property_index.constraint_insert(&label, key, value.clone(), node_id);
+            }
+        }
+
         // Get the node and add the label
         let node = self.nodes.get_mut(idx).and_then(|v| v.last_mut()).ok_or(GraphError::NodeNotFound(node_id))?;
+        let newly_added = !node.has_label(&label);
         node.add_label(label.clone());
 
         // Update the label index so queries can find this node by the new label
@@ -1271,6 +1640,16 @@ NodeDeleted { tenant_id: _, id, labels, properties } => {
             self.handle_index_event(event, None);
         }
 
+        if newly_added {
+            self.record_undo(|| UndoEntry::AddLabel { tenant_id: tenant_id.to_string(), id: node_id, label: label.clone() });
+        }
+
+        self.publish_change(|| ChangeEvent::NodeLabelAdded {
+            tenant_id: tenant_id.to_string(),
+            id: node_id,
+            label,
+        });
+
         Ok(())
     }
 
@@ -1307,6 +1686,7 @@ NodeDeleted { tenant_id: _, id, labels, properties } => {
             self.edge_type_ids.resize(idx + 1, Self::EDGE_TYPE_UNSET);
         }
         self.edge_type_ids[idx] = type_id;
+        self.track_typed_edge(source, target, type_id, edge_id);
 
         // DS-07c: Edge endpoints
         if idx >= self.edge_endpoints.len() {
@@ -1370,6 +1750,7 @@ NodeDeleted { tenant_id: _, id, labels, properties } => {
             self.edge_type_ids.resize(idx + 1, Self::EDGE_TYPE_UNSET);
         }
         self.edge_type_ids[idx] = type_id;
+        self.track_typed_edge(source, target, type_id, edge_id);
 
         // Update edge type index
         self.edge_type_index
@@ -1382,6 +1763,15 @@ NodeDeleted { tenant_id: _, id, labels, properties } => {
         let tgt_labels: Vec<Label> = self.get_node(target).map(|n| n.labels.iter().cloned().collect()).unwrap_or_default();
         self.catalog.on_edge_created(source, &src_labels, &edge_type, target, &tgt_labels);
 
+        self.record_undo(|| UndoEntry::CreateEdge(edge_id));
+        self.publish_change(|| ChangeEvent::EdgeCreated {
+            id: edge_id,
+            source,
+            target,
+            edge_type,
+            properties: PropertyMap::new(),
+        });
+
         Ok(edge_id)
     }
 
@@ -1445,6 +1835,7 @@ NodeDeleted { tenant_id: _, id, labels, properties } => {
             self.edge_type_ids.resize(idx + 1, Self::EDGE_TYPE_UNSET);
         }
         self.edge_type_ids[idx] = type_id;
+        self.track_typed_edge(source, target, type_id, edge_id);
         if !edge.properties.is_empty() {
             self.edge_properties.insert(edge_id, edge.properties.clone());
         }
@@ -1460,9 +1851,103 @@ NodeDeleted { tenant_id: _, id, labels, properties } => {
         let tgt_labels: Vec<Label> = self.get_node(target).map(|n| n.labels.iter().cloned().collect()).unwrap_or_default();
         self.catalog.on_edge_created(source, &src_labels, &edge_type, target, &tgt_labels);
 
+        self.record_undo(|| UndoEntry::CreateEdge(edge_id));
+        self.publish_change(|| ChangeEvent::EdgeCreated {
+            id: edge_id,
+            source,
+            target,
+            edge_type,
+            properties: edge.properties,
+        });
+
         Ok(edge_id)
     }
 
+    /// Create many edges at once, returning their ids in input order.
+    ///
+    /// Equivalent to calling [`create_edge`](Self::create_edge) for each triple,
+    /// but the adjacency lists are appended to and re-sorted once per touched
+    /// node instead of sorted-inserted per edge, and storage arrays grow once.
+    /// All endpoints are validated first: on error nothing is created.
+    pub fn create_edges_bulk(&mut self, edges: &[(NodeId, NodeId, EdgeType)]) -> GraphResult<Vec<EdgeId>> {
+        for (source, target, _) in edges {
+            if !self.has_node(*source) {
+                return Err(GraphError::InvalidEdgeSource(*source));
+            }
+            if !self.has_node(*target) {
+                return Err(GraphError::InvalidEdgeTarget(*target));
+            }
+        }
+        if edges.is_empty() {
+            return Ok(Vec::new());
+        }
+        self.invalidate_statistics_cache();
+
+        let mut ids = Vec::with_capacity(edges.len());
+        for _ in edges {
+            let id = self.free_edge_ids.pop().unwrap_or_else(|| {
+                self.next_edge_id += 1;
+                self.next_edge_id - 1
+            });
+            ids.push(EdgeId::new(id));
+        }
+        let max_idx = ids.iter().map(|id| id.as_u64() as usize).max().unwrap_or(0);
+        if max_idx >= self.edge_endpoints.len() {
+            self.edge_endpoints.resize(max_idx + 1, (NodeId::new(0), NodeId::new(0)));
+        }
+        if max_idx >= self.edge_type_ids.len() {
+            self.edge_type_ids.resize(max_idx + 1, Self::EDGE_TYPE_UNSET);
+        }
+
+        let mut touched_out = HashSet::new();
+        let mut touched_in = HashSet::new();
+        let mut labels_of: HashMap<NodeId, Vec<Label>> = HashMap::new();
+        for (&edge_id, (source, target, edge_type)) in ids.iter().zip(edges) {
+            let (source, target) = (*source, *target);
+            let idx = edge_id.as_u64() as usize;
+            self.outgoing[source.as_u64() as usize].push((target, edge_id));
+            self.incoming[target.as_u64() as usize].push((source, edge_id));
+            touched_out.insert(source.as_u64() as usize);
+            touched_in.insert(target.as_u64() as usize);
+
+            self.edge_endpoints[idx] = (source, target);
+            let type_id = self.intern_edge_type(edge_type);
+            self.edge_type_ids[idx] = type_id;
+            self.track_typed_edge(source, target, type_id, edge_id);
+            self.edge_type_index.entry(edge_type.clone()).or_default().insert(edge_id);
+
+            for node in [source, target] {
+                labels_of
+                    .entry(node)
+                    .or_insert_with(|| self.get_node(node).map(|n| n.labels.iter().cloned().collect()).unwrap_or_default());
+            }
+        }
+        self.catalog.on_edges_created(edges.iter().map(|(source, target, edge_type)| {
+            (*source, labels_of[source].as_slice(), edge_type, *target, labels_of[target].as_slice())
+        }));
+
+        // Restore the neighbor ordering create_edge maintains by sorted insert
+        for idx in touched_out {
+            self.outgoing[idx].sort_by_key(|(nid, _)| *nid);
+        }
+        for idx in touched_in {
+            self.incoming[idx].sort_by_key(|(nid, _)| *nid);
+        }
+
+        for (&id, (source, target, edge_type)) in ids.iter().zip(edges) {
+            self.record_undo(|| UndoEntry::CreateEdge(id));
+            self.publish_change(|| ChangeEvent::EdgeCreated {
+                id,
+                source: *source,
+                target: *target,
+                edge_type: edge_type.clone(),
+                properties: PropertyMap::new(),
+            });
+        }
+
+        Ok(ids)
+    }
+
     /// Get an edge by ID at a specific version (MVCC)
     pub fn get_edge_at_version(&self, id: EdgeId, version: u64) -> Option<Edge> {
         let idx = id.as_u64() as usize;
@@ -1564,8 +2049,25 @@ NodeDeleted { tenant_id: _, id, labels, properties } => {
     /// DS-07c: Set a property on an edge via sparse map
     pub fn set_edge_property_sparse(&mut self, edge_id: EdgeId, key: impl Into<String>, value: impl Into<PropertyValue>) {
         self.invalidate_statistics_cache();
+        let key = key.into();
         let props = self.edge_properties.entry(edge_id).or_insert_with(PropertyMap::new);
-        props.insert(key.into(), value.into());
+        let old = props.insert(key.clone(), value.into());
+        self.record_undo(|| UndoEntry::EdgeProperty { id: edge_id, key, old });
+    }
+
+    /// Remove a property from an edge, returning its previous value
+    pub fn remove_edge_property(&mut self, edge_id: EdgeId, key: &str) -> Option<PropertyValue> {
+        self.invalidate_statistics_cache();
+        self.edge_columns.remove_property(edge_id.as_u64() as usize, key);
+        let old = self.edge_properties.get_mut(&edge_id)?.remove(key)?;
+        self.record_undo(|| UndoEntry::EdgeProperty { id: edge_id, key: key.to_string(), old: Some(old.clone()) });
+        self.publish_change(|| ChangeEvent::EdgeUpdated {
+            id: edge_id,
+            key: key.to_string(),
+            old_value: Some(old.clone()),
+            new_value: PropertyValue::Null,
+        });
+        Some(old)
     }
 
     /// Check if an edge exists
@@ -1585,20 +2087,42 @@ NodeDeleted { tenant_id: _, id, labels, properties } => {
         let src_labels: Vec<Label> = self.get_node(edge.source).map(|n| n.labels.iter().cloned().collect()).unwrap_or_default();
         let tgt_labels: Vec<Label> = self.get_node(edge.target).map(|n| n.labels.iter().cloned().collect()).unwrap_or_default();
 
-        // Add to free list
-        self.free_edge_ids.push(id.as_u64());
-
         // Remove from edge type index
         if let Some(edge_set) = self.edge_type_index.get_mut(&edge.edge_type) {
             edge_set.remove(&id);
         }
 
-        // Remove from adjacency lists
-        if let Some(adj) = self.outgoing.get_mut(edge.source.as_u64() as usize) {
-            adj.retain(|&(_, eid)| eid != id);
+        // Remove from typed adjacency and per-type counts
+        if let Some(&type_id) = self.edge_type_to_id.get(&edge.edge_type) {
+            if let Some(count) = self.edge_type_counts.get_mut(type_id as usize) {
+                *count = count.saturating_sub(1);
+            }
+            if let Some(ids) = self.outgoing_by_type.get_mut(&(edge.source, type_id)) {
+                ids.retain(|&eid| eid != id);
+                if ids.is_empty() {
+                    self.outgoing_by_type.remove(&(edge.source, type_id));
+                }
+            }
+        }
+
+        // Remove from adjacency lists. Frozen segments are immutable, so an
+        // edge found there is only counted as deleted, and its id is never
+        // reused: the stale frozen entry would otherwise resolve to the new edge.
+        let in_outgoing_buffer = Self::remove_adjacency(&mut self.outgoing, edge.source, id);
+        let in_incoming_buffer = Self::remove_adjacency(&mut self.incoming, edge.target, id);
+        if !in_outgoing_buffer {
+            *self.frozen_deleted_outgoing.entry(edge.source).or_insert(0) += 1;
+        }
+        if !in_incoming_buffer {
+            *self.frozen_deleted_incoming.entry(edge.target).or_insert(0) += 1;
         }
-        if let Some(adj) = self.incoming.get_mut(edge.target.as_u64() as usize) {
-            adj.retain(|&(_, eid)| eid != id);
+        if in_outgoing_buffer && in_incoming_buffer {
+            self.free_edge_ids.push(id.as_u64());
+        }
+        if edge.source == edge.target {
+            if let Some(count) = self.self_loops.get_mut(&edge.source) {
+                *count = count.saturating_sub(1);
+            }
         }
 
         // Clear DS-07c fields
@@ -1614,9 +2138,29 @@ NodeDeleted { tenant_id: _, id, labels, properties } => {
         // Update catalog triple stats
         self.catalog.on_edge_deleted(edge.source, &src_labels, &edge.edge_type, edge.target, &tgt_labels);
 
+        self.record_undo(|| UndoEntry::DeleteEdge(edge.clone()));
+        self.publish_change(|| ChangeEvent::EdgeDeleted {
+            id,
+            source: edge.source,
+            target: edge.target,
+            edge_type: edge.edge_type.clone(),
+        });
+
         Ok(edge)
     }
 
+    /// Remove `id` from a node's write-buffer adjacency; false if it was not there
+    fn remove_adjacency(buffer: &mut [Vec<(NodeId, EdgeId)>], node_id: NodeId, id: EdgeId) -> bool {
+        match buffer.get_mut(node_id.as_u64() as usize) {
+            Some(adj) => {
+                let before = adj.len();
+                adj.retain(|&(_, eid)| eid != id);
+                adj.len() != before
+            }
+            None => false,
+        }
+    }
+
     /// Get all outgoing edges from a node
     pub fn get_outgoing_edges(&self, node_id: NodeId) -> Vec<Edge> {
         let idx = node_id.as_u64() as usize;
@@ -1651,6 +2195,40 @@ NodeDeleted { tenant_id: _, id, labels, properties } => {
         result
     }
 
+    /// Number of outgoing edges of a node
+    ///
+    /// Counts adjacency entries instead of materializing edges: O(1) per
+    /// frozen (CSR) segment plus the write buffer length, minus the edges
+    /// deleted from frozen segments since they were compacted.
+    pub fn out_degree(&self, node_id: NodeId) -> usize {
+        Self::adjacency_degree(&self.frozen_outgoing, &self.outgoing, &self.frozen_deleted_outgoing, node_id)
+    }
+
+    /// Number of incoming edges of a node
+    pub fn in_degree(&self, node_id: NodeId) -> usize {
+        Self::adjacency_degree(&self.frozen_incoming, &self.incoming, &self.frozen_deleted_incoming, node_id)
+    }
+
+    /// Number of edges touching a node in either direction
+    ///
+    /// A self-loop counts once, as in Cypher's `size((n)--())`.
+    pub fn degree(&self, node_id: NodeId) -> usize {
+        let self_loops = self.self_loops.get(&node_id).copied().unwrap_or(0);
+        self.out_degree(node_id) + self.in_degree(node_id) - self_loops
+    }
+
+    fn adjacency_degree(
+        frozen: &FrozenAdjacencyStore,
+        buffer: &[Vec<(NodeId, EdgeId)>],
+        frozen_deleted: &HashMap<NodeId, usize>,
+        node_id: NodeId,
+    ) -> usize {
+        let idx = node_id.as_u64() as usize;
+        let frozen_entries: usize = frozen.segments.iter().map(|seg| seg.neighbors(idx).len()).sum();
+        let deleted = frozen_deleted.get(&node_id).copied().unwrap_or(0);
+        frozen_entries - deleted + buffer.get(idx).map_or(0, Vec::len)
+    }
+
     /// Get outgoing edge targets as lightweight tuples.
     /// Returns (EdgeId, source NodeId, target NodeId, EdgeType) for each outgoing edge.
     /// Delegates to the DS-07c owned version.
@@ -1724,26 +2302,26 @@ NodeDeleted { tenant_id: _, id, labels, properties } => {
         count
     }
 
-    /// Outgoing-direction analogue.
+    /// Outgoing-direction analogue, answered from the typed adjacency.
     pub fn outgoing_degree_for_type(&self, node_id: NodeId, edge_type: &EdgeType) -> usize {
-        let type_id = match self.edge_type_to_id.get(edge_type) { Some(&id) => id, None => return 0 };
-        let idx = node_id.as_u64() as usize;
-        let mut count = 0usize;
-        for seg in &self.frozen_outgoing.segments {
-            for &(_tgt, eid) in seg.neighbors(idx) {
-                if self.edge_type_ids.get(eid.as_u64() as usize).copied().unwrap_or(Self::EDGE_TYPE_UNSET) == type_id {
-                    count += 1;
-                }
-            }
-        }
-        if let Some(entries) = self.outgoing.get(idx) {
-            for &(_tgt, eid) in entries {
-                if self.edge_type_ids.get(eid.as_u64() as usize).copied().unwrap_or(Self::EDGE_TYPE_UNSET) == type_id {
-                    count += 1;
-                }
-            }
+        self.outgoing_edge_ids_of_type(node_id, edge_type).len()
+    }
+
+    /// Ids of the outgoing edges of `node_id` with type `edge_type`, in
+    /// creation order. Only edges of that type are visited.
+    pub fn outgoing_edge_ids_of_type(&self, node_id: NodeId, edge_type: &EdgeType) -> &[EdgeId] {
+        match self.edge_type_to_id.get(edge_type) {
+            Some(&type_id) => self.outgoing_ids_of_type_id(node_id, type_id),
+            None => &[],
         }
-        count
+    }
+
+    /// Get the outgoing edges of `node_id` with type `edge_type`
+    pub fn get_outgoing_edges_of_type(&self, node_id: NodeId, edge_type: &EdgeType) -> Vec<Edge> {
+        self.outgoing_edge_ids_of_type(node_id, edge_type)
+            .iter()
+            .filter_map(|&eid| self.get_edge(eid))
+            .collect()
     }
 
     /// Visit every incoming neighbor reachable through an edge of `edge_type`.
@@ -1793,8 +2371,79 @@ NodeDeleted { tenant_id: _, id, labels, properties } => {
         self.get_incoming_edge_sources_owned(node_id)
     }
 
-    /// Helper: search a sorted slice for edges between source and target
-    fn search_adjacency_slice(
+    /// Nodes within `max_hops` edges of `start`, nearest first
+    ///
+    /// Breadth-first expansion over the adjacency lists, following only edges in
+    /// `direction` whose type is in `edge_types` (any type when `None`). Each node is
+    /// reported once, at its shortest hop distance, and `start` itself is never included;
+    /// nodes at the same distance appear in adjacency order. Returns an empty list when
+    /// `start` does not exist or `max_hops` is 0.
+    pub fn neighbors(
+        &self,
+        start: NodeId,
+        max_hops: usize,
+        edge_types: Option<&[EdgeType]>,
+        direction: EdgeDirection,
+    ) -> Vec<NodeId> {
+        if max_hops == 0 || !self.has_node(start) {
+            return Vec::new();
+        }
+        // Types the graph has never seen cannot match any edge
+        let type_ids: Option<Vec<u16>> = edge_types
+            .map(|types| types.iter().filter_map(|t| self.edge_type_to_id.get(t).copied()).collect());
+
+        let mut visited = HashSet::from([start]);
+        let mut found = Vec::new();
+        let mut frontier = vec![start];
+        for _ in 0..max_hops {
+            let mut next = Vec::new();
+            for &node in &frontier {
+                self.for_each_adjacent(node, direction, |neighbor, eid| {
+                    let type_id = self.edge_type_ids.get(eid.as_u64() as usize).copied().unwrap_or(Self::EDGE_TYPE_UNSET);
+                    // Unset type ids mark deleted edges still listed in the frozen tier
+                    let followed = type_id != Self::EDGE_TYPE_UNSET
+                        && type_ids.as_ref().map_or(true, |ids| ids.contains(&type_id));
+                    if followed && visited.insert(neighbor) {
+                        next.push(neighbor);
+                    }
+                });
+            }
+            if next.is_empty() {
+                break;
+            }
+            found.extend_from_slice(&next);
+            frontier = next;
+        }
+        found
+    }
+
+    /// Visit `(neighbor, edge)` for each adjacency entry of `node_id` in `direction`,
+    /// frozen tier before the write buffer.
+    fn for_each_adjacent<F: FnMut(NodeId, EdgeId)>(&self, node_id: NodeId, direction: EdgeDirection, mut f: F) {
+        let idx = node_id.as_u64() as usize;
+        let outgoing = (&self.frozen_outgoing, &self.outgoing);
+        let incoming = (&self.frozen_incoming, &self.incoming);
+        let tiers = match direction {
+            EdgeDirection::Outgoing => [Some(outgoing), None],
+            EdgeDirection::Incoming => [Some(incoming), None],
+            EdgeDirection::Both => [Some(outgoing), Some(incoming)],
+        };
+        for (frozen, buffer) in tiers.into_iter().flatten() {
+            for seg in &frozen.segments {
+                for &(neighbor, eid) in seg.neighbors(idx) {
+                    f(neighbor, eid);
+                }
+            }
+            if let Some(entries) = buffer.get(idx) {
+                for &(neighbor, eid) in entries {
+                    f(neighbor, eid);
+                }
+            }
+        }
+    }
+
+    /// Helper: search a sorted slice for edges between source and target
+    fn search_adjacency_slice(
         &self, entries: &[(NodeId, EdgeId)], search_key: NodeId,
         source: NodeId, target: NodeId, edge_type: Option<&EdgeType>,
     ) -> Vec<EdgeId> {
@@ -1883,6 +2532,67 @@ NodeDeleted { tenant_id: _, id, labels, properties } => {
             .unwrap_or_default()
     }
 
+    /// Get nodes with `label` whose `property` equals `value`
+    ///
+    /// Uses the property index for (label, property) when one exists and
+    /// falls back to scanning the label otherwise.
+    pub fn get_nodes_by_property(&self, label: &Label, property: &str, value: &PropertyValue) -> Vec<&Node> {
+        if let Some(ids) = self.property_index.lookup(label, property, value) {
+            return ids
+                .into_iter()
+                .filter_map(|id| self.get_node(id))
+                .filter(|node| node.labels.contains(label))
+                .collect();
+        }
+        self.get_nodes_by_label(label)
+            .into_iter()
+            .filter(|node| match node.get_property(property) {
+                Some(v) => v == value,
+                None => self.node_columns.get_property(node.id.as_u64() as usize, property) == *value,
+            })
+            .collect()
+    }
+
+    /// Find the `label` node whose properties equal all of `key_props`, or create it.
+    ///
+    /// Returns the node id and whether it was created; a new node gets exactly
+    /// `key_props`. Candidates come from a unique constraint or property index
+    /// on one of the keys when available, otherwise from a label scan. Property
+    /// indexes are skipped under background indexing
+    /// ([`with_async_indexing`](Self::with_async_indexing)), where they may lag
+    /// recent writes; unique constraints are always maintained in-line. If
+    /// several nodes match, the lowest id wins. As with
+    /// [`create_node_with_properties`](Self::create_node_with_properties),
+    /// constraints are not checked on creation.
+    pub fn merge_node(&mut self, label: impl Into<Label>, key_props: &PropertyMap) -> (NodeId, bool) {
+        let label = label.into();
+        let index_is_current = self.index_sender.is_none();
+        let candidates = key_props.iter().find_map(|(key, value)| {
+            if self.property_index.has_unique_constraint(&label, key) {
+                Some(self.property_index.unique_holders(&label, key, value))
+            } else if index_is_current {
+                self.property_index.lookup(&label, key, value)
+            } else {
+                None
+            }
+        });
+        let candidates = candidates
+            .unwrap_or_else(|| self.label_index.get(&label).map(|ids| ids.iter().copied().collect()).unwrap_or_default());
+
+        let existing = candidates.into_iter()
+            .filter(|&id| self.get_node(id).is_some_and(|node| {
+                node.labels.contains(&label) && key_props.iter().all(|(key, value)| match node.get_property(key) {
+                    Some(v) => v == value,
+                    None => self.node_columns.get_property(id.as_u64() as usize, key) == *value,
+                })
+            }))
+            .min();
+        match existing {
+            Some(id) => (id, false),
+            None => (self.create_node_with_properties("default", vec![label], key_props.clone()), true),
+        }
+    }
+
     /// Get NodeIds for a label without resolving each `&Node`. Optionally takes
     /// only the first `limit` ids (`None` = all). No sort is applied — order
     /// is HashMap-iteration order, which is stable within a process but
@@ -1976,6 +2686,46 @@ NodeDeleted { tenant_id: _, id, labels, properties } => {
         result
     }
 
+    /// Extract the slice of the graph made of nodes carrying any of
+    /// `node_labels` and the edges of `edge_types` running between them.
+    ///
+    /// Returns a new store with copies of the matching nodes and edges,
+    /// properties included. Node and edge ids are reassigned in the copy.
+    pub fn subgraph(&self, node_labels: &[Label], edge_types: &[EdgeType]) -> GraphStore {
+        let mut sub = GraphStore::new();
+
+        let mut node_ids: Vec<NodeId> = node_labels
+            .iter()
+            .filter_map(|label| self.label_index.get(label))
+            .flatten()
+            .copied()
+            .collect();
+        node_ids.sort();
+        node_ids.dedup();
+
+        let mut id_map: HashMap<NodeId, NodeId> = HashMap::with_capacity(node_ids.len());
+        for &id in &node_ids {
+            if let Some(node) = self.get_node(id) {
+                let mut labels: Vec<Label> = node.labels.iter().cloned().collect();
+                labels.sort();
+                let new_id = sub.create_node_with_properties("default", labels, node.properties.clone());
+                id_map.insert(id, new_id);
+            }
+        }
+
+        for &id in &node_ids {
+            for edge_type in edge_types {
+                for edge in self.get_outgoing_edges_of_type(id, edge_type) {
+                    if let (Some(&source), Some(&target)) = (id_map.get(&edge.source), id_map.get(&edge.target)) {
+                        let _ = sub.create_edge_with_properties(source, target, edge.edge_type, edge.properties);
+                    }
+                }
+            }
+        }
+
+        sub
+    }
+
     // ============================================================
     // Graph Statistics (for cost-based query optimization)
     // ============================================================
@@ -2086,6 +2836,32 @@ NodeDeleted { tenant_id: _, id, labels, properties } => {
         }
     }
 
+    /// Backfill all registered property indexes (and the composite indexes built
+    /// on them) from node data.
+    ///
+    /// The property-index counterpart of [`rebuild_vector_index`](Self::rebuild_vector_index):
+    /// snapshot import writes properties without index maintenance, so indexes
+    /// registered on the target store stay empty until this runs. Values are read
+    /// inline first, then from the column store.
+    pub fn rebuild_property_index(&mut self) {
+        for (label, property) in self.property_index.list_indexes() {
+            let entries: Vec<(NodeId, PropertyValue)> = self
+                .get_nodes_by_label(&label)
+                .iter()
+                .filter_map(|node| {
+                    let value = match node.get_property(&property) {
+                        Some(v) => v.clone(),
+                        None => self.node_columns.get_property(node.id.as_u64() as usize, &property),
+                    };
+                    (!value.is_null()).then_some((node.id, value))
+                })
+                .collect();
+            for (node_id, value) in entries {
+                self.property_index.index_insert(&label, &property, value, node_id);
+            }
+        }
+    }
+
     /// Discover all (label, property_key, dims) tuples from node Vector properties,
     /// register any missing HNSW indices, then populate them.
     /// This is the correct post-import call when no indices were pre-registered.
@@ -2201,6 +2977,112 @@ NodeDeleted { tenant_id: _, id, labels, properties } => {
         self.label_index.get(label).map(|s| s.len()).unwrap_or(0)
     }
 
+    /// Node count per label, read from the incrementally maintained label
+    /// index. Labels with no remaining nodes are omitted.
+    pub fn label_cardinalities(&self) -> HashMap<Label, usize> {
+        self.label_index
+            .iter()
+            .filter(|(_, ids)| !ids.is_empty())
+            .map(|(label, ids)| (label.clone(), ids.len()))
+            .collect()
+    }
+
+    /// Edge count per edge type, stub edges included. Types with no
+    /// remaining edges are omitted.
+    pub fn edge_type_cardinalities(&self) -> HashMap<EdgeType, usize> {
+        self.edge_type_counts
+            .iter()
+            .enumerate()
+            .filter(|(_, &count)| count > 0)
+            .map(|(type_id, &count)| (self.edge_type_table[type_id].clone(), count))
+            .collect()
+    }
+
+    /// Snapshot of the store's cardinalities and indexes, read from the
+    /// incremental maps rather than by scanning
+    pub fn stats(&self) -> StoreStats {
+        fn ranked(counts: impl Iterator<Item = (String, usize)>) -> Vec<NameCount> {
+            let mut entries: Vec<NameCount> = counts
+                .map(|(name, count)| NameCount { name, count: count as u64 })
+                .collect();
+            entries.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.name.cmp(&b.name)));
+            entries
+        }
+
+        let mut indexes: Vec<IndexedProperty> = self
+            .property_index
+            .list_indexes()
+            .into_iter()
+            .map(|(label, property)| IndexedProperty { label: label.as_str().to_string(), property })
+            .collect();
+        indexes.sort_by(|a, b| (&a.label, &a.property).cmp(&(&b.label, &b.property)));
+        let mut vector_indexes: Vec<IndexedProperty> = self
+            .vector_index
+            .list_indices()
+            .into_iter()
+            .map(|key| IndexedProperty { label: key.label, property: key.property_key })
+            .collect();
+        vector_indexes.sort_by(|a, b| (&a.label, &a.property).cmp(&(&b.label, &b.property)));
+
+        StoreStats {
+            nodes: self.node_count() as u64,
+            edges: self.edge_count() as u64,
+            labels: ranked(self.label_cardinalities().into_iter().map(|(l, n)| (l.as_str().to_string(), n))),
+            edge_types: ranked(self.edge_type_cardinalities().into_iter().map(|(t, n)| (t.as_str().to_string(), n))),
+            indexes,
+            vector_indexes,
+        }
+    }
+
+    /// Describe the labels, property types and edge types of the graph
+    ///
+    /// Property types are inferred from up to 100 nodes per label; edge
+    /// endpoint labels come from the catalog's triple statistics.
+    pub fn schema_info(&self) -> SchemaInfo {
+        let mut labels: Vec<LabelSchema> = self.label_cardinalities().into_iter()
+            .map(|(label, count)| {
+                let mut properties: BTreeMap<String, String> = BTreeMap::new();
+                let sample = self.label_index.get(&label).into_iter().flatten()
+                    .take(SCHEMA_SAMPLE_SIZE)
+                    .filter_map(|id| self.get_node(*id));
+                for node in sample {
+                    for (key, value) in &node.properties {
+                        if value.is_null() {
+                            continue;
+                        }
+                        let type_name = value.type_name();
+                        properties.entry(key.clone())
+                            .and_modify(|seen| if seen != type_name { *seen = "Mixed".to_string() })
+                            .or_insert_with(|| type_name.to_string());
+                    }
+                }
+                LabelSchema { label: label.as_str().to_string(), count: count as u64, properties }
+            })
+            .collect();
+        labels.sort_by(|a, b| a.label.cmp(&b.label));
+
+        let mut endpoints: HashMap<&str, (BTreeSet<String>, BTreeSet<String>)> = HashMap::new();
+        for pattern in self.catalog.all_triple_stats().keys() {
+            let entry = endpoints.entry(pattern.edge_type.as_str()).or_default();
+            entry.0.insert(pattern.source_label.as_str().to_string());
+            entry.1.insert(pattern.target_label.as_str().to_string());
+        }
+        let mut edge_types: Vec<EdgeTypeSchema> = self.edge_type_cardinalities().into_iter()
+            .map(|(edge_type, count)| {
+                let (sources, targets) = endpoints.remove(edge_type.as_str()).unwrap_or_default();
+                EdgeTypeSchema {
+                    edge_type: edge_type.as_str().to_string(),
+                    count: count as u64,
+                    source_labels: sources.into_iter().collect(),
+                    target_labels: targets.into_iter().collect(),
+                }
+            })
+            .collect();
+        edge_types.sort_by(|a, b| a.edge_type.cmp(&b.edge_type));
+
+        SchemaInfo { labels, edge_types }
+    }
+
     /// Get the raw node ID set for a label (for sampling without full materialization)
     pub fn label_index_ids(&self, label: &Label) -> Option<&HashSet<NodeId>> {
         self.label_index.get(label)
@@ -2211,6 +3093,11 @@ NodeDeleted { tenant_id: _, id, labels, properties } => {
         self.edge_type_index.get(edge_type).map(|s| s.len()).unwrap_or(0)
     }
 
+    /// Get the raw edge ID set for a type (for sampling without full materialization)
+    pub fn edge_type_index_ids(&self, edge_type: &EdgeType) -> Option<&HashSet<EdgeId>> {
+        self.edge_type_index.get(edge_type)
+    }
+
     /// Get all label names in the graph
     pub fn all_labels(&self) -> Vec<&Label> {
         self.label_index.keys().collect()
@@ -2221,6 +3108,25 @@ NodeDeleted { tenant_id: _, id, labels, properties } => {
         self.edge_type_index.keys().collect()
     }
 
+    /// Every property key in use on a live node or edge, sorted.
+    ///
+    /// Exact rather than sampled: RESP clients resolve the compact result-set
+    /// encoding's property ids by position in this list (`db.propertyKeys()`).
+    pub fn property_keys(&self) -> Vec<String> {
+        let mut keys: std::collections::BTreeSet<&str> = std::collections::BTreeSet::new();
+        for versions in &self.nodes {
+            if let Some(node) = versions.last() {
+                keys.extend(node.properties.keys().map(String::as_str));
+            }
+        }
+        for (id, props) in &self.edge_properties {
+            if self.has_edge(*id) {
+                keys.extend(props.keys().map(String::as_str));
+            }
+        }
+        keys.into_iter().map(str::to_string).collect()
+    }
+
     /// Generate a schema summary for NLQ pipeline
     pub fn schema_summary(&self) -> String {
         let mut summary = String::new();
@@ -2452,6 +3358,247 @@ NodeDeleted { tenant_id: _, id, labels, properties } => {
         Ok(())
     }
 
+    // ============================================================
+    // Atomic write batches (BEGIN / COMMIT / ROLLBACK)
+    // ============================================================
+
+    /// Open a write batch: writes apply immediately, so later statements see
+    /// them, but [`rollback_write_batch`](Self::rollback_write_batch) undoes all
+    /// of them and change-feed events are held back until
+    /// [`commit_write_batch`](Self::commit_write_batch). Batches do not nest.
+    ///
+    /// Covered are the `GraphStore` write methods (node/edge create, delete,
+    /// property set/remove, label add). Stub loaders, direct mutation through
+    /// `get_node_mut`/`get_edge_properties_mut`, and index/constraint DDL are not
+    /// undone.
+    pub fn begin_write_batch(&mut self) -> GraphResult<()> {
+        if self.write_batch.is_some() {
+            return Err(GraphError::WriteBatchActive);
+        }
+        self.write_batch = Some(WriteBatch::default());
+        Ok(())
+    }
+
+    /// Whether a write batch is open
+    pub fn in_write_batch(&self) -> bool {
+        self.write_batch.is_some()
+    }
+
+    /// Number of writes the open write batch has recorded so far, 0 without
+    /// one; a later [`write_counts_since`](Self::write_counts_since) counts
+    /// the writes after it
+    pub fn write_batch_position(&self) -> usize {
+        self.write_batch.as_ref().map_or(0, |batch| batch.undo.len())
+    }
+
+    /// Count the open write batch's writes recorded after `position`
+    pub fn write_counts_since(&self, position: usize) -> WriteCounts {
+        let mut counts = WriteCounts::default();
+        let Some(batch) = &self.write_batch else {
+            return counts;
+        };
+        for entry in batch.undo.iter().skip(position) {
+            match entry {
+                UndoEntry::CreateNode { .. } => counts.nodes_created += 1,
+                UndoEntry::DeleteNode { .. } => counts.nodes_deleted += 1,
+                UndoEntry::CreateEdge(_) => counts.relationships_created += 1,
+                UndoEntry::DeleteEdge(_) => counts.relationships_deleted += 1,
+                UndoEntry::NodeProperty { .. } | UndoEntry::AddLabel { .. } | UndoEntry::EdgeProperty { .. } => {}
+            }
+        }
+        counts
+    }
+
+    /// Keep the open batch's writes, publish its change-feed events and
+    /// report which nodes and edges it changed
+    pub fn commit_write_batch(&mut self) -> GraphResult<WriteSummary> {
+        let batch = self.write_batch.take().ok_or(GraphError::NoWriteBatch)?;
+        for event in batch.changes {
+            let _ = self.change_feed.send(event);
+        }
+
+        // Touched ids in first-write order, and whether the batch created them
+        let mut nodes: Vec<(NodeId, bool)> = Vec::new();
+        let mut edges: Vec<(EdgeId, bool)> = Vec::new();
+        let mut seen_nodes = HashSet::new();
+        let mut seen_edges = HashSet::new();
+        for entry in &batch.undo {
+            match entry {
+                UndoEntry::CreateNode { id, .. } => {
+                    if seen_nodes.insert(*id) {
+                        nodes.push((*id, true));
+                    }
+                }
+                UndoEntry::NodeProperty { id, .. } | UndoEntry::AddLabel { id, .. } => {
+                    if seen_nodes.insert(*id) {
+                        nodes.push((*id, false));
+                    }
+                }
+                UndoEntry::DeleteNode { node, .. } => {
+                    if seen_nodes.insert(node.id) {
+                        nodes.push((node.id, false));
+                    }
+                }
+                UndoEntry::CreateEdge(id) => {
+                    if seen_edges.insert(*id) {
+                        edges.push((*id, true));
+                    }
+                }
+                UndoEntry::EdgeProperty { id, .. } => {
+                    if seen_edges.insert(*id) {
+                        edges.push((*id, false));
+                    }
+                }
+                UndoEntry::DeleteEdge(edge) => {
+                    if seen_edges.insert(edge.id) {
+                        edges.push((edge.id, false));
+                    }
+                }
+            }
+        }
+
+        let mut summary = WriteSummary::default();
+        for (id, created) in nodes {
+            match (created, self.has_node(id)) {
+                (true, true) => summary.nodes_created.push(id),
+                (false, true) => summary.nodes_updated.push(id),
+                (false, false) => summary.nodes_deleted.push(id),
+                (true, false) => {}
+            }
+        }
+        for (id, created) in edges {
+            match (created, self.has_edge(id)) {
+                (true, true) => summary.edges_created.push(id),
+                (false, true) => summary.edges_updated.push(id),
+                (false, false) => summary.edges_deleted.push(id),
+                (true, false) => {}
+            }
+        }
+        Ok(summary)
+    }
+
+    /// Undo every write of the open batch, newest first, and drop its events
+    pub fn rollback_write_batch(&mut self) -> GraphResult<()> {
+        let batch = self.write_batch.take().ok_or(GraphError::NoWriteBatch)?;
+        // Undo through the regular write paths so indexes, columns and the catalog
+        // follow along; a scratch batch swallows the undo writes' own bookkeeping.
+        self.write_batch = Some(WriteBatch::default());
+        for entry in batch.undo.into_iter().rev() {
+            self.undo(entry);
+        }
+        self.write_batch = None;
+        Ok(())
+    }
+
+    fn undo(&mut self, entry: UndoEntry) {
+        match entry {
+            UndoEntry::CreateNode { tenant_id, id } => {
+                let _ = self.delete_node(&tenant_id, id);
+            }
+            UndoEntry::NodeProperty { tenant_id, id, key, old: Some(value) } => {
+                let _ = self.set_node_property(&tenant_id, id, key, value);
+            }
+            UndoEntry::NodeProperty { tenant_id, id, key, old: None } => {
+                let _ = self.remove_node_property(&tenant_id, id, &key);
+            }
+            UndoEntry::AddLabel { tenant_id, id, label } => self.remove_label_from_node(&tenant_id, id, &label),
+            UndoEntry::DeleteNode { tenant_id, node } => self.restore_node(&tenant_id, node),
+            UndoEntry::CreateEdge(id) => {
+                let _ = self.delete_edge(id);
+            }
+            UndoEntry::EdgeProperty { id, key, old: Some(value) } => {
+                let _ = self.set_edge_property(id, key, value);
+            }
+            UndoEntry::EdgeProperty { id, key, old: None } => {
+                self.remove_edge_property(id, &key);
+            }
+            UndoEntry::DeleteEdge(edge) => self.restore_edge(edge),
+        }
+    }
+
+    /// Take `label` off a node, updating the label index, catalog and property indexes
+    fn remove_label_from_node(&mut self, tenant_id: &str, id: NodeId, label: &Label) {
+        self.invalidate_statistics_cache();
+        let Some(node) = self.nodes.get_mut(id.as_u64() as usize).and_then(|v| v.last_mut()) else {
+            return;
+        };
+        if !node.remove_label(label) {
+            return;
+        }
+        let properties = node.properties.clone();
+
+        if let Some(node_set) = self.label_index.get_mut(label) {
+            node_set.remove(&id);
+        }
+        self.catalog.on_label_removed(label);
+        for (key, value) in &properties {
+            self.property_index.constraint_remove(label, key, value, id);
+        }
+
+        let event = crate::graph::event::IndexEvent::LabelRemoved {
+            tenant_id: tenant_id.to_string(),
+            id,
+            label: label.clone(),
+            properties,
+        };
+
+        if let Some(sender) = &self.index_sender {
+            let _ = sender.send(event);
+        } else {
+            self.handle_index_event(event, None);
+        }
+    }
+
+    /// Put a deleted node back under its old id
+    fn restore_node(&mut self, tenant_id: &str, node: Node) {
+        self.invalidate_statistics_cache();
+        let id = node.id;
+        let idx = id.as_u64() as usize;
+        self.free_node_ids.retain(|&free| free != id.as_u64());
+
+        for (key, value) in &node.properties {
+            self.node_columns.set_property(idx, key, value.clone());
+        }
+        for label in &node.labels {
+            self.label_index.entry(label.clone()).or_default().insert(id);
+            self.catalog.on_label_added(label);
+            for (key, value) in &node.properties {
+                self.property_index.constraint_insert(label, key, value.clone(), id);
+            }
+        }
+
+        let event = crate::graph::event::IndexEvent::NodeCreated {
+            tenant_id: tenant_id.to_string(),
+            id,
+            labels: node.labels.iter().cloned().collect(),
+            properties: node.properties.clone(),
+        };
+
+        if let Some(sender) = &self.index_sender {
+            let _ = sender.send(event);
+        } else {
+            self.handle_index_event(event, None);
+        }
+
+        self.nodes[idx].push(node);
+    }
+
+    /// Put a deleted edge back under its old id
+    fn restore_edge(&mut self, edge: Edge) {
+        self.invalidate_statistics_cache();
+        let idx = edge.id.as_u64() as usize;
+        self.free_edge_ids.retain(|&free| free != edge.id.as_u64());
+
+        for (key, value) in &edge.properties {
+            self.edge_columns.set_property(idx, key, value.clone());
+        }
+        let src_labels: Vec<Label> = self.get_node(edge.source).map(|n| n.labels.iter().cloned().collect()).unwrap_or_default();
+        let tgt_labels: Vec<Label> = self.get_node(edge.target).map(|n| n.labels.iter().cloned().collect()).unwrap_or_default();
+        self.catalog.on_edge_created(edge.source, &src_labels, &edge.edge_type, edge.target, &tgt_labels);
+
+        let _ = self.insert_recovered_edge(edge);
+    }
+
     // ============================================================
     // MVCC Version Garbage Collection
     // ============================================================
@@ -2544,10 +3691,15 @@ NodeDeleted { tenant_id: _, id, labels, properties } => {
         self.incoming.clear();
         self.frozen_outgoing.clear();
         self.frozen_incoming.clear();
+        self.frozen_deleted_outgoing.clear();
+        self.frozen_deleted_incoming.clear();
+        self.self_loops.clear();
         self.free_node_ids.clear();
         self.free_edge_ids.clear();
         self.label_index.clear();
         self.edge_type_index.clear();
+        self.outgoing_by_type.clear();
+        self.edge_type_counts.clear();
         self.vector_index = Arc::new(VectorIndexManager::new());
         self.property_index = Arc::new(IndexManager::new());
         self.node_columns = ColumnStore::new();
@@ -2557,6 +3709,100 @@ NodeDeleted { tenant_id: _, id, labels, properties } => {
         self.catalog.clear();
     }
 
+    // ============================================================
+    // Consistency checks
+    // ============================================================
+
+    /// Check that edges and adjacency lists agree with the node set.
+    ///
+    /// Reports edges whose source or target node is gone (e.g. after a node was
+    /// deleted through the persistence layer without its edges) and write-buffer
+    /// adjacency entries naming a missing edge or an edge with other endpoints.
+    /// The frozen CSR tier is immutable and skips deleted edges on read, so its
+    /// stale entries are expected and not reported. Results are ordered by edge
+    /// id, then by node id.
+    pub fn validate(&self) -> Vec<Inconsistency> {
+        let node_exists = |id: NodeId| self.nodes.get(id.as_u64() as usize).is_some_and(|v| !v.is_empty());
+        let mut found = Vec::new();
+
+        for (idx, &(source, target)) in self.edge_endpoints.iter().enumerate() {
+            if source.as_u64() == 0 && target.as_u64() == 0 {
+                continue;
+            }
+            let edge = EdgeId::new(idx as u64);
+            if !node_exists(source) {
+                found.push(Inconsistency::MissingSource { edge, source });
+            }
+            if !node_exists(target) {
+                found.push(Inconsistency::MissingTarget { edge, target });
+            }
+        }
+
+        for (idx, entries) in self.outgoing.iter().enumerate() {
+            let node = NodeId::new(idx as u64);
+            for &(neighbor, edge) in entries {
+                if self.get_edge_endpoints(edge) != Some((node, neighbor)) {
+                    found.push(Inconsistency::DanglingOutgoing { node, edge });
+                }
+            }
+        }
+        for (idx, entries) in self.incoming.iter().enumerate() {
+            let node = NodeId::new(idx as u64);
+            for &(neighbor, edge) in entries {
+                if self.get_edge_endpoints(edge) != Some((neighbor, node)) {
+                    found.push(Inconsistency::DanglingIncoming { node, edge });
+                }
+            }
+        }
+
+        found
+    }
+
+    /// Fix everything [`validate`](Self::validate) reports: edges with a missing
+    /// endpoint are deleted and stale adjacency entries dropped. Returns the
+    /// inconsistencies found; `validate()` is empty afterwards.
+    pub fn repair(&mut self) -> Vec<Inconsistency> {
+        let found = self.validate();
+        if found.is_empty() {
+            return found;
+        }
+
+        let mut orphaned: Vec<EdgeId> = found
+            .iter()
+            .filter_map(|i| match i {
+                Inconsistency::MissingSource { edge, .. } | Inconsistency::MissingTarget { edge, .. } => Some(*edge),
+                _ => None,
+            })
+            .collect();
+        orphaned.dedup();
+        for edge in orphaned {
+            if self.delete_edge(edge).is_err() {
+                // No resolvable edge type: clear the endpoints so the pass below drops its entries
+                self.edge_endpoints[edge.as_u64() as usize] = (NodeId::new(0), NodeId::new(0));
+            }
+        }
+
+        let endpoints = &self.edge_endpoints;
+        let live = |edge: EdgeId| {
+            endpoints.get(edge.as_u64() as usize).copied().filter(|&(s, t)| s.as_u64() != 0 || t.as_u64() != 0)
+        };
+        for (idx, entries) in self.outgoing.iter_mut().enumerate() {
+            let node = NodeId::new(idx as u64);
+            entries.retain(|&(neighbor, edge)| live(edge) == Some((node, neighbor)));
+        }
+        for (idx, entries) in self.incoming.iter_mut().enumerate() {
+            let node = NodeId::new(idx as u64);
+            entries.retain(|&(neighbor, edge)| live(edge) == Some((neighbor, node)));
+        }
+        self.outgoing_by_type.retain(|_, edges| {
+            edges.retain(|&edge| live(edge).is_some());
+            !edges.is_empty()
+        });
+        self.invalidate_statistics_cache();
+
+        found
+    }
+
     // ============================================================
     // Event Handling
     // ============================================================
@@ -2582,15 +3828,25 @@ NodeDeleted { tenant_id: _, id, labels, properties } => {
                         self.property_index.index_remove(label, &key, &value, id);
                     }
                 }
+                for label in &labels {
+                    self.vector_index.remove_node(label.as_str(), id);
+                }
             }
             PropertySet { tenant_id: _, id, labels, key, old_value, new_value } => {
+                if old_value.is_some() || new_value.is_null() {
+                    for label in &labels {
+                        self.vector_index.remove_vector(label.as_str(), &key, id);
+                    }
+                }
                 if let Some(old) = old_value {
                     for label in &labels {
                         self.property_index.index_remove(label, &key, &old, id);
                     }
                 }
-                for label in &labels {
-                    self.property_index.index_insert(label, &key, new_value.clone(), id);
+                if !new_value.is_null() {
+                    for label in &labels {
+                        self.property_index.index_insert(label, &key, new_value.clone(), id);
+                    }
                 }
                 if let PropertyValue::Vector(vec) = &new_value {
                     for label in &labels {
@@ -2598,15 +3854,210 @@ NodeDeleted { tenant_id: _, id, labels, properties } => {
                     }
                 }
             }
-            LabelAdded { tenant_id: _, id, label, properties } => {
-                for (key, value) in properties {
-                    if let PropertyValue::Vector(vec) = &value {
-                        let _ = self.vector_index.add_vector(label.as_str(), &key, id, vec);
-                    }
-                    self.property_index.index_insert(&label, &key, value.clone(), id);
+            LabelAdded { tenant_id: _, id, label, properties } => {
+                for (key, value) in properties {
+                    if let PropertyValue::Vector(vec) = &value {
+                        let _ = self.vector_index.add_vector(label.as_str(), &key, id, vec);
+                    }
+                    self.property_index.index_insert(&label, &key, value.clone(), id);
+                }
+            }
+            LabelRemoved { tenant_id: _, id, label, properties } => {
+                for (key, value) in &properties {
+                    self.property_index.index_remove(&label, key, value, id);
+                }
+                self.vector_index.remove_node(label.as_str(), id);
+            }
+        }
+    }
+
+    // ============================================================
+    // Property constraints
+    // ============================================================
+
+    /// Create a property constraint on `label`, validating existing nodes first.
+    ///
+    /// A unique constraint is backed by a property index on (label, property).
+    /// Once created, constraints are enforced by `set_node_property`,
+    /// `remove_node_property` and (unique only) `add_label_to_node`; see
+    /// [`check_node_constraints`](Self::check_node_constraints) for new nodes.
+    /// Creating an existing constraint again is a no-op.
+    pub fn create_constraint(&mut self, label: Label, property: String, kind: ConstraintKind) -> GraphResult<()> {
+        let mut seen: HashMap<PropertyValue, NodeId> = HashMap::new();
+        for node in self.get_nodes_by_label(&label) {
+            let value = match node.get_property(&property) {
+                Some(v) => v.clone(),
+                None => self.node_columns.get_property(node.id.as_u64() as usize, &property),
+            };
+            match kind {
+                ConstraintKind::NotNull if value.is_null() => {
+                    return Err(GraphError::ConstraintViolation(format!(
+                        "cannot create {}: node {} has no value",
+                        constraint_name(&label, &property, kind), node.id
+                    )));
+                }
+                ConstraintKind::Unique if !value.is_null() => {
+                    if let Some(other) = seen.insert(value.clone(), node.id) {
+                        return Err(GraphError::ConstraintViolation(format!(
+                            "cannot create {}: nodes {} and {} share value {}",
+                            constraint_name(&label, &property, kind), other, node.id, value
+                        )));
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        match kind {
+            ConstraintKind::Unique => {
+                self.property_index.create_unique_constraint(label.clone(), property.clone());
+                for (value, node_id) in seen {
+                    self.property_index.index_insert(&label, &property, value.clone(), node_id);
+                    self.property_index.constraint_insert(&label, &property, value, node_id);
+                }
+            }
+            ConstraintKind::NotNull => self.property_index.create_not_null_constraint(label, property),
+        }
+        Ok(())
+    }
+
+    /// Drop a property constraint. Returns false if it did not exist.
+    pub fn drop_constraint(&mut self, label: &Label, property: &str, kind: ConstraintKind) -> bool {
+        self.property_index.drop_constraint(label, property, kind)
+    }
+
+    /// Check a complete node against every constraint on its labels.
+    ///
+    /// `node_id` is the node being validated (excluded from uniqueness checks),
+    /// or `None` for a node that has not been created yet.
+    pub fn check_node_constraints(
+        &self,
+        node_id: Option<NodeId>,
+        labels: &[Label],
+        properties: &PropertyMap,
+    ) -> GraphResult<()> {
+        if !self.property_index.has_constraints() {
+            return Ok(());
+        }
+        for label in labels {
+            for (property, kind) in self.property_index.constraints_on(label) {
+                let value = properties.get(&property).filter(|v| !v.is_null());
+                match (kind, value) {
+                    (ConstraintKind::NotNull, None) => {
+                        return Err(GraphError::ConstraintViolation(format!(
+                            "{} but {} has no value",
+                            constraint_name(label, &property, kind),
+                            node_id.map_or_else(|| "the new node".to_string(), |id| format!("node {}", id))
+                        )));
+                    }
+                    (ConstraintKind::Unique, Some(value)) => {
+                        let holder = self.property_index.unique_holders(label, &property, value)
+                            .into_iter()
+                            .find(|&id| Some(id) != node_id);
+                        if let Some(other) = holder {
+                            return Err(unique_violation(label, &property, value, other));
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Remove a property from a node, keeping indexes and constraints in sync.
+    /// Returns the removed value, if any.
+    pub fn remove_node_property(
+        &mut self,
+        tenant_id: &str,
+        node_id: NodeId,
+        key: &str,
+    ) -> GraphResult<Option<PropertyValue>> {
+        self.invalidate_statistics_cache();
+        let idx = node_id.as_u64() as usize;
+        let node = self.nodes.get(idx).and_then(|v| v.last()).ok_or(GraphError::NodeNotFound(node_id))?;
+        if !node.properties.contains_key(key) {
+            // Stub-loaded nodes keep their properties in the column store only
+            self.node_columns.remove_property(idx, key);
+            return Ok(None);
+        }
+        self.check_property_write(node_id, &node.labels, key, &PropertyValue::Null)?;
+
+        self.node_columns.remove_property(idx, key);
+        let node = self.nodes[idx].last_mut().unwrap();
+        let old_value = node.remove_property(key);
+        let labels: Vec<Label> = node.labels.iter().cloned().collect();
+
+        if let Some(old) = &old_value {
+            for label in &labels {
+                self.property_index.constraint_remove(label, key, old, node_id);
+            }
+        }
+
+        self.publish_change(|| ChangeEvent::NodeUpdated {
+            tenant_id: tenant_id.to_string(),
+            id: node_id,
+            key: key.to_string(),
+            old_value: old_value.clone(),
+            new_value: PropertyValue::Null,
+        });
+
+        self.record_undo(|| UndoEntry::NodeProperty {
+            tenant_id: tenant_id.to_string(),
+            id: node_id,
+            key: key.to_string(),
+            old: old_value.clone(),
+        });
+
+        let event = crate::graph::event::IndexEvent::PropertySet {
+            tenant_id: tenant_id.to_string(),
+            id: node_id,
+            labels,
+            key: key.to_string(),
+            old_value: old_value.clone(),
+            new_value: PropertyValue::Null,
+        };
+
+        if let Some(sender) = &self.index_sender {
+            let _ = sender.send(event);
+        } else {
+            self.handle_index_event(event, None);
+        }
+
+        Ok(old_value)
+    }
+
+    /// Check that setting `key` to `value` on a node with `labels` keeps constraints
+    fn check_property_write<'a>(
+        &self,
+        node_id: NodeId,
+        labels: impl IntoIterator<Item = &'a Label>,
+        key: &str,
+        value: &PropertyValue,
+    ) -> GraphResult<()> {
+        for label in labels {
+            if value.is_null() {
+                if self.property_index.has_not_null_constraint(label, key) {
+                    return Err(GraphError::ConstraintViolation(format!(
+                        "{} but node {} would lose its value",
+                        constraint_name(label, key, ConstraintKind::NotNull), node_id
+                    )));
                 }
+            } else {
+                self.check_unique(node_id, label, key, value)?;
             }
         }
+        Ok(())
+    }
+
+    fn check_unique(&self, node_id: NodeId, label: &Label, key: &str, value: &PropertyValue) -> GraphResult<()> {
+        if value.is_null() {
+            return Ok(());
+        }
+        match self.property_index.unique_holders(label, key, value).into_iter().find(|&id| id != node_id) {
+            Some(other) => Err(unique_violation(label, key, value, other)),
+            None => Ok(()),
+        }
     }
 
     // ============================================================
@@ -2614,6 +4065,9 @@ NodeDeleted { tenant_id: _, id, labels, properties } => {
     // ============================================================
 
     /// Create a vector index for a specific label and property
+    ///
+    /// Uses the default [`HnswConfig`] (`m = 16`, `ef_construction = 200`,
+    /// `ef_search = 64`); see [`create_vector_index_with_config`](Self::create_vector_index_with_config).
     pub fn create_vector_index(
         &self,
         label: &str,
@@ -2624,6 +4078,18 @@ NodeDeleted { tenant_id: _, id, labels, properties } => {
         self.vector_index.create_index(label, property_key, dimensions, metric)
     }
 
+    /// Create a vector index with explicit HNSW parameters
+    pub fn create_vector_index_with_config(
+        &self,
+        label: &str,
+        property_key: &str,
+        dimensions: usize,
+        metric: DistanceMetric,
+        config: HnswConfig,
+    ) -> VectorResult<()> {
+        self.vector_index.create_index_with_config(label, property_key, dimensions, metric, config)
+    }
+
     /// Search for nearest neighbors using a vector index
     pub fn vector_search(
         &self,
@@ -2635,6 +4101,58 @@ NodeDeleted { tenant_id: _, id, labels, properties } => {
         self.vector_index.search(label, property_key, query, k)
     }
 
+    /// Search for nearest neighbors, overriding the index's `ef_search` when given
+    ///
+    /// Raising `ef_search` trades query latency for recall.
+    pub fn vector_search_with_ef(
+        &self,
+        label: &str,
+        property_key: &str,
+        query: &[f32],
+        k: usize,
+        ef_search: Option<usize>,
+    ) -> VectorResult<Vec<(NodeId, f32)>> {
+        self.vector_index.search_with_ef(label, property_key, query, k, ef_search)
+    }
+
+    /// Exact nearest neighbors by linear scan of a vector index
+    ///
+    /// Ground truth for measuring the recall of [`vector_search`](Self::vector_search).
+    pub fn vector_search_exact(
+        &self,
+        label: &str,
+        property_key: &str,
+        query: &[f32],
+        k: usize,
+    ) -> VectorResult<Vec<(NodeId, f32)>> {
+        self.vector_index.exact_search(label, property_key, query, k)
+    }
+
+    /// Search for nearest neighbors among nodes matching a property filter
+    ///
+    /// Properties are read from the column store first, then the node itself,
+    /// so compacted nodes filter correctly.
+    pub fn vector_search_filtered(
+        &self,
+        label: &str,
+        property_key: &str,
+        query: &[f32],
+        k: usize,
+        filter: &VectorFilter,
+    ) -> VectorResult<Vec<(NodeId, f32)>> {
+        self.vector_index.search_filtered(label, property_key, query, k, |id| {
+            filter.matches(&|key: &str| {
+                let value = self.node_columns.get_property(id.as_u64() as usize, key);
+                if !value.is_null() {
+                    return value;
+                }
+                self.get_node(id)
+                    .and_then(|node| node.get_property(key).cloned())
+                    .unwrap_or(PropertyValue::Null)
+            })
+        })
+    }
+
     // ============================================================
     // Recovery methods - used to rebuild graph from persisted data
     // ============================================================
@@ -2710,6 +4228,7 @@ NodeDeleted { tenant_id: _, id, labels, properties } => {
             self.edge_type_ids.resize(idx + 1, Self::EDGE_TYPE_UNSET);
         }
         self.edge_type_ids[idx] = type_id;
+        self.track_typed_edge(source, target, type_id, edge_id);
         if !edge.properties.is_empty() {
             self.edge_properties.insert(edge_id, edge.properties);
         }
@@ -2739,6 +4258,35 @@ impl Default for GraphStore {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_write_counts_are_per_write_not_net() {
+        let mut store = GraphStore::new();
+        let a = store.create_node("Person");
+        store.begin_write_batch().unwrap();
+        let position = store.write_batch_position();
+
+        // Create one node and delete another: the node count is unchanged
+        let b = store.create_node("Person");
+        store.create_edge(a, b, "KNOWS").unwrap();
+        store.set_node_property("default", b, "name", "Bob").unwrap();
+        store.delete_node("default", a).unwrap();
+        assert_eq!(store.node_count(), 1);
+
+        let counts = store.write_counts_since(position);
+        assert_eq!(counts, WriteCounts { nodes_created: 1, nodes_deleted: 1, relationships_created: 1, relationships_deleted: 1 });
+        // Writes before the position are not counted
+        let position = store.write_batch_position();
+        store.set_node_property("default", b, "age", 30).unwrap();
+        assert_eq!(store.write_counts_since(position), WriteCounts::default());
+
+        // The edge was created and deleted within the batch, so it is not reported
+        let written = store.commit_write_batch().unwrap();
+        assert_eq!(written.nodes_created, vec![b]);
+        assert_eq!(written.nodes_deleted, vec![a]);
+        assert!(written.edges_created.is_empty() && written.edges_deleted.is_empty());
+        assert_eq!(store.write_counts_since(0), WriteCounts::default());
+    }
+
     #[test]
     fn test_create_and_get_node() {
         let mut store = GraphStore::new();
@@ -2859,6 +4407,214 @@ mod tests {
         assert_eq!(follows_edges.len(), 1);
     }
 
+    #[test]
+    fn test_cardinalities_track_deletes() {
+        let mut store = GraphStore::new();
+        let alice = store.create_node("Person");
+        store.add_label_to_node("default", alice, "Employee").unwrap();
+        let bob = store.create_node("Person");
+        let acme = store.create_node("Company");
+        let knows = store.create_edge(alice, bob, "KNOWS").unwrap();
+        store.create_edge(alice, acme, "WORKS_AT").unwrap();
+        store.create_edge(bob, acme, "WORKS_AT").unwrap();
+        store.create_edge_stub(bob, alice, "KNOWS").unwrap();
+
+        let labels = |store: &GraphStore, expected: &[(&str, usize)]| {
+            let want: HashMap<Label, usize> = expected.iter().map(|&(l, n)| (Label::new(l), n)).collect();
+            assert_eq!(store.label_cardinalities(), want);
+        };
+        let types = |store: &GraphStore, expected: &[(&str, usize)]| {
+            let want: HashMap<EdgeType, usize> = expected.iter().map(|&(t, n)| (EdgeType::new(t), n)).collect();
+            assert_eq!(store.edge_type_cardinalities(), want);
+        };
+
+        labels(&store, &[("Person", 2), ("Employee", 1), ("Company", 1)]);
+        types(&store, &[("KNOWS", 2), ("WORKS_AT", 2)]);
+
+        store.delete_edge(knows).unwrap();
+        types(&store, &[("KNOWS", 1), ("WORKS_AT", 2)]);
+
+        // Deleting a node drops its labels and every incident edge
+        store.delete_node("default", alice).unwrap();
+        labels(&store, &[("Person", 1), ("Company", 1)]);
+        types(&store, &[("WORKS_AT", 1)]);
+
+        store.delete_node("default", acme).unwrap();
+        labels(&store, &[("Person", 1)]);
+        types(&store, &[]);
+    }
+
+    #[test]
+    fn test_stats() {
+        let mut store = GraphStore::new();
+        let a = store.create_node("Person");
+        let b = store.create_node("Person");
+        let c = store.create_node("Company");
+        store.create_edge(a, b, "KNOWS").unwrap();
+        store.create_edge(a, c, "WORKS_AT").unwrap();
+        store.create_edge(b, c, "WORKS_AT").unwrap();
+        store.property_index.create_index(Label::new("Person"), "name".to_string());
+
+        let stats = store.stats();
+        assert_eq!((stats.nodes, stats.edges), (3, 3));
+        let names = |entries: &[NameCount]| -> Vec<(String, u64)> {
+            entries.iter().map(|e| (e.name.clone(), e.count)).collect()
+        };
+        assert_eq!(names(&stats.labels), vec![("Person".to_string(), 2), ("Company".to_string(), 1)]);
+        assert_eq!(names(&stats.edge_types), vec![("WORKS_AT".to_string(), 2), ("KNOWS".to_string(), 1)]);
+        assert_eq!(stats.indexes, vec![IndexedProperty { label: "Person".to_string(), property: "name".to_string() }]);
+        assert!(stats.vector_indexes.is_empty());
+    }
+
+    #[test]
+    fn test_neighbors_hop_limited() {
+        let mut store = GraphStore::new();
+        let people: Vec<NodeId> = (0..4).map(|_| store.create_node("Person")).collect();
+        let chain: Vec<EdgeId> = people
+            .windows(2)
+            .map(|pair| store.create_edge(pair[0], pair[1], "KNOWS").unwrap())
+            .collect();
+        let acme = store.create_node("Company");
+        store.create_edge(people[0], acme, "WORKS_AT").unwrap();
+        let knows = [EdgeType::new("KNOWS")];
+        let out = EdgeDirection::Outgoing;
+
+        assert_eq!(store.neighbors(people[0], 1, Some(&knows[..]), out), vec![people[1]]);
+        assert_eq!(store.neighbors(people[0], 2, Some(&knows[..]), out), vec![people[1], people[2]]);
+        assert_eq!(store.neighbors(people[0], 10, Some(&knows[..]), out), people[1..].to_vec());
+        assert_eq!(store.neighbors(people[0], 1, None, out), vec![people[1], acme]);
+        assert_eq!(store.neighbors(people[0], 1, Some(&[EdgeType::new("LIKES")][..]), out), vec![]);
+        assert!(store.neighbors(people[0], 0, None, out).is_empty());
+
+        assert_eq!(store.neighbors(people[2], 2, Some(&knows[..]), EdgeDirection::Incoming), vec![people[1], people[0]]);
+        let both: HashSet<NodeId> = store.neighbors(people[1], 1, None, EdgeDirection::Both).into_iter().collect();
+        assert_eq!(both, HashSet::from([people[0], people[2]]));
+
+        // Compacted adjacency and deleted edges
+        store.compact_adjacency();
+        store.delete_edge(chain[1]).unwrap();
+        assert_eq!(store.neighbors(people[0], 3, Some(&knows[..]), out), vec![people[1]]);
+    }
+
+    #[test]
+    fn test_schema_info() {
+        let mut store = GraphStore::new();
+        let alice = store.create_node("Person");
+        store.set_node_property("default", alice, "name", "Alice").unwrap();
+        store.set_node_property("default", alice, "age", 30i64).unwrap();
+        let bob = store.create_node("Person");
+        store.set_node_property("default", bob, "name", "Bob").unwrap();
+        store.set_node_property("default", bob, "age", 41.5).unwrap();
+        let acme = store.create_node("Company");
+        store.create_edge(alice, bob, "KNOWS").unwrap();
+        store.create_edge(alice, acme, "WORKS_AT").unwrap();
+
+        let schema = store.schema_info();
+        let labels: Vec<(&str, u64)> = schema.labels.iter().map(|l| (l.label.as_str(), l.count)).collect();
+        assert_eq!(labels, vec![("Company", 1), ("Person", 2)]);
+        let person = &schema.labels[1].properties;
+        assert_eq!(person["name"], "String");
+        assert_eq!(person["age"], "Mixed");
+
+        assert_eq!(schema.edge_types, vec![
+            EdgeTypeSchema {
+                edge_type: "KNOWS".to_string(),
+                count: 1,
+                source_labels: vec!["Person".to_string()],
+                target_labels: vec!["Person".to_string()],
+            },
+            EdgeTypeSchema {
+                edge_type: "WORKS_AT".to_string(),
+                count: 1,
+                source_labels: vec!["Person".to_string()],
+                target_labels: vec!["Company".to_string()],
+            },
+        ]);
+    }
+
+    #[test]
+    fn test_subgraph() {
+        let mut store = GraphStore::new();
+        let mut docs = Vec::new();
+        for i in 0..6 {
+            let id = store.create_node("Document");
+            store.set_node_property("default", id, "title", format!("Doc{}", i)).unwrap();
+            docs.push(id);
+        }
+        let author = store.create_node("Person");
+        for i in 0..5 {
+            let mut props = PropertyMap::new();
+            props.insert("weight".to_string(), PropertyValue::Integer(i));
+            store.create_edge_with_properties(docs[i as usize], docs[i as usize + 1], "REFERENCES", props).unwrap();
+        }
+        store.create_edge(docs[0], docs[2], "SUPERSEDES").unwrap();
+        for &doc in &docs {
+            store.create_edge(author, doc, "AUTHORED").unwrap();
+        }
+        store.create_edge(docs[3], author, "REFERENCES").unwrap();
+
+        let document = Label::new("Document");
+        let references = EdgeType::new("REFERENCES");
+        let sub = store.subgraph(std::slice::from_ref(&document), std::slice::from_ref(&references));
+
+        let expected_edges = store
+            .get_edges_by_type(&references)
+            .iter()
+            .filter(|e| {
+                store.get_node(e.source).is_some_and(|n| n.has_label(&document))
+                    && store.get_node(e.target).is_some_and(|n| n.has_label(&document))
+            })
+            .count();
+        assert_eq!(sub.node_count(), store.get_nodes_by_label(&document).len());
+        assert_eq!(sub.edge_count(), expected_edges);
+        assert_eq!(sub.edge_count(), 5);
+        assert!(sub.get_nodes_by_label(&Label::new("Person")).is_empty());
+
+        let first = sub
+            .get_nodes_by_label(&document)
+            .into_iter()
+            .find(|n| n.get_property("title") == Some(&PropertyValue::String("Doc0".to_string())))
+            .unwrap()
+            .id;
+        let out = sub.get_outgoing_edges_of_type(first, &references);
+        assert_eq!(out.len(), 1);
+        assert_eq!(out[0].get_property("weight"), Some(&PropertyValue::Integer(0)));
+    }
+
+    #[test]
+    fn test_outgoing_edges_of_type() {
+        let mut store = GraphStore::new();
+        let hub = store.create_node("Person");
+        let a = store.create_node("Person");
+        let b = store.create_node("Person");
+        let c = store.create_node("Person");
+        let knows = EdgeType::new("KNOWS");
+
+        store.create_edge(hub, a, "KNOWS").unwrap();
+        store.create_edge(hub, b, "FOLLOWS").unwrap();
+        store.create_edge_stub(hub, c, "KNOWS").unwrap();
+        store.create_edge(a, hub, "KNOWS").unwrap();
+
+        let targets = |store: &GraphStore| -> Vec<NodeId> {
+            store.get_outgoing_edges_of_type(hub, &knows).iter().map(|e| e.target).collect()
+        };
+        assert_eq!(targets(&store), vec![a, c]);
+        assert_eq!(store.outgoing_degree_for_type(hub, &knows), 2);
+        assert!(store.outgoing_edge_ids_of_type(hub, &EdgeType::new("NONE")).is_empty());
+
+        // Survives compaction into the frozen tier
+        store.compact_adjacency();
+        assert_eq!(targets(&store), vec![a, c]);
+
+        let ab = store.outgoing_edge_ids_of_type(hub, &knows)[0];
+        store.delete_edge(ab).unwrap();
+        assert_eq!(targets(&store), vec![c]);
+
+        store.delete_node("default", c).unwrap();
+        assert!(targets(&store).is_empty());
+        assert_eq!(store.outgoing_degree_for_type(hub, &EdgeType::new("FOLLOWS")), 1);
+    }
+
     #[test]
     fn test_delete_node() {
         let mut store = GraphStore::new();
@@ -2948,6 +4704,133 @@ mod tests {
         assert!(node.has_label(&Label::new("Employee")));
     }
 
+    #[test]
+    fn test_change_feed_emits_writes() {
+        use tokio::sync::broadcast::error::TryRecvError;
+
+        let mut store = GraphStore::new();
+        let before = store.create_node("Person");
+        let mut changes = store.subscribe_changes();
+
+        let alice = store.create_node("Person");
+        store.set_node_property("default", alice, "name", "Alice").unwrap();
+        let edge = store.create_edge(alice, before, "KNOWS").unwrap();
+        store.delete_edge(edge).unwrap();
+
+        match changes.try_recv().unwrap() {
+            ChangeEvent::NodeCreated { id, labels, .. } => {
+                assert_eq!(id, alice);
+                assert_eq!(labels, vec![Label::new("Person")]);
+            }
+            other => panic!("expected NodeCreated, got {:?}", other),
+        }
+        assert!(matches!(changes.try_recv().unwrap(),
+            ChangeEvent::NodeUpdated { id, key, old_value: None, new_value: PropertyValue::String(v), .. }
+                if id == alice && key == "name" && v == "Alice"));
+        assert!(matches!(changes.try_recv().unwrap(),
+            ChangeEvent::EdgeCreated { id, source, target, .. } if id == edge && source == alice && target == before));
+        assert!(matches!(changes.try_recv().unwrap(), ChangeEvent::EdgeDeleted { id, .. } if id == edge));
+        assert_eq!(changes.try_recv().unwrap_err(), TryRecvError::Empty);
+
+        // A subscriber that falls behind is told how many events it missed
+        for _ in 0..GraphStore::CHANGE_FEED_CAPACITY + 10 {
+            store.create_node("Bulk");
+        }
+        assert_eq!(changes.try_recv().unwrap_err(), TryRecvError::Lagged(10));
+        assert!(matches!(changes.try_recv().unwrap(), ChangeEvent::NodeCreated { .. }));
+    }
+
+    #[test]
+    fn test_merge_node_is_idempotent() {
+        let mut store = GraphStore::new();
+        let mut key = PropertyMap::new();
+        key.insert("code".to_string(), PropertyValue::String("IN".into()));
+        key.insert("kind".to_string(), PropertyValue::String("country".into()));
+
+        // Without an index the label is scanned
+        let (first, created) = store.merge_node("Place", &key);
+        assert!(created);
+        let (second, created) = store.merge_node("Place", &key);
+        assert!(!created);
+        assert_eq!(first, second);
+        assert_eq!(store.label_node_count(&Label::new("Place")), 1);
+
+        // A partial match is a different node
+        let mut other = key.clone();
+        other.insert("kind".to_string(), PropertyValue::String("city".into()));
+        let (third, created) = store.merge_node("Place", &other);
+        assert!(created);
+        assert_ne!(third, first);
+
+        // With an index on a key the match is an index lookup
+        store.property_index.create_index(Label::new("Place"), "code".to_string());
+        for id in [first, third] {
+            store.property_index.index_insert(&Label::new("Place"), "code", PropertyValue::String("IN".into()), id);
+        }
+        let lookups = store.property_index.lookup_count();
+        assert_eq!(store.merge_node("Place", &key), (first, false));
+        assert_eq!(store.merge_node("Place", &other), (third, false));
+        assert_eq!(store.property_index.lookup_count() - lookups, 2);
+        assert_eq!(store.label_node_count(&Label::new("Place")), 2);
+    }
+
+    #[test]
+    fn test_merge_node_scans_under_async_indexing() {
+        let (mut store, _rx) = GraphStore::with_async_indexing();
+        store.property_index.create_index(Label::new("Place"), "code".to_string());
+        let mut key = PropertyMap::new();
+        key.insert("code".to_string(), PropertyValue::String("IN".into()));
+
+        // Nothing drains the indexer, so the index never sees the first node
+        let (first, created) = store.merge_node("Place", &key);
+        assert!(created);
+        let lookups = store.property_index.lookup_count();
+        assert_eq!(store.merge_node("Place", &key), (first, false));
+        assert_eq!(store.property_index.lookup_count(), lookups);
+        assert_eq!(store.label_node_count(&Label::new("Place")), 1);
+    }
+
+    #[test]
+    fn test_degree_matches_edge_lists() {
+        let mut store = GraphStore::new();
+        let hub = store.create_node("Hub");
+        let spokes: Vec<NodeId> = (0..5).map(|_| store.create_node("Spoke")).collect();
+        for &spoke in &spokes {
+            store.create_edge(hub, spoke, "LINKS").unwrap();
+        }
+        store.create_edge(spokes[0], hub, "LINKS").unwrap();
+        store.create_edge(hub, hub, "SELF").unwrap();
+
+        // Move the adjacency into a frozen segment, then mutate both tiers
+        store.compact_adjacency();
+        let frozen_edge = store.get_outgoing_edges(hub)
+            .into_iter()
+            .find(|e| e.edge_type.as_str() == "LINKS")
+            .unwrap()
+            .id;
+        store.delete_edge(frozen_edge).unwrap();
+        store.create_edge(spokes[1], hub, "LINKS").unwrap();
+        let buffered = store.create_edge(hub, spokes[2], "LINKS").unwrap();
+        store.delete_edge(buffered).unwrap();
+
+        for id in std::iter::once(hub).chain(spokes.iter().copied()) {
+            let out = store.get_outgoing_edges(id).len();
+            let inc = store.get_incoming_edges(id).len();
+            assert_eq!(store.out_degree(id), out);
+            assert_eq!(store.in_degree(id), inc);
+            if id != hub {
+                assert_eq!(store.degree(id), out + inc);
+            }
+        }
+        assert_eq!(store.out_degree(hub), 5);
+        assert_eq!(store.in_degree(hub), 3);
+        // The self-loop counts once, as in size((n)--())
+        assert_eq!(store.degree(hub), 7);
+        // A frozen edge's id is not recycled while its stale entry remains
+        assert!(!store.has_edge(frozen_edge));
+        assert_eq!(store.degree(NodeId::new(999)), 0);
+    }
+
     #[test]
     fn test_add_label_to_nonexistent_node() {
         let mut store = GraphStore::new();
@@ -3489,7 +5372,7 @@ mod tests {
         assert!(result.is_ok());
 
         // Creating a second index with different label should also succeed
-        let result2 = store.create_vector_index("Document", "vec", 256, crate::vector::DistanceMetric::L2);
+        let result2 = store.create_vector_index("Document", "vec", 256, crate::vector::DistanceMetric::Euclidean);
         assert!(result2.is_ok());
     }
 
@@ -3681,6 +5564,95 @@ mod tests {
         assert_eq!(results[0].0, n1);
     }
 
+    #[test]
+    fn test_delete_node_removes_embedding() {
+        let mut store = GraphStore::new();
+        store.create_vector_index("Document", "embedding", 4, crate::vector::DistanceMetric::Cosine).unwrap();
+
+        let mut ids = Vec::new();
+        for v in [[1.0, 0.0, 0.0, 0.0], [0.9, 0.1, 0.0, 0.0], [0.0, 1.0, 0.0, 0.0]] {
+            let mut props = PropertyMap::new();
+            props.insert("embedding".to_string(), PropertyValue::Vector(v.to_vec()));
+            ids.push(store.create_node_with_properties("default", vec![Label::new("Document")], props));
+        }
+        let results = store.vector_search("Document", "embedding", &[1.0, 0.0, 0.0, 0.0], 1).unwrap();
+        assert_eq!(results[0].0, ids[0]);
+
+        store.delete_node("default", ids[0]).unwrap();
+
+        let results = store.vector_search("Document", "embedding", &[1.0, 0.0, 0.0, 0.0], 3).unwrap();
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].0, ids[1]);
+        assert!(results.iter().all(|(id, _)| *id != ids[0]));
+    }
+
+    #[test]
+    fn test_overwrite_vector_replaces_embedding() {
+        let mut store = GraphStore::new();
+        store.create_vector_index("Document", "embedding", 4, crate::vector::DistanceMetric::Cosine).unwrap();
+
+        let mut ids = Vec::new();
+        for v in [[1.0, 0.0, 0.0, 0.0], [0.0, 1.0, 0.0, 0.0]] {
+            let mut props = PropertyMap::new();
+            props.insert("embedding".to_string(), PropertyValue::Vector(v.to_vec()));
+            ids.push(store.create_node_with_properties("default", vec![Label::new("Document")], props));
+        }
+        store.set_node_property("default", ids[0], "embedding", PropertyValue::Vector(vec![0.0, 0.0, 1.0, 0.0])).unwrap();
+
+        // The old vector no longer matches, and the node is listed once
+        let results = store.vector_search("Document", "embedding", &[1.0, 0.0, 0.0, 0.0], 3).unwrap();
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].0, ids[1]);
+        let results = store.vector_search("Document", "embedding", &[0.0, 0.0, 1.0, 0.0], 1).unwrap();
+        assert_eq!(results[0].0, ids[0]);
+        assert!(results[0].1 < 1e-6);
+    }
+
+    #[test]
+    fn test_null_vector_removes_embedding() {
+        let mut store = GraphStore::new();
+        store.create_vector_index("Document", "embedding", 4, crate::vector::DistanceMetric::Cosine).unwrap();
+
+        let mut ids = Vec::new();
+        for v in [[1.0, 0.0, 0.0, 0.0], [0.9, 0.1, 0.0, 0.0]] {
+            let mut props = PropertyMap::new();
+            props.insert("embedding".to_string(), PropertyValue::Vector(v.to_vec()));
+            ids.push(store.create_node_with_properties("default", vec![Label::new("Document")], props));
+        }
+        store.set_node_property("default", ids[0], "embedding", PropertyValue::Null).unwrap();
+
+        let results = store.vector_search("Document", "embedding", &[1.0, 0.0, 0.0, 0.0], 2).unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].0, ids[1]);
+
+        store.remove_node_property("default", ids[1], "embedding").unwrap();
+        assert!(store.vector_search("Document", "embedding", &[1.0, 0.0, 0.0, 0.0], 2).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_vector_search_filtered_by_department() {
+        let mut store = GraphStore::new();
+        store.create_vector_index("Document", "embedding", 2, crate::vector::DistanceMetric::Cosine).unwrap();
+
+        let mut security = Vec::new();
+        for i in 0..12 {
+            let angle = i as f32 * 0.1;
+            let department = if i % 4 == 3 { "Security" } else { "Sales" };
+            let mut props = PropertyMap::new();
+            props.insert("embedding".to_string(), PropertyValue::Vector(vec![angle.cos(), angle.sin()]));
+            props.insert("department".to_string(), PropertyValue::String(department.to_string()));
+            let id = store.create_node_with_properties("default", vec![Label::new("Document")], props);
+            if department == "Security" {
+                security.push(id);
+            }
+        }
+
+        let filter = VectorFilter::eq("department", "Security");
+        let results = store.vector_search_filtered("Document", "embedding", &[1.0, 0.0], 3, &filter).unwrap();
+        let ids: Vec<NodeId> = results.iter().map(|(id, _)| *id).collect();
+        assert_eq!(ids, security);
+    }
+
     #[test]
     fn test_vector_search_nonexistent_index() {
         let store = GraphStore::new();
@@ -4139,6 +6111,175 @@ mod tests {
         assert!(nodes.is_empty());
     }
 
+    #[test]
+    fn test_constraints_enforced_by_store_writes() {
+        let mut store = GraphStore::new();
+        let person = Label::new("Person");
+        let ann = store.create_node("Person");
+        store.set_node_property("default", ann, "email", "a@x").unwrap();
+        store.create_constraint(person.clone(), "email".to_string(), ConstraintKind::Unique).unwrap();
+        store.create_constraint(person.clone(), "email".to_string(), ConstraintKind::NotNull).unwrap();
+        // Backed by a populated property index
+        assert_eq!(store.property_index.lookup(&person, "email", &PropertyValue::String("a@x".into())), Some(vec![ann]));
+
+        let bob = store.create_node("Person");
+        let err = store.set_node_property("default", bob, "email", "a@x").unwrap_err();
+        assert!(matches!(err, GraphError::ConstraintViolation(_)));
+        assert!(store.get_node(bob).unwrap().get_property("email").is_none());
+        // Re-setting a node's own value is fine
+        store.set_node_property("default", ann, "email", "a@x").unwrap();
+
+        // Changing a value frees the old one
+        store.set_node_property("default", ann, "email", "ann@x").unwrap();
+        store.set_node_property("default", bob, "email", "a@x").unwrap();
+
+        assert!(matches!(
+            store.remove_node_property("default", bob, "email"),
+            Err(GraphError::ConstraintViolation(_))
+        ));
+        assert!(store.check_node_constraints(None, &[person.clone()], &PropertyMap::new()).is_err());
+
+        // A node gaining the label must not clash either
+        let carl = store.create_node("Employee");
+        store.set_node_property("default", carl, "email", "a@x").unwrap();
+        assert!(store.add_label_to_node("default", carl, "Person").is_err());
+        assert!(!store.get_node(carl).unwrap().labels.contains(&person));
+
+        store.delete_node("default", bob).unwrap();
+        store.add_label_to_node("default", carl, "Person").unwrap();
+
+        assert!(store.drop_constraint(&person, "email", ConstraintKind::NotNull));
+        assert_eq!(store.remove_node_property("default", carl, "email").unwrap(), Some(PropertyValue::String("a@x".into())));
+    }
+
+    #[test]
+    fn test_create_edges_bulk_matches_individual_creates() {
+        let knows = EdgeType::new("KNOWS");
+        let likes = EdgeType::new("LIKES");
+        let build = |bulk: bool| {
+            let mut store = GraphStore::new();
+            let n: Vec<NodeId> = (0..4).map(|_| store.create_node("Person")).collect();
+            let triples = vec![
+                (n[0], n[3], knows.clone()),
+                (n[0], n[1], likes.clone()),
+                (n[2], n[0], knows.clone()),
+                (n[0], n[2], knows.clone()),
+            ];
+            let ids = if bulk {
+                store.create_edges_bulk(&triples).unwrap()
+            } else {
+                triples.iter().map(|(s, t, ty)| store.create_edge(*s, *t, ty.clone()).unwrap()).collect()
+            };
+            (store, n, ids)
+        };
+        let (single, _, single_ids) = build(false);
+        let (bulk, n, bulk_ids) = build(true);
+
+        assert_eq!(bulk_ids, single_ids);
+        assert_eq!(bulk.edge_count(), 4);
+        assert_eq!(bulk.edge_type_count(&knows), 3);
+        let keys = |edges: Vec<Edge>| -> Vec<_> { edges.into_iter().map(|e| (e.id, e.source, e.target, e.edge_type)).collect() };
+        for node in &n {
+            assert_eq!(keys(bulk.get_outgoing_edges(*node)), keys(single.get_outgoing_edges(*node)));
+            assert_eq!(keys(bulk.get_incoming_edges(*node)), keys(single.get_incoming_edges(*node)));
+            assert_eq!(
+                keys(bulk.get_outgoing_edges_of_type(*node, &knows)),
+                keys(single.get_outgoing_edges_of_type(*node, &knows))
+            );
+        }
+        assert_eq!(bulk.get_edges_by_type(&likes).len(), 1);
+        assert_eq!(bulk.get_edge(bulk_ids[1]).unwrap().target, n[1]);
+    }
+
+    #[test]
+    fn test_create_edges_bulk_is_all_or_nothing() {
+        let mut store = GraphStore::new();
+        let a = store.create_node("Person");
+        let b = store.create_node("Person");
+        let missing = NodeId::new(99);
+        let err = store
+            .create_edges_bulk(&[(a, b, EdgeType::new("KNOWS")), (a, missing, EdgeType::new("KNOWS"))])
+            .unwrap_err();
+        assert!(matches!(err, GraphError::InvalidEdgeTarget(id) if id == missing));
+        assert_eq!(store.edge_count(), 0);
+        assert!(store.get_outgoing_edges(a).is_empty());
+        assert!(store.create_edges_bulk(&[]).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_validate_and_repair_dangling_edges() {
+        let mut store = GraphStore::new();
+        let a = store.create_node("Person");
+        let b = store.create_node("Person");
+        let c = store.create_node("Person");
+        let ab = store.create_edge(a, b, "KNOWS").unwrap();
+        let bc = store.create_edge(b, c, "KNOWS").unwrap();
+        let ac = store.create_edge(a, c, "KNOWS").unwrap();
+        assert!(store.validate().is_empty());
+
+        // Drop b's storage record without touching its edges
+        store.nodes[b.as_u64() as usize].clear();
+        store.outgoing[a.as_u64() as usize].push((c, EdgeId::new(999)));
+
+        assert_eq!(store.validate(), vec![
+            Inconsistency::MissingTarget { edge: ab, target: b },
+            Inconsistency::MissingSource { edge: bc, source: b },
+            Inconsistency::DanglingOutgoing { node: a, edge: EdgeId::new(999) },
+        ]);
+
+        assert_eq!(store.repair().len(), 3);
+        assert!(store.validate().is_empty());
+        assert!(store.get_edge(ab).is_none());
+        assert!(store.get_edge(bc).is_none());
+        let remaining: Vec<EdgeId> = store.get_outgoing_edges(a).iter().map(|e| e.id).collect();
+        assert_eq!(remaining, vec![ac]);
+        assert!(store.get_incoming_edges(c).iter().all(|e| e.id == ac));
+        assert!(store.repair().is_empty());
+    }
+
+    #[test]
+    fn test_delete_node_leaves_store_consistent() {
+        let mut store = GraphStore::new();
+        let a = store.create_node("Person");
+        let b = store.create_node("Person");
+        store.create_edge(a, b, "KNOWS").unwrap();
+        store.create_edge(b, a, "KNOWS").unwrap();
+        store.delete_node("default", b).unwrap();
+        assert!(store.validate().is_empty());
+    }
+
+    #[test]
+    fn test_get_nodes_by_property_uses_index() {
+        let mut store = GraphStore::new();
+        let person = Label::new("Person");
+        let mut ids = Vec::new();
+        for city in ["Pune", "Delhi", "Pune"] {
+            let id = store.create_node("Person");
+            store.set_node_property("default", id, "city", city).unwrap();
+            ids.push(id);
+        }
+        let other = store.create_node("City");
+        store.set_node_property("default", other, "city", "Pune").unwrap();
+
+        let pune = PropertyValue::String("Pune".to_string());
+        let mut scanned: Vec<NodeId> = store.get_nodes_by_property(&person, "city", &pune).iter().map(|n| n.id).collect();
+        scanned.sort();
+        assert_eq!(scanned, vec![ids[0], ids[2]]);
+        assert_eq!(store.property_index.lookup_count(), 0);
+
+        store.property_index.create_index(person.clone(), "city".to_string());
+        for &id in &ids {
+            let value = store.get_node(id).unwrap().get_property("city").unwrap().clone();
+            store.property_index.index_insert(&person, "city", value, id);
+        }
+
+        let mut indexed: Vec<NodeId> = store.get_nodes_by_property(&person, "city", &pune).iter().map(|n| n.id).collect();
+        indexed.sort();
+        assert_eq!(indexed, scanned);
+        assert_eq!(store.property_index.lookup_count(), 1);
+        assert!(store.get_nodes_by_property(&person, "city", &PropertyValue::String("Goa".to_string())).is_empty());
+    }
+
     #[test]
     fn test_get_edges_by_type_nonexistent() {
         let store = GraphStore::new();
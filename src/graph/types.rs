@@ -2,8 +2,8 @@
 //!
 //! This module defines the fundamental identifier and label types used throughout
 //! the graph engine. Every type here follows Rust's **newtype pattern**: wrapping
-//! a primitive (`u64` for identifiers, `String` for names) in a single-field
-//! tuple struct.
+//! a primitive (`u64` for identifiers, an interned [`Symbol`] for names) in a
+//! single-field tuple struct.
 //!
 //! ## The newtype pattern
 //!
@@ -31,9 +31,21 @@
 //! they are just `u64`), `PartialEq`, `Eq`, `Hash` (for use as `HashMap` keys),
 //! `PartialOrd`, `Ord` (for sorted adjacency lists and `BTreeMap` usage),
 //! and `Serialize`/`Deserialize` (for persistence and network transport).
+//!
+//! ## Interned names
+//!
+//! A graph has a handful of distinct labels and edge types but millions of
+//! nodes and edges carrying them. [`Label`] and [`EdgeType`] therefore wrap a
+//! [`Symbol`]: a pointer to a process-wide, deduplicated copy of the string.
+//! Cloning is a pointer copy, equality and hashing compare the pointer, and
+//! the text is only looked at for ordering and display. Serialized forms are
+//! unchanged (the plain string).
 
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::collections::HashSet;
 use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::sync::{Arc, OnceLock, RwLock};
 
 /// Unique identifier for a node
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, PartialOrd, Ord)]
@@ -87,69 +99,165 @@ impl From<u64> for EdgeId {
     }
 }
 
+/// Interned string shared by every [`Label`] / [`EdgeType`] with the same text
+///
+/// Each distinct string is stored once while any symbol refers to it, so two
+/// symbols are equal iff they point to the same entry. The interner drops
+/// entries no symbol uses any more each time it doubles in size, so names that
+/// only appear in parsed queries (typos, one-off labels) don't accumulate.
+/// Implements REQ-MEM-003: Memory-optimized data structures
+#[derive(Clone)]
+pub struct Symbol(Arc<str>);
+
+/// Live symbols, plus the table size at which unused entries are next purged
+struct Interner {
+    table: HashSet<Arc<str>>,
+    purge_at: usize,
+}
+
+impl Symbol {
+    pub fn intern(s: &str) -> Self {
+        let interner = Self::interner();
+        if let Some(existing) = interner.read().unwrap().table.get(s) {
+            return Symbol(Arc::clone(existing));
+        }
+        let mut interner = interner.write().unwrap();
+        if let Some(existing) = interner.table.get(s) {
+            return Symbol(Arc::clone(existing));
+        }
+        if interner.table.len() >= interner.purge_at {
+            // Only the table holds these; the write lock keeps anyone from
+            // taking a new reference while we check
+            interner.table.retain(|entry| Arc::strong_count(entry) > 1);
+            interner.purge_at = (interner.table.len() * 2).max(1024);
+        }
+        let entry: Arc<str> = Arc::from(s);
+        interner.table.insert(Arc::clone(&entry));
+        Symbol(entry)
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    fn interner() -> &'static RwLock<Interner> {
+        static INTERNER: OnceLock<RwLock<Interner>> = OnceLock::new();
+        INTERNER.get_or_init(|| RwLock::new(Interner { table: HashSet::new(), purge_at: 1024 }))
+    }
+}
+
+impl PartialEq for Symbol {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for Symbol {}
+
+impl Hash for Symbol {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (self.0.as_ptr() as usize).hash(state);
+    }
+}
+
+impl PartialOrd for Symbol {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Symbol {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        if self == other {
+            std::cmp::Ordering::Equal
+        } else {
+            self.0.cmp(&other.0)
+        }
+    }
+}
+
+impl fmt::Debug for Symbol {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&*self.0, f)
+    }
+}
+
+impl Serialize for Symbol {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for Symbol {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        Ok(Symbol::intern(&s))
+    }
+}
+
 /// Node label (e.g., "Person", "Employee")
 /// Implements REQ-GRAPH-002: Nodes with labels
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, PartialOrd, Ord)]
-pub struct Label(String);
+pub struct Label(Symbol);
 
 impl Label {
     pub fn new(label: impl Into<String>) -> Self {
-        Label(label.into())
+        Label(Symbol::intern(&label.into()))
     }
 
     pub fn as_str(&self) -> &str {
-        &self.0
+        self.0.as_str()
     }
 }
 
 impl fmt::Display for Label {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.0)
+        write!(f, "{}", self.as_str())
     }
 }
 
 impl From<String> for Label {
     fn from(s: String) -> Self {
-        Label(s)
+        Label(Symbol::intern(&s))
     }
 }
 
 impl From<&str> for Label {
     fn from(s: &str) -> Self {
-        Label(s.to_string())
+        Label(Symbol::intern(s))
     }
 }
 
 /// Edge type (relationship type, e.g., "KNOWS", "WORKS_AT")
 /// Implements REQ-GRAPH-003: Edges with types
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, PartialOrd, Ord)]
-pub struct EdgeType(String);
+pub struct EdgeType(Symbol);
 
 impl EdgeType {
     pub fn new(edge_type: impl Into<String>) -> Self {
-        EdgeType(edge_type.into())
+        EdgeType(Symbol::intern(&edge_type.into()))
     }
 
     pub fn as_str(&self) -> &str {
-        &self.0
+        self.0.as_str()
     }
 }
 
 impl fmt::Display for EdgeType {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.0)
+        write!(f, "{}", self.as_str())
     }
 }
 
 impl From<String> for EdgeType {
     fn from(s: String) -> Self {
-        EdgeType(s)
+        EdgeType(Symbol::intern(&s))
     }
 }
 
 impl From<&str> for EdgeType {
     fn from(s: &str) -> Self {
-        EdgeType(s.to_string())
+        EdgeType(Symbol::intern(s))
     }
 }
 
@@ -191,6 +299,41 @@ mod tests {
         assert_eq!(format!("{}", edge_type), "KNOWS");
     }
 
+    #[test]
+    fn test_names_are_interned() {
+        let a = Label::new("Interned");
+        let b = Label::from(String::from("Interned"));
+        assert_eq!(a, b);
+        assert!(std::ptr::eq(a.as_str(), b.as_str()));
+        assert_ne!(Label::new("Interned"), Label::new("interned"));
+        assert_eq!(EdgeType::new("KNOWS").as_str(), EdgeType::from("KNOWS").as_str());
+
+        // Ordering still follows the text, not the pointer
+        let mut labels = [Label::new("Zeta"), Label::new("Alpha"), Label::new("Mid")];
+        labels.sort();
+        assert_eq!(labels.iter().map(|l| l.as_str()).collect::<Vec<_>>(), ["Alpha", "Mid", "Zeta"]);
+
+        // Serialized form is the plain string
+        assert_eq!(serde_json::to_string(&a).unwrap(), "\"Interned\"");
+        let back: EdgeType = serde_json::from_str("\"KNOWS\"").unwrap();
+        assert_eq!(back, EdgeType::new("KNOWS"));
+        let bytes = bincode::serialize(&a).unwrap();
+        assert_eq!(bincode::deserialize::<String>(&bytes).unwrap(), "Interned");
+        assert_eq!(format!("{:?}", a), "Label(\"Interned\")");
+    }
+
+    #[test]
+    fn test_unused_names_are_released() {
+        let kept = Label::new("KeptAcrossPurges");
+        for i in 0..20_000 {
+            let _ = Label::new(format!("throwaway_{}", i));
+        }
+        // Other tests intern names concurrently, so only bound the table loosely
+        assert!(Symbol::interner().read().unwrap().table.len() < 10_000);
+        assert_eq!(Label::new("KeptAcrossPurges"), kept);
+        assert!(std::ptr::eq(Label::new("KeptAcrossPurges").as_str(), kept.as_str()));
+    }
+
     #[test]
     fn test_id_ordering() {
         let id1 = NodeId::new(1);
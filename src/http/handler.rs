@@ -9,7 +9,7 @@ use crate::graph::PropertyValue;
 use crate::http::server::AppState;
 use serde::{Deserialize, Serialize};
 use serde_json::json;
-use std::collections::{HashMap, BTreeMap, BTreeSet};
+use std::collections::HashMap;
 
 /// Request for executing a Cypher query
 #[derive(Deserialize)]
@@ -17,6 +17,15 @@ pub struct QueryRequest {
     pub query: String,
     #[serde(default = "default_graph")]
     pub graph: String,
+    /// Values for `$name` parameters referenced by the query
+    #[serde(default)]
+    pub params: HashMap<String, serde_json::Value>,
+    /// Abort the query after this many milliseconds (0 or absent = server default)
+    #[serde(default)]
+    pub timeout_ms: Option<u64>,
+    /// Run on the read-only path, so write clauses are rejected
+    #[serde(default)]
+    pub readonly: bool,
 }
 
 fn default_graph() -> String {
@@ -32,14 +41,70 @@ pub struct QueryResponse {
     records: Vec<Vec<serde_json::Value>>,
 }
 
+/// Media type of an Arrow IPC stream, accepted by [`query_handler`]
+pub const ARROW_STREAM_MIME: &str = "application/vnd.apache.arrow.stream";
+
+/// Whether `Accept` asks for an Arrow stream rather than JSON
+///
+/// Arrow is chosen only when it ranks above every range JSON satisfies, by
+/// q-value and then header order. A build without the `arrow` feature answers
+/// JSON whenever JSON is acceptable at all.
+fn wants_arrow(headers: &axum::http::HeaderMap) -> bool {
+    let accept: Vec<&str> = headers
+        .get_all(axum::http::header::ACCEPT)
+        .iter()
+        .filter_map(|v| v.to_str().ok())
+        .collect();
+    let ranges = crate::sparql::media_ranges(&accept.join(","));
+    let arrow = ranges.iter().position(|(media, _)| media == ARROW_STREAM_MIME);
+    let json = ranges
+        .iter()
+        .position(|(media, _)| matches!(media.as_str(), "application/json" | "application/*" | "*/*"));
+    match (arrow, json) {
+        (Some(arrow), Some(json)) => cfg!(feature = "arrow") && arrow < json,
+        (Some(_), None) => true,
+        (None, _) => false,
+    }
+}
+
+/// Result rows as an Arrow IPC stream (requires the `arrow` feature)
+#[cfg(feature = "arrow")]
+fn arrow_response(batch: &crate::query::RecordBatch) -> axum::response::Response {
+    match batch.to_arrow_ipc() {
+        Ok(bytes) => ([(axum::http::header::CONTENT_TYPE, ARROW_STREAM_MIME)], bytes).into_response(),
+        Err(e) => (axum::http::StatusCode::INTERNAL_SERVER_ERROR, Json(json!({ "error": e.to_string() })))
+            .into_response(),
+    }
+}
+
+#[cfg(not(feature = "arrow"))]
+fn arrow_response(_batch: &crate::query::RecordBatch) -> axum::response::Response {
+    (
+        axum::http::StatusCode::NOT_ACCEPTABLE,
+        Json(json!({ "error": "Arrow responses require a server built with the `arrow` feature" })),
+    )
+        .into_response()
+}
+
 /// Handler for Cypher queries
+///
+/// Replies with JSON unless the request's `Accept` header lists
+/// `application/vnd.apache.arrow.stream`, in which case the result columns are sent as an
+/// Arrow IPC stream (see [`RecordBatch::to_arrow`](crate::query::RecordBatch)).
 pub async fn query_handler(
     State(state): State<AppState>,
+    headers: axum::http::HeaderMap,
     Json(payload): Json<QueryRequest>,
 ) -> impl IntoResponse {
+    if let Some(tenants) = &state.tenant_manager {
+        if let Err(e) = tenants.acquire_query(&payload.graph) {
+            return (axum::http::StatusCode::TOO_MANY_REQUESTS, Json(json!({ "error": e.to_string() }))).into_response();
+        }
+    }
+
     // Check if query is write or read
     let query_upper = payload.query.trim().to_uppercase();
-    let is_write = query_upper.starts_with("CREATE") ||
+    let is_write = !payload.readonly && (query_upper.starts_with("CREATE") ||
                    query_upper.starts_with("SET") ||
                    query_upper.starts_with("DELETE") ||
                    query_upper.starts_with("MERGE") ||
@@ -48,17 +113,30 @@ pub async fn query_handler(
                      query_upper.contains(" DELETE ") || query_upper.contains(" MERGE ") ||
                      query_upper.contains(" REMOVE ") ||
                      query_upper.ends_with(" CREATE") || query_upper.ends_with(" SET") ||
-                     query_upper.ends_with(" DELETE") || query_upper.ends_with(" MERGE")));
+                     query_upper.ends_with(" DELETE") || query_upper.ends_with(" MERGE"))));
+
+    let params: HashMap<String, PropertyValue> = payload.params.iter()
+        .map(|(k, v)| (k.clone(), PropertyValue::from_json(v)))
+        .collect();
+
+    let timeout = payload.timeout_ms.filter(|&ms| ms > 0).map(std::time::Duration::from_millis);
 
     let result = if is_write {
         let mut store_guard = state.store.write().await;
-        state.engine.execute_mut(&payload.query, &mut *store_guard, &payload.graph)
+        match timeout {
+            Some(t) => state.engine.execute_mut_with_timeout(&payload.query, &mut store_guard, &payload.graph, params, t),
+            None => state.engine.execute_mut_with_params(&payload.query, &mut store_guard, &payload.graph, params),
+        }
     } else {
         let store_guard = state.store.read().await;
-        state.engine.execute(&payload.query, &*store_guard)
+        match timeout {
+            Some(t) => state.engine.execute_with_timeout(&payload.query, &store_guard, params, t),
+            None => state.engine.execute_with_params(&payload.query, &store_guard, params),
+        }
     };
 
     match result {
+        Ok(batch) if wants_arrow(&headers) => arrow_response(&batch),
         Ok(batch) => {
             let mut nodes = HashMap::new();
             let mut edges = HashMap::new();
@@ -169,10 +247,18 @@ pub async fn status_handler(
             "hits": stats.hits(),
             "misses": stats.misses(),
             "size": state.engine.cache_len(),
+            "capacity": state.engine.cache_capacity(),
         }
     }))
 }
 
+/// Handler for per-label / per-type cardinalities and index listing
+pub async fn stats_handler(
+    State(state): State<AppState>,
+) -> impl IntoResponse {
+    Json(state.store.read().await.stats())
+}
+
 /// Handler for graph schema introspection
 pub async fn schema_handler(
     State(state): State<AppState>,
@@ -182,68 +268,7 @@ pub async fn schema_handler(
     let total_nodes = store_guard.node_count();
     let total_edges = store_guard.edge_count();
 
-    // Build node types: use label_index for counts, sample 1 node per label for property types
-    let mut node_types = Vec::new();
-    for label in store_guard.all_labels() {
-        let count = store_guard.label_node_count(label);
-        let mut properties = BTreeMap::new();
-
-        // Sample a single node to discover property names and types (O(1))
-        if let Some(&sample_id) = store_guard
-            .label_index_ids(label)
-            .and_then(|ids| ids.iter().next())
-        {
-            if let Some(node) = store_guard.get_node(sample_id) {
-                for (key, val) in &node.properties {
-                    let prop_type = match val {
-                        PropertyValue::String(_) => "String",
-                        PropertyValue::Integer(_) => "Integer",
-                        PropertyValue::Float(_) => "Float",
-                        PropertyValue::Boolean(_) => "Boolean",
-                        PropertyValue::Vector(_) => "Vector",
-                        _ => "Unknown",
-                    };
-                    properties.insert(key.clone(), prop_type.to_string());
-                }
-            }
-        }
-
-        node_types.push(json!({
-            "label": label.as_str(),
-            "count": count,
-            "properties": properties,
-        }));
-    }
-
-    // Build edge types: use catalog triple stats for source/target labels (O(1))
-    let catalog = store_guard.catalog();
-    let triple_stats = catalog.all_triple_stats();
-
-    let mut edge_source_targets: BTreeMap<String, (BTreeSet<String>, BTreeSet<String>)> =
-        BTreeMap::new();
-    for (pattern, _stats) in triple_stats {
-        let entry = edge_source_targets
-            .entry(pattern.edge_type.as_str().to_string())
-            .or_insert_with(|| (BTreeSet::new(), BTreeSet::new()));
-        entry.0.insert(pattern.source_label.as_str().to_string());
-        entry.1.insert(pattern.target_label.as_str().to_string());
-    }
-
-    let mut edge_types = Vec::new();
-    for edge_type in store_guard.all_edge_types() {
-        let count = store_guard.edge_type_count(edge_type);
-        let (source_labels, target_labels) = edge_source_targets
-            .get(edge_type.as_str())
-            .cloned()
-            .unwrap_or_default();
-
-        edge_types.push(json!({
-            "type": edge_type.as_str(),
-            "count": count,
-            "source_labels": source_labels.into_iter().collect::<Vec<_>>(),
-            "target_labels": target_labels.into_iter().collect::<Vec<_>>(),
-        }));
-    }
+    let schema = store_guard.schema_info();
 
     let index_list = store_guard.property_index.list_indexes();
     let indexes: Vec<_> = index_list.iter().map(|(l, p)| {
@@ -251,8 +276,8 @@ pub async fn schema_handler(
     }).collect();
 
     let constraint_list = store_guard.property_index.list_constraints();
-    let constraints: Vec<_> = constraint_list.iter().map(|(l, p)| {
-        json!({ "label": l.as_str(), "property": p, "type": "UNIQUE" })
+    let constraints: Vec<_> = constraint_list.iter().map(|(l, p, kind)| {
+        json!({ "label": l.as_str(), "property": p, "type": kind.as_str() })
     }).collect();
 
     let avg_out_degree = if total_nodes > 0 {
@@ -262,8 +287,8 @@ pub async fn schema_handler(
     };
 
     Json(json!({
-        "node_types": node_types,
-        "edge_types": edge_types,
+        "node_types": schema.labels,
+        "edge_types": schema.edge_types,
         "indexes": indexes,
         "constraints": constraints,
         "statistics": {
@@ -600,6 +625,137 @@ pub async fn export_snapshot_handler(
     }
 }
 
+/// Query parameters for the streaming snapshot export
+#[derive(Deserialize, Default)]
+pub struct SnapshotExportParams {
+    /// Graph name, used for the download filename.
+    #[serde(default = "default_graph")]
+    pub graph: String,
+    /// Byte offset to resume from after a dropped connection. A
+    /// `Range: bytes=N-` header is accepted as an alternative.
+    #[serde(default)]
+    pub offset: Option<u64>,
+    /// `created_at` of the export being resumed, as returned in the
+    /// `X-Snapshot-Created-At` header of the first response.
+    #[serde(default)]
+    pub created_at: Option<String>,
+}
+
+const EXPORT_CHUNK_SIZE: usize = 64 * 1024;
+
+/// `Write` adapter that forwards export output to a response body channel,
+/// dropping the first `skip` bytes.
+struct ChannelWriter {
+    tx: tokio::sync::mpsc::Sender<std::io::Result<axum::body::Bytes>>,
+    skip: u64,
+    buf: Vec<u8>,
+}
+
+impl ChannelWriter {
+    fn send_buffered(&mut self) -> std::io::Result<()> {
+        if self.buf.is_empty() {
+            return Ok(());
+        }
+        let chunk = axum::body::Bytes::from(std::mem::take(&mut self.buf));
+        self.tx.blocking_send(Ok(chunk)).map_err(|_| {
+            std::io::Error::new(std::io::ErrorKind::BrokenPipe, "client disconnected")
+        })
+    }
+}
+
+impl std::io::Write for ChannelWriter {
+    fn write(&mut self, data: &[u8]) -> std::io::Result<usize> {
+        let skipped = (self.skip.min(data.len() as u64)) as usize;
+        self.skip -= skipped as u64;
+        self.buf.extend_from_slice(&data[skipped..]);
+        if self.buf.len() >= EXPORT_CHUNK_SIZE {
+            self.send_buffered()?;
+        }
+        Ok(data.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.send_buffered()
+    }
+}
+
+/// Parse a `Range: bytes=N-` header into its start offset.
+fn parse_range_start(headers: &axum::http::HeaderMap) -> Option<u64> {
+    let value = headers.get(axum::http::header::RANGE)?.to_str().ok()?;
+    let spec = value.trim().strip_prefix("bytes=")?;
+    let (start, end) = spec.split_once('-')?;
+    if !end.trim().is_empty() {
+        return None;
+    }
+    start.trim().parse().ok()
+}
+
+/// GET /api/snapshot/export?graph=g — stream a .sgsnap snapshot
+///
+/// The snapshot is written to a chunked response as it is produced, in the
+/// same format `/api/snapshot/import` reads. To resume a dropped download,
+/// repeat the request with `offset=N` (or `Range: bytes=N-`) and the
+/// `created_at` from the first response's `X-Snapshot-Created-At` header;
+/// as long as the graph has not changed, the remaining bytes match.
+pub async fn stream_snapshot_export_handler(
+    State(state): State<AppState>,
+    Query(params): Query<SnapshotExportParams>,
+    headers: axum::http::HeaderMap,
+) -> axum::response::Response {
+    use axum::http::{header, StatusCode};
+
+    let offset = params.offset.or_else(|| parse_range_start(&headers)).unwrap_or(0);
+    let created_at = match params.created_at {
+        Some(created_at) => created_at,
+        None if offset > 0 => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(json!({ "error": "created_at is required when resuming from an offset" })),
+            )
+                .into_response()
+        }
+        None => chrono::Utc::now().to_rfc3339(),
+    };
+
+    let store = state.store.clone().read_owned().await;
+    let (tx, rx) = tokio::sync::mpsc::channel(16);
+    let stamp = created_at.clone();
+    tokio::task::spawn_blocking(move || {
+        let mut writer = ChannelWriter { tx: tx.clone(), skip: offset, buf: Vec::new() };
+        let result = crate::snapshot::export_tenant_as_of(&store, &mut writer, &stamp)
+            .map_err(|e| e.to_string())
+            .and_then(|_| writer.send_buffered().map_err(|e| e.to_string()));
+        if let Err(e) = result {
+            // Fail the body so the client sees a broken transfer rather than
+            // a short but apparently complete snapshot.
+            let _ = tx.blocking_send(Err(std::io::Error::other(e)));
+        }
+    });
+
+    let status = if offset > 0 { StatusCode::PARTIAL_CONTENT } else { StatusCode::OK };
+    let filename: String = params
+        .graph
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+        .collect();
+    let disposition = format!("attachment; filename=\"{}.sgsnap\"", filename);
+    let mut response = axum::response::Response::new(axum::body::Body::from_stream(
+        tokio_stream::wrappers::ReceiverStream::new(rx),
+    ));
+    *response.status_mut() = status;
+    let response_headers = response.headers_mut();
+    response_headers.insert(header::CONTENT_TYPE, "application/octet-stream".parse().unwrap());
+    response_headers.insert(header::ACCEPT_RANGES, "bytes".parse().unwrap());
+    if let Ok(value) = disposition.parse() {
+        response_headers.insert(header::CONTENT_DISPOSITION, value);
+    }
+    if let Ok(value) = created_at.parse() {
+        response_headers.insert("x-snapshot-created-at", value);
+    }
+    response_headers.insert("x-snapshot-offset", offset.into());
+    response
+}
+
 /// Query parameters for snapshot import
 #[derive(Deserialize, Default)]
 pub struct SnapshotImportParams {
@@ -609,69 +765,149 @@ pub struct SnapshotImportParams {
     pub dedup_key: Option<String>,
 }
 
+/// Removes a spooled upload when the import finishes, unless it was already
+/// moved into the snapshot directory.
+struct SpoolFile(std::path::PathBuf);
+
+impl Drop for SpoolFile {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.0);
+    }
+}
+
+fn snapshot_import_error(
+    status: axum::http::StatusCode,
+    message: String,
+    bytes_received: u64,
+) -> axum::response::Response {
+    (
+        status,
+        Json(json!({ "error": message, "bytes_received": bytes_received })),
+    )
+        .into_response()
+}
+
 /// POST /api/snapshot/import — import a .sgsnap snapshot
 /// Optional query param: ?dedup_key=name,go_id (comma-separated)
+///
+/// The `file` field is streamed to a spool file rather than buffered, so
+/// upload size is bounded by disk rather than RAM.
 pub async fn restore_snapshot_handler(
     State(state): State<AppState>,
     Query(params): Query<SnapshotImportParams>,
     mut multipart: Multipart,
 ) -> impl IntoResponse {
-    // Read the snapshot file from multipart
-    let mut snapshot_data: Option<Vec<u8>> = None;
+    use axum::http::StatusCode;
+    use tokio::io::AsyncWriteExt;
+
+    let spool = match crate::snapshot::persist::incoming_snapshot_path(state.data_path.as_deref()) {
+        Ok(path) => SpoolFile(path),
+        Err(e) => {
+            return snapshot_import_error(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                format!("Failed to create spool file: {}", e),
+                0,
+            )
+        }
+    };
+    let mut bytes_received: u64 = 0;
+    let mut found_file = false;
 
     loop {
-        let field_result: Result<Option<axum::extract::multipart::Field<'_>>, _> =
-            multipart.next_field().await;
-        match field_result {
-            Ok(Some(field)) => {
-                let name = field.name().unwrap_or("").to_string();
-                if name == "file" {
-                    match field.bytes().await {
-                        Ok(bytes) => snapshot_data = Some(bytes.to_vec()),
-                        Err(e) => {
-                            return (
-                                axum::http::StatusCode::BAD_REQUEST,
-                                Json(json!({ "error": format!("Failed to read file: {}", e) })),
-                            )
-                                .into_response()
-                        }
+        let mut field = match multipart.next_field().await {
+            Ok(Some(field)) => field,
+            Ok(None) => break,
+            Err(e) => {
+                return snapshot_import_error(
+                    StatusCode::BAD_REQUEST,
+                    format!("Malformed multipart body after {} bytes: {}", bytes_received, e),
+                    bytes_received,
+                )
+            }
+        };
+        if field.name() != Some("file") {
+            continue;
+        }
+        found_file = true;
+        bytes_received = 0;
+
+        let mut out = match tokio::fs::File::create(&spool.0).await {
+            Ok(f) => f,
+            Err(e) => {
+                return snapshot_import_error(
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    format!("Failed to create spool file: {}", e),
+                    0,
+                )
+            }
+        };
+        loop {
+            match field.chunk().await {
+                Ok(Some(chunk)) => {
+                    if let Err(e) = out.write_all(&chunk).await {
+                        return snapshot_import_error(
+                            StatusCode::INTERNAL_SERVER_ERROR,
+                            format!("Failed to spool upload after {} bytes: {}", bytes_received, e),
+                            bytes_received,
+                        );
                     }
+                    bytes_received += chunk.len() as u64;
+                }
+                Ok(None) => break,
+                Err(e) => {
+                    return snapshot_import_error(
+                        StatusCode::BAD_REQUEST,
+                        format!("Failed to read file after {} bytes: {}", bytes_received, e),
+                        bytes_received,
+                    )
                 }
             }
-            Ok(None) => break,
-            Err(_) => break,
+        }
+        if let Err(e) = out.flush().await {
+            return snapshot_import_error(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                format!("Failed to spool upload after {} bytes: {}", bytes_received, e),
+                bytes_received,
+            );
         }
     }
 
-    let data = match snapshot_data {
-        Some(d) => d,
-        None => {
-            return (
-                axum::http::StatusCode::BAD_REQUEST,
-                Json(json!({ "error": "No file field in multipart request" })),
+    if !found_file {
+        return snapshot_import_error(
+            StatusCode::BAD_REQUEST,
+            "No file field in multipart request".to_string(),
+            bytes_received,
+        );
+    }
+
+    let file = match std::fs::File::open(&spool.0) {
+        Ok(f) => std::io::BufReader::new(f),
+        Err(e) => {
+            return snapshot_import_error(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                format!("Failed to reopen spooled upload: {}", e),
+                bytes_received,
             )
-                .into_response()
         }
     };
 
     let mut store_guard = state.store.write().await;
-    let cursor = std::io::Cursor::new(&data);
     let dedup_keys: Vec<String> = params
         .dedup_key
         .map(|s| s.split(',').map(|k| k.trim().to_string()).filter(|k| !k.is_empty()).collect())
         .unwrap_or_default();
     let dedup_key_refs: Vec<&str> = dedup_keys.iter().map(|s| s.as_str()).collect();
 
-    match crate::snapshot::import_tenant_with_dedup(&mut store_guard, cursor, &dedup_key_refs) {
+    match crate::snapshot::import_tenant_with_dedup(&mut store_guard, file, &dedup_key_refs) {
         Ok(stats) => {
-            // HA-08: Persist snapshot atomically (tmp → fsync → rename → marker)
+            // HA-08: Persist snapshot atomically (rename → fsync → marker)
             // so it survives server restart. Crash-before-marker = ignored on boot.
             if let Some(ref data_path) = state.data_path {
-                match crate::snapshot::persist::persist_snapshot(data_path, &data) {
+                match crate::snapshot::persist::persist_snapshot_file(data_path, &spool.0) {
                     Ok(_) => eprintln!(
                         "[snapshot-persist] Committed snapshot to {}/snapshots ({} bytes)",
                         data_path,
-                        data.len()
+                        bytes_received
                     ),
                     Err(e) => eprintln!("[snapshot-persist] Failed to persist: {}", e),
                 }
@@ -690,14 +926,11 @@ pub async fn restore_snapshot_handler(
                 "labels": stats.labels,
                 "edge_types": stats.edge_types,
                 "vector_indices_rebuilt": vector_indices_rebuilt,
+                "bytes_received": bytes_received,
             }))
             .into_response()
         }
-        Err(e) => (
-            axum::http::StatusCode::BAD_REQUEST,
-            Json(json!({ "error": e.to_string() })),
-        )
-            .into_response(),
+        Err(e) => snapshot_import_error(StatusCode::BAD_REQUEST, e.to_string(), bytes_received),
     }
 }
 
@@ -730,6 +963,12 @@ mod tests {
         let app = Router::new()
             .route("/api/query", post(query_handler))
             .route("/api/status", get(status_handler))
+            .route("/api/stats", get(stats_handler))
+            .route("/api/snapshot/export", get(stream_snapshot_export_handler))
+            .route(
+                "/api/snapshot/import",
+                post(restore_snapshot_handler).layer(axum::extract::DefaultBodyLimit::disable()),
+            )
             .with_state(state.clone());
         (app, state)
     }
@@ -818,6 +1057,34 @@ mod tests {
         assert_eq!(json["cache"]["size"], 1);
     }
 
+    #[tokio::test]
+    async fn test_stats_handler() {
+        let (app, state) = test_app();
+        {
+            let mut store = state.store.write().await;
+            let alice = store.create_node("Person");
+            let bob = store.create_node("Person");
+            let acme = store.create_node("Company");
+            store.create_edge(alice, bob, "KNOWS").unwrap();
+            store.create_edge(alice, acme, "WORKS_AT").unwrap();
+        }
+
+        let response = app
+            .oneshot(Request::builder().method("GET").uri("/api/stats").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let bytes = response.into_body().collect().await.unwrap().to_bytes();
+        let json: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+
+        assert_eq!(json["nodes"], 3);
+        assert_eq!(json["edges"], 2);
+        assert_eq!(json["labels"][0], json!({"name": "Person", "count": 2}));
+        assert_eq!(json["labels"][1], json!({"name": "Company", "count": 1}));
+        assert_eq!(json["edge_types"].as_array().unwrap().len(), 2);
+        assert_eq!(json["indexes"], json!([]));
+    }
+
     // ==================== query_handler read tests ====================
 
     #[tokio::test]
@@ -934,6 +1201,72 @@ mod tests {
 
     // ==================== query_handler write tests ====================
 
+    #[tokio::test]
+    async fn test_query_handler_timeout_ms() {
+        let (app, state) = test_app();
+        {
+            let mut store = state.store.write().await;
+            for _ in 0..1000 {
+                store.create_node("N");
+            }
+        }
+
+        // 10^9 rows: the query cannot finish before the deadline on any machine
+        let (status, json) = post_query(
+            app.clone(),
+            r#"{"query": "MATCH (a:N), (b:N), (c:N) RETURN count(*)", "timeout_ms": 100}"#,
+        ).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert!(json["error"].as_str().unwrap().contains("Query timed out"), "{}", json);
+
+        let (status, json) = post_query(
+            app,
+            r#"{"query": "MATCH (a:N) RETURN count(a) AS c", "timeout_ms": 60000}"#,
+        ).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(json["records"], json!([[1000]]));
+    }
+
+    #[tokio::test]
+    async fn test_query_handler_readonly_rejects_writes() {
+        let (app, state) = test_app();
+
+        let (status, json) = post_query(
+            app.clone(),
+            r#"{"query": "CREATE (n:Person) RETURN $name AS name", "params": {"name": "Alice"}, "readonly": true}"#,
+        ).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert!(json["error"].as_str().unwrap().contains("read-only"), "{}", json);
+        assert_eq!(state.store.read().await.node_count(), 0);
+
+        let (status, _) = post_query(
+            app,
+            r#"{"query": "MATCH (n:Person) WHERE n.name = $name RETURN n", "params": {"name": "Alice"}, "readonly": true}"#,
+        ).await;
+        assert_eq!(status, StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_query_handler_rate_limited() {
+        let (_, mut state) = test_app();
+        let tenants = Arc::new(crate::persistence::TenantManager::new());
+        let quotas = crate::persistence::ResourceQuotas { max_queries_per_sec: Some(2), ..Default::default() };
+        tenants.create_tenant("noisy".to_string(), "Noisy".to_string(), Some(quotas)).unwrap();
+        state.tenant_manager = Some(tenants);
+        let app = Router::new().route("/api/query", post(query_handler)).with_state(state);
+
+        let noisy = r#"{"query": "MATCH (n) RETURN count(n)", "graph": "noisy"}"#;
+        for _ in 0..2 {
+            assert_eq!(post_query(app.clone(), noisy).await.0, StatusCode::OK);
+        }
+        let (status, json) = post_query(app.clone(), noisy).await;
+        assert_eq!(status, StatusCode::TOO_MANY_REQUESTS);
+        assert!(json["error"].as_str().unwrap().contains("Rate limited"), "{}", json);
+
+        let (status, _) = post_query(app, r#"{"query": "MATCH (n) RETURN count(n)"}"#).await;
+        assert_eq!(status, StatusCode::OK);
+    }
+
     #[tokio::test]
     async fn test_query_handler_create_node() {
         let (app, state) = test_app();
@@ -988,6 +1321,95 @@ mod tests {
         assert_eq!(records[0][0], 30);
     }
 
+    #[tokio::test]
+    async fn test_query_handler_arrow_accept() {
+        let (app, state) = test_app();
+        {
+            let mut store = state.store.write().await;
+            let n = store.create_node("Person");
+            store.get_node_mut(n).unwrap().set_property("name", "Ann");
+            store.get_node_mut(n).unwrap().set_property("age", 41i64);
+        }
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/query")
+                    .header("content-type", "application/json")
+                    .header("accept", "application/json;q=0.5, application/vnd.apache.arrow.stream")
+                    .body(Body::from(r#"{"query": "MATCH (n:Person) RETURN n.name, n.age"}"#))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        #[cfg(feature = "arrow")]
+        {
+            assert_eq!(response.status(), StatusCode::OK);
+            assert_eq!(response.headers()["content-type"], ARROW_STREAM_MIME);
+            let bytes = response.into_body().collect().await.unwrap().to_bytes();
+            let reader = arrow::ipc::reader::StreamReader::try_new(bytes.as_ref(), None).unwrap();
+            let schema = reader.schema();
+            let fields: Vec<(&str, &arrow::datatypes::DataType)> =
+                schema.fields().iter().map(|f| (f.name().as_str(), f.data_type())).collect();
+            assert_eq!(
+                fields,
+                vec![("n.name", &arrow::datatypes::DataType::Utf8), ("n.age", &arrow::datatypes::DataType::Int64)]
+            );
+            let rows: usize = reader.map(|batch| batch.unwrap().num_rows()).sum();
+            assert_eq!(rows, 1);
+        }
+        // Without the feature the acceptable JSON is served instead
+        #[cfg(not(feature = "arrow"))]
+        {
+            assert_eq!(response.status(), StatusCode::OK);
+            assert_eq!(response.headers()["content-type"], "application/json");
+        }
+    }
+
+    #[cfg(not(feature = "arrow"))]
+    #[tokio::test]
+    async fn test_query_handler_arrow_only_not_acceptable() {
+        let (app, _state) = test_app();
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/query")
+                    .header("content-type", "application/json")
+                    .header("accept", ARROW_STREAM_MIME)
+                    .body(Body::from(r#"{"query": "MATCH (n:Person) RETURN n.name"}"#))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_ACCEPTABLE);
+    }
+
+    #[test]
+    fn test_wants_arrow() {
+        let headers = |accept: &str| {
+            let mut map = axum::http::HeaderMap::new();
+            map.insert("accept", accept.parse().unwrap());
+            map
+        };
+        assert!(wants_arrow(&headers("application/vnd.apache.arrow.stream")));
+        assert!(wants_arrow(&headers("text/html, Application/Vnd.Apache.Arrow.Stream;q=0.9")));
+        assert!(!wants_arrow(&headers("application/json")));
+        assert!(!wants_arrow(&headers("application/vnd.apache.arrow.file")));
+        assert!(!wants_arrow(&axum::http::HeaderMap::new()));
+        // q-values rank the two; a refused (q=0) Arrow is never picked
+        assert!(!wants_arrow(&headers("application/vnd.apache.arrow.stream;q=0.5, application/json")));
+        assert!(!wants_arrow(&headers("application/json, application/vnd.apache.arrow.stream")));
+        assert!(!wants_arrow(&headers("application/vnd.apache.arrow.stream;q=0")));
+        assert_eq!(
+            wants_arrow(&headers("application/json;q=0.5, application/vnd.apache.arrow.stream")),
+            cfg!(feature = "arrow")
+        );
+        assert_eq!(wants_arrow(&headers("*/*;q=0.1, application/vnd.apache.arrow.stream")), cfg!(feature = "arrow"));
+    }
+
     // ==================== query_handler error tests ====================
 
     #[tokio::test]
@@ -1254,4 +1676,175 @@ mod tests {
         assert_eq!(status, StatusCode::OK);
     }
 
+    // ==================== snapshot import tests ====================
+
+    const BOUNDARY: &str = "samyama-test-boundary";
+
+    fn multipart_body(file: &[u8], close: bool) -> Vec<u8> {
+        let mut body = format!(
+            "--{BOUNDARY}\r\nContent-Disposition: form-data; name=\"file\"; filename=\"g.sgsnap\"\r\n\
+             Content-Type: application/octet-stream\r\n\r\n"
+        )
+        .into_bytes();
+        body.extend_from_slice(file);
+        if close {
+            body.extend_from_slice(format!("\r\n--{BOUNDARY}--\r\n").as_bytes());
+        }
+        body
+    }
+
+    async fn post_snapshot(app: Router, body: Vec<u8>) -> (StatusCode, serde_json::Value) {
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/snapshot/import")
+                    .header("content-type", format!("multipart/form-data; boundary={BOUNDARY}"))
+                    .body(Body::from(body))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        let status = response.status();
+        let bytes = response.into_body().collect().await.unwrap().to_bytes();
+        (status, serde_json::from_slice(&bytes).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_snapshot_import_streams_past_default_body_limit() {
+        // Incompressible payloads push the gzipped snapshot past axum's 2 MB
+        // default body limit, so this only passes when the route streams.
+        let mut source = GraphStore::new();
+        let mut seed: u64 = 0x9E37_79B9_7F4A_7C15;
+        for i in 0..8000 {
+            let payload: String = (0..32)
+                .map(|_| {
+                    seed ^= seed << 13;
+                    seed ^= seed >> 7;
+                    seed ^= seed << 17;
+                    format!("{:016x}", seed)
+                })
+                .collect();
+            let id = source.create_node("Doc");
+            source.set_node_property("default", id, "i", i as i64).unwrap();
+            source.set_node_property("default", id, "payload", payload).unwrap();
+        }
+        let mut snapshot = Vec::new();
+        crate::snapshot::export_tenant(&source, &mut snapshot).unwrap();
+        assert!(snapshot.len() > 2 * 1024 * 1024, "snapshot only {} bytes", snapshot.len());
+
+        let (app, state) = test_app();
+        let (status, json) = post_snapshot(app, multipart_body(&snapshot, true)).await;
+        assert_eq!(status, StatusCode::OK, "{json}");
+        assert_eq!(json["nodes_imported"], 8000);
+        assert_eq!(json["bytes_received"], snapshot.len() as u64);
+        assert_eq!(state.store.read().await.node_count(), 8000);
+    }
+
+    #[tokio::test]
+    async fn test_snapshot_import_truncated_upload_reports_bytes() {
+        let (app, state) = test_app();
+        let (status, json) = post_snapshot(app, multipart_body(&[7u8; 4096], false)).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert!(json["error"].as_str().unwrap().contains("bytes"), "{json}");
+        assert!(json["bytes_received"].as_u64().is_some());
+        assert_eq!(state.store.read().await.node_count(), 0);
+    }
+
+    // ==================== snapshot export tests ====================
+
+    async fn populate_chain(state: &AppState, nodes: usize) {
+        let mut store = state.store.write().await;
+        let mut prev = None;
+        for i in 0..nodes {
+            let id = store.create_node("Item");
+            store.set_node_property("default", id, "i", i as i64).unwrap();
+            store.set_node_property("default", id, "name", format!("item-{i}")).unwrap();
+            if let Some(prev) = prev {
+                store.create_edge(prev, id, "NEXT").unwrap();
+            }
+            prev = Some(id);
+        }
+    }
+
+    async fn get_export(
+        app: Router,
+        uri: &str,
+        range: Option<&str>,
+    ) -> (StatusCode, axum::http::HeaderMap, Vec<u8>) {
+        let mut request = Request::builder().method("GET").uri(uri);
+        if let Some(range) = range {
+            request = request.header("range", range);
+        }
+        let response = app.oneshot(request.body(Body::empty()).unwrap()).await.unwrap();
+        let status = response.status();
+        let headers = response.headers().clone();
+        let bytes = response.into_body().collect().await.unwrap().to_bytes();
+        (status, headers, bytes.to_vec())
+    }
+
+    #[tokio::test]
+    async fn test_snapshot_export_round_trips_through_import() {
+        let (app, state) = test_app();
+        populate_chain(&state, 5000).await;
+
+        let (status, headers, snapshot) = get_export(app, "/api/snapshot/export?graph=g", None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(headers["accept-ranges"], "bytes");
+        assert_eq!(headers["content-disposition"], "attachment; filename=\"g.sgsnap\"");
+        assert!(headers.contains_key("x-snapshot-created-at"));
+
+        let (target, target_state) = test_app();
+        let (status, json) = post_snapshot(target, multipart_body(&snapshot, true)).await;
+        assert_eq!(status, StatusCode::OK, "{json}");
+        assert_eq!(json["nodes_imported"], 5000);
+        assert_eq!(json["edges_imported"], 4999);
+        let imported = target_state.store.read().await;
+        assert_eq!(imported.node_count(), 5000);
+        assert_eq!(imported.edge_count(), 4999);
+    }
+
+    #[tokio::test]
+    async fn test_snapshot_export_resumes_from_offset() {
+        let (app, state) = test_app();
+        populate_chain(&state, 3000).await;
+
+        let (_, headers, full) = get_export(app.clone(), "/api/snapshot/export", None).await;
+        let created_at = headers["x-snapshot-created-at"].to_str().unwrap().to_string();
+        let cut = full.len() / 3;
+        let stamp: String = url_encode(&created_at);
+
+        let (status, headers, tail) = get_export(
+            app.clone(),
+            &format!("/api/snapshot/export?offset={cut}&created_at={stamp}"),
+            None,
+        )
+        .await;
+        assert_eq!(status, StatusCode::PARTIAL_CONTENT);
+        assert_eq!(headers["x-snapshot-offset"], cut.to_string().as_str());
+        assert_eq!(tail, full[cut..]);
+
+        let (status, _, tail) = get_export(
+            app.clone(),
+            &format!("/api/snapshot/export?created_at={stamp}"),
+            Some(&format!("bytes={cut}-")),
+        )
+        .await;
+        assert_eq!(status, StatusCode::PARTIAL_CONTENT);
+        assert_eq!(tail, full[cut..]);
+
+        let (status, _, _) = get_export(app, &format!("/api/snapshot/export?offset={cut}"), None).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+    }
+
+    fn url_encode(value: &str) -> String {
+        value
+            .bytes()
+            .map(|b| match b {
+                b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' => (b as char).to_string(),
+                _ => format!("%{:02X}", b),
+            })
+            .collect()
+    }
+
 }
@@ -23,7 +23,14 @@ use samyama_optimization::common::{
     MultiObjectiveProblem, Problem, SimpleProblem, SolverConfig,
 };
 use serde::{Deserialize, Serialize};
-use std::{collections::HashMap, convert::Infallible, pin::Pin, sync::Arc};
+use std::{
+    collections::HashMap,
+    convert::Infallible,
+    ops::ControlFlow,
+    pin::Pin,
+    sync::atomic::{AtomicBool, Ordering},
+    sync::Arc,
+};
 use tokio::sync::{mpsc, Mutex};
 use tokio_stream::wrappers::ReceiverStream;
 use uuid::Uuid;
@@ -98,6 +105,7 @@ fn algorithm_catalog() -> Vec<AlgorithmInfo> {
         vec![
             ParamSpec { name: "population_size", ty: "int", default: serde_json::json!(50) },
             ParamSpec { name: "iterations",      ty: "int", default: serde_json::json!(200) },
+            ParamSpec { name: "seed",            ty: "int", default: serde_json::Value::Null },
         ]
     };
 
@@ -360,7 +368,7 @@ struct JobHandle {
 
 #[derive(Clone)]
 struct CancelHandle {
-    flag: std::sync::Arc<std::sync::atomic::AtomicBool>,
+    flag: Arc<AtomicBool>,
 }
 
 #[derive(Debug, Clone)]
@@ -435,15 +443,14 @@ async fn start_solve(
     let cfg = SolverConfig {
         population_size: req.population_size,
         max_iterations: req.iterations,
+        seed: req.seed,
+        ..Default::default()
     };
     let dim = req.dim.unwrap_or(bench.dim);
 
-    // Seed (not currently propagated into solvers, which use thread_rng).
-    let _ = req.seed;
-
     // Run in a blocking task so we don't stall the async runtime.
-    // AtomicBool cancel flag — polled between the compute future and emit loop.
-    let cancelled_flag = std::sync::Arc::new(std::sync::atomic::AtomicBool::new(false));
+    // AtomicBool cancel flag — polled by the running solver and the emit loop.
+    let cancelled_flag = Arc::new(AtomicBool::new(false));
     let cancel_state = CancelHandle { flag: cancelled_flag.clone() };
 
     {
@@ -459,8 +466,9 @@ async fn start_solve(
     let _ = cancel_rx; // silence warning; we don't use the oneshot receiver
 
     tokio::task::spawn(async move {
+        let solver_cancelled = cancelled_flag.clone();
         let compute = tokio::task::spawn_blocking(move || {
-            run_solver(&algo.id, algo.multi_objective, &bench.id, bench.num_objectives, dim, cfg)
+            run_solver(&algo.id, algo.multi_objective, &bench.id, bench.num_objectives, dim, cfg, &solver_cancelled)
         });
 
         match compute.await {
@@ -468,7 +476,7 @@ async fn start_solve(
                 let iterations = history.len();
                 let last_iter = iterations.saturating_sub(1);
                 for (iter, best) in history.iter().enumerate() {
-                    if cancelled_flag.load(std::sync::atomic::Ordering::Relaxed) {
+                    if cancelled_flag.load(Ordering::Relaxed) {
                         let _ = event_tx.send(SseEvent::Error { message: "cancelled".into() }).await;
                         return;
                     }
@@ -515,18 +523,27 @@ fn run_solver(
     _num_obj: usize,
     bench_dim: usize,
     cfg: SolverConfig,
+    cancelled: &AtomicBool,
 ) -> Result<SolverOutcome, String> {
+    // Stop the solver between iterations once the job is cancelled.
+    let mut progress = |_: usize, _: f64| {
+        if cancelled.load(Ordering::Relaxed) {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    };
     if multi_objective {
         let (hist, final_f, pareto) = match bench_id {
             "zdt1" | "zdt2" | "zdt3" => {
                 let v = bench_id.chars().last().unwrap().to_digit(10).unwrap() as u8;
                 let problem = ZDT { variant: v, dim: 30 };
                 let r = match algo_id {
-                    "mo_bmr"    => MOBMWRSolver::new(cfg, MOBMWRVariant::MOBMR).solve(&problem),
-                    "mo_bwr"    => MOBMWRSolver::new(cfg, MOBMWRVariant::MOBWR).solve(&problem),
-                    "mo_bmwr"   => MOBMWRSolver::new(cfg, MOBMWRVariant::MOBMWR).solve(&problem),
-                    "mo_rao_de" => MORaoDESolver::new(cfg).solve(&problem),
-                    "nsga2"     => NSGA2Solver::new(cfg).solve(&problem),
+                    "mo_bmr"    => MOBMWRSolver::new(cfg, MOBMWRVariant::MOBMR).solve_with_progress(&problem, &mut progress),
+                    "mo_bwr"    => MOBMWRSolver::new(cfg, MOBMWRVariant::MOBWR).solve_with_progress(&problem, &mut progress),
+                    "mo_bmwr"   => MOBMWRSolver::new(cfg, MOBMWRVariant::MOBMWR).solve_with_progress(&problem, &mut progress),
+                    "mo_rao_de" => MORaoDESolver::new(cfg).solve_with_progress(&problem, &mut progress),
+                    "nsga2"     => NSGA2Solver::new(cfg).solve_with_progress(&problem, &mut progress),
                     _           => return Err(format!("algorithm {} not multi-objective", algo_id)),
                 };
                 let final_first = r.pareto_front.iter()
@@ -544,11 +561,11 @@ fn run_solver(
             "uc2_dosing" => {
                 let problem = super::uc_problems::UC2DosingProblem::new();
                 let r = match algo_id {
-                    "mo_bmr"    => MOBMWRSolver::new(cfg, MOBMWRVariant::MOBMR).solve(&problem),
-                    "mo_bwr"    => MOBMWRSolver::new(cfg, MOBMWRVariant::MOBWR).solve(&problem),
-                    "mo_bmwr"   => MOBMWRSolver::new(cfg, MOBMWRVariant::MOBMWR).solve(&problem),
-                    "mo_rao_de" => MORaoDESolver::new(cfg).solve(&problem),
-                    "nsga2"     => NSGA2Solver::new(cfg).solve(&problem),
+                    "mo_bmr"    => MOBMWRSolver::new(cfg, MOBMWRVariant::MOBMR).solve_with_progress(&problem, &mut progress),
+                    "mo_bwr"    => MOBMWRSolver::new(cfg, MOBMWRVariant::MOBWR).solve_with_progress(&problem, &mut progress),
+                    "mo_bmwr"   => MOBMWRSolver::new(cfg, MOBMWRVariant::MOBMWR).solve_with_progress(&problem, &mut progress),
+                    "mo_rao_de" => MORaoDESolver::new(cfg).solve_with_progress(&problem, &mut progress),
+                    "nsga2"     => NSGA2Solver::new(cfg).solve_with_progress(&problem, &mut progress),
                     _           => return Err(format!("algorithm {} not multi-objective", algo_id)),
                 };
                 let final_first = r.pareto_front.iter()
@@ -563,11 +580,11 @@ fn run_solver(
             "dtlz1" => {
                 let problem = DTLZ1 { dim: 7, m: 3 };
                 let r = match algo_id {
-                    "mo_bmr"    => MOBMWRSolver::new(cfg, MOBMWRVariant::MOBMR).solve(&problem),
-                    "mo_bwr"    => MOBMWRSolver::new(cfg, MOBMWRVariant::MOBWR).solve(&problem),
-                    "mo_bmwr"   => MOBMWRSolver::new(cfg, MOBMWRVariant::MOBMWR).solve(&problem),
-                    "mo_rao_de" => MORaoDESolver::new(cfg).solve(&problem),
-                    "nsga2"     => NSGA2Solver::new(cfg).solve(&problem),
+                    "mo_bmr"    => MOBMWRSolver::new(cfg, MOBMWRVariant::MOBMR).solve_with_progress(&problem, &mut progress),
+                    "mo_bwr"    => MOBMWRSolver::new(cfg, MOBMWRVariant::MOBWR).solve_with_progress(&problem, &mut progress),
+                    "mo_bmwr"   => MOBMWRSolver::new(cfg, MOBMWRVariant::MOBMWR).solve_with_progress(&problem, &mut progress),
+                    "mo_rao_de" => MORaoDESolver::new(cfg).solve_with_progress(&problem, &mut progress),
+                    "nsga2"     => NSGA2Solver::new(cfg).solve_with_progress(&problem, &mut progress),
                     _           => return Err(format!("algorithm {} not multi-objective", algo_id)),
                 };
                 let final_first = r.pareto_front.iter()
@@ -592,24 +609,24 @@ fn run_solver(
         let problem = single_obj(bench.id, dim, bench.lower, bench.upper);
 
         let result = match algo_id {
-            "jaya"      => JayaSolver::new(cfg).solve(&problem),
-            "rao1"      => RaoSolver::new(cfg, RaoVariant::Rao1).solve(&problem),
-            "rao2"      => RaoSolver::new(cfg, RaoVariant::Rao2).solve(&problem),
-            "rao3"      => RaoSolver::new(cfg, RaoVariant::Rao3).solve(&problem),
-            "tlbo"      => TLBOSolver::new(cfg).solve(&problem),
-            "itlbo"     => ITLBOSolver::new(cfg).solve(&problem),
-            "qojaya"    => QOJayaSolver::new(cfg).solve(&problem),
-            "gotlbo"    => GOTLBOSolver::new(cfg).solve(&problem),
-            "bmr"       => BMRSolver::new(cfg).solve(&problem),
-            "bwr"       => BWRSolver::new(cfg).solve(&problem),
-            "bmwr"      => BMWRSolver::new(cfg).solve(&problem),
-            "samp_jaya" => SAMPJayaSolver::new(cfg).solve(&problem),
-            "qo_rao"    => QORaoSolver::new(cfg, RaoVariant::Rao1).solve(&problem),
-            "ehrjaya"   => EHRJayaSolver::new(cfg).solve(&problem),
-            "saphr"     => SAPHRSolver::new(cfg).solve(&problem),
-            "pso"       => PSOSolver::new(cfg).solve(&problem),
-            "de"        => DESolver::new(cfg).solve(&problem),
-            "ga"        => GASolver::new(cfg).solve(&problem),
+            "jaya"      => JayaSolver::new(cfg).solve_with_progress(&problem, &mut progress),
+            "rao1"      => RaoSolver::new(cfg, RaoVariant::Rao1).solve_with_progress(&problem, &mut progress),
+            "rao2"      => RaoSolver::new(cfg, RaoVariant::Rao2).solve_with_progress(&problem, &mut progress),
+            "rao3"      => RaoSolver::new(cfg, RaoVariant::Rao3).solve_with_progress(&problem, &mut progress),
+            "tlbo"      => TLBOSolver::new(cfg).solve_with_progress(&problem, &mut progress),
+            "itlbo"     => ITLBOSolver::new(cfg).solve_with_progress(&problem, &mut progress),
+            "qojaya"    => QOJayaSolver::new(cfg).solve_with_progress(&problem, &mut progress),
+            "gotlbo"    => GOTLBOSolver::new(cfg).solve_with_progress(&problem, &mut progress),
+            "bmr"       => BMRSolver::new(cfg).solve_with_progress(&problem, &mut progress),
+            "bwr"       => BWRSolver::new(cfg).solve_with_progress(&problem, &mut progress),
+            "bmwr"      => BMWRSolver::new(cfg).solve_with_progress(&problem, &mut progress),
+            "samp_jaya" => SAMPJayaSolver::new(cfg).solve_with_progress(&problem, &mut progress),
+            "qo_rao"    => QORaoSolver::new(cfg, RaoVariant::Rao1).solve_with_progress(&problem, &mut progress),
+            "ehrjaya"   => EHRJayaSolver::new(cfg).solve_with_progress(&problem, &mut progress),
+            "saphr"     => SAPHRSolver::new(cfg).solve_with_progress(&problem, &mut progress),
+            "pso"       => PSOSolver::new(cfg).solve_with_progress(&problem, &mut progress),
+            "de"        => DESolver::new(cfg).solve_with_progress(&problem, &mut progress),
+            "ga"        => GASolver::new(cfg).solve_with_progress(&problem, &mut progress),
             other       => return Err(format!("algorithm {} not supported on single-objective benchmarks", other)),
         };
         Ok(SolverOutcome {
@@ -678,7 +695,7 @@ async fn cancel_solve(
     match jobs.get_mut(&id) {
         Some(h) => {
             if let Some(handle) = h.cancel_flag.as_ref() {
-                handle.flag.store(true, std::sync::atomic::Ordering::Relaxed);
+                handle.flag.store(true, Ordering::Relaxed);
                 Json(CancelResp { cancelled: true })
             } else {
                 Json(CancelResp { cancelled: false })
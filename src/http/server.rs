@@ -16,9 +16,9 @@ use tokio::sync::RwLock;
 use tower_http::cors::CorsLayer;
 use tracing::info;
 use super::handler::{
-    query_handler, status_handler, schema_handler, sample_handler,
+    query_handler, status_handler, stats_handler, schema_handler, sample_handler,
     import_csv_handler, import_json_handler,
-    export_snapshot_handler, restore_snapshot_handler,
+    export_snapshot_handler, stream_snapshot_export_handler, restore_snapshot_handler,
 };
 use super::vector::{list_indexes_handler, create_index_handler, search_handler};
 
@@ -106,6 +106,7 @@ impl HttpServer {
             .route("/", get(static_handler))
             .route("/api/query", post(query_handler))
             .route("/api/status", get(status_handler))
+            .route("/api/stats", get(stats_handler))
             .route("/api/schema", get(schema_handler))
             .route("/api/sample", post(sample_handler))
             .route("/api/import/csv", post(import_csv_handler))
@@ -113,13 +114,12 @@ impl HttpServer {
             .route("/api/vector/indexes", get(list_indexes_handler))
             .route("/api/vector/indexes", post(create_index_handler))
             .route("/api/vector-search", post(search_handler))
-            .route("/api/snapshot/export", post(export_snapshot_handler))
+            .route("/api/snapshot/export", get(stream_snapshot_export_handler).post(export_snapshot_handler))
             .route("/api/snapshot/import", post(restore_snapshot_handler)
-                // 64 GB cap. PubMed-v2 (11 GB) and trifecta-pubmed (12 GB) need
-                // headroom; 64 GB lets per-source snapshots up to ~50 GB through.
-                // Body is buffered in memory by the multipart extractor — see #197
-                // follow-up for streaming-to-disk to drop the RAM ceiling.
-                .layer(DefaultBodyLimit::max(64 * 1024 * 1024 * 1024)))
+                // No cap: the handler streams the upload to disk, so PubMed-v2
+                // (11 GB) and trifecta-pubmed (12 GB) snapshots are bounded by
+                // disk space rather than RAM.
+                .layer(DefaultBodyLimit::disable()))
             .with_state(state);
 
         let mut app = main_router
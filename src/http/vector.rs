@@ -3,7 +3,7 @@
 use axum::{extract::State, response::IntoResponse, Json};
 use crate::embed::EmbedPipeline;
 use crate::http::server::AppState;
-use crate::vector::DistanceMetric;
+use crate::vector::{DistanceMetric, HnswConfig};
 use serde::Deserialize;
 use serde_json::json;
 use std::sync::Arc;
@@ -31,9 +31,15 @@ pub struct CreateIndexRequest {
     pub label: String,
     pub property_key: String,
     pub dimensions: usize,
-    /// "cosine" (default), "l2", or "inner_product"
+    /// "cosine" (default), "l2" (alias "euclidean"), or "inner_product" (aliases "dot_product", "dot")
     #[serde(default = "default_metric")]
     pub metric: String,
+    /// HNSW max connections per node (default 16)
+    pub m: Option<usize>,
+    /// HNSW insertion candidate list size (default 200)
+    pub ef_construction: Option<usize>,
+    /// HNSW default search candidate list size (default 64)
+    pub ef_search: Option<usize>,
 }
 
 fn default_metric() -> String {
@@ -43,8 +49,8 @@ fn default_metric() -> String {
 fn parse_metric(s: &str) -> Option<DistanceMetric> {
     match s.to_lowercase().as_str() {
         "cosine" => Some(DistanceMetric::Cosine),
-        "l2" => Some(DistanceMetric::L2),
-        "inner_product" | "dot" => Some(DistanceMetric::InnerProduct),
+        "euclidean" | "l2" => Some(DistanceMetric::Euclidean),
+        "dot_product" | "inner_product" | "dot" => Some(DistanceMetric::DotProduct),
         _ => None,
     }
 }
@@ -52,8 +58,8 @@ fn parse_metric(s: &str) -> Option<DistanceMetric> {
 fn canonical_metric(m: &DistanceMetric) -> &'static str {
     match m {
         DistanceMetric::Cosine => "cosine",
-        DistanceMetric::L2 => "l2",
-        DistanceMetric::InnerProduct => "inner_product",
+        DistanceMetric::Euclidean => "l2",
+        DistanceMetric::DotProduct => "inner_product",
     }
 }
 
@@ -83,7 +89,7 @@ pub async fn create_index_handler(
             return (
                 axum::http::StatusCode::BAD_REQUEST,
                 Json(json!({ "error": format!(
-                    "unknown metric '{}'; expected cosine, l2, or inner_product",
+                    "unknown metric '{}'; expected cosine, l2 (euclidean), or inner_product (dot_product)",
                     payload.metric
                 ) })),
             )
@@ -91,16 +97,26 @@ pub async fn create_index_handler(
         }
     };
 
+    let defaults = HnswConfig::default();
+    let config = HnswConfig {
+        m: payload.m.unwrap_or(defaults.m),
+        ef_construction: payload.ef_construction.unwrap_or(defaults.ef_construction),
+        ef_search: payload.ef_search.unwrap_or(defaults.ef_search),
+    };
+
     let canonical = canonical_metric(&metric);
     // write lock: create_vector_index mutates the index registry
     let store = state.store.write().await;
-    match store.create_vector_index(&payload.label, &payload.property_key, payload.dimensions, metric) {
+    match store.create_vector_index_with_config(&payload.label, &payload.property_key, payload.dimensions, metric, config) {
         Ok(_) => Json(json!({
             "status": "ok",
             "label": payload.label,
             "property_key": payload.property_key,
             "dimensions": payload.dimensions,
             "metric": canonical,
+            "m": config.m,
+            "ef_construction": config.ef_construction,
+            "ef_search": config.ef_search,
         }))
         .into_response(),
         Err(e) => (
@@ -128,6 +144,8 @@ pub struct VectorSearchRequest {
     pub property_key: Option<String>,
     /// Number of nearest neighbors to return (default: 10)
     pub k: Option<usize>,
+    /// Override the index's HNSW ef_search for this query (higher = better recall, slower)
+    pub ef_search: Option<usize>,
     /// Tenant/graph to search. Defaults to "default".
     #[serde(default = "default_graph")]
     pub graph: String,
@@ -232,7 +250,12 @@ pub async fn search_handler(
     let store = state.store.read().await;
     let label = payload.label.as_deref().unwrap_or("Paper");
 
-    match store.vector_search(label, property_key, &query_vector, k) {
+    let metric = store
+        .vector_index
+        .get_index(label, property_key)
+        .map(|index| index.read().unwrap().metric());
+
+    match store.vector_search_with_ef(label, property_key, &query_vector, k, payload.ef_search) {
         Ok(results) => {
             let search_results: Vec<_> = results
                 .iter()
@@ -256,9 +279,7 @@ pub async fn search_handler(
                         })
                         .unwrap_or_else(|| json!({ "id": node_id.as_u64() }));
 
-                    // score = 1/(1+distance) is a monotonic similarity proxy.
-                    // Accurate for L2; an approximation for cosine and inner-product.
-                    let score = 1.0_f32 / (1.0_f32 + distance);
+                    let score = metric.unwrap_or(DistanceMetric::Cosine).score(*distance);
                     json!({ "node": node_info, "distance": distance, "score": score })
                 })
                 .collect();
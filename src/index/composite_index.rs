@@ -0,0 +1,180 @@
+//! Composite (multi-property) index
+//!
+//! Keys nodes on an ordered tuple of property values so a query fixing the
+//! leading properties by equality, and optionally bounding the next one, reads
+//! a single contiguous run of the index.
+
+use crate::graph::{NodeId, PropertyValue};
+use super::property_index::range_segments;
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::ops::Bound;
+
+/// Index over an ordered list of properties on a specific label
+///
+/// A node is indexed once it has any of the properties; missing ones are held
+/// as `Null` in its tuple, so equality on a prefix still finds it.
+#[derive(Debug, Clone)]
+pub struct CompositeIndex {
+    properties: Vec<String>,
+    /// Value tuple -> Set of NodeIds
+    index: BTreeMap<Vec<PropertyValue>, HashSet<NodeId>>,
+    /// Current tuple of each indexed node, so single-property updates can re-key it
+    tuples: HashMap<NodeId, Vec<PropertyValue>>,
+}
+
+impl CompositeIndex {
+    pub fn new(properties: Vec<String>) -> Self {
+        Self {
+            properties,
+            index: BTreeMap::new(),
+            tuples: HashMap::new(),
+        }
+    }
+
+    /// Indexed properties, in key order
+    pub fn properties(&self) -> &[String] {
+        &self.properties
+    }
+
+    /// Record that `node_id` now has `value` for `property`
+    pub fn set(&mut self, node_id: NodeId, property: &str, value: PropertyValue) {
+        let Some(pos) = self.properties.iter().position(|p| p == property) else {
+            return;
+        };
+        let mut tuple = self.tuples.get(&node_id).cloned()
+            .unwrap_or_else(|| vec![PropertyValue::Null; self.properties.len()]);
+        tuple[pos] = value;
+        self.rekey(node_id, tuple);
+    }
+
+    /// Record that `node_id` no longer has `value` for `property`
+    pub fn unset(&mut self, node_id: NodeId, property: &str, value: &PropertyValue) {
+        let Some(pos) = self.properties.iter().position(|p| p == property) else {
+            return;
+        };
+        let Some(mut tuple) = self.tuples.get(&node_id).cloned() else {
+            return;
+        };
+        if tuple[pos] == *value {
+            tuple[pos] = PropertyValue::Null;
+            self.rekey(node_id, tuple);
+        }
+    }
+
+    fn rekey(&mut self, node_id: NodeId, tuple: Vec<PropertyValue>) {
+        if let Some(old) = self.tuples.remove(&node_id) {
+            if let Some(nodes) = self.index.get_mut(&old) {
+                nodes.remove(&node_id);
+                if nodes.is_empty() {
+                    self.index.remove(&old);
+                }
+            }
+        }
+        if tuple.iter().any(|v| !v.is_null()) {
+            self.index.entry(tuple.clone()).or_default().insert(node_id);
+            self.tuples.insert(node_id, tuple);
+        }
+    }
+
+    /// Entries whose leading values equal `prefix` and, when a bound is given,
+    /// whose next value lies between `lower` and `upper`.
+    ///
+    /// The bounds compare like [`PropertyIndex::range_entries`](super::PropertyIndex::range_entries).
+    pub fn prefix_entries(
+        &self,
+        prefix: &[PropertyValue],
+        lower: Bound<&PropertyValue>,
+        upper: Bound<&PropertyValue>,
+    ) -> Vec<(&Vec<PropertyValue>, &HashSet<NodeId>)> {
+        let in_prefix = |key: &Vec<PropertyValue>| key.starts_with(prefix);
+        if matches!((lower, upper), (Bound::Unbounded, Bound::Unbounded)) {
+            return self.index.range(prefix.to_vec()..).take_while(|(key, _)| in_prefix(key)).collect();
+        }
+        let k = prefix.len();
+        if k >= self.properties.len() {
+            return Vec::new();
+        }
+
+        let mut entries = Vec::new();
+        for segment in range_segments(lower, upper) {
+            let mut start = prefix.to_vec();
+            if let Bound::Included(v) | Bound::Excluded(v) = &segment.lower {
+                start.push(v.clone());
+            }
+            entries.extend(
+                self.index.range(start..)
+                    .take_while(|(key, _)| in_prefix(key) && segment.position(&key[k]) != Ordering::Greater)
+                    .filter(|(key, _)| segment.position(&key[k]) == Ordering::Equal),
+            );
+        }
+        entries
+    }
+
+    /// Node ids of [`prefix_entries`](Self::prefix_entries)
+    pub fn prefix_scan(
+        &self,
+        prefix: &[PropertyValue],
+        lower: Bound<&PropertyValue>,
+        upper: Bound<&PropertyValue>,
+    ) -> Vec<NodeId> {
+        self.prefix_entries(prefix, lower, upper)
+            .into_iter()
+            .flat_map(|(_, nodes)| nodes.iter().cloned())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ops::Bound::{Excluded, Included, Unbounded};
+
+    fn docs() -> CompositeIndex {
+        let mut index = CompositeIndex::new(vec!["department".to_string(), "date".to_string()]);
+        for i in 0..20u64 {
+            let dept = if i % 2 == 0 { "legal" } else { "sales" };
+            index.set(NodeId::new(i), "department", PropertyValue::String(dept.into()));
+            index.set(NodeId::new(i), "date", PropertyValue::Integer(i as i64));
+        }
+        index
+    }
+
+    fn sorted(mut ids: Vec<NodeId>) -> Vec<u64> {
+        ids.sort();
+        ids.into_iter().map(|id| id.as_u64()).collect()
+    }
+
+    #[test]
+    fn test_composite_prefix_and_range() {
+        let index = docs();
+        let legal = [PropertyValue::String("legal".into())];
+
+        assert_eq!(index.prefix_scan(&legal, Unbounded, Unbounded).len(), 10);
+        let range = index.prefix_entries(&legal, Excluded(&PropertyValue::Integer(10)), Included(&PropertyValue::Float(16.0)));
+        assert_eq!(range.len(), 3);
+        assert_eq!(sorted(range.into_iter().flat_map(|(_, n)| n.iter().cloned()).collect()), vec![12, 14, 16]);
+
+        let full = [PropertyValue::String("sales".into()), PropertyValue::Integer(7)];
+        assert_eq!(sorted(index.prefix_scan(&full, Unbounded, Unbounded)), vec![7]);
+    }
+
+    #[test]
+    fn test_composite_rekeys_on_update() {
+        let mut index = docs();
+        let legal = [PropertyValue::String("legal".into())];
+
+        // A node missing the second property still matches on the first
+        index.set(NodeId::new(100), "department", PropertyValue::String("legal".into()));
+        assert_eq!(index.prefix_scan(&legal, Unbounded, Unbounded).len(), 11);
+        assert!(!index.prefix_scan(&legal, Unbounded, Included(&PropertyValue::Integer(100))).contains(&NodeId::new(100)));
+
+        // Moving a node between departments and removing properties re-keys it
+        index.unset(NodeId::new(2), "department", &PropertyValue::String("legal".into()));
+        index.set(NodeId::new(2), "department", PropertyValue::String("sales".into()));
+        assert_eq!(index.prefix_scan(&legal, Unbounded, Unbounded).len(), 10);
+        index.unset(NodeId::new(100), "department", &PropertyValue::String("legal".into()));
+        assert_eq!(index.prefix_scan(&legal, Unbounded, Unbounded).len(), 9);
+        assert!(!index.tuples.contains_key(&NodeId::new(100)));
+    }
+}
@@ -3,8 +3,11 @@
 //! Handles creation, deletion, and access to property indices.
 
 use crate::graph::{Label, NodeId, PropertyValue};
+use super::composite_index::CompositeIndex;
 use super::property_index::PropertyIndex;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::ops::Bound;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::{Arc, RwLock};
 
 /// Key for identifying a property index
@@ -14,19 +17,57 @@ pub struct PropertyIndexKey {
     pub property: String,
 }
 
+/// Key for identifying a composite index: a label and an ordered property list
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CompositeIndexKey {
+    pub label: Label,
+    pub properties: Vec<String>,
+}
+
+/// Kind of property constraint
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ConstraintKind {
+    /// `ASSERT n.prop IS UNIQUE`: no two nodes with the label share a value
+    Unique,
+    /// `ASSERT n.prop IS NOT NULL`: every node with the label has the property
+    NotNull,
+}
+
+impl ConstraintKind {
+    /// Name as shown by SHOW CONSTRAINTS
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ConstraintKind::Unique => "UNIQUE",
+            ConstraintKind::NotNull => "NOT NULL",
+        }
+    }
+}
+
 /// Manager for all property indices
 #[derive(Debug)]
 pub struct IndexManager {
     indices: RwLock<HashMap<PropertyIndexKey, Arc<RwLock<PropertyIndex>>>>,
+    /// Multi-property indices, maintained alongside the single-property ones
+    composite_indices: RwLock<HashMap<CompositeIndexKey, Arc<RwLock<CompositeIndex>>>>,
     /// Unique constraints (label, property) pairs
     unique_constraints: RwLock<HashMap<PropertyIndexKey, Arc<RwLock<PropertyIndex>>>>,
+    /// Not-null (existence) constraints
+    not_null_constraints: RwLock<HashSet<PropertyIndexKey>>,
+    /// Number of equality lookups answered from an index
+    lookups: AtomicU64,
+    /// Number of index entries read by range and composite scans
+    range_probes: AtomicU64,
 }
 
 impl IndexManager {
     pub fn new() -> Self {
         Self {
             indices: RwLock::new(HashMap::new()),
+            composite_indices: RwLock::new(HashMap::new()),
             unique_constraints: RwLock::new(HashMap::new()),
+            not_null_constraints: RwLock::new(HashSet::new()),
+            lookups: AtomicU64::new(0),
+            range_probes: AtomicU64::new(0),
         }
     }
 
@@ -49,6 +90,9 @@ impl IndexManager {
 
     /// Update index when a node property is set/changed
     pub fn index_insert(&self, label: &Label, property: &str, value: PropertyValue, node_id: NodeId) {
+        for index in self.composites_covering(label, property) {
+            index.write().unwrap().set(node_id, property, value.clone());
+        }
         let key = PropertyIndexKey {
             label: label.clone(),
             property: property.to_string(),
@@ -61,6 +105,9 @@ impl IndexManager {
 
     /// Update index when a node property is removed (or old value replaced)
     pub fn index_remove(&self, label: &Label, property: &str, value: &PropertyValue, node_id: NodeId) {
+        for index in self.composites_covering(label, property) {
+            index.write().unwrap().unset(node_id, property, value);
+        }
         let key = PropertyIndexKey {
             label: label.clone(),
             property: property.to_string(),
@@ -89,6 +136,46 @@ impl IndexManager {
         self.indices.read().unwrap().get(&key).cloned()
     }
 
+    /// Look up the nodes whose indexed property equals `value`.
+    ///
+    /// Returns `None` when no index exists for (label, property), so the
+    /// caller can fall back to a scan.
+    pub fn lookup(&self, label: &Label, property: &str, value: &PropertyValue) -> Option<Vec<NodeId>> {
+        let index = self.get_index(label, property)?;
+        self.lookups.fetch_add(1, Ordering::Relaxed);
+        let ids = index.read().unwrap().get(value);
+        Some(ids)
+    }
+
+    /// Number of lookups served by an index since creation
+    pub fn lookup_count(&self) -> u64 {
+        self.lookups.load(Ordering::Relaxed)
+    }
+
+    /// Look up the nodes whose indexed property lies between `lower` and `upper`.
+    ///
+    /// Comparison follows [`PropertyIndex::range_entries`]. Returns `None` when
+    /// no index exists for (label, property).
+    pub fn range_lookup(
+        &self,
+        label: &Label,
+        property: &str,
+        lower: Bound<&PropertyValue>,
+        upper: Bound<&PropertyValue>,
+    ) -> Option<Vec<NodeId>> {
+        let index = self.get_index(label, property)?;
+        let index = index.read().unwrap();
+        let entries = index.range_entries(lower, upper);
+        self.range_probes.fetch_add(entries.len() as u64, Ordering::Relaxed);
+        Some(entries.into_iter().flat_map(|(_, nodes)| nodes.iter().cloned()).collect())
+    }
+
+    /// Number of index entries (distinct values or tuples) read by range and
+    /// composite lookups since creation
+    pub fn range_probe_count(&self) -> u64 {
+        self.range_probes.load(Ordering::Relaxed)
+    }
+
     /// List all indexes
     pub fn list_indexes(&self) -> Vec<(Label, String)> {
         self.indices.read().unwrap().keys()
@@ -134,8 +221,11 @@ impl IndexManager {
         Ok(())
     }
 
-    /// Insert into unique constraint index
+    /// Insert into unique constraint index. Nulls are never indexed.
     pub fn constraint_insert(&self, label: &Label, property: &str, value: PropertyValue, node_id: NodeId) {
+        if value.is_null() {
+            return;
+        }
         let key = PropertyIndexKey {
             label: label.clone(),
             property: property.to_string(),
@@ -146,18 +236,150 @@ impl IndexManager {
         }
     }
 
-    /// List all constraints
-    pub fn list_constraints(&self) -> Vec<(Label, String)> {
-        self.unique_constraints.read().unwrap().keys()
-            .map(|k| (k.label.clone(), k.property.clone()))
+    /// Remove a value from a unique constraint index (property changed or node deleted)
+    pub fn constraint_remove(&self, label: &Label, property: &str, value: &PropertyValue, node_id: NodeId) {
+        let key = PropertyIndexKey {
+            label: label.clone(),
+            property: property.to_string(),
+        };
+        let constraints = self.unique_constraints.read().unwrap();
+        if let Some(index) = constraints.get(&key) {
+            index.write().unwrap().remove(value, node_id);
+        }
+    }
+
+    /// Nodes already holding `value` under a unique constraint (empty when unconstrained)
+    pub fn unique_holders(&self, label: &Label, property: &str, value: &PropertyValue) -> Vec<NodeId> {
+        let key = PropertyIndexKey {
+            label: label.clone(),
+            property: property.to_string(),
+        };
+        let constraints = self.unique_constraints.read().unwrap();
+        constraints.get(&key).map(|index| index.read().unwrap().get(value)).unwrap_or_default()
+    }
+
+    /// Create a not-null constraint
+    pub fn create_not_null_constraint(&self, label: Label, property: String) {
+        self.not_null_constraints.write().unwrap().insert(PropertyIndexKey { label, property });
+    }
+
+    /// Check if a not-null constraint exists
+    pub fn has_not_null_constraint(&self, label: &Label, property: &str) -> bool {
+        let key = PropertyIndexKey {
+            label: label.clone(),
+            property: property.to_string(),
+        };
+        self.not_null_constraints.read().unwrap().contains(&key)
+    }
+
+    /// Drop a constraint. The index created alongside a unique constraint is kept.
+    /// Returns false if no such constraint existed.
+    pub fn drop_constraint(&self, label: &Label, property: &str, kind: ConstraintKind) -> bool {
+        let key = PropertyIndexKey {
+            label: label.clone(),
+            property: property.to_string(),
+        };
+        match kind {
+            ConstraintKind::Unique => self.unique_constraints.write().unwrap().remove(&key).is_some(),
+            ConstraintKind::NotNull => self.not_null_constraints.write().unwrap().remove(&key),
+        }
+    }
+
+    /// True if any constraint is defined (lets write paths skip constraint work)
+    pub fn has_constraints(&self) -> bool {
+        !self.unique_constraints.read().unwrap().is_empty()
+            || !self.not_null_constraints.read().unwrap().is_empty()
+    }
+
+    /// Constraints declared on a label, as (property, kind) pairs
+    pub fn constraints_on(&self, label: &Label) -> Vec<(String, ConstraintKind)> {
+        self.list_constraints()
+            .into_iter()
+            .filter(|(l, _, _)| l == label)
+            .map(|(_, property, kind)| (property, kind))
             .collect()
     }
 
-    /// Create a composite index on multiple properties (creates individual indexes for each)
+    /// List all constraints, sorted by label, property and kind
+    pub fn list_constraints(&self) -> Vec<(Label, String, ConstraintKind)> {
+        let mut constraints: Vec<_> = self.unique_constraints.read().unwrap().keys()
+            .map(|k| (k.label.clone(), k.property.clone(), ConstraintKind::Unique))
+            .chain(self.not_null_constraints.read().unwrap().iter()
+                .map(|k| (k.label.clone(), k.property.clone(), ConstraintKind::NotNull)))
+            .collect();
+        constraints.sort_by(|a, b| (a.0.as_str(), &a.1, a.2).cmp(&(b.0.as_str(), &b.1, b.2)));
+        constraints
+    }
+
+    /// Create a composite index on multiple properties.
+    ///
+    /// Individual indexes are created for each property as well, so queries on
+    /// any single one of them stay indexed.
     pub fn create_composite_index(&self, label: Label, properties: Vec<String>) {
         for prop in &properties {
             self.create_index(label.clone(), prop.clone());
         }
+        let key = CompositeIndexKey { label, properties: properties.clone() };
+        let mut composites = self.composite_indices.write().unwrap();
+        composites.entry(key).or_insert_with(|| Arc::new(RwLock::new(CompositeIndex::new(properties))));
+    }
+
+    /// Drop the composite index on exactly `properties`, in that order; false if there is none
+    ///
+    /// The per-property indexes created with it are kept.
+    pub fn drop_composite_index(&self, label: &Label, properties: &[String]) -> bool {
+        let key = CompositeIndexKey { label: label.clone(), properties: properties.to_vec() };
+        self.composite_indices.write().unwrap().remove(&key).is_some()
+    }
+
+    /// Composite indexes declared on `label`, as ordered property lists
+    pub fn composite_indexes_on(&self, label: &Label) -> Vec<Vec<String>> {
+        self.composite_indices.read().unwrap().keys()
+            .filter(|k| &k.label == label)
+            .map(|k| k.properties.clone())
+            .collect()
+    }
+
+    /// List all composite indexes, sorted by label and properties
+    pub fn list_composite_indexes(&self) -> Vec<(Label, Vec<String>)> {
+        let mut composites: Vec<_> = self.composite_indices.read().unwrap().keys()
+            .map(|k| (k.label.clone(), k.properties.clone()))
+            .collect();
+        composites.sort_by(|a, b| (a.0.as_str(), &a.1).cmp(&(b.0.as_str(), &b.1)));
+        composites
+    }
+
+    /// Look up nodes through a composite index: the leading properties equal
+    /// `prefix`, and the next one lies between `lower` and `upper` (both
+    /// `Unbounded` to skip the range).
+    ///
+    /// Returns `None` when no such composite index exists.
+    pub fn composite_lookup(
+        &self,
+        label: &Label,
+        properties: &[String],
+        prefix: &[PropertyValue],
+        lower: Bound<&PropertyValue>,
+        upper: Bound<&PropertyValue>,
+    ) -> Option<Vec<NodeId>> {
+        let key = CompositeIndexKey { label: label.clone(), properties: properties.to_vec() };
+        let index = self.composite_indices.read().unwrap().get(&key).cloned()?;
+        let index = index.read().unwrap();
+        let entries = index.prefix_entries(prefix, lower, upper);
+        self.range_probes.fetch_add(entries.len() as u64, Ordering::Relaxed);
+        Some(entries.into_iter().flat_map(|(_, nodes)| nodes.iter().cloned()).collect())
+    }
+
+    /// Composite indices on `label` that include `property`
+    fn composites_covering(&self, label: &Label, property: &str) -> Vec<Arc<RwLock<CompositeIndex>>> {
+        let composites = self.composite_indices.read().unwrap();
+        if composites.is_empty() {
+            return Vec::new();
+        }
+        composites.iter()
+            .filter(|(k, _)| &k.label == label && k.properties.iter().any(|p| p == property))
+            .map(|(_, index)| Arc::clone(index))
+            .collect()
     }
 
     /// Get all indexed properties for a label
@@ -307,6 +529,41 @@ mod tests {
         assert_eq!(constraints.len(), 2);
     }
 
+    #[test]
+    fn test_not_null_constraint_and_drop() {
+        let mgr = IndexManager::new();
+        let person = Label::new("Person");
+        assert!(!mgr.has_constraints());
+        mgr.create_not_null_constraint(person.clone(), "name".to_string());
+        mgr.create_unique_constraint(person.clone(), "email".to_string());
+        assert!(mgr.has_not_null_constraint(&person, "name"));
+        assert!(!mgr.has_not_null_constraint(&person, "email"));
+        assert_eq!(
+            mgr.constraints_on(&person),
+            vec![("email".to_string(), ConstraintKind::Unique), ("name".to_string(), ConstraintKind::NotNull)]
+        );
+
+        assert!(mgr.drop_constraint(&person, "name", ConstraintKind::NotNull));
+        assert!(!mgr.drop_constraint(&person, "name", ConstraintKind::NotNull));
+        assert!(mgr.drop_constraint(&person, "email", ConstraintKind::Unique));
+        assert!(!mgr.has_constraints());
+        // The backing index outlives the unique constraint
+        assert!(mgr.has_index(&person, "email"));
+    }
+
+    #[test]
+    fn test_unique_holders_tracks_removals() {
+        let mgr = IndexManager::new();
+        let label = Label::new("Person");
+        let val = PropertyValue::String("a@x".to_string());
+        assert!(mgr.unique_holders(&label, "email", &val).is_empty());
+        mgr.create_unique_constraint(label.clone(), "email".to_string());
+        mgr.constraint_insert(&label, "email", val.clone(), NodeId::new(4));
+        assert_eq!(mgr.unique_holders(&label, "email", &val), vec![NodeId::new(4)]);
+        mgr.constraint_remove(&label, "email", &val, NodeId::new(4));
+        assert!(mgr.unique_holders(&label, "email", &val).is_empty());
+    }
+
     #[test]
     fn test_composite_index() {
         let mgr = IndexManager::new();
@@ -427,6 +684,39 @@ mod tests {
         assert_eq!(mgr.get_indexed_properties(&label).len(), 3);
     }
 
+    #[test]
+    fn test_composite_lookup_follows_property_updates() {
+        use std::ops::Bound::{Excluded, Unbounded};
+        let mgr = IndexManager::new();
+        let doc = Label::new("Doc");
+        let props = vec!["department".to_string(), "date".to_string()];
+        mgr.create_composite_index(doc.clone(), props.clone());
+        assert_eq!(mgr.list_composite_indexes(), vec![(doc.clone(), props.clone())]);
+
+        let legal = PropertyValue::String("legal".to_string());
+        for i in 1..=4 {
+            mgr.index_insert(&doc, "department", legal.clone(), NodeId::new(i));
+            mgr.index_insert(&doc, "date", PropertyValue::Integer(i as i64 * 10), NodeId::new(i));
+        }
+        // Other labels and unrelated properties leave the composite alone
+        mgr.index_insert(&Label::new("Person"), "department", legal.clone(), NodeId::new(9));
+        mgr.index_insert(&doc, "title", PropertyValue::String("x".to_string()), NodeId::new(1));
+
+        let after = |date: i64| {
+            let mut ids = mgr.composite_lookup(&doc, &props, &[legal.clone()], Excluded(&PropertyValue::Integer(date)), Unbounded).unwrap();
+            ids.sort();
+            ids
+        };
+        assert_eq!(after(15), vec![NodeId::new(2), NodeId::new(3), NodeId::new(4)]);
+
+        // Replacing a value re-keys the node
+        mgr.index_remove(&doc, "date", &PropertyValue::Integer(40), NodeId::new(4));
+        mgr.index_insert(&doc, "date", PropertyValue::Integer(5), NodeId::new(4));
+        assert_eq!(after(15), vec![NodeId::new(2), NodeId::new(3)]);
+
+        assert!(mgr.composite_lookup(&doc, &["date".to_string()], &[], Unbounded, Unbounded).is_none());
+    }
+
     #[test]
     fn test_get_indexed_properties_no_indexes() {
         let mgr = IndexManager::new();
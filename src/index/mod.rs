@@ -3,7 +3,9 @@
 //! Provides B-Tree indices for optimizing property lookups.
 
 pub mod property_index;
+pub mod composite_index;
 pub mod manager;
 
 pub use property_index::PropertyIndex;
-pub use manager::{IndexManager, PropertyIndexKey};
+pub use composite_index::CompositeIndex;
+pub use manager::{CompositeIndexKey, ConstraintKind, IndexManager, PropertyIndexKey};
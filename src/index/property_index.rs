@@ -4,6 +4,9 @@
 
 use crate::graph::{NodeId, PropertyValue};
 use std::collections::{BTreeMap, HashSet};
+use std::cmp::Ordering;
+use std::mem::{discriminant, Discriminant};
+use std::ops::Bound::{self, Excluded, Included, Unbounded};
 
 /// Index for a specific property on a specific label
 #[derive(Debug, Clone)]
@@ -48,6 +51,169 @@ impl PropertyIndex {
         }
         result
     }
+
+    /// Entries (one per distinct value) between `lower` and `upper`, using
+    /// WHERE comparison semantics: integers and floats compare with each other,
+    /// every other type only with itself, and null or NaN bounds match nothing.
+    ///
+    /// Unlike [`range`](Self::range), an open side stops at the end of the
+    /// bound's type instead of running into values of other types.
+    pub fn range_entries(&self, lower: Bound<&PropertyValue>, upper: Bound<&PropertyValue>) -> Vec<(&PropertyValue, &HashSet<NodeId>)> {
+        range_segments(lower, upper)
+            .into_iter()
+            .flat_map(|segment| {
+                self.entries_between(segment.lower.clone(), segment.upper.clone(), |v| segment.position(v) == Ordering::Equal)
+            })
+            .collect()
+    }
+
+    /// Node ids of [`range_entries`](Self::range_entries)
+    pub fn range_scan(&self, lower: Bound<&PropertyValue>, upper: Bound<&PropertyValue>) -> Vec<NodeId> {
+        self.range_entries(lower, upper)
+            .into_iter()
+            .flat_map(|(_, nodes)| nodes.iter().cloned())
+            .collect()
+    }
+
+    /// Entries between the bounds; an open side is cut off where `in_type` stops holding
+    fn entries_between(
+        &self,
+        lower: Bound<PropertyValue>,
+        upper: Bound<PropertyValue>,
+        in_type: impl Fn(&PropertyValue) -> bool,
+    ) -> Vec<(&PropertyValue, &HashSet<NodeId>)> {
+        match (&lower, &upper) {
+            (Unbounded, Unbounded) => Vec::new(),
+            (_, Unbounded) => self.index.range((lower, upper)).take_while(|(v, _)| in_type(v)).collect(),
+            (Unbounded, _) => {
+                let mut entries: Vec<_> = self.index.range((lower, upper)).rev().take_while(|(v, _)| in_type(v)).collect();
+                entries.reverse();
+                entries
+            }
+            (Included(lo) | Excluded(lo), Included(hi) | Excluded(hi)) => {
+                // BTreeMap::range panics on inverted (or empty, doubly-excluded) ranges
+                let empty = match (&lower, &upper) {
+                    (Included(_), Included(_)) => lo > hi,
+                    _ => lo >= hi,
+                };
+                if empty {
+                    Vec::new()
+                } else {
+                    self.index.range((lower, upper)).collect()
+                }
+            }
+        }
+    }
+}
+
+/// One run of same-typed index keys that a typed range can match
+///
+/// A numeric range yields an integer and a float segment, since the two
+/// types sort apart in index order; any other range yields one segment.
+pub(crate) struct RangeSegment {
+    pub(crate) lower: Bound<PropertyValue>,
+    pub(crate) upper: Bound<PropertyValue>,
+    kind: Discriminant<PropertyValue>,
+    /// A bound value of the segment's type, to place foreign-typed values
+    reference: PropertyValue,
+}
+
+impl RangeSegment {
+    /// Where `value` falls relative to this segment in index order
+    pub(crate) fn position(&self, value: &PropertyValue) -> Ordering {
+        let is_nan = matches!(value, PropertyValue::Float(f) if f.is_nan());
+        if discriminant(value) != self.kind || is_nan {
+            return if *value < self.reference { Ordering::Less } else { Ordering::Greater };
+        }
+        match &self.lower {
+            Included(lo) if value < lo => return Ordering::Less,
+            Excluded(lo) if value <= lo => return Ordering::Less,
+            _ => {}
+        }
+        match &self.upper {
+            Included(hi) if value > hi => Ordering::Greater,
+            Excluded(hi) if value >= hi => Ordering::Greater,
+            _ => Ordering::Equal,
+        }
+    }
+}
+
+/// Split a typed range into the key segments it can match (see
+/// [`PropertyIndex::range_entries`])
+pub(crate) fn range_segments(lower: Bound<&PropertyValue>, upper: Bound<&PropertyValue>) -> Vec<RangeSegment> {
+    let values: Vec<&PropertyValue> = [lower, upper]
+        .into_iter()
+        .filter_map(|b| match b {
+            Included(v) | Excluded(v) => Some(v),
+            Unbounded => None,
+        })
+        .collect();
+    let Some(&first) = values.first() else {
+        return Vec::new();
+    };
+    if values.iter().any(|v| matches!(v, PropertyValue::Null) || matches!(v, PropertyValue::Float(f) if f.is_nan())) {
+        return Vec::new();
+    }
+
+    let segment = |lower: Bound<PropertyValue>, upper: Bound<PropertyValue>| {
+        let reference = match (&lower, &upper) {
+            (Included(v) | Excluded(v), _) | (_, Included(v) | Excluded(v)) => v.clone(),
+            (Unbounded, Unbounded) => return None,
+        };
+        Some(RangeSegment { kind: discriminant(&reference), reference, lower, upper })
+    };
+
+    if values.iter().all(|v| matches!(v, PropertyValue::Integer(_) | PropertyValue::Float(_))) {
+        let mut segments = Vec::new();
+        if let (Some(lo), Some(hi)) = (integer_bound(lower, true), integer_bound(upper, false)) {
+            segments.extend(segment(lo, hi));
+        }
+        segments.extend(segment(float_bound(lower), float_bound(upper)));
+        return segments;
+    }
+
+    if values.iter().any(|v| discriminant(*v) != discriminant(first)) {
+        return Vec::new();
+    }
+    segment(lower.cloned(), upper.cloned()).into_iter().collect()
+}
+
+/// A numeric bound restated over integer keys; `None` when no integer can satisfy it
+fn integer_bound(bound: Bound<&PropertyValue>, is_lower: bool) -> Option<Bound<PropertyValue>> {
+    let int = |i: i64| PropertyValue::Integer(i);
+    match bound {
+        Unbounded => Some(Unbounded),
+        Included(PropertyValue::Integer(i)) => Some(Included(int(*i))),
+        Excluded(PropertyValue::Integer(i)) => Some(Excluded(int(*i))),
+        Included(PropertyValue::Float(f)) | Excluded(PropertyValue::Float(f)) => {
+            if f.is_infinite() {
+                // -inf below / +inf above admits every integer; the reverse admits none
+                return (is_lower == (*f < 0.0)).then_some(Unbounded);
+            }
+            if f.fract() == 0.0 {
+                let i = int(*f as i64);
+                Some(if matches!(bound, Included(_)) { Included(i) } else { Excluded(i) })
+            } else if is_lower {
+                Some(Included(int(f.ceil() as i64)))
+            } else {
+                Some(Included(int(f.floor() as i64)))
+            }
+        }
+        _ => None,
+    }
+}
+
+/// A numeric bound restated over float keys
+fn float_bound(bound: Bound<&PropertyValue>) -> Bound<PropertyValue> {
+    let as_float = |v: &PropertyValue| match v {
+        PropertyValue::Integer(i) => PropertyValue::Float(*i as f64),
+        other => other.clone(),
+    };
+    match bound {
+        Included(v) => Included(as_float(v)),
+        Excluded(v) => Excluded(as_float(v)),
+        Unbounded => Unbounded,
+    }
 }
 
 impl Default for PropertyIndex {
@@ -101,4 +267,37 @@ mod tests {
             assert!(results.contains(&NodeId::new(i)));
         }
     }
+
+    #[test]
+    fn test_range_scan_respects_types() {
+        let mut index = PropertyIndex::new();
+        for i in 1..=10 {
+            index.insert(PropertyValue::Integer(i), NodeId::new(i as u64));
+        }
+        index.insert(PropertyValue::Float(4.5), NodeId::new(45));
+        index.insert(PropertyValue::Float(f64::NAN), NodeId::new(99));
+        index.insert(PropertyValue::String("zzz".into()), NodeId::new(100));
+        index.insert(PropertyValue::Boolean(true), NodeId::new(101));
+
+        let sorted = |mut ids: Vec<NodeId>| { ids.sort(); ids.into_iter().map(|id| id.as_u64()).collect::<Vec<_>>() };
+
+        // n.v > 8: no string or NaN tail
+        let gt = index.range_scan(Excluded(&PropertyValue::Integer(8)), Unbounded);
+        assert_eq!(sorted(gt), vec![9, 10]);
+        // n.v < 3: no boolean/null head
+        let lt = index.range_scan(Unbounded, Excluded(&PropertyValue::Integer(3)));
+        assert_eq!(sorted(lt), vec![1, 2]);
+        // 4 <= n.v < 5.5 mixes integer and float keys
+        let mixed = index.range_scan(Included(&PropertyValue::Integer(4)), Excluded(&PropertyValue::Float(5.5)));
+        assert_eq!(sorted(mixed), vec![4, 5, 45]);
+        // Fractional bounds round inward over integers
+        let frac = index.range_scan(Excluded(&PropertyValue::Float(2.5)), Included(&PropertyValue::Float(4.0)));
+        assert_eq!(sorted(frac), vec![3, 4]);
+
+        // Strings only compare to strings; inverted ranges are empty, not a panic
+        assert_eq!(sorted(index.range_scan(Included(&PropertyValue::String("a".into())), Unbounded)), vec![100]);
+        assert!(index.range_scan(Excluded(&PropertyValue::Integer(7)), Excluded(&PropertyValue::Integer(7))).is_empty());
+        assert!(index.range_scan(Included(&PropertyValue::Integer(9)), Included(&PropertyValue::Integer(2))).is_empty());
+        assert!(index.range_scan(Included(&PropertyValue::Integer(1)), Included(&PropertyValue::String("z".into()))).is_empty());
+    }
 }
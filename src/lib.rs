@@ -152,7 +152,7 @@ pub use persistence::{
     PersistentStorage, StorageError, StorageResult,
     Tenant, TenantManager, ResourceQuotas, ResourceUsage, TenantError, TenantResult,
     Wal, WalEntry, WalError, WalResult,
-    AutoEmbedConfig, NLQConfig, LLMProvider,
+    AutoEmbedConfig, NLQConfig, LLMProvider, RetryPolicy,
 };
 
 pub use embed::{
@@ -166,7 +166,7 @@ pub use nlq::{
 pub use raft::{
     RaftNode, RaftNodeId, RaftError, RaftResult,
     GraphStateMachine, Request as RaftRequest, Response as RaftResponse,
-    ClusterConfig, ClusterManager, NodeId as RaftNodeIdWithAddr,
+    ClusterConfig, ClusterManager, NodeId as RaftNodeIdWithAddr, RaftBackedStore,
 };
 
 pub use rdf::{
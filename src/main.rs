@@ -555,7 +555,7 @@ async fn start_server() {
 
     // Start background indexer now that store is wrapped in Arc
     if let Some(ref pm) = persistence {
-        pm.start_indexer(&*store.read().await, rx);
+        pm.start_indexer(&store, rx).await;
     }
 
     // Start HTTP server for Visualizer API (port from --http-port, default 8080)
@@ -1,15 +1,19 @@
 //! NLQ Client for LLM interactions
 
-use crate::persistence::tenant::{NLQConfig, LLMProvider};
+use crate::persistence::tenant::{NLQConfig, LLMProvider, RetryPolicy};
 use crate::nlq::{NLQError, NLQResult};
 use serde::{Deserialize, Serialize};
 use reqwest::Client;
+use std::future::Future;
 use std::time::Duration;
+use tracing::warn;
 
 pub struct NLQClient {
     client: Client,
     config: NLQConfig,
     api_base_url: String,
+    /// Client for `config.fallback`, tried once the primary provider gives up
+    fallback: Option<Box<NLQClient>>,
 }
 
 impl NLQClient {
@@ -31,14 +35,37 @@ impl NLQClient {
             }
         });
 
+        let fallback = match &config.fallback {
+            Some(fallback) => Some(Box::new(NLQClient::new(fallback)?)),
+            None => None,
+        };
+
         Ok(Self {
             client,
             config: config.clone(),
             api_base_url,
+            fallback,
         })
     }
 
+    /// Ask the provider for Cypher, retrying transient failures per
+    /// `config.retry` and then falling back to `config.fallback` if set
     pub async fn generate_cypher(&self, prompt: &str) -> NLQResult<String> {
+        let mut client = self;
+        loop {
+            let result = with_retry(&client.config.retry, || client.request_cypher(prompt)).await;
+            match (result, &client.fallback) {
+                (Err(e), Some(fallback)) => {
+                    warn!("NLQ provider {:?} failed ({}); falling back to {:?}", client.config.provider, e, fallback.config.provider);
+                    client = fallback.as_ref();
+                }
+                (result, _) => return result,
+            }
+        }
+    }
+
+    /// One request to the configured provider
+    async fn request_cypher(&self, prompt: &str) -> NLQResult<String> {
         match self.config.provider {
             LLMProvider::OpenAI => self.openai_chat(prompt).await,
             LLMProvider::Ollama => self.ollama_chat(prompt).await,
@@ -97,7 +124,7 @@ impl NLQClient {
             .map_err(|e| NLQError::NetworkError(e.to_string()))?;
 
         if !resp.status().is_success() {
-            return Err(NLQError::ApiError(format!("OpenAI error: {}", resp.status())));
+            return Err(http_error("OpenAI", resp.status(), String::new()));
         }
 
         let result: Response = resp.json().await.map_err(|e| NLQError::SerializationError(e.to_string()))?;
@@ -133,7 +160,7 @@ impl NLQClient {
             .map_err(|e| NLQError::NetworkError(e.to_string()))?;
 
         if !resp.status().is_success() {
-            return Err(NLQError::ApiError(format!("Ollama error: {}", resp.status())));
+            return Err(http_error("Ollama", resp.status(), String::new()));
         }
 
         let result: Response = resp.json().await.map_err(|e| NLQError::SerializationError(e.to_string()))?;
@@ -218,8 +245,9 @@ impl NLQClient {
             .map_err(|e| NLQError::NetworkError(e.to_string()))?;
 
         if !resp.status().is_success() {
+            let status = resp.status();
             let text = resp.text().await.unwrap_or_default();
-            return Err(NLQError::ApiError(format!("Gemini error: {}", text)));
+            return Err(http_error("Gemini", status, text));
         }
 
         let result: Response = resp.json().await.map_err(|e| NLQError::SerializationError(e.to_string()))?;
@@ -236,6 +264,40 @@ impl NLQClient {
     }
 }
 
+/// Error for a non-success response; `body` is appended when not empty
+fn http_error(provider: &str, status: reqwest::StatusCode, body: String) -> NLQError {
+    let message = if body.is_empty() {
+        format!("{} error: {}", provider, status)
+    } else {
+        format!("{} error: {}: {}", provider, status, body)
+    };
+    NLQError::HttpStatus { status: status.as_u16(), message }
+}
+
+/// Run `attempt` until it succeeds, fails with a non-retryable error, or
+/// `policy.max_retries` retries are used up, sleeping with exponential
+/// backoff between attempts
+async fn with_retry<T, F, Fut>(policy: &RetryPolicy, mut attempt: F) -> NLQResult<T>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = NLQResult<T>>,
+{
+    let mut backoff = Duration::from_millis(policy.initial_backoff_ms);
+    let max_backoff = Duration::from_millis(policy.max_backoff_ms);
+    let mut retries = 0;
+    loop {
+        match attempt().await {
+            Err(e) if e.is_retryable() && retries < policy.max_retries => {
+                retries += 1;
+                warn!("NLQ request failed ({}); retry {}/{} in {:?}", e, retries, policy.max_retries, backoff);
+                tokio::time::sleep(backoff).await;
+                backoff = (backoff * 2).min(max_backoff);
+            }
+            result => return result,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -248,6 +310,8 @@ mod tests {
             api_key: None,
             api_base_url: None,
             system_prompt: None,
+            retry: Default::default(),
+            fallback: None,
         }
     }
 
@@ -277,6 +341,8 @@ mod tests {
             api_key: Some("sk-test".to_string()),
             api_base_url: None,
             system_prompt: Some("You are a Cypher expert.".to_string()),
+            retry: Default::default(),
+            fallback: None,
         };
         let client = NLQClient::new(&config);
         assert!(client.is_ok());
@@ -291,6 +357,8 @@ mod tests {
             api_key: None,
             api_base_url: Some("http://localhost:11434".to_string()),
             system_prompt: None,
+            retry: Default::default(),
+            fallback: None,
         };
         let client = NLQClient::new(&config);
         assert!(client.is_ok());
@@ -305,6 +373,8 @@ mod tests {
             api_key: Some("test-key".to_string()),
             api_base_url: None,
             system_prompt: None,
+            retry: Default::default(),
+            fallback: None,
         };
         let client = NLQClient::new(&config);
         assert!(client.is_ok());
@@ -319,6 +389,8 @@ mod tests {
             api_key: Some("test-key".to_string()),
             api_base_url: None,
             system_prompt: None,
+            retry: Default::default(),
+            fallback: None,
         };
         let client = NLQClient::new(&config);
         assert!(client.is_ok());
@@ -333,6 +405,8 @@ mod tests {
             api_key: Some("test-key".to_string()),
             api_base_url: Some("https://myendpoint.openai.azure.com".to_string()),
             system_prompt: None,
+            retry: Default::default(),
+            fallback: None,
         };
         let client = NLQClient::new(&config);
         assert!(client.is_ok());
@@ -347,6 +421,8 @@ mod tests {
             api_key: None,
             api_base_url: None,
             system_prompt: None,
+            retry: Default::default(),
+            fallback: None,
         };
         let client = NLQClient::new(&config);
         assert!(client.is_ok());
@@ -361,6 +437,8 @@ mod tests {
             api_key: Some("sk-test".to_string()),
             api_base_url: Some("https://custom.api.example.com/v1".to_string()),
             system_prompt: Some("Custom system prompt".to_string()),
+            retry: Default::default(),
+            fallback: None,
         };
         let client = NLQClient::new(&config);
         assert!(client.is_ok());
@@ -375,6 +453,8 @@ mod tests {
             api_key: Some("test-key".to_string()),
             api_base_url: Some("https://test.openai.azure.com".to_string()),
             system_prompt: None,
+            retry: Default::default(),
+            fallback: None,
         };
         let client = NLQClient::new(&config).unwrap();
         let result = client.generate_cypher("test").await;
@@ -401,6 +481,8 @@ mod tests {
             api_key: Some("sk-test".to_string()),
             api_base_url: None,
             system_prompt: None,
+            retry: Default::default(),
+            fallback: None,
         };
         let client = NLQClient::new(&config).unwrap();
         assert_eq!(client.api_base_url, "https://api.openai.com/v1");
@@ -413,6 +495,8 @@ mod tests {
             api_key: None,
             api_base_url: None,
             system_prompt: None,
+            retry: Default::default(),
+            fallback: None,
         };
         let client_ollama = NLQClient::new(&config_ollama).unwrap();
         assert_eq!(client_ollama.api_base_url, "http://localhost:11434");
@@ -425,6 +509,8 @@ mod tests {
             api_key: Some("key".to_string()),
             api_base_url: None,
             system_prompt: None,
+            retry: Default::default(),
+            fallback: None,
         };
         let client_gemini = NLQClient::new(&config_gemini).unwrap();
         assert_eq!(client_gemini.api_base_url, "https://generativelanguage.googleapis.com/v1beta");
@@ -437,6 +523,8 @@ mod tests {
             api_key: Some("key".to_string()),
             api_base_url: None,
             system_prompt: None,
+            retry: Default::default(),
+            fallback: None,
         };
         let client_anthropic = NLQClient::new(&config_anthropic).unwrap();
         assert_eq!(client_anthropic.api_base_url, "https://api.anthropic.com/v1");
@@ -449,6 +537,8 @@ mod tests {
             api_key: Some("key".to_string()),
             api_base_url: None,
             system_prompt: None,
+            retry: Default::default(),
+            fallback: None,
         };
         let client_azure = NLQClient::new(&config_azure).unwrap();
         assert_eq!(client_azure.api_base_url, "");
@@ -461,6 +551,8 @@ mod tests {
             api_key: None,
             api_base_url: None,
             system_prompt: None,
+            retry: Default::default(),
+            fallback: None,
         };
         let client_cc = NLQClient::new(&config_cc).unwrap();
         assert_eq!(client_cc.api_base_url, "");
@@ -479,6 +571,8 @@ mod tests {
             api_key: Some("sk-test".to_string()),
             api_base_url: Some("https://custom.openai.proxy.com/v1".to_string()),
             system_prompt: None,
+            retry: Default::default(),
+            fallback: None,
         };
         let client = NLQClient::new(&config).unwrap();
         assert_eq!(client.api_base_url, "https://custom.openai.proxy.com/v1");
@@ -505,6 +599,8 @@ mod tests {
             api_key: Some("key".to_string()),
             api_base_url: None,
             system_prompt: None,
+            retry: Default::default(),
+            fallback: None,
         };
         let client = NLQClient::new(&config).unwrap();
         let result = client.generate_cypher("test").await;
@@ -522,11 +618,74 @@ mod tests {
             api_key: None,
             api_base_url: None,
             system_prompt: Some("You are a graph database expert specialized in medical data.".to_string()),
+            retry: Default::default(),
+            fallback: None,
         };
         let client = NLQClient::new(&config);
         assert!(client.is_ok());
     }
 
+    fn fast_retry(max_retries: u32) -> RetryPolicy {
+        RetryPolicy { max_retries, initial_backoff_ms: 1, max_backoff_ms: 4 }
+    }
+
+    #[tokio::test]
+    async fn test_retry_recovers_from_rate_limit() {
+        let calls = std::cell::Cell::new(0);
+        let result = with_retry(&fast_retry(3), || {
+            calls.set(calls.get() + 1);
+            let first = calls.get() == 1;
+            async move {
+                if first {
+                    Err(NLQError::HttpStatus { status: 429, message: "OpenAI error: 429 Too Many Requests".to_string() })
+                } else {
+                    Ok("MATCH (n) RETURN n".to_string())
+                }
+            }
+        }).await;
+        assert_eq!(result.unwrap(), "MATCH (n) RETURN n");
+        assert_eq!(calls.get(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_retry_gives_up() {
+        // Retryable errors stop after max_retries
+        let calls = std::cell::Cell::new(0);
+        let result: NLQResult<String> = with_retry(&fast_retry(2), || {
+            calls.set(calls.get() + 1);
+            async { Err(NLQError::HttpStatus { status: 503, message: "unavailable".to_string() }) }
+        }).await;
+        assert!(result.is_err());
+        assert_eq!(calls.get(), 3);
+
+        // Anything else is not retried at all
+        let calls = std::cell::Cell::new(0);
+        let result: NLQResult<String> = with_retry(&fast_retry(2), || {
+            calls.set(calls.get() + 1);
+            async { Err(NLQError::HttpStatus { status: 401, message: "unauthorized".to_string() }) }
+        }).await;
+        assert!(result.is_err());
+        assert_eq!(calls.get(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_generate_cypher_falls_back() {
+        // Anthropic chat is not implemented, so the primary always fails
+        let config = NLQConfig {
+            enabled: true,
+            provider: LLMProvider::Anthropic,
+            model: "claude-3".to_string(),
+            api_key: Some("key".to_string()),
+            api_base_url: None,
+            system_prompt: None,
+            retry: fast_retry(1),
+            fallback: Some(Box::new(mock_config())),
+        };
+        let client = NLQClient::new(&config).unwrap();
+        let result = client.generate_cypher("Find all people").await.unwrap();
+        assert_eq!(result, "MATCH (n) RETURN n LIMIT 10");
+    }
+
     #[test]
     fn test_nlq_error_display() {
         let e1 = NLQError::ApiError("test api error".to_string());
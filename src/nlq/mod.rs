@@ -4,13 +4,19 @@
 
 pub mod client;
 
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 use thiserror::Error;
+use crate::graph::GraphStore;
+use crate::query::parse_query;
 use crate::persistence::tenant::NLQConfig;
 
 #[derive(Error, Debug)]
 pub enum NLQError {
     #[error("LLM API error: {0}")]
     ApiError(String),
+    /// The provider answered with a non-success HTTP status
+    #[error("LLM API error: {message}")]
+    HttpStatus { status: u16, message: String },
     #[error("Configuration error: {0}")]
     ConfigError(String),
     #[error("Network error: {0}")]
@@ -21,8 +27,26 @@ pub enum NLQError {
     ValidationError(String),
 }
 
+impl NLQError {
+    /// Whether retrying the same request may succeed: network failures,
+    /// rate limiting (429) and server errors (5xx)
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            NLQError::NetworkError(_) => true,
+            NLQError::HttpStatus { status, .. } => *status == 429 || (500..600).contains(status),
+            _ => false,
+        }
+    }
+}
+
 pub type NLQResult<T> = Result<T, NLQError>;
 
+/// Nodes sampled per label, and edges per type, when introspecting a schema
+const SCHEMA_SAMPLE_SIZE: usize = 100;
+
+/// Most properties listed per label in a generated schema
+const SCHEMA_MAX_PROPERTIES: usize = 10;
+
 pub struct NLQPipeline {
     client: client::NLQClient,
 }
@@ -59,6 +83,70 @@ impl NLQPipeline {
         }
     }
 
+    /// Translate `question` using a schema generated from `store`
+    pub async fn text_to_cypher_auto(&self, question: &str, store: &GraphStore) -> NLQResult<String> {
+        let schema = Self::schema_from_store(store);
+        self.text_to_cypher(question, &schema).await
+    }
+
+    /// Build a compact schema summary for the prompt by sampling `store`
+    ///
+    /// Lists each label with its node count, the `(Label)-[:TYPE]->(Label)`
+    /// patterns seen on sampled edges, and the properties carried by at least
+    /// half of each label's sampled nodes.
+    pub fn schema_from_store(store: &GraphStore) -> String {
+        let labels: BTreeMap<&str, _> = store.all_labels().into_iter()
+            .map(|label| (label.as_str(), label))
+            .collect();
+
+        let mut summary = String::from("Node Labels:\n");
+        for (name, label) in &labels {
+            summary.push_str(&format!("  :{} ({} nodes)\n", name, store.label_node_count(label)));
+        }
+
+        let mut patterns: BTreeSet<String> = BTreeSet::new();
+        for edge_type in store.all_edge_types() {
+            let Some(edge_ids) = store.edge_type_index_ids(edge_type) else { continue };
+            for edge in edge_ids.iter().take(SCHEMA_SAMPLE_SIZE).filter_map(|id| store.get_edge(*id)) {
+                let (Some(source), Some(target)) = (store.get_node(edge.source), store.get_node(edge.target)) else {
+                    continue;
+                };
+                for src in &source.labels {
+                    for tgt in &target.labels {
+                        patterns.insert(format!("({})-[:{}]->({})", src.as_str(), edge_type.as_str(), tgt.as_str()));
+                    }
+                }
+            }
+        }
+        summary.push_str("\nRelationship Patterns:\n");
+        for pattern in &patterns {
+            summary.push_str(&format!("  {}\n", pattern));
+        }
+
+        summary.push_str("\nKey Properties:\n");
+        for (name, label) in &labels {
+            let Some(node_ids) = store.label_index_ids(label) else { continue };
+            let mut counts: HashMap<&str, usize> = HashMap::new();
+            let mut sampled = 0;
+            for node in node_ids.iter().take(SCHEMA_SAMPLE_SIZE).filter_map(|id| store.get_node(*id)) {
+                sampled += 1;
+                for key in node.properties.keys() {
+                    *counts.entry(key.as_str()).or_default() += 1;
+                }
+            }
+            let mut common: Vec<(&str, usize)> = counts.into_iter()
+                .filter(|(_, count)| count * 2 >= sampled)
+                .collect();
+            common.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+            if !common.is_empty() {
+                let props: Vec<&str> = common.iter().take(SCHEMA_MAX_PROPERTIES).map(|(key, _)| *key).collect();
+                summary.push_str(&format!("  :{} has properties: {}\n", name, props.join(", ")));
+            }
+        }
+
+        summary
+    }
+
     /// Extract a Cypher query from an LLM response that may contain markdown
     /// fences, explanations, or multiple code blocks.
     fn extract_cypher(response: &str) -> String {
@@ -99,14 +187,86 @@ impl NLQPipeline {
             .to_string()
     }
 
+    /// Whether `query` is a single statement with no write or schema clauses
+    ///
+    /// A query the parser accepts is judged by its AST. Read forms the parser
+    /// does not support (e.g. a leading UNWIND) fall back to the keywords: the
+    /// query must open with a read clause, and no write keyword may appear
+    /// outside string literals, property keys and labels.
     pub fn is_safe_query(&self, query: &str) -> bool {
-        let trimmed = query.trim().to_uppercase();
-        trimmed.starts_with("MATCH") ||
-        trimmed.starts_with("RETURN") ||
-        trimmed.starts_with("UNWIND") ||
-        trimmed.starts_with("CALL") ||
-        trimmed.starts_with("WITH")
+        let Some(keywords) = statement_keywords(query) else {
+            return false;
+        };
+        match parse_query(query) {
+            Ok(ast) => ast.is_read_only(),
+            Err(_) => {
+                keywords.first().is_some_and(|k| READ_KEYWORDS.contains(&k.as_str()))
+                    && !keywords.iter().any(|k| WRITE_KEYWORDS.contains(&k.as_str()))
+            }
+        }
+    }
+}
+
+/// Clauses a read-only query may open with
+const READ_KEYWORDS: &[&str] = &["MATCH", "OPTIONAL", "RETURN", "UNWIND", "CALL", "WITH"];
+
+/// Keywords of clauses that write data or change the schema
+const WRITE_KEYWORDS: &[&str] = &["CREATE", "MERGE", "DELETE", "DETACH", "SET", "REMOVE", "DROP", "FOREACH"];
+
+/// Uppercased words of a Cypher statement that could be keywords
+///
+/// Skips string literals, backquoted names, comments, and words following
+/// `.`, `:` or `$` (property keys, labels, parameters). Returns `None` if the
+/// text holds more than one statement; a trailing `;` is allowed.
+fn statement_keywords(query: &str) -> Option<Vec<String>> {
+    let chars: Vec<char> = query.chars().collect();
+    let mut words = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        match chars[i] {
+            quote @ ('\'' | '"' | '`') => {
+                i += 1;
+                while i < chars.len() && chars[i] != quote {
+                    i += if chars[i] == '\\' { 2 } else { 1 };
+                }
+                i += 1;
+            }
+            '/' if chars.get(i + 1) == Some(&'/') => {
+                while i < chars.len() && chars[i] != '\n' {
+                    i += 1;
+                }
+            }
+            '/' if chars.get(i + 1) == Some(&'*') => {
+                i += 2;
+                while i < chars.len() && !(chars[i] == '*' && chars.get(i + 1) == Some(&'/')) {
+                    i += 1;
+                }
+                i += 2;
+            }
+            ';' => {
+                if chars[i + 1..].iter().any(|c| !c.is_whitespace()) {
+                    return None;
+                }
+                i += 1;
+            }
+            c if c.is_alphanumeric() || c == '_' => {
+                let start = i;
+                while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+                    i += 1;
+                }
+                let qualified = chars[..start]
+                    .iter()
+                    .rev()
+                    .find(|c| !c.is_whitespace())
+                    .is_some_and(|c| matches!(c, '.' | ':' | '$'));
+                if !qualified {
+                    words.push(chars[start..i].iter().collect::<String>().to_uppercase());
+                }
+            }
+            _ => i += 1,
+        }
     }
+    Some(words)
 }
 
 #[cfg(test)]
@@ -122,6 +282,8 @@ mod tests {
             api_key: None,
             api_base_url: None,
             system_prompt: None,
+            retry: Default::default(),
+            fallback: None,
         }).unwrap()
     }
 
@@ -143,6 +305,46 @@ mod tests {
         assert!(pipeline.is_safe_query("match (n) return n")); // lowercase
     }
 
+    #[test]
+    fn test_safe_query_keywords_in_identifiers() {
+        let pipeline = make_pipeline();
+        assert!(pipeline.is_safe_query("MATCH (n) RETURN n.created"));
+        assert!(pipeline.is_safe_query("MATCH (n) RETURN n.createdAt, n.deleted"));
+        assert!(pipeline.is_safe_query("MATCH (a:Asset) RETURN a.settings"));
+    }
+
+    #[test]
+    fn test_unsafe_writes_after_read_clauses() {
+        let pipeline = make_pipeline();
+        assert!(!pipeline.is_safe_query("MATCH (n:Person) DELETE n"));
+        assert!(!pipeline.is_safe_query("MATCH (n:Person) DETACH DELETE n"));
+        assert!(!pipeline.is_safe_query("MATCH (n:Person) SET n.age = 1"));
+        assert!(!pipeline.is_safe_query("MATCH (n:Person) REMOVE n.age"));
+        assert!(!pipeline.is_safe_query("MATCH (a), (b) CREATE (a)-[:KNOWS]->(b)"));
+        assert!(!pipeline.is_safe_query("MATCH (n) RETURN n UNION MATCH (m) DELETE m"));
+        assert!(!pipeline.is_safe_query("not cypher at all"));
+        // Read forms the parser does not take still reject writes
+        assert!(!pipeline.is_safe_query("UNWIND [1,2,3] AS x CREATE (:N {v: x})"));
+        assert!(!pipeline.is_safe_query("WITH 1 AS x MATCH (n) DETACH DELETE n"));
+    }
+
+    #[test]
+    fn test_unsafe_multiple_statements() {
+        let pipeline = make_pipeline();
+        assert!(!pipeline.is_safe_query("MATCH (n) RETURN n; MATCH (m) DELETE m"));
+        assert!(!pipeline.is_safe_query("UNWIND [1] AS x RETURN x; DROP INDEX idx"));
+        assert!(!pipeline.is_safe_query("MATCH (n) RETURN n; MATCH (m) RETURN m"));
+        assert!(pipeline.is_safe_query("MATCH (n) RETURN n;"));
+        assert!(pipeline.is_safe_query("MATCH (n) WHERE n.note = 'a; b' RETURN n"));
+    }
+
+    #[test]
+    fn test_unparsed_read_ignores_keywords_in_literals_and_keys() {
+        let pipeline = make_pipeline();
+        assert!(pipeline.is_safe_query("UNWIND ['CREATE', \"DELETE\"] AS x RETURN x"));
+        assert!(pipeline.is_safe_query("WITH 1 AS x MATCH (n:Set) RETURN n.remove, n.`delete`"));
+    }
+
     #[test]
     fn test_unsafe_write_queries() {
         let pipeline = make_pipeline();
@@ -347,6 +549,39 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    fn demo_store() -> GraphStore {
+        let mut store = GraphStore::new();
+        let alice = store.create_node("Person");
+        store.get_node_mut(alice).unwrap().set_property("name", "Alice");
+        store.get_node_mut(alice).unwrap().set_property("age", 30i64);
+        let bob = store.create_node("Person");
+        store.get_node_mut(bob).unwrap().set_property("name", "Bob");
+        let acme = store.create_node("Company");
+        store.get_node_mut(acme).unwrap().set_property("name", "Acme");
+        store.create_edge(alice, bob, "KNOWS").unwrap();
+        store.create_edge(alice, acme, "WORKS_AT").unwrap();
+        store
+    }
+
+    #[test]
+    fn test_schema_from_store() {
+        let schema = NLQPipeline::schema_from_store(&demo_store());
+        assert!(schema.contains(":Person (2 nodes)"));
+        assert!(schema.contains(":Company (1 nodes)"));
+        assert!(schema.contains("(Person)-[:KNOWS]->(Person)"));
+        assert!(schema.contains("(Person)-[:WORKS_AT]->(Company)"));
+        // `name` is on every Person, `age` on half of them
+        assert!(schema.contains(":Person has properties: name, age"));
+        assert_eq!(schema, NLQPipeline::schema_from_store(&demo_store()));
+    }
+
+    #[tokio::test]
+    async fn test_text_to_cypher_auto_with_mock() {
+        let pipeline = make_pipeline();
+        let cypher = pipeline.text_to_cypher_auto("Who does Alice know?", &demo_store()).await.unwrap();
+        assert!(cypher.contains("MATCH"));
+    }
+
     #[test]
     fn test_extract_cypher_plain_fence_no_lang_tag() {
         let input = "```\nRETURN 42\n```";
@@ -372,6 +607,8 @@ mod tests {
             api_key: Some("sk-test".to_string()),
             api_base_url: None,
             system_prompt: None,
+            retry: Default::default(),
+            fallback: None,
         };
         let pipeline = NLQPipeline::new(config);
         assert!(pipeline.is_ok());
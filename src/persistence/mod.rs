@@ -35,6 +35,21 @@
 //! through the WAL first (for durability), then to RocksDB (for indexed storage). On
 //! startup, any WAL entries written after the last checkpoint are replayed to bring the
 //! in-memory graph state up to date.
+//!
+//! ## Snapshot + log recovery
+//!
+//! Rebuilding a large tenant by scanning all of RocksDB is slow. `snapshot_tenant` writes
+//! a full-state image of one tenant to `<base>/checkpoints/` stamped with the WAL sequence
+//! it covers, and rolls the WAL over to a new file. `recover` then loads the latest image
+//! and replays only the WAL entries after it — the same snapshot-plus-log pattern Raft
+//! uses for log compaction. `snapshot_due` reports when a tenant has logged
+//! `SAMYAMA_SNAPSHOT_INTERVAL` entries of its own (default 5000, matching the Raft
+//! snapshot policy) since its last image, and `snapshot_if_due` takes that snapshot on a
+//! blocking worker so writers never wait on it.
+//!
+//! Node and edge writes are logged as full records (`CreateNode`/`CreateEdge` replace
+//! the replayed entity wholesale), so removed properties and label changes survive
+//! replay; in property updates a `Null` value removes the key.
 
 pub mod storage;
 pub mod tenant;
@@ -43,15 +58,16 @@ pub mod wal;
 pub use storage::{PersistentStorage, StorageError, StorageResult};
 pub use tenant::{
     ResourceQuotas, ResourceUsage, Tenant, TenantError, TenantManager, TenantResult,
-    AutoEmbedConfig, NLQConfig, AgentConfig, ToolConfig, LLMProvider,
+    AutoEmbedConfig, NLQConfig, AgentConfig, ToolConfig, LLMProvider, RetryPolicy,
 };
 pub use wal::{Wal, WalEntry, WalError, WalResult};
 
-use crate::graph::{Edge, Node, PropertyMap, GraphStore};
+use crate::graph::{Edge, EdgeId, EdgeType, Label, Node, NodeId, PropertyMap, GraphStore};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
 use std::path::Path;
 use std::sync::Arc;
-// warn removed - was unused import causing compiler warning
-use tracing::info;
+use tracing::{info, warn};
 
 /// Integrated persistence manager combining WAL, storage, and tenancy
 pub struct PersistenceManager {
@@ -63,6 +79,39 @@ pub struct PersistenceManager {
     wal: Arc<std::sync::Mutex<Wal>>,
     /// Tenant manager
     tenants: Arc<TenantManager>,
+    /// WAL sequence of each tenant's latest snapshot (loaded lazily)
+    snapshot_sequences: std::sync::Mutex<HashMap<String, u64>>,
+    /// WAL entries logged for each tenant since its latest snapshot
+    writes_since_snapshot: std::sync::Mutex<HashMap<String, u64>>,
+    /// Tenants with a `snapshot_if_due` snapshot still being written
+    snapshots_in_flight: std::sync::Mutex<HashSet<String>>,
+    /// Tenant WAL entries after which `snapshot_due` reports a new snapshot is needed
+    snapshot_interval: u64,
+}
+
+/// Default number of WAL entries between tenant snapshots, matching the Raft
+/// `LogsSinceLast(5000)` snapshot policy
+pub const DEFAULT_SNAPSHOT_INTERVAL: u64 = 5000;
+
+/// Full-state image of one tenant as of a WAL sequence number
+#[derive(Serialize, Deserialize)]
+struct TenantSnapshot {
+    /// Tenant the snapshot was taken of
+    tenant: String,
+    /// Last WAL sequence reflected in `nodes` and `edges`
+    sequence: u64,
+    nodes: Vec<Node>,
+    edges: Vec<Edge>,
+}
+
+/// Tenant state rebuilt by recovery, before it is handed to the caller
+struct RecoveredState {
+    nodes: Vec<Node>,
+    edges: Vec<Edge>,
+    /// WAL entries applied on top of the snapshot (0 for a storage scan)
+    wal_entries_replayed: u64,
+    /// Sequence of the snapshot the state started from, if any
+    snapshot_sequence: Option<u64>,
 }
 
 impl PersistenceManager {
@@ -78,6 +127,7 @@ impl PersistenceManager {
         std::fs::create_dir_all(&storage_path)?;
         std::fs::create_dir_all(&wal_path)?;
         std::fs::create_dir_all(&vector_path)?;
+        std::fs::create_dir_all(base_path.join("checkpoints"))?;
 
         info!("Initializing persistence manager at: {:?}", base_path);
 
@@ -98,6 +148,11 @@ impl PersistenceManager {
             storage: Arc::new(storage),
             wal: Arc::new(std::sync::Mutex::new(wal)),
             tenants: Arc::new(tenants),
+            snapshot_sequences: std::sync::Mutex::new(HashMap::new()),
+            writes_since_snapshot: std::sync::Mutex::new(HashMap::new()),
+            snapshots_in_flight: std::sync::Mutex::new(HashSet::new()),
+            snapshot_interval: std::env::var("SAMYAMA_SNAPSHOT_INTERVAL")
+                .ok().and_then(|s| s.parse().ok()).unwrap_or(DEFAULT_SNAPSHOT_INTERVAL),
         })
     }
 
@@ -112,10 +167,22 @@ impl PersistenceManager {
     }
 
     /// Start the background indexer for a store
-    pub fn start_indexer(&self, store: &GraphStore, receiver: tokio::sync::mpsc::UnboundedReceiver<crate::graph::event::IndexEvent>) {
-        let vector_index = Arc::clone(&store.vector_index);
-        let property_index = Arc::clone(&store.property_index);
+    ///
+    /// The indexer also runs auto-embed for tenants with an `embed_config`,
+    /// writing embeddings back to `store` and to this manager's WAL. It holds
+    /// only weak references, so it stops once the store is dropped.
+    pub async fn start_indexer(
+        self: &Arc<Self>,
+        store: &Arc<tokio::sync::RwLock<GraphStore>>,
+        receiver: tokio::sync::mpsc::UnboundedReceiver<crate::graph::event::IndexEvent>,
+    ) {
+        let (vector_index, property_index) = {
+            let guard = store.read().await;
+            (Arc::clone(&guard.vector_index), Arc::clone(&guard.property_index))
+        };
         let tenant_manager = Arc::clone(&self.tenants);
+        let store = Arc::downgrade(store);
+        let persistence = Arc::downgrade(self);
 
         tokio::spawn(async move {
             GraphStore::start_background_indexer(
@@ -123,6 +190,8 @@ impl PersistenceManager {
                 vector_index,
                 property_index,
                 tenant_manager,
+                store,
+                persistence,
             ).await;
         });
     }
@@ -132,6 +201,19 @@ impl PersistenceManager {
         // Check tenant quota
         self.tenants.check_quota(tenant, "nodes")?;
 
+        self.persist_replace_node(tenant, node)?;
+
+        // Update usage
+        self.tenants.increment_usage(tenant, "nodes", 1)?;
+
+        Ok(())
+    }
+
+    /// Persist the full current state of an already persisted node
+    ///
+    /// Replay replaces the node wholesale, so label changes and removed
+    /// properties survive recovery.
+    pub fn persist_replace_node(&self, tenant: &str, node: &Node) -> Result<(), PersistenceError> {
         // Serialize properties
         let properties = bincode::serialize(&node.properties)?;
 
@@ -142,14 +224,13 @@ impl PersistenceManager {
             labels: node.labels.iter().map(|l| l.as_str().to_string()).collect(),
             properties,
         };
-        self.wal.lock().unwrap().append(entry)?;
+        // Hold the WAL lock until storage is written so a concurrent
+        // snapshot never sees a logged entry that storage lacks
+        let mut wal = self.wal.lock().unwrap();
+        self.log(&mut wal, tenant, entry)?;
 
         // Write to storage
         self.storage.put_node(tenant, node)?;
-
-        // Update usage
-        self.tenants.increment_usage(tenant, "nodes", 1)?;
-
         Ok(())
     }
 
@@ -158,6 +239,16 @@ impl PersistenceManager {
         // Check tenant quota
         self.tenants.check_quota(tenant, "edges")?;
 
+        self.persist_replace_edge(tenant, edge)?;
+
+        // Update usage
+        self.tenants.increment_usage(tenant, "edges", 1)?;
+
+        Ok(())
+    }
+
+    /// Persist the full current state of an already persisted edge
+    pub fn persist_replace_edge(&self, tenant: &str, edge: &Edge) -> Result<(), PersistenceError> {
         // Serialize properties
         let properties = bincode::serialize(&edge.properties)?;
 
@@ -170,14 +261,11 @@ impl PersistenceManager {
             edge_type: edge.edge_type.as_str().to_string(),
             properties,
         };
-        self.wal.lock().unwrap().append(entry)?;
+        let mut wal = self.wal.lock().unwrap();
+        self.log(&mut wal, tenant, entry)?;
 
         // Write to storage
         self.storage.put_edge(tenant, edge)?;
-
-        // Update usage
-        self.tenants.increment_usage(tenant, "edges", 1)?;
-
         Ok(())
     }
 
@@ -188,10 +276,12 @@ impl PersistenceManager {
             tenant: tenant.to_string(),
             node_id,
         };
-        self.wal.lock().unwrap().append(entry)?;
+        let mut wal = self.wal.lock().unwrap();
+        self.log(&mut wal, tenant, entry)?;
 
         // Write to storage
         self.storage.delete_node(tenant, node_id)?;
+        drop(wal);
 
         // Update usage
         self.tenants.decrement_usage(tenant, "nodes", 1)?;
@@ -206,10 +296,12 @@ impl PersistenceManager {
             tenant: tenant.to_string(),
             edge_id,
         };
-        self.wal.lock().unwrap().append(entry)?;
+        let mut wal = self.wal.lock().unwrap();
+        self.log(&mut wal, tenant, entry)?;
 
         // Write to storage
         self.storage.delete_edge(tenant, edge_id)?;
+        drop(wal);
 
         // Update usage
         self.tenants.decrement_usage(tenant, "edges", 1)?;
@@ -228,6 +320,8 @@ impl PersistenceManager {
     }
 
     /// Persist node property update with MVCC version.
+    ///
+    /// A `Null` value removes the property, as `SET n.prop = null` does.
     pub fn persist_update_node_properties_versioned(
         &self,
         tenant: &str,
@@ -242,11 +336,12 @@ impl PersistenceManager {
             properties: properties_bytes,
             version,
         };
-        self.wal.lock().unwrap().append(entry)?;
-        Ok(())
+        self.log_node_update(tenant, node_id, entry)
     }
 
     /// Persist edge property update with MVCC version.
+    ///
+    /// A `Null` value removes the property.
     pub fn persist_update_edge_properties(
         &self,
         tenant: &str,
@@ -261,7 +356,43 @@ impl PersistenceManager {
             properties: properties_bytes,
             version,
         };
-        self.wal.lock().unwrap().append(entry)?;
+        self.log_edge_update(tenant, edge_id, entry)
+    }
+
+    /// Append `entry` for `tenant`, counting it toward the tenant's next snapshot
+    fn log(&self, wal: &mut Wal, tenant: &str, entry: WalEntry) -> Result<u64, PersistenceError> {
+        let sequence = wal.append(entry)?;
+        *self.writes_since_snapshot.lock().unwrap().entry(tenant.to_string()).or_insert(0) += 1;
+        Ok(sequence)
+    }
+
+    /// Log `entry`, an update of node `node_id`, and apply it to the stored node as well
+    fn log_node_update(&self, tenant: &str, node_id: u64, entry: WalEntry) -> Result<(), PersistenceError> {
+        let mut wal = self.wal.lock().unwrap();
+        let mut nodes: HashMap<u64, Node> = self.storage.get_node(tenant, node_id)?
+            .into_iter()
+            .map(|node| (node_id, node))
+            .collect();
+        apply_wal_entry(tenant, &entry, &mut nodes, &mut HashMap::new())?;
+        self.log(&mut wal, tenant, entry)?;
+        if let Some(node) = nodes.get(&node_id) {
+            self.storage.put_node(tenant, node)?;
+        }
+        Ok(())
+    }
+
+    /// Log `entry`, an update of edge `edge_id`, and apply it to the stored edge as well
+    fn log_edge_update(&self, tenant: &str, edge_id: u64, entry: WalEntry) -> Result<(), PersistenceError> {
+        let mut wal = self.wal.lock().unwrap();
+        let mut edges: HashMap<u64, Edge> = self.storage.get_edge(tenant, edge_id)?
+            .into_iter()
+            .map(|edge| (edge_id, edge))
+            .collect();
+        apply_wal_entry(tenant, &entry, &mut HashMap::new(), &mut edges)?;
+        self.log(&mut wal, tenant, entry)?;
+        if let Some(edge) = edges.get(&edge_id) {
+            self.storage.put_edge(tenant, edge)?;
+        }
         Ok(())
     }
 
@@ -271,22 +402,183 @@ impl PersistenceManager {
     }
 
     /// Recover from storage and WAL
+    ///
+    /// If the tenant has a snapshot, it is loaded and only the WAL entries
+    /// written after it are replayed; otherwise the tenant is scanned from
+    /// storage.
     pub fn recover(&self, tenant: &str) -> Result<(Vec<Node>, Vec<Edge>), PersistenceError> {
         info!("Starting recovery for tenant: {}", tenant);
 
-        // Load nodes from storage
-        let nodes = self.storage.scan_nodes(tenant)?;
-        info!("Recovered {} nodes from storage", nodes.len());
+        let state = {
+            let wal = self.wal.lock().unwrap();
+            self.load_tenant_state(&wal, tenant)?
+        };
+        match state.snapshot_sequence {
+            Some(sequence) => info!(
+                "Recovered {} nodes, {} edges from snapshot at sequence {} + {} WAL entries",
+                state.nodes.len(), state.edges.len(), sequence, state.wal_entries_replayed
+            ),
+            None => info!(
+                "Recovered {} nodes, {} edges from storage",
+                state.nodes.len(), state.edges.len()
+            ),
+        }
 
-        // Load edges from storage
-        let edges = self.storage.scan_edges(tenant)?;
-        info!("Recovered {} edges from storage", edges.len());
+        self.writes_since_snapshot.lock().unwrap()
+            .insert(tenant.to_string(), state.wal_entries_replayed);
 
         // Update resource usage
-        self.tenants.increment_usage(tenant, "nodes", nodes.len())?;
-        self.tenants.increment_usage(tenant, "edges", edges.len())?;
+        self.tenants.increment_usage(tenant, "nodes", state.nodes.len())?;
+        self.tenants.increment_usage(tenant, "edges", state.edges.len())?;
 
-        Ok((nodes, edges))
+        Ok((state.nodes, state.edges))
+    }
+
+    /// Write a full-state snapshot of `tenant` and return the WAL sequence it covers
+    ///
+    /// The state is rebuilt the same way `recover` would (previous snapshot
+    /// plus WAL tail, or a storage scan) and the WAL is rolled over so later
+    /// recoveries skip every file before this point. Only the capture holds
+    /// the WAL lock; the file is written atomically after it is released.
+    pub fn snapshot_tenant(&self, tenant: &str) -> Result<u64, PersistenceError> {
+        // Writers append under this lock, so nothing lands between capturing
+        // the state and reading the sequence it corresponds to
+        let (state, sequence, captured_writes) = {
+            let mut wal = self.wal.lock().unwrap();
+            wal.flush()?;
+            let state = self.load_tenant_state(&wal, tenant)?;
+            let sequence = wal.current_sequence();
+            wal.rotate()?;
+            let captured_writes = self.writes_since_snapshot.lock().unwrap()
+                .get(tenant).copied().unwrap_or(0);
+            (state, sequence, captured_writes)
+        };
+
+        let snapshot = TenantSnapshot {
+            tenant: tenant.to_string(),
+            sequence,
+            nodes: state.nodes,
+            edges: state.edges,
+        };
+        let path = self.snapshot_path(tenant);
+        let tmp = path.with_extension("snap.tmp");
+        {
+            let mut writer = std::io::BufWriter::new(std::fs::File::create(&tmp)?);
+            bincode::serialize_into(&mut writer, &snapshot)?;
+            std::io::Write::flush(&mut writer)?;
+            writer.get_ref().sync_all()?;
+        }
+        std::fs::rename(&tmp, &path)?;
+
+        if let Some(writes) = self.writes_since_snapshot.lock().unwrap().get_mut(tenant) {
+            *writes = writes.saturating_sub(captured_writes);
+        }
+        self.snapshot_sequences.lock().unwrap().insert(tenant.to_string(), sequence);
+        info!(
+            "Snapshot of tenant {} at sequence {}: {} nodes, {} edges",
+            tenant, sequence, snapshot.nodes.len(), snapshot.edges.len()
+        );
+        Ok(sequence)
+    }
+
+    /// Whether `tenant` has logged at least the snapshot interval of WAL
+    /// entries since its latest snapshot (or since recovery)
+    ///
+    /// Entries written for other tenants do not count.
+    pub fn snapshot_due(&self, tenant: &str) -> Result<bool, PersistenceError> {
+        let writes = self.writes_since_snapshot.lock().unwrap().get(tenant).copied().unwrap_or(0);
+        Ok(writes >= self.snapshot_interval)
+    }
+
+    /// Snapshot `tenant` on a blocking worker if one is due and none is running
+    ///
+    /// Called after each committed write; returns immediately so the writer
+    /// never waits on snapshot I/O. Must be called from within a Tokio runtime.
+    pub fn snapshot_if_due(self: &Arc<Self>, tenant: &str) {
+        if !matches!(self.snapshot_due(tenant), Ok(true)) {
+            return;
+        }
+        if !self.snapshots_in_flight.lock().unwrap().insert(tenant.to_string()) {
+            return;
+        }
+        let manager = Arc::clone(self);
+        let tenant = tenant.to_string();
+        tokio::task::spawn_blocking(move || {
+            if let Err(e) = manager.snapshot_tenant(&tenant) {
+                warn!("Snapshot of tenant {} failed: {}", tenant, e);
+            }
+            manager.snapshots_in_flight.lock().unwrap().remove(&tenant);
+        });
+    }
+
+    /// WAL sequence covered by the tenant's latest snapshot, if it has one
+    pub fn last_snapshot_sequence(&self, tenant: &str) -> Result<Option<u64>, PersistenceError> {
+        if let Some(&sequence) = self.snapshot_sequences.lock().unwrap().get(tenant) {
+            return Ok(Some(sequence));
+        }
+        let sequence = self.read_snapshot(tenant)?.map(|s| s.sequence);
+        if let Some(sequence) = sequence {
+            self.snapshot_sequences.lock().unwrap().insert(tenant.to_string(), sequence);
+        }
+        Ok(sequence)
+    }
+
+    /// Snapshot file of `tenant`, named by the hex of the tenant id so that
+    /// distinct tenants never share a file (even on case-insensitive filesystems)
+    fn snapshot_path(&self, tenant: &str) -> std::path::PathBuf {
+        let name: String = tenant.bytes().map(|b| format!("{:02x}", b)).collect();
+        self.base_path.join("checkpoints").join(format!("{}.snap", name))
+    }
+
+    fn read_snapshot(&self, tenant: &str) -> Result<Option<TenantSnapshot>, PersistenceError> {
+        let file = match std::fs::File::open(self.snapshot_path(tenant)) {
+            Ok(file) => file,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(e.into()),
+        };
+        let snapshot: TenantSnapshot = bincode::deserialize_from(std::io::BufReader::new(file))?;
+        if snapshot.tenant != tenant {
+            return Err(PersistenceError::SnapshotTenantMismatch {
+                expected: tenant.to_string(),
+                found: snapshot.tenant,
+            });
+        }
+        Ok(Some(snapshot))
+    }
+
+    /// Rebuild a tenant from its snapshot plus WAL tail, or from storage
+    fn load_tenant_state(&self, wal: &Wal, tenant: &str) -> Result<RecoveredState, PersistenceError> {
+        let Some(snapshot) = self.read_snapshot(tenant)? else {
+            return Ok(RecoveredState {
+                nodes: self.storage.scan_nodes(tenant)?,
+                edges: self.storage.scan_edges(tenant)?,
+                wal_entries_replayed: 0,
+                snapshot_sequence: None,
+            });
+        };
+
+        let mut nodes: HashMap<u64, Node> =
+            snapshot.nodes.into_iter().map(|n| (n.id.as_u64(), n)).collect();
+        let mut edges: HashMap<u64, Edge> =
+            snapshot.edges.into_iter().map(|e| (e.id.as_u64(), e)).collect();
+        let mut replayed = 0u64;
+        wal.replay(snapshot.sequence + 1, |entry| {
+            if apply_wal_entry(tenant, entry, &mut nodes, &mut edges)? {
+                replayed += 1;
+            }
+            Ok(())
+        })?;
+
+        let mut nodes: Vec<Node> = nodes.into_values().collect();
+        let mut edges: Vec<Edge> = edges.into_values().collect();
+        nodes.sort_by_key(|n| n.id.as_u64());
+        edges.sort_by_key(|e| e.id.as_u64());
+        Ok(RecoveredState {
+            nodes,
+            edges,
+            wal_entries_replayed: replayed,
+            snapshot_sequence: Some(snapshot.sequence),
+        })
     }
 
     /// Create a checkpoint
@@ -338,6 +630,67 @@ impl PersistenceManager {
     }
 }
 
+/// Apply one WAL entry to a tenant's recovered state; returns whether the
+/// entry belonged to `tenant`
+fn apply_wal_entry(
+    tenant: &str,
+    entry: &WalEntry,
+    nodes: &mut HashMap<u64, Node>,
+    edges: &mut HashMap<u64, Edge>,
+) -> WalResult<bool> {
+    match entry {
+        WalEntry::CreateNode { tenant: t, node_id, labels, properties } if t == tenant => {
+            let mut node = Node::new_with_labels(
+                NodeId::new(*node_id),
+                labels.iter().map(Label::new).collect(),
+            );
+            node.properties = bincode::deserialize(properties)?;
+            nodes.insert(*node_id, node);
+        }
+        WalEntry::CreateEdge { tenant: t, edge_id, source, target, edge_type, properties } if t == tenant => {
+            let mut edge = Edge::new(
+                EdgeId::new(*edge_id),
+                NodeId::new(*source),
+                NodeId::new(*target),
+                EdgeType::new(edge_type.as_str()),
+            );
+            edge.properties = bincode::deserialize(properties)?;
+            edges.insert(*edge_id, edge);
+        }
+        WalEntry::DeleteNode { tenant: t, node_id } if t == tenant => {
+            nodes.remove(node_id);
+        }
+        WalEntry::DeleteEdge { tenant: t, edge_id } if t == tenant => {
+            edges.remove(edge_id);
+        }
+        WalEntry::UpdateNodeProperties { tenant: t, node_id, properties, .. } if t == tenant => {
+            let update: PropertyMap = bincode::deserialize(properties)?;
+            if let Some(node) = nodes.get_mut(node_id) {
+                apply_property_update(&mut node.properties, update);
+            }
+        }
+        WalEntry::UpdateEdgeProperties { tenant: t, edge_id, properties, .. } if t == tenant => {
+            let update: PropertyMap = bincode::deserialize(properties)?;
+            if let Some(edge) = edges.get_mut(edge_id) {
+                apply_property_update(&mut edge.properties, update);
+            }
+        }
+        _ => return Ok(false),
+    }
+    Ok(true)
+}
+
+/// Merge a logged property update: `Null` values remove their key
+fn apply_property_update(properties: &mut PropertyMap, update: PropertyMap) {
+    for (key, value) in update {
+        if value.is_null() {
+            properties.remove(&key);
+        } else {
+            properties.insert(key, value);
+        }
+    }
+}
+
 /// Persistence errors
 #[derive(Debug, thiserror::Error)]
 pub enum PersistenceError {
@@ -355,6 +708,9 @@ pub enum PersistenceError {
 
     #[error("I/O error: {0}")]
     Io(#[from] std::io::Error),
+
+    #[error("Snapshot of tenant {found} found where tenant {expected} was expected")]
+    SnapshotTenantMismatch { expected: String, found: String },
 }
 
 pub type PersistenceResult<T> = Result<T, PersistenceError>;
@@ -411,6 +767,239 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_recovery_list_property() {
+        let temp_dir = TempDir::new().unwrap();
+        let tags = PropertyValue::from(vec!["graph", "rust"]);
+
+        {
+            let manager = PersistenceManager::new(temp_dir.path()).unwrap();
+            let mut node = Node::new(NodeId::new(1), Label::new("Post"));
+            node.set_property("tags", tags.clone());
+            node.set_property("scores", vec![1i64, 2, 3]);
+            manager.persist_create_node("default", &node).unwrap();
+            manager.flush().unwrap();
+        }
+
+        let manager = PersistenceManager::new(temp_dir.path()).unwrap();
+        let (nodes, _edges) = manager.recover("default").unwrap();
+        assert_eq!(nodes[0].get_property("tags"), Some(&tags));
+        assert_eq!(
+            nodes[0].get_property("scores"),
+            Some(&PropertyValue::Array(vec![
+                PropertyValue::Integer(1),
+                PropertyValue::Integer(2),
+                PropertyValue::Integer(3),
+            ]))
+        );
+    }
+
+    #[test]
+    fn test_recovery_null_property() {
+        let temp_dir = TempDir::new().unwrap();
+
+        {
+            let manager = PersistenceManager::new(temp_dir.path()).unwrap();
+            let mut node = Node::new(NodeId::new(1), Label::new("Shipment"));
+            node.set_property("eta", PropertyValue::Null);
+            manager.persist_create_node("default", &node).unwrap();
+            manager.flush().unwrap();
+        }
+
+        let manager = PersistenceManager::new(temp_dir.path()).unwrap();
+        let (nodes, _edges) = manager.recover("default").unwrap();
+        let eta = nodes[0].get_property("eta");
+        assert_eq!(eta, Some(&PropertyValue::Null));
+        assert_eq!(eta.unwrap().to_json(), serde_json::Value::Null);
+        assert!(nodes[0].get_property("missing").is_none());
+    }
+
+    /// Persist `count` Person nodes with ids starting at `first`.
+    fn persist_people(manager: &PersistenceManager, first: u64, count: u64) {
+        for i in first..first + count {
+            let mut node = Node::new(NodeId::new(i), Label::new("Person"));
+            node.set_property("n", i as i64);
+            manager.persist_create_node("default", &node).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_snapshot_recovery_replays_only_wal_tail() {
+        // Recovery work after a snapshot depends on the tail, not on how much
+        // history preceded it.
+        for history in [100u64, 3000] {
+            let temp_dir = TempDir::new().unwrap();
+            {
+                let manager = PersistenceManager::new(temp_dir.path()).unwrap();
+                persist_people(&manager, 1, history);
+                let sequence = manager.snapshot_tenant("default").unwrap();
+                assert_eq!(sequence, history);
+                assert_eq!(manager.last_snapshot_sequence("default").unwrap(), Some(history));
+
+                // Tail: two creates, an update, a delete, plus another tenant's write
+                persist_people(&manager, history + 1, 2);
+                let mut props = PropertyMap::new();
+                props.insert("n".to_string(), PropertyValue::Integer(-1));
+                manager.persist_update_node_properties("default", 1, &props).unwrap();
+                manager.persist_delete_node("default", 2).unwrap();
+                manager.tenants().create_tenant("other".to_string(), "Other".to_string(), None).unwrap();
+                let other = Node::new(NodeId::new(1), Label::new("Other"));
+                manager.persist_create_node("other", &other).unwrap();
+                manager.flush().unwrap();
+            }
+
+            let manager = PersistenceManager::new(temp_dir.path()).unwrap();
+            let state = {
+                let wal = manager.wal.lock().unwrap();
+                manager.load_tenant_state(&wal, "default").unwrap()
+            };
+            assert_eq!(state.snapshot_sequence, Some(history));
+            assert_eq!(state.wal_entries_replayed, 4);
+
+            let (nodes, edges) = manager.recover("default").unwrap();
+            assert_eq!(nodes.len() as u64, history + 1);
+            assert!(edges.is_empty());
+            assert!(nodes.iter().all(|n| n.id != NodeId::new(2)));
+            let first = nodes.iter().find(|n| n.id == NodeId::new(1)).unwrap();
+            assert_eq!(first.get_property("n"), Some(&PropertyValue::Integer(-1)));
+            assert!(nodes.iter().any(|n| n.id == NodeId::new(history + 2)));
+        }
+    }
+
+    #[test]
+    fn test_snapshot_includes_edges_and_chains() {
+        let temp_dir = TempDir::new().unwrap();
+        let manager = PersistenceManager::new(temp_dir.path()).unwrap();
+        persist_people(&manager, 1, 3);
+        let edge = Edge::new(EdgeId::new(1), NodeId::new(1), NodeId::new(2), EdgeType::new("KNOWS"));
+        manager.persist_create_edge("default", &edge).unwrap();
+        manager.snapshot_tenant("default").unwrap();
+
+        // A second snapshot builds on the first plus its tail
+        let edge = Edge::new(EdgeId::new(2), NodeId::new(2), NodeId::new(3), EdgeType::new("KNOWS"));
+        manager.persist_create_edge("default", &edge).unwrap();
+        manager.persist_delete_edge("default", 1).unwrap();
+        manager.snapshot_tenant("default").unwrap();
+
+        let (nodes, edges) = manager.recover("default").unwrap();
+        assert_eq!(nodes.len(), 3);
+        assert_eq!(edges.len(), 1);
+        assert_eq!(edges[0].id, EdgeId::new(2));
+        assert_eq!(edges[0].edge_type.as_str(), "KNOWS");
+    }
+
+    #[test]
+    fn test_snapshot_due_after_interval() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut manager = PersistenceManager::new(temp_dir.path()).unwrap();
+        manager.snapshot_interval = 10;
+
+        persist_people(&manager, 1, 9);
+        assert!(!manager.snapshot_due("default").unwrap());
+        persist_people(&manager, 10, 1);
+        assert!(manager.snapshot_due("default").unwrap());
+
+        manager.snapshot_tenant("default").unwrap();
+        assert!(!manager.snapshot_due("default").unwrap());
+    }
+
+    #[test]
+    fn test_snapshot_due_counts_only_own_tenant() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut manager = PersistenceManager::new(temp_dir.path()).unwrap();
+        manager.snapshot_interval = 10;
+        manager.tenants.create_tenant("other".to_string(), "Other".to_string(), None).unwrap();
+
+        persist_people(&manager, 1, 5);
+        for i in 100..120 {
+            manager.persist_create_node("other", &Node::new(NodeId::new(i), Label::new("Person"))).unwrap();
+        }
+        assert!(manager.snapshot_due("other").unwrap());
+        assert!(!manager.snapshot_due("default").unwrap());
+
+        manager.snapshot_tenant("other").unwrap();
+        assert!(!manager.snapshot_due("other").unwrap());
+        persist_people(&manager, 6, 5);
+        assert!(manager.snapshot_due("default").unwrap());
+    }
+
+    #[test]
+    fn test_snapshots_of_similar_tenant_names_stay_apart() {
+        let temp_dir = TempDir::new().unwrap();
+        let manager = PersistenceManager::new(temp_dir.path()).unwrap();
+        for (tenant, count) in [("team.a", 2u64), ("team_a", 3)] {
+            manager.tenants().create_tenant(tenant.to_string(), tenant.to_string(), None).unwrap();
+            for i in 1..=count {
+                manager.persist_create_node(tenant, &Node::new(NodeId::new(i), Label::new("Person"))).unwrap();
+            }
+            manager.snapshot_tenant(tenant).unwrap();
+        }
+        assert_ne!(manager.snapshot_path("team.a"), manager.snapshot_path("team_a"));
+        assert_eq!(manager.recover("team.a").unwrap().0.len(), 2);
+        assert_eq!(manager.recover("team_a").unwrap().0.len(), 3);
+
+        // A snapshot file holding another tenant is rejected, not loaded
+        std::fs::copy(manager.snapshot_path("team_a"), manager.snapshot_path("team.a")).unwrap();
+        assert!(matches!(
+            manager.recover("team.a"),
+            Err(PersistenceError::SnapshotTenantMismatch { expected, found }) if expected == "team.a" && found == "team_a"
+        ));
+    }
+
+    #[test]
+    fn test_wal_tail_replays_removed_properties_and_labels() {
+        let temp_dir = TempDir::new().unwrap();
+        {
+            let manager = PersistenceManager::new(temp_dir.path()).unwrap();
+            let mut node = Node::new(NodeId::new(1), Label::new("Person"));
+            node.set_property("name", "Alice");
+            node.set_property("age", 30i64);
+            node.set_property("city", "Pune");
+            manager.persist_create_node("default", &node).unwrap();
+            manager.snapshot_tenant("default").unwrap();
+
+            // SET n.age = null
+            let mut update = PropertyMap::new();
+            update.insert("age".to_string(), PropertyValue::Null);
+            manager.persist_update_node_properties("default", 1, &update).unwrap();
+
+            // REMOVE n.city, SET n:Admin
+            node.properties.remove("age");
+            node.properties.remove("city");
+            node.add_label(Label::new("Admin"));
+            manager.persist_replace_node("default", &node).unwrap();
+            manager.flush().unwrap();
+        }
+
+        let manager = PersistenceManager::new(temp_dir.path()).unwrap();
+        let (nodes, _edges) = manager.recover("default").unwrap();
+        assert_eq!(nodes.len(), 1);
+        assert!(nodes[0].get_property("age").is_none());
+        assert!(nodes[0].get_property("city").is_none());
+        assert_eq!(nodes[0].get_property("name"), Some(&PropertyValue::String("Alice".to_string())));
+        assert!(nodes[0].has_label(&Label::new("Admin")));
+        assert!(!manager.snapshot_due("default").unwrap());
+    }
+
+    #[test]
+    fn test_property_update_reaches_storage() {
+        let temp_dir = TempDir::new().unwrap();
+        let manager = PersistenceManager::new(temp_dir.path()).unwrap();
+        let mut node = Node::new(NodeId::new(1), Label::new("Person"));
+        node.set_property("age", 30i64);
+        manager.persist_create_node("default", &node).unwrap();
+
+        let mut update = PropertyMap::new();
+        update.insert("age".to_string(), PropertyValue::Null);
+        update.insert("name".to_string(), PropertyValue::String("Bob".to_string()));
+        manager.persist_update_node_properties("default", 1, &update).unwrap();
+
+        // No snapshot, so recovery is a storage scan
+        let (nodes, _edges) = manager.recover("default").unwrap();
+        assert!(nodes[0].get_property("age").is_none());
+        assert_eq!(nodes[0].get_property("name"), Some(&PropertyValue::String("Bob".to_string())));
+    }
+
     #[test]
     fn test_vector_index_persistence() {
         use crate::vector::{VectorIndexManager, DistanceMetric};
@@ -439,6 +1028,107 @@ mod tests {
         assert_eq!(results[0].0, NodeId::new(1));
     }
 
+    #[test]
+    fn test_vector_recovery_checks_format_version() {
+        use crate::vector::{VectorIndexManager, DistanceMetric};
+        use crate::graph::NodeId;
+
+        let temp_dir = TempDir::new().unwrap();
+        let manager = PersistenceManager::new(temp_dir.path()).unwrap();
+        let vim = VectorIndexManager::new();
+        vim.create_index("Person", "embedding", 2, DistanceMetric::Cosine).unwrap();
+        vim.add_vector("Person", "embedding", NodeId::new(1), &vec![1.0, 0.0]).unwrap();
+        manager.checkpoint_vectors(&vim).unwrap();
+
+        let metadata_path = temp_dir.path().join("vectors").join("metadata.json");
+        let metadata: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(&metadata_path).unwrap()).unwrap();
+        assert_eq!(metadata["format_version"], crate::vector::VECTOR_FORMAT_VERSION);
+
+        // Metadata written before versioning was a bare array
+        std::fs::write(&metadata_path, metadata["indices"].to_string()).unwrap();
+        let legacy = VectorIndexManager::new();
+        manager.recover_vectors(&legacy).unwrap();
+        assert_eq!(legacy.search("Person", "embedding", &[1.0, 0.0], 1).unwrap()[0].0, NodeId::new(1));
+
+        let mut future = metadata.clone();
+        future["format_version"] = serde_json::json!(99);
+        std::fs::write(&metadata_path, future.to_string()).unwrap();
+        let err = manager.recover_vectors(&VectorIndexManager::new()).unwrap_err();
+        assert!(err.to_string().contains("format version 99"));
+    }
+
+    #[tokio::test]
+    async fn test_auto_embed_writes_vector_property() {
+        async fn embedding(store: &tokio::sync::RwLock<GraphStore>, id: NodeId, previous: Option<&Vec<f32>>) -> Vec<f32> {
+            for _ in 0..500 {
+                if let Some(PropertyValue::Vector(v)) = store.read().await.get_node(id).and_then(|n| n.get_property("content_embedding")) {
+                    if Some(v) != previous {
+                        return v.clone();
+                    }
+                }
+                tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+            }
+            panic!("node {:?} was not embedded", id);
+        }
+
+        let temp_dir = TempDir::new().unwrap();
+        let manager = Arc::new(PersistenceManager::new(temp_dir.path()).unwrap());
+        manager.tenants().update_embed_config("default", Some(AutoEmbedConfig {
+            provider: LLMProvider::Mock,
+            embedding_model: "mock".to_string(),
+            api_key: None,
+            api_base_url: None,
+            chunk_size: 512,
+            chunk_overlap: 0,
+            vector_dimension: 64,
+            metric: crate::vector::DistanceMetric::Euclidean,
+            embedding_policies: HashMap::from([("Document".to_string(), vec!["content".to_string()])]),
+        })).unwrap();
+
+        let (graph, rx) = GraphStore::with_async_indexing();
+        let store = Arc::new(tokio::sync::RwLock::new(graph));
+        manager.start_indexer(&store, rx).await;
+
+        let (note, doc) = {
+            let mut graph = store.write().await;
+            let mut props = PropertyMap::new();
+            props.insert("content".to_string(), PropertyValue::String("not a document".to_string()));
+            let note = graph.create_node_with_properties("default", vec![Label::new("Note")], props.clone());
+            props.insert("content".to_string(), PropertyValue::String("graphs are everywhere".to_string()));
+            let doc = graph.create_node_with_properties("default", vec![Label::new("Document")], props);
+            // Logged before the embedding can be, which waits for this write lock
+            manager.persist_create_node("default", graph.get_node(doc).unwrap()).unwrap();
+            manager.snapshot_tenant("default").unwrap();
+            (note, doc)
+        };
+
+        let first = embedding(&store, doc, None).await;
+        assert_eq!(first.len(), 64);
+        // Only labels with a policy are embedded
+        assert!(store.read().await.get_node(note).unwrap().get_property("content_embedding").is_none());
+        // Indexed under the text property, with the configured metric
+        {
+            let graph = store.read().await;
+            assert_eq!(graph.vector_search("Document", "content", &first, 1).unwrap()[0].0, doc);
+            let index = graph.vector_index.get_index("Document", "content").unwrap();
+            assert_eq!(index.read().unwrap().metric(), crate::vector::DistanceMetric::Euclidean);
+            assert!(graph.vector_index.get_index("Document", "content_embedding").is_none());
+        }
+        // The embedding went to the WAL
+        manager.flush().unwrap();
+        let (nodes, _) = manager.recover("default").unwrap();
+        let recovered = nodes.iter().find(|n| n.id == doc).unwrap();
+        assert_eq!(recovered.get_property("content_embedding"), Some(&PropertyValue::Vector(first.clone())));
+
+        // Rewriting the text re-embeds it
+        store.write().await
+            .set_node_property("default", doc, "content", "a longer text about graph databases")
+            .unwrap();
+        let second = embedding(&store, doc, Some(&first)).await;
+        assert_ne!(first, second);
+    }
+
     #[test]
     fn test_quota_enforcement() {
         let temp_dir = TempDir::new().unwrap();
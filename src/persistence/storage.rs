@@ -124,6 +124,7 @@ impl PersistentStorage {
             ColumnFamilyDescriptor::new("nodes", Self::node_cf_options()),
             ColumnFamilyDescriptor::new("edges", Self::edge_cf_options()),
             ColumnFamilyDescriptor::new("indices", Self::index_cf_options()),
+            ColumnFamilyDescriptor::new("metadata", Options::default()),
         ];
 
         // Open database
@@ -304,6 +305,23 @@ impl PersistentStorage {
         Ok(())
     }
 
+    /// Store a small named value outside any tenant (e.g. ID watermarks)
+    pub fn put_metadata(&self, key: &str, value: &[u8]) -> StorageResult<()> {
+        let cf = self.db.cf_handle("metadata")
+            .ok_or_else(|| StorageError::ColumnFamily("metadata".to_string()))?;
+
+        self.db.put_cf(&cf, key.as_bytes(), value)?;
+        Ok(())
+    }
+
+    /// Get a value stored with `put_metadata`
+    pub fn get_metadata(&self, key: &str) -> StorageResult<Option<Vec<u8>>> {
+        let cf = self.db.cf_handle("metadata")
+            .ok_or_else(|| StorageError::ColumnFamily("metadata".to_string()))?;
+
+        Ok(self.db.get_cf(&cf, key.as_bytes())?)
+    }
+
     /// Create a snapshot
     pub fn create_snapshot(&self) -> rocksdb::Snapshot<'_> {
         self.db.snapshot()
@@ -441,6 +459,20 @@ mod tests {
         assert_eq!(retrieved_node.get_property("name").unwrap().as_string().unwrap(), "Alice");
     }
 
+    #[test]
+    fn test_metadata_survives_reopen() {
+        let temp_dir = TempDir::new().unwrap();
+        let storage = PersistentStorage::open(temp_dir.path()).unwrap();
+        assert_eq!(storage.get_metadata("k").unwrap(), None);
+        storage.put_metadata("k", b"v").unwrap();
+        drop(storage);
+
+        let storage = PersistentStorage::open(temp_dir.path()).unwrap();
+        assert_eq!(storage.get_metadata("k").unwrap(), Some(b"v".to_vec()));
+        // Metadata is not mistaken for tenant data
+        assert!(storage.list_persisted_tenants().unwrap().is_empty());
+    }
+
     #[test]
     fn test_tenant_isolation() {
         let temp_dir = TempDir::new().unwrap();
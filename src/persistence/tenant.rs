@@ -4,7 +4,9 @@
 
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use std::sync::{Arc, RwLock};
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::Instant;
+use crate::vector::DistanceMetric;
 use thiserror::Error;
 // warn removed - was unused import causing compiler warning
 use tracing::{debug, info};
@@ -30,6 +32,13 @@ pub enum TenantError {
     /// Permission denied
     #[error("Permission denied for tenant {0}")]
     PermissionDenied(String),
+
+    /// Query rate quota exceeded
+    #[error("Rate limited: tenant {tenant} exceeded {limit} queries/sec")]
+    RateLimited {
+        tenant: String,
+        limit: u32,
+    },
 }
 
 pub type TenantResult<T> = Result<T, TenantError>;
@@ -49,6 +58,9 @@ pub struct ResourceQuotas {
     pub max_connections: Option<usize>,
     /// Maximum query execution time in milliseconds
     pub max_query_time_ms: Option<u64>,
+    /// Maximum queries per second (token bucket, bursts up to one second's worth)
+    #[serde(default)]
+    pub max_queries_per_sec: Option<u32>,
 }
 
 impl Default for ResourceQuotas {
@@ -60,6 +72,7 @@ impl Default for ResourceQuotas {
             max_storage_bytes: Some(10_737_418_240), // 10 GB
             max_connections: Some(100),
             max_query_time_ms: Some(60_000),   // 60 seconds
+            max_queries_per_sec: None,
         }
     }
 }
@@ -74,6 +87,7 @@ impl ResourceQuotas {
             max_storage_bytes: None,
             max_connections: None,
             max_query_time_ms: None,
+            max_queries_per_sec: None,
         }
     }
 }
@@ -142,6 +156,34 @@ impl ResourceUsage {
     }
 }
 
+/// Token bucket enforcing `max_queries_per_sec`
+///
+/// Holds at most one second's worth of tokens and refills continuously, so a
+/// tenant may burst up to its rate after being idle but never sustain more.
+#[derive(Debug, Clone)]
+struct TokenBucket {
+    tokens: f64,
+    refilled_at: Instant,
+}
+
+impl TokenBucket {
+    fn full(rate: u32, now: Instant) -> Self {
+        Self { tokens: rate as f64, refilled_at: now }
+    }
+
+    fn try_take(&mut self, rate: u32, now: Instant) -> bool {
+        let elapsed = now.saturating_duration_since(self.refilled_at).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * rate as f64).min(rate as f64);
+        self.refilled_at = now;
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            true
+        } else {
+            false
+        }
+    }
+}
+
 /// Tenant configuration and metadata
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Tenant {
@@ -250,6 +292,33 @@ pub struct NLQConfig {
     pub api_base_url: Option<String>,
     /// System prompt for the LLM
     pub system_prompt: Option<String>,
+    /// Retries for rate-limited or transiently failing requests
+    #[serde(default)]
+    pub retry: RetryPolicy,
+    /// Provider to ask when the primary one still fails after its retries
+    #[serde(default)]
+    pub fallback: Option<Box<NLQConfig>>,
+}
+
+/// Exponential backoff for retrying LLM requests
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RetryPolicy {
+    /// Retries after the first attempt (0 disables retrying)
+    pub max_retries: u32,
+    /// Delay before the first retry; doubles on each further retry
+    pub initial_backoff_ms: u64,
+    /// Upper bound on the delay between retries
+    pub max_backoff_ms: u64,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            initial_backoff_ms: 500,
+            max_backoff_ms: 8_000,
+        }
+    }
 }
 
 /// Configuration for Auto-Embed features
@@ -269,16 +338,26 @@ pub struct AutoEmbedConfig {
     pub chunk_overlap: usize,
     /// Vector dimension size
     pub vector_dimension: usize,
+    /// Distance metric of the vector indexes auto-embed creates
+    #[serde(default = "default_embed_metric")]
+    pub metric: DistanceMetric,
     /// Embedding policies: Label -> `Vec<PropertyKey>`
     pub embedding_policies: HashMap<String, Vec<String>>,
 }
 
+/// Configs saved before `metric` existed embedded into cosine indexes
+fn default_embed_metric() -> DistanceMetric {
+    DistanceMetric::Cosine
+}
+
 /// Tenant manager - manages all tenants and their resources
 pub struct TenantManager {
     /// All tenants
     tenants: Arc<RwLock<HashMap<String, Tenant>>>,
     /// Resource usage per tenant
     usage: Arc<RwLock<HashMap<String, ResourceUsage>>>,
+    /// Query-rate token buckets per tenant (only tenants with `max_queries_per_sec`)
+    rate_limits: Arc<Mutex<HashMap<String, TokenBucket>>>,
 }
 
 impl TenantManager {
@@ -297,6 +376,7 @@ impl TenantManager {
         Self {
             tenants: Arc::new(RwLock::new(tenants)),
             usage: Arc::new(RwLock::new(usage)),
+            rate_limits: Arc::new(Mutex::new(HashMap::new())),
         }
     }
 
@@ -338,6 +418,7 @@ impl TenantManager {
 
         tenants.remove(id);
         usage.remove(id);
+        self.rate_limits.lock().unwrap().remove(id);
 
         info!("Deleted tenant: {}", id);
 
@@ -393,6 +474,73 @@ impl TenantManager {
             })
     }
 
+    /// Check that the tenant has room for `nodes` more nodes and `edges` more edges
+    ///
+    /// Unlike [`check_quota`](Self::check_quota), which admits one more unit,
+    /// this admits a bulk load such as a graph copy as a whole.
+    pub fn check_capacity(&self, tenant_id: &str, nodes: usize, edges: usize) -> TenantResult<()> {
+        let tenants = self.tenants.read().unwrap();
+        let usage = self.usage.read().unwrap();
+
+        let tenant = tenants.get(tenant_id)
+            .ok_or_else(|| TenantError::NotFound(tenant_id.to_string()))?;
+
+        if !tenant.enabled {
+            return Err(TenantError::PermissionDenied(format!("Tenant {} is disabled", tenant_id)));
+        }
+
+        let current_usage = usage.get(tenant_id)
+            .ok_or_else(|| TenantError::NotFound(tenant_id.to_string()))?;
+
+        let limits = [
+            ("nodes", current_usage.node_count + nodes, tenant.quotas.max_nodes),
+            ("edges", current_usage.edge_count + edges, tenant.quotas.max_edges),
+        ];
+        for (resource, needed, max) in limits {
+            if let Some(max) = max {
+                if needed > max {
+                    return Err(TenantError::QuotaExceeded {
+                        tenant: tenant_id.to_string(),
+                        resource: format!("{} ({}/{})", resource, needed, max),
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Take one query from the tenant's `max_queries_per_sec` budget
+    ///
+    /// Returns `RateLimited` when the budget is spent. Tenants without a rate
+    /// quota, and graph names that are not registered tenants, are never throttled.
+    pub fn acquire_query(&self, tenant_id: &str) -> TenantResult<()> {
+        self.acquire_query_at(tenant_id, Instant::now())
+    }
+
+    fn acquire_query_at(&self, tenant_id: &str, now: Instant) -> TenantResult<()> {
+        let rate = {
+            let tenants = self.tenants.read().unwrap();
+            match tenants.get(tenant_id).and_then(|t| t.quotas.max_queries_per_sec) {
+                Some(rate) => rate,
+                None => return Ok(()),
+            }
+        };
+
+        let mut buckets = self.rate_limits.lock().unwrap();
+        let bucket = buckets
+            .entry(tenant_id.to_string())
+            .or_insert_with(|| TokenBucket::full(rate, now));
+        if bucket.try_take(rate, now) {
+            Ok(())
+        } else {
+            debug!("Tenant {} throttled at {} queries/sec", tenant_id, rate);
+            Err(TenantError::RateLimited {
+                tenant: tenant_id.to_string(),
+                limit: rate,
+            })
+        }
+    }
+
     /// Increment resource usage
     pub fn increment_usage(&self, tenant_id: &str, resource: &str, amount: usize) -> TenantResult<()> {
         let mut usage = self.usage.write().unwrap();
@@ -451,6 +599,7 @@ impl TenantManager {
             .ok_or_else(|| TenantError::NotFound(tenant_id.to_string()))?;
 
         tenant.quotas = quotas;
+        self.rate_limits.lock().unwrap().remove(tenant_id);
 
         info!("Updated quotas for tenant: {}", tenant_id);
 
@@ -592,6 +741,64 @@ mod tests {
         assert!(matches!(result.unwrap_err(), TenantError::QuotaExceeded { .. }));
     }
 
+    #[test]
+    fn test_check_capacity() {
+        let manager = TenantManager::new();
+        let quotas = ResourceQuotas {
+            max_nodes: Some(10),
+            max_edges: Some(5),
+            ..ResourceQuotas::default()
+        };
+        manager.create_tenant("tenant1".to_string(), "Tenant 1".to_string(), Some(quotas)).unwrap();
+        manager.increment_usage("tenant1", "nodes", 4).unwrap();
+
+        manager.check_capacity("tenant1", 6, 5).unwrap();
+        let err = manager.check_capacity("tenant1", 7, 0).unwrap_err();
+        assert!(matches!(&err, TenantError::QuotaExceeded { resource, .. } if resource == "nodes (11/10)"));
+        assert!(manager.check_capacity("tenant1", 0, 6).is_err());
+        assert!(matches!(manager.check_capacity("missing", 0, 0), Err(TenantError::NotFound(_))));
+    }
+
+    #[test]
+    fn test_query_rate_limit() {
+        let manager = TenantManager::new();
+        let quotas = ResourceQuotas {
+            max_queries_per_sec: Some(5),
+            ..ResourceQuotas::default()
+        };
+        manager.create_tenant("noisy".to_string(), "Noisy".to_string(), Some(quotas)).unwrap();
+        manager.create_tenant("quiet".to_string(), "Quiet".to_string(), None).unwrap();
+
+        let now = Instant::now();
+        for _ in 0..5 {
+            manager.acquire_query_at("noisy", now).unwrap();
+        }
+        let result = manager.acquire_query_at("noisy", now);
+        assert!(matches!(result, Err(TenantError::RateLimited { limit: 5, .. })));
+
+        // Other tenants and unregistered graphs are unaffected
+        for _ in 0..100 {
+            manager.acquire_query_at("quiet", now).unwrap();
+            manager.acquire_query_at("scratch", now).unwrap();
+        }
+
+        // Tokens refill at the configured rate, capped at one second's worth
+        let later = now + std::time::Duration::from_millis(500);
+        manager.acquire_query_at("noisy", later).unwrap();
+        manager.acquire_query_at("noisy", later).unwrap();
+        assert!(manager.acquire_query_at("noisy", later).is_err());
+
+        let much_later = now + std::time::Duration::from_secs(60);
+        for _ in 0..5 {
+            manager.acquire_query_at("noisy", much_later).unwrap();
+        }
+        assert!(manager.acquire_query_at("noisy", much_later).is_err());
+
+        // Lifting the quota lifts the throttle
+        manager.update_quotas("noisy", ResourceQuotas::default()).unwrap();
+        manager.acquire_query_at("noisy", much_later).unwrap();
+    }
+
     #[test]
     fn test_usage_tracking() {
         let manager = TenantManager::new();
@@ -645,6 +852,7 @@ mod tests {
             chunk_size: 512,
             chunk_overlap: 64,
             vector_dimension: 1536,
+            metric: DistanceMetric::Cosine,
             embedding_policies: HashMap::from([("Document".to_string(), vec!["content".to_string()])]),
         };
 
@@ -671,6 +879,8 @@ mod tests {
             api_key: None,
             api_base_url: Some("http://localhost:11434".to_string()),
             system_prompt: Some("You are a Cypher expert.".to_string()),
+            retry: Default::default(),
+            fallback: None,
         };
 
         manager.update_nlq_config("tenant1", Some(nlq_config)).unwrap();
@@ -716,6 +926,8 @@ mod tests {
             api_key: None,
             api_base_url: None,
             system_prompt: None,
+            retry: Default::default(),
+            fallback: None,
         };
 
         let result = manager.update_nlq_config("nonexistent", Some(nlq_config));
@@ -835,6 +1047,7 @@ mod tests {
             max_storage_bytes: None,
             max_connections: Some(10),
             max_query_time_ms: Some(5000),
+            max_queries_per_sec: None,
         };
 
         manager.update_quotas("t1", new_quotas).unwrap();
@@ -880,6 +1093,7 @@ mod tests {
             max_storage_bytes: None,
             max_connections: None,
             max_query_time_ms: None,
+            max_queries_per_sec: None,
         };
         manager.create_tenant("t1".to_string(), "T1".to_string(), Some(quotas)).unwrap();
 
@@ -902,6 +1116,7 @@ mod tests {
             max_storage_bytes: None,
             max_connections: None,
             max_query_time_ms: None,
+            max_queries_per_sec: None,
         };
         manager.create_tenant("t1".to_string(), "T1".to_string(), Some(quotas)).unwrap();
 
@@ -920,6 +1135,7 @@ mod tests {
             max_storage_bytes: None,
             max_connections: Some(2),
             max_query_time_ms: None,
+            max_queries_per_sec: None,
         };
         manager.create_tenant("t1".to_string(), "T1".to_string(), Some(quotas)).unwrap();
 
@@ -1036,6 +1252,7 @@ mod tests {
             chunk_size: 256,
             chunk_overlap: 32,
             vector_dimension: 1536,
+            metric: DistanceMetric::Cosine,
             embedding_policies: HashMap::new(),
         };
         let result = manager.update_embed_config("ghost", Some(config));
@@ -1062,6 +1279,7 @@ mod tests {
             chunk_size: 256,
             chunk_overlap: 32,
             vector_dimension: 768,
+            metric: DistanceMetric::Cosine,
             embedding_policies: HashMap::new(),
         };
         manager.update_embed_config("t1", Some(config)).unwrap();
@@ -1084,6 +1302,8 @@ mod tests {
             api_key: None,
             api_base_url: None,
             system_prompt: None,
+            retry: Default::default(),
+            fallback: None,
         };
         manager.update_nlq_config("t1", Some(config)).unwrap();
         assert!(manager.get_tenant("t1").unwrap().nlq_config.is_some());
@@ -1146,6 +1366,7 @@ mod tests {
             max_storage_bytes: None,
             max_connections: Some(10),
             max_query_time_ms: Some(30_000),
+            max_queries_per_sec: None,
         };
         let json = serde_json::to_string(&quotas).unwrap();
         let deserialized: ResourceQuotas = serde_json::from_str(&json).unwrap();
@@ -1164,12 +1385,20 @@ mod tests {
             api_key: Some("key123".to_string()),
             api_base_url: None,
             system_prompt: Some("You are a graph expert.".to_string()),
+            retry: Default::default(),
+            fallback: None,
         };
         let json = serde_json::to_string(&config).unwrap();
         let deserialized: NLQConfig = serde_json::from_str(&json).unwrap();
         assert_eq!(deserialized.provider, LLMProvider::Gemini);
         assert_eq!(deserialized.model, "gemini-pro");
         assert_eq!(deserialized.api_key, Some("key123".to_string()));
+
+        // Configs stored before retry and fallback existed still load
+        let legacy = r#"{"enabled":true,"provider":"OpenAI","model":"gpt-4o","api_key":null,"api_base_url":null,"system_prompt":null}"#;
+        let legacy: NLQConfig = serde_json::from_str(legacy).unwrap();
+        assert_eq!(legacy.retry.max_retries, RetryPolicy::default().max_retries);
+        assert!(legacy.fallback.is_none());
     }
 
     #[test]
@@ -1182,6 +1411,7 @@ mod tests {
             chunk_size: 512,
             chunk_overlap: 64,
             vector_dimension: 1536,
+            metric: DistanceMetric::Cosine,
             embedding_policies: HashMap::from([
                 ("Document".to_string(), vec!["content".to_string(), "title".to_string()]),
             ]),
@@ -1314,6 +1544,10 @@ mod tests {
 
         let e4 = TenantError::PermissionDenied("t4".to_string());
         assert!(format!("{}", e4).contains("Permission denied"));
+
+        let e5 = TenantError::RateLimited { tenant: "t5".to_string(), limit: 10 };
+        assert!(format!("{}", e5).contains("Rate limited"));
+        assert!(format!("{}", e5).contains("10 queries/sec"));
     }
 
     #[test]
@@ -1330,6 +1564,7 @@ mod tests {
             chunk_size: 1024,
             chunk_overlap: 128,
             vector_dimension: 768,
+            metric: DistanceMetric::Cosine,
             embedding_policies: policies,
         };
 
@@ -1351,6 +1586,8 @@ mod tests {
             api_key: Some("azure-key".to_string()),
             api_base_url: Some("https://my-endpoint.openai.azure.com".to_string()),
             system_prompt: None,
+            retry: Default::default(),
+            fallback: None,
         };
 
         assert!(!config.enabled);
@@ -1407,6 +1644,7 @@ mod tests {
             max_storage_bytes: None,
             max_connections: Some(5),
             max_query_time_ms: Some(10_000),
+            max_queries_per_sec: None,
         };
 
         manager.create_tenant("custom_t".to_string(), "Custom".to_string(), Some(quotas)).unwrap();
@@ -1461,6 +1699,7 @@ mod tests {
             chunk_size: 256,
             chunk_overlap: 32,
             vector_dimension: 1536,
+            metric: DistanceMetric::Cosine,
             embedding_policies: HashMap::new(),
         });
         tenant.nlq_config = Some(NLQConfig {
@@ -1470,6 +1709,8 @@ mod tests {
             api_key: None,
             api_base_url: None,
             system_prompt: None,
+            retry: Default::default(),
+            fallback: None,
         });
 
         let json = serde_json::to_string(&tenant).unwrap();
@@ -1488,6 +1729,7 @@ mod tests {
             max_storage_bytes: None,
             max_connections: Some(10),
             max_query_time_ms: Some(5000),
+            max_queries_per_sec: None,
         };
 
         let cloned = quotas.clone();
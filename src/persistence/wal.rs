@@ -245,7 +245,22 @@ impl Wal {
         Ok(())
     }
 
+    /// Close the current WAL file so the next append starts a new one
+    ///
+    /// Entries appended afterwards land in a file whose name records the
+    /// current sequence, which lets [`replay`](Self::replay) from that point
+    /// skip every earlier file without reading it.
+    pub fn rotate(&mut self) -> WalResult<()> {
+        self.flush()?;
+        self.current_file = None;
+        Ok(())
+    }
+
     /// Replay the WAL from a specific sequence number
+    ///
+    /// Files whose entries all precede `from_sequence` are skipped unread, so
+    /// replaying a short tail costs time proportional to the tail rather than
+    /// to the whole log.
     pub fn replay<F>(&self, from_sequence: u64, mut callback: F) -> WalResult<u64>
     where
         F: FnMut(&WalEntry) -> WalResult<()>,
@@ -256,8 +271,14 @@ impl Wal {
         let mut replayed = 0u64;
         let mut last_sequence = from_sequence;
 
-        for file_path in files {
-            let file = File::open(&file_path)?;
+        for (i, file_path) in files.iter().enumerate() {
+            // A file holds sequences up to the start of the next file
+            let next_start = files.get(i + 1).and_then(|f| Self::file_start_sequence(f));
+            if next_start.is_some_and(|next| next < from_sequence) {
+                continue;
+            }
+
+            let file = File::open(file_path)?;
             let mut reader = BufReader::new(file);
             let mut buf = Vec::new();
 
@@ -342,30 +363,58 @@ impl Wal {
     }
 
     /// Find the latest sequence number from existing WAL files
+    ///
+    /// The newest file's name only records the sequence it started at, so its
+    /// records are read to find the last one actually written. A truncated
+    /// final record (torn write) ends the scan.
     fn find_latest_sequence(path: &Path) -> WalResult<u64> {
         let files = match std::fs::read_dir(path) {
             Ok(entries) => entries,
             Err(_) => return Ok(0), // No directory yet
         };
 
-        let mut max_sequence = 0u64;
+        let mut latest: Option<(u64, PathBuf)> = None;
 
         for entry in files.flatten() {
-            if let Some(filename) = entry.file_name().to_str() {
-                if filename.starts_with("wal-") && filename.ends_with(".log") {
-                    // Parse sequence from filename
-                    if let Some(seq_str) = filename.strip_prefix("wal-").and_then(|s| s.strip_suffix(".log")) {
-                        if let Ok(seq) = u64::from_str_radix(seq_str, 16) {
-                            max_sequence = max_sequence.max(seq);
-                        }
-                    }
+            let file_path = entry.path();
+            if let Some(seq) = Self::file_start_sequence(&file_path) {
+                if latest.as_ref().is_none_or(|(max, _)| seq >= *max) {
+                    latest = Some((seq, file_path));
                 }
             }
         }
 
+        let Some((mut max_sequence, file_path)) = latest else {
+            return Ok(0);
+        };
+
+        let mut reader = BufReader::new(File::open(&file_path)?);
+        let mut buf = Vec::new();
+        loop {
+            let mut len_bytes = [0u8; 4];
+            if reader.read_exact(&mut len_bytes).is_err() {
+                break;
+            }
+            buf.resize(u32::from_le_bytes(len_bytes) as usize, 0);
+            if reader.read_exact(&mut buf).is_err() {
+                break;
+            }
+            match bincode::deserialize::<WalRecord>(&buf) {
+                Ok(record) => max_sequence = max_sequence.max(record.sequence),
+                Err(_) => break,
+            }
+        }
+
         Ok(max_sequence)
     }
 
+    /// Sequence a WAL file was opened at, parsed from its `wal-<hex>.log` name
+    fn file_start_sequence(file_path: &Path) -> Option<u64> {
+        let filename = file_path.file_name()?.to_str()?;
+        let seq_str = filename.strip_prefix("wal-")?.strip_suffix(".log")?;
+        u64::from_str_radix(seq_str, 16).ok()
+    }
+
     /// Get all WAL files in sequence order
     fn get_wal_files(&self) -> WalResult<Vec<PathBuf>> {
         let mut files = Vec::new();
@@ -552,4 +601,70 @@ mod tests {
         }).unwrap();
         assert_eq!(found_version, Some(0));
     }
+
+    fn node_entry(node_id: u64) -> WalEntry {
+        WalEntry::CreateNode {
+            tenant: "default".to_string(),
+            node_id,
+            labels: vec![],
+            properties: vec![],
+        }
+    }
+
+    #[test]
+    fn test_wal_sequence_survives_reopen() {
+        let dir = TempDir::new().unwrap();
+        {
+            let mut wal = Wal::new(dir.path()).unwrap();
+            for i in 1..=7 {
+                wal.append(node_entry(i)).unwrap();
+            }
+            wal.flush().unwrap();
+        }
+
+        // The file is named after its starting sequence (0); reopening must
+        // still continue after the last record, not reuse sequences 1..=7.
+        let mut wal = Wal::new(dir.path()).unwrap();
+        assert_eq!(wal.current_sequence(), 7);
+        assert_eq!(wal.append(node_entry(8)).unwrap(), 8);
+        wal.flush().unwrap();
+
+        let mut ids = Vec::new();
+        let last = wal.replay(0, |entry| {
+            if let WalEntry::CreateNode { node_id, .. } = entry {
+                ids.push(*node_id);
+            }
+            Ok(())
+        }).unwrap();
+        assert_eq!(ids, (1..=8).collect::<Vec<_>>());
+        assert_eq!(last, 8);
+    }
+
+    #[test]
+    fn test_wal_replay_tail_skips_earlier_files() {
+        let dir = TempDir::new().unwrap();
+        let mut wal = Wal::new(dir.path()).unwrap();
+        for i in 1..=100 {
+            wal.append(node_entry(i)).unwrap();
+        }
+        wal.rotate().unwrap();
+        let tail_start = wal.current_sequence() + 1;
+        for i in 101..=103 {
+            wal.append(node_entry(i)).unwrap();
+        }
+        wal.flush().unwrap();
+
+        // Corrupt the first file: a tail replay must never read it.
+        std::fs::write(dir.path().join(format!("wal-{:016x}.log", 0)), [3, 0, 0, 0, 0xff, 0xff, 0xff]).unwrap();
+
+        let mut ids = Vec::new();
+        wal.replay(tail_start, |entry| {
+            if let WalEntry::CreateNode { node_id, .. } = entry {
+                ids.push(*node_id);
+            }
+            Ok(())
+        }).unwrap();
+        assert_eq!(ids, vec![101, 102, 103]);
+        assert!(wal.replay(0, |_| Ok(())).is_err());
+    }
 }
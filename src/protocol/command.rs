@@ -3,14 +3,73 @@
 //! Implements REQ-REDIS-004 (Redis-compatible graph commands)
 //! Now with persistence support - writes are persisted to disk when enabled
 
-use crate::graph::GraphStore;
-use crate::persistence::{PersistenceManager, TenantManager};
+use crate::graph::{GraphStore, Label, WriteSummary};
+use crate::index::ConstraintKind;
+use crate::persistence::{PersistenceManager, TenantError, TenantManager};
 use crate::protocol::resp::RespValue;
-use crate::query::{QueryEngine, Value};
+use crate::protocol::result_set::{self, QueryStatistics, StreamError};
+use crate::query::QueryEngine;
+use std::collections::HashMap;
 use std::sync::Arc;
+use tokio::io::{AsyncWrite, AsyncWriteExt};
 use tokio::sync::RwLock;
 use tracing::{debug, error, warn};
 
+/// Trailing GRAPH.QUERY arguments
+#[derive(Debug, Default, PartialEq)]
+struct QueryOptions {
+    timeout: Option<std::time::Duration>,
+    /// Reply with the compact (typed, id-based) result-set encoding
+    compact: bool,
+}
+
+/// Per-connection state: the statements queued by an open `BEGIN`
+///
+/// `GRAPH.QUERY g "BEGIN"` opens a transaction on graph `g`; the queries that
+/// follow on the same connection are answered with `QUEUED` and run together,
+/// under one write lock and one write batch, at `COMMIT`. `ROLLBACK` discards
+/// them. Nothing reaches the WAL until every statement has succeeded.
+#[derive(Debug, Default)]
+pub struct Session {
+    transaction: Option<QueuedTransaction>,
+}
+
+impl Session {
+    /// Whether a `BEGIN` is waiting for its `COMMIT` or `ROLLBACK`
+    pub fn in_transaction(&self) -> bool {
+        self.transaction.is_some()
+    }
+}
+
+#[derive(Debug)]
+struct QueuedTransaction {
+    graph: String,
+    statements: Vec<(String, QueryOptions)>,
+}
+
+/// A transaction-control statement sent as a GRAPH.QUERY
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum TransactionControl {
+    Begin,
+    Commit,
+    Rollback,
+}
+
+impl TransactionControl {
+    fn parse(query: &str) -> Option<Self> {
+        let keyword = query.trim().trim_end_matches(';').trim_end();
+        if keyword.eq_ignore_ascii_case("BEGIN") {
+            Some(Self::Begin)
+        } else if keyword.eq_ignore_ascii_case("COMMIT") {
+            Some(Self::Commit)
+        } else if keyword.eq_ignore_ascii_case("ROLLBACK") {
+            Some(Self::Rollback)
+        } else {
+            None
+        }
+    }
+}
+
 /// Command handler for processing GRAPH.* commands
 pub struct CommandHandler {
     query_engine: QueryEngine,
@@ -18,6 +77,8 @@ pub struct CommandHandler {
     persistence: Option<Arc<PersistenceManager>>,
     /// Shared tenant registry — HA-09 unifies HTTP + RESP views
     tenant_manager: Arc<TenantManager>,
+    /// Read-only graphs created by GRAPH.COPY, by name; every other name uses the shared store
+    graphs: std::sync::Mutex<HashMap<String, Arc<RwLock<GraphStore>>>>,
 }
 
 impl CommandHandler {
@@ -35,6 +96,7 @@ impl CommandHandler {
             query_engine: QueryEngine::new(),
             persistence,
             tenant_manager,
+            graphs: std::sync::Mutex::new(HashMap::new()),
         }
     }
 
@@ -49,6 +111,7 @@ impl CommandHandler {
             query_engine: QueryEngine::new(),
             persistence,
             tenant_manager,
+            graphs: std::sync::Mutex::new(HashMap::new()),
         }
     }
 
@@ -57,6 +120,16 @@ impl CommandHandler {
         Arc::clone(&self.tenant_manager)
     }
 
+    /// The store of a graph created by GRAPH.COPY, if `name` is one
+    fn copied_graph(&self, name: &str) -> Option<Arc<RwLock<GraphStore>>> {
+        self.graphs.lock().unwrap().get(name).cloned()
+    }
+
+    /// Reply to a write aimed at a GRAPH.COPY copy, which is never persisted
+    fn read_only_copy(name: &str) -> RespValue {
+        RespValue::Error(format!("ERR graph '{}' is a read-only in-memory copy made by GRAPH.COPY", name))
+    }
+
     /// Handle a RESP command
     pub async fn handle_command(
         &self,
@@ -94,6 +167,9 @@ impl CommandHandler {
             "GRAPH.RO_QUERY" => self.handle_graph_ro_query(args, store).await,
             "GRAPH.DELETE" => self.handle_graph_delete(args, store).await,
             "GRAPH.LIST" => self.handle_graph_list(args, store).await,
+            "GRAPH.STATS" => self.handle_graph_stats(args, store).await,
+            "GRAPH.CONSTRAINT" => self.handle_graph_constraint(args, store).await,
+            "GRAPH.COPY" => self.handle_graph_copy(args, store).await,
             "PING" => self.handle_ping(args),
             "ECHO" => self.handle_echo(args),
             "INFO" => self.handle_info(args),
@@ -101,8 +177,198 @@ impl CommandHandler {
         }
     }
 
+    /// Handle a RESP command on a client connection that keeps `session`
+    ///
+    /// Answers `BEGIN`, `COMMIT` and `ROLLBACK` and queues the queries of an open
+    /// transaction (see [`Session`]); everything else goes to
+    /// [`handle_command`](Self::handle_command).
+    pub async fn handle_session_command(
+        &self,
+        value: &RespValue,
+        store: &Arc<RwLock<GraphStore>>,
+        session: &mut Session,
+    ) -> RespValue {
+        let Some((ro_query, graph_name, query_str, rest)) = Self::query_args(value) else {
+            return self.handle_command(value, store).await;
+        };
+
+        match (TransactionControl::parse(&query_str), session.transaction.as_mut()) {
+            (Some(TransactionControl::Begin), Some(tx)) => {
+                RespValue::Error(format!("ERR a transaction is already open on graph '{}'", tx.graph))
+            }
+            (Some(TransactionControl::Begin), None) => {
+                session.transaction = Some(QueuedTransaction { graph: graph_name, statements: Vec::new() });
+                RespValue::SimpleString("OK".to_string())
+            }
+            (Some(TransactionControl::Commit), Some(_)) => {
+                let tx = session.transaction.take().unwrap();
+                self.commit_transaction(tx, store).await
+            }
+            (Some(TransactionControl::Rollback), Some(_)) => {
+                session.transaction = None;
+                RespValue::SimpleString("OK".to_string())
+            }
+            (Some(_), None) => RespValue::Error("ERR no transaction is open".to_string()),
+            (None, Some(tx)) => {
+                if tx.graph != graph_name {
+                    return RespValue::Error(format!(
+                        "ERR the open transaction is on graph '{}', not '{}'",
+                        tx.graph, graph_name
+                    ));
+                }
+                let options = match Self::parse_query_options(rest) {
+                    Ok(o) => o,
+                    Err(e) => return RespValue::Error(e),
+                };
+                if ro_query && !crate::query::parse_query(&query_str).is_ok_and(|q| q.is_read_only()) {
+                    return RespValue::Error(
+                        "ERR GRAPH.RO_QUERY is to be executed only on read-only queries".to_string(),
+                    );
+                }
+                if let Err(e) = self.tenant_manager.acquire_query(&graph_name) {
+                    return RespValue::Error(format!("ERR {}", e));
+                }
+                tx.statements.push((query_str, options));
+                RespValue::SimpleString("QUEUED".to_string())
+            }
+            (None, None) => self.handle_command(value, store).await,
+        }
+    }
+
+    /// Whether it is GRAPH.RO_QUERY, graph name, query and trailing arguments of a
+    /// well-formed GRAPH.QUERY or GRAPH.RO_QUERY
+    fn query_args(value: &RespValue) -> Option<(bool, String, String, &[RespValue])> {
+        let args = value.as_array().ok()?;
+        let cmd = args.first()?.as_string().ok()??;
+        let ro_query = cmd.eq_ignore_ascii_case("GRAPH.RO_QUERY");
+        if !(ro_query || cmd.eq_ignore_ascii_case("GRAPH.QUERY")) {
+            return None;
+        }
+        let graph_name = args.get(1)?.as_string().ok()??;
+        let query_str = args.get(2)?.as_string().ok()??;
+        Some((ro_query, graph_name, query_str, &args[3..]))
+    }
+
+    /// Run the statements of `tx` in one write batch and persist them once all succeeded
+    async fn commit_transaction(&self, tx: QueuedTransaction, store: &Arc<RwLock<GraphStore>>) -> RespValue {
+        debug!("Committing {} statements on {}", tx.statements.len(), tx.graph);
+
+        let copy = self.copied_graph(&tx.graph);
+        if copy.is_some() && tx.statements.iter().any(|(query_str, _)| Self::is_write_query(query_str)) {
+            return Self::read_only_copy(&tx.graph);
+        }
+        let store = copy.as_ref().unwrap_or(store);
+
+        let mut store_guard = store.write().await;
+        if let Err(e) = store_guard.begin_write_batch() {
+            return RespValue::Error(format!("ERR {}", e));
+        }
+
+        let mut replies = Vec::with_capacity(tx.statements.len());
+        for (i, (query_str, options)) in tx.statements.iter().enumerate() {
+            let res = if Self::is_write_query(query_str) {
+                self.execute_write(query_str, options, &tx.graph, &mut store_guard)
+            } else {
+                self.execute_read(query_str, options, &store_guard)
+            };
+            match res {
+                Ok(reply) => replies.push(reply),
+                Err(e) => {
+                    if let Err(e) = store_guard.rollback_write_batch() {
+                        error!("Failed to roll back transaction on {}: {}", tx.graph, e);
+                    }
+                    error!("Query error: {}", e);
+                    return RespValue::Error(format!(
+                        "ERR statement {} failed, transaction rolled back: {}",
+                        i + 1,
+                        e
+                    ));
+                }
+            }
+        }
+
+        let written = match store_guard.commit_write_batch() {
+            Ok(written) => written,
+            Err(e) => return RespValue::Error(format!("ERR {}", e)),
+        };
+        if let Some(persist_mgr) = &self.persistence {
+            Self::persist_write(persist_mgr, &tx.graph, &store_guard, &written);
+        }
+        RespValue::Array(replies)
+    }
+
+    /// Reply to a read-only GRAPH.QUERY or GRAPH.RO_QUERY by streaming its result set to `out`
+    ///
+    /// Returns `Ok(false)` without writing anything when `value` is any other command, has
+    /// malformed arguments, or is not a read-only query; the caller then falls back to
+    /// [`handle_command`](Self::handle_command), which also produces the error replies.
+    /// A query over the tenant's `max_queries_per_sec` is answered here with the
+    /// rate-limit error. An `Err` means the connection can no longer be used and should be
+    /// closed. The store's read lock is held until the last row has been written, so a client
+    /// that reads slowly delays writers for that long.
+    pub async fn stream_read_query<W: AsyncWrite + Unpin>(
+        &self,
+        value: &RespValue,
+        store: &Arc<RwLock<GraphStore>>,
+        resp3: bool,
+        out: &mut W,
+    ) -> std::io::Result<bool> {
+        let Ok(args) = value.as_array() else { return Ok(false) };
+        let is_query = args.first().and_then(|a| a.as_string().ok().flatten()).is_some_and(|cmd| {
+            cmd.eq_ignore_ascii_case("GRAPH.QUERY") || cmd.eq_ignore_ascii_case("GRAPH.RO_QUERY")
+        });
+        if !is_query || args.len() < 3 {
+            return Ok(false);
+        }
+        let Ok(Some(graph_name)) = args[1].as_string() else { return Ok(false) };
+        let Ok(Some(query_str)) = args[2].as_string() else { return Ok(false) };
+        let Ok(options) = Self::parse_query_options(&args[3..]) else { return Ok(false) };
+        if !crate::query::parse_query(&query_str).is_ok_and(|q| q.is_read_only()) {
+            return Ok(false);
+        }
+
+        if let Err(e) = self.tenant_manager.acquire_query(&graph_name) {
+            let mut reply = Vec::new();
+            RespValue::Error(format!("ERR {}", e)).encode(&mut reply)?;
+            out.write_all(&reply).await?;
+            return Ok(true);
+        }
+
+        debug!("Streaming read query: {}", query_str);
+
+        let copy = self.copied_graph(&graph_name);
+        let store = copy.as_ref().unwrap_or(store).read().await;
+        let started = std::time::Instant::now();
+        let failure = match self
+            .query_engine
+            .execute_cursor_with_timeout(&query_str, &store, options.timeout)
+            .map_err(|e| e.to_string())
+        {
+            Err(e) => Some(e),
+            Ok(mut cursor) => {
+                match result_set::stream_result_set(&mut cursor, &store, options.compact, resp3, started, out).await {
+                    Ok(()) => None,
+                    Err(StreamError::Query(e)) => Some(e.to_string()),
+                    Err(StreamError::Aborted(e)) => {
+                        error!("Query error mid-reply, closing connection: {}", e);
+                        return Err(std::io::Error::other(e.to_string()));
+                    }
+                    Err(StreamError::Io(e)) => return Err(e),
+                }
+            }
+        };
+
+        if let Some(e) = failure {
+            error!("Query error: {}", e);
+            let mut reply = Vec::new();
+            RespValue::Error(format!("ERR {}", e)).encode(&mut reply)?;
+            out.write_all(&reply).await?;
+        }
+        Ok(true)
+    }
+
     /// Handle GRAPH.QUERY command
-    /// Format: GRAPH.QUERY graph_name "MATCH (n) RETURN n"
+    /// Format: GRAPH.QUERY graph_name "MATCH (n) RETURN n" [TIMEOUT ms] [--compact]
     async fn handle_graph_query(
         &self,
         args: &[RespValue],
@@ -126,70 +392,53 @@ impl CommandHandler {
             Err(e) => return RespValue::Error(format!("ERR {}", e)),
         };
 
+        let options = match Self::parse_query_options(&args[3..]) {
+            Ok(o) => o,
+            Err(e) => return RespValue::Error(e),
+        };
+
+        if TransactionControl::parse(&query_str).is_some() {
+            return RespValue::Error(format!("ERR {} needs a client connection", query_str.trim()));
+        }
+
+        if let Err(e) = self.tenant_manager.acquire_query(&graph_name) {
+            return RespValue::Error(format!("ERR {}", e));
+        }
+
         debug!("Executing query: {}", query_str);
 
-        // Check if this is a write query (CREATE, DELETE, SET, MERGE)
-        let query_upper = query_str.trim().to_uppercase();
-        let is_write_query = query_upper.starts_with("CREATE")
-            || query_upper.starts_with("DELETE")
-            || query_upper.starts_with("SET")
-            || query_upper.starts_with("MERGE")
-            || query_upper.contains(" CREATE ")
-            || query_upper.contains(" DELETE ")
-            || query_upper.contains(" SET ")
-            || query_upper.contains(" MERGE ");
+        // Copies made by GRAPH.COPY live in memory only and take no writes
+        let copy = self.copied_graph(&graph_name);
+        let is_write = Self::is_write_query(&query_str);
+        if copy.is_some() && is_write {
+            return Self::read_only_copy(&graph_name);
+        }
+        let store = copy.as_ref().unwrap_or(store);
 
         // Execute query with appropriate method
-        let result = if is_write_query {
+        let reply = if is_write {
             let mut store_guard = store.write().await;
-            
-            // Set current tenant for indexing events
-            // In a more complex architecture, the store_guard would be isolated
-            let res = self.query_engine.execute_mut(&query_str, &mut *store_guard, &graph_name);
-
-            // If write succeeded and persistence is enabled, persist the changes
-            if let (Ok(ref batch), Some(ref persist_mgr)) = (&res, &self.persistence) {
-                // Extract created nodes/edges from the result and persist them
-                // The RecordBatch contains Node and Edge values that were created
-                for record in &batch.records {
-                    for (_col, value) in record.bindings().iter() {
-                        match value {
-                            Value::Node(node_id, node) => {
-                                // Persist the created node
-                                if let Err(e) = persist_mgr.persist_create_node(&graph_name, node) {
-                                    warn!("Failed to persist node {:?}: {}", node_id, e);
-                                }
-                            }
-                            Value::Edge(edge_id, edge) => {
-                                // Persist the created edge
-                                if let Err(e) = persist_mgr.persist_create_edge(&graph_name, edge) {
-                                    warn!("Failed to persist edge {:?}: {}", edge_id, e);
-                                }
-                            }
-                            Value::NodeRef(_) | Value::EdgeRef(..) => {
-                                // Refs from read queries — nothing to persist
-                            }
-                            _ => {} // Other value types don't need persistence
-                        }
+            // The batch records what the query wrote; a failed query keeps its
+            // partial writes, so they are persisted all the same
+            let res = match store_guard.begin_write_batch() {
+                Ok(()) => self.execute_write(&query_str, &options, &graph_name, &mut store_guard),
+                Err(e) => Err(e.into()),
+            };
+            match store_guard.commit_write_batch() {
+                Ok(written) => {
+                    if let Some(persist_mgr) = &self.persistence {
+                        Self::persist_write(persist_mgr, &graph_name, &store_guard, &written);
                     }
                 }
-                debug!("Write query persisted successfully");
+                Err(e) => warn!("No write batch to commit on {}: {}", graph_name, e),
             }
-
-            drop(store_guard);
             res
         } else {
-            let store_guard = store.read().await;
-            let res = self.query_engine.execute(&query_str, &*store_guard);
-            drop(store_guard);
-            res
+            self.execute_read(&query_str, &options, &*store.read().await)
         };
 
-        match result {
-            Ok(batch) => {
-                // Format result as RESP array
-                self.format_query_result(batch)
-            }
+        match reply {
+            Ok(reply) => reply,
             Err(e) => {
                 error!("Query error: {}", e);
                 RespValue::Error(format!("ERR {}", e))
@@ -197,14 +446,184 @@ impl CommandHandler {
         }
     }
 
+    /// Whether `query_str` must run under the write lock (CREATE, DELETE, SET, REMOVE, MERGE)
+    fn is_write_query(query_str: &str) -> bool {
+        let query_upper = query_str.trim().to_uppercase();
+        query_upper.starts_with("CREATE")
+            || query_upper.starts_with("DELETE")
+            || query_upper.starts_with("SET")
+            || query_upper.starts_with("MERGE")
+            || query_upper.starts_with("REMOVE")
+            || query_upper.contains(" CREATE ")
+            || query_upper.contains(" DELETE ")
+            || query_upper.contains(" SET ")
+            || query_upper.contains(" MERGE ")
+            || query_upper.contains(" REMOVE ")
+    }
+
+    /// Run a write query inside the caller's open write batch and encode its
+    /// reply; persisting it is up to the caller
+    fn execute_write(
+        &self,
+        query_str: &str,
+        options: &QueryOptions,
+        graph_name: &str,
+        store: &mut GraphStore,
+    ) -> Result<RespValue, Box<dyn std::error::Error>> {
+        let position = store.write_batch_position();
+        let started = std::time::Instant::now();
+        let batch = match options.timeout {
+            Some(t) => self.query_engine.execute_mut_with_timeout(query_str, store, graph_name, HashMap::new(), t)?,
+            None => self.query_engine.execute_mut(query_str, store, graph_name)?,
+        };
+        let stats = QueryStatistics::from_writes(store.write_counts_since(position), started.elapsed());
+
+        // RedisGraph replies to a write without RETURN with statistics only
+        let returns_rows = crate::query::parse_query(query_str)
+            .map_or(true, |q| q.return_clause.is_some());
+        let reply = if returns_rows {
+            result_set::encode_result_set(&batch, store, &stats, options.compact)
+        } else {
+            result_set::encode_statistics_only(&stats)
+        };
+        Ok(reply)
+    }
+
+    /// Log the nodes and edges a committed write batch changed, then start a
+    /// snapshot in the background if the graph is due one
+    fn persist_write(persist_mgr: &Arc<PersistenceManager>, graph_name: &str, store: &GraphStore, written: &WriteSummary) {
+        for &id in &written.nodes_created {
+            let Some(node) = store.get_node(id) else { continue };
+            if let Err(e) = persist_mgr.persist_create_node(graph_name, node) {
+                warn!("Failed to persist node {:?}: {}", id, e);
+            }
+        }
+        for &id in &written.nodes_updated {
+            let Some(node) = store.get_node(id) else { continue };
+            if let Err(e) = persist_mgr.persist_replace_node(graph_name, node) {
+                warn!("Failed to persist node {:?}: {}", id, e);
+            }
+        }
+        for &id in &written.edges_created {
+            let Some(edge) = store.get_edge(id) else { continue };
+            if let Err(e) = persist_mgr.persist_create_edge(graph_name, &edge) {
+                warn!("Failed to persist edge {:?}: {}", id, e);
+            }
+        }
+        for &id in &written.edges_updated {
+            let Some(edge) = store.get_edge(id) else { continue };
+            if let Err(e) = persist_mgr.persist_replace_edge(graph_name, &edge) {
+                warn!("Failed to persist edge {:?}: {}", id, e);
+            }
+        }
+        for &id in &written.edges_deleted {
+            if let Err(e) = persist_mgr.persist_delete_edge(graph_name, id.as_u64()) {
+                warn!("Failed to persist deletion of edge {:?}: {}", id, e);
+            }
+        }
+        for &id in &written.nodes_deleted {
+            if let Err(e) = persist_mgr.persist_delete_node(graph_name, id.as_u64()) {
+                warn!("Failed to persist deletion of node {:?}: {}", id, e);
+            }
+        }
+        debug!("Write query persisted successfully");
+
+        // Periodic snapshot keeps restart replay bounded to the WAL tail
+        persist_mgr.snapshot_if_due(graph_name);
+    }
+
+    /// Run a read query and encode its result set
+    fn execute_read(
+        &self,
+        query_str: &str,
+        options: &QueryOptions,
+        store: &GraphStore,
+    ) -> Result<RespValue, Box<dyn std::error::Error>> {
+        let started = std::time::Instant::now();
+        let batch = match options.timeout {
+            Some(t) => self.query_engine.execute_with_timeout(query_str, store, HashMap::new(), t)?,
+            None => self.query_engine.execute(query_str, store)?,
+        };
+        let stats = QueryStatistics { execution_time: started.elapsed(), ..Default::default() };
+        Ok(result_set::encode_result_set(&batch, store, &stats, options.compact))
+    }
+
+    /// Parse the trailing GRAPH.QUERY arguments: `TIMEOUT ms` and `--compact`.
+    /// Other trailing arguments are ignored. `TIMEOUT 0` means the server default.
+    fn parse_query_options(args: &[RespValue]) -> Result<QueryOptions, String> {
+        let mut options = QueryOptions::default();
+        let mut iter = args.iter();
+        while let Some(arg) = iter.next() {
+            let Ok(Some(arg)) = arg.as_string() else { continue };
+            if arg.eq_ignore_ascii_case("--compact") {
+                options.compact = true;
+            } else if arg.eq_ignore_ascii_case("TIMEOUT") {
+                let ms = iter
+                    .next()
+                    .and_then(|v| v.as_string().ok().flatten())
+                    .and_then(|s| s.parse::<u64>().ok())
+                    .ok_or_else(|| "ERR TIMEOUT requires a non-negative integer (milliseconds)".to_string())?;
+                options.timeout = (ms > 0).then(|| std::time::Duration::from_millis(ms));
+            }
+        }
+        Ok(options)
+    }
+
     /// Handle GRAPH.RO_QUERY (read-only query)
+    ///
+    /// The query is parsed up front and rejected if any clause writes data or
+    /// changes the schema; otherwise it runs under a shared read lock.
     async fn handle_graph_ro_query(
         &self,
         args: &[RespValue],
         store: &Arc<RwLock<GraphStore>>,
     ) -> RespValue {
-        // For now, same as GRAPH.QUERY (we don't enforce read-only yet)
-        self.handle_graph_query(args, store).await
+        if args.len() < 3 {
+            return RespValue::Error("ERR wrong number of arguments for 'GRAPH.RO_QUERY' command".to_string());
+        }
+
+        let graph_name = match args[1].as_string() {
+            Ok(Some(s)) => s,
+            Ok(None) => return RespValue::Error("ERR null graph name".to_string()),
+            Err(e) => return RespValue::Error(format!("ERR {}", e)),
+        };
+
+        let query_str = match args[2].as_string() {
+            Ok(Some(s)) => s,
+            Ok(None) => return RespValue::Error("ERR null query".to_string()),
+            Err(e) => return RespValue::Error(format!("ERR {}", e)),
+        };
+
+        let options = match Self::parse_query_options(&args[3..]) {
+            Ok(o) => o,
+            Err(e) => return RespValue::Error(e),
+        };
+
+        match crate::query::parse_query(&query_str) {
+            Ok(query) if !query.is_read_only() => {
+                return RespValue::Error(
+                    "ERR GRAPH.RO_QUERY is to be executed only on read-only queries".to_string(),
+                );
+            }
+            Ok(_) => {}
+            Err(e) => return RespValue::Error(format!("ERR {}", e)),
+        }
+
+        if let Err(e) = self.tenant_manager.acquire_query(&graph_name) {
+            return RespValue::Error(format!("ERR {}", e));
+        }
+
+        debug!("Executing read-only query: {}", query_str);
+
+        let copy = self.copied_graph(&graph_name);
+        let guard = copy.as_ref().unwrap_or(store).read().await;
+        match self.execute_read(&query_str, &options, &guard) {
+            Ok(reply) => reply,
+            Err(e) => {
+                error!("Query error: {}", e);
+                RespValue::Error(format!("ERR {}", e))
+            }
+        }
     }
 
     /// Handle GRAPH.DELETE command
@@ -217,12 +636,20 @@ impl CommandHandler {
             return RespValue::Error("ERR wrong number of arguments for 'GRAPH.DELETE' command".to_string());
         }
 
-        let _graph_name = match args[1].as_string() {
+        let graph_name = match args[1].as_string() {
             Ok(Some(s)) => s,
             Ok(None) => return RespValue::Error("ERR null graph name".to_string()),
             Err(e) => return RespValue::Error(format!("ERR {}", e)),
         };
 
+        // A copy is dropped outright, along with its tenant
+        if self.graphs.lock().unwrap().remove(&graph_name).is_some() {
+            if let Err(e) = self.tenant_manager.delete_tenant(&graph_name) {
+                warn!("Failed to delete tenant {}: {}", graph_name, e);
+            }
+            return RespValue::SimpleString("OK".to_string());
+        }
+
         // Clear the graph
         let mut store_guard = store.write().await;
         store_guard.clear();
@@ -251,6 +678,204 @@ impl CommandHandler {
         )
     }
 
+    /// Handle GRAPH.STATS command
+    /// Format: GRAPH.STATS graph_name
+    ///
+    /// Replies with a flat field/value array: `nodes`, `edges`, then `labels`
+    /// and `edge_types` as `[name, count]` pairs and `indexes` and
+    /// `vector_indexes` as `[label, property]` pairs.
+    async fn handle_graph_stats(
+        &self,
+        args: &[RespValue],
+        store: &Arc<RwLock<GraphStore>>,
+    ) -> RespValue {
+        if args.len() < 2 {
+            return RespValue::Error("ERR wrong number of arguments for 'GRAPH.STATS' command".to_string());
+        }
+
+        let graph_name = match args[1].as_string() {
+            Ok(Some(s)) => s,
+            Ok(None) => return RespValue::Error("ERR null graph name".to_string()),
+            Err(e) => return RespValue::Error(format!("ERR {}", e)),
+        };
+
+        let copy = self.copied_graph(&graph_name);
+        let stats = copy.as_ref().unwrap_or(store).read().await.stats();
+        let bulk = |s: &str| RespValue::BulkString(Some(s.as_bytes().to_vec()));
+        let counts = |entries: &[crate::graph::NameCount]| {
+            RespValue::Array(
+                entries
+                    .iter()
+                    .map(|e| RespValue::Array(vec![bulk(&e.name), RespValue::Integer(e.count as i64)]))
+                    .collect(),
+            )
+        };
+        let indexes = |entries: &[crate::graph::IndexedProperty]| {
+            RespValue::Array(
+                entries
+                    .iter()
+                    .map(|e| RespValue::Array(vec![bulk(&e.label), bulk(&e.property)]))
+                    .collect(),
+            )
+        };
+
+        RespValue::Array(vec![
+            bulk("nodes"),
+            RespValue::Integer(stats.nodes as i64),
+            bulk("edges"),
+            RespValue::Integer(stats.edges as i64),
+            bulk("labels"),
+            counts(&stats.labels),
+            bulk("edge_types"),
+            counts(&stats.edge_types),
+            bulk("indexes"),
+            indexes(&stats.indexes),
+            bulk("vector_indexes"),
+            indexes(&stats.vector_indexes),
+        ])
+    }
+
+    /// Handle GRAPH.CONSTRAINT command
+    /// Format: GRAPH.CONSTRAINT CREATE|DROP graph_name UNIQUE|MANDATORY NODE label PROPERTIES 1 prop
+    ///
+    /// Same as `CREATE CONSTRAINT ON (n:label) ASSERT n.prop IS UNIQUE | IS NOT NULL`.
+    /// Only single-property node constraints are supported.
+    async fn handle_graph_constraint(
+        &self,
+        args: &[RespValue],
+        store: &Arc<RwLock<GraphStore>>,
+    ) -> RespValue {
+        const USAGE: &str = "ERR usage: GRAPH.CONSTRAINT CREATE|DROP graph UNIQUE|MANDATORY NODE label PROPERTIES 1 prop";
+        let mut words = Vec::with_capacity(args.len());
+        for arg in &args[1..] {
+            match arg.as_string() {
+                Ok(Some(s)) => words.push(s),
+                Ok(None) => return RespValue::Error("ERR null argument".to_string()),
+                Err(e) => return RespValue::Error(format!("ERR {}", e)),
+            }
+        }
+        let [op, graph_name, kind, entity, label, properties_kw, count, property] = words.as_slice() else {
+            return RespValue::Error(USAGE.to_string());
+        };
+
+        let kind = if kind.eq_ignore_ascii_case("UNIQUE") {
+            ConstraintKind::Unique
+        } else if kind.eq_ignore_ascii_case("MANDATORY") {
+            ConstraintKind::NotNull
+        } else {
+            return RespValue::Error(format!("ERR unknown constraint type '{}'", kind));
+        };
+        if entity.eq_ignore_ascii_case("RELATIONSHIP") {
+            return RespValue::Error("ERR relationship constraints are not supported".to_string());
+        }
+        if !entity.eq_ignore_ascii_case("NODE") || !properties_kw.eq_ignore_ascii_case("PROPERTIES") {
+            return RespValue::Error(USAGE.to_string());
+        }
+        if count != "1" {
+            return RespValue::Error("ERR only single-property constraints are supported".to_string());
+        }
+
+        if self.copied_graph(graph_name).is_some() {
+            return Self::read_only_copy(graph_name);
+        }
+        let label = Label::new(label.as_str());
+        let mut store_guard = store.write().await;
+        if op.eq_ignore_ascii_case("CREATE") {
+            match store_guard.create_constraint(label, property.clone(), kind) {
+                Ok(()) => RespValue::SimpleString("OK".to_string()),
+                Err(e) => RespValue::Error(format!("ERR {}", e)),
+            }
+        } else if op.eq_ignore_ascii_case("DROP") {
+            if store_guard.drop_constraint(&label, property, kind) {
+                RespValue::SimpleString("OK".to_string())
+            } else {
+                RespValue::Error("ERR constraint does not exist".to_string())
+            }
+        } else {
+            RespValue::Error(USAGE.to_string())
+        }
+    }
+
+    /// Handle GRAPH.COPY command
+    /// Format: GRAPH.COPY src_graph dst_graph
+    ///
+    /// Deep-copies nodes, edges, property and vector indexes and constraints of
+    /// `src_graph` into a new graph `dst_graph`. The source's read lock is held
+    /// for the whole copy, so concurrent writes land entirely before or after it.
+    /// If `dst_graph` is already a registered tenant its quotas bound the copy;
+    /// otherwise it is registered with default quotas. Copies are kept in
+    /// memory only and are not persisted, so they are read-only: writes and
+    /// constraint changes on them are rejected, and they are lost on restart.
+    async fn handle_graph_copy(
+        &self,
+        args: &[RespValue],
+        store: &Arc<RwLock<GraphStore>>,
+    ) -> RespValue {
+        if args.len() != 3 {
+            return RespValue::Error("ERR wrong number of arguments for 'GRAPH.COPY' command".to_string());
+        }
+
+        let mut names = Vec::with_capacity(2);
+        for arg in &args[1..] {
+            match arg.as_string() {
+                Ok(Some(s)) => names.push(s),
+                Ok(None) => return RespValue::Error("ERR null graph name".to_string()),
+                Err(e) => return RespValue::Error(format!("ERR {}", e)),
+            }
+        }
+        let (src_name, dst_name) = (&names[0], &names[1]);
+
+        let already_exists = || RespValue::Error(format!("ERR graph '{}' already exists", dst_name));
+        if dst_name == src_name || dst_name == "default" || self.copied_graph(dst_name).is_some() {
+            return already_exists();
+        }
+
+        let src_copy = self.copied_graph(src_name);
+        let src = src_copy.as_ref().unwrap_or(store).read().await;
+
+        let registered = match self.tenant_manager.create_tenant(dst_name.clone(), dst_name.clone(), None) {
+            Ok(()) => true,
+            Err(TenantError::AlreadyExists(_)) => false,
+            Err(e) => return RespValue::Error(format!("ERR {}", e)),
+        };
+        let rollback = || {
+            if registered {
+                let _ = self.tenant_manager.delete_tenant(dst_name);
+            }
+        };
+
+        let (nodes, edges) = (src.node_count(), src.edge_count());
+        if let Err(e) = self.tenant_manager.check_capacity(dst_name, nodes, edges) {
+            rollback();
+            return RespValue::Error(format!("ERR {}", e));
+        }
+
+        debug!("Copying graph {} to {}", src_name, dst_name);
+        let copied = match crate::snapshot::copy_tenant(&src) {
+            Ok((copied, _)) => copied,
+            Err(e) => {
+                error!("Copy error: {}", e);
+                rollback();
+                return RespValue::Error(format!("ERR {}", e));
+            }
+        };
+        drop(src);
+
+        {
+            let mut graphs = self.graphs.lock().unwrap();
+            if graphs.contains_key(dst_name) {
+                drop(graphs);
+                rollback();
+                return already_exists();
+            }
+            graphs.insert(dst_name.clone(), Arc::new(RwLock::new(copied)));
+        }
+        let _ = self.tenant_manager.increment_usage(dst_name, "nodes", nodes);
+        let _ = self.tenant_manager.increment_usage(dst_name, "edges", edges);
+
+        RespValue::SimpleString("OK (read-only in-memory copy, not persisted)".to_string())
+    }
+
     /// Handle PING command
     fn handle_ping(&self, args: &[RespValue]) -> RespValue {
         if args.len() > 1 {
@@ -292,83 +917,6 @@ impl CommandHandler {
         );
         RespValue::BulkString(Some(info.into_bytes()))
     }
-
-    /// Format query results as RESP value
-    fn format_query_result(&self, batch: crate::query::RecordBatch) -> RespValue {
-        let mut result_rows = Vec::new();
-
-        // Add header row with column names
-        let mut header = Vec::new();
-        for col in &batch.columns {
-            header.push(RespValue::BulkString(Some(col.clone().into_bytes())));
-        }
-        result_rows.push(RespValue::Array(header));
-
-        // Add data rows
-        for record in &batch.records {
-            let mut row = Vec::new();
-            for col_name in &batch.columns {
-                if let Some(value) = record.get(col_name) {
-                    row.push(self.format_value(value));
-                } else {
-                    row.push(RespValue::Null);
-                }
-            }
-            result_rows.push(RespValue::Array(row));
-        }
-
-        // Return array of [header, data1, data2, ...]
-        RespValue::Array(result_rows)
-    }
-
-    /// Format a query value as RESP
-    fn format_value(&self, value: &Value) -> RespValue {
-        match value {
-            // _node prefixed with underscore - node data available but not used in
-            // simple string formatting (only showing id for RESP compatibility)
-            Value::Node(id, _node) => {
-                // Format node as JSON-like string
-                let node_str = format!("Node({:?})", id);
-                RespValue::BulkString(Some(node_str.into_bytes()))
-            }
-            Value::NodeRef(id) => {
-                let node_str = format!("Node({:?})", id);
-                RespValue::BulkString(Some(node_str.into_bytes()))
-            }
-            Value::Edge(id, edge) => {
-                // Format edge as JSON-like string
-                let edge_str = format!("Edge({:?}, {} -> {})", id, edge.source, edge.target);
-                RespValue::BulkString(Some(edge_str.into_bytes()))
-            }
-            Value::EdgeRef(id, src, tgt, _) => {
-                let edge_str = format!("Edge({:?}, {} -> {})", id, src, tgt);
-                RespValue::BulkString(Some(edge_str.into_bytes()))
-            }
-            Value::Property(prop) => {
-                // Format property value
-                match prop {
-                    crate::graph::PropertyValue::String(s) => {
-                        RespValue::BulkString(Some(s.clone().into_bytes()))
-                    }
-                    crate::graph::PropertyValue::Integer(i) => {
-                        RespValue::Integer(*i)
-                    }
-                    crate::graph::PropertyValue::Float(f) => {
-                        RespValue::BulkString(Some(f.to_string().into_bytes()))
-                    }
-                    crate::graph::PropertyValue::Boolean(b) => {
-                        RespValue::BulkString(Some(b.to_string().into_bytes()))
-                    }
-                    _ => RespValue::BulkString(Some(format!("{:?}", prop).into_bytes())),
-                }
-            }
-            Value::Path { nodes, edges } => {
-                let path_str = format!("Path(nodes: {:?}, edges: {:?})", nodes, edges);
-                RespValue::BulkString(Some(path_str.into_bytes()))
-            }
-            Value::Null => RespValue::Null,
-        }
-    }
 }
 
 impl Default for CommandHandler {
@@ -433,6 +981,39 @@ mod tests {
         assert!(matches!(response, RespValue::Array(_)));
     }
 
+    #[tokio::test]
+    async fn test_graph_query_timeout_argument() {
+        let handler = CommandHandler::new(None);
+        let mut graph_store = GraphStore::new();
+        for _ in 0..1000 {
+            graph_store.create_node("N");
+        }
+        let store = Arc::new(RwLock::new(graph_store));
+
+        let query = |q: &str, trailer: &[&str]| {
+            let mut parts = vec![
+                RespValue::BulkString(Some(b"GRAPH.QUERY".to_vec())),
+                RespValue::BulkString(Some(b"mygraph".to_vec())),
+                RespValue::BulkString(Some(q.as_bytes().to_vec())),
+            ];
+            parts.extend(trailer.iter().map(|t| RespValue::BulkString(Some(t.as_bytes().to_vec()))));
+            RespValue::Array(parts)
+        };
+
+        // 10^9 rows: the query cannot finish before the deadline on any machine
+        let cmd = query("MATCH (a:N), (b:N), (c:N) RETURN count(*)", &["TIMEOUT", "100"]);
+        match handler.handle_command(&cmd, &store).await {
+            RespValue::Error(e) => assert!(e.contains("Query timed out"), "{}", e),
+            other => panic!("expected timeout error, got {:?}", other),
+        }
+
+        let cmd = query("MATCH (a:N) RETURN count(a)", &["timeout", "60000"]);
+        assert!(matches!(handler.handle_command(&cmd, &store).await, RespValue::Array(_)));
+
+        let cmd = query("MATCH (a:N) RETURN count(a)", &["TIMEOUT", "soon"]);
+        assert!(matches!(handler.handle_command(&cmd, &store).await, RespValue::Error(_)));
+    }
+
     // ========== Batch 6: Additional Command Tests ==========
 
     #[tokio::test]
@@ -465,6 +1046,33 @@ mod tests {
         assert!(matches!(response, RespValue::Array(_)));
     }
 
+    #[tokio::test]
+    async fn test_graph_ro_query_rejects_writes() {
+        let handler = CommandHandler::new(None);
+        let store = Arc::new(RwLock::new(GraphStore::new()));
+        let command = |query: &str| RespValue::Array(vec![
+            RespValue::BulkString(Some(b"GRAPH.RO_QUERY".to_vec())),
+            RespValue::BulkString(Some(b"mygraph".to_vec())),
+            RespValue::BulkString(Some(query.as_bytes().to_vec())),
+        ]);
+
+        for query in [
+            "CREATE (n:Person {name: 'Alice'})",
+            "MATCH (n) SET n.seen = true",
+            "MATCH (n) DETACH DELETE n",
+            "CREATE INDEX ON :Person(name)",
+        ] {
+            match handler.handle_command(&command(query), &store).await {
+                RespValue::Error(e) => assert!(e.contains("read-only"), "{}", e),
+                other => panic!("{} should be rejected, got {:?}", query, other),
+            }
+        }
+        assert_eq!(store.read().await.node_count(), 0);
+
+        let response = handler.handle_command(&command("MATCH (n) RETURN n"), &store).await;
+        assert!(matches!(response, RespValue::Array(_)));
+    }
+
     #[tokio::test]
     async fn test_graph_delete() {
         let handler = CommandHandler::new(None);
@@ -491,6 +1099,38 @@ mod tests {
         assert!(matches!(response, RespValue::Array(_)));
     }
 
+    #[tokio::test]
+    async fn test_graph_stats() {
+        let handler = CommandHandler::new(None);
+        let mut graph_store = GraphStore::new();
+        let a = graph_store.create_node("Person");
+        let b = graph_store.create_node("Person");
+        graph_store.create_edge(a, b, "KNOWS").unwrap();
+        let store = Arc::new(RwLock::new(graph_store));
+
+        let cmd = RespValue::Array(vec![
+            RespValue::BulkString(Some(b"GRAPH.STATS".to_vec())),
+            RespValue::BulkString(Some(b"mygraph".to_vec())),
+        ]);
+        let response = handler.handle_command(&cmd, &store).await;
+        let RespValue::Array(fields) = response else {
+            panic!("expected array, got {:?}", response);
+        };
+        assert_eq!(fields[0], RespValue::BulkString(Some(b"nodes".to_vec())));
+        assert_eq!(fields[1], RespValue::Integer(2));
+        assert_eq!(fields[3], RespValue::Integer(1));
+        assert_eq!(
+            fields[5],
+            RespValue::Array(vec![RespValue::Array(vec![
+                RespValue::BulkString(Some(b"Person".to_vec())),
+                RespValue::Integer(2),
+            ])])
+        );
+
+        let missing_graph = RespValue::Array(vec![RespValue::BulkString(Some(b"GRAPH.STATS".to_vec()))]);
+        assert!(matches!(handler.handle_command(&missing_graph, &store).await, RespValue::Error(_)));
+    }
+
     #[tokio::test]
     async fn test_info_command() {
         let handler = CommandHandler::new(None);
@@ -538,7 +1178,355 @@ mod tests {
             RespValue::BulkString(Some(b"CREATE (n:Person {name: 'Alice'})".to_vec())),
         ]);
         let response = handler.handle_command(&cmd, &store).await;
-        assert!(matches!(response, RespValue::Array(_)));
+        // No RETURN: the reply is the statistics section alone
+        let RespValue::Array(sections) = response else { panic!("expected array") };
+        assert_eq!(sections.len(), 1);
+        let RespValue::Array(stats) = &sections[0] else { panic!("expected stats array") };
+        assert_eq!(stats[0], RespValue::BulkString(Some(b"Nodes created: 1".to_vec())));
+        match &stats[1] {
+            RespValue::BulkString(Some(b)) => assert!(b.starts_with(b"Query internal execution time:")),
+            other => panic!("unexpected stats line {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_graph_query_statistics_count_each_write() {
+        let handler = CommandHandler::new(None);
+        let store = Arc::new(RwLock::new(GraphStore::new()));
+        let query = |q: &str| {
+            RespValue::Array(["GRAPH.QUERY", "g", q].iter().map(|p| RespValue::BulkString(Some(p.as_bytes().to_vec()))).collect())
+        };
+        let stats = |reply: RespValue| -> Vec<String> {
+            let RespValue::Array(sections) = reply else { panic!("expected array, got {:?}", reply) };
+            let Some(RespValue::Array(lines)) = sections.last() else { panic!("expected stats array") };
+            lines.iter().filter_map(|l| l.as_string().ok().flatten()).collect()
+        };
+
+        for q in [
+            "CREATE (:Person {name: 'Alice'})",
+            "CREATE (:Person {name: 'Bob'})",
+            "MATCH (a:Person {name: 'Alice'}), (b:Person {name: 'Bob'}) CREATE (a)-[:KNOWS]->(b)",
+        ] {
+            handler.handle_command(&query(q), &store).await;
+        }
+        let lines = stats(handler.handle_command(&query("MATCH (n:Person {name: 'Bob'}) DETACH DELETE n"), &store).await);
+        assert_eq!(lines[..lines.len() - 1], ["Nodes deleted: 1", "Relationships deleted: 1"]);
+    }
+
+    #[tokio::test]
+    async fn test_graph_query_compact_flag() {
+        let handler = CommandHandler::new(None);
+        let mut graph_store = GraphStore::new();
+        let n = graph_store.create_node("Person");
+        graph_store.set_node_property("default", n, "name", "Ann").unwrap();
+        let store = Arc::new(RwLock::new(graph_store));
+
+        let cmd = RespValue::Array(vec![
+            RespValue::BulkString(Some(b"GRAPH.QUERY".to_vec())),
+            RespValue::BulkString(Some(b"mygraph".to_vec())),
+            RespValue::BulkString(Some(b"MATCH (n:Person) RETURN n.name AS name".to_vec())),
+            RespValue::BulkString(Some(b"--compact".to_vec())),
+        ]);
+        let RespValue::Array(sections) = handler.handle_command(&cmd, &store).await else {
+            panic!("expected array")
+        };
+        assert_eq!(sections.len(), 3);
+        assert_eq!(
+            sections[0],
+            RespValue::Array(vec![RespValue::Array(vec![
+                RespValue::Integer(1),
+                RespValue::BulkString(Some(b"name".to_vec())),
+            ])])
+        );
+        assert_eq!(
+            sections[1],
+            RespValue::Array(vec![RespValue::Array(vec![RespValue::Array(vec![
+                RespValue::Integer(2),
+                RespValue::BulkString(Some(b"Ann".to_vec())),
+            ])])])
+        );
+    }
+
+    #[tokio::test]
+    async fn test_stream_read_query() {
+        let handler = CommandHandler::new(None);
+        let store = Arc::new(RwLock::new(GraphStore::new()));
+        let command = |parts: &[&str]| {
+            RespValue::Array(parts.iter().map(|p| RespValue::BulkString(Some(p.as_bytes().to_vec()))).collect())
+        };
+        handler.handle_command(&command(&["GRAPH.QUERY", "g", "CREATE (:Person {name: 'Ann'})"]), &store).await;
+
+        for cmd in [
+            command(&["GRAPH.QUERY", "g", "CREATE (:Person {name: 'Bob'})"]),
+            command(&["GRAPH.RO_QUERY", "g", "MATCH (n) SET n.x = 1"]),
+            command(&["GRAPH.QUERY", "g", "MATCH (n"]),
+            command(&["GRAPH.QUERY", "g"]),
+            command(&["GRAPH.LIST"]),
+        ] {
+            let mut out = Vec::new();
+            assert!(!handler.stream_read_query(&cmd, &store, false, &mut out).await.unwrap());
+            assert!(out.is_empty());
+        }
+
+        let read = command(&["GRAPH.RO_QUERY", "g", "MATCH (n:Person) RETURN n.name", "--compact"]);
+        let mut out = Vec::new();
+        assert!(handler.stream_read_query(&read, &store, false, &mut out).await.unwrap());
+        let streamed = RespValue::decode(&mut bytes::BytesMut::from(&out[..])).unwrap().unwrap();
+        let (RespValue::Array(streamed), RespValue::Array(buffered)) =
+            (streamed, handler.handle_command(&read, &store).await)
+        else {
+            panic!("expected arrays")
+        };
+        assert_eq!(streamed[..2], buffered[..2]);
+    }
+
+    #[tokio::test]
+    async fn test_query_rate_limited_per_tenant() {
+        let handler = CommandHandler::new(None);
+        let quotas = crate::persistence::ResourceQuotas {
+            max_queries_per_sec: Some(3),
+            ..Default::default()
+        };
+        handler.tenant_manager().create_tenant("noisy".to_string(), "Noisy".to_string(), Some(quotas)).unwrap();
+        handler.tenant_manager().create_tenant("quiet".to_string(), "Quiet".to_string(), None).unwrap();
+        let store = Arc::new(RwLock::new(GraphStore::new()));
+        let command = |parts: &[&str]| {
+            RespValue::Array(parts.iter().map(|p| RespValue::BulkString(Some(p.as_bytes().to_vec()))).collect())
+        };
+
+        let read = command(&["GRAPH.QUERY", "noisy", "MATCH (n) RETURN count(n)"]);
+        let mut out = Vec::new();
+        assert!(handler.stream_read_query(&read, &store, false, &mut out).await.unwrap());
+        for q in ["CREATE (:Person)", "MATCH (n) RETURN n"] {
+            let reply = handler.handle_command(&command(&["GRAPH.QUERY", "noisy", q]), &store).await;
+            assert!(!matches!(reply, RespValue::Error(_)), "unexpected {:?}", reply);
+        }
+
+        let RespValue::Error(e) = handler.handle_command(&command(&["GRAPH.RO_QUERY", "noisy", "MATCH (n) RETURN n"]), &store).await
+        else {
+            panic!("fourth query within a second should be throttled")
+        };
+        assert!(e.contains("Rate limited"), "{}", e);
+        let mut out = Vec::new();
+        assert!(handler.stream_read_query(&read, &store, false, &mut out).await.unwrap());
+        assert!(String::from_utf8_lossy(&out).starts_with("-ERR Rate limited"));
+
+        for _ in 0..10 {
+            let reply = handler.handle_command(&command(&["GRAPH.QUERY", "quiet", "MATCH (n) RETURN n"]), &store).await;
+            assert!(!matches!(reply, RespValue::Error(_)), "unexpected {:?}", reply);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_write_queries_persist_every_change() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let persistence = Arc::new(PersistenceManager::new(temp_dir.path()).unwrap());
+        let handler = CommandHandler::new(Some(Arc::clone(&persistence)));
+        let store = Arc::new(RwLock::new(GraphStore::new()));
+        let query = |q: &str| {
+            RespValue::Array(["GRAPH.QUERY", "default", q].iter().map(|p| RespValue::BulkString(Some(p.as_bytes().to_vec()))).collect())
+        };
+
+        // None of these return the nodes they write
+        for q in [
+            "CREATE (:Person {name: 'Alice', age: 30, city: 'Pune'})",
+            "MATCH (a:Person {name: 'Alice'}) CREATE (a)-[:KNOWS]->(:Person {name: 'Bob'})",
+            "MATCH (n:Person {name: 'Alice'}) SET n.age = 31",
+            "MATCH (n:Person {name: 'Alice'}) REMOVE n.city",
+            "MATCH (n:Person {name: 'Bob'}) DETACH DELETE n",
+        ] {
+            let reply = handler.handle_command(&query(q), &store).await;
+            assert!(!matches!(reply, RespValue::Error(_)), "{}: {:?}", q, reply);
+        }
+
+        persistence.flush().unwrap();
+        let (nodes, edges) = persistence.recover("default").unwrap();
+        assert_eq!(nodes.len(), 1);
+        assert!(edges.is_empty());
+        assert_eq!(nodes[0].get_property("age"), Some(&crate::graph::PropertyValue::Integer(31)));
+        assert!(nodes[0].get_property("city").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_graph_constraint() {
+        let handler = CommandHandler::new(None);
+        let store = Arc::new(RwLock::new(GraphStore::new()));
+        let command = |parts: &[&str]| {
+            RespValue::Array(parts.iter().map(|p| RespValue::BulkString(Some(p.as_bytes().to_vec()))).collect())
+        };
+        let query = |q: &str| command(&["GRAPH.QUERY", "g", q]);
+
+        let create = command(&["GRAPH.CONSTRAINT", "CREATE", "g", "UNIQUE", "NODE", "Person", "PROPERTIES", "1", "email"]);
+        assert_eq!(handler.handle_command(&create, &store).await, RespValue::SimpleString("OK".to_string()));
+
+        handler.handle_command(&query("CREATE (p:Person {email: 'a@x.com'})"), &store).await;
+        match handler.handle_command(&query("CREATE (p:Person {email: 'a@x.com'})"), &store).await {
+            RespValue::Error(e) => assert!(e.contains("Constraint violation"), "{}", e),
+            other => panic!("expected constraint error, got {:?}", other),
+        }
+        assert_eq!(store.read().await.node_count(), 1);
+
+        let drop = command(&["GRAPH.CONSTRAINT", "DROP", "g", "UNIQUE", "NODE", "Person", "PROPERTIES", "1", "email"]);
+        assert_eq!(handler.handle_command(&drop, &store).await, RespValue::SimpleString("OK".to_string()));
+        assert!(matches!(handler.handle_command(&drop, &store).await, RespValue::Error(_)));
+        assert!(matches!(
+            handler.handle_command(&query("CREATE (p:Person {email: 'a@x.com'})"), &store).await,
+            RespValue::Array(_)
+        ));
+
+        // MANDATORY rejects existing nodes without the property
+        let mandatory = command(&["GRAPH.CONSTRAINT", "CREATE", "g", "MANDATORY", "NODE", "Person", "PROPERTIES", "1", "name"]);
+        assert!(matches!(handler.handle_command(&mandatory, &store).await, RespValue::Error(_)));
+
+        for bad in [
+            command(&["GRAPH.CONSTRAINT", "CREATE", "g", "UNIQUE", "RELATIONSHIP", "KNOWS", "PROPERTIES", "1", "since"]),
+            command(&["GRAPH.CONSTRAINT", "CREATE", "g", "UNIQUE", "NODE", "Person", "PROPERTIES", "2", "a", "b"]),
+            command(&["GRAPH.CONSTRAINT", "CREATE", "g", "EXISTS", "NODE", "Person", "PROPERTIES", "1", "email"]),
+            command(&["GRAPH.CONSTRAINT", "CREATE", "g"]),
+        ] {
+            assert!(matches!(handler.handle_command(&bad, &store).await, RespValue::Error(_)));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_graph_copy() {
+        let handler = CommandHandler::new(None);
+        let store = Arc::new(RwLock::new(GraphStore::new()));
+        let command = |parts: &[&str]| {
+            RespValue::Array(parts.iter().map(|p| RespValue::BulkString(Some(p.as_bytes().to_vec()))).collect())
+        };
+        let ok = RespValue::SimpleString("OK".to_string());
+        let counts = |graph: &str| {
+            let cmd = command(&["GRAPH.STATS", graph]);
+            let (handler, store) = (&handler, &store);
+            async move {
+                let RespValue::Array(fields) = handler.handle_command(&cmd, store).await else {
+                    panic!("expected array")
+                };
+                (fields[1].clone(), fields[3].clone(), fields[9].clone())
+            }
+        };
+
+        handler.handle_command(&command(&["GRAPH.QUERY", "g", "CREATE INDEX ON :Person(name)"]), &store).await;
+        handler
+            .handle_command(&command(&["GRAPH.QUERY", "g", "CREATE (a:Person {name: 'Ann'})-[:KNOWS]->(b:Person {name: 'Bob'})"]), &store)
+            .await;
+        let unique = command(&["GRAPH.CONSTRAINT", "CREATE", "g", "UNIQUE", "NODE", "Person", "PROPERTIES", "1", "name"]);
+        assert_eq!(handler.handle_command(&unique, &store).await, ok);
+
+        let copied = RespValue::SimpleString("OK (read-only in-memory copy, not persisted)".to_string());
+        assert_eq!(handler.handle_command(&command(&["GRAPH.COPY", "g", "g2"]), &store).await, copied);
+        let src_counts = counts("g").await;
+        assert_eq!(src_counts.0, RespValue::Integer(2));
+        assert_eq!(counts("g2").await, src_counts);
+        let RespValue::Array(graphs) = handler.handle_command(&command(&["GRAPH.LIST"]), &store).await else {
+            panic!("expected array")
+        };
+        assert!(graphs.contains(&RespValue::BulkString(Some(b"g2".to_vec()))));
+
+        // The copy takes no writes or constraint changes, so nothing bypasses the WAL
+        for write in [
+            command(&["GRAPH.QUERY", "g2", "CREATE (:Person {name: 'Cy'})"]),
+            command(&["GRAPH.CONSTRAINT", "DROP", "g2", "UNIQUE", "NODE", "Person", "PROPERTIES", "1", "name"]),
+        ] {
+            let RespValue::Error(e) = handler.handle_command(&write, &store).await else {
+                panic!("write to a copy should fail")
+            };
+            assert!(e.contains("read-only"), "{}", e);
+        }
+        assert_eq!(counts("g2").await, src_counts);
+        assert_eq!(counts("g").await, src_counts);
+
+        for bad in [
+            command(&["GRAPH.COPY", "g", "g2"]),
+            command(&["GRAPH.COPY", "g", "g"]),
+            command(&["GRAPH.COPY", "g"]),
+        ] {
+            assert!(matches!(handler.handle_command(&bad, &store).await, RespValue::Error(_)));
+        }
+
+        // A pre-registered destination's quotas bound the copy
+        let quotas = crate::persistence::ResourceQuotas { max_nodes: Some(1), ..Default::default() };
+        handler.tenant_manager().create_tenant("small".to_string(), "Small".to_string(), Some(quotas)).unwrap();
+        let RespValue::Error(e) = handler.handle_command(&command(&["GRAPH.COPY", "g", "small"]), &store).await else {
+            panic!("copy over quota should fail")
+        };
+        assert!(e.contains("Quota exceeded"), "{}", e);
+
+        assert_eq!(handler.handle_command(&command(&["GRAPH.DELETE", "g2"]), &store).await, ok);
+        assert!(handler.tenant_manager().get_tenant("g2").is_err());
+        assert_eq!(counts("g").await, src_counts);
+    }
+
+    #[tokio::test]
+    async fn test_session_transaction() {
+        let handler = CommandHandler::new(None);
+        let store = Arc::new(RwLock::new(GraphStore::new()));
+        let command = |parts: &[&str]| {
+            RespValue::Array(parts.iter().map(|p| RespValue::BulkString(Some(p.as_bytes().to_vec()))).collect())
+        };
+        let ok = RespValue::SimpleString("OK".to_string());
+        let queued = RespValue::SimpleString("QUEUED".to_string());
+        let mut session = Session::default();
+
+        // Queued writes are invisible until COMMIT, then land together
+        assert_eq!(handler.handle_session_command(&command(&["GRAPH.QUERY", "g", "BEGIN"]), &store, &mut session).await, ok);
+        assert!(session.in_transaction());
+        let create = command(&["GRAPH.QUERY", "g", "CREATE (:Person {name: 'Ann'})"]);
+        assert_eq!(handler.handle_session_command(&create, &store, &mut session).await, queued);
+        let read = command(&["GRAPH.RO_QUERY", "g", "MATCH (n:Person) RETURN n.name"]);
+        assert_eq!(handler.handle_session_command(&read, &store, &mut session).await, queued);
+        assert_eq!(store.read().await.node_count(), 0);
+        let other = command(&["GRAPH.QUERY", "h", "CREATE (:Person)"]);
+        assert!(matches!(handler.handle_session_command(&other, &store, &mut session).await, RespValue::Error(_)));
+        let ro_write = command(&["GRAPH.RO_QUERY", "g", "CREATE (:Person)"]);
+        assert!(matches!(handler.handle_session_command(&ro_write, &store, &mut session).await, RespValue::Error(_)));
+
+        let commit = command(&["GRAPH.QUERY", "g", "commit;"]);
+        let RespValue::Array(replies) = handler.handle_session_command(&commit, &store, &mut session).await else {
+            panic!("expected one reply per statement")
+        };
+        assert_eq!(replies.len(), 2);
+        // The read ran after the write, inside the same batch
+        let RespValue::Array(rows) = &replies[1] else { panic!("expected result set") };
+        assert_eq!(rows[1], RespValue::Array(vec![RespValue::Array(vec![RespValue::BulkString(Some(b"Ann".to_vec()))])]));
+        assert!(!session.in_transaction());
+        assert_eq!(store.read().await.node_count(), 1);
+        assert!(!store.read().await.in_write_batch());
+
+        // A failing statement rolls back the ones before it
+        handler.handle_session_command(&command(&["GRAPH.QUERY", "g", "BEGIN"]), &store, &mut session).await;
+        handler.handle_session_command(&create, &store, &mut session).await;
+        handler.handle_session_command(&command(&["GRAPH.QUERY", "g", "MATCH (n) RETURN n.x +"]), &store, &mut session).await;
+        let RespValue::Error(e) = handler.handle_session_command(&commit, &store, &mut session).await else {
+            panic!("commit should fail")
+        };
+        assert!(e.contains("statement 2 failed"), "{}", e);
+        assert_eq!(store.read().await.node_count(), 1);
+        assert!(!store.read().await.in_write_batch());
+
+        // ROLLBACK drops the queue; COMMIT and ROLLBACK need an open transaction
+        handler.handle_session_command(&command(&["GRAPH.QUERY", "g", "BEGIN"]), &store, &mut session).await;
+        handler.handle_session_command(&create, &store, &mut session).await;
+        assert_eq!(handler.handle_session_command(&command(&["GRAPH.QUERY", "g", "ROLLBACK"]), &store, &mut session).await, ok);
+        assert_eq!(store.read().await.node_count(), 1);
+        assert!(matches!(handler.handle_session_command(&commit, &store, &mut session).await, RespValue::Error(_)));
+
+        // Without a session there is nothing to queue into
+        assert!(matches!(handler.handle_command(&command(&["GRAPH.QUERY", "g", "BEGIN"]), &store).await, RespValue::Error(_)));
+    }
+
+    #[test]
+    fn test_parse_query_options() {
+        let args = |parts: &[&str]| -> Vec<RespValue> {
+            parts.iter().map(|p| RespValue::BulkString(Some(p.as_bytes().to_vec()))).collect()
+        };
+        assert_eq!(CommandHandler::parse_query_options(&[]).unwrap(), QueryOptions::default());
+        assert_eq!(
+            CommandHandler::parse_query_options(&args(&["--COMPACT", "timeout", "25"])).unwrap(),
+            QueryOptions { timeout: Some(std::time::Duration::from_millis(25)), compact: true }
+        );
+        assert!(CommandHandler::parse_query_options(&args(&["--compact", "TIMEOUT"])).is_err());
     }
 
     // ========== Coverage expansion tests ==========
@@ -683,262 +1671,6 @@ mod tests {
         );
     }
 
-    #[tokio::test]
-    async fn test_format_value_node() {
-        use crate::graph::{Node, NodeId};
-
-        let handler = CommandHandler::new(None);
-        let node = Node::new(NodeId::new(1), "Person");
-        let value = Value::Node(NodeId::new(1), node);
-        let result = handler.format_value(&value);
-        match result {
-            RespValue::BulkString(Some(bytes)) => {
-                let s = String::from_utf8(bytes).unwrap();
-                assert!(s.contains("Node("));
-                assert!(s.contains("NodeId(1)"));
-            }
-            _ => panic!("Expected BulkString for Node"),
-        }
-    }
-
-    #[tokio::test]
-    async fn test_format_value_node_ref() {
-        use crate::graph::NodeId;
-
-        let handler = CommandHandler::new(None);
-        let value = Value::NodeRef(NodeId::new(42));
-        let result = handler.format_value(&value);
-        match result {
-            RespValue::BulkString(Some(bytes)) => {
-                let s = String::from_utf8(bytes).unwrap();
-                assert!(s.contains("Node("));
-                assert!(s.contains("NodeId(42)"));
-            }
-            _ => panic!("Expected BulkString for NodeRef"),
-        }
-    }
-
-    #[tokio::test]
-    async fn test_format_value_edge() {
-        use crate::graph::{Edge, EdgeId, NodeId};
-
-        let handler = CommandHandler::new(None);
-        let edge = Edge::new(EdgeId::new(10), NodeId::new(1), NodeId::new(2), "KNOWS");
-        let value = Value::Edge(EdgeId::new(10), edge);
-        let result = handler.format_value(&value);
-        match result {
-            RespValue::BulkString(Some(bytes)) => {
-                let s = String::from_utf8(bytes).unwrap();
-                assert!(s.contains("Edge("));
-                assert!(s.contains("NodeId(1)"));
-                assert!(s.contains("NodeId(2)"));
-            }
-            _ => panic!("Expected BulkString for Edge"),
-        }
-    }
-
-    #[tokio::test]
-    async fn test_format_value_edge_ref() {
-        use crate::graph::{EdgeId, EdgeType, NodeId};
-
-        let handler = CommandHandler::new(None);
-        let value = Value::EdgeRef(
-            EdgeId::new(5),
-            NodeId::new(10),
-            NodeId::new(20),
-            EdgeType::new("FOLLOWS"),
-        );
-        let result = handler.format_value(&value);
-        match result {
-            RespValue::BulkString(Some(bytes)) => {
-                let s = String::from_utf8(bytes).unwrap();
-                assert!(s.contains("Edge("));
-                assert!(s.contains("NodeId(10)"));
-                assert!(s.contains("NodeId(20)"));
-            }
-            _ => panic!("Expected BulkString for EdgeRef"),
-        }
-    }
-
-    #[tokio::test]
-    async fn test_format_value_property_integer() {
-        use crate::graph::PropertyValue;
-
-        let handler = CommandHandler::new(None);
-        let value = Value::Property(PropertyValue::Integer(42));
-        let result = handler.format_value(&value);
-        assert_eq!(result, RespValue::Integer(42));
-    }
-
-    #[tokio::test]
-    async fn test_format_value_property_float() {
-        use crate::graph::PropertyValue;
-
-        let handler = CommandHandler::new(None);
-        let value = Value::Property(PropertyValue::Float(3.14));
-        let result = handler.format_value(&value);
-        match result {
-            RespValue::BulkString(Some(bytes)) => {
-                let s = String::from_utf8(bytes).unwrap();
-                assert!(s.contains("3.14"));
-            }
-            _ => panic!("Expected BulkString for Float"),
-        }
-    }
-
-    #[tokio::test]
-    async fn test_format_value_property_boolean() {
-        use crate::graph::PropertyValue;
-
-        let handler = CommandHandler::new(None);
-        let value_true = Value::Property(PropertyValue::Boolean(true));
-        let result = handler.format_value(&value_true);
-        assert_eq!(
-            result,
-            RespValue::BulkString(Some(b"true".to_vec()))
-        );
-
-        let value_false = Value::Property(PropertyValue::Boolean(false));
-        let result = handler.format_value(&value_false);
-        assert_eq!(
-            result,
-            RespValue::BulkString(Some(b"false".to_vec()))
-        );
-    }
-
-    #[tokio::test]
-    async fn test_format_value_property_other() {
-        use crate::graph::PropertyValue;
-
-        let handler = CommandHandler::new(None);
-        // DateTime is one of the "other" property variants (not String/Integer/Float/Boolean)
-        let value = Value::Property(PropertyValue::DateTime(1709712000000));
-        let result = handler.format_value(&value);
-        match result {
-            RespValue::BulkString(Some(bytes)) => {
-                let s = String::from_utf8(bytes).unwrap();
-                assert!(s.contains("DateTime"));
-            }
-            _ => panic!("Expected BulkString for DateTime property"),
-        }
-    }
-
-    #[tokio::test]
-    async fn test_format_value_path() {
-        use crate::graph::{EdgeId, NodeId};
-
-        let handler = CommandHandler::new(None);
-        let value = Value::Path {
-            nodes: vec![NodeId::new(1), NodeId::new(2), NodeId::new(3)],
-            edges: vec![EdgeId::new(10), EdgeId::new(20)],
-        };
-        let result = handler.format_value(&value);
-        match result {
-            RespValue::BulkString(Some(bytes)) => {
-                let s = String::from_utf8(bytes).unwrap();
-                assert!(s.contains("Path("));
-                assert!(s.contains("nodes:"));
-                assert!(s.contains("edges:"));
-            }
-            _ => panic!("Expected BulkString for Path"),
-        }
-    }
-
-    #[tokio::test]
-    async fn test_format_value_null() {
-        let handler = CommandHandler::new(None);
-        let value = Value::Null;
-        let result = handler.format_value(&value);
-        assert_eq!(result, RespValue::Null);
-    }
-
-    #[tokio::test]
-    async fn test_format_query_result_multiple_columns_rows() {
-        use crate::graph::PropertyValue;
-        use crate::query::{Record, RecordBatch};
-
-        let handler = CommandHandler::new(None);
-
-        let mut batch = RecordBatch::new(vec!["name".to_string(), "age".to_string()]);
-
-        let mut r1 = Record::new();
-        r1.bind("name".to_string(), Value::Property(PropertyValue::String("Alice".to_string())));
-        r1.bind("age".to_string(), Value::Property(PropertyValue::Integer(30)));
-        batch.push(r1);
-
-        let mut r2 = Record::new();
-        r2.bind("name".to_string(), Value::Property(PropertyValue::String("Bob".to_string())));
-        r2.bind("age".to_string(), Value::Property(PropertyValue::Integer(25)));
-        batch.push(r2);
-
-        let result = handler.format_query_result(batch);
-        match result {
-            RespValue::Array(rows) => {
-                // First row is the header
-                assert_eq!(rows.len(), 3); // 1 header + 2 data rows
-                // Check header
-                match &rows[0] {
-                    RespValue::Array(header) => {
-                        assert_eq!(header.len(), 2);
-                        assert_eq!(header[0], RespValue::BulkString(Some(b"name".to_vec())));
-                        assert_eq!(header[1], RespValue::BulkString(Some(b"age".to_vec())));
-                    }
-                    _ => panic!("Expected Array for header row"),
-                }
-                // Check first data row
-                match &rows[1] {
-                    RespValue::Array(data) => {
-                        assert_eq!(data.len(), 2);
-                        assert_eq!(data[0], RespValue::BulkString(Some(b"Alice".to_vec())));
-                        assert_eq!(data[1], RespValue::Integer(30));
-                    }
-                    _ => panic!("Expected Array for first data row"),
-                }
-                // Check second data row
-                match &rows[2] {
-                    RespValue::Array(data) => {
-                        assert_eq!(data.len(), 2);
-                        assert_eq!(data[0], RespValue::BulkString(Some(b"Bob".to_vec())));
-                        assert_eq!(data[1], RespValue::Integer(25));
-                    }
-                    _ => panic!("Expected Array for second data row"),
-                }
-            }
-            _ => panic!("Expected Array for format_query_result"),
-        }
-    }
-
-    #[tokio::test]
-    async fn test_format_query_result_missing_column_value() {
-        use crate::graph::PropertyValue;
-        use crate::query::{Record, RecordBatch};
-
-        let handler = CommandHandler::new(None);
-
-        // Record only has "name" bound, but batch declares "name" + "age" columns
-        let mut batch = RecordBatch::new(vec!["name".to_string(), "age".to_string()]);
-        let mut r = Record::new();
-        r.bind("name".to_string(), Value::Property(PropertyValue::String("Alice".to_string())));
-        // "age" is NOT bound
-        batch.push(r);
-
-        let result = handler.format_query_result(batch);
-        match result {
-            RespValue::Array(rows) => {
-                assert_eq!(rows.len(), 2); // 1 header + 1 data row
-                match &rows[1] {
-                    RespValue::Array(data) => {
-                        assert_eq!(data.len(), 2);
-                        assert_eq!(data[0], RespValue::BulkString(Some(b"Alice".to_vec())));
-                        assert_eq!(data[1], RespValue::Null); // missing column -> Null
-                    }
-                    _ => panic!("Expected Array for data row"),
-                }
-            }
-            _ => panic!("Expected Array for format_query_result"),
-        }
-    }
-
     #[tokio::test]
     async fn test_non_array_command() {
         let handler = CommandHandler::new(None);
@@ -1054,14 +1786,4 @@ mod tests {
         // without panicking
         assert!(matches!(response, RespValue::Array(_) | RespValue::Error(_)));
     }
-
-    #[tokio::test]
-    async fn test_format_value_property_string() {
-        use crate::graph::PropertyValue;
-
-        let handler = CommandHandler::new(None);
-        let value = Value::Property(PropertyValue::String("hello".to_string()));
-        let result = handler.format_value(&value);
-        assert_eq!(result, RespValue::BulkString(Some(b"hello".to_vec())));
-    }
 }
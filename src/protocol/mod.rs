@@ -18,6 +18,9 @@
 //! - `*2\r\n...\r\n...\r\n` — Array (element count prefix, then elements)
 //! - `_\r\n` — Null (RESP3)
 //!
+//! A client that sends `HELLO 3` is answered with a RESP3 map (`%`) and may then receive
+//! streamed arrays (`*?\r\n` elements, then `.\r\n`) in large query replies.
+//!
 //! The length-prefixed bulk strings are important: they allow binary data (including
 //! `\r\n` within the payload) to be transmitted without escaping.
 //!
@@ -25,10 +28,16 @@
 //!
 //! Samyama extends the RESP command set with graph-specific commands:
 //! - `GRAPH.QUERY <graph> <cypher>` — execute a read-write Cypher query
-//! - `GRAPH.RO_QUERY <graph> <cypher>` — execute a read-only Cypher query (can be
-//!   routed to replicas in a cluster)
+//! - `GRAPH.RO_QUERY <graph> <cypher>` — execute a read-only Cypher query; writes
+//!   are rejected, so it can be routed to replicas in a cluster
 //! - `GRAPH.DELETE <graph>` — delete an entire graph
 //!
+//! `GRAPH.QUERY <graph> BEGIN` opens a transaction on the connection: later queries are
+//! answered `+QUEUED` and run atomically at `COMMIT` (or are dropped by `ROLLBACK`).
+//!
+//! Query replies follow the RedisGraph result-set layout (header, rows, statistics);
+//! see [`result_set`] for the encoding.
+//!
 //! ## Why the Redis protocol?
 //!
 //! This design was inspired by FalkorDB (formerly RedisGraph). By speaking Redis's
@@ -39,11 +48,12 @@
 pub mod resp;
 pub mod server;
 pub mod command;
+pub mod result_set;
 
 // Re-export main types
 pub use resp::{RespValue, RespError, RespResult};
 pub use server::{RespServer, ServerConfig};
-pub use command::CommandHandler;
+pub use command::{CommandHandler, Session};
 
 #[cfg(test)]
 mod tests {
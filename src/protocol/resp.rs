@@ -59,6 +59,16 @@ pub enum RespError {
 
 pub type RespResult<T> = Result<T, RespError>;
 
+/// Simple strings and errors end at the first CRLF, so line breaks inside them
+/// (e.g. a multi-line parse error) are sent as spaces
+fn single_line(s: &str) -> std::borrow::Cow<'_, str> {
+    if s.contains(['\r', '\n']) {
+        std::borrow::Cow::Owned(s.replace(['\r', '\n'], " "))
+    } else {
+        std::borrow::Cow::Borrowed(s)
+    }
+}
+
 /// RESP value types
 #[derive(Debug, Clone, PartialEq)]
 pub enum RespValue {
@@ -81,10 +91,10 @@ impl RespValue {
     pub fn encode(&self, buf: &mut Vec<u8>) -> io::Result<()> {
         match self {
             RespValue::SimpleString(s) => {
-                write!(buf, "+{}\r\n", s)?;
+                write!(buf, "+{}\r\n", single_line(s))?;
             }
             RespValue::Error(e) => {
-                write!(buf, "-{}\r\n", e)?;
+                write!(buf, "-{}\r\n", single_line(e))?;
             }
             RespValue::Integer(i) => {
                 write!(buf, ":{}\r\n", i)?;
@@ -375,6 +385,21 @@ mod tests {
         assert_eq!(buf, b"-ERR unknown command\r\n");
     }
 
+    #[test]
+    fn test_encode_multiline_error_stays_one_reply() {
+        let mut buf = Vec::new();
+        RespValue::Error("ERR Parse error:\n  |\r\n1 | BAD".to_string()).encode(&mut buf).unwrap();
+        RespValue::SimpleString("OK".to_string()).encode(&mut buf).unwrap();
+        assert_eq!(buf, b"-ERR Parse error:   |  1 | BAD\r\n+OK\r\n");
+
+        let mut bytes = BytesMut::from(&buf[..]);
+        assert_eq!(
+            RespValue::decode(&mut bytes).unwrap(),
+            Some(RespValue::Error("ERR Parse error:   |  1 | BAD".to_string()))
+        );
+        assert_eq!(RespValue::decode(&mut bytes).unwrap(), Some(RespValue::SimpleString("OK".to_string())));
+    }
+
     #[test]
     fn test_encode_integer() {
         let val = RespValue::Integer(1000);
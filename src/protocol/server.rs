@@ -5,7 +5,7 @@
 use crate::graph::GraphStore;
 use crate::persistence::PersistenceManager;
 use crate::protocol::resp::{RespValue, RespError};
-use crate::protocol::command::CommandHandler;
+use crate::protocol::command::{CommandHandler, Session};
 use crate::sharding::{Router, Proxy, RouteResult};
 use crate::raft::ClusterManager;
 use bytes::BytesMut;
@@ -120,7 +120,16 @@ impl RespServer {
         self.handler.tenant_manager()
     }
 
-    /// Enable sharding for this server
+    /// Route `GRAPH.*` commands through `router`, forwarding remote tenants
+    /// via `proxy` to the shard addresses registered on it
+    pub fn with_shard_routing(mut self, router: Arc<Router>, proxy: Arc<Proxy>) -> Self {
+        self.router = Some(router);
+        self.proxy = Some(proxy);
+        self
+    }
+
+    /// Enable sharding for this server, resolving shard addresses from the
+    /// cluster config
     pub fn with_sharding(
         mut self,
         router: Arc<Router>,
@@ -160,6 +169,64 @@ impl RespServer {
     }
 }
 
+/// Make sure the proxy knows the address of the shard a command routes to,
+/// looking it up in the cluster config when it hasn't been registered
+async fn register_cluster_address(router: &Router, proxy: &Proxy, cluster: &ClusterManager, command: &RespValue) {
+    let Some(key) = Proxy::routing_key(command) else { return };
+    if let Some(RouteResult::Remote(node_id)) = router.route(&key) {
+        if proxy.shard_address(node_id).is_none() {
+            let config = cluster.get_config().await;
+            if let Some(node_config) = config.nodes.iter().find(|n| n.id == node_id) {
+                proxy.register_shard(node_id, node_config.address.clone());
+            }
+        }
+    }
+}
+
+/// Answer `HELLO [protover]`, switching the connection's protocol version
+///
+/// Returns `None` for any other command. Only the protocol version is negotiated; further
+/// HELLO options (AUTH, SETNAME) are ignored. The reply is a map under RESP3 and a flat
+/// key/value array under RESP2, as in Redis.
+fn handle_hello(value: &RespValue, resp3: &mut bool) -> Option<Vec<u8>> {
+    let args = value.as_array().ok()?;
+    let cmd = args.first()?.as_string().ok()??;
+    if !cmd.eq_ignore_ascii_case("HELLO") {
+        return None;
+    }
+
+    let mut reply = Vec::new();
+    match args.get(1).map(|v| v.as_string().ok().flatten()) {
+        None => {}
+        Some(Some(v)) if v == "2" => *resp3 = false,
+        Some(Some(v)) if v == "3" => *resp3 = true,
+        Some(_) => {
+            let _ = RespValue::Error("NOPROTO unsupported protocol version".to_string()).encode(&mut reply);
+            return Some(reply);
+        }
+    }
+
+    let fields = [
+        ("server", RespValue::BulkString(Some(b"samyama".to_vec()))),
+        ("version", RespValue::BulkString(Some(env!("CARGO_PKG_VERSION").as_bytes().to_vec()))),
+        ("proto", RespValue::Integer(if *resp3 { 3 } else { 2 })),
+    ];
+    let flat: Vec<RespValue> = fields
+        .into_iter()
+        .flat_map(|(key, value)| [RespValue::BulkString(Some(key.as_bytes().to_vec())), value])
+        .collect();
+    if *resp3 {
+        // RESP3 map: `%<pairs>` followed by alternating keys and values
+        reply.extend_from_slice(format!("%{}\r\n", flat.len() / 2).as_bytes());
+        for item in &flat {
+            let _ = item.encode(&mut reply);
+        }
+    } else {
+        let _ = RespValue::Array(flat).encode(&mut reply);
+    }
+    Some(reply)
+}
+
 /// Handle a single client connection
 async fn handle_connection(
     mut socket: TcpStream,
@@ -170,6 +237,10 @@ async fn handle_connection(
     cluster: Option<Arc<ClusterManager>>,
 ) -> Result<(), Box<dyn std::error::Error>> {
     let mut buffer = BytesMut::with_capacity(4096);
+    // Negotiated with HELLO; enables streamed result sets
+    let mut resp3 = false;
+    // Statements queued by BEGIN on this connection
+    let mut session = Session::default();
 
     loop {
         // Read data from socket
@@ -188,49 +259,29 @@ async fn handle_connection(
                     let mut forwarded = false;
 
                     // Attempt routing if configured
-                    if let (Some(router), Some(proxy), Some(cluster)) = (&router, &proxy, &cluster) {
-                        if let Ok(args) = value.as_array() {
-                            if args.len() >= 2 {
-                                if let Ok(Some(cmd)) = args[0].as_string() {
-                                    if cmd.to_uppercase().starts_with("GRAPH.") {
-                                        if let Ok(Some(key)) = args[1].as_string() {
-                                            if let Some(RouteResult::Remote(node_id)) = router.route(&key) {
-                                                // Resolve address from ClusterConfig
-                                                let config = cluster.get_config().await;
-                                                if let Some(node_config) = config.nodes.iter().find(|n| n.id == node_id) {
-                                                    debug!("Routing command for tenant '{}' to node {} ({})", key, node_id, node_config.address);
-                                                    
-                                                    // Re-encode command
-                                                    let mut cmd_bytes = Vec::new();
-                                                    value.encode(&mut cmd_bytes)?;
-
-                                                    // Forward
-                                                    match proxy.forward(&node_config.address, &cmd_bytes).await {
-                                                        Ok(response_bytes) => {
-                                                            socket.write_all(&response_bytes).await?;
-                                                            forwarded = true;
-                                                        }
-                                                        Err(e) => {
-                                                            error!("Failed to forward request: {}", e);
-                                                            let err = RespValue::Error(format!("ERR routing failed: {}", e));
-                                                            let mut buf = Vec::new();
-                                                            err.encode(&mut buf)?;
-                                                            socket.write_all(&buf).await?;
-                                                            forwarded = true; // Handled as error
-                                                        }
-                                                    }
-                                                }
-                                            }
-                                        }
-                                    }
-                                }
-                            }
+                    if let (Some(router), Some(proxy)) = (&router, &proxy) {
+                        if let Some(cluster) = &cluster {
+                            register_cluster_address(router, proxy, cluster, &value).await;
+                        }
+                        if let Some(reply) = proxy.dispatch(router, &value).await {
+                            socket.write_all(&reply).await?;
+                            forwarded = true;
                         }
                     }
 
                     if !forwarded {
+                        if let Some(reply) = handle_hello(&value, &mut resp3) {
+                            socket.write_all(&reply).await?;
+                            continue;
+                        }
+                        if !session.in_transaction()
+                            && handler.stream_read_query(&value, &store, resp3, &mut socket).await?
+                        {
+                            continue;
+                        }
+
                         // Process command locally
-                        let response = handler.handle_command(&value, &store).await;
+                        let response = handler.handle_session_command(&value, &store, &mut session).await;
 
                         // Encode and send response
                         let mut response_buf = Vec::new();
@@ -293,6 +344,32 @@ mod tests {
         drop(handler);
     }
 
+    #[test]
+    fn test_hello_negotiates_protocol() {
+        let command = |args: &[&str]| {
+            RespValue::Array(args.iter().map(|a| RespValue::BulkString(Some(a.as_bytes().to_vec()))).collect())
+        };
+        let mut resp3 = false;
+
+        assert!(handle_hello(&command(&["PING"]), &mut resp3).is_none());
+
+        let reply = handle_hello(&command(&["HELLO"]), &mut resp3).unwrap();
+        assert!(reply.starts_with(b"*6\r\n"));
+        assert!(!resp3);
+
+        let reply = handle_hello(&command(&["hello", "3"]), &mut resp3).unwrap();
+        assert!(reply.starts_with(b"%3\r\n"));
+        assert!(reply.ends_with(b"$5\r\nproto\r\n:3\r\n"));
+        assert!(resp3);
+
+        let reply = handle_hello(&command(&["HELLO", "4"]), &mut resp3).unwrap();
+        assert!(reply.starts_with(b"-NOPROTO"));
+        assert!(resp3);
+
+        handle_hello(&command(&["HELLO", "2"]), &mut resp3).unwrap();
+        assert!(!resp3);
+    }
+
     #[test]
     fn test_server_config_custom() {
         let config = ServerConfig {
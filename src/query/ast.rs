@@ -42,6 +42,7 @@
 //! also breaks a recursive type cycle between `Expression` and `WhereClause`).
 
 use crate::graph::{EdgeType, Label, PropertyValue};
+use crate::index::ConstraintKind;
 use std::collections::HashMap;
 
 /// The root AST node representing a complete Cypher query.
@@ -81,6 +82,8 @@ pub struct Query {
     pub limit: Option<usize>,
     /// SKIP clause (optional)
     pub skip: Option<usize>,
+    /// SAMPLE clause (optional): reservoir sample of at most k result rows
+    pub sample: Option<usize>,
     /// CALL clause (optional)
     pub call_clause: Option<CallClause>,
     /// CALL subquery (optional)
@@ -153,14 +156,17 @@ pub struct CreateIndexClause {
 pub struct DropIndexClause {
     pub label: Label,
     pub property: String,
+    /// Further properties when dropping a composite index
+    pub additional_properties: Vec<String>,
 }
 
-/// Unique constraint clause
+/// Constraint clause: `ASSERT n.prop IS UNIQUE` or `IS NOT NULL`
 #[derive(Debug, Clone, PartialEq)]
 pub struct CreateConstraintClause {
     pub variable: String,
     pub label: Label,
     pub property: String,
+    pub kind: ConstraintKind,
 }
 
 /// CALL clause: CALL db.index.vector.queryNodes('Person', 'embedding', [...], 10) YIELD node, score
@@ -600,6 +606,7 @@ impl Query {
             order_by: None,
             limit: None,
             skip: None,
+            sample: None,
             call_clause: None,
             call_subquery: None,
             delete_clause: None,
@@ -626,8 +633,22 @@ impl Query {
     }
 
     /// Check if this is a read-only query
+    ///
+    /// False if any clause writes data or changes the schema, including
+    /// inside CALL subqueries and UNION branches.
     pub fn is_read_only(&self) -> bool {
         self.create_clause.is_none()
+            && self.delete_clause.is_none()
+            && self.set_clauses.is_empty()
+            && self.remove_clauses.is_empty()
+            && self.merge_clause.is_none()
+            && self.foreach_clause.is_none()
+            && self.create_vector_index_clause.is_none()
+            && self.create_index_clause.is_none()
+            && self.drop_index_clause.is_none()
+            && self.create_constraint_clause.is_none()
+            && self.call_subquery.as_ref().is_none_or(|q| q.is_read_only())
+            && self.union_queries.iter().all(|(q, _)| q.is_read_only())
     }
 }
 
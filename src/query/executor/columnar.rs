@@ -0,0 +1,289 @@
+//! # Arrow Export for Query Results
+//!
+//! [`RecordBatch::to_arrow`] converts a row-oriented query result into an Apache Arrow
+//! record batch, so analytics clients (pyarrow, Polars, DuckDB) can load results without
+//! parsing JSON. Available with the `arrow` feature.
+//!
+//! ## Column typing
+//!
+//! Each result column becomes one nullable Arrow field. Its type is derived from the
+//! non-null values in the column, so the same query over the same data always produces
+//! the same schema:
+//!
+//! | Column values                  | Arrow type                     |
+//! |--------------------------------|--------------------------------|
+//! | integers                       | `Int64`                        |
+//! | floats, or integers and floats | `Float64` (integers widened)   |
+//! | booleans                       | `Boolean`                      |
+//! | strings                        | `Utf8`                         |
+//! | datetimes                      | `Timestamp(Millisecond)`       |
+//! | vectors                        | `List<Float32>`                |
+//! | nulls only (or no rows)        | `Null`                         |
+//! | anything else, or mixed kinds  | `Utf8`                         |
+//!
+//! In a `Utf8` fallback column, strings are kept as-is and every other value is written
+//! as JSON text: nodes as `{"id", "labels", "properties"}`, edges as
+//! `{"id", "type", "source", "target", "properties"}` and paths as `{"nodes", "edges"}`.
+
+use super::{RecordBatch, Value};
+use crate::graph::PropertyValue;
+use ::arrow::array::{
+    ArrayRef, BooleanBuilder, Float32Builder, Float64Builder, Int64Builder, ListBuilder, NullArray,
+    StringBuilder, TimestampMillisecondBuilder,
+};
+use ::arrow::datatypes::{Field, Schema};
+use ::arrow::error::ArrowError;
+use ::arrow::ipc::writer::StreamWriter;
+use ::arrow::record_batch::{RecordBatch as ArrowRecordBatch, RecordBatchOptions};
+use serde_json::json;
+use std::sync::Arc;
+
+/// Arrow type chosen for a result column
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ColumnKind {
+    Null,
+    Int,
+    Float,
+    Bool,
+    Str,
+    Timestamp,
+    Vector,
+    Text,
+}
+
+impl ColumnKind {
+    fn of(value: &Value) -> Self {
+        match value {
+            Value::Null | Value::Property(PropertyValue::Null) => ColumnKind::Null,
+            Value::Property(PropertyValue::Integer(_)) => ColumnKind::Int,
+            Value::Property(PropertyValue::Float(_)) => ColumnKind::Float,
+            Value::Property(PropertyValue::Boolean(_)) => ColumnKind::Bool,
+            Value::Property(PropertyValue::String(_)) => ColumnKind::Str,
+            Value::Property(PropertyValue::DateTime(_)) => ColumnKind::Timestamp,
+            Value::Property(PropertyValue::Vector(_)) => ColumnKind::Vector,
+            _ => ColumnKind::Text,
+        }
+    }
+
+    fn unify(self, other: Self) -> Self {
+        match (self, other) {
+            (a, b) if a == b => a,
+            (ColumnKind::Null, k) | (k, ColumnKind::Null) => k,
+            (ColumnKind::Int, ColumnKind::Float) | (ColumnKind::Float, ColumnKind::Int) => ColumnKind::Float,
+            _ => ColumnKind::Text,
+        }
+    }
+}
+
+impl RecordBatch {
+    /// Convert to an Arrow record batch, one field per column in `columns` order
+    ///
+    /// See the [module documentation](self) for how column types are chosen.
+    pub fn to_arrow(&self) -> Result<ArrowRecordBatch, ArrowError> {
+        let arrays: Vec<ArrayRef> = self.columns.iter().map(|col| self.column_array(col)).collect();
+        let fields: Vec<Field> = self
+            .columns
+            .iter()
+            .zip(&arrays)
+            .map(|(name, array)| Field::new(name, array.data_type().clone(), true))
+            .collect();
+        ArrowRecordBatch::try_new_with_options(
+            Arc::new(Schema::new(fields)),
+            arrays,
+            &RecordBatchOptions::new().with_row_count(Some(self.records.len())),
+        )
+    }
+
+    /// Encode as an Arrow IPC stream (`application/vnd.apache.arrow.stream`)
+    pub fn to_arrow_ipc(&self) -> Result<Vec<u8>, ArrowError> {
+        let batch = self.to_arrow()?;
+        let mut writer = StreamWriter::try_new(Vec::new(), &batch.schema())?;
+        writer.write(&batch)?;
+        writer.finish()?;
+        writer.into_inner()
+    }
+
+    fn column_array(&self, column: &str) -> ArrayRef {
+        let values: Vec<&Value> = self
+            .records
+            .iter()
+            .map(|record| record.get(column).unwrap_or(&Value::Null))
+            .collect();
+        let kind = values.iter().fold(ColumnKind::Null, |kind, v| kind.unify(ColumnKind::of(v)));
+
+        match kind {
+            ColumnKind::Null => Arc::new(NullArray::new(values.len())),
+            ColumnKind::Int => {
+                let mut builder = Int64Builder::with_capacity(values.len());
+                for v in values {
+                    builder.append_option(as_property(v).and_then(PropertyValue::as_i64));
+                }
+                Arc::new(builder.finish())
+            }
+            ColumnKind::Float => {
+                let mut builder = Float64Builder::with_capacity(values.len());
+                for v in values {
+                    builder.append_option(as_property(v).and_then(PropertyValue::as_f64));
+                }
+                Arc::new(builder.finish())
+            }
+            ColumnKind::Bool => {
+                let mut builder = BooleanBuilder::with_capacity(values.len());
+                for v in values {
+                    builder.append_option(as_property(v).and_then(PropertyValue::as_bool));
+                }
+                Arc::new(builder.finish())
+            }
+            ColumnKind::Timestamp => {
+                let mut builder = TimestampMillisecondBuilder::with_capacity(values.len());
+                for v in values {
+                    builder.append_option(match as_property(v) {
+                        Some(PropertyValue::DateTime(ms)) => Some(*ms),
+                        _ => None,
+                    });
+                }
+                Arc::new(builder.finish())
+            }
+            ColumnKind::Vector => {
+                let mut builder = ListBuilder::new(Float32Builder::new());
+                for v in values {
+                    match as_property(v) {
+                        Some(PropertyValue::Vector(vector)) => {
+                            builder.values().append_slice(vector);
+                            builder.append(true);
+                        }
+                        _ => builder.append_null(),
+                    }
+                }
+                Arc::new(builder.finish())
+            }
+            ColumnKind::Str | ColumnKind::Text => {
+                let mut builder = StringBuilder::with_capacity(values.len(), values.len() * 16);
+                for v in values {
+                    builder.append_option(text(v));
+                }
+                Arc::new(builder.finish())
+            }
+        }
+    }
+}
+
+fn as_property(value: &Value) -> Option<&PropertyValue> {
+    match value {
+        Value::Property(p) => Some(p),
+        _ => None,
+    }
+}
+
+/// Cell of a `Utf8` column: strings verbatim, other values as JSON text
+fn text(value: &Value) -> Option<String> {
+    let json = match value {
+        Value::Null | Value::Property(PropertyValue::Null) => return None,
+        Value::Property(PropertyValue::String(s)) => return Some(s.clone()),
+        Value::Property(p) => p.to_json(),
+        Value::Node(id, node) => json!({
+            "id": id.as_u64(),
+            "labels": node.labels.iter().map(|l| l.as_str()).collect::<Vec<_>>(),
+            "properties": node.properties.iter().map(|(k, v)| (k.clone(), v.to_json())).collect::<serde_json::Map<_, _>>(),
+        }),
+        Value::NodeRef(id) => json!({ "id": id.as_u64() }),
+        Value::Edge(id, edge) => json!({
+            "id": id.as_u64(),
+            "type": edge.edge_type.as_str(),
+            "source": edge.source.as_u64(),
+            "target": edge.target.as_u64(),
+            "properties": edge.properties.iter().map(|(k, v)| (k.clone(), v.to_json())).collect::<serde_json::Map<_, _>>(),
+        }),
+        Value::EdgeRef(id, src, tgt, edge_type) => json!({
+            "id": id.as_u64(),
+            "type": edge_type.as_str(),
+            "source": src.as_u64(),
+            "target": tgt.as_u64(),
+        }),
+        Value::Path { nodes, edges } => json!({
+            "nodes": nodes.iter().map(|n| n.as_u64()).collect::<Vec<_>>(),
+            "edges": edges.iter().map(|e| e.as_u64()).collect::<Vec<_>>(),
+        }),
+    };
+    Some(json.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::GraphStore;
+    use crate::query::QueryEngine;
+    use ::arrow::array::{Array, Float64Array, Int64Array, StringArray};
+    use ::arrow::datatypes::DataType;
+    use ::arrow::ipc::reader::StreamReader;
+
+    #[test]
+    fn test_mixed_result_schema() {
+        let mut store = GraphStore::new();
+        for (name, age, score) in [("Alice", 30i64, 1.5f64), ("Bob", 25, 2.0)] {
+            let id = store.create_node("Person");
+            store.set_node_property("default", id, "name", name).unwrap();
+            store.set_node_property("default", id, "age", age).unwrap();
+            store.set_node_property("default", id, "score", score).unwrap();
+        }
+        let carol = store.create_node("Person");
+        store.set_node_property("default", carol, "name", "Carol").unwrap();
+        store.set_node_property("default", carol, "score", 3i64).unwrap();
+
+        let batch = QueryEngine::new()
+            .execute(
+                "MATCH (n:Person) RETURN n.age AS age, n.name AS name, n.score AS score, n.missing AS missing, n ORDER BY n.name",
+                &store,
+            )
+            .unwrap();
+        let arrow = batch.to_arrow().unwrap();
+
+        let schema = arrow.schema();
+        let types: Vec<(&str, &DataType)> =
+            schema.fields().iter().map(|f| (f.name().as_str(), f.data_type())).collect();
+        assert_eq!(
+            types,
+            vec![
+                ("age", &DataType::Int64),
+                ("name", &DataType::Utf8),
+                ("score", &DataType::Float64),
+                ("missing", &DataType::Null),
+                ("n", &DataType::Utf8),
+            ]
+        );
+        assert_eq!(arrow.num_rows(), 3);
+
+        let ages = arrow.column(0).as_any().downcast_ref::<Int64Array>().unwrap();
+        assert_eq!((ages.value(0), ages.value(1), ages.is_null(2)), (30, 25, true));
+        let names = arrow.column(1).as_any().downcast_ref::<StringArray>().unwrap();
+        assert_eq!(names.value(2), "Carol");
+        let scores = arrow.column(2).as_any().downcast_ref::<Float64Array>().unwrap();
+        assert_eq!(scores.values().to_vec(), vec![1.5, 2.0, 3.0]);
+        let nodes = arrow.column(4).as_any().downcast_ref::<StringArray>().unwrap();
+        let alice: serde_json::Value = serde_json::from_str(nodes.value(0)).unwrap();
+        assert_eq!(alice["labels"], json!(["Person"]));
+        assert_eq!(alice["properties"]["name"], "Alice");
+
+        let ipc = batch.to_arrow_ipc().unwrap();
+        let decoded: Vec<_> = StreamReader::try_new(ipc.as_slice(), None).unwrap().collect::<Result<_, _>>().unwrap();
+        assert_eq!(decoded, vec![arrow]);
+    }
+
+    #[test]
+    fn test_empty_and_mixed_columns() {
+        let empty = RecordBatch::new(vec!["a".to_string()]);
+        let arrow = empty.to_arrow().unwrap();
+        assert_eq!(arrow.num_rows(), 0);
+        assert_eq!(arrow.schema().field(0).data_type(), &DataType::Null);
+
+        let mut batch = RecordBatch::new(vec!["mixed".to_string()]);
+        for value in [PropertyValue::Integer(1), PropertyValue::String("two".to_string())] {
+            let mut record = crate::query::Record::new();
+            record.bind("mixed".to_string(), Value::Property(value));
+            batch.push(record);
+        }
+        let arrow = batch.to_arrow().unwrap();
+        let mixed = arrow.column(0).as_any().downcast_ref::<StringArray>().unwrap();
+        assert_eq!((mixed.value(0), mixed.value(1)), ("1", "two"));
+    }
+}
@@ -25,7 +25,7 @@
 //!        │        │
 //!        │        └── checks WHERE predicate, passes or skips
 //!        │
-//!        └── extracts RETURN columns, materializes NodeRef → Node if needed
+//!        └── extracts RETURN columns (node/edge refs stay refs)
 //! ```
 //!
 //! ## Why Volcano?
@@ -44,7 +44,7 @@
 //! - **Scans**: `NodeScanOperator`, `IndexScanOperator` -- leaf operators that read from the graph
 //! - **Filters**: `FilterOperator` -- evaluates WHERE predicates
 //! - **Traversal**: `ExpandOperator` (fan-out along edges), `ExpandIntoOperator` (check edge existence between two bound nodes), `ShortestPathOperator`
-//! - **Projection**: `ProjectOperator` -- evaluates RETURN expressions, materializes lazy refs
+//! - **Projection**: `ProjectOperator` -- evaluates RETURN expressions
 //! - **Pagination**: `LimitOperator`, `SkipOperator`
 //! - **Grouping**: `AggregateOperator` (COUNT, SUM, AVG, etc.), `SortOperator`
 //! - **Joins**: `JoinOperator` (hash join), `LeftOuterJoinOperator` (for OPTIONAL MATCH), `CartesianProductOperator`
@@ -57,12 +57,15 @@
 //! its properties. Properties are resolved **on demand** via `Value::resolve_property()`.
 //! This is the key performance optimization for traversal-heavy queries: on a 3-hop path
 //! query, most intermediate nodes are never accessed for their properties, so cloning them
-//! would be pure waste.
+//! would be pure waste. On the read path the refs that reach the output are hydrated only as
+//! rows leave the executor ([`Record::materialize`]), after SKIP and LIMIT have run.
 //!
 //! ## Read vs Write Execution
 //!
 //! - **[`QueryExecutor`]**: read-only path. Takes `&GraphStore` (shared reference).
-//!   Operators call `next(store)` with an immutable borrow.
+//!   Operators call `next(store)` with an immutable borrow. [`QueryExecutor::cursor`]
+//!   hands the operator tree to the caller as a [`RecordCursor`] so huge results
+//!   can be consumed one record at a time instead of as one `RecordBatch`.
 //! - **[`MutQueryExecutor`]**: write path. Takes `&mut GraphStore` (exclusive reference).
 //!   Operators call `next_mut(store)` to create nodes, edges, or modify properties.
 //!
@@ -77,6 +80,8 @@
 //! heterogeneous collections of operators.
 
 pub mod adjacency_agg_detector;
+#[cfg(feature = "arrow")]
+pub mod columnar;
 pub mod cost_model;
 pub mod semi_join_detector;
 pub mod leapfrog;
@@ -124,12 +129,74 @@ pub enum ExecutionError {
 
 pub type ExecutionResult<T> = Result<T, ExecutionError>;
 
+/// Shared flag for aborting a running query from another thread
+///
+/// Clones share the same flag. Executors given a token check it between
+/// batches and inside long-running operator loops (variable-length expansion,
+/// shortest paths, joins, sorts, aggregation) and fail with
+/// `RuntimeError("Query cancelled")` once [`cancel`](Self::cancel) is called.
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken(std::sync::Arc<std::sync::atomic::AtomicBool>);
+
+impl CancellationToken {
+    /// Create a token that has not been cancelled
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Request cancellation of every query holding a clone of this token
+    pub fn cancel(&self) {
+        self.0.store(true, std::sync::atomic::Ordering::Relaxed);
+    }
+
+    /// Whether [`cancel`](Self::cancel) has been called
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(std::sync::atomic::Ordering::Relaxed)
+    }
+}
+
+/// Install `deadline` and `cancel` for the operators run by `f` on this thread
+fn with_query_guard<T>(
+    deadline: Option<std::time::Instant>,
+    cancel: &Option<CancellationToken>,
+    f: impl FnOnce() -> ExecutionResult<T>,
+) -> ExecutionResult<T> {
+    operator::set_query_deadline(deadline);
+    operator::set_query_cancellation(cancel.clone());
+    let result = f();
+    operator::set_query_deadline(None);
+    operator::set_query_cancellation(None);
+    result
+}
+
+/// Per-batch check made by the executors' pull loops
+fn check_batch(
+    deadline: Option<std::time::Instant>,
+    cancel: &Option<CancellationToken>,
+    rows: usize,
+) -> ExecutionResult<()> {
+    if cancel.as_ref().is_some_and(|c| c.is_cancelled()) {
+        return Err(ExecutionError::RuntimeError(
+            format!("Query cancelled after {} rows", rows)
+        ));
+    }
+    if let Some(deadline) = deadline {
+        if std::time::Instant::now() > deadline {
+            return Err(ExecutionError::RuntimeError(
+                format!("Query timed out after {} rows", rows)
+            ));
+        }
+    }
+    Ok(())
+}
+
 /// Query executor for read-only queries (MATCH, RETURN, etc.)
 pub struct QueryExecutor<'a> {
     store: &'a GraphStore,
     planner: QueryPlanner,
     params: HashMap<String, crate::graph::PropertyValue>,
     deadline: Option<std::time::Instant>,
+    cancel: Option<CancellationToken>,
 }
 
 impl<'a> QueryExecutor<'a> {
@@ -140,6 +207,7 @@ impl<'a> QueryExecutor<'a> {
             planner: QueryPlanner::new(),
             params: HashMap::new(),
             deadline: None,
+            cancel: None,
         }
     }
 
@@ -150,6 +218,7 @@ impl<'a> QueryExecutor<'a> {
             planner,
             params: HashMap::new(),
             deadline: None,
+            cancel: None,
         }
     }
 
@@ -159,25 +228,67 @@ impl<'a> QueryExecutor<'a> {
         self
     }
 
+    /// Abort execution once `token` is cancelled
+    pub fn with_cancellation(mut self, token: CancellationToken) -> Self {
+        self.cancel = Some(token);
+        self
+    }
+
     /// Set query parameters
     pub fn with_params(mut self, params: HashMap<String, crate::graph::PropertyValue>) -> Self {
         self.params = params;
         self
     }
 
-    /// Execute a read-only query and return results
-    pub fn execute(&self, query: &Query) -> ExecutionResult<RecordBatch> {
-        // Substitute parameters if any
-        let query = if !self.params.is_empty() || !query.params.is_empty() {
+    /// Clone `query` with its parameters substituted
+    fn bind_params(&self, query: &Query) -> ExecutionResult<Query> {
+        if !self.params.is_empty() || !query.params.is_empty() {
             let mut q = query.clone();
             let mut merged_params = query.params.clone();
             merged_params.extend(self.params.clone());
             substitute_params(&mut q, &merged_params)?;
-            q
+            Ok(q)
         } else {
-            query.clone()
-        };
-        let query = &query;
+            Ok(query.clone())
+        }
+    }
+
+    /// Plan a read-only query and return a cursor that produces its records lazily
+    ///
+    /// Unlike [`execute`](Self::execute), nothing is evaluated until the cursor is
+    /// advanced, and records are never collected into a `RecordBatch`, so memory
+    /// stays bounded by the plan's blocking operators (sort, aggregation) rather
+    /// than by the result size. EXPLAIN and PROFILE queries are evaluated eagerly
+    /// and replayed from the cursor.
+    pub fn cursor(&self, query: &Query) -> ExecutionResult<RecordCursor> {
+        let bound = self.bind_params(query)?;
+        if bound.explain || bound.profile {
+            let batch = self.execute(query)?;
+            return Ok(RecordCursor {
+                columns: batch.columns,
+                source: CursorSource::Buffered(batch.records.into_iter()),
+                deadline: None,
+                cancel: None,
+            });
+        }
+
+        let plan = self.planner.plan(&bound, self.store)?;
+        if plan.is_write {
+            return Err(ExecutionError::RuntimeError(
+                "Cannot execute write query with read-only executor. Use MutQueryExecutor instead.".to_string()
+            ));
+        }
+        Ok(RecordCursor {
+            columns: plan.output_columns.clone(),
+            source: CursorSource::Plan(plan.root),
+            deadline: self.deadline,
+            cancel: self.cancel.clone(),
+        })
+    }
+
+    /// Execute a read-only query and return results
+    pub fn execute(&self, query: &Query) -> ExecutionResult<RecordBatch> {
+        let query = &self.bind_params(query)?;
 
         // Plan the query
         let plan = self.planner.plan(query, self.store)?;
@@ -273,31 +384,23 @@ impl<'a> QueryExecutor<'a> {
     }
 
     fn execute_plan(&self, mut plan: ExecutionPlan) -> ExecutionResult<RecordBatch> {
-        // Set thread-local deadline so operators can check it during materialization
-        operator::set_query_deadline(self.deadline);
-
         let mut records = Vec::new();
         let batch_size = 1024;
 
-        // Pull records from the root operator in batches (Vectorized Execution)
-        let result = (|| {
-            while let Some(batch) = plan.root.next_batch(self.store, batch_size)? {
-                records.extend(batch.records);
-                // Cooperative timeout check every batch
-                if let Some(deadline) = self.deadline {
-                    if std::time::Instant::now() > deadline {
-                        return Err(ExecutionError::RuntimeError(
-                            format!("Query timed out after {} rows", records.len())
-                        ));
-                    }
+        // Pull records from the root operator in batches (Vectorized Execution),
+        // with the deadline and cancellation token visible to operators that
+        // materialize or expand internally
+        with_query_guard(self.deadline, &self.cancel, || {
+            while let Some(mut batch) = plan.root.next_batch(self.store, batch_size)? {
+                for record in &mut batch.records {
+                    record.materialize(self.store);
                 }
+                records.extend(batch.records);
+                // Cooperative timeout/cancellation check every batch
+                check_batch(self.deadline, &self.cancel, records.len())?;
             }
             Ok(())
-        })();
-
-        // Clear deadline after execution
-        operator::set_query_deadline(None);
-        result?;
+        })?;
 
         Ok(RecordBatch {
             records,
@@ -306,6 +409,58 @@ impl<'a> QueryExecutor<'a> {
     }
 }
 
+/// Lazily evaluated result of a read-only query, created by [`QueryExecutor::cursor`]
+///
+/// The cursor owns the operator tree but not the store: each call to
+/// [`next`](Self::next) pulls one record from the root operator against the store
+/// passed in, which must be the store the query was planned against and must not
+/// have been modified since. The query deadline and cancellation token, if
+/// any, are enforced while operators run inside `next`; time spent by the
+/// caller between calls is not interrupted.
+pub struct RecordCursor {
+    columns: Vec<String>,
+    source: CursorSource,
+    deadline: Option<std::time::Instant>,
+    cancel: Option<CancellationToken>,
+}
+
+enum CursorSource {
+    Plan(OperatorBox),
+    Buffered(std::vec::IntoIter<Record>),
+}
+
+impl RecordCursor {
+    /// Output column names, in RETURN order
+    pub fn columns(&self) -> &[String] {
+        &self.columns
+    }
+
+    /// Number of records still to come, when known without evaluating the query
+    ///
+    /// Only cursors replaying an eagerly evaluated result (EXPLAIN, PROFILE) know
+    /// their length up front; a cursor over a live plan returns `None`.
+    pub fn remaining_hint(&self) -> Option<usize> {
+        match &self.source {
+            CursorSource::Buffered(records) => Some(records.len()),
+            CursorSource::Plan(_) => None,
+        }
+    }
+
+    /// Pull the next record, or `None` once the query is exhausted
+    pub fn next(&mut self, store: &GraphStore) -> ExecutionResult<Option<Record>> {
+        match &mut self.source {
+            CursorSource::Buffered(records) => Ok(records.next()),
+            CursorSource::Plan(root) => {
+                let mut record = with_query_guard(self.deadline, &self.cancel, || root.next(store))?;
+                if let Some(record) = &mut record {
+                    record.materialize(store);
+                }
+                Ok(record)
+            }
+        }
+    }
+}
+
 /// Query executor for write queries (CREATE, DELETE, SET, etc.)
 /// Takes mutable reference to GraphStore to allow modifications
 pub struct MutQueryExecutor<'a> {
@@ -313,6 +468,8 @@ pub struct MutQueryExecutor<'a> {
     planner: QueryPlanner,
     tenant_id: String,
     params: HashMap<String, crate::graph::PropertyValue>,
+    deadline: Option<std::time::Instant>,
+    cancel: Option<CancellationToken>,
 }
 
 impl<'a> MutQueryExecutor<'a> {
@@ -323,6 +480,8 @@ impl<'a> MutQueryExecutor<'a> {
             planner: QueryPlanner::new(),
             tenant_id,
             params: HashMap::new(),
+            deadline: None,
+            cancel: None,
         }
     }
 
@@ -332,6 +491,20 @@ impl<'a> MutQueryExecutor<'a> {
         self
     }
 
+    /// Set a query execution deadline
+    ///
+    /// Writes already applied when the deadline passes are not rolled back.
+    pub fn with_deadline(mut self, deadline: std::time::Instant) -> Self {
+        self.deadline = Some(deadline);
+        self
+    }
+
+    /// Abort execution once `token` is cancelled
+    pub fn with_cancellation(mut self, token: CancellationToken) -> Self {
+        self.cancel = Some(token);
+        self
+    }
+
     /// Execute a query (read or write) and return results
     /// For CREATE queries, nodes/edges are created in the graph store
     pub fn execute(&mut self, query: &Query) -> ExecutionResult<RecordBatch> {
@@ -363,15 +536,56 @@ impl<'a> MutQueryExecutor<'a> {
         self.execute_plan_mut(plan)
     }
 
+    /// BEGIN: open a write batch on the store, so the following statements can
+    /// be committed or rolled back together
+    pub fn begin(&mut self) -> ExecutionResult<()> {
+        self.store.begin_write_batch().map_err(|e| ExecutionError::GraphError(e.to_string()))
+    }
+
+    /// COMMIT the write batch opened by [`begin`](Self::begin)
+    pub fn commit(&mut self) -> ExecutionResult<()> {
+        self.store.commit_write_batch()
+            .map(|_| ())
+            .map_err(|e| ExecutionError::GraphError(e.to_string()))
+    }
+
+    /// ROLLBACK: undo every write since [`begin`](Self::begin)
+    pub fn rollback(&mut self) -> ExecutionResult<()> {
+        self.store.rollback_write_batch().map_err(|e| ExecutionError::GraphError(e.to_string()))
+    }
+
+    /// Execute `queries` in order as one atomic write: if any of them fails,
+    /// the writes of all of them are rolled back and its error is returned.
+    pub fn execute_atomic(&mut self, queries: &[Query]) -> ExecutionResult<Vec<RecordBatch>> {
+        self.begin()?;
+        let mut results = Vec::with_capacity(queries.len());
+        for query in queries {
+            match self.execute(query) {
+                Ok(batch) => results.push(batch),
+                Err(e) => {
+                    self.rollback()?;
+                    return Err(e);
+                }
+            }
+        }
+        self.commit()?;
+        Ok(results)
+    }
+
     fn execute_plan_mut(&mut self, mut plan: ExecutionPlan) -> ExecutionResult<RecordBatch> {
         let mut records = Vec::new();
         let batch_size = 1024;
 
         // Pull records from the root operator in batches
         // Use next_batch_mut to allow operators to modify the graph store
-        while let Some(batch) = plan.root.next_batch_mut(self.store, &self.tenant_id, batch_size)? {
-            records.extend(batch.records);
-        }
+        let (deadline, cancel) = (self.deadline, self.cancel.clone());
+        with_query_guard(deadline, &cancel, || {
+            while let Some(batch) = plan.root.next_batch_mut(self.store, &self.tenant_id, batch_size)? {
+                records.extend(batch.records);
+                check_batch(deadline, &cancel, records.len())?;
+            }
+            Ok(())
+        })?;
 
         Ok(RecordBatch {
             records,
@@ -1741,6 +1955,33 @@ mod tests {
         assert!(plan_text.contains("Profile"), "Profile should contain Profile section: {}", plan_text);
     }
 
+    #[test]
+    fn test_read_results_materialize_returned_entities() {
+        let mut store = GraphStore::new();
+        for rank in 0..5i64 {
+            let id = store.create_node("Document");
+            store.set_node_property("default", id, "rank", rank).unwrap();
+            store.set_node_property("default", id, "title", format!("doc {}", rank)).unwrap();
+        }
+        let executor = QueryExecutor::new(&store);
+
+        let query = parse_query("MATCH (n:Document) RETURN n ORDER BY n.rank SKIP 1 LIMIT 2").unwrap();
+        let result = executor.execute(&query).unwrap();
+        let ranks: Vec<i64> = result.records.iter().map(|r| match r.get("n") {
+            Some(Value::Node(_, node)) => node.get_property_as::<i64>("rank").unwrap(),
+            other => panic!("expected a materialized node, got {:?}", other),
+        }).collect();
+        assert_eq!(ranks, vec![1, 2]);
+
+        let mut cursor = executor.cursor(&query).unwrap();
+        assert!(matches!(cursor.next(&store).unwrap().unwrap().get("n"), Some(Value::Node(..))));
+
+        let query = parse_query("MATCH (n:Document) RETURN n.title").unwrap();
+        let result = executor.execute(&query).unwrap();
+        assert_eq!(result.len(), 5);
+        assert!(result.records.iter().all(|r| matches!(r.get("n.title"), Some(Value::Property(_)))));
+    }
+
     #[test]
     fn test_write_query_in_read_executor() {
         let store = GraphStore::new();
@@ -1837,6 +2078,43 @@ mod tests {
         assert_eq!(persons[0].id, employees[0].id); // same node
     }
 
+    #[test]
+    fn test_match_multi_label_requires_all_labels() {
+        let mut store = GraphStore::new();
+        exec_mut(&mut store, "CREATE (n:Person:Employee {name: 'Bob'})");
+        exec_mut(&mut store, "CREATE (n:Person {name: 'Alice'})");
+        exec_mut(&mut store, "CREATE (n:Employee {name: 'Carol'})");
+        exec_mut(&mut store, "CREATE INDEX ON :Person(name)");
+
+        let names = |store: &GraphStore, q: &str| -> Vec<String> {
+            let mut names: Vec<String> = exec_read(store, q).records.iter()
+                .map(|r| r.get("name").unwrap().as_property().unwrap().as_string().unwrap().to_string())
+                .collect();
+            names.sort();
+            names
+        };
+        assert_eq!(names(&store, "MATCH (n:Person) RETURN n.name AS name"), vec!["Alice", "Bob"]);
+        assert_eq!(names(&store, "MATCH (n:Employee) RETURN n.name AS name"), vec!["Bob", "Carol"]);
+        assert_eq!(names(&store, "MATCH (n:Person:Employee) RETURN n.name AS name"), vec!["Bob"]);
+        assert_eq!(names(&store, "MATCH (n:Employee:Person) RETURN n.name AS name"), vec!["Bob"]);
+        assert!(names(&store, "MATCH (n:Person:Ghost) RETURN n.name AS name").is_empty());
+
+        // Index scan on Person still enforces Employee
+        assert_eq!(names(&store, "MATCH (n:Person:Employee {name: 'Bob'}) RETURN n.name AS name"), vec!["Bob"]);
+        assert!(names(&store, "MATCH (n:Person:Employee {name: 'Alice'}) RETURN n.name AS name").is_empty());
+
+        // Expand target with two labels
+        exec_mut(&mut store, "MATCH (a:Person {name: 'Alice'}), (b:Employee) CREATE (a)-[:KNOWS]->(b)");
+        assert_eq!(
+            names(&store, "MATCH (:Person {name: 'Alice'})-[:KNOWS]->(m:Employee:Person) RETURN m.name AS name"),
+            vec!["Bob"]
+        );
+
+        // MERGE assigns every label through the label index
+        exec_mut(&mut store, "MERGE (n:Person:Manager {name: 'Dave'})");
+        assert_eq!(names(&store, "MATCH (n:Manager:Person) RETURN n.name AS name"), vec!["Dave"]);
+    }
+
     #[test]
     fn test_create_edge_with_props() {
         let mut store = GraphStore::new();
@@ -1895,6 +2173,31 @@ mod tests {
         exec_mut(&mut store, "MATCH (n:Person) REMOVE n.age");
         let nodes = store.get_nodes_by_label(&Label::new("Person"));
         assert!(nodes[0].properties.get("age").is_none());
+        let batch = exec_read(&store, "MATCH (n:Person) RETURN n.age AS age");
+        assert_eq!(batch.records[0].get("age"), Some(&Value::Property(PropertyValue::Null)));
+    }
+
+    #[test]
+    fn test_set_property_to_null_keeps_key() {
+        let mut store = GraphStore::new();
+        exec_mut(&mut store, "CREATE (n:Person {name: 'Alice', age: 25})");
+        exec_mut(&mut store, "CREATE (n:Person {name: 'Bob'})");
+        exec_mut(&mut store, "MATCH (n:Person {name: 'Alice'}) SET n.age = null");
+
+        let alice = exec_read(&store, "MATCH (n:Person {name: 'Alice'}) RETURN n.age AS age, keys(n) AS k");
+        assert_eq!(alice.records[0].get("age"), Some(&Value::Property(PropertyValue::Null)));
+        match alice.records[0].get("k") {
+            Some(Value::Property(PropertyValue::Array(keys))) => {
+                assert!(keys.contains(&PropertyValue::String("age".to_string())));
+            }
+            other => panic!("unexpected keys: {:?}", other),
+        }
+
+        // Explicit null and absent both satisfy IS NULL.
+        let batch = exec_read(&store, "MATCH (n:Person) WHERE n.age IS NULL RETURN n.name AS name");
+        assert_eq!(batch.records.len(), 2);
+        let batch = exec_read(&store, "MATCH (n:Person) WHERE n.age IS NOT NULL RETURN n.name AS name");
+        assert!(batch.records.is_empty());
     }
 
     #[test]
@@ -1911,6 +2214,55 @@ mod tests {
         assert!(result.is_ok(), "REMOVE label should execute without error");
     }
 
+    #[test]
+    fn test_execute_atomic_rolls_back_on_failure() {
+        let mut store = GraphStore::new();
+        exec_mut(&mut store, "CREATE CONSTRAINT ON (a:Account) ASSERT a.id IS UNIQUE");
+        exec_mut(&mut store, "CREATE (a:Account {id: 1, balance: 100}), (b:Account {id: 2, balance: 50})");
+        exec_mut(&mut store, "MATCH (a:Account {id: 1}), (b:Account {id: 2}) CREATE (a)-[:TRANSFER {amount: 10}]->(b)");
+        let nodes_before = store.node_count();
+        let edges_before = store.edge_count();
+
+        // The debit applies, then the second statement violates the constraint
+        let queries = vec![
+            parse_query("MATCH (a:Account {id: 1}) SET a.balance = 70").unwrap(),
+            parse_query("CREATE (c:Account {id: 1, balance: 30})").unwrap(),
+        ];
+        let mut executor = MutQueryExecutor::new(&mut store, "default".to_string());
+        assert!(executor.execute_atomic(&queries).is_err());
+        assert!(!store.in_write_batch());
+        let batch = exec_read(&store, "MATCH (a:Account {id: 1}) RETURN a.balance AS balance");
+        assert_eq!(batch.records.len(), 1);
+        assert_eq!(batch.records[0].get("balance").unwrap().as_property(), Some(&PropertyValue::Integer(100)));
+
+        // Creates, deletes and edge updates are undone too
+        let queries = vec![
+            parse_query("CREATE (:Audit {amount: 30})").unwrap(),
+            parse_query("MATCH (:Account)-[r:TRANSFER]->() SET r.amount = 30").unwrap(),
+            parse_query("MATCH (:Account)-[r:TRANSFER]->() DELETE r").unwrap(),
+            parse_query("MATCH (b:Account {id: 2}) SET b.id = 1").unwrap(),
+        ];
+        let mut executor = MutQueryExecutor::new(&mut store, "default".to_string());
+        assert!(executor.execute_atomic(&queries).is_err());
+        assert_eq!(store.node_count(), nodes_before);
+        assert_eq!(store.edge_count(), edges_before);
+        assert!(store.get_nodes_by_label(&Label::new("Audit")).is_empty());
+        let batch = exec_read(&store, "MATCH (a:Account)-[r:TRANSFER]->(b:Account {id: 2}) RETURN r.amount AS amount, a.id AS id");
+        assert_eq!(batch.records.len(), 1);
+        assert_eq!(batch.records[0].get("amount").unwrap().as_property(), Some(&PropertyValue::Integer(10)));
+        assert_eq!(batch.records[0].get("id").unwrap().as_property(), Some(&PropertyValue::Integer(1)));
+
+        // A successful batch keeps all its writes
+        let queries = vec![
+            parse_query("MATCH (a:Account {id: 1}) SET a.balance = 70").unwrap(),
+            parse_query("MATCH (b:Account {id: 2}) SET b.balance = 80").unwrap(),
+        ];
+        let mut executor = MutQueryExecutor::new(&mut store, "default".to_string());
+        assert_eq!(executor.execute_atomic(&queries).unwrap().len(), 2);
+        let batch = exec_read(&store, "MATCH (a:Account) WHERE a.balance >= 70 RETURN a");
+        assert_eq!(batch.records.len(), 2);
+    }
+
     #[test]
     fn test_merge_on_create() {
         let mut store = GraphStore::new();
@@ -2431,6 +2783,60 @@ mod tests {
         assert_eq!(result.records.len(), 1);
     }
 
+    #[test]
+    fn test_return_string_concat_and_coalesce() {
+        let mut store = GraphStore::new();
+        exec_mut(&mut store, "CREATE (p:Person {first: 'Ada', last: 'Lovelace', age: 36, nickname: 'Countess'})");
+        exec_mut(&mut store, "CREATE (p:Person {first: 'Alan', last: 'Turing', age: 41})");
+
+        let result = exec_read(
+            &store,
+            "MATCH (p:Person) RETURN p.first + ' ' + p.last AS fullname, p.first + ' (' + p.age + ')' AS label, \
+             coalesce(p.nickname, p.first, 'anon') AS shown, p.nickname + '!' AS shout ORDER BY p.first",
+        );
+        let get = |row: usize, col: &str| result.records[row].get(col).cloned();
+        let s = |v: &str| Some(Value::Property(PropertyValue::String(v.to_string())));
+        assert_eq!(get(0, "fullname"), s("Ada Lovelace"));
+        assert_eq!(get(0, "label"), s("Ada (36)"));
+        assert_eq!(get(0, "shown"), s("Countess"));
+        assert_eq!(get(0, "shout"), s("Countess!"));
+        assert_eq!(get(1, "fullname"), s("Alan Turing"));
+        // No nickname: coalesce falls through, concatenation with null is null.
+        assert_eq!(get(1, "shown"), s("Alan"));
+        assert_eq!(get(1, "shout"), Some(Value::Property(PropertyValue::Null)));
+
+        let result = exec_read(&store, "MATCH (p:Person) WHERE p.first = 'Alan' RETURN coalesce(p.nickname, p.title, 'anon') AS shown");
+        assert_eq!(result.records[0].get("shown").cloned(), s("anon"));
+    }
+
+    #[test]
+    fn test_return_arithmetic_precedence_and_nulls() {
+        let mut store = GraphStore::new();
+        exec_mut(&mut store, "CREATE (s:Shipment {containers: 3, weight: 1.5})");
+
+        let result = exec_read(
+            &store,
+            "MATCH (s:Shipment) RETURN s.containers * 40 AS teu, s.containers + s.weight AS mixed, \
+             2 + s.containers * 4 - 1 AS prec, (2 + s.containers) * 4 AS grouped, \
+             s.containers % 2 AS rem, s.missing * 40 AS missing",
+        );
+        let get = |col: &str| result.records[0].get(col).cloned();
+        assert_eq!(get("teu"), Some(Value::Property(PropertyValue::Integer(120))));
+        assert_eq!(get("mixed"), Some(Value::Property(PropertyValue::Float(4.5))));
+        assert_eq!(get("prec"), Some(Value::Property(PropertyValue::Integer(13))));
+        assert_eq!(get("grouped"), Some(Value::Property(PropertyValue::Integer(20))));
+        assert_eq!(get("rem"), Some(Value::Property(PropertyValue::Integer(1))));
+        assert_eq!(get("missing"), Some(Value::Property(PropertyValue::Null)));
+
+        // Null arithmetic in WHERE filters the row out instead of erroring.
+        let result = exec_read(&store, "MATCH (s:Shipment) WHERE s.missing + 1 > 0 RETURN s");
+        assert!(result.records.is_empty());
+
+        // Integer division by zero is a query error.
+        let query = parse_query("MATCH (s:Shipment) RETURN s.containers / 0 AS teu").unwrap();
+        assert!(QueryExecutor::new(&store).execute(&query).is_err());
+    }
+
     #[test]
     fn test_create_edge_via_mutation() {
         let mut store = GraphStore::new();
@@ -3521,6 +3927,85 @@ mod tests {
         assert!(result.records.len() >= 1, "Should have at least 1 constraint");
     }
 
+    fn try_exec_mut(store: &mut GraphStore, cypher: &str) -> ExecutionResult<RecordBatch> {
+        let query = parse_query(cypher).unwrap();
+        MutQueryExecutor::new(store, "default".to_string()).execute(&query)
+    }
+
+    #[test]
+    fn test_unique_constraint_rejects_duplicates() {
+        let mut store = GraphStore::new();
+        exec_mut(&mut store, "CREATE CONSTRAINT ON (p:Person) ASSERT p.email IS UNIQUE");
+        exec_mut(&mut store, "CREATE (p:Person {email: 'a@x.com', name: 'Ann'})");
+
+        let err = try_exec_mut(&mut store, "CREATE (p:Person {email: 'a@x.com', name: 'Bob'})").unwrap_err();
+        assert!(err.to_string().contains(":Person(email) IS UNIQUE"), "{}", err);
+        // The rejected node is not left behind
+        assert_eq!(store.get_nodes_by_label(&Label::new("Person")).len(), 1);
+
+        // Duplicates within one CREATE are caught as well
+        assert!(try_exec_mut(&mut store, "CREATE (a:Person {email: 'c@x.com'}), (b:Person {email: 'c@x.com'})").is_err());
+        assert_eq!(
+            store.get_nodes_by_property(&Label::new("Person"), "email", &PropertyValue::String("c@x.com".into())).len(),
+            1
+        );
+
+        // MERGE finds the existing node instead of duplicating it
+        exec_mut(&mut store, "MERGE (p:Person {email: 'a@x.com'})");
+        exec_mut(&mut store, "CREATE (p:Person {email: 'b@x.com'})");
+        assert!(try_exec_mut(&mut store, "MATCH (p:Person {email: 'b@x.com'}) SET p.email = 'a@x.com'").is_err());
+
+        // Other labels and freed values are unaffected
+        exec_mut(&mut store, "CREATE (c:Company {email: 'a@x.com'})");
+        exec_mut(&mut store, "MATCH (p:Person {email: 'a@x.com'}) DELETE p");
+        exec_mut(&mut store, "CREATE (p:Person {email: 'a@x.com'})");
+    }
+
+    #[test]
+    fn test_unique_constraint_creation_validates_existing_data() {
+        let mut store = GraphStore::new();
+        exec_mut(&mut store, "CREATE (p:Person {email: 'a@x.com'})");
+        exec_mut(&mut store, "CREATE (p:Person {email: 'a@x.com'})");
+        let err = try_exec_mut(&mut store, "CREATE CONSTRAINT ON (p:Person) ASSERT p.email IS UNIQUE").unwrap_err();
+        assert!(err.to_string().contains("share value \"a@x.com\""), "{}", err);
+        assert!(store.property_index.list_constraints().is_empty());
+
+        exec_mut(&mut store, "MATCH (p:Person) DELETE p");
+        exec_mut(&mut store, "CREATE (p:Person {email: 'a@x.com'})");
+        exec_mut(&mut store, "CREATE CONSTRAINT ON (p:Person) ASSERT p.email IS UNIQUE");
+        // Backfilled: the existing value already counts
+        assert!(try_exec_mut(&mut store, "CREATE (p:Person {email: 'a@x.com'})").is_err());
+    }
+
+    #[test]
+    fn test_not_null_constraint() {
+        let mut store = GraphStore::new();
+        exec_mut(&mut store, "CREATE CONSTRAINT ON (p:Person) ASSERT p.name IS NOT NULL");
+        let result = exec_read(&store, "SHOW CONSTRAINTS");
+        assert_eq!(
+            result.records[0].get("type"),
+            Some(&Value::Property(PropertyValue::String("NOT NULL".to_string())))
+        );
+
+        let err = try_exec_mut(&mut store, "CREATE (p:Person {age: 3})").unwrap_err();
+        assert!(err.to_string().contains(":Person(name) IS NOT NULL"), "{}", err);
+        assert!(try_exec_mut(&mut store, "CREATE (p:Employee:Person {age: 3})").is_err());
+        assert_eq!(store.node_count(), 0);
+
+        exec_mut(&mut store, "CREATE (p:Person {name: 'Ann'})");
+        exec_mut(&mut store, "MERGE (p:Person {age: 40}) ON CREATE SET p.name = 'Bob'");
+        assert!(try_exec_mut(&mut store, "MERGE (p:Person {age: 50})").is_err());
+        assert_eq!(store.node_count(), 2);
+
+        assert!(try_exec_mut(&mut store, "MATCH (p:Person {name: 'Ann'}) REMOVE p.name").is_err());
+        assert!(try_exec_mut(&mut store, "MATCH (p:Person {name: 'Ann'}) SET p.name = null").is_err());
+        assert_eq!(store.get_nodes_by_label(&Label::new("Person")).iter().filter(|n| n.get_property("name").is_some()).count(), 2);
+
+        // Nodes created before the constraint must comply too
+        exec_mut(&mut store, "CREATE (c:City {population: 5})");
+        assert!(try_exec_mut(&mut store, "CREATE CONSTRAINT ON (c:City) ASSERT c.name IS NOT NULL").is_err());
+    }
+
     // --- EXPLAIN with various query shapes ---
     #[test]
     fn test_explain_match_traversal() {
@@ -3780,6 +4265,69 @@ mod tests {
         assert!(result.records.len() >= 1, "Should find the KNOWS edge");
     }
 
+    fn knows_graph_with_edge_props() -> GraphStore {
+        let mut store = GraphStore::new();
+        for name in ["Alice", "Bob", "Carol"] {
+            exec_mut(&mut store, &format!("CREATE (n:Person {{name: '{}'}})", name));
+        }
+        for (from, to, since, strength) in [("Alice", "Bob", 2020, 0.9), ("Alice", "Carol", 2018, 0.5), ("Bob", "Carol", 2020, 0.7)] {
+            exec_mut(&mut store, &format!(
+                "MATCH (a:Person {{name: '{}'}}), (b:Person {{name: '{}'}}) CREATE (a)-[:KNOWS {{since: {}, strength: {}}}]->(b)",
+                from, to, since, strength
+            ));
+        }
+        store
+    }
+
+    fn pairs(result: &RecordBatch) -> Vec<(String, String)> {
+        let name = |r: &Record, col: &str| r.get(col).unwrap().as_property().unwrap().as_string().unwrap().to_string();
+        let mut pairs: Vec<(String, String)> = result.records.iter().map(|r| (name(r, "a.name"), name(r, "b.name"))).collect();
+        pairs.sort();
+        pairs
+    }
+
+    #[test]
+    fn test_match_inline_edge_properties() {
+        let store = knows_graph_with_edge_props();
+        let p = |a: &str, b: &str| (a.to_string(), b.to_string());
+
+        let result = exec_read(&store, "MATCH (a:Person)-[r:KNOWS {since: 2020}]->(b) RETURN a.name, b.name");
+        assert_eq!(pairs(&result), vec![p("Alice", "Bob"), p("Bob", "Carol")]);
+
+        // No edge variable, and a map with two entries
+        let result = exec_read(&store, "MATCH (a)-[:KNOWS {since: 2020, strength: 0.9}]->(b) RETURN a.name, b.name");
+        assert_eq!(pairs(&result), vec![p("Alice", "Bob")]);
+
+        // Incoming direction and a value no edge has
+        let result = exec_read(&store, "MATCH (b:Person)<-[:KNOWS {since: 2018}]-(a) RETURN a.name, b.name");
+        assert_eq!(pairs(&result), vec![p("Alice", "Carol")]);
+        let result = exec_read(&store, "MATCH (a)-[:KNOWS {since: 1999}]->(b) RETURN a.name, b.name");
+        assert!(result.records.is_empty());
+
+        // Start bound by WITH
+        let result = exec_read(&store, "MATCH (a:Person {name: 'Alice'}) WITH a MATCH (a)-[:KNOWS {since: 2018}]->(b) RETURN a.name, b.name");
+        assert_eq!(pairs(&result), vec![p("Alice", "Carol")]);
+
+        // Variable length: every hop must match
+        let result = exec_read(&store, "MATCH (a:Person {name: 'Alice'})-[:KNOWS*1..2 {since: 2020}]->(b) RETURN a.name, b.name");
+        assert_eq!(pairs(&result), vec![p("Alice", "Bob"), p("Alice", "Carol")]);
+        let result = exec_read(&store, "MATCH (a:Person {name: 'Alice'})-[:KNOWS*2..2 {since: 2018}]->(b) RETURN a.name, b.name");
+        assert!(result.records.is_empty());
+    }
+
+    #[test]
+    fn test_match_edge_property_in_where() {
+        let store = knows_graph_with_edge_props();
+        let p = |a: &str, b: &str| (a.to_string(), b.to_string());
+
+        let result = exec_read(&store, "MATCH (a:Person)-[r:KNOWS]->(b:Person) WHERE r.strength > 0.8 RETURN a.name, b.name");
+        assert_eq!(pairs(&result), vec![p("Alice", "Bob")]);
+
+        let result = exec_read(&store, "MATCH (a:Person)-[r:KNOWS {since: 2020}]->(b) WHERE r.strength < 0.8 RETURN a.name, b.name, r.strength");
+        assert_eq!(pairs(&result), vec![p("Bob", "Carol")]);
+        assert_eq!(result.records[0].get("r.strength").unwrap().as_property().unwrap().as_float(), Some(0.7));
+    }
+
     // --- Multiple SET items ---
     #[test]
     fn test_multiple_set_items() {
@@ -3958,6 +4506,34 @@ mod tests {
         assert!(result.records.len() >= 1, "Should have composite index");
     }
 
+    #[test]
+    fn test_composite_index_for_pattern_create_and_drop() {
+        let mut store = GraphStore::new();
+        exec_mut(&mut store, "CREATE INDEX FOR (n:Doc) ON (n.department, n.date)");
+        for i in 0..40 {
+            let dept = if i % 4 == 0 { "legal" } else { "sales" };
+            exec_mut(&mut store, &format!("CREATE (:Doc {{department: '{}', date: {}}})", dept, i));
+        }
+        let composite = vec![(Label::new("Doc"), vec!["department".to_string(), "date".to_string()])];
+        assert_eq!(store.property_index.list_composite_indexes(), composite);
+
+        let query = "MATCH (n:Doc) WHERE n.department = 'legal' AND n.date >= 20 RETURN n.date";
+        let plan = get_explain_plan(&store, &format!("EXPLAIN {}", query));
+        assert!(plan.contains("CompositeIndexScan"), "{}", plan);
+        assert_eq!(exec_read(&store, query).records.len(), 5);
+
+        exec_mut(&mut store, "DROP INDEX FOR (n:Doc) ON (n.department, n.date)");
+        assert!(store.property_index.list_composite_indexes().is_empty());
+        let plan = get_explain_plan(&store, &format!("EXPLAIN {}", query));
+        assert!(!plan.contains("CompositeIndexScan"), "{}", plan);
+        assert_eq!(exec_read(&store, query).records.len(), 5);
+
+        // Gone now; the per-property indexes made with it remain
+        let again = parse_query("DROP INDEX ON :Doc(department, date)").unwrap();
+        assert!(MutQueryExecutor::new(&mut store, "default".to_string()).execute(&again).is_err());
+        exec_mut(&mut store, "DROP INDEX ON :Doc(date)");
+    }
+
     // --- WITH + WHERE filtering ---
     #[test]
     fn test_with_where_clause() {
@@ -4079,6 +4655,43 @@ mod tests {
         assert_eq!(val, &PropertyValue::String("Alice".to_string()));
     }
 
+    #[test]
+    fn test_knows_edge_all_directions() {
+        let mut store = GraphStore::new();
+        let a = store.create_node("Person");
+        store.set_node_property("default", a, "name", "Alice").unwrap();
+        let b = store.create_node("Person");
+        store.set_node_property("default", b, "name", "Bob").unwrap();
+        store.create_edge(a, b, "KNOWS").unwrap();
+
+        let pairs = |store: &GraphStore, query: &str| -> Vec<(String, String)> {
+            let mut rows: Vec<(String, String)> = exec_read(store, query)
+                .records
+                .iter()
+                .map(|r| {
+                    let name = |col: &str| r.get(col).unwrap().as_property().unwrap().as_string().unwrap().to_string();
+                    (name("x.name"), name("y.name"))
+                })
+                .collect();
+            rows.sort();
+            rows
+        };
+        let pair = |x: &str, y: &str| (x.to_string(), y.to_string());
+
+        assert_eq!(pairs(&store, "MATCH (x:Person)-[:KNOWS]->(y:Person) RETURN x.name, y.name"), vec![pair("Alice", "Bob")]);
+        assert_eq!(pairs(&store, "MATCH (x:Person)<-[:KNOWS]-(y:Person) RETURN x.name, y.name"), vec![pair("Bob", "Alice")]);
+        // Undirected: the edge binds once per orientation, never twice for the same one
+        assert_eq!(
+            pairs(&store, "MATCH (x:Person)-[:KNOWS]-(y:Person) RETURN x.name, y.name"),
+            vec![pair("Alice", "Bob"), pair("Bob", "Alice")]
+        );
+        assert_eq!(pairs(&store, "MATCH (x:Person {name: 'Alice'})-[:KNOWS]-(y) RETURN x.name, y.name"), vec![pair("Alice", "Bob")]);
+
+        // A self-loop matches an undirected pattern once
+        store.create_edge(b, b, "KNOWS").unwrap();
+        assert_eq!(pairs(&store, "MATCH (x:Person {name: 'Bob'})-[:KNOWS]-(y) RETURN x.name, y.name"), vec![pair("Bob", "Alice"), pair("Bob", "Bob")]);
+    }
+
     // --- Arithmetic in WHERE clause ---
     #[test]
     fn test_arithmetic_in_where() {
@@ -4386,6 +4999,30 @@ mod tests {
         assert_eq!(result.records.len(), 0, "LIMIT 0 should return no results");
     }
 
+    // --- SAMPLE draws k rows from the final result ---
+    #[test]
+    fn test_sample_returns_k_rows() {
+        let mut store = GraphStore::new();
+        for i in 0..50i64 {
+            let id = store.create_node("Person");
+            store.set_node_property("default", id, "age", i).unwrap();
+        }
+
+        let result = exec_read(&store, "MATCH (n:Person) RETURN n.age AS age SAMPLE 7");
+        assert_eq!(result.records.len(), 7);
+
+        // SAMPLE applies after LIMIT: only ages 0..10 can be drawn
+        let result = exec_read(&store, "MATCH (n:Person) RETURN n.age AS age ORDER BY n.age LIMIT 10 SAMPLE 4");
+        assert_eq!(result.records.len(), 4);
+        for record in &result.records {
+            let age = record.get("age").unwrap().as_property().unwrap().as_i64().unwrap();
+            assert!(age < 10, "sampled age {} outside LIMIT window", age);
+        }
+
+        let result = exec_read(&store, "MATCH (n:Person) RETURN n SAMPLE 1000");
+        assert_eq!(result.records.len(), 50);
+    }
+
     // --- SortOperator with integer values ---
     #[test]
     fn test_order_by_integer_asc() {
@@ -4663,6 +5300,36 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_algo_directed_false_config() {
+        let mut store = GraphStore::new();
+        let a = store.create_node("Doc");
+        let b = store.create_node("Doc");
+        let c = store.create_node("Doc");
+        store.create_edge(a, b, "REFERENCES").unwrap();
+        store.create_edge(c, b, "REFERENCES").unwrap();
+
+        // c is only reachable from a against the direction of c -> b
+        let path = |config: &str| {
+            let cypher = format!("CALL algo.shortestPath({}, {}{}) YIELD path, cost", a.as_u64(), c.as_u64(), config);
+            QueryExecutor::new(&store).execute(&parse_query(&cypher).unwrap()).unwrap().records.len()
+        };
+        assert_eq!(path(""), 0);
+        assert_eq!(path(", {directed: false}"), 1);
+
+        // Undirected PageRank: the sink b no longer collects all the rank
+        let scores = |config: &str| {
+            let cypher = format!("CALL algo.pageRank('Doc', 'REFERENCES'{}) YIELD node, score", config);
+            let result = QueryExecutor::new(&store).execute(&parse_query(&cypher).unwrap()).unwrap();
+            result.records.iter().map(|r| r.get("score").unwrap().as_property().unwrap().as_float().unwrap()).collect::<Vec<_>>()
+        };
+        let directed = scores("");
+        let undirected = scores(", {directed: false}");
+        assert_eq!(directed.len(), 3);
+        assert_eq!(undirected.len(), 3);
+        assert!(directed[0] - directed[2] > undirected[0] - undirected[2]);
+    }
+
     #[test]
     fn test_algo_triangle_count() {
         let store = build_triangle_graph();
@@ -6248,6 +6915,73 @@ mod tests {
         assert_eq!(result.records.len(), 2);
     }
 
+    #[test]
+    fn test_unnamed_endpoint_and_relationship_patterns() {
+        // The demo graph: Alice knows Bob, Carol knows nobody
+        let mut store = GraphStore::new();
+        exec_mut(&mut store, "CREATE (a:Person {name: 'Alice', age: 30}), (b:Person {name: 'Bob', age: 25}), (c:Person {name: 'Carol', age: 41})");
+        exec_mut(&mut store, "MATCH (a:Person {name: 'Alice'}), (b:Person {name: 'Bob'}) CREATE (a)-[:KNOWS]->(b)");
+
+        let names = |cypher: &str| -> Vec<String> {
+            exec_read(&store, cypher).records.iter()
+                .map(|r| r.get("name").unwrap().as_property().unwrap().as_string().unwrap().to_string())
+                .collect()
+        };
+        assert_eq!(names("MATCH (a:Person)-[:KNOWS]->() RETURN a.name AS name"), vec!["Alice"]);
+        assert_eq!(names("MATCH (a:Person)-->() RETURN a.name AS name"), vec!["Alice"]);
+        assert_eq!(names("MATCH (a:Person)-[]->() RETURN a.name AS name"), vec!["Alice"]);
+        assert_eq!(names("MATCH (a:Person)<--() RETURN a.name AS name"), vec!["Bob"]);
+        assert_eq!(names("MATCH (a:Person)--() RETURN a.name AS name ORDER BY name"), vec!["Alice", "Bob"]);
+        assert_eq!(names("MATCH (a)-->(b) RETURN b.name AS name"), vec!["Bob"]);
+    }
+
+    #[test]
+    fn test_degree_functions_and_size_patterns() {
+        // Alice knows Bob and Carol, Bob knows Carol, Carol works at Acme
+        let mut store = GraphStore::new();
+        exec_mut(&mut store, "CREATE (a:Person {name: 'Alice'}), (b:Person {name: 'Bob'}), (c:Person {name: 'Carol'}), (d:Company {name: 'Acme'})");
+        exec_mut(&mut store, "MATCH (a:Person {name: 'Alice'}), (b:Person {name: 'Bob'}), (c:Person {name: 'Carol'}) CREATE (a)-[:KNOWS]->(b), (a)-[:KNOWS]->(c), (b)-[:KNOWS]->(c)");
+        exec_mut(&mut store, "MATCH (c:Person {name: 'Carol'}), (d:Company) CREATE (c)-[:WORKS_AT]->(d)");
+
+        let counts = |cypher: &str| -> Vec<(String, i64)> {
+            exec_read(&store, cypher).records.iter()
+                .map(|r| (
+                    r.get("name").unwrap().as_property().unwrap().as_string().unwrap().to_string(),
+                    r.get("k").unwrap().as_property().unwrap().as_integer().unwrap(),
+                ))
+                .collect()
+        };
+        let expected = |rows: &[(&str, i64)]| -> Vec<(String, i64)> {
+            rows.iter().map(|(n, k)| (n.to_string(), *k)).collect()
+        };
+
+        assert_eq!(
+            counts("MATCH (n:Person) RETURN n.name AS name, degree(n) AS k ORDER BY name"),
+            expected(&[("Alice", 2), ("Bob", 2), ("Carol", 3)]),
+        );
+        assert_eq!(
+            counts("MATCH (n:Person) RETURN n.name AS name, size((n)-->()) AS k ORDER BY name"),
+            expected(&[("Alice", 2), ("Bob", 1), ("Carol", 1)]),
+        );
+        assert_eq!(
+            counts("MATCH (n:Person) RETURN n.name AS name, size((n)<--()) AS k ORDER BY name"),
+            expected(&[("Alice", 0), ("Bob", 1), ("Carol", 2)]),
+        );
+        assert_eq!(
+            counts("MATCH (n) WHERE degree(n) > 2 RETURN n.name AS name, outDegree(n) AS k"),
+            expected(&[("Carol", 1)]),
+        );
+        // Typed or filtered patterns fall back to counting matches
+        assert_eq!(
+            counts("MATCH (n:Person) RETURN n.name AS name, size((n)-[:KNOWS]->(:Person)) AS k ORDER BY name"),
+            expected(&[("Alice", 2), ("Bob", 1), ("Carol", 0)]),
+        );
+
+        let query = parse_query("MATCH (n) WHERE size((n)--()) > 1 RETURN n").unwrap();
+        let predicate = &query.where_clause.as_ref().unwrap().predicate;
+        assert!(format!("{:?}", predicate).contains("\"degree\""));
+    }
+
     // ==================== CY-12: CREATE...RETURN ====================
 
     #[test]
@@ -7244,4 +7978,69 @@ mod tests {
             Value::Property(PropertyValue::Integer(17))
         );
     }
+
+    /// Complete directed graph on `n` nodes — unbounded var-length paths over
+    /// it are quadratic per source.
+    fn complete_graph(n: usize) -> GraphStore {
+        let mut store = GraphStore::new();
+        let ids: Vec<_> = (0..n).map(|_| store.create_node("N")).collect();
+        for &a in &ids {
+            for &b in &ids {
+                if a != b {
+                    store.create_edge(a, b, "E").unwrap();
+                }
+            }
+        }
+        store
+    }
+
+    #[test]
+    fn test_var_length_expand_checks_deadline() {
+        let store = complete_graph(30);
+        let query = parse_query("MATCH (a:N)-[*]->(b) RETURN b").unwrap();
+
+        // The cursor has no per-batch check, so the error must come from
+        // inside VarLengthExpandOperator.
+        let expired = std::time::Instant::now() - std::time::Duration::from_millis(1);
+        let mut cursor = QueryExecutor::new(&store).with_deadline(expired).cursor(&query).unwrap();
+        let err = cursor.next(&store).unwrap_err();
+        assert!(err.to_string().contains("Query timed out"), "{}", err);
+
+        let mut cursor = QueryExecutor::new(&store).cursor(&query).unwrap();
+        assert!(cursor.next(&store).unwrap().is_some());
+    }
+
+    #[test]
+    fn test_cancellation_token_aborts_query() {
+        let store = complete_graph(30);
+        let query = parse_query("MATCH (a:N)-[*]->(b) RETURN count(b) AS c").unwrap();
+
+        let token = CancellationToken::new();
+        let result = QueryExecutor::new(&store).with_cancellation(token.clone()).execute(&query).unwrap();
+        assert_eq!(
+            *result.records[0].get("c").unwrap(),
+            Value::Property(PropertyValue::Integer(30 * 29))
+        );
+
+        let other = token.clone();
+        other.cancel();
+        assert!(token.is_cancelled());
+        let err = QueryExecutor::new(&store).with_cancellation(token).execute(&query).unwrap_err();
+        assert!(err.to_string().contains("Query cancelled"), "{}", err);
+
+        // The thread-local guard is cleared once execution finishes.
+        assert!(QueryExecutor::new(&store).execute(&query).is_ok());
+    }
+
+    #[test]
+    fn test_mut_executor_deadline() {
+        let mut store = complete_graph(10);
+        let query = parse_query("MATCH (a:N)-[*]->(b) SET b.seen = true").unwrap();
+        let expired = std::time::Instant::now() - std::time::Duration::from_millis(1);
+        let err = MutQueryExecutor::new(&mut store, "default".to_string())
+            .with_deadline(expired)
+            .execute(&query)
+            .unwrap_err();
+        assert!(err.to_string().contains("Query timed out"), "{}", err);
+    }
 }
@@ -48,8 +48,10 @@
 //!
 //! Operators work with `Value::NodeRef(id)` instead of full `Value::Node(id, clone)`.
 //! Property access goes through `resolve_property()`, which looks up the property from
-//! the [`GraphStore`] on demand. Full materialization only happens at `ProjectOperator`
-//! when the query returns a node variable. See ADR-012.
+//! the [`GraphStore`] on demand. Read plans never materialize: the executor hydrates
+//! references in the rows it hands back ([`Record::materialize`]), so `RETURN n.name`
+//! clones nothing and `RETURN n` clones only the nodes that survive SKIP/LIMIT. Write
+//! plans materialize at `ProjectOperator`. See ADR-012.
 //!
 //! # Metaheuristic Optimization Solvers
 //!
@@ -64,20 +66,23 @@
 //! - `HashMap` — build phase of hash joins in `JoinOperator`
 //! - `BTreeSet` — sorted unique results where ordering matters
 
-use crate::graph::{GraphStore, Label, NodeId, EdgeType};
+use crate::graph::{GraphError, GraphResult, GraphStore, Label, NodeId, EdgeType};
 use crate::query::ast::{Expression, BinaryOp, UnaryOp, Direction, Pattern};
-use crate::query::executor::{ExecutionError, ExecutionResult, Record, Value, RecordBatch};
+use crate::query::executor::{CancellationToken, ExecutionError, ExecutionResult, Record, Value, RecordBatch};
 use crate::graph::PropertyValue;
+use crate::index::ConstraintKind;
 use std::collections::{BTreeSet, HashMap, HashSet};
 use rayon::prelude::*;
 use samyama_optimization::common::{Problem, SolverConfig, MultiObjectiveProblem};
 use samyama_optimization::algorithms::{JayaSolver, RaoSolver, RaoVariant, TLBOSolver, FireflySolver, CuckooSolver, GWOSolver, GASolver, SASolver, BatSolver, ABCSolver, GSASolver, NSGA2Solver, MOTLBOSolver, HSSolver, FPASolver};
 use ndarray::Array1;
 
-// Thread-local query deadline for cooperative timeout inside operator materialization loops.
-// Set by QueryExecutor before execution, checked by JoinOperator/AggregateOperator/SortOperator.
+// Thread-local query deadline and cancellation token for cooperative abort inside
+// operator loops. Set by QueryExecutor before execution, checked by
+// JoinOperator/AggregateOperator/SortOperator and VarLengthExpandOperator.
 thread_local! {
     static QUERY_DEADLINE: std::cell::Cell<Option<std::time::Instant>> = const { std::cell::Cell::new(None) };
+    static QUERY_CANCEL: std::cell::RefCell<Option<CancellationToken>> = const { std::cell::RefCell::new(None) };
 }
 
 /// Set the query deadline for the current thread (called by QueryExecutor)
@@ -85,8 +90,17 @@ pub fn set_query_deadline(deadline: Option<std::time::Instant>) {
     QUERY_DEADLINE.with(|d| d.set(deadline));
 }
 
-/// Check if the query deadline has been exceeded; returns Err if so
-fn check_deadline() -> ExecutionResult<()> {
+/// Set the cancellation token for the current thread (called by QueryExecutor)
+pub fn set_query_cancellation(token: Option<CancellationToken>) {
+    QUERY_CANCEL.with(|c| *c.borrow_mut() = token);
+}
+
+/// Check if the query was cancelled or its deadline exceeded; returns Err if so
+pub(crate) fn check_deadline() -> ExecutionResult<()> {
+    let cancelled = QUERY_CANCEL.with(|c| c.borrow().as_ref().is_some_and(|t| t.is_cancelled()));
+    if cancelled {
+        return Err(ExecutionError::RuntimeError("Query cancelled".to_string()));
+    }
     QUERY_DEADLINE.with(|d| {
         if let Some(deadline) = d.get() {
             if std::time::Instant::now() > deadline {
@@ -105,6 +119,28 @@ fn node_id_of(v: &Value) -> Option<NodeId> {
     }
 }
 
+/// Wrap a checked integer result, turning i64 overflow into a runtime error.
+fn checked_int(result: Option<i64>) -> ExecutionResult<PropertyValue> {
+    result
+        .map(PropertyValue::Integer)
+        .ok_or_else(|| ExecutionError::RuntimeError("Integer overflow".to_string()))
+}
+
+/// String `+`: concatenates a string with a string, number or boolean,
+/// rendering floats with a decimal point as openCypher does (`'v' + 1.0` = `"v1.0"`).
+fn concat_strings(left: &PropertyValue, right: &PropertyValue) -> ExecutionResult<PropertyValue> {
+    fn part(value: &PropertyValue) -> ExecutionResult<String> {
+        match value {
+            PropertyValue::String(s) => Ok(s.clone()),
+            PropertyValue::Integer(i) => Ok(i.to_string()),
+            PropertyValue::Float(f) => Ok(format!("{:?}", f)),
+            PropertyValue::Boolean(b) => Ok(b.to_string()),
+            _ => Err(ExecutionError::TypeError("Cannot concatenate string with this type".to_string())),
+        }
+    }
+    Ok(PropertyValue::String(part(left)? + &part(right)?))
+}
+
 /// Shared binary operator evaluation used by Project, Aggregate, and Sort operators
 fn eval_binary_op(op: &BinaryOp, left: Value, right: Value) -> ExecutionResult<Value> {
     // Node/edge identity comparison (Cypher: n1 = n2, n1 <> n2)
@@ -127,6 +163,12 @@ fn eval_binary_op(op: &BinaryOp, left: Value, right: Value) -> ExecutionResult<V
         Value::Null => PropertyValue::Null,
         _ => return Err(ExecutionError::TypeError("Binary op requires property values".to_string())),
     };
+    // Arithmetic with a null operand yields null (checked before division by zero).
+    if matches!(op, BinaryOp::Add | BinaryOp::Sub | BinaryOp::Mul | BinaryOp::Div | BinaryOp::Mod)
+        && (left_prop.is_null() || right_prop.is_null())
+    {
+        return Ok(Value::Property(PropertyValue::Null));
+    }
     let result = match op {
         BinaryOp::Eq => PropertyValue::Boolean(left_prop == right_prop),
         BinaryOp::Ne => PropertyValue::Boolean(left_prop != right_prop),
@@ -155,11 +197,11 @@ fn eval_binary_op(op: &BinaryOp, left: Value, right: Value) -> ExecutionResult<V
             _ => return Err(ExecutionError::TypeError("OR requires booleans".to_string())),
         },
         BinaryOp::Add => match (&left_prop, &right_prop) {
-            (PropertyValue::Integer(l), PropertyValue::Integer(r)) => PropertyValue::Integer(l + r),
+            (PropertyValue::Integer(l), PropertyValue::Integer(r)) => checked_int(l.checked_add(*r))?,
             (PropertyValue::Float(l), PropertyValue::Float(r)) => PropertyValue::Float(l + r),
             (PropertyValue::Integer(l), PropertyValue::Float(r)) => PropertyValue::Float(*l as f64 + r),
             (PropertyValue::Float(l), PropertyValue::Integer(r)) => PropertyValue::Float(l + *r as f64),
-            (PropertyValue::String(l), PropertyValue::String(r)) => PropertyValue::String(format!("{}{}", l, r)),
+            (PropertyValue::String(_), _) | (_, PropertyValue::String(_)) => concat_strings(&left_prop, &right_prop)?,
             // DateTime + Duration
             (PropertyValue::DateTime(dt), PropertyValue::Duration { months, days, seconds, .. }) |
             (PropertyValue::Duration { months, days, seconds, .. }, PropertyValue::DateTime(dt)) => {
@@ -173,7 +215,7 @@ fn eval_binary_op(op: &BinaryOp, left: Value, right: Value) -> ExecutionResult<V
             _ => return Err(ExecutionError::TypeError("Add requires numeric or string operands".to_string())),
         },
         BinaryOp::Sub => match (&left_prop, &right_prop) {
-            (PropertyValue::Integer(l), PropertyValue::Integer(r)) => PropertyValue::Integer(l - r),
+            (PropertyValue::Integer(l), PropertyValue::Integer(r)) => checked_int(l.checked_sub(*r))?,
             (PropertyValue::Float(l), PropertyValue::Float(r)) => PropertyValue::Float(l - r),
             (PropertyValue::Integer(l), PropertyValue::Float(r)) => PropertyValue::Float(*l as f64 - r),
             (PropertyValue::Float(l), PropertyValue::Integer(r)) => PropertyValue::Float(l - *r as f64),
@@ -195,7 +237,7 @@ fn eval_binary_op(op: &BinaryOp, left: Value, right: Value) -> ExecutionResult<V
             _ => return Err(ExecutionError::TypeError("Sub requires numeric operands".to_string())),
         },
         BinaryOp::Mul => match (&left_prop, &right_prop) {
-            (PropertyValue::Integer(l), PropertyValue::Integer(r)) => PropertyValue::Integer(l * r),
+            (PropertyValue::Integer(l), PropertyValue::Integer(r)) => checked_int(l.checked_mul(*r))?,
             (PropertyValue::Float(l), PropertyValue::Float(r)) => PropertyValue::Float(l * r),
             (PropertyValue::Integer(l), PropertyValue::Float(r)) => PropertyValue::Float(*l as f64 * r),
             (PropertyValue::Float(l), PropertyValue::Integer(r)) => PropertyValue::Float(l * *r as f64),
@@ -203,7 +245,7 @@ fn eval_binary_op(op: &BinaryOp, left: Value, right: Value) -> ExecutionResult<V
         },
         BinaryOp::Div => match (&left_prop, &right_prop) {
             (PropertyValue::Integer(_), PropertyValue::Integer(0)) => return Err(ExecutionError::RuntimeError("Division by zero".to_string())),
-            (PropertyValue::Integer(l), PropertyValue::Integer(r)) => PropertyValue::Integer(l / r),
+            (PropertyValue::Integer(l), PropertyValue::Integer(r)) => checked_int(l.checked_div(*r))?,
             (PropertyValue::Float(l), PropertyValue::Float(r)) => PropertyValue::Float(l / r),
             (PropertyValue::Integer(l), PropertyValue::Float(r)) => PropertyValue::Float(*l as f64 / r),
             (PropertyValue::Float(l), PropertyValue::Integer(r)) => PropertyValue::Float(l / *r as f64),
@@ -211,7 +253,7 @@ fn eval_binary_op(op: &BinaryOp, left: Value, right: Value) -> ExecutionResult<V
         },
         BinaryOp::Mod => match (&left_prop, &right_prop) {
             (PropertyValue::Integer(_), PropertyValue::Integer(0)) => return Err(ExecutionError::RuntimeError("Modulo by zero".to_string())),
-            (PropertyValue::Integer(l), PropertyValue::Integer(r)) => PropertyValue::Integer(l % r),
+            (PropertyValue::Integer(l), PropertyValue::Integer(r)) => checked_int(l.checked_rem(*r))?,
             (PropertyValue::Float(l), PropertyValue::Float(r)) => PropertyValue::Float(l % r),
             (PropertyValue::Integer(l), PropertyValue::Float(r)) => PropertyValue::Float(*l as f64 % r),
             (PropertyValue::Float(l), PropertyValue::Integer(r)) => PropertyValue::Float(l % *r as f64),
@@ -265,7 +307,8 @@ fn eval_unary_op(op: &UnaryOp, val: Value) -> ExecutionResult<Value> {
             _ => Err(ExecutionError::TypeError("NOT requires boolean".to_string())),
         },
         UnaryOp::Minus => match val {
-            Value::Property(PropertyValue::Integer(i)) => Ok(Value::Property(PropertyValue::Integer(-i))),
+            Value::Property(PropertyValue::Integer(i)) => Ok(Value::Property(checked_int(i.checked_neg())?)),
+            Value::Null | Value::Property(PropertyValue::Null) => Ok(Value::Property(PropertyValue::Null)),
             Value::Property(PropertyValue::Float(f)) => Ok(Value::Property(PropertyValue::Float(-f))),
             _ => Err(ExecutionError::TypeError("Negation requires numeric type".to_string())),
         },
@@ -491,6 +534,10 @@ fn exists_neighbors(
     }
     if matches!(edge_pat.direction, Direction::Incoming | Direction::Both) {
         for e in store.get_incoming_edges(node) {
+            // Self-loops were already collected on the outgoing side
+            if edge_pat.direction == Direction::Both && e.source == e.target {
+                continue;
+            }
             if edge_matches(&e) {
                 let other = e.source;
                 out.push((e, other));
@@ -778,7 +825,7 @@ fn eval_pattern_comprehension(
                         Direction::Incoming => store.get_incoming_edges(*node_id),
                         Direction::Both => {
                             let mut all = store.get_outgoing_edges(*node_id);
-                            all.extend(store.get_incoming_edges(*node_id));
+                            all.extend(store.get_incoming_edges(*node_id).into_iter().filter(|e| e.source != e.target));
                             all
                         }
                     };
@@ -1118,6 +1165,20 @@ pub fn eval_function(name: &str, args: &[Value], store: Option<&GraphStore>) ->
                 _ => Err(ExecutionError::TypeError("type() requires an edge".to_string())),
             }
         }
+        "degree" | "indegree" | "outdegree" => {
+            let id = match &args[0] {
+                Value::NodeRef(id) | Value::Node(id, _) => *id,
+                Value::Null | Value::Property(PropertyValue::Null) => return Ok(Value::Null),
+                _ => return Err(ExecutionError::TypeError(format!("{}() requires a node", name))),
+            };
+            let s = store.ok_or_else(|| ExecutionError::RuntimeError(format!("{}() requires store", name)))?;
+            let degree = match name.to_lowercase().as_str() {
+                "indegree" => s.in_degree(id),
+                "outdegree" => s.out_degree(id),
+                _ => s.degree(id),
+            };
+            Ok(Value::Property(PropertyValue::Integer(degree as i64)))
+        }
         "keys" => {
             match &args[0] {
                 Value::Node(_, node) => {
@@ -1230,15 +1291,9 @@ pub fn eval_function(name: &str, args: &[Value], store: Option<&GraphStore>) ->
                 Ok(Value::Property(PropertyValue::DateTime(now)))
             } else {
                 match &args[0] {
-                    Value::Property(PropertyValue::String(s)) => {
-                        if let Ok(dt) = chrono::DateTime::parse_from_rfc3339(s) {
-                            Ok(Value::Property(PropertyValue::DateTime(dt.timestamp_millis())))
-                        } else if let Ok(dt) = chrono::NaiveDateTime::parse_from_str(s, "%Y-%m-%dT%H:%M:%S") {
-                            Ok(Value::Property(PropertyValue::DateTime(dt.and_utc().timestamp_millis())))
-                        } else {
-                            Err(ExecutionError::RuntimeError(format!("Cannot parse datetime: {}", s)))
-                        }
-                    }
+                    Value::Property(PropertyValue::String(s)) => PropertyValue::parse_datetime(s)
+                        .map(Value::Property)
+                        .ok_or_else(|| ExecutionError::RuntimeError(format!("Cannot parse datetime: {}", s))),
                     Value::Property(PropertyValue::Map(map)) => {
                         use chrono::TimeZone;
                         let year = map.get("year").and_then(|v| v.as_integer()).unwrap_or(1970) as i32;
@@ -2027,7 +2082,7 @@ impl NodeScanOperator {
         // Three cases:
         //   1. No labels  → scan all nodes (rare)
         //   2. Single label → direct copy of label_index entry; no dedup
-        //   3. Multi-label → union with HashSet for dedup
+        //   3. Multi-label → intersection, driven by the smallest label set
         //
         // Sort behavior is conditional:
         //   - With early_limit (LIMIT pushdown): skip sort — only `limit`
@@ -2042,18 +2097,25 @@ impl NodeScanOperator {
         } else if self.labels.len() == 1 {
             self.node_ids = store.node_ids_by_label(&self.labels[0], self.early_limit);
         } else {
-            // Multi-label: union via HashSet. Stop early if early_limit is set.
+            // Multi-label: a node must carry every label. Drive the scan from
+            // the smallest label set and probe the others. Stop early if
+            // early_limit is set.
             let cap = self.early_limit.unwrap_or(usize::MAX);
-            let mut node_set: HashSet<NodeId> = HashSet::new();
-            'outer: for label in &self.labels {
-                for nid in store.node_ids_by_label(label, None) {
-                    node_set.insert(nid);
-                    if node_set.len() >= cap {
-                        break 'outer;
-                    }
+            let mut sets = Vec::with_capacity(self.labels.len());
+            for label in &self.labels {
+                match store.label_index_ids(label) {
+                    Some(ids) => sets.push(ids),
+                    None => return,
                 }
             }
-            self.node_ids = node_set.into_iter().collect();
+            sets.sort_by_key(|ids| ids.len());
+            let (smallest, rest) = sets.split_first().expect("multi-label scan has labels");
+            self.node_ids = smallest
+                .iter()
+                .filter(|nid| rest.iter().all(|ids| ids.contains(nid)))
+                .take(cap)
+                .copied()
+                .collect();
         }
 
         // Sort only when no early_limit (preserves cache locality on full scans).
@@ -2381,7 +2443,8 @@ impl FilterOperator {
                     }
                     UnaryOp::Minus => {
                         match val {
-                            Value::Property(PropertyValue::Integer(i)) => Ok(Value::Property(PropertyValue::Integer(-i))),
+                            Value::Property(PropertyValue::Integer(i)) => Ok(Value::Property(checked_int(i.checked_neg())?)),
+                            Value::Null | Value::Property(PropertyValue::Null) => Ok(Value::Property(PropertyValue::Null)),
                             Value::Property(PropertyValue::Float(f)) => Ok(Value::Property(PropertyValue::Float(-f))),
                             _ => Err(ExecutionError::TypeError("Negation requires numeric type".to_string())),
                         }
@@ -2584,18 +2647,20 @@ impl FilterOperator {
 
     fn arithmetic_add(&self, left: &PropertyValue, right: &PropertyValue) -> ExecutionResult<PropertyValue> {
         match (left, right) {
-            (PropertyValue::Integer(l), PropertyValue::Integer(r)) => Ok(PropertyValue::Integer(l + r)),
+            (PropertyValue::Null, _) | (_, PropertyValue::Null) => Ok(PropertyValue::Null),
+            (PropertyValue::Integer(l), PropertyValue::Integer(r)) => checked_int(l.checked_add(*r)),
             (PropertyValue::Float(l), PropertyValue::Float(r)) => Ok(PropertyValue::Float(l + r)),
             (PropertyValue::Integer(l), PropertyValue::Float(r)) => Ok(PropertyValue::Float(*l as f64 + r)),
             (PropertyValue::Float(l), PropertyValue::Integer(r)) => Ok(PropertyValue::Float(l + *r as f64)),
-            (PropertyValue::String(l), PropertyValue::String(r)) => Ok(PropertyValue::String(format!("{}{}", l, r))),
+            (PropertyValue::String(_), _) | (_, PropertyValue::String(_)) => concat_strings(left, right),
             _ => Err(ExecutionError::TypeError("Addition requires numeric or string operands".to_string())),
         }
     }
 
     fn arithmetic_sub(&self, left: &PropertyValue, right: &PropertyValue) -> ExecutionResult<PropertyValue> {
         match (left, right) {
-            (PropertyValue::Integer(l), PropertyValue::Integer(r)) => Ok(PropertyValue::Integer(l - r)),
+            (PropertyValue::Null, _) | (_, PropertyValue::Null) => Ok(PropertyValue::Null),
+            (PropertyValue::Integer(l), PropertyValue::Integer(r)) => checked_int(l.checked_sub(*r)),
             (PropertyValue::Float(l), PropertyValue::Float(r)) => Ok(PropertyValue::Float(l - r)),
             (PropertyValue::Integer(l), PropertyValue::Float(r)) => Ok(PropertyValue::Float(*l as f64 - r)),
             (PropertyValue::Float(l), PropertyValue::Integer(r)) => Ok(PropertyValue::Float(l - *r as f64)),
@@ -2605,7 +2670,8 @@ impl FilterOperator {
 
     fn arithmetic_mul(&self, left: &PropertyValue, right: &PropertyValue) -> ExecutionResult<PropertyValue> {
         match (left, right) {
-            (PropertyValue::Integer(l), PropertyValue::Integer(r)) => Ok(PropertyValue::Integer(l * r)),
+            (PropertyValue::Null, _) | (_, PropertyValue::Null) => Ok(PropertyValue::Null),
+            (PropertyValue::Integer(l), PropertyValue::Integer(r)) => checked_int(l.checked_mul(*r)),
             (PropertyValue::Float(l), PropertyValue::Float(r)) => Ok(PropertyValue::Float(l * r)),
             (PropertyValue::Integer(l), PropertyValue::Float(r)) => Ok(PropertyValue::Float(*l as f64 * r)),
             (PropertyValue::Float(l), PropertyValue::Integer(r)) => Ok(PropertyValue::Float(l * *r as f64)),
@@ -2615,8 +2681,9 @@ impl FilterOperator {
 
     fn arithmetic_div(&self, left: &PropertyValue, right: &PropertyValue) -> ExecutionResult<PropertyValue> {
         match (left, right) {
+            (PropertyValue::Null, _) | (_, PropertyValue::Null) => Ok(PropertyValue::Null),
             (PropertyValue::Integer(_), PropertyValue::Integer(0)) => Err(ExecutionError::RuntimeError("Division by zero".to_string())),
-            (PropertyValue::Integer(l), PropertyValue::Integer(r)) => Ok(PropertyValue::Integer(l / r)),
+            (PropertyValue::Integer(l), PropertyValue::Integer(r)) => checked_int(l.checked_div(*r)),
             (PropertyValue::Float(l), PropertyValue::Float(r)) => Ok(PropertyValue::Float(l / r)),
             (PropertyValue::Integer(l), PropertyValue::Float(r)) => Ok(PropertyValue::Float(*l as f64 / r)),
             (PropertyValue::Float(l), PropertyValue::Integer(r)) => Ok(PropertyValue::Float(l / *r as f64)),
@@ -2626,8 +2693,9 @@ impl FilterOperator {
 
     fn arithmetic_mod(&self, left: &PropertyValue, right: &PropertyValue) -> ExecutionResult<PropertyValue> {
         match (left, right) {
+            (PropertyValue::Null, _) | (_, PropertyValue::Null) => Ok(PropertyValue::Null),
             (PropertyValue::Integer(_), PropertyValue::Integer(0)) => Err(ExecutionError::RuntimeError("Modulo by zero".to_string())),
-            (PropertyValue::Integer(l), PropertyValue::Integer(r)) => Ok(PropertyValue::Integer(l % r)),
+            (PropertyValue::Integer(l), PropertyValue::Integer(r)) => checked_int(l.checked_rem(*r)),
             (PropertyValue::Float(l), PropertyValue::Float(r)) => Ok(PropertyValue::Float(l % r)),
             (PropertyValue::Integer(l), PropertyValue::Float(r)) => Ok(PropertyValue::Float(*l as f64 % r)),
             (PropertyValue::Float(l), PropertyValue::Integer(r)) => Ok(PropertyValue::Float(l % *r as f64)),
@@ -2740,6 +2808,18 @@ impl PhysicalOperator for FilterOperator {
     }
 }
 
+/// Whether an edge carries every inline property of its relationship pattern,
+/// e.g. `-[:KNOWS {since: 2020}]->`. Works for stub edges via the sparse map.
+fn edge_has_properties(store: &GraphStore, edge_id: crate::graph::EdgeId, props: &[(String, PropertyValue)]) -> bool {
+    if props.is_empty() {
+        return true;
+    }
+    match store.get_edge_properties(edge_id) {
+        Some(map) => props.iter().all(|(k, v)| map.get(k) == Some(v)),
+        None => false,
+    }
+}
+
 /// Expand operator: `-[:KNOWS]->`
 pub struct ExpandOperator {
     /// Input operator
@@ -2754,6 +2834,8 @@ pub struct ExpandOperator {
     edge_types: Vec<String>,
     /// Target node labels to filter (empty = any label)
     target_labels: Vec<Label>,
+    /// Inline relationship properties the edge must carry (empty = any)
+    edge_properties: Vec<(String, PropertyValue)>,
     /// Direction
     direction: Direction,
     /// Current input record
@@ -2783,6 +2865,7 @@ impl ExpandOperator {
             edge_var,
             edge_types,
             target_labels: Vec::new(),
+            edge_properties: Vec::new(),
             direction,
             current_record: None,
             current_edges: Vec::new(),
@@ -2803,6 +2886,12 @@ impl ExpandOperator {
         self
     }
 
+    /// Only expand along edges carrying these inline properties
+    pub fn with_edge_properties(mut self, props: HashMap<String, PropertyValue>) -> Self {
+        self.edge_properties = props.into_iter().collect();
+        self
+    }
+
     fn load_edges(&mut self, record: &Record, store: &GraphStore) -> ExecutionResult<()> {
         let source_val = record.get(&self.source_var)
             .ok_or_else(|| ExecutionError::VariableNotFound(self.source_var.clone()))?;
@@ -2816,8 +2905,9 @@ impl ExpandOperator {
             Direction::Outgoing => store.get_outgoing_edge_targets_owned(node_id),
             Direction::Incoming => store.get_incoming_edge_sources_owned(node_id),
             Direction::Both => {
+                // Self-loops are already in the outgoing list
                 let mut all = store.get_outgoing_edge_targets_owned(node_id);
-                all.extend(store.get_incoming_edge_sources_owned(node_id));
+                all.extend(store.get_incoming_edge_sources_owned(node_id).into_iter().filter(|(_, src, tgt, _)| src != tgt));
                 all
             }
         };
@@ -2831,6 +2921,11 @@ impl ExpandOperator {
                 .collect()
         };
 
+        // Filter by inline relationship properties if specified
+        if !self.edge_properties.is_empty() {
+            self.current_edges.retain(|(eid, ..)| edge_has_properties(store, *eid, &self.edge_properties));
+        }
+
         // Filter by target node labels if specified
         if !self.target_labels.is_empty() {
             self.current_edges.retain(|(_, src, tgt, _)| {
@@ -3006,6 +3101,8 @@ pub struct VarLengthExpandOperator {
     target_var: String,
     edge_types: Vec<String>,
     target_labels: Vec<Label>,
+    /// Inline relationship properties every hop's edge must carry.
+    edge_properties: Vec<(String, PropertyValue)>,
     direction: Direction,
     min_hops: usize,
     max_hops: usize,
@@ -3033,6 +3130,7 @@ impl VarLengthExpandOperator {
             target_var,
             edge_types,
             target_labels: Vec::new(),
+            edge_properties: Vec::new(),
             direction,
             min_hops,
             max_hops,
@@ -3047,13 +3145,19 @@ impl VarLengthExpandOperator {
         self
     }
 
+    /// Restrict every traversed edge to those carrying these inline properties.
+    pub fn with_edge_properties(mut self, props: HashMap<String, PropertyValue>) -> Self {
+        self.edge_properties = props.into_iter().collect();
+        self
+    }
+
     /// Set path variable for named-path materialization.
     pub fn with_path_variable(mut self, var: String) -> Self {
         self.path_variable = Some(var);
         self
     }
 
-    /// One-hop neighbours of `node` honouring direction + edge-type filter,
+    /// One-hop neighbours of `node` honouring direction, edge-type and edge-property filters,
     /// returned as `(neighbour_node, edge_id)` pairs.
     fn neighbors(&self, node: NodeId, store: &GraphStore) -> Vec<(NodeId, crate::graph::EdgeId)> {
         let raw: Vec<(crate::graph::EdgeId, NodeId, NodeId, EdgeType)> = match self.direction {
@@ -3061,7 +3165,7 @@ impl VarLengthExpandOperator {
             Direction::Incoming => store.get_incoming_edge_sources_owned(node),
             Direction::Both => {
                 let mut all = store.get_outgoing_edge_targets_owned(node);
-                all.extend(store.get_incoming_edge_sources_owned(node));
+                all.extend(store.get_incoming_edge_sources_owned(node).into_iter().filter(|(_, src, tgt, _)| src != tgt));
                 all
             }
         };
@@ -3069,6 +3173,7 @@ impl VarLengthExpandOperator {
             .filter(|(_, _, _, et)| {
                 self.edge_types.is_empty() || self.edge_types.iter().any(|t| et.as_str() == t)
             })
+            .filter(|(eid, ..)| edge_has_properties(store, *eid, &self.edge_properties))
             .map(|(eid, src, tgt, _)| {
                 let other = match self.direction {
                     Direction::Outgoing => tgt,
@@ -3114,6 +3219,7 @@ impl VarLengthExpandOperator {
             depth += 1;
             let mut next = Vec::new();
             for &cur in &frontier {
+                check_deadline()?;
                 for (nb, eid) in self.neighbors(cur, store) {
                     if visited.insert(nb) {
                         parent.insert(nb, (cur, eid));
@@ -3235,26 +3341,41 @@ impl ProjectOperator {
         Self { input, projections }
     }
 
-    fn evaluate_expression(&self, expr: &Expression, record: &Record, store: &GraphStore) -> ExecutionResult<Value> {
-        match expr {
-            Expression::Variable(var) => {
-                let val = record.get(var)
-                    .cloned()
-                    .ok_or_else(|| ExecutionError::VariableNotFound(var.clone()))?;
-                // Materialize refs at projection time (RETURN n)
-                match val {
+    /// Evaluate every projection against `record`
+    ///
+    /// Node and edge variables stay references unless `materialize` is set. The read
+    /// executors hydrate them once rows leave the plan, so rows later dropped by SKIP
+    /// or LIMIT, or reordered by a sort above this operator, never clone whole entities.
+    fn project(&self, record: &Record, store: &GraphStore, materialize: bool) -> ExecutionResult<Record> {
+        let mut new_record = Record::new();
+        for (expr, alias) in &self.projections {
+            let mut value = self.evaluate_expression(expr, record, store)?;
+            if materialize {
+                value = match value {
                     Value::NodeRef(id) => {
                         let node = store.get_node(id)
                             .ok_or_else(|| ExecutionError::RuntimeError(format!("Node {:?} not found", id)))?;
-                        Ok(Value::Node(id, node.clone()))
+                        Value::Node(id, node.clone())
                     }
                     Value::EdgeRef(id, ..) => {
                         let edge = store.get_edge(id)
                             .ok_or_else(|| ExecutionError::RuntimeError(format!("Edge {:?} not found", id)))?;
-                        Ok(Value::Edge(id, edge.clone()))
+                        Value::Edge(id, edge.clone())
                     }
-                    other => Ok(other),
-                }
+                    other => other,
+                };
+            }
+            new_record.bind(alias.clone(), value);
+        }
+        Ok(new_record)
+    }
+
+    fn evaluate_expression(&self, expr: &Expression, record: &Record, store: &GraphStore) -> ExecutionResult<Value> {
+        match expr {
+            Expression::Variable(var) => {
+                record.get(var)
+                    .cloned()
+                    .ok_or_else(|| ExecutionError::VariableNotFound(var.clone()))
             }
             Expression::Property { variable, property } => {
                 let val = record.get(variable)
@@ -3322,17 +3443,9 @@ impl ProjectOperator {
 
 impl PhysicalOperator for ProjectOperator {
     fn next(&mut self, store: &GraphStore) -> ExecutionResult<Option<Record>> {
-        if let Some(record) = self.input.next(store)? {
-            let mut new_record = Record::new();
-
-            for (expr, alias) in &self.projections {
-                let value = self.evaluate_expression(expr, &record, store)?;
-                new_record.bind(alias.clone(), value);
-            }
-
-            Ok(Some(new_record))
-        } else {
-            Ok(None)
+        match self.input.next(store)? {
+            Some(record) => Ok(Some(self.project(&record, store, false)?)),
+            None => Ok(None),
         }
     }
 
@@ -3347,12 +3460,7 @@ impl PhysicalOperator for ProjectOperator {
             let columns: Vec<String> = self.projections.iter().map(|(_, a)| a.clone()).collect();
 
             for record in batch.records {
-                let mut new_record = Record::new();
-                for (expr, alias) in &self.projections {
-                    let value = self.evaluate_expression(expr, &record, store)?;
-                    new_record.bind(alias.clone(), value);
-                }
-                projected_records.push(new_record);
+                projected_records.push(self.project(&record, store, false)?);
             }
 
             Ok(Some(RecordBatch {
@@ -3365,15 +3473,11 @@ impl PhysicalOperator for ProjectOperator {
     }
 
     fn next_mut(&mut self, store: &mut GraphStore, tenant_id: &str) -> ExecutionResult<Option<Record>> {
-        if let Some(record) = self.input.next_mut(store, tenant_id)? {
-            let mut new_record = Record::new();
-            for (expr, alias) in &self.projections {
-                let value = self.evaluate_expression(expr, &record, store)?;
-                new_record.bind(alias.clone(), value);
-            }
-            Ok(Some(new_record))
-        } else {
-            Ok(None)
+        // Write queries snapshot returned entities as of this row, before later
+        // rows of the same query modify them
+        match self.input.next_mut(store, tenant_id)? {
+            Some(record) => Ok(Some(self.project(&record, store, true)?)),
+            None => Ok(None),
         }
     }
 
@@ -3418,6 +3522,17 @@ pub struct AggregateFunction {
     pub distinct: bool,
 }
 
+impl AggregateFunction {
+    /// Whether this is `count(*)` (or a count of a non-null literal), which
+    /// counts every row without evaluating its argument. `count(expr)` in
+    /// general skips nulls and so must evaluate it.
+    fn counts_rows(&self) -> bool {
+        matches!(self.func, AggregateType::Count)
+            && !self.distinct
+            && matches!(&self.expr, Expression::Literal(v) if !v.is_null())
+    }
+}
+
 /// Internal state for an aggregator
 #[derive(Debug, Clone)]
 enum AggregatorState {
@@ -3884,7 +3999,7 @@ impl AggregateOperator {
         let group_expr = &self.group_by[0].0;
 
         // Check if all aggregates are simple count (non-distinct) — can skip aggregate expression evaluation
-        let all_simple_count = self.aggregates.iter().all(|a| matches!(a.func, AggregateType::Count) && !a.distinct);
+        let all_simple_count = self.aggregates.iter().all(AggregateFunction::counts_rows);
 
         let batch_size = 65536;
         let mut batch_count = 0u64;
@@ -3936,7 +4051,7 @@ impl AggregateOperator {
             .map(|agg| AggregatorState::new(&agg.func, agg.distinct))
             .collect();
 
-        let all_simple_count = self.aggregates.iter().all(|a| matches!(a.func, AggregateType::Count) && !a.distinct);
+        let all_simple_count = self.aggregates.iter().all(AggregateFunction::counts_rows);
 
         let batch_size = 65536;
         let mut batch_count = 0u64;
@@ -4528,6 +4643,10 @@ pub struct IndexScanOperator {
     property: String,
     op: BinaryOp,
     value: PropertyValue,
+    /// Opposite-side comparison closing a range, e.g. `< 40` alongside `> 20`
+    second_bound: Option<(BinaryOp, PropertyValue)>,
+    /// Further labels a node must carry, for `(n:A:B)` patterns served by an index on one of them
+    required_labels: Vec<Label>,
     node_ids: Vec<NodeId>,
     current: usize,
 }
@@ -4540,42 +4659,60 @@ impl IndexScanOperator {
             property,
             op,
             value,
+            second_bound: None,
+            required_labels: Vec::new(),
             node_ids: Vec::new(),
             current: 0,
         }
     }
 
+    /// Add a second comparison on the same property so both ends of a range
+    /// are answered by one index read
+    pub fn with_second_bound(mut self, op: BinaryOp, value: PropertyValue) -> Self {
+        self.second_bound = Some((op, value));
+        self
+    }
+
+    /// Only emit nodes carrying all of `labels`; the index label itself may be included
+    pub fn with_required_labels(mut self, labels: Vec<Label>) -> Self {
+        self.required_labels = labels.into_iter().filter(|l| *l != self.label).collect();
+        self
+    }
+
     fn initialize(&mut self, store: &GraphStore) {
         if !self.node_ids.is_empty() {
             return;
         }
 
-        if let Some(index_lock) = store.property_index.get_index(&self.label, &self.property) {
-            let index = index_lock.read().unwrap();
-            self.node_ids = match self.op {
-                BinaryOp::Eq => index.get(&self.value),
-                BinaryOp::Gt => {
-                    use std::ops::Bound::Excluded;
-                    use std::ops::Bound::Unbounded;
-                    index.range((Excluded(self.value.clone()), Unbounded))
-                },
-                BinaryOp::Ge => {
-                    use std::ops::Bound::Included;
-                    use std::ops::Bound::Unbounded;
-                    index.range((Included(self.value.clone()), Unbounded))
-                },
-                BinaryOp::Lt => {
-                    use std::ops::Bound::Excluded;
-                    use std::ops::Bound::Unbounded;
-                    index.range((Unbounded, Excluded(self.value.clone())))
-                },
-                BinaryOp::Le => {
-                    use std::ops::Bound::Included;
-                    use std::ops::Bound::Unbounded;
-                    index.range((Unbounded, Included(self.value.clone())))
-                },
-                _ => Vec::new(),
-            };
+        if matches!(self.op, BinaryOp::Eq) {
+            if let Some(index_lock) = store.property_index.get_index(&self.label, &self.property) {
+                self.node_ids = index_lock.read().unwrap().get(&self.value);
+            }
+        } else {
+            use std::ops::Bound::{Excluded, Included, Unbounded};
+            let mut lower = Unbounded;
+            let mut upper = Unbounded;
+            for (op, value) in std::iter::once((&self.op, &self.value))
+                .chain(self.second_bound.as_ref().map(|(op, value)| (op, value)))
+            {
+                match op {
+                    BinaryOp::Gt => lower = Excluded(value),
+                    BinaryOp::Ge => lower = Included(value),
+                    BinaryOp::Lt => upper = Excluded(value),
+                    BinaryOp::Le => upper = Included(value),
+                    _ => {}
+                }
+            }
+            self.node_ids = store.property_index
+                .range_lookup(&self.label, &self.property, lower, upper)
+                .unwrap_or_default();
+        }
+
+        if !self.required_labels.is_empty() {
+            let required = &self.required_labels;
+            self.node_ids.retain(|id| {
+                required.iter().all(|l| store.label_index_ids(l).is_some_and(|ids| ids.contains(id)))
+            });
         }
     }
 }
@@ -4629,13 +4766,162 @@ impl PhysicalOperator for IndexScanOperator {
     }
 
     fn describe(&self) -> OperatorDescription {
-        let op_str = match self.op {
+        let op_str = |op: &BinaryOp| match op {
             BinaryOp::Eq => "=", BinaryOp::Gt => ">", BinaryOp::Ge => ">=",
             BinaryOp::Lt => "<", BinaryOp::Le => "<=", _ => "?",
         };
+        let mut details = format!("var={}, {}.{} {} {:?}", self.variable, self.label, self.property, op_str(&self.op), self.value);
+        if let Some((op, value)) = &self.second_bound {
+            details.push_str(&format!(" AND {} {:?}", op_str(op), value));
+        }
         OperatorDescription {
             name: "IndexScan".to_string(),
-            details: format!("var={}, {}.{} {} {:?}", self.variable, self.label, self.property, op_str, self.value),
+            details,
+            children: Vec::new(),
+        }
+    }
+}
+
+/// Composite index scan: equality on the leading properties of a composite
+/// index, optionally with a range on the property after them
+pub struct CompositeIndexScanOperator {
+    variable: String,
+    label: Label,
+    /// All properties of the index, in key order
+    properties: Vec<String>,
+    /// Values for the leading properties
+    prefix: Vec<PropertyValue>,
+    /// Comparisons (`>`, `>=`, `<`, `<=`) on the property following the prefix
+    range: Vec<(BinaryOp, PropertyValue)>,
+    /// Further labels a node must carry, as for `IndexScanOperator`
+    required_labels: Vec<Label>,
+    node_ids: Vec<NodeId>,
+    current: usize,
+    initialized: bool,
+}
+
+impl CompositeIndexScanOperator {
+    pub fn new(
+        variable: String,
+        label: Label,
+        properties: Vec<String>,
+        prefix: Vec<PropertyValue>,
+        range: Vec<(BinaryOp, PropertyValue)>,
+    ) -> Self {
+        Self {
+            variable,
+            label,
+            properties,
+            prefix,
+            range,
+            required_labels: Vec::new(),
+            node_ids: Vec::new(),
+            current: 0,
+            initialized: false,
+        }
+    }
+
+    /// Only emit nodes carrying all of `labels`; the index label itself may be included
+    pub fn with_required_labels(mut self, labels: Vec<Label>) -> Self {
+        self.required_labels = labels.into_iter().filter(|l| *l != self.label).collect();
+        self
+    }
+
+    fn initialize(&mut self, store: &GraphStore) {
+        if self.initialized {
+            return;
+        }
+        self.initialized = true;
+
+        use std::ops::Bound::{Excluded, Included, Unbounded};
+        let mut lower = Unbounded;
+        let mut upper = Unbounded;
+        for (op, value) in &self.range {
+            match op {
+                BinaryOp::Gt => lower = Excluded(value),
+                BinaryOp::Ge => lower = Included(value),
+                BinaryOp::Lt => upper = Excluded(value),
+                BinaryOp::Le => upper = Included(value),
+                _ => {}
+            }
+        }
+        self.node_ids = store.property_index
+            .composite_lookup(&self.label, &self.properties, &self.prefix, lower, upper)
+            .unwrap_or_default();
+
+        if !self.required_labels.is_empty() {
+            let required = &self.required_labels;
+            self.node_ids.retain(|id| {
+                required.iter().all(|l| store.label_index_ids(l).is_some_and(|ids| ids.contains(id)))
+            });
+        }
+    }
+}
+
+impl PhysicalOperator for CompositeIndexScanOperator {
+    fn next(&mut self, store: &GraphStore) -> ExecutionResult<Option<Record>> {
+        self.initialize(store);
+
+        while self.current < self.node_ids.len() {
+            let node_id = self.node_ids[self.current];
+            self.current += 1;
+
+            if store.has_node(node_id) {
+                let mut record = Record::new();
+                record.bind(self.variable.clone(), Value::NodeRef(node_id));
+                return Ok(Some(record));
+            }
+        }
+
+        Ok(None)
+    }
+
+    fn next_batch(&mut self, store: &GraphStore, batch_size: usize) -> ExecutionResult<Option<RecordBatch>> {
+        self.initialize(store);
+
+        if self.current >= self.node_ids.len() {
+            return Ok(None);
+        }
+
+        let mut records = Vec::with_capacity(batch_size);
+        while records.len() < batch_size && self.current < self.node_ids.len() {
+            let node_id = self.node_ids[self.current];
+            self.current += 1;
+
+            if store.has_node(node_id) {
+                let mut record = Record::new();
+                record.bind(self.variable.clone(), Value::NodeRef(node_id));
+                records.push(record);
+            }
+        }
+
+        if records.is_empty() {
+            Ok(None)
+        } else {
+            Ok(Some(RecordBatch { records, columns: vec![self.variable.clone()] }))
+        }
+    }
+
+    fn reset(&mut self) {
+        self.current = 0;
+    }
+
+    fn describe(&self) -> OperatorDescription {
+        let mut conditions: Vec<String> = self.properties.iter()
+            .zip(&self.prefix)
+            .map(|(property, value)| format!("{} = {:?}", property, value))
+            .collect();
+        if let Some(property) = self.properties.get(self.prefix.len()) {
+            for (op, value) in &self.range {
+                let op_str = match op {
+                    BinaryOp::Gt => ">", BinaryOp::Ge => ">=", BinaryOp::Lt => "<", BinaryOp::Le => "<=", _ => "?",
+                };
+                conditions.push(format!("{} {} {:?}", property, op_str, value));
+            }
+        }
+        OperatorDescription {
+            name: "CompositeIndexScan".to_string(),
+            details: format!("var={}, :{}({}) {}", self.variable, self.label, self.properties.join(", "), conditions.join(" AND ")),
             children: Vec::new(),
         }
     }
@@ -5153,6 +5439,60 @@ impl PhysicalOperator for LeftOuterJoinOperator {
     }
 }
 
+/// Create a node with its labels and inline properties.
+///
+/// Unique constraints are enforced as each property is written, so a clash with a
+/// node created earlier in the same query is caught too. On failure the partly built
+/// node is removed again. Call [`check_new_node`] once the node is complete.
+fn create_pattern_node<'a>(
+    store: &mut GraphStore,
+    tenant_id: &str,
+    primary: Label,
+    extra_labels: &[Label],
+    properties: impl IntoIterator<Item = (&'a String, &'a PropertyValue)>,
+) -> ExecutionResult<NodeId> {
+    let node_id = store.create_node(primary);
+    let built = extra_labels
+        .iter()
+        .try_for_each(|label| store.add_label_to_node(tenant_id, node_id, label.clone()))
+        .and_then(|_| {
+            properties
+                .into_iter()
+                .try_for_each(|(k, v)| store.set_node_property(tenant_id, node_id, k.clone(), v.clone()))
+        });
+    match built {
+        Ok(()) => Ok(node_id),
+        Err(e) => {
+            let _ = store.delete_node(tenant_id, node_id);
+            Err(ExecutionError::GraphError(e.to_string()))
+        }
+    }
+}
+
+/// Check a newly created node against every constraint on its labels,
+/// removing it again on a violation (e.g. a missing NOT NULL property).
+fn check_new_node(store: &mut GraphStore, tenant_id: &str, node_id: NodeId) -> ExecutionResult<()> {
+    let checked = match store.get_node(node_id) {
+        Some(node) => {
+            let labels: Vec<Label> = node.labels.iter().cloned().collect();
+            store.check_node_constraints(Some(node_id), &labels, &node.properties)
+        }
+        None => Ok(()),
+    };
+    checked.map_err(|e| {
+        let _ = store.delete_node(tenant_id, node_id);
+        ExecutionError::GraphError(e.to_string())
+    })
+}
+
+/// Constraint violations abort the query; other store errors keep SET/REMOVE best-effort
+fn enforce_constraints<T>(result: GraphResult<T>) -> ExecutionResult<()> {
+    match result {
+        Err(e @ GraphError::ConstraintViolation(_)) => Err(ExecutionError::GraphError(e.to_string())),
+        _ => Ok(()),
+    }
+}
+
 /// Create node operator: CREATE (n:Person {name: "Alice"})
 pub struct CreateNodeOperator {
     /// Nodes to create (label, properties, variable)
@@ -5194,17 +5534,9 @@ impl PhysicalOperator for CreateNodeOperator {
                     .map(|l| l.clone())
                     .unwrap_or_else(|| Label::new(""));
 
-                let node_id = store.create_node(primary_label);
-
-                // Add additional labels
-                for label in labels.iter().skip(1) {
-                    let _ = store.add_label_to_node(tenant_id, node_id, label.clone());
-                }
-
-                // Set properties using store.set_node_property to trigger indexing
-                for (key, value) in properties {
-                    let _ = store.set_node_property(tenant_id, node_id, key.clone(), value.clone());
-                }
+                // Extra labels and properties go through the store to trigger indexing
+                let node_id = create_pattern_node(store, tenant_id, primary_label, labels.get(1..).unwrap_or(&[]), properties)?;
+                check_new_node(store, tenant_id, node_id)?;
 
                 self.created_nodes.push((node_id, variable.clone()));
             }
@@ -5348,7 +5680,8 @@ impl PhysicalOperator for CreateVectorIndexOperator {
 
         let metric = match self.similarity.to_lowercase().as_str() {
             "cosine" => crate::vector::DistanceMetric::Cosine,
-            "l2" => crate::vector::DistanceMetric::L2,
+            "euclidean" | "l2" => crate::vector::DistanceMetric::Euclidean,
+            "dot" | "dot_product" | "inner_product" => crate::vector::DistanceMetric::DotProduct,
             _ => return Err(ExecutionError::RuntimeError(format!("Unsupported similarity metric: {}", self.similarity))),
         };
 
@@ -5395,10 +5728,9 @@ impl PhysicalOperator for CompositeCreateIndexOperator {
             return Ok(None);
         }
 
-        // Create individual indexes for each property
+        // Create the composite index plus individual indexes for each property
+        store.property_index.create_composite_index(self.label.clone(), self.properties.clone());
         for property in &self.properties {
-            store.property_index.create_index(self.label.clone(), property.clone());
-
             // Backfill each index (check both HashMap and ColumnStore)
             let mut entries = Vec::new();
             let nodes = store.get_nodes_by_label(&self.label);
@@ -5438,16 +5770,17 @@ impl PhysicalOperator for CompositeCreateIndexOperator {
     }
 }
 
-/// Create unique constraint operator
+/// Create constraint operator: CREATE CONSTRAINT ON (n:Label) ASSERT n.prop IS UNIQUE | IS NOT NULL
 pub struct CreateConstraintOperator {
     label: Label,
     property: String,
+    kind: ConstraintKind,
     executed: bool,
 }
 
 impl CreateConstraintOperator {
-    pub fn new(label: Label, property: String) -> Self {
-        Self { label, property, executed: false }
+    pub fn new(label: Label, property: String, kind: ConstraintKind) -> Self {
+        Self { label, property, kind, executed: false }
     }
 }
 
@@ -5463,34 +5796,9 @@ impl PhysicalOperator for CreateConstraintOperator {
             return Ok(None);
         }
 
-        // Check existing data for uniqueness violations
-        let nodes = store.get_nodes_by_label(&self.label);
-        let mut seen_values: std::collections::HashSet<PropertyValue> = std::collections::HashSet::new();
-        for node in nodes {
-            if let Some(val) = node.get_property(&self.property) {
-                if !val.is_null() && !seen_values.insert(val.clone()) {
-                    return Err(ExecutionError::RuntimeError(format!(
-                        "Cannot create unique constraint: duplicate value {:?} for :{}({})",
-                        val, self.label.as_str(), self.property
-                    )));
-                }
-            }
-        }
-
-        // Create the constraint
-        store.property_index.create_unique_constraint(self.label.clone(), self.property.clone());
-
-        // Backfill constraint index
-        let mut entries = Vec::new();
-        let nodes = store.get_nodes_by_label(&self.label);
-        for node in nodes {
-            if let Some(val) = node.get_property(&self.property) {
-                entries.push((node.id, val.clone()));
-            }
-        }
-        for (node_id, val) in entries {
-            store.property_index.constraint_insert(&self.label, &self.property, val, node_id);
-        }
+        // Validates existing nodes, then registers (and for UNIQUE, backfills) the constraint
+        store.create_constraint(self.label.clone(), self.property.clone(), self.kind)
+            .map_err(|e| ExecutionError::GraphError(e.to_string()))?;
 
         self.executed = true;
         Ok(Some(Record::new()))
@@ -5507,22 +5815,25 @@ impl PhysicalOperator for CreateConstraintOperator {
     fn describe(&self) -> OperatorDescription {
         OperatorDescription {
             name: "CreateConstraint".to_string(),
-            details: format!("UNIQUE :{}({})", self.label.as_str(), self.property),
+            details: format!("{} :{}({})", self.kind.as_str(), self.label.as_str(), self.property),
             children: Vec::new(),
         }
     }
 }
 
 /// Drop index operator: DROP INDEX ON :Label(property)
+///
+/// With several properties it drops the composite index on them; the
+/// per-property indexes created alongside it stay until dropped one by one.
 pub struct DropIndexOperator {
     label: Label,
-    property: String,
+    properties: Vec<String>,
     executed: bool,
 }
 
 impl DropIndexOperator {
-    pub fn new(label: Label, property: String) -> Self {
-        Self { label, property, executed: false }
+    pub fn new(label: Label, properties: Vec<String>) -> Self {
+        Self { label, properties, executed: false }
     }
 }
 
@@ -5538,13 +5849,19 @@ impl PhysicalOperator for DropIndexOperator {
             return Ok(None);
         }
 
-        if !store.property_index.has_index(&self.label, &self.property) {
+        let dropped = match self.properties.as_slice() {
+            [property] => {
+                let exists = store.property_index.has_index(&self.label, property);
+                store.property_index.drop_index(&self.label, property);
+                exists
+            }
+            properties => store.property_index.drop_composite_index(&self.label, properties),
+        };
+        if !dropped {
             return Err(ExecutionError::RuntimeError(
-                format!("Index on :{}({}) does not exist", self.label.as_str(), self.property)
+                format!("Index on :{}({}) does not exist", self.label.as_str(), self.properties.join(", "))
             ));
         }
-
-        store.property_index.drop_index(&self.label, &self.property);
         self.executed = true;
         Ok(Some(Record::new()))
     }
@@ -5560,7 +5877,7 @@ impl PhysicalOperator for DropIndexOperator {
     fn describe(&self) -> OperatorDescription {
         OperatorDescription {
             name: "DropIndex".to_string(),
-            details: format!(":{}({})", self.label.as_str(), self.property),
+            details: format!(":{}({})", self.label.as_str(), self.properties.join(", ")),
             children: Vec::new(),
         }
     }
@@ -5589,6 +5906,13 @@ impl PhysicalOperator for ShowIndexesOperator {
                 record.bind("type".to_string(), Value::Property(PropertyValue::String("BTREE".to_string())));
                 records.push(record);
             }
+            for (label, properties) in store.property_index.list_composite_indexes() {
+                let mut record = Record::new();
+                record.bind("label".to_string(), Value::Property(PropertyValue::String(label.as_str().to_string())));
+                record.bind("property".to_string(), Value::Property(PropertyValue::String(properties.join(", "))));
+                record.bind("type".to_string(), Value::Property(PropertyValue::String("COMPOSITE".to_string())));
+                records.push(record);
+            }
             self.results = Some(records.into_iter());
         }
 
@@ -5624,11 +5948,11 @@ impl PhysicalOperator for ShowConstraintsOperator {
         if self.results.is_none() {
             let constraints = store.property_index.list_constraints();
             let mut records = Vec::new();
-            for (label, property) in constraints {
+            for (label, property, kind) in constraints {
                 let mut record = Record::new();
                 record.bind("label".to_string(), Value::Property(PropertyValue::String(label.as_str().to_string())));
                 record.bind("property".to_string(), Value::Property(PropertyValue::String(property)));
-                record.bind("type".to_string(), Value::Property(PropertyValue::String("UNIQUE".to_string())));
+                record.bind("type".to_string(), Value::Property(PropertyValue::String(kind.as_str().to_string())));
                 records.push(record);
             }
             self.results = Some(records.into_iter());
@@ -5744,21 +6068,8 @@ impl ShowPropertyKeysOperator {
 impl PhysicalOperator for ShowPropertyKeysOperator {
     fn next(&mut self, store: &GraphStore) -> ExecutionResult<Option<Record>> {
         if self.results.is_none() {
-            let mut keys = std::collections::BTreeSet::new();
-            let stats = store.statistics();
-            for ((_, prop), _) in &stats.property_stats {
-                keys.insert(prop.clone());
-            }
-            for edge_type in store.all_edge_types() {
-                let edges = store.get_edges_by_type(edge_type);
-                for edge in edges.iter().take(1000) {
-                    for key in edge.properties.keys() {
-                        keys.insert(key.clone());
-                    }
-                }
-            }
             let mut records = Vec::new();
-            for key in keys {
+            for key in store.property_keys() {
                 let mut record = Record::new();
                 record.bind("propertyKey".to_string(), Value::Property(PropertyValue::String(key)));
                 records.push(record);
@@ -6324,8 +6635,22 @@ impl AlgorithmOperator {
         }
     }
 
+    /// Edge direction for the algorithm's view: `Undirected` when a config map
+    /// argument sets `directed: false`, otherwise `Directed`
+    fn directedness(&self) -> crate::algo::Directedness {
+        let undirected = self.args.iter().any(|arg| {
+            matches!(arg, Expression::Literal(PropertyValue::Map(m))
+                if matches!(m.get("directed"), Some(PropertyValue::Boolean(false))))
+        });
+        if undirected {
+            crate::algo::Directedness::Undirected
+        } else {
+            crate::algo::Directedness::Directed
+        }
+    }
+
     fn execute_pagerank(&mut self, store: &GraphStore) -> ExecutionResult<()> {
-        // Arguments: (label?, edge_type?, config_map?)
+        // Arguments: (label?, edge_type?, config_map?), config keys: iterations, damping, directed
         let mut label = None;
         let mut edge_type = None;
         let mut config = crate::algo::PageRankConfig::default();
@@ -6354,7 +6679,7 @@ impl AlgorithmOperator {
         }
 
         // Build view and run
-        let view = crate::algo::build_view(store, label.as_deref(), edge_type.as_deref(), None);
+        let view = crate::algo::build_view(store, label.as_deref(), edge_type.as_deref(), None, self.directedness());
         let scores = crate::algo::page_rank(&view, config);
 
         // Convert to records
@@ -6404,7 +6729,7 @@ impl AlgorithmOperator {
         }
         
         // Build view
-        let view = crate::algo::build_view(store, None, None, weight_prop.as_deref());
+        let view = crate::algo::build_view(store, None, None, weight_prop.as_deref(), self.directedness());
         
         // Run Algorithm
         let result = if weight_prop.is_some() {
@@ -6447,7 +6772,7 @@ impl AlgorithmOperator {
         }
 
         // Build view and run WCC
-        let view = crate::algo::build_view(store, label.as_deref(), edge_type.as_deref(), None);
+        let view = crate::algo::build_view(store, label.as_deref(), edge_type.as_deref(), None, self.directedness());
         let result = crate::algo::weakly_connected_components(&view);
 
         // Convert to records
@@ -6500,7 +6825,7 @@ impl AlgorithmOperator {
             }
         }
 
-        let view = crate::algo::build_view(store, label.as_deref(), edge_type.as_deref(), None);
+        let view = crate::algo::build_view(store, label.as_deref(), edge_type.as_deref(), None, self.directedness());
         let result = crate::algo::cdlp(&view, &config);
 
         for (node_id, community_id) in result.labels {
@@ -6546,7 +6871,7 @@ impl AlgorithmOperator {
             }
         }
 
-        let view = crate::algo::build_view(store, label.as_deref(), edge_type.as_deref(), None);
+        let view = crate::algo::build_view(store, label.as_deref(), edge_type.as_deref(), None, self.directedness());
         let result = crate::algo::local_clustering_coefficient(&view);
 
         for (node_id, coeff) in result.coefficients {
@@ -6594,7 +6919,7 @@ impl AlgorithmOperator {
         };
 
         // Build view with weights
-        let view = crate::algo::build_view(store, None, None, Some(&weight_prop));
+        let view = crate::algo::build_view(store, None, None, Some(&weight_prop), self.directedness());
         
         if let Some(result) = crate::algo::dijkstra(&view, source_id, target_id) {
              let mut record = Record::new();
@@ -6652,6 +6977,7 @@ impl AlgorithmOperator {
         
         let pop_size = config_map.get("population_size").and_then(|v| v.as_integer()).unwrap_or(50) as usize;
         let max_iter = config_map.get("max_iterations").and_then(|v| v.as_integer()).unwrap_or(100) as usize;
+        let seed = config_map.get("seed").and_then(|v| v.as_integer()).map(|s| s as u64);
 
         // 1. Gather nodes and costs
         let label = Label::new(label_str);
@@ -6698,6 +7024,8 @@ impl AlgorithmOperator {
         let solver_config = SolverConfig {
             population_size: pop_size,
             max_iterations: max_iter,
+            seed,
+            ..Default::default()
         };
 
         // 3. Run Solver
@@ -6791,7 +7119,7 @@ impl AlgorithmOperator {
         };
 
         // Build view
-        let view = crate::algo::build_view(store, None, None, cap_prop.as_deref());
+        let view = crate::algo::build_view(store, None, None, cap_prop.as_deref(), self.directedness());
         
         // edmonds_karp expects u64 (AlgoNodeId), not crate::graph::NodeId
         if let Some(result) = crate::algo::edmonds_karp(&view, source_id, target_id) {
@@ -6819,7 +7147,7 @@ impl AlgorithmOperator {
             None
         };
 
-        let view = crate::algo::build_view(store, None, None, weight_prop.as_deref());
+        let view = crate::algo::build_view(store, None, None, weight_prop.as_deref(), self.directedness());
         let result = crate::algo::prim_mst(&view);
 
         // Return total weight
@@ -6848,7 +7176,7 @@ impl AlgorithmOperator {
 
     fn execute_triangle_count(&mut self, store: &GraphStore) -> ExecutionResult<()> {
         // Build view (undirected treatment is handled in the algorithm)
-        let view = crate::algo::build_view(store, None, None, None);
+        let view = crate::algo::build_view(store, None, None, None, self.directedness());
         let count = crate::algo::count_triangles(&view);
 
         let mut record = Record::new();
@@ -6860,7 +7188,7 @@ impl AlgorithmOperator {
 
     fn execute_scc(&mut self, store: &GraphStore) -> ExecutionResult<()> {
         // Build view and run SCC
-        let view = crate::algo::build_view(store, None, None, None);
+        let view = crate::algo::build_view(store, None, None, None, self.directedness());
         let result = crate::algo::strongly_connected_components(&view);
 
         // For SCC, we return (node, componentId)
@@ -6983,23 +7311,15 @@ impl PhysicalOperator for SkipOperator {
 
     fn next_batch(&mut self, store: &GraphStore, batch_size: usize) -> ExecutionResult<Option<RecordBatch>> {
         while self.skipped < self.skip {
-            if let Some(batch) = self.input.next_batch(store, batch_size)? {
-                for record in batch.records {
-                    self.skipped += 1;
-                    if self.skipped >= self.skip {
-                        // We may have extra records in this batch — collect remaining
-                        let mut remaining = vec![record];
-                        // Continue pulling from current batch not possible since we consumed it,
-                        // but we've finished skipping
-                        break;
-                    }
-                }
-                if self.skipped >= self.skip {
-                    // Start fresh from next batch
-                    break;
-                }
-            } else {
+            let Some(mut batch) = self.input.next_batch(store, batch_size)? else {
                 return Ok(None);
+            };
+            let drop = (self.skip - self.skipped).min(batch.records.len());
+            self.skipped += drop;
+            if drop < batch.records.len() {
+                // Finished skipping part-way through this batch; return the rest of it
+                batch.records.drain(..drop);
+                return Ok(Some(batch));
             }
         }
         self.input.next_batch(store, batch_size)
@@ -7019,6 +7339,82 @@ impl PhysicalOperator for SkipOperator {
     }
 }
 
+/// Sample operator: SAMPLE k
+///
+/// Reservoir sampling (Algorithm R) over the input in a single pass: the first
+/// k records fill the reservoir, and the i-th record after that replaces a
+/// random slot with probability k/i. Every input record ends up in the output
+/// with equal probability, and memory stays bounded by k however large the
+/// input is. Emits min(k, n) records once the input is exhausted.
+pub struct SampleOperator {
+    input: OperatorBox,
+    size: usize,
+    reservoir: Vec<Record>,
+    current: usize,
+    executed: bool,
+}
+
+impl SampleOperator {
+    pub fn new(input: OperatorBox, size: usize) -> Self {
+        Self {
+            input,
+            size,
+            reservoir: Vec::new(),
+            current: 0,
+            executed: false,
+        }
+    }
+
+    fn fill(&mut self, store: &GraphStore) -> ExecutionResult<()> {
+        use rand::Rng;
+        let mut rng = rand::thread_rng();
+        let mut seen = 0usize;
+        self.reservoir = Vec::with_capacity(self.size.min(1024));
+        while let Some(record) = self.input.next(store)? {
+            seen += 1;
+            if self.reservoir.len() < self.size {
+                self.reservoir.push(record);
+            } else {
+                let slot = rng.gen_range(0..seen);
+                if slot < self.size {
+                    self.reservoir[slot] = record;
+                }
+            }
+        }
+        self.executed = true;
+        Ok(())
+    }
+}
+
+impl PhysicalOperator for SampleOperator {
+    fn next(&mut self, store: &GraphStore) -> ExecutionResult<Option<Record>> {
+        if !self.executed {
+            self.fill(store)?;
+        }
+        if self.current >= self.reservoir.len() {
+            return Ok(None);
+        }
+        let record = std::mem::take(&mut self.reservoir[self.current]);
+        self.current += 1;
+        Ok(Some(record))
+    }
+
+    fn reset(&mut self) {
+        self.input.reset();
+        self.reservoir.clear();
+        self.current = 0;
+        self.executed = false;
+    }
+
+    fn describe(&self) -> OperatorDescription {
+        OperatorDescription {
+            name: "Sample".to_string(),
+            details: format!("{}", self.size),
+            children: vec![self.input.describe()],
+        }
+    }
+}
+
 /// Delete operator: DELETE n or DETACH DELETE n
 pub struct DeleteOperator {
     input: OperatorBox,
@@ -7128,7 +7524,7 @@ impl PhysicalOperator for SetPropertyOperator {
                 if let Some(node_val) = record.get(var) {
                     match node_val {
                         Value::NodeRef(id) | Value::Node(id, _) => {
-                            let _ = store.set_node_property(tenant_id, *id, prop.clone(), val.clone());
+                            enforce_constraints(store.set_node_property(tenant_id, *id, prop.clone(), val.clone()))?;
                         }
                         Value::EdgeRef(id, ..) | Value::Edge(id, _) => {
                             let _ = store.set_edge_property(*id, prop.clone(), val.clone());
@@ -7186,14 +7582,10 @@ impl PhysicalOperator for RemovePropertyOperator {
                 if let Some(node_val) = record.get(var) {
                     match node_val {
                         Value::NodeRef(id) | Value::Node(id, _) => {
-                            if let Some(node) = store.get_node_mut(*id) {
-                                node.remove_property(prop);
-                            }
+                            enforce_constraints(store.remove_node_property(tenant_id, *id, prop))?;
                         }
                         Value::EdgeRef(id, ..) | Value::Edge(id, _) => {
-                            if let Some(props) = store.get_edge_properties_mut(*id) {
-                                props.remove(prop);
-                            }
+                            store.remove_edge_property(*id, prop);
                         }
                         _ => {}
                     }
@@ -7370,25 +7762,13 @@ impl PhysicalOperator for MergeOperator {
                 if var == &start_var {
                     let val = eval_expression(expr, &record, store)?;
                     if let Value::Property(pv) = val {
-                        let _ = store.set_node_property(tenant_id, node_id, prop.clone(), pv);
+                        enforce_constraints(store.set_node_property(tenant_id, node_id, prop.clone(), pv))?;
                     }
                 }
             }
         } else {
-            let label_str = labels.first().map(|l| l.as_str()).unwrap_or("Node");
-            node_id = store.create_node(label_str);
-
-            for label in labels.iter().skip(1) {
-                if let Some(node) = store.get_node_mut(node_id) {
-                    node.labels.insert(label.clone());
-                }
-            }
-
-            if let Some(required_props) = props {
-                for (k, v) in required_props {
-                    let _ = store.set_node_property(tenant_id, node_id, k.clone(), v.clone());
-                }
-            }
+            let primary = labels.first().cloned().unwrap_or_else(|| Label::new("Node"));
+            node_id = create_pattern_node(store, tenant_id, primary, labels.get(1..).unwrap_or(&[]), props.into_iter().flatten())?;
 
             record.bind(start_var.clone(), Value::NodeRef(node_id));
 
@@ -7396,10 +7776,15 @@ impl PhysicalOperator for MergeOperator {
                 if var == &start_var {
                     let val = eval_expression(expr, &record, store)?;
                     if let Value::Property(pv) = val {
-                        let _ = store.set_node_property(tenant_id, node_id, prop.clone(), pv);
+                        if let Err(e) = enforce_constraints(store.set_node_property(tenant_id, node_id, prop.clone(), pv)) {
+                            let _ = store.delete_node(tenant_id, node_id);
+                            return Err(e);
+                        }
                     }
                 }
             }
+            // ON CREATE SET may supply NOT NULL properties, so check the finished node
+            check_new_node(store, tenant_id, node_id)?;
         }
 
         Ok(Some(record))
@@ -7475,7 +7860,7 @@ impl PhysicalOperator for ForeachOperator {
                     if let Some(node_val) = inner_record.get(var) {
                         match node_val {
                             Value::NodeRef(id) | Value::Node(id, _) => {
-                                let _ = store.set_node_property(tenant_id, *id, prop.to_string(), prop_val.clone());
+                                enforce_constraints(store.set_node_property(tenant_id, *id, prop.to_string(), prop_val.clone()))?;
                             }
                             Value::EdgeRef(id, ..) | Value::Edge(id, _) => {
                                 let _ = store.set_edge_property(*id, prop.to_string(), prop_val.clone());
@@ -7488,15 +7873,12 @@ impl PhysicalOperator for ForeachOperator {
                 // Execute CREATE operations
                 for pattern in &self.create_patterns {
                     for path in &pattern.paths {
-                        let label_str = path.start.labels.first()
-                            .map(|l| l.as_str())
-                            .unwrap_or("Node");
-                        let node_id = store.create_node(label_str);
-                        if let Some(props) = &path.start.properties {
-                            for (k, v) in props {
-                                let _ = store.set_node_property(tenant_id, node_id, k.to_string(), v.clone());
-                            }
-                        }
+                        let labels = &path.start.labels;
+                        let primary = labels.first().cloned().unwrap_or_else(|| Label::new("Node"));
+                        let node_id = create_pattern_node(
+                            store, tenant_id, primary, labels.get(1..).unwrap_or(&[]), path.start.properties.iter().flatten(),
+                        )?;
+                        check_new_node(store, tenant_id, node_id)?;
                     }
                 }
             }
@@ -7571,7 +7953,7 @@ impl ShortestPathOperator {
                 .ok_or_else(|| ExecutionError::RuntimeError("shortestPath target not a node".to_string()))?;
 
             // BFS to find shortest path(s)
-            let paths = self.bfs_shortest(store, source_id, target_id);
+            let paths = self.bfs_shortest(store, source_id, target_id)?;
 
             if self.all_paths {
                 for path in paths {
@@ -7601,11 +7983,11 @@ impl ShortestPathOperator {
         Ok(())
     }
 
-    fn bfs_shortest(&self, store: &GraphStore, source: NodeId, target: NodeId) -> Vec<(Vec<NodeId>, Vec<crate::graph::EdgeId>)> {
+    fn bfs_shortest(&self, store: &GraphStore, source: NodeId, target: NodeId) -> ExecutionResult<Vec<(Vec<NodeId>, Vec<crate::graph::EdgeId>)>> {
         use std::collections::VecDeque;
 
         if source == target {
-            return vec![(vec![source], vec![])];
+            return Ok(vec![(vec![source], vec![])]);
         }
 
         let mut queue: VecDeque<(NodeId, Vec<NodeId>, Vec<crate::graph::EdgeId>)> = VecDeque::new();
@@ -7616,7 +7998,10 @@ impl ShortestPathOperator {
         queue.push_back((source, vec![source], vec![]));
         visited.insert(source);
 
+        let mut popped = 0usize;
         while let Some((current, path_nodes, path_edges)) = queue.pop_front() {
+            popped += 1;
+            if popped.is_multiple_of(1024) { check_deadline()?; }
             if let Some(max_dist) = found_distance {
                 if path_nodes.len() > max_dist {
                     break;
@@ -7628,7 +8013,7 @@ impl ShortestPathOperator {
                 Direction::Incoming => store.get_incoming_edges(current),
                 Direction::Both => {
                     let mut all = store.get_outgoing_edges(current);
-                    all.extend(store.get_incoming_edges(current));
+                    all.extend(store.get_incoming_edges(current).into_iter().filter(|e| e.source != e.target));
                     all
                 }
             };
@@ -7651,7 +8036,7 @@ impl ShortestPathOperator {
                     results.push((new_nodes, new_edges));
 
                     if !self.all_paths {
-                        return results;
+                        return Ok(results);
                     }
                     continue;
                 }
@@ -7669,7 +8054,7 @@ impl ShortestPathOperator {
             }
         }
 
-        results
+        Ok(results)
     }
 }
 
@@ -8284,20 +8669,19 @@ mod tests {
     }
 
     #[test]
-    fn test_node_scan_multi_label_dedup_and_limit() {
-        // Multi-label scan must dedup across labels (a node with both labels
-        // counts once) and still respect early_limit.
+    fn test_node_scan_multi_label_intersection_and_limit() {
+        // Multi-label scan only yields nodes carrying every label, and still
+        // respects early_limit.
         let mut store = GraphStore::new();
         for _ in 0..50 {
             let id = store.create_node("Person");
             // Add second label to half — ensures overlap
             if id.as_u64() % 2 == 0 {
-                if let Some(node) = store.get_node_mut(id) {
-                    node.labels.insert(Label::new("Adult"));
-                }
+                store.add_label_to_node("default", id, "Adult").unwrap();
             }
         }
-        // Without limit: 50 unique nodes (the Adults are also Persons)
+        store.create_node("Adult");
+        // Without limit: only the 25 Person+Adult nodes
         let mut op = NodeScanOperator::new(
             "n".to_string(),
             vec![Label::new("Person"), Label::new("Adult")],
@@ -8306,7 +8690,7 @@ mod tests {
         while let Ok(Some(_)) = op.next(&store) {
             count += 1;
         }
-        assert_eq!(count, 50, "multi-label union should dedup");
+        assert_eq!(count, 25, "multi-label scan should intersect");
 
         // With early_limit: capped at limit
         let mut op = NodeScanOperator::new(
@@ -8372,6 +8756,55 @@ mod tests {
         assert_eq!(count, 3);
     }
 
+    #[test]
+    fn test_sample_operator_size() {
+        let mut store = GraphStore::new();
+        for _ in 0..100 {
+            store.create_node("Person");
+        }
+
+        let scan = NodeScanOperator::new("n".to_string(), vec![Label::new("Person")]);
+        let mut sample = SampleOperator::new(Box::new(scan), 10);
+        let mut ids = HashSet::new();
+        while let Some(record) = sample.next(&store).unwrap() {
+            ids.insert(record.get("n").unwrap().node_id().unwrap());
+        }
+        assert_eq!(ids.len(), 10, "expected 10 distinct records");
+
+        // Fewer input records than k: everything comes back
+        let scan = NodeScanOperator::new("n".to_string(), vec![Label::new("Person")]);
+        let mut sample = SampleOperator::new(Box::new(scan), 500);
+        let batch = sample.next_batch(&store, 1000).unwrap().unwrap();
+        assert_eq!(batch.len(), 100);
+
+        sample.reset();
+        assert_eq!(sample.next_batch(&store, 1000).unwrap().unwrap().len(), 100);
+    }
+
+    #[test]
+    fn test_sample_operator_uniform() {
+        let mut store = GraphStore::new();
+        let nodes: Vec<NodeId> = (0..10).map(|_| store.create_node("Person")).collect();
+
+        let runs = 20_000;
+        let mut hits: HashMap<NodeId, usize> = HashMap::new();
+        let scan = NodeScanOperator::new("n".to_string(), vec![Label::new("Person")]);
+        let mut sample = SampleOperator::new(Box::new(scan), 3);
+        for _ in 0..runs {
+            sample.reset();
+            while let Some(record) = sample.next(&store).unwrap() {
+                *hits.entry(record.get("n").unwrap().node_id().unwrap()).or_default() += 1;
+            }
+        }
+
+        // Each node is picked with probability 3/10: 6000 expected hits,
+        // standard deviation ~65, so +/-600 only fails on a broken sampler.
+        for id in nodes {
+            let count = hits.get(&id).copied().unwrap_or(0);
+            assert!((5400..=6600).contains(&count), "node {:?} sampled {} times", id, count);
+        }
+    }
+
     #[test]
     fn test_node_scan_batch() {
         let mut store = GraphStore::new();
@@ -8396,6 +8829,24 @@ mod tests {
         assert!(batch4.is_none());
     }
 
+    #[test]
+    fn test_project_keeps_references_for_reads() {
+        let mut store = GraphStore::new();
+        let id = store.create_node("Person");
+        store.set_node_property("default", id, "name", "Alice").unwrap();
+        let projection = || vec![(Expression::Variable("n".to_string()), "n".to_string())];
+
+        let scan = NodeScanOperator::new("n".to_string(), vec![Label::new("Person")]);
+        let mut project = ProjectOperator::new(Box::new(scan), projection());
+        let record = project.next(&store).unwrap().unwrap();
+        assert!(matches!(record.get("n"), Some(Value::NodeRef(n)) if *n == id));
+
+        let scan = NodeScanOperator::new("n".to_string(), vec![Label::new("Person")]);
+        let mut project = ProjectOperator::new(Box::new(scan), projection());
+        let record = project.next_mut(&mut store, "default").unwrap().unwrap();
+        assert!(matches!(record.get("n"), Some(Value::Node(n, _)) if *n == id));
+    }
+
     #[test]
     fn test_project_batch() {
         let mut store = GraphStore::new();
@@ -9383,6 +9834,61 @@ mod tests {
 
     // ========== eval_binary_op tests ==========
 
+    fn int(i: i64) -> Value {
+        Value::Property(PropertyValue::Integer(i))
+    }
+
+    #[test]
+    fn test_binary_op_arithmetic_null_propagates() {
+        let null = Value::Property(PropertyValue::Null);
+        for op in [BinaryOp::Add, BinaryOp::Sub, BinaryOp::Mul, BinaryOp::Div, BinaryOp::Mod] {
+            assert_eq!(eval_binary_op(&op, null.clone(), int(2)).unwrap(), null);
+            assert_eq!(eval_binary_op(&op, int(2), Value::Null).unwrap(), null);
+        }
+        // Null wins over division by zero.
+        assert_eq!(eval_binary_op(&BinaryOp::Div, null.clone(), int(0)).unwrap(), null);
+        assert_eq!(eval_unary_op(&UnaryOp::Minus, Value::Null).unwrap(), null);
+    }
+
+    #[test]
+    fn test_binary_op_string_concat_coerces_scalars() {
+        let s = |v: &str| Value::Property(PropertyValue::String(v.to_string()));
+        assert_eq!(eval_binary_op(&BinaryOp::Add, s("Ada"), s(" Lovelace")).unwrap(), s("Ada Lovelace"));
+        assert_eq!(eval_binary_op(&BinaryOp::Add, s("v"), int(2)).unwrap(), s("v2"));
+        assert_eq!(eval_binary_op(&BinaryOp::Add, int(2), s("x")).unwrap(), s("2x"));
+        assert_eq!(
+            eval_binary_op(&BinaryOp::Add, s("w="), Value::Property(PropertyValue::Float(1.0))).unwrap(),
+            s("w=1.0")
+        );
+        assert_eq!(
+            eval_binary_op(&BinaryOp::Add, s("ok: "), Value::Property(PropertyValue::Boolean(true))).unwrap(),
+            s("ok: true")
+        );
+        assert_eq!(eval_binary_op(&BinaryOp::Add, s("x"), Value::Null).unwrap(), Value::Property(PropertyValue::Null));
+        assert!(eval_binary_op(&BinaryOp::Add, s("x"), Value::Property(PropertyValue::Array(vec![]))).is_err());
+    }
+
+    #[test]
+    fn test_binary_op_division_by_zero() {
+        // Integer division/modulo by zero is an error; float division follows IEEE 754.
+        assert!(eval_binary_op(&BinaryOp::Div, int(1), int(0)).is_err());
+        assert!(eval_binary_op(&BinaryOp::Mod, int(1), int(0)).is_err());
+        let result = eval_binary_op(&BinaryOp::Div,
+            Value::Property(PropertyValue::Float(1.5)),
+            int(0),
+        ).unwrap();
+        assert_eq!(result, Value::Property(PropertyValue::Float(f64::INFINITY)));
+    }
+
+    #[test]
+    fn test_binary_op_integer_overflow_is_error() {
+        assert!(eval_binary_op(&BinaryOp::Add, int(i64::MAX), int(1)).is_err());
+        assert!(eval_binary_op(&BinaryOp::Sub, int(i64::MIN), int(1)).is_err());
+        assert!(eval_binary_op(&BinaryOp::Mul, int(i64::MAX), int(2)).is_err());
+        assert!(eval_binary_op(&BinaryOp::Div, int(i64::MIN), int(-1)).is_err());
+        assert!(eval_unary_op(&UnaryOp::Minus, int(i64::MIN)).is_err());
+    }
+
     #[test]
     fn test_binary_op_mod_int() {
         let result = eval_binary_op(&BinaryOp::Mod,
@@ -62,7 +62,7 @@ use std::sync::Mutex;
 use crate::query::executor::{
     ExecutionError, ExecutionResult, OperatorBox,
     // Added CreateNodeOperator and CreateNodesAndEdgesOperator for CREATE statement support
-    operator::{NodeScanOperator, FilterOperator, ExpandOperator, ProjectOperator, LimitOperator, SkipOperator, CreateNodeOperator, CreateNodesAndEdgesOperator, CartesianProductOperator, VectorSearchOperator, JoinOperator, LeftOuterJoinOperator, CreateVectorIndexOperator, CreateIndexOperator, CompositeCreateIndexOperator, CreateConstraintOperator, DropIndexOperator, ShowIndexesOperator, ShowConstraintsOperator, ShowLabelsOperator, ShowRelationshipTypesOperator, ShowPropertyKeysOperator, SchemaVisualizationOperator, AlgorithmOperator, IndexScanOperator, AggregateOperator, AggregateType, AggregateFunction, SortOperator, DeleteOperator, SetPropertyOperator, RemovePropertyOperator, UnwindOperator, MergeOperator, ForeachOperator, ShortestPathOperator, VarLengthExpandOperator, WithBarrierOperator, LabelCountOperator, EdgeTypeCountOperator},
+    operator::{NodeScanOperator, FilterOperator, ExpandOperator, ProjectOperator, LimitOperator, SkipOperator, SampleOperator, CreateNodeOperator, CreateNodesAndEdgesOperator, CartesianProductOperator, VectorSearchOperator, JoinOperator, LeftOuterJoinOperator, CreateVectorIndexOperator, CreateIndexOperator, CompositeCreateIndexOperator, CreateConstraintOperator, DropIndexOperator, ShowIndexesOperator, ShowConstraintsOperator, ShowLabelsOperator, ShowRelationshipTypesOperator, ShowPropertyKeysOperator, SchemaVisualizationOperator, AlgorithmOperator, IndexScanOperator, CompositeIndexScanOperator, AggregateOperator, AggregateType, AggregateFunction, SortOperator, DeleteOperator, SetPropertyOperator, RemovePropertyOperator, UnwindOperator, MergeOperator, ForeachOperator, ShortestPathOperator, VarLengthExpandOperator, WithBarrierOperator, LabelCountOperator, EdgeTypeCountOperator},
 };
 use crate::graph::EdgeType;  // Added for CREATE edge support
 use std::collections::{HashMap, HashSet};  // Added for CREATE properties and JOIN logic
@@ -314,6 +314,7 @@ impl QueryPlanner {
                 root: Box::new(CreateConstraintOperator::new(
                     clause.label.clone(),
                     clause.property.clone(),
+                    clause.kind,
                 )),
                 output_columns: vec![],
                 is_write: true, candidates_evaluated: 0, chosen_plan_cost: 0.0, candidate_costs: Vec::new(),
@@ -325,7 +326,9 @@ impl QueryPlanner {
             return Ok(ExecutionPlan {
                 root: Box::new(DropIndexOperator::new(
                     clause.label.clone(),
-                    clause.property.clone(),
+                    std::iter::once(clause.property.clone())
+                        .chain(clause.additional_properties.iter().cloned())
+                        .collect(),
                 )),
                 output_columns: vec![],
                 is_write: true, candidates_evaluated: 0, chosen_plan_cost: 0.0, candidate_costs: Vec::new(),
@@ -1094,18 +1097,11 @@ impl QueryPlanner {
             }
 
             // Label count cache: O(1) shortcut for MATCH (n:Label) RETURN count(n)
-            // Detect: single count aggregate, no group-by, no WHERE, no edges, single MATCH
-            let use_label_count = has_aggregation
-                && aggregates.len() == 1
-                && group_by.is_empty()
-                && matches!(aggregates[0].func, AggregateType::Count)
-                && !aggregates[0].distinct
-                && query.where_clause.is_none()
-                && query.with_clause.is_none()
-                && query.match_clauses.len() == 1
-                && query.match_clauses[0].pattern.paths.len() == 1
-                && query.match_clauses[0].pattern.paths[0].segments.is_empty()
-                && !query.match_clauses[0].pattern.paths[0].start.labels.is_empty();
+            let label_count_labels = if has_aggregation && group_by.is_empty() {
+                label_count_shortcut(query, &aggregates)
+            } else {
+                None
+            };
 
             // Edge type count cache: O(1) shortcut for MATCH ()-[r]->() RETURN type(r), count(r)
             // Detect: one count aggregate, one group-by with type() function, single edge path, no WHERE
@@ -1136,11 +1132,7 @@ impl QueryPlanner {
                         .map(|i| (i.expression.clone(), i.ascending)).collect();
                     operator = Box::new(SortOperator::new(operator, sort_items));
                 }
-            } else if use_label_count {
-                let labels = query.match_clauses[0].pattern.paths[0]
-                    .start
-                    .labels
-                    .clone();
+            } else if let Some(labels) = label_count_labels {
                 let alias = aggregates[0].alias.clone();
                 operator = Box::new(LabelCountOperator::new(labels, alias));
                 // Apply post-projection to map __agg_0 -> user alias
@@ -1189,6 +1181,10 @@ impl QueryPlanner {
                 for item in &call_clause.yield_items {
                     output_columns.push(item.alias.clone().unwrap_or_else(|| item.name.clone()));
                 }
+                // A bare `CALL db.labels()` returns the procedure's own columns
+                if call_clause.yield_items.is_empty() {
+                    output_columns.extend(Self::procedure_columns(&call_clause.procedure_name).iter().map(|c| c.to_string()));
+                }
             }
         }
 
@@ -1215,6 +1211,11 @@ impl QueryPlanner {
             operator.try_push_limit(push_n);
         }
 
+        // Add SAMPLE if present: it draws from whatever survives SKIP/LIMIT
+        if let Some(k) = query.sample {
+            operator = Box::new(SampleOperator::new(operator, k));
+        }
+
         // QP-01: Predicate pushdown is handled inline during plan_match() via AND-chain decomposition
         // QP-02: Cost-based plan selection uses GraphStatistics to pick indexes over scans
         // QP-04: Early LIMIT propagation — done when NodeScanOperator gets early_limit set
@@ -1227,6 +1228,16 @@ impl QueryPlanner {
         })
     }
 
+    /// Result columns of the schema procedures, used when CALL has no YIELD
+    fn procedure_columns(procedure_name: &str) -> &'static [&'static str] {
+        match procedure_name {
+            "db.labels" => &["label"],
+            "db.relationshipTypes" => &["relationshipType"],
+            "db.propertyKeys" => &["propertyKey"],
+            _ => &[],
+        }
+    }
+
     fn plan_call(&self, call_clause: &CallClause) -> ExecutionResult<OperatorBox> {
         if call_clause.procedure_name == "db.index.vector.queryNodes" {
             // CALL db.index.vector.queryNodes(label, property, vector, k) YIELD node, score
@@ -1322,6 +1333,17 @@ impl QueryPlanner {
         }
 
         let pg = PatternGraph::from_match_clause(match_clause);
+        // LabelScan carries a single label; conjunctive `(n:A:B)` patterns go to the legacy planner
+        if pg.nodes.values().any(|n| n.labels.len() > 1) {
+            return Err(ExecutionError::PlanningError("Multi-label node patterns not supported".to_string()));
+        }
+        // PatternEdge has no property map; inline relationship properties are filtered by the legacy Expand
+        let edge_props = pattern.paths.iter()
+            .flat_map(|p| &p.segments)
+            .any(|seg| seg.edge.properties.as_ref().is_some_and(|m| !m.is_empty()));
+        if edge_props {
+            return Err(ExecutionError::PlanningError("Relationship property maps not supported".to_string()));
+        }
         let catalog = store.catalog();
         let config = EnumerationConfig {
             max_candidate_plans: self.config.max_candidate_plans,
@@ -1483,11 +1505,27 @@ impl QueryPlanner {
             // Optimization: Check for index usage (using this path's assigned predicates).
             // Recognizes both `n.prop OP literal` and `literal OP n.prop` operand orders.
             let mut remaining_predicates: Vec<Expression> = per_path_preds[path_idx].clone();
-            let mut path_operator: OperatorBox = if let Some((idx, label, property, op, val)) =
+            let mut path_operator: OperatorBox = if let Some(composite) =
+                find_composite_predicates(&start_var, &path.start.labels, &remaining_predicates, store)
+            {
+                for &i in composite.used.iter().rev() {
+                    remaining_predicates.remove(i);
+                }
+                Box::new(composite.into_operator(start_var.clone(), path.start.labels.clone()))
+            } else if let Some((idx, label, property, op, val)) =
                 find_index_predicate(&start_var, &path.start.labels, &remaining_predicates, store)
             {
-                remaining_predicates.remove(idx);
-                Box::new(IndexScanOperator::new(start_var.clone(), label, property, op, val))
+                let partner = find_range_partner(&start_var, &property, &op, &remaining_predicates, idx);
+                let mut scan = IndexScanOperator::new(start_var.clone(), label, property, op, val)
+                    .with_required_labels(path.start.labels.clone());
+                if let Some((partner_idx, partner_op, partner_val)) = partner {
+                    remaining_predicates.remove(idx.max(partner_idx));
+                    remaining_predicates.remove(idx.min(partner_idx));
+                    scan = scan.with_second_bound(partner_op, partner_val);
+                } else {
+                    remaining_predicates.remove(idx);
+                }
+                Box::new(scan)
             } else {
                 Box::new(NodeScanOperator::new(
                     start_var.clone(),
@@ -1592,6 +1630,9 @@ impl QueryPlanner {
                         if let Some(ref pv) = path.path_variable {
                             expand = expand.with_path_variable(pv.clone());
                         }
+                        if let Some(ref props) = segment.edge.properties {
+                            expand = expand.with_edge_properties(props.clone());
+                        }
                         path_operator = if !segment.node.labels.is_empty() {
                             Box::new(expand.with_target_labels(segment.node.labels.clone()))
                         } else {
@@ -1620,6 +1661,9 @@ impl QueryPlanner {
                     if let Some(ref pv) = path.path_variable {
                         expand = expand.with_path_variable(pv.clone());
                     }
+                    if let Some(ref props) = segment.edge.properties {
+                        expand = expand.with_edge_properties(props.clone());
+                    }
 
                     // Add target label filter if labels specified on target node
                     path_operator = if !segment.node.labels.is_empty() {
@@ -1736,11 +1780,27 @@ impl QueryPlanner {
         }
         candidates.extend(anchor_only_preds);
 
-        let mut path_operator: OperatorBox = if let Some((idx, label, property, op, val)) =
+        let mut path_operator: OperatorBox = if let Some(composite) =
+            find_composite_predicates(&anchor_var, &anchor.labels, &candidates, store)
+        {
+            for &i in composite.used.iter().rev() {
+                candidates.remove(i);
+            }
+            Box::new(composite.into_operator(anchor_var.clone(), anchor.labels.clone()))
+        } else if let Some((idx, label, property, op, val)) =
             find_index_predicate(&anchor_var, &anchor.labels, &candidates, store)
         {
-            candidates.remove(idx);
-            Box::new(IndexScanOperator::new(anchor_var.clone(), label, property, op, val))
+            let partner = find_range_partner(&anchor_var, &property, &op, &candidates, idx);
+            let mut scan = IndexScanOperator::new(anchor_var.clone(), label, property, op, val)
+                .with_required_labels(anchor.labels.clone());
+            if let Some((partner_idx, partner_op, partner_val)) = partner {
+                candidates.remove(idx.max(partner_idx));
+                candidates.remove(idx.min(partner_idx));
+                scan = scan.with_second_bound(partner_op, partner_val);
+            } else {
+                candidates.remove(idx);
+            }
+            Box::new(scan)
         } else {
             Box::new(NodeScanOperator::new(anchor_var.clone(), anchor.labels.clone()))
         };
@@ -1765,7 +1825,10 @@ impl QueryPlanner {
                 Direction::Incoming => Direction::Outgoing,
                 Direction::Both => Direction::Both,
             };
-            let expand = ExpandOperator::new(path_operator, current_var.clone(), target.var.clone(), edge_var, edge_types, reversed_dir);
+            let mut expand = ExpandOperator::new(path_operator, current_var.clone(), target.var.clone(), edge_var, edge_types, reversed_dir);
+            if let Some(ref props) = segment.edge.properties {
+                expand = expand.with_edge_properties(props.clone());
+            }
             path_operator = if !target.labels.is_empty() {
                 Box::new(expand.with_target_labels(target.labels.clone()))
             } else {
@@ -1787,7 +1850,10 @@ impl QueryPlanner {
             let target = &nodes[seg_idx + 1];
             let edge_var = segment.edge.variable.clone();
             let edge_types: Vec<String> = segment.edge.types.iter().map(|t| t.as_str().to_string()).collect();
-            let expand = ExpandOperator::new(path_operator, current_var.clone(), target.var.clone(), edge_var, edge_types, segment.edge.direction.clone());
+            let mut expand = ExpandOperator::new(path_operator, current_var.clone(), target.var.clone(), edge_var, edge_types, segment.edge.direction.clone());
+            if let Some(ref props) = segment.edge.properties {
+                expand = expand.with_edge_properties(props.clone());
+            }
             path_operator = if !target.labels.is_empty() {
                 Box::new(expand.with_target_labels(target.labels.clone()))
             } else {
@@ -2005,6 +2071,9 @@ impl QueryPlanner {
         if let Some(limit) = query.limit {
             operator = Box::new(LimitOperator::new(operator, limit));
         }
+        if let Some(k) = query.sample {
+            operator = Box::new(SampleOperator::new(operator, k));
+        }
 
         Ok(ExecutionPlan {
             root: operator,
@@ -2156,6 +2225,9 @@ impl QueryPlanner {
         if let Some(limit) = query.limit {
             operator = Box::new(LimitOperator::new(operator, limit));
         }
+        if let Some(k) = query.sample {
+            operator = Box::new(SampleOperator::new(operator, k));
+        }
 
         Ok(ExecutionPlan {
             root: operator,
@@ -2285,6 +2357,9 @@ impl QueryPlanner {
         if let Some(limit) = query.limit {
             operator = Box::new(LimitOperator::new(operator, limit));
         }
+        if let Some(k) = query.sample {
+            operator = Box::new(SampleOperator::new(operator, k));
+        }
 
         Ok(ExecutionPlan {
             root: operator,
@@ -2395,6 +2470,47 @@ impl Default for QueryPlanner {
     }
 }
 
+/// Label to count if the query is a bare `MATCH (n:Label) RETURN count(n)` /
+/// `count(*)` that `LabelCountOperator` can answer from the label cardinalities
+/// instead of scanning.
+///
+/// Anything that could drop, duplicate or null out matched nodes disqualifies
+/// it: WHERE, inline properties, relationships, OPTIONAL MATCH, DISTINCT,
+/// counting an expression other than the node itself, or any further clause.
+/// Unlabeled and multi-label patterns take the regular scan: the label index
+/// records neither the intersection of labels nor a live-node total.
+fn label_count_shortcut(query: &Query, aggregates: &[AggregateFunction]) -> Option<Vec<Label>> {
+    let [agg] = aggregates else { return None };
+    let [mc] = query.match_clauses.as_slice() else { return None };
+    let [path] = mc.pattern.paths.as_slice() else { return None };
+    let start = &path.start;
+
+    let counts_rows = match &agg.expr {
+        Expression::Literal(PropertyValue::Integer(1)) => true, // count(*)
+        Expression::Variable(v) => start.variable.as_deref() == Some(v.as_str()),
+        _ => false,
+    };
+    let simple_query = query.where_clause.is_none()
+        && query.with_clause.is_none()
+        && query.unwind_clause.is_none()
+        && query.call_clause.is_none()
+        && query.call_subquery.is_none()
+        && query.create_clause.is_none()
+        && query.merge_clause.is_none()
+        && query.delete_clause.is_none()
+        && query.foreach_clause.is_none()
+        && query.set_clauses.is_empty()
+        && query.remove_clauses.is_empty()
+        && query.union_queries.is_empty();
+    let simple_pattern = !mc.optional
+        && path.segments.is_empty()
+        && start.labels.len() == 1
+        && start.properties.as_ref().is_none_or(|p| p.is_empty());
+
+    (matches!(agg.func, AggregateType::Count) && !agg.distinct && counts_rows && simple_query && simple_pattern)
+        .then(|| start.labels.clone())
+}
+
 /// Propagate node labels and inline properties for variables shared across multiple
 /// MATCH clauses (planned independently and later joined on the shared variable).
 /// If a variable is typed or constrained in one clause but referenced bare (e.g.
@@ -2506,22 +2622,11 @@ fn find_index_predicate(
     store: &GraphStore,
 ) -> Option<(usize, Label, String, BinaryOp, PropertyValue)> {
     for (i, pred) in preds.iter().enumerate() {
-        if let Expression::Binary { left, op, right } = pred {
-            let matched = match (left.as_ref(), right.as_ref()) {
-                (Expression::Property { variable, property }, Expression::Literal(val)) if variable == var => {
-                    Some((property.clone(), op.clone(), val.clone()))
-                }
-                (Expression::Literal(val), Expression::Property { variable, property }) if variable == var => {
-                    Some((property.clone(), flip_comparison_op(op), val.clone()))
-                }
-                _ => None,
-            };
-            if let Some((property, norm_op, val)) = matched {
-                if matches!(norm_op, BinaryOp::Eq | BinaryOp::Gt | BinaryOp::Ge | BinaryOp::Lt | BinaryOp::Le) {
-                    for label in labels {
-                        if store.property_index.has_index(label, &property) {
-                            return Some((i, label.clone(), property, norm_op, val));
-                        }
+        if let Some((property, norm_op, val)) = property_comparison(var, pred) {
+            if matches!(norm_op, BinaryOp::Eq | BinaryOp::Gt | BinaryOp::Ge | BinaryOp::Lt | BinaryOp::Le) {
+                for label in labels {
+                    if store.property_index.has_index(label, &property) {
+                        return Some((i, label.clone(), property, norm_op, val));
                     }
                 }
             }
@@ -2530,6 +2635,115 @@ fn find_index_predicate(
     None
 }
 
+/// Split `var.prop OP literal` (either operand order) into property, normalized
+/// operator, and literal.
+fn property_comparison(var: &str, pred: &Expression) -> Option<(String, BinaryOp, PropertyValue)> {
+    let Expression::Binary { left, op, right } = pred else {
+        return None;
+    };
+    match (left.as_ref(), right.as_ref()) {
+        (Expression::Property { variable, property }, Expression::Literal(val)) if variable == var => {
+            Some((property.clone(), op.clone(), val.clone()))
+        }
+        (Expression::Literal(val), Expression::Property { variable, property }) if variable == var => {
+            Some((property.clone(), flip_comparison_op(op), val.clone()))
+        }
+        _ => None,
+    }
+}
+
+/// Find a comparison on `var.property` bounding it from the side opposite `op`,
+/// so `n.age > 20 AND n.age < 40` becomes a single index range read. `chosen` is
+/// the position of the predicate already used for the index scan.
+fn find_range_partner(
+    var: &str,
+    property: &str,
+    op: &BinaryOp,
+    preds: &[Expression],
+    chosen: usize,
+) -> Option<(usize, BinaryOp, PropertyValue)> {
+    let wanted: &[BinaryOp] = match op {
+        BinaryOp::Gt | BinaryOp::Ge => &[BinaryOp::Lt, BinaryOp::Le],
+        BinaryOp::Lt | BinaryOp::Le => &[BinaryOp::Gt, BinaryOp::Ge],
+        _ => return None,
+    };
+    preds.iter().enumerate().filter(|(i, _)| *i != chosen).find_map(|(i, pred)| {
+        let (prop, norm_op, val) = property_comparison(var, pred)?;
+        (prop == property && wanted.contains(&norm_op)).then_some((i, norm_op, val))
+    })
+}
+
+/// A composite index read chosen by [`find_composite_predicates`]
+struct CompositeScanPlan {
+    label: Label,
+    properties: Vec<String>,
+    prefix: Vec<PropertyValue>,
+    range: Vec<(BinaryOp, PropertyValue)>,
+    /// Positions of the predicates the scan answers, ascending
+    used: Vec<usize>,
+}
+
+impl CompositeScanPlan {
+    fn into_operator(self, var: String, labels: Vec<Label>) -> CompositeIndexScanOperator {
+        CompositeIndexScanOperator::new(var, self.label, self.properties, self.prefix, self.range)
+            .with_required_labels(labels)
+    }
+}
+
+/// Find the composite index on one of `labels` that answers the most predicates:
+/// equality on its leading properties, then up to one lower and one upper bound
+/// on the next property. Only worth it over a single-property scan when it
+/// covers at least two predicates.
+fn find_composite_predicates(
+    var: &str,
+    labels: &[Label],
+    preds: &[Expression],
+    store: &GraphStore,
+) -> Option<CompositeScanPlan> {
+    let comparisons: Vec<Option<(String, BinaryOp, PropertyValue)>> =
+        preds.iter().map(|pred| property_comparison(var, pred)).collect();
+    let mut best: Option<CompositeScanPlan> = None;
+
+    for label in labels {
+        for properties in store.property_index.composite_indexes_on(label) {
+            let mut prefix = Vec::new();
+            let mut used = Vec::new();
+            for property in &properties {
+                let eq = comparisons.iter().enumerate().find_map(|(i, c)| match c {
+                    Some((p, BinaryOp::Eq, val)) if p == property && !val.is_null() => Some((i, val.clone())),
+                    _ => None,
+                });
+                let Some((i, val)) = eq else { break };
+                prefix.push(val);
+                used.push(i);
+            }
+            if prefix.is_empty() {
+                continue;
+            }
+
+            let mut range: Vec<(BinaryOp, PropertyValue)> = Vec::new();
+            if let Some(next) = properties.get(prefix.len()) {
+                for side in [[BinaryOp::Gt, BinaryOp::Ge], [BinaryOp::Lt, BinaryOp::Le]] {
+                    let bound = comparisons.iter().enumerate().find_map(|(i, c)| match c {
+                        Some((p, op, val)) if p == next && side.contains(op) => Some((i, op.clone(), val.clone())),
+                        _ => None,
+                    });
+                    if let Some((i, op, val)) = bound {
+                        range.push((op, val));
+                        used.push(i);
+                    }
+                }
+            }
+
+            if used.len() >= 2 && best.as_ref().is_none_or(|b| used.len() > b.used.len()) {
+                used.sort_unstable();
+                best = Some(CompositeScanPlan { label: label.clone(), properties, prefix, range, used });
+            }
+        }
+    }
+    best
+}
+
 /// Choose the cheapest node in a path pattern to anchor the scan at: prefer a
 /// node with an indexable predicate (cost ~= label cardinality * selectivity),
 /// falling back to plain label-scan cardinality, and finally an all-nodes scan
@@ -2743,6 +2957,9 @@ impl QueryPlanner {
             if let Some(ref pv) = path.path_variable {
                 expand = expand.with_path_variable(pv.clone());
             }
+            if let Some(ref props) = segment.edge.properties {
+                expand = expand.with_edge_properties(props.clone());
+            }
 
             path_operator = if !segment.node.labels.is_empty() {
                 Box::new(expand.with_target_labels(segment.node.labels.clone()))
@@ -3440,6 +3657,7 @@ mod tests {
             order_by: None,
             limit: None,
             skip: None,
+            sample: None,
             call_clause: None,
             call_subquery: None,
             delete_clause: None,
@@ -3697,6 +3915,82 @@ mod tests {
         assert_eq!(rows.records.len(), 24, "Expected ages 26..49 to match 25 < n.age");
     }
 
+    #[test]
+    fn test_two_sided_range_uses_single_index_range_read() {
+        let mut store = GraphStore::new();
+        store.property_index.create_index(crate::graph::Label::new("Person"), "age".to_string());
+        for i in 0..100 {
+            let id = store.create_node("Person");
+            store.set_node_property("default", id, "age", crate::graph::PropertyValue::Integer(i as i64)).unwrap();
+        }
+        let other = store.create_node("Person");
+        store.set_node_property("default", other, "age", crate::graph::PropertyValue::String("unknown".into())).unwrap();
+
+        use crate::query::executor::record::Value;
+        use crate::graph::PropertyValue;
+
+        let query = parse_query("EXPLAIN MATCH (n:Person) WHERE n.age >= 20 AND 40 > n.age RETURN n").unwrap();
+        let result = crate::query::executor::QueryExecutor::new(&store).execute(&query).unwrap();
+        let plan_text = if let Some(Value::Property(PropertyValue::String(s))) = result.records[0].get("plan") {
+            s.clone()
+        } else { panic!("Expected plan text"); };
+        assert!(plan_text.contains("IndexScan") && plan_text.contains("AND <"),
+            "Both bounds should be folded into the IndexScan: {}", plan_text);
+
+        // Only the 20 in-range index entries are read, not all 101
+        let before = store.property_index.range_probe_count();
+        let query = parse_query("MATCH (n:Person) WHERE n.age >= 20 AND 40 > n.age RETURN n.age AS age").unwrap();
+        let rows = crate::query::executor::QueryExecutor::new(&store).execute(&query).unwrap();
+        assert_eq!(rows.records.len(), 20);
+        assert_eq!(store.property_index.range_probe_count() - before, 20);
+
+        // The open upper side stops at the end of the integers instead of
+        // returning the string-valued node
+        let query = parse_query("MATCH (n:Person) WHERE n.age > 95 RETURN n.age AS age").unwrap();
+        let rows = crate::query::executor::QueryExecutor::new(&store).execute(&query).unwrap();
+        assert_eq!(rows.records.len(), 4);
+    }
+
+    #[test]
+    fn test_composite_index_serves_prefix_and_range() {
+        use crate::query::executor::record::Value;
+        use crate::graph::PropertyValue;
+
+        let mut store = GraphStore::new();
+        store.property_index.create_composite_index(
+            crate::graph::Label::new("Doc"),
+            vec!["department".to_string(), "date".to_string()],
+        );
+        for i in 0..100 {
+            let id = store.create_node("Doc");
+            let dept = if i % 4 == 0 { "legal" } else { "sales" };
+            store.set_node_property("default", id, "department", PropertyValue::String(dept.into())).unwrap();
+            store.set_node_property("default", id, "date", PropertyValue::Integer(i as i64)).unwrap();
+        }
+
+        let query = parse_query("EXPLAIN MATCH (n:Doc) WHERE n.department = 'legal' AND n.date > 50 RETURN n").unwrap();
+        let result = crate::query::executor::QueryExecutor::new(&store).execute(&query).unwrap();
+        let plan_text = if let Some(Value::Property(PropertyValue::String(s))) = result.records[0].get("plan") {
+            s.clone()
+        } else { panic!("Expected plan text"); };
+        assert!(plan_text.contains("CompositeIndexScan"), "Expected CompositeIndexScan: {}", plan_text);
+
+        // legal docs dated 52, 56, ..., 96: only those 12 entries are read
+        let before = store.property_index.range_probe_count();
+        let query = parse_query("MATCH (n:Doc) WHERE n.department = 'legal' AND n.date > 50 RETURN n.date AS date").unwrap();
+        let rows = crate::query::executor::QueryExecutor::new(&store).execute(&query).unwrap();
+        assert_eq!(rows.records.len(), 12);
+        assert_eq!(store.property_index.range_probe_count() - before, 12);
+
+        // A lone equality on the leading property keeps the single-property scan
+        let query = parse_query("EXPLAIN MATCH (n:Doc) WHERE n.department = 'legal' RETURN n").unwrap();
+        let result = crate::query::executor::QueryExecutor::new(&store).execute(&query).unwrap();
+        let plan_text = if let Some(Value::Property(PropertyValue::String(s))) = result.records[0].get("plan") {
+            s.clone()
+        } else { panic!("Expected plan text"); };
+        assert!(!plan_text.contains("CompositeIndexScan"), "Single predicate: {}", plan_text);
+    }
+
     #[test]
     fn test_anchor_selection_uses_index_on_non_start_node() {
         // MATCH (a:Company)-[:WORKS_AT]->(b:Person) WHERE b.name = '...' — the predicate
@@ -4335,6 +4629,23 @@ mod tests {
         assert_native_query_ok(&store, "MATCH (h:Horse)-[:SIRE*1..3]->(ancestor:Horse) RETURN h.name, ancestor.name");
     }
 
+    #[test]
+    fn test_native_edge_property_map_fallback() {
+        let mut store = horse_twin_store();
+        let horses: Vec<crate::graph::NodeId> = store.get_nodes_by_label(&Label::new("Horse")).iter().map(|n| n.id).collect();
+        let e = store.create_edge(horses[0], horses[1], "RACED").unwrap();
+        store.set_edge_property_sparse(e, "year", PropertyValue::Integer(2024));
+        store.create_edge(horses[1], horses[0], "RACED").unwrap();
+
+        let executor = super::super::QueryExecutor::with_planner(&store, QueryPlanner::with_config(PlannerConfig {
+            graph_native: true,
+            max_candidate_plans: 64,
+        }));
+        let query = crate::query::parse_query("MATCH (a:Horse)-[:RACED {year: 2024}]->(b:Horse) RETURN a.name").unwrap();
+        let result = executor.execute(&query).unwrap();
+        assert_eq!(result.records.len(), 1, "inline relationship properties must filter under the graph-native planner");
+    }
+
     #[test]
     fn test_native_three_hop_chain() {
         let store = horse_twin_store();
@@ -4716,4 +5027,56 @@ mod tests {
             &crate::query::executor::record::Value::Property(PropertyValue::Integer(49)),
         );
     }
+
+    #[test]
+    fn test_label_count_shortcut_matches_full_scan() {
+        use crate::query::QueryExecutor;
+
+        let mut store = GraphStore::new();
+        for i in 0..10 {
+            let id = store.create_node("Person");
+            if i < 4 {
+                store.get_node_mut(id).unwrap().set_property("age", PropertyValue::Integer(i));
+            }
+            if i % 3 == 0 {
+                store.add_label_to_node("default", id, "Employee").unwrap();
+            }
+        }
+        store.create_node("Company");
+
+        let run = |q: &str| {
+            let query = parse_query(q).unwrap();
+            let plan = QueryPlanner::new().plan(&query, &store).unwrap();
+            let shortcut = plan.root.describe().format(0).contains("LabelCount");
+            let result = QueryExecutor::new(&store).execute_plan(plan).unwrap();
+            let count = result.records[0].get("c").unwrap().as_property().unwrap().as_integer().unwrap();
+            (shortcut, count)
+        };
+
+        // (query, expect shortcut, count from the regular operator tree)
+        let cases = [
+            ("MATCH (n:Person) RETURN count(n) AS c", true, 10),
+            ("MATCH (n:Person) RETURN count(*) AS c", true, 10),
+            ("MATCH (n:Employee) RETURN count(n) AS c", true, 4),
+            ("MATCH (n:Ghost) RETURN count(n) AS c", true, 0),
+            ("MATCH (n:Person:Employee) RETURN count(n) AS c", false, 4),
+            ("MATCH (n:Person {age: 2}) RETURN count(n) AS c", false, 1),
+            ("MATCH (n:Person) RETURN count(n.age) AS c", false, 4),
+            ("MATCH (n:Person) RETURN count(DISTINCT n) AS c", false, 10),
+            ("MATCH (n) RETURN count(n) AS c", false, 11),
+        ];
+        for (q, expect_shortcut, expected) in cases {
+            let (shortcut, count) = run(q);
+            assert_eq!(shortcut, expect_shortcut, "{}", q);
+            assert_eq!(count, expected, "{}", q);
+        }
+
+        // Fast path agrees with the same count forced through a scan
+        for label in ["Person", "Employee", "Company", "Ghost"] {
+            let fast = run(&format!("MATCH (n:{}) RETURN count(n) AS c", label));
+            let full = run(&format!("MATCH (n:{}) WHERE true RETURN count(n) AS c", label));
+            assert!(fast.0 && !full.0);
+            assert_eq!(fast.1, full.1, "{}", label);
+        }
+    }
 }
@@ -58,8 +58,9 @@ pub struct Record {
 ///   Properties are resolved on demand via `resolve_property(prop, store)`.
 ///
 /// - **`Node(id, node)`** -- a fully materialized node. Contains a clone of the `Node`
-///   struct with all labels and properties. Produced by `ProjectOperator` when the RETURN
-///   clause requests `RETURN n` (the entire node), triggering full materialization.
+///   struct with all labels and properties. Produced when a returned row binds a whole
+///   node (`RETURN n`): by [`Record::materialize`] as read results leave the executor,
+///   or by `ProjectOperator` in write queries.
 ///
 /// The same lazy/eager split exists for edges: `EdgeRef(id, src, tgt, type)` carries the
 /// structural data (endpoints and type) without property clones, while `Edge(id, edge)`
@@ -161,6 +162,20 @@ impl Record {
         self.bindings.extend(other.bindings);
     }
 
+    /// Replace node and edge references with full copies from `store`
+    ///
+    /// The read executors call this once per row they return, so entities are only
+    /// cloned for rows that reach the caller. A reference whose entity is gone
+    /// becomes `Null`.
+    pub fn materialize(&mut self, store: &GraphStore) {
+        for value in self.bindings.values_mut() {
+            if matches!(value, Value::NodeRef(_) | Value::EdgeRef(..)) {
+                let lazy = std::mem::replace(value, Value::Null);
+                *value = lazy.materialize_node(store).materialize_edge(store);
+            }
+        }
+    }
+
     /// Clone with only specified variables
     pub fn project(&self, variables: &[String]) -> Record {
         let mut new_record = Record::new();
@@ -204,9 +219,9 @@ impl Value {
         }
     }
 
-    /// Check if this is null
+    /// Check if this is null, either `Value::Null` or a null property value
     pub fn is_null(&self) -> bool {
-        matches!(self, Value::Null)
+        matches!(self, Value::Null | Value::Property(PropertyValue::Null))
     }
 
     /// Extract NodeId from any node variant (Node or NodeRef)
@@ -460,6 +475,8 @@ mod tests {
 
         let null_val = Value::Null;
         assert!(null_val.is_null());
+        assert!(Value::Property(PropertyValue::Null).is_null());
+        assert!(!prop_val.is_null());
     }
 
     #[test]
@@ -56,7 +56,10 @@
 //! applications execute the same queries repeatedly with different parameters, this module
 //! maintains an **LRU (Least Recently Used) cache** of parsed ASTs. On a cache hit, we skip
 //! parsing entirely and jump straight to planning. The cache uses `Mutex<LruCache>` for
-//! thread safety, with lock-free `AtomicU64` counters for hit/miss statistics.
+//! thread safety, with lock-free `AtomicU64` counters for hit/miss statistics. Its size
+//! defaults to 1024 entries and can be set with the `SAMYAMA_QUERY_CACHE_SIZE` environment
+//! variable. Parameters are bound after the cached AST is cloned, so a parameterized query
+//! is parsed once no matter how many different values it runs with.
 //!
 //! ## Read vs Write Execution Paths
 //!
@@ -73,6 +76,7 @@ pub mod ast;
 pub mod parser;
 pub mod executor;
 
+use std::collections::HashMap;
 use std::num::NonZeroUsize;
 use std::sync::Mutex;
 use std::sync::atomic::{AtomicU64, Ordering};
@@ -83,7 +87,7 @@ pub use ast::Query;
 pub use parser::{parse_query, ParseError, ParseResult};
 pub use executor::{
     QueryExecutor, ExecutionError, ExecutionResult,
-    Record, RecordBatch, Value,
+    Record, RecordBatch, RecordCursor, Value, CancellationToken,
     MutQueryExecutor,  // Added for CREATE/DELETE/SET support
 };
 
@@ -138,9 +142,13 @@ pub struct QueryEngine {
 }
 
 impl QueryEngine {
-    /// Create a new query engine with the default cache capacity (1024 entries)
+    /// Create a new query engine with the cache capacity from
+    /// `SAMYAMA_QUERY_CACHE_SIZE`, or the default (1024 entries) if unset
     pub fn new() -> Self {
-        Self::with_capacity(DEFAULT_CACHE_CAPACITY)
+        Self::with_capacity(
+            std::env::var("SAMYAMA_QUERY_CACHE_SIZE")
+                .ok().and_then(|s| s.parse().ok()).unwrap_or(DEFAULT_CACHE_CAPACITY)
+        )
     }
 
     /// Create a new query engine with a specific cache capacity
@@ -164,9 +172,14 @@ impl QueryEngine {
         self.ast_cache.lock().unwrap().len()
     }
 
+    /// Return the maximum number of entries the cache holds before evicting.
+    pub fn cache_capacity(&self) -> usize {
+        self.ast_cache.lock().unwrap().cap().get()
+    }
+
     /// Parse with caching — normalizes whitespace for cache hits
     fn cached_parse(&self, query_str: &str) -> Result<Query, Box<dyn std::error::Error>> {
-        let normalized = query_str.split_whitespace().collect::<Vec<_>>().join(" ");
+        let normalized = normalize_query(query_str);
 
         // Check cache (LruCache::get promotes to most-recently-used)
         {
@@ -188,14 +201,85 @@ impl QueryEngine {
         Ok(query)
     }
 
+    /// Whether a query has no write or schema clauses
+    ///
+    /// Parses through the AST cache, so checking before `execute` costs a
+    /// single parse.
+    pub fn is_read_only(&self, query_str: &str) -> Result<bool, Box<dyn std::error::Error>> {
+        Ok(self.cached_parse(query_str)?.is_read_only())
+    }
+
     /// Parse and execute a read-only Cypher query (MATCH, RETURN, etc.)
     pub fn execute(
         &self,
         query_str: &str,
         store: &crate::graph::GraphStore,
+    ) -> Result<RecordBatch, Box<dyn std::error::Error>> {
+        self.execute_with_params(query_str, store, HashMap::new())
+    }
+
+    /// Parse and execute a read-only Cypher query, binding `$name`
+    /// references to the given parameter values
+    pub fn execute_with_params(
+        &self,
+        query_str: &str,
+        store: &crate::graph::GraphStore,
+        params: HashMap<String, crate::graph::PropertyValue>,
     ) -> Result<RecordBatch, Box<dyn std::error::Error>> {
         let query = self.cached_parse(query_str)?;
+        let result = self.read_executor(store, None).with_params(params).execute(&query)?;
+
+        Ok(result)
+    }
 
+    /// Parse and execute a read-only Cypher query that fails with a
+    /// "Query timed out" error once `timeout` has elapsed
+    ///
+    /// The timeout can only shorten the engine-wide `SAMYAMA_QUERY_TIMEOUT`
+    /// limit, never extend it.
+    pub fn execute_with_timeout(
+        &self,
+        query_str: &str,
+        store: &crate::graph::GraphStore,
+        params: HashMap<String, crate::graph::PropertyValue>,
+        timeout: std::time::Duration,
+    ) -> Result<RecordBatch, Box<dyn std::error::Error>> {
+        let query = self.cached_parse(query_str)?;
+        let result = self.read_executor(store, Some(timeout)).with_params(params).execute(&query)?;
+
+        Ok(result)
+    }
+
+    /// Parse and plan a read-only Cypher query, returning a cursor that
+    /// produces its records lazily against `store`
+    pub fn execute_cursor(
+        &self,
+        query_str: &str,
+        store: &crate::graph::GraphStore,
+    ) -> Result<RecordCursor, Box<dyn std::error::Error>> {
+        self.execute_cursor_with_timeout(query_str, store, None)
+    }
+
+    /// [`execute_cursor`](Self::execute_cursor) with an optional per-query
+    /// timeout, enforced while the cursor is advanced
+    pub fn execute_cursor_with_timeout(
+        &self,
+        query_str: &str,
+        store: &crate::graph::GraphStore,
+        timeout: Option<std::time::Duration>,
+    ) -> Result<RecordCursor, Box<dyn std::error::Error>> {
+        let query = self.cached_parse(query_str)?;
+        let cursor = self.read_executor(store, timeout).cursor(&query)?;
+
+        Ok(cursor)
+    }
+
+    /// Read-only executor honouring the planner and timeout settings
+    fn read_executor<'a>(
+        &self,
+        store: &'a crate::graph::GraphStore,
+        timeout: Option<std::time::Duration>,
+    ) -> QueryExecutor<'a> {
         let mut executor = if std::env::var("SAMYAMA_GRAPH_NATIVE").unwrap_or_default() == "true" {
             QueryExecutor::with_planner(store, executor::planner::QueryPlanner::with_config(
                 executor::planner::PlannerConfig { graph_native: true, max_candidate_plans: 64 }
@@ -203,14 +287,20 @@ impl QueryEngine {
         } else {
             QueryExecutor::new(store)
         };
-        if self.query_timeout_secs > 0 {
-            executor = executor.with_deadline(
-                std::time::Instant::now() + std::time::Duration::from_secs(self.query_timeout_secs)
-            );
+        if let Some(timeout) = self.effective_timeout(timeout) {
+            executor = executor.with_deadline(std::time::Instant::now() + timeout);
         }
-        let result = executor.execute(&query)?;
+        executor
+    }
 
-        Ok(result)
+    /// The tighter of a per-call timeout and the engine-wide limit
+    fn effective_timeout(&self, timeout: Option<std::time::Duration>) -> Option<std::time::Duration> {
+        let engine = (self.query_timeout_secs > 0)
+            .then(|| std::time::Duration::from_secs(self.query_timeout_secs));
+        match (timeout, engine) {
+            (Some(t), Some(e)) => Some(t.min(e)),
+            (t, e) => t.or(e),
+        }
     }
 
     /// Parse and execute a write Cypher query (CREATE, DELETE, SET, etc.)
@@ -220,16 +310,86 @@ impl QueryEngine {
         query_str: &str,
         store: &mut crate::graph::GraphStore,
         tenant_id: &str,
+    ) -> Result<RecordBatch, Box<dyn std::error::Error>> {
+        self.execute_mut_with_params(query_str, store, tenant_id, HashMap::new())
+    }
+
+    /// Parse and execute a write Cypher query with bound parameter values
+    pub fn execute_mut_with_params(
+        &self,
+        query_str: &str,
+        store: &mut crate::graph::GraphStore,
+        tenant_id: &str,
+        params: HashMap<String, crate::graph::PropertyValue>,
+    ) -> Result<RecordBatch, Box<dyn std::error::Error>> {
+        let query = self.cached_parse(query_str)?;
+
+        let mut executor = MutQueryExecutor::new(store, tenant_id.to_string()).with_params(params);
+        let result = executor.execute(&query)?;
+
+        Ok(result)
+    }
+
+    /// Parse and execute a write Cypher query that fails with a
+    /// "Query timed out" error once `timeout` has elapsed
+    ///
+    /// Writes applied before the deadline are kept. Without a per-call
+    /// timeout, write queries are not subject to the engine-wide limit.
+    pub fn execute_mut_with_timeout(
+        &self,
+        query_str: &str,
+        store: &mut crate::graph::GraphStore,
+        tenant_id: &str,
+        params: HashMap<String, crate::graph::PropertyValue>,
+        timeout: std::time::Duration,
     ) -> Result<RecordBatch, Box<dyn std::error::Error>> {
         let query = self.cached_parse(query_str)?;
 
-        let mut executor = MutQueryExecutor::new(store, tenant_id.to_string());
+        let mut executor = MutQueryExecutor::new(store, tenant_id.to_string())
+            .with_params(params)
+            .with_deadline(std::time::Instant::now() + timeout);
         let result = executor.execute(&query)?;
 
         Ok(result)
     }
 }
 
+/// Cache key for a query string: runs of whitespace outside string literals
+/// and backtick-quoted identifiers collapse to a single space, so queries
+/// differing only in layout share an entry while `'a  b'` and `'a b'` do not.
+fn normalize_query(query_str: &str) -> String {
+    let mut out = String::with_capacity(query_str.len());
+    let mut quote: Option<char> = None;
+    let mut escaped = false;
+    let mut pending_space = false;
+    for c in query_str.trim().chars() {
+        if let Some(q) = quote {
+            out.push(c);
+            if escaped {
+                escaped = false;
+            } else if c == '\\' && q != '`' {
+                escaped = true;
+            } else if c == q {
+                quote = None;
+            }
+            continue;
+        }
+        if c.is_whitespace() {
+            pending_space = true;
+            continue;
+        }
+        if pending_space {
+            out.push(' ');
+            pending_space = false;
+        }
+        if matches!(c, '\'' | '"' | '`') {
+            quote = Some(c);
+        }
+        out.push(c);
+    }
+    out
+}
+
 impl Default for QueryEngine {
     fn default() -> Self {
         Self::new()
@@ -566,6 +726,53 @@ mod tests {
         let _ = engine.execute("MATCH  (n:Person)  RETURN  n", &store);
         assert_eq!(engine.cache_stats().hits(), 2);
         assert_eq!(engine.cache_stats().misses(), 2);
+
+        // A read-only check shares the cache with execution
+        assert!(!engine.is_read_only("CREATE (n:Movie)").unwrap());
+        let _ = engine.execute("CREATE (n:Movie)", &store);
+        assert_eq!(engine.cache_stats().hits(), 3);
+        assert_eq!(engine.cache_stats().misses(), 3);
+    }
+
+    #[test]
+    fn test_cache_key_preserves_string_literals() {
+        assert_eq!(
+            normalize_query("  MATCH (n)\n\tWHERE  n.name = 'a  b'  RETURN n "),
+            "MATCH (n) WHERE n.name = 'a  b' RETURN n"
+        );
+        assert_eq!(normalize_query(r#"RETURN "x\"  y"  AS  `my  col`"#), r#"RETURN "x\"  y" AS `my  col`"#);
+
+        let mut store = GraphStore::new();
+        let id = store.create_node("Person");
+        store.get_node_mut(id).unwrap().set_property("name", "a  b");
+
+        let engine = QueryEngine::new();
+        let two = engine.execute("MATCH (n:Person) WHERE n.name = 'a  b' RETURN n", &store).unwrap();
+        let one = engine.execute("MATCH (n:Person) WHERE n.name = 'a b' RETURN n", &store).unwrap();
+        assert_eq!(two.len(), 1);
+        assert_eq!(one.len(), 0, "literal with different spacing must not hit the cached AST");
+        assert_eq!(engine.cache_len(), 2);
+    }
+
+    #[test]
+    fn test_cached_ast_reused_across_parameter_values() {
+        let mut store = GraphStore::new();
+        for name in ["Alice", "Bob"] {
+            let id = store.create_node("Person");
+            store.get_node_mut(id).unwrap().set_property("name", name);
+        }
+
+        let engine = QueryEngine::with_capacity(8);
+        assert_eq!(engine.cache_capacity(), 8);
+        let query = "MATCH (n:Person) WHERE n.name = $name RETURN n.name AS name";
+        for name in ["Alice", "Bob", "Carol"] {
+            let params = HashMap::from([("name".to_string(), crate::graph::PropertyValue::String(name.to_string()))]);
+            let result = engine.execute_with_params(query, &store, params).unwrap();
+            assert_eq!(result.len(), usize::from(name != "Carol"));
+        }
+        assert_eq!(engine.cache_stats().misses(), 1);
+        assert_eq!(engine.cache_stats().hits(), 2);
+        assert_eq!(engine.cache_len(), 1);
     }
 
     #[test]
@@ -588,4 +795,147 @@ mod tests {
         // We had 3 misses so far, this should be a 4th miss
         assert_eq!(engine.cache_stats().misses(), 4);
     }
+
+    #[test]
+    fn test_execute_cursor_pulls_records_lazily() {
+        let mut store = GraphStore::new();
+        for i in 0..5i64 {
+            let id = store.create_node("Person");
+            store.get_node_mut(id).unwrap().set_property("rank", i);
+        }
+
+        let engine = QueryEngine::new();
+        let query = "MATCH (n:Person) RETURN n.rank ORDER BY n.rank";
+        let mut cursor = engine.execute_cursor(query, &store).unwrap();
+        assert_eq!(cursor.columns(), ["n.rank"]);
+        assert_eq!(cursor.remaining_hint(), None);
+
+        let mut ranks = Vec::new();
+        while let Some(record) = cursor.next(&store).unwrap() {
+            ranks.push(record.get("n.rank").unwrap().as_property().unwrap().as_integer().unwrap());
+        }
+        assert_eq!(ranks, vec![0, 1, 2, 3, 4]);
+        assert!(cursor.next(&store).unwrap().is_none());
+
+        let batch = engine.execute(query, &store).unwrap();
+        assert_eq!(batch.len(), ranks.len());
+
+        let explain = engine.execute_cursor(&format!("EXPLAIN {}", query), &store).unwrap();
+        assert_eq!(explain.remaining_hint(), Some(1));
+
+        assert!(engine.execute_cursor("CREATE (n:Person)", &store).is_err());
+    }
+
+    #[test]
+    fn test_execute_with_params() {
+        let mut store = GraphStore::new();
+        let engine = QueryEngine::new();
+        engine.execute_mut("CREATE (n:Person {name: 'Alice'})", &mut store, "default").unwrap();
+        let mut params = HashMap::new();
+        params.insert("age".to_string(), crate::graph::PropertyValue::Integer(30));
+        engine.execute_mut_with_params("MATCH (n:Person) SET n.age = $age", &mut store, "default", params)
+            .unwrap();
+
+        let query = "MATCH (n:Person) WHERE n.age > $min RETURN n.name";
+        let mut params = HashMap::new();
+        params.insert("min".to_string(), crate::graph::PropertyValue::Integer(25));
+        let batch = engine.execute_with_params(query, &store, params).unwrap();
+        assert_eq!(batch.len(), 1);
+
+        let mut params = HashMap::new();
+        params.insert("min".to_string(), crate::graph::PropertyValue::Integer(35));
+        assert_eq!(engine.execute_with_params(query, &store, params).unwrap().len(), 0);
+
+        let err = engine.execute(query, &store).unwrap_err();
+        assert!(err.to_string().contains("$min"));
+    }
+
+    #[test]
+    fn test_list_properties() {
+        let mut store = GraphStore::new();
+        let engine = QueryEngine::new();
+        let id = store.create_node("Post");
+        store.set_node_property("default", id, "tags", vec!["graph", "rust"]).unwrap();
+        engine.execute_mut("CREATE (n:Post {tags: ['db']})", &mut store, "default").unwrap();
+
+        let batch = engine.execute("MATCH (n:Post) UNWIND n.tags AS t RETURN t", &store).unwrap();
+        assert_eq!(batch.len(), 3);
+        let batch = engine.execute("MATCH (n:Post) WHERE 'rust' IN n.tags RETURN n", &store).unwrap();
+        assert_eq!(batch.len(), 1);
+        let batch = engine.execute("MATCH (n:Post) WHERE size(n.tags) = 2 RETURN n", &store).unwrap();
+        assert_eq!(batch.len(), 1);
+    }
+
+    #[test]
+    fn test_order_documents_by_date() {
+        let mut store = GraphStore::new();
+        let engine = QueryEngine::new();
+        for (title, date) in [("Q2 plan", "2025-04-10"), ("Kickoff", "2025-01-05"), ("Audit", "2025-03-15")] {
+            let id = store.create_node("Document");
+            store.set_node_property("default", id, "title", title).unwrap();
+            let date = crate::graph::PropertyValue::parse_datetime(date).unwrap();
+            store.set_node_property("default", id, "date", date).unwrap();
+        }
+
+        let batch = engine
+            .execute(
+                "MATCH (d:Document) WHERE d.date > date('2025-03-01') RETURN d.title ORDER BY d.date",
+                &store,
+            )
+            .unwrap();
+        let titles: Vec<String> = batch
+            .records
+            .iter()
+            .map(|r| r.get("d.title").unwrap().as_property().unwrap().as_string().unwrap().to_string())
+            .collect();
+        assert_eq!(titles, vec!["Audit", "Q2 plan"]);
+    }
+
+    #[test]
+    fn test_execute_with_timeout_aborts_expensive_query() {
+        let mut store = GraphStore::new();
+        for _ in 0..1000 {
+            store.create_node(Label::new("N"));
+        }
+
+        // 10^9 rows: the query cannot finish before the deadline on any machine
+        let engine = QueryEngine::new();
+        let start = std::time::Instant::now();
+        let err = engine
+            .execute_with_timeout(
+                "MATCH (a:N), (b:N), (c:N) RETURN count(*)",
+                &store,
+                HashMap::new(),
+                std::time::Duration::from_millis(100),
+            )
+            .unwrap_err();
+        assert!(err.to_string().contains("Query timed out"), "{}", err);
+        // Aborted near the deadline rather than after running to completion
+        assert!(start.elapsed() < std::time::Duration::from_secs(2), "{:?}", start.elapsed());
+
+        // A generous per-call timeout does not get in the way.
+        let result = engine
+            .execute_with_timeout(
+                "MATCH (a:N) RETURN count(a) AS c",
+                &store,
+                HashMap::new(),
+                std::time::Duration::from_secs(60),
+            )
+            .unwrap();
+        assert_eq!(result.records.len(), 1);
+    }
+
+    #[test]
+    fn test_effective_timeout_never_exceeds_engine_limit() {
+        let mut engine = QueryEngine::new();
+        engine.query_timeout_secs = 2;
+        let short = std::time::Duration::from_millis(10);
+        let long = std::time::Duration::from_secs(10);
+        assert_eq!(engine.effective_timeout(Some(short)), Some(short));
+        assert_eq!(engine.effective_timeout(Some(long)), Some(std::time::Duration::from_secs(2)));
+        assert_eq!(engine.effective_timeout(None), Some(std::time::Duration::from_secs(2)));
+        engine.query_timeout_secs = 0;
+        assert_eq!(engine.effective_timeout(Some(long)), Some(long));
+        assert_eq!(engine.effective_timeout(None), None);
+    }
 }
@@ -58,6 +58,7 @@
 //! without explicit locking.
 
 use crate::graph::{EdgeType, Label, PropertyValue};
+use crate::index::ConstraintKind;
 use crate::query::ast::*;
 use pest::Parser;
 use pest::pratt_parser::{PrattParser, Op, Assoc};
@@ -206,6 +207,13 @@ fn parse_statement(pair: pest::iterators::Pair<Rule>, query: &mut Query) -> Pars
                                 }
                             }
                         }
+                        Rule::sample_clause => {
+                            for sample_inner in child.into_inner() {
+                                if sample_inner.as_rule() == Rule::integer {
+                                    query.sample = sample_inner.as_str().parse::<usize>().ok();
+                                }
+                            }
+                        }
                         _ => {}
                     }
                 }
@@ -233,6 +241,13 @@ fn parse_statement(pair: pest::iterators::Pair<Rule>, query: &mut Query) -> Pars
                                 }
                             }
                         }
+                        Rule::sample_clause => {
+                            for sample_inner in child.into_inner() {
+                                if sample_inner.as_rule() == Rule::integer {
+                                    query.sample = sample_inner.as_str().parse::<usize>().ok();
+                                }
+                            }
+                        }
                         _ => {}
                     }
                 }
@@ -243,47 +258,49 @@ fn parse_statement(pair: pest::iterators::Pair<Rule>, query: &mut Query) -> Pars
     Ok(())
 }
 
-fn parse_create_index_statement(pair: pest::iterators::Pair<Rule>, query: &mut Query) -> ParseResult<()> {
+/// Label and ordered properties of `ON :Label(a, b)` or `FOR (n:Label) ON (n.a, n.b)`
+fn parse_index_target(pair: pest::iterators::Pair<Rule>) -> ParseResult<(Label, String, Vec<String>)> {
+    let mut variable = None;
     let mut label = None;
     let mut properties: Vec<String> = Vec::new();
 
     for inner in pair.into_inner() {
         match inner.as_rule() {
+            Rule::variable => variable = Some(inner.as_str().to_string()),
             Rule::label => label = Some(Label::new(inner.as_str())),
             Rule::property_key => properties.push(inner.as_str().to_string()),
+            Rule::property_access => {
+                let mut parts = inner.into_inner();
+                let var = parts.next().map(|p| p.as_str().to_string());
+                if var != variable {
+                    return Err(ParseError::SemanticError(format!(
+                        "Index property must belong to {}", variable.unwrap_or_default()
+                    )));
+                }
+                if let Some(key) = parts.next() {
+                    properties.push(key.as_str().to_string());
+                }
+            }
             _ => {}
         }
     }
 
-    let first_property = properties.first()
-        .ok_or_else(|| ParseError::SemanticError("Missing property".to_string()))?
-        .clone();
-    let additional_properties = properties.into_iter().skip(1).collect();
+    let label = label.ok_or_else(|| ParseError::SemanticError("Missing label".to_string()))?;
+    let mut properties = properties.into_iter();
+    let first_property = properties.next()
+        .ok_or_else(|| ParseError::SemanticError("Missing property".to_string()))?;
+    Ok((label, first_property, properties.collect()))
+}
 
-    query.create_index_clause = Some(CreateIndexClause {
-        label: label.ok_or_else(|| ParseError::SemanticError("Missing label".to_string()))?,
-        property: first_property,
-        additional_properties,
-    });
+fn parse_create_index_statement(pair: pest::iterators::Pair<Rule>, query: &mut Query) -> ParseResult<()> {
+    let (label, property, additional_properties) = parse_index_target(pair)?;
+    query.create_index_clause = Some(CreateIndexClause { label, property, additional_properties });
     Ok(())
 }
 
 fn parse_drop_index_statement(pair: pest::iterators::Pair<Rule>, query: &mut Query) -> ParseResult<()> {
-    let mut label = None;
-    let mut property = None;
-
-    for inner in pair.into_inner() {
-        match inner.as_rule() {
-            Rule::label => label = Some(Label::new(inner.as_str())),
-            Rule::property_key => property = Some(inner.as_str().to_string()),
-            _ => {}
-        }
-    }
-
-    query.drop_index_clause = Some(DropIndexClause {
-        label: label.ok_or_else(|| ParseError::SemanticError("Missing label".to_string()))?,
-        property: property.ok_or_else(|| ParseError::SemanticError("Missing property".to_string()))?,
-    });
+    let (label, property, additional_properties) = parse_index_target(pair)?;
+    query.drop_index_clause = Some(DropIndexClause { label, property, additional_properties });
     Ok(())
 }
 
@@ -291,6 +308,7 @@ fn parse_create_constraint_statement(pair: pest::iterators::Pair<Rule>, query: &
     let mut variable = None;
     let mut label = None;
     let mut property = None;
+    let mut kind = ConstraintKind::Unique;
 
     for inner in pair.into_inner() {
         match inner.as_rule() {
@@ -308,6 +326,13 @@ fn parse_create_constraint_statement(pair: pest::iterators::Pair<Rule>, query: &
                     }
                 }
             }
+            Rule::constraint_kind => {
+                kind = if inner.as_str().eq_ignore_ascii_case("UNIQUE") {
+                    ConstraintKind::Unique
+                } else {
+                    ConstraintKind::NotNull
+                };
+            }
             _ => {}
         }
     }
@@ -316,6 +341,7 @@ fn parse_create_constraint_statement(pair: pest::iterators::Pair<Rule>, query: &
         variable: variable.ok_or_else(|| ParseError::SemanticError("Missing variable".to_string()))?,
         label: label.ok_or_else(|| ParseError::SemanticError("Missing label".to_string()))?,
         property: property.ok_or_else(|| ParseError::SemanticError("Missing property".to_string()))?,
+        kind,
     });
     Ok(())
 }
@@ -571,6 +597,13 @@ fn parse_match_statement(pair: pest::iterators::Pair<Rule>, query: &mut Query) -
                     }
                 }
             }
+            Rule::sample_clause => {
+                for sample_inner in inner.into_inner() {
+                    if sample_inner.as_rule() == Rule::integer {
+                        query.sample = Some(sample_inner.as_str().parse().unwrap());
+                    }
+                }
+            }
             _ => {}
         }
     }
@@ -1458,6 +1491,9 @@ fn parse_primary(pair: pest::iterators::Pair<Rule>) -> ParseResult<Expression> {
             Rule::property_access => {
                 return parse_property_access(inner);
             }
+            Rule::size_pattern => {
+                return parse_size_pattern(inner);
+            }
             Rule::function_call => {
                 return parse_function_call(inner);
             }
@@ -1637,6 +1673,51 @@ fn parse_pattern_comprehension(pair: pest::iterators::Pair<Rule>) -> ParseResult
     })
 }
 
+/// `size((n)-->())` counts pattern matches. A single plain hop from a bound
+/// variable is a degree lookup; anything else counts a pattern comprehension.
+fn parse_size_pattern(pair: pest::iterators::Pair<Rule>) -> ParseResult<Expression> {
+    let path = pair
+        .into_inner()
+        .find(|inner| inner.as_rule() == Rule::path)
+        .ok_or_else(|| ParseError::SemanticError("size() missing pattern".to_string()))?;
+    let path = parse_path(path)?;
+
+    if let [segment] = path.segments.as_slice() {
+        let (start, edge, end) = (&path.start, &segment.edge, &segment.node);
+        let plain = start.labels.is_empty()
+            && start.properties.is_none()
+            && edge.variable.is_none()
+            && edge.types.is_empty()
+            && edge.length.is_none()
+            && edge.properties.is_none()
+            && end.variable.is_none()
+            && end.labels.is_empty()
+            && end.properties.is_none();
+        if let (true, Some(variable)) = (plain, &start.variable) {
+            let name = match edge.direction {
+                Direction::Outgoing => "outDegree",
+                Direction::Incoming => "inDegree",
+                Direction::Both => "degree",
+            };
+            return Ok(Expression::Function {
+                name: name.to_string(),
+                args: vec![Expression::Variable(variable.clone())],
+                distinct: false,
+            });
+        }
+    }
+
+    Ok(Expression::Function {
+        name: "size".to_string(),
+        args: vec![Expression::PatternComprehension {
+            pattern: Pattern { paths: vec![path] },
+            filter: None,
+            projection: Box::new(Expression::Literal(PropertyValue::Integer(1))),
+        }],
+        distinct: false,
+    })
+}
+
 fn parse_reduce_expression(pair: pest::iterators::Pair<Rule>) -> ParseResult<Expression> {
     let mut variables = Vec::new();
     let mut expressions = Vec::new();
@@ -2214,6 +2295,23 @@ mod tests {
         assert_eq!(di.property, "name");
     }
 
+    #[test]
+    fn test_parse_index_for_pattern() {
+        let ast = parse_query("CREATE INDEX FOR (n:Doc) ON (n.department, n.date)").unwrap();
+        let idx = ast.create_index_clause.unwrap();
+        assert_eq!(idx.label, Label::new("Doc"));
+        assert_eq!(idx.property, "department");
+        assert_eq!(idx.additional_properties, vec!["date".to_string()]);
+
+        let ast = parse_query("DROP INDEX ON :Doc(department, date)").unwrap();
+        let di = ast.drop_index_clause.unwrap();
+        assert_eq!((di.property.as_str(), di.additional_properties), ("department", vec!["date".to_string()]));
+        let ast = parse_query("drop index for (d:Doc) on (d.title)").unwrap();
+        assert_eq!(ast.drop_index_clause.unwrap().property, "title");
+
+        assert!(parse_query("CREATE INDEX FOR (n:Doc) ON (m.department)").is_err());
+    }
+
     #[test]
     fn test_parse_show_indexes() {
         let query = "SHOW INDEXES";
@@ -2314,6 +2412,21 @@ mod tests {
         assert!(result.is_ok(), "Failed to parse bidirectional edge: {:?}", result.err());
     }
 
+    #[test]
+    fn test_parse_bare_edges() {
+        for (query, direction) in [
+            ("MATCH (a)-->(b) RETURN b", Direction::Outgoing),
+            ("MATCH (a)<--(b) RETURN b", Direction::Incoming),
+            ("MATCH (a)--(b) RETURN b", Direction::Both),
+            ("MATCH (a)-[]->(b) RETURN b", Direction::Outgoing),
+        ] {
+            let parsed = parse_query(query).unwrap_or_else(|e| panic!("{}: {:?}", query, e));
+            let edge = &parsed.match_clauses[0].pattern.paths[0].segments[0].edge;
+            assert_eq!(edge.direction, direction, "{}", query);
+            assert!(edge.variable.is_none() && edge.types.is_empty(), "{}", query);
+        }
+    }
+
     #[test]
     fn test_parse_return_distinct() {
         let query = "MATCH (n:Person) RETURN DISTINCT n.name";
@@ -2345,6 +2458,19 @@ mod tests {
         assert_eq!(ast.limit, Some(10));
     }
 
+    #[test]
+    fn test_parse_sample() {
+        let ast = parse_query("MATCH (n:Person) RETURN n SAMPLE 100").unwrap();
+        assert_eq!(ast.sample, Some(100));
+        assert_eq!(ast.limit, None);
+
+        let ast = parse_query("MATCH (n:Person) RETURN n.name ORDER BY n.name LIMIT 50 SAMPLE 10").unwrap();
+        assert_eq!((ast.limit, ast.sample), (Some(50), Some(10)));
+
+        let ast = parse_query("RETURN 1 AS x sample 2").unwrap();
+        assert_eq!(ast.sample, Some(2));
+    }
+
     #[test]
     fn test_parse_error_malformed() {
         let query = "MATCHH (n) RETURN n";
@@ -2607,6 +2733,18 @@ mod tests {
         assert_eq!(cc.label, Label::new("Company"));
         assert_eq!(cc.property, "taxId");
         assert_eq!(cc.variable, "c");
+        assert_eq!(cc.kind, ConstraintKind::Unique);
+    }
+
+    #[test]
+    fn test_parse_create_constraint_not_null() {
+        let ast = parse_query("CREATE CONSTRAINT ON (p:Person) ASSERT p.name IS NOT NULL").unwrap();
+        let cc = ast.create_constraint_clause.unwrap();
+        assert_eq!(cc.label, Label::new("Person"));
+        assert_eq!(cc.property, "name");
+        assert_eq!(cc.kind, ConstraintKind::NotNull);
+
+        assert!(parse_query("CREATE CONSTRAINT ON (p:Person) ASSERT p.name IS NULL").is_err());
     }
 
     #[test]
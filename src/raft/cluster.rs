@@ -1,9 +1,9 @@
 //! Cluster membership management
 
 // NodeId removed - was unused import causing compiler warning
-use crate::raft::{RaftError, RaftNodeId, RaftResult};
+use crate::raft::{RaftError, RaftNode, RaftNodeId, RaftResult};
 use serde::{Deserialize, Serialize};
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeSet, HashMap, HashSet};
 use std::sync::Arc;
 use tokio::sync::RwLock;
 // warn removed - was unused import causing compiler warning
@@ -87,6 +87,8 @@ pub struct ClusterManager {
     active_nodes: Arc<RwLock<HashSet<RaftNodeId>>>,
     /// Node metadata
     node_metadata: Arc<RwLock<HashMap<RaftNodeId, NodeMetadata>>>,
+    /// Raft node handles reachable from this manager, by ID
+    raft_nodes: Arc<RwLock<HashMap<RaftNodeId, RaftNode>>>,
 }
 
 /// Node metadata
@@ -136,6 +138,7 @@ impl ClusterManager {
             config: Arc::new(RwLock::new(config)),
             active_nodes: Arc::new(RwLock::new(HashSet::new())),
             node_metadata: Arc::new(RwLock::new(node_metadata)),
+            raft_nodes: Arc::new(RwLock::new(HashMap::new())),
         })
     }
 
@@ -201,6 +204,84 @@ impl ClusterManager {
         Ok(())
     }
 
+    /// Register the Raft node handle for a cluster member
+    ///
+    /// Membership changes are driven through the current leader's handle;
+    /// a node must be registered before it can be added as a learner.
+    pub async fn register_raft_node(&self, node: RaftNode) {
+        self.raft_nodes.write().await.insert(node.id(), node);
+    }
+
+    /// Find the registered node that currently leads the cluster
+    async fn leader(&self) -> RaftResult<RaftNode> {
+        let nodes: Vec<RaftNode> = self.raft_nodes.read().await.values().cloned().collect();
+        for node in nodes {
+            if node.is_leader().await {
+                return Ok(node);
+            }
+        }
+        Err(RaftError::Cluster("No leader available for membership change".to_string()))
+    }
+
+    /// Add a node as a non-voting learner
+    ///
+    /// The leader catches the node up with a snapshot before it starts
+    /// receiving log entries. Promote it with [`change_membership`](Self::change_membership).
+    pub async fn add_learner(&self, node_id: RaftNodeId, address: String) -> RaftResult<()> {
+        let learner = self
+            .raft_nodes
+            .read()
+            .await
+            .get(&node_id)
+            .cloned()
+            .ok_or_else(|| RaftError::Cluster(format!("Node {} is not registered", node_id)))?;
+
+        self.leader().await?.add_learner(learner).await?;
+        self.add_node(node_id, address, false).await
+    }
+
+    /// Replace the voting membership with `members` in a single call
+    ///
+    /// New voters must have been added with [`add_learner`](Self::add_learner)
+    /// first. Fails with [`RaftError::QuorumLoss`] if the change would leave
+    /// either the old or the new voter set without a reachable majority.
+    pub async fn change_membership(&self, members: BTreeSet<RaftNodeId>) -> RaftResult<()> {
+        self.leader().await?.change_membership(members.clone()).await?;
+
+        let mut config = self.config.write().await;
+        let mut metadata = self.node_metadata.write().await;
+        let removed: Vec<RaftNodeId> = config
+            .nodes
+            .iter()
+            .filter(|n| n.voter && !members.contains(&n.id))
+            .map(|n| n.id)
+            .collect();
+        config.nodes.retain(|n| !removed.contains(&n.id));
+        for node in config.nodes.iter_mut() {
+            if members.contains(&node.id) && !node.voter {
+                node.voter = true;
+                if let Some(meta) = metadata.get_mut(&node.id) {
+                    meta.role = NodeRole::Follower;
+                }
+            }
+        }
+        for id in &removed {
+            metadata.remove(id);
+        }
+        drop(metadata);
+        drop(config);
+
+        let mut active = self.active_nodes.write().await;
+        let mut raft_nodes = self.raft_nodes.write().await;
+        for id in &removed {
+            active.remove(id);
+            raft_nodes.remove(id);
+        }
+
+        info!("Cluster membership changed to {:?}", members);
+        Ok(())
+    }
+
     /// Mark node as active (received heartbeat)
     pub async fn mark_active(&self, id: RaftNodeId) {
         let mut active = self.active_nodes.write().await;
@@ -292,6 +373,9 @@ pub struct ClusterHealth {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::persistence::PersistenceManager;
+    use crate::raft::GraphStateMachine;
+    use tempfile::TempDir;
 
     #[test]
     fn test_cluster_config() {
@@ -715,4 +799,75 @@ mod tests {
         assert_eq!(meta2.role, NodeRole::Learner);
         assert!(!meta2.reachable);
     }
+
+    async fn raft_node(id: RaftNodeId, dir: &TempDir) -> RaftNode {
+        let persistence = Arc::new(PersistenceManager::new(dir.path()).unwrap());
+        let mut node = RaftNode::new(id, GraphStateMachine::new(persistence));
+        node.initialize(vec![]).await.unwrap();
+        node
+    }
+
+    #[tokio::test]
+    async fn test_cluster_manager_scale_out_via_learners() {
+        let dirs: Vec<TempDir> = (0..5).map(|_| TempDir::new().unwrap()).collect();
+        let mut config = ClusterConfig::new("test".to_string(), 3);
+        for id in 1..=3 {
+            config.add_node(id, format!("127.0.0.1:500{}", id), true);
+        }
+        let manager = ClusterManager::new(config).unwrap();
+
+        let mut nodes = Vec::new();
+        for (i, dir) in dirs.iter().enumerate() {
+            let node = raft_node(i as u64 + 1, dir).await;
+            manager.register_raft_node(node.clone()).await;
+            nodes.push(node);
+        }
+        nodes[0].add_replica(nodes[1].clone()).await;
+        nodes[0].add_replica(nodes[2].clone()).await;
+        nodes[3].follow(1).await;
+        nodes[4].follow(1).await;
+
+        manager.add_learner(4, "127.0.0.1:5004".to_string()).await.unwrap();
+        manager.add_learner(5, "127.0.0.1:5005".to_string()).await.unwrap();
+        assert_eq!(manager.get_config().await.learners().len(), 2);
+        assert_eq!(manager.get_node_metadata(4).await.unwrap().role, NodeRole::Learner);
+
+        manager.change_membership(BTreeSet::from([1, 2, 3, 4, 5])).await.unwrap();
+        let cfg = manager.get_config().await;
+        assert_eq!(cfg.voters().len(), 5);
+        assert!(cfg.learners().is_empty());
+        assert_eq!(manager.get_node_metadata(5).await.unwrap().role, NodeRole::Follower);
+        assert_eq!(nodes[0].members().await.len(), 5);
+    }
+
+    #[tokio::test]
+    async fn test_cluster_manager_change_membership_quorum_loss() {
+        let dirs: Vec<TempDir> = (0..3).map(|_| TempDir::new().unwrap()).collect();
+        let mut config = ClusterConfig::new("test".to_string(), 3);
+        for id in 1..=3 {
+            config.add_node(id, format!("127.0.0.1:500{}", id), true);
+        }
+        let manager = ClusterManager::new(config).unwrap();
+
+        let mut nodes = Vec::new();
+        for (i, dir) in dirs.iter().enumerate() {
+            let node = raft_node(i as u64 + 1, dir).await;
+            manager.register_raft_node(node.clone()).await;
+            nodes.push(node);
+        }
+        nodes[0].add_replica(nodes[1].clone()).await;
+        nodes[0].add_replica(nodes[2].clone()).await;
+
+        // Two of three voters are unreachable from the leader
+        nodes[1].follow(7).await;
+        nodes[2].follow(7).await;
+
+        let result = manager.change_membership(BTreeSet::from([1, 2])).await;
+        assert!(matches!(result, Err(RaftError::QuorumLoss { .. })));
+        assert_eq!(manager.get_config().await.voters().len(), 3);
+
+        // Unregistered nodes cannot join
+        let result = manager.add_learner(9, "127.0.0.1:5009".to_string()).await;
+        assert!(matches!(result, Err(RaftError::Cluster(_))));
+    }
 }
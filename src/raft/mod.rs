@@ -37,8 +37,18 @@
 //! ## In Samyama
 //!
 //! All write operations (CREATE, SET, DELETE, MERGE) go through the Raft leader, which
-//! replicates them to followers before committing. Reads can go to any node with relaxed
-//! consistency (may read slightly stale data) or only to the leader for strong consistency.
+//! replicates them to followers before committing. [`RaftBackedStore`] is the entry point
+//! for that path: it turns each mutation into a [`Request`] log entry and returns only
+//! after the entry has been applied on a majority.
+//!
+//! Reads pick a [`ConsistencyLevel`]: `Eventual` reads are served from any node's local
+//! state machine (a lagging follower may return stale data), while `Linearizable` reads
+//! go through the leader's read-index so they observe every previously acknowledged write.
+//!
+//! The cluster grows or shrinks through [`ClusterManager`]: `add_learner` catches a new
+//! node up from a state machine snapshot, and `change_membership` promotes learners or
+//! drops voters in one call, refusing any change that would leave the cluster without
+//! a reachable majority.
 //! This module uses the `openraft` crate, a Rust implementation of the Raft protocol.
 
 pub mod node;
@@ -46,12 +56,14 @@ pub mod network;
 pub mod state_machine;
 pub mod storage;
 pub mod cluster;
+pub mod replicated;
 
-pub use node::{NodeId, RaftNode};
+pub use node::{ConsistencyLevel, NodeId, RaftNode};
 pub use network::RaftNetwork;
 pub use state_machine::{GraphStateMachine, Request, Response};
 pub use storage::RaftStorage;
 pub use cluster::{ClusterConfig, ClusterManager};
+pub use replicated::RaftBackedStore;
 
 use openraft::Config;
 // Arc removed - was unused import causing compiler warning
@@ -77,6 +89,9 @@ pub enum RaftError {
 
     #[error("Cluster error: {0}")]
     Cluster(String),
+
+    #[error("Quorum lost: {reachable} voters reachable, {required} required")]
+    QuorumLoss { reachable: usize, required: usize },
 }
 
 pub type RaftResult<T> = Result<T, RaftError>;
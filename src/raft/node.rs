@@ -1,11 +1,13 @@
 //! Raft node implementation
 
+use crate::query::RecordBatch;
+use crate::raft::storage::LogEntry;
 use crate::raft::{GraphStateMachine, RaftError, RaftNodeId, RaftResult, Request, Response};
 use serde::{Deserialize, Serialize};
-use std::collections::BTreeSet;
+use std::collections::{BTreeMap, BTreeSet};
 use std::sync::Arc;
 use tokio::sync::RwLock;
-use tracing::info;
+use tracing::{debug, info, warn};
 
 /// Node identifier with address
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, Default)]
@@ -49,6 +51,18 @@ pub mod typ {
     pub struct TypeConfig;
 }
 
+/// Read consistency for queries served by a Raft node
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ConsistencyLevel {
+    /// Leader read-index: the read reflects every write acknowledged before it
+    /// started. Only the leader can serve it.
+    #[default]
+    Linearizable,
+    /// Serve from the local state machine as-is. Any node can serve it, but a
+    /// lagging follower may return stale results.
+    Eventual,
+}
+
 /// Raft metrics (simplified)
 #[derive(Debug, Clone, Default)]
 pub struct SimpleRaftMetrics {
@@ -59,6 +73,9 @@ pub struct SimpleRaftMetrics {
 }
 
 /// Raft node managing consensus
+///
+/// Cloning a `RaftNode` yields another handle to the same node (all state is shared).
+#[derive(Clone)]
 pub struct RaftNode {
     /// Node ID
     node_id: RaftNodeId,
@@ -68,6 +85,13 @@ pub struct RaftNode {
     metrics: Arc<RwLock<SimpleRaftMetrics>>,
     /// Is initialized?
     initialized: Arc<RwLock<bool>>,
+    /// Replicated log
+    log: Arc<RwLock<Vec<LogEntry>>>,
+    /// Follower replicas this node ships log entries to while it is leader.
+    /// In-process handles stand in for the AppendEntries RPC transport.
+    replicas: Arc<RwLock<BTreeMap<RaftNodeId, RaftNode>>>,
+    /// Replicas that receive entries but do not vote (not counted toward quorum)
+    learners: Arc<RwLock<BTreeSet<RaftNodeId>>>,
 }
 
 impl RaftNode {
@@ -80,6 +104,9 @@ impl RaftNode {
             state_machine: Arc::new(RwLock::new(state_machine)),
             metrics: Arc::new(RwLock::new(SimpleRaftMetrics::default())),
             initialized: Arc::new(RwLock::new(false)),
+            log: Arc::new(RwLock::new(Vec::new())),
+            replicas: Arc::new(RwLock::new(BTreeMap::new())),
+            learners: Arc::new(RwLock::new(BTreeSet::new())),
         }
     }
 
@@ -101,30 +128,246 @@ impl RaftNode {
         Ok(())
     }
 
+    /// Get a handle to the state machine
+    pub fn state_machine(&self) -> Arc<RwLock<GraphStateMachine>> {
+        Arc::clone(&self.state_machine)
+    }
+
     /// Submit a write request (goes through Raft consensus)
+    ///
+    /// The request is appended to the leader's log, replicated to every
+    /// registered replica, and applied once a majority has acknowledged it.
+    /// Replicas apply the entry before this call returns, so a successful
+    /// response means the write is visible on every reachable follower.
     pub async fn write(&self, request: Request) -> RaftResult<Response> {
-        if *self.initialized.read().await {
-            // Apply directly to state machine
-            let sm = self.state_machine.read().await;
-            let response = sm.apply(request).await;
-
-            // Update metrics
-            let mut metrics = self.metrics.write().await;
-            metrics.last_log_index += 1;
-            metrics.last_applied = metrics.last_log_index;
-
-            Ok(response)
-        } else {
-            Err(RaftError::Raft("Raft not initialized".to_string()))
+        if !*self.initialized.read().await {
+            return Err(RaftError::Raft("Raft not initialized".to_string()));
+        }
+
+        // Holding the metrics lock serializes writes so log indices stay dense
+        let mut metrics = self.metrics.write().await;
+        if metrics.current_leader != Some(self.node_id) {
+            return Err(RaftError::NotLeader {
+                leader: metrics.current_leader,
+            });
+        }
+
+        let data = bincode::serialize(&request)
+            .map_err(|e| RaftError::Storage(format!("Failed to encode log entry: {}", e)))?;
+        let entry = LogEntry {
+            index: metrics.last_log_index + 1,
+            term: metrics.current_term,
+            data,
+        };
+        self.log.write().await.push(entry.clone());
+        metrics.last_log_index = entry.index;
+
+        // Replicate to followers and learners; only voters count toward the
+        // quorum (the leader acks itself)
+        let replicas: Vec<RaftNode> = self.replicas.read().await.values().cloned().collect();
+        let learners = self.learners.read().await.clone();
+        let mut acks = 1;
+        for replica in &replicas {
+            match self.replicate_to(replica, entry.index).await {
+                Ok(()) if !learners.contains(&replica.id()) => acks += 1,
+                Ok(()) => {}
+                Err(e) => warn!("Replication of entry {} to node {} failed: {}", entry.index, replica.id(), e),
+            }
+        }
+
+        let cluster_size = replicas.len() - learners.len() + 1;
+        let quorum = cluster_size / 2 + 1;
+        if acks < quorum {
+            self.log.write().await.retain(|e| e.index < entry.index);
+            metrics.last_log_index = entry.index - 1;
+            return Err(RaftError::Raft(format!(
+                "Entry {} not committed: {} of {} acks (quorum {})",
+                entry.index,
+                acks,
+                cluster_size,
+                quorum
+            )));
+        }
+
+        // Committed: apply locally, then tell followers to advance their commit index
+        let response = self.apply_entry(request, entry.index).await;
+        metrics.last_applied = entry.index;
+        drop(metrics);
+
+        for replica in &replicas {
+            replica.commit(entry.index).await;
+        }
+
+        Ok(response)
+    }
+
+    /// Ship every entry the replica is missing, up to and including `up_to`
+    async fn replicate_to(&self, replica: &RaftNode, up_to: u64) -> RaftResult<()> {
+        let next_index = replica.metrics().await.last_applied + 1;
+        let entries: Vec<LogEntry> = self
+            .log
+            .read()
+            .await
+            .iter()
+            .filter(|e| e.index >= next_index && e.index <= up_to)
+            .cloned()
+            .collect();
+        replica.append_entries(self.node_id, entries).await
+    }
+
+    /// Accept replicated entries from `leader` (the follower side of AppendEntries)
+    ///
+    /// Uncommitted entries that conflict with the incoming ones are overwritten.
+    pub async fn append_entries(&self, leader: RaftNodeId, entries: Vec<LogEntry>) -> RaftResult<()> {
+        if !*self.initialized.read().await {
+            return Err(RaftError::Raft("Raft not initialized".to_string()));
+        }
+
+        let mut metrics = self.metrics.write().await;
+        metrics.current_leader = Some(leader);
+
+        let mut log = self.log.write().await;
+        for entry in entries {
+            if entry.index <= metrics.last_applied {
+                continue;
+            }
+            if entry.index > metrics.last_log_index + 1 {
+                return Err(RaftError::Raft(format!(
+                    "Log gap on node {}: expected index {}, got {}",
+                    self.node_id,
+                    metrics.last_log_index + 1,
+                    entry.index
+                )));
+            }
+            log.retain(|e| e.index < entry.index);
+            debug!("Node {} appending entry {}", self.node_id, entry.index);
+            metrics.last_log_index = entry.index;
+            log.push(entry);
+        }
+
+        Ok(())
+    }
+
+    /// Apply all log entries up to `commit_index` to the state machine
+    pub async fn commit(&self, commit_index: u64) {
+        let mut metrics = self.metrics.write().await;
+        let pending: Vec<LogEntry> = self
+            .log
+            .read()
+            .await
+            .iter()
+            .filter(|e| e.index > metrics.last_applied && e.index <= commit_index)
+            .cloned()
+            .collect();
+
+        for entry in pending {
+            match bincode::deserialize::<Request>(&entry.data) {
+                Ok(request) => {
+                    self.apply_entry(request, entry.index).await;
+                }
+                Err(e) => warn!("Node {} skipping undecodable entry {}: {}", self.node_id, entry.index, e),
+            }
+            metrics.last_applied = entry.index;
         }
     }
 
+    /// Apply a committed request to the state machine
+    async fn apply_entry(&self, request: Request, index: u64) -> Response {
+        let sm = self.state_machine.read().await;
+        let response = sm.apply(request).await;
+        sm.set_last_applied(index).await;
+        response
+    }
+
+    /// Register a follower replica
+    ///
+    /// The replica starts following this node and receives every entry
+    /// committed from now on (plus any it has missed).
+    pub async fn add_replica(&self, replica: RaftNode) {
+        info!("Node {} replicating to node {}", self.node_id, replica.id());
+        replica.follow(self.node_id).await;
+        self.replicas.write().await.insert(replica.id(), replica);
+    }
+
+    /// Remove a follower replica
+    pub async fn remove_replica(&self, node_id: RaftNodeId) -> Option<RaftNode> {
+        self.learners.write().await.remove(&node_id);
+        self.replicas.write().await.remove(&node_id)
+    }
+
+    /// Step down and follow `leader`
+    pub async fn follow(&self, leader: RaftNodeId) {
+        self.metrics.write().await.current_leader = Some(leader);
+    }
+
     /// Execute a read request (can be served locally if leader)
     pub async fn read(&self, request: Request) -> RaftResult<Response> {
         let sm = self.state_machine.read().await;
         Ok(sm.apply(request).await)
     }
 
+    /// Execute a read-only Cypher query at the requested consistency level
+    ///
+    /// Both levels execute through `GraphStateMachine::query`, i.e. the read-only
+    /// `QueryExecutor` path against this node's materialized graph. They differ
+    /// only in what happens before execution:
+    /// - `Eventual` runs immediately on whichever node receives it.
+    /// - `Linearizable` first obtains a read index from [`read_index`](Self::read_index)
+    ///   and waits until that index is applied locally; followers reject it with
+    ///   `RaftError::NotLeader` so the client can redirect to the leader.
+    pub async fn query(
+        &self,
+        tenant: &str,
+        cypher: &str,
+        consistency: ConsistencyLevel,
+    ) -> RaftResult<RecordBatch> {
+        if consistency == ConsistencyLevel::Linearizable {
+            let read_index = self.read_index().await?;
+            self.commit(read_index).await;
+        }
+
+        let sm = self.state_machine.read().await;
+        sm.query(tenant, cypher).await.map_err(RaftError::Raft)
+    }
+
+    /// Leader read-index
+    ///
+    /// Records the current commit index, then confirms this node is still the
+    /// leader by heartbeating a quorum. Reads served once the state machine has
+    /// applied the returned index are linearizable.
+    pub async fn read_index(&self) -> RaftResult<u64> {
+        if !*self.initialized.read().await {
+            return Err(RaftError::Raft("Raft not initialized".to_string()));
+        }
+
+        let (leader, commit_index) = {
+            let metrics = self.metrics.read().await;
+            (metrics.current_leader, metrics.last_applied)
+        };
+        if leader != Some(self.node_id) {
+            return Err(RaftError::NotLeader { leader });
+        }
+
+        let voters = self.members().await;
+        let acks = self.reachable(&voters).await;
+        let quorum = voters.len() / 2 + 1;
+        if acks < quorum {
+            return Err(RaftError::Raft(format!(
+                "Leadership not confirmed: {} of {} acks (quorum {})",
+                acks,
+                voters.len(),
+                quorum
+            )));
+        }
+
+        Ok(commit_index)
+    }
+
+    /// Follower side of a heartbeat: true if this node still follows `leader`
+    pub async fn heartbeat(&self, leader: RaftNodeId) -> bool {
+        *self.initialized.read().await && self.metrics.read().await.current_leader == Some(leader)
+    }
+
     /// Check if this node is the leader
     pub async fn is_leader(&self) -> bool {
         let metrics = self.metrics.read().await;
@@ -136,29 +379,174 @@ impl RaftNode {
         self.metrics.read().await.current_leader
     }
 
-    /// Add a new node to the cluster
-    pub async fn add_learner(&self, node_id: RaftNodeId, _node: NodeId) -> RaftResult<()> {
-        info!("Adding learner {} to cluster", node_id);
+    /// Add a new node to the cluster as a non-voting learner
+    ///
+    /// The learner is first caught up by installing a snapshot of this node's
+    /// state machine, then receives every subsequent entry without counting
+    /// toward the write quorum. Promote it with [`change_membership`](Self::change_membership).
+    pub async fn add_learner(&self, learner: RaftNode) -> RaftResult<()> {
+        if !*self.initialized.read().await {
+            return Err(RaftError::Raft("Raft not initialized".to_string()));
+        }
+        if !self.is_leader().await {
+            return Err(RaftError::NotLeader {
+                leader: self.get_leader().await,
+            });
+        }
+        if learner.id() == self.node_id || self.replicas.read().await.contains_key(&learner.id()) {
+            return Err(RaftError::Cluster(format!(
+                "Node {} is already a cluster member",
+                learner.id()
+            )));
+        }
+
+        info!("Adding learner {} to cluster", learner.id());
 
-        if *self.initialized.read().await {
-            Ok(())
-        } else {
-            Err(RaftError::Raft("Raft not initialized".to_string()))
+        // Hold the metrics lock so no write commits between snapshot and registration
+        let metrics = self.metrics.write().await;
+        let snapshot = self.state_machine.read().await.create_snapshot().await;
+        learner
+            .install_snapshot(self.node_id, snapshot, metrics.current_term)
+            .await?;
+        self.learners.write().await.insert(learner.id());
+        self.replicas.write().await.insert(learner.id(), learner);
+
+        Ok(())
+    }
+
+    /// Follower side of InstallSnapshot: replace local state with `snapshot`
+    pub async fn install_snapshot(&self, leader: RaftNodeId, snapshot: Vec<u8>, term: u64) -> RaftResult<()> {
+        if !*self.initialized.read().await {
+            return Err(RaftError::Raft("Raft not initialized".to_string()));
         }
+
+        let mut metrics = self.metrics.write().await;
+        let index = self
+            .state_machine
+            .read()
+            .await
+            .install_snapshot(snapshot)
+            .await
+            .ok_or_else(|| RaftError::Storage("Invalid snapshot".to_string()))?;
+
+        self.log.write().await.clear();
+        metrics.current_leader = Some(leader);
+        metrics.current_term = term;
+        metrics.last_log_index = index;
+        metrics.last_applied = index;
+        info!("Node {} installed snapshot at index {} from {}", self.node_id, index, leader);
+
+        Ok(())
     }
 
-    /// Change cluster membership
+    /// Change the set of voting members
+    ///
+    /// `members` must include this (leader) node, and every new member must
+    /// already be replicating as a learner (see [`add_learner`](Self::add_learner)).
+    /// Learners being promoted are caught up to the last log index first.
+    /// Voters left out of `members` are removed from the cluster; learners
+    /// left out stay learners.
+    ///
+    /// The change is refused with [`RaftError::QuorumLoss`] unless a majority
+    /// of both the current and the new voter set is reachable, so a change can
+    /// never leave the cluster unable to commit.
     pub async fn change_membership(
         &self,
         members: BTreeSet<RaftNodeId>,
     ) -> RaftResult<()> {
+        if !*self.initialized.read().await {
+            return Err(RaftError::Raft("Raft not initialized".to_string()));
+        }
+        if !self.is_leader().await {
+            return Err(RaftError::NotLeader {
+                leader: self.get_leader().await,
+            });
+        }
+
         info!("Changing cluster membership to: {:?}", members);
 
-        if *self.initialized.read().await {
-            Ok(())
-        } else {
-            Err(RaftError::Raft("Raft not initialized".to_string()))
+        if !members.contains(&self.node_id) {
+            return Err(RaftError::Cluster(format!(
+                "Leader {} cannot remove itself from the membership",
+                self.node_id
+            )));
         }
+        let known = self.replicas.read().await.keys().copied().collect::<BTreeSet<_>>();
+        if let Some(unknown) = members.iter().find(|id| **id != self.node_id && !known.contains(id)) {
+            return Err(RaftError::Cluster(format!(
+                "Node {} must be added as a learner before it can vote",
+                unknown
+            )));
+        }
+
+        // Serialize with writes while the configuration changes
+        let metrics = self.metrics.write().await;
+
+        let old_voters = self.members().await;
+        for voters in [&old_voters, &members] {
+            let reachable = self.reachable(voters).await;
+            let required = voters.len() / 2 + 1;
+            if reachable < required {
+                return Err(RaftError::QuorumLoss { reachable, required });
+            }
+        }
+
+        // Catch up promoted learners before they start counting toward quorum
+        let promoted: Vec<RaftNode> = {
+            let learners = self.learners.read().await;
+            let replicas = self.replicas.read().await;
+            members
+                .iter()
+                .filter(|id| learners.contains(id))
+                .filter_map(|id| replicas.get(id).cloned())
+                .collect()
+        };
+        for learner in &promoted {
+            self.replicate_to(learner, metrics.last_log_index).await?;
+            learner.commit(metrics.last_applied).await;
+        }
+
+        let mut learners = self.learners.write().await;
+        let mut replicas = self.replicas.write().await;
+        learners.retain(|id| !members.contains(id));
+        replicas.retain(|id, _| members.contains(id) || learners.contains(id));
+
+        Ok(())
+    }
+
+    /// Number of `nodes` that acknowledge a heartbeat from this node (including itself)
+    async fn reachable(&self, nodes: &BTreeSet<RaftNodeId>) -> usize {
+        let replicas: Vec<RaftNode> = {
+            let replicas = self.replicas.read().await;
+            nodes.iter().filter_map(|id| replicas.get(id).cloned()).collect()
+        };
+        let mut acks = usize::from(nodes.contains(&self.node_id));
+        for replica in &replicas {
+            if replica.heartbeat(self.node_id).await {
+                acks += 1;
+            }
+        }
+        acks
+    }
+
+    /// Current learners (non-voting replicas)
+    pub async fn learners(&self) -> BTreeSet<RaftNodeId> {
+        self.learners.read().await.clone()
+    }
+
+    /// Current voting members: this node plus every non-learner replica
+    pub async fn members(&self) -> BTreeSet<RaftNodeId> {
+        let learners = self.learners.read().await;
+        let mut voters: BTreeSet<RaftNodeId> = self
+            .replicas
+            .read()
+            .await
+            .keys()
+            .filter(|id| !learners.contains(id))
+            .copied()
+            .collect();
+        voters.insert(self.node_id);
+        voters
     }
 
     /// Get Raft metrics
@@ -312,8 +700,10 @@ mod tests {
         let sm = GraphStateMachine::new(persistence);
         let node = RaftNode::new(1, sm);
 
-        let new_node = NodeId::new(2, "127.0.0.1:5001".to_string());
-        let result = node.add_learner(2, new_node).await;
+        let learner_dir = TempDir::new().unwrap();
+        let learner_persistence = Arc::new(PersistenceManager::new(learner_dir.path()).unwrap());
+        let learner = RaftNode::new(2, GraphStateMachine::new(learner_persistence));
+        let result = node.add_learner(learner).await;
         assert!(result.is_err());
     }
 
@@ -326,9 +716,14 @@ mod tests {
 
         node.initialize(vec![]).await.unwrap();
 
-        let new_node = NodeId::new(2, "127.0.0.1:5001".to_string());
-        let result = node.add_learner(2, new_node).await;
+        let learner_dir = TempDir::new().unwrap();
+        let learner_persistence = Arc::new(PersistenceManager::new(learner_dir.path()).unwrap());
+        let mut learner = RaftNode::new(2, GraphStateMachine::new(learner_persistence));
+        learner.initialize(vec![]).await.unwrap();
+        let result = node.add_learner(learner).await;
         assert!(result.is_ok());
+        assert_eq!(node.learners().await, BTreeSet::from([2]));
+        assert_eq!(node.members().await, BTreeSet::from([1]));
     }
 
     #[tokio::test]
@@ -357,8 +752,97 @@ mod tests {
         let mut members = BTreeSet::new();
         members.insert(1);
         members.insert(2);
-        let result = node.change_membership(members).await;
+
+        // Node 2 has not joined as a learner yet
+        let result = node.change_membership(members.clone()).await;
+        assert!(matches!(result, Err(RaftError::Cluster(_))));
+
+        let learner_dir = TempDir::new().unwrap();
+        let learner_persistence = Arc::new(PersistenceManager::new(learner_dir.path()).unwrap());
+        let mut learner = RaftNode::new(2, GraphStateMachine::new(learner_persistence));
+        learner.initialize(vec![]).await.unwrap();
+        node.add_learner(learner).await.unwrap();
+
+        let result = node.change_membership(members.clone()).await;
         assert!(result.is_ok());
+        assert_eq!(node.members().await, members);
+        assert!(node.learners().await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_scale_three_to_five_with_snapshot_catch_up() {
+        let dirs: Vec<TempDir> = (0..5).map(|_| TempDir::new().unwrap()).collect();
+        let mut nodes = Vec::new();
+        let mut stores = Vec::new();
+        for (i, dir) in dirs.iter().enumerate() {
+            let persistence = Arc::new(PersistenceManager::new(dir.path()).unwrap());
+            let mut node = RaftNode::new(i as u64 + 1, GraphStateMachine::new(Arc::clone(&persistence)));
+            node.initialize(vec![]).await.unwrap();
+            nodes.push(node);
+            stores.push(persistence);
+        }
+        let leader = &nodes[0];
+        leader.add_replica(nodes[1].clone()).await;
+        leader.add_replica(nodes[2].clone()).await;
+
+        let create = |node_id: u64| Request::CreateNode {
+            tenant: "default".to_string(),
+            node_id,
+            labels: vec!["Person".to_string()],
+            properties: crate::graph::PropertyMap::new(),
+        };
+        leader.write(create(1)).await.unwrap();
+        leader.write(create(2)).await.unwrap();
+
+        // New nodes join as learners and are caught up from a snapshot
+        leader.add_learner(nodes[3].clone()).await.unwrap();
+        leader.add_learner(nodes[4].clone()).await.unwrap();
+        assert_eq!(nodes[3].metrics().await.last_applied, 2);
+        assert!(stores[4].storage().get_node("default", 2).unwrap().is_some());
+
+        // Learners receive new entries
+        leader.write(create(3)).await.unwrap();
+        assert_eq!(nodes[4].metrics().await.last_applied, 3);
+
+        leader.change_membership(BTreeSet::from([1, 2, 3, 4, 5])).await.unwrap();
+        assert_eq!(leader.members().await.len(), 5);
+        assert!(leader.learners().await.is_empty());
+
+        let batch = nodes[4]
+            .query("default", "MATCH (n:Person) RETURN n", ConsistencyLevel::Eventual)
+            .await
+            .unwrap();
+        assert_eq!(batch.len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_change_membership_rejects_quorum_loss() {
+        let dirs: Vec<TempDir> = (0..3).map(|_| TempDir::new().unwrap()).collect();
+        let mut nodes = Vec::new();
+        for (i, dir) in dirs.iter().enumerate() {
+            let persistence = Arc::new(PersistenceManager::new(dir.path()).unwrap());
+            let mut node = RaftNode::new(i as u64 + 1, GraphStateMachine::new(persistence));
+            node.initialize(vec![]).await.unwrap();
+            nodes.push(node);
+        }
+        let leader = &nodes[0];
+        leader.add_replica(nodes[1].clone()).await;
+        leader.add_replica(nodes[2].clone()).await;
+
+        // Both followers are partitioned away (following someone else)
+        nodes[1].follow(9).await;
+        nodes[2].follow(9).await;
+
+        let result = leader.change_membership(BTreeSet::from([1, 2])).await;
+        assert!(matches!(
+            result,
+            Err(RaftError::QuorumLoss { reachable: 1, required: 2 })
+        ));
+        assert_eq!(leader.members().await, BTreeSet::from([1, 2, 3]));
+
+        // Followers cannot drive membership changes
+        let result = nodes[1].change_membership(BTreeSet::from([1, 2])).await;
+        assert!(matches!(result, Err(RaftError::NotLeader { leader: Some(9) })));
     }
 
     #[tokio::test]
@@ -404,6 +888,89 @@ mod tests {
         assert_eq!(metrics.last_applied, 5);
     }
 
+    #[tokio::test]
+    async fn test_raft_node_write_on_follower_not_leader() {
+        let dir1 = TempDir::new().unwrap();
+        let dir2 = TempDir::new().unwrap();
+        let p1 = Arc::new(PersistenceManager::new(dir1.path()).unwrap());
+        let p2 = Arc::new(PersistenceManager::new(dir2.path()).unwrap());
+        let mut leader = RaftNode::new(1, GraphStateMachine::new(p1));
+        let mut follower = RaftNode::new(2, GraphStateMachine::new(p2));
+        leader.initialize(vec![]).await.unwrap();
+        follower.initialize(vec![]).await.unwrap();
+        leader.add_replica(follower.clone()).await;
+
+        let request = Request::DeleteNode {
+            tenant: "default".to_string(),
+            node_id: 1,
+        };
+        let result = follower.write(request.clone()).await;
+        assert!(matches!(result, Err(RaftError::NotLeader { leader: Some(1) })));
+
+        leader.write(request).await.unwrap();
+        let metrics = follower.metrics().await;
+        assert_eq!(metrics.last_log_index, 1);
+        assert_eq!(metrics.last_applied, 1);
+    }
+
+    #[tokio::test]
+    async fn test_follower_stale_read_vs_linearizable() {
+        let dirs: Vec<TempDir> = (0..3).map(|_| TempDir::new().unwrap()).collect();
+        let mut nodes = Vec::new();
+        for (i, dir) in dirs.iter().enumerate() {
+            let persistence = Arc::new(PersistenceManager::new(dir.path()).unwrap());
+            let mut node = RaftNode::new(i as u64 + 1, GraphStateMachine::new(persistence));
+            node.initialize(vec![]).await.unwrap();
+            nodes.push(node);
+        }
+        let (leader, follower2, follower3) = (&nodes[0], &nodes[1], &nodes[2]);
+        leader.add_replica(follower2.clone()).await;
+        leader.add_replica(follower3.clone()).await;
+
+        let create = |node_id: u64| Request::CreateNode {
+            tenant: "default".to_string(),
+            node_id,
+            labels: vec!["Person".to_string()],
+            properties: crate::graph::PropertyMap::new(),
+        };
+        leader.write(create(1)).await.unwrap();
+
+        // Follower 3 is cut off and misses the second write
+        leader.remove_replica(3).await;
+        leader.write(create(2)).await.unwrap();
+
+        let cypher = "MATCH (n:Person) RETURN n";
+        let stale = follower3.query("default", cypher, ConsistencyLevel::Eventual).await.unwrap();
+        assert_eq!(stale.len(), 1);
+        let fresh = follower2.query("default", cypher, ConsistencyLevel::Eventual).await.unwrap();
+        assert_eq!(fresh.len(), 2);
+
+        let result = follower3.query("default", cypher, ConsistencyLevel::Linearizable).await;
+        assert!(matches!(result, Err(RaftError::NotLeader { leader: Some(1) })));
+        let strong = leader.query("default", cypher, ConsistencyLevel::Linearizable).await.unwrap();
+        assert_eq!(strong.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_read_index_requires_quorum() {
+        let dirs: Vec<TempDir> = (0..2).map(|_| TempDir::new().unwrap()).collect();
+        let p1 = Arc::new(PersistenceManager::new(dirs[0].path()).unwrap());
+        let p2 = Arc::new(PersistenceManager::new(dirs[1].path()).unwrap());
+        let mut leader = RaftNode::new(1, GraphStateMachine::new(p1));
+        let mut follower = RaftNode::new(2, GraphStateMachine::new(p2));
+        leader.initialize(vec![]).await.unwrap();
+        follower.initialize(vec![]).await.unwrap();
+        leader.add_replica(follower.clone()).await;
+        assert_eq!(leader.read_index().await.unwrap(), 0);
+
+        // The follower moved on to another leader: node 1 can no longer confirm leadership
+        follower.follow(3).await;
+        assert!(leader.read_index().await.is_err());
+        let result = leader.query("default", "MATCH (n) RETURN n", ConsistencyLevel::Linearizable).await;
+        assert!(result.is_err());
+        assert!(leader.query("default", "MATCH (n) RETURN n", ConsistencyLevel::Eventual).await.is_ok());
+    }
+
     #[test]
     fn test_node_id_default() {
         let node_id = NodeId::default();
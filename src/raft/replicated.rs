@@ -0,0 +1,231 @@
+//! Raft-backed graph store
+//!
+//! `RaftBackedStore` is the write path for a clustered deployment: every
+//! CREATE/SET/DELETE mutation is turned into a [`Request`] log entry, submitted
+//! to the local [`RaftNode`], replicated to the followers and applied through
+//! each node's [`GraphStateMachine`](crate::raft::GraphStateMachine) once a
+//! majority has acknowledged it. Writes submitted on a follower are rejected
+//! with [`RaftError::NotLeader`] so the client can redirect to the leader.
+
+use crate::graph::PropertyMap;
+use crate::raft::{RaftError, RaftNode, RaftResult, Request, Response};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Graph mutations routed through Raft consensus for a single tenant
+pub struct RaftBackedStore {
+    /// Local Raft node (must be the leader to accept writes)
+    node: RaftNode,
+    /// Tenant the mutations apply to
+    tenant: String,
+    /// Next node ID to hand out
+    next_node_id: AtomicU64,
+    /// Next edge ID to hand out
+    next_edge_id: AtomicU64,
+}
+
+impl RaftBackedStore {
+    /// Create a store that submits mutations for `tenant` through `node`
+    pub fn new(node: RaftNode, tenant: impl Into<String>) -> Self {
+        Self {
+            node,
+            tenant: tenant.into(),
+            next_node_id: AtomicU64::new(0),
+            next_edge_id: AtomicU64::new(0),
+        }
+    }
+
+    /// Get the underlying Raft node
+    pub fn node(&self) -> &RaftNode {
+        &self.node
+    }
+
+    /// Get the tenant this store writes to
+    pub fn tenant(&self) -> &str {
+        &self.tenant
+    }
+
+    /// Create a node (CREATE) and return its ID once committed
+    pub async fn create_node(&self, labels: Vec<String>, properties: PropertyMap) -> RaftResult<u64> {
+        let floor = self.node.state_machine().read().await.next_node_id().await;
+        let node_id = Self::allocate(&self.next_node_id, floor);
+
+        match self
+            .submit(Request::CreateNode {
+                tenant: self.tenant.clone(),
+                node_id,
+                labels,
+                properties,
+            })
+            .await?
+        {
+            Response::NodeCreated { node_id } => Ok(node_id),
+            other => Err(RaftError::Raft(format!("Unexpected response: {:?}", other))),
+        }
+    }
+
+    /// Create an edge (CREATE) and return its ID once committed
+    pub async fn create_edge(
+        &self,
+        source: u64,
+        target: u64,
+        edge_type: impl Into<String>,
+        properties: PropertyMap,
+    ) -> RaftResult<u64> {
+        let floor = self.node.state_machine().read().await.next_edge_id().await;
+        let edge_id = Self::allocate(&self.next_edge_id, floor);
+
+        match self
+            .submit(Request::CreateEdge {
+                tenant: self.tenant.clone(),
+                edge_id,
+                source,
+                target,
+                edge_type: edge_type.into(),
+                properties,
+            })
+            .await?
+        {
+            Response::EdgeCreated { edge_id } => Ok(edge_id),
+            other => Err(RaftError::Raft(format!("Unexpected response: {:?}", other))),
+        }
+    }
+
+    /// Set node properties (SET)
+    pub async fn set_node_properties(&self, node_id: u64, properties: PropertyMap) -> RaftResult<()> {
+        self.submit(Request::UpdateNodeProperties {
+            tenant: self.tenant.clone(),
+            node_id,
+            properties,
+            version: 0,
+        })
+        .await
+        .map(|_| ())
+    }
+
+    /// Set edge properties (SET)
+    pub async fn set_edge_properties(&self, edge_id: u64, properties: PropertyMap) -> RaftResult<()> {
+        self.submit(Request::UpdateEdgeProperties {
+            tenant: self.tenant.clone(),
+            edge_id,
+            properties,
+            version: 0,
+        })
+        .await
+        .map(|_| ())
+    }
+
+    /// Delete a node (DELETE)
+    pub async fn delete_node(&self, node_id: u64) -> RaftResult<()> {
+        self.submit(Request::DeleteNode {
+            tenant: self.tenant.clone(),
+            node_id,
+        })
+        .await
+        .map(|_| ())
+    }
+
+    /// Delete an edge (DELETE)
+    pub async fn delete_edge(&self, edge_id: u64) -> RaftResult<()> {
+        self.submit(Request::DeleteEdge {
+            tenant: self.tenant.clone(),
+            edge_id,
+        })
+        .await
+        .map(|_| ())
+    }
+
+    /// Submit a request through consensus, surfacing apply failures as errors
+    async fn submit(&self, request: Request) -> RaftResult<Response> {
+        match self.node.write(request).await? {
+            Response::Error { message } => Err(RaftError::Storage(message)),
+            response => Ok(response),
+        }
+    }
+
+    /// Hand out the next ID, never going below the replicated high-water mark
+    /// (a newly elected leader continues where the previous one stopped)
+    fn allocate(counter: &AtomicU64, floor: u64) -> u64 {
+        counter.fetch_max(floor, Ordering::SeqCst);
+        counter.fetch_add(1, Ordering::SeqCst)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::PropertyValue;
+    use crate::persistence::PersistenceManager;
+    use crate::raft::GraphStateMachine;
+    use std::sync::Arc;
+    use tempfile::TempDir;
+
+    async fn make_node(id: u64, dir: &TempDir) -> (RaftNode, Arc<PersistenceManager>) {
+        let persistence = Arc::new(PersistenceManager::new(dir.path()).unwrap());
+        let mut node = RaftNode::new(id, GraphStateMachine::new(Arc::clone(&persistence)));
+        node.initialize(vec![]).await.unwrap();
+        (node, persistence)
+    }
+
+    #[tokio::test]
+    async fn test_leader_write_replicated_to_followers() {
+        let dirs: Vec<TempDir> = (0..3).map(|_| TempDir::new().unwrap()).collect();
+        let (leader, p1) = make_node(1, &dirs[0]).await;
+        let (follower2, p2) = make_node(2, &dirs[1]).await;
+        let (follower3, p3) = make_node(3, &dirs[2]).await;
+        leader.add_replica(follower2.clone()).await;
+        leader.add_replica(follower3.clone()).await;
+
+        let store = RaftBackedStore::new(leader.clone(), "default");
+        let mut props = PropertyMap::new();
+        props.insert("name".to_string(), PropertyValue::String("Alice".to_string()));
+        let alice = store.create_node(vec!["Person".to_string()], props).await.unwrap();
+        let bob = store.create_node(vec!["Person".to_string()], PropertyMap::new()).await.unwrap();
+        let knows = store.create_edge(alice, bob, "KNOWS", PropertyMap::new()).await.unwrap();
+        assert_ne!(alice, bob);
+
+        // Followers applied the entries before the client was acknowledged
+        for persistence in [&p1, &p2, &p3] {
+            let node = persistence.storage().get_node("default", alice).unwrap().unwrap();
+            assert_eq!(node.get_property("name"), Some(&PropertyValue::String("Alice".to_string())));
+            assert!(persistence.storage().get_edge("default", knows).unwrap().is_some());
+        }
+        for follower in [&follower2, &follower3] {
+            let metrics = follower.metrics().await;
+            assert_eq!(metrics.last_applied, 3);
+            assert_eq!(metrics.current_leader, Some(1));
+        }
+
+        store.delete_edge(knows).await.unwrap();
+        assert!(p3.storage().get_edge("default", knows).unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_write_on_follower_rejected_with_leader_hint() {
+        let dirs: Vec<TempDir> = (0..2).map(|_| TempDir::new().unwrap()).collect();
+        let (leader, _p1) = make_node(1, &dirs[0]).await;
+        let (follower, p2) = make_node(2, &dirs[1]).await;
+        leader.add_replica(follower.clone()).await;
+
+        let store = RaftBackedStore::new(follower, "default");
+        let err = store.create_node(vec!["Person".to_string()], PropertyMap::new()).await.unwrap_err();
+        assert!(matches!(err, RaftError::NotLeader { leader: Some(1) }));
+        assert!(p2.storage().scan_nodes("default").unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_new_leader_continues_id_sequence() {
+        let dirs: Vec<TempDir> = (0..2).map(|_| TempDir::new().unwrap()).collect();
+        let (leader, _p1) = make_node(1, &dirs[0]).await;
+        let (follower, _p2) = make_node(2, &dirs[1]).await;
+        leader.add_replica(follower.clone()).await;
+
+        let store = RaftBackedStore::new(leader, "default");
+        let first = store.create_node(vec!["A".to_string()], PropertyMap::new()).await.unwrap();
+
+        // Failover: the follower takes over and must not reuse the ID
+        follower.follow(2).await;
+        let new_store = RaftBackedStore::new(follower, "default");
+        let second = new_store.create_node(vec!["A".to_string()], PropertyMap::new()).await.unwrap();
+        assert!(second > first);
+    }
+}
@@ -1,13 +1,21 @@
 //! Raft state machine for graph operations
 //!
-//! The state machine receives replicated commands and applies them to the graph
+//! The state machine receives replicated commands and applies them to the graph:
+//! each committed request is persisted (WAL + RocksDB) and mirrored into an
+//! in-memory `GraphStore` per tenant, which is what reads on this node query.
 
-use crate::graph::{Edge, EdgeId, EdgeType, Label, Node, NodeId, PropertyMap};
-use crate::persistence::PersistenceManager;
+use crate::graph::{Edge, EdgeId, EdgeType, GraphStore, Label, Node, NodeId, PropertyMap};
+use crate::persistence::{PersistenceManager, StorageResult};
+use crate::query::{QueryEngine, RecordBatch};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::sync::Arc;
 use tokio::sync::RwLock;
-use tracing::{debug, info};
+use tracing::{debug, info, warn};
+
+/// Metadata keys the ID watermarks are persisted under
+const NEXT_NODE_ID_KEY: &str = "raft:next_node_id";
+const NEXT_EDGE_ID_KEY: &str = "raft:next_edge_id";
 
 /// Graph operation requests that will be replicated via Raft
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -121,16 +129,94 @@ pub struct GraphStateMachine {
     /// Last membership config (retained for future cluster membership tracking)
     #[allow(dead_code)]
     last_membership: Arc<RwLock<Option<String>>>,
+    /// Next unused node ID (high-water mark of applied CreateNode requests)
+    next_node_id: Arc<RwLock<u64>>,
+    /// Next unused edge ID (high-water mark of applied CreateEdge requests)
+    next_edge_id: Arc<RwLock<u64>>,
+    /// Materialized graph per tenant (the state local reads are served from)
+    graphs: Arc<RwLock<HashMap<String, GraphStore>>>,
+    /// Query engine for read-only queries against the materialized graphs
+    engine: Arc<QueryEngine>,
 }
 
 impl GraphStateMachine {
     /// Create a new graph state machine
+    ///
+    /// The materialized graphs and ID watermarks are restored from storage,
+    /// so a restarted node serves its applied data and never hands out an
+    /// ID allocated before the restart.
     pub fn new(persistence: Arc<PersistenceManager>) -> Self {
+        let next_node_id = Self::load_watermark(&persistence, NEXT_NODE_ID_KEY);
+        let next_edge_id = Self::load_watermark(&persistence, NEXT_EDGE_ID_KEY);
+        let graphs = Self::recover_graphs(&persistence);
         Self {
             persistence,
             last_applied_log: Arc::new(RwLock::new(0)),
             last_membership: Arc::new(RwLock::new(None)),
+            next_node_id: Arc::new(RwLock::new(next_node_id)),
+            next_edge_id: Arc::new(RwLock::new(next_edge_id)),
+            graphs: Arc::new(RwLock::new(graphs)),
+            engine: Arc::new(QueryEngine::new()),
+        }
+    }
+
+    /// Rebuild the materialized graph of every persisted tenant
+    fn recover_graphs(persistence: &PersistenceManager) -> HashMap<String, GraphStore> {
+        let mut graphs = HashMap::new();
+        let tenants = match persistence.list_persisted_tenants() {
+            Ok(tenants) => tenants,
+            Err(e) => {
+                warn!("Failed to list persisted tenants: {}", e);
+                return graphs;
+            }
+        };
+        for tenant in tenants {
+            match persistence.recover(&tenant) {
+                Ok((nodes, edges)) => {
+                    let mut graph = GraphStore::new();
+                    for node in nodes {
+                        graph.insert_recovered_node(node);
+                    }
+                    for edge in edges {
+                        if let Err(e) = graph.insert_recovered_edge(edge) {
+                            warn!("Recovered edge not materialized: {}", e);
+                        }
+                    }
+                    info!("Recovered tenant {}: {} nodes, {} edges", tenant, graph.node_count(), graph.edge_count());
+                    graphs.insert(tenant, graph);
+                }
+                Err(e) => warn!("Failed to recover tenant {}: {}", tenant, e),
+            }
         }
+        graphs
+    }
+
+    /// Read a persisted watermark, treating a missing or unreadable one as 0
+    fn load_watermark(persistence: &PersistenceManager, key: &str) -> u64 {
+        match persistence.storage().get_metadata(key) {
+            Ok(Some(bytes)) => match <[u8; 8]>::try_from(bytes.as_slice()) {
+                Ok(bytes) => u64::from_le_bytes(bytes),
+                Err(_) => {
+                    warn!("Ignoring malformed watermark {} ({} bytes)", key, bytes.len());
+                    0
+                }
+            },
+            Ok(None) => 0,
+            Err(e) => {
+                warn!("Failed to load watermark {}: {}", key, e);
+                0
+            }
+        }
+    }
+
+    /// Raise a watermark to `next` and persist it before it takes effect
+    async fn store_watermark(&self, watermark: &RwLock<u64>, key: &str, next: u64) -> StorageResult<()> {
+        let mut current = watermark.write().await;
+        if next > *current {
+            self.persistence.storage().put_metadata(key, &next.to_le_bytes())?;
+            *current = next;
+        }
+        Ok(())
     }
 
     /// Apply a request to the state machine
@@ -157,9 +243,17 @@ impl GraphStateMachine {
                 // Set properties
                 node.properties = properties;
 
+                if let Err(e) = self.store_watermark(&self.next_node_id, NEXT_NODE_ID_KEY, node_id + 1).await {
+                    return Response::Error {
+                        message: format!("Failed to create node: {}", e),
+                    };
+                }
+
                 match self.persistence.persist_create_node(&tenant, &node) {
                     Ok(_) => {
                         info!("Node {} created for tenant {}", node_id, tenant);
+                        let mut graphs = self.graphs.write().await;
+                        graphs.entry(tenant).or_default().insert_recovered_node(node);
                         Response::NodeCreated { node_id }
                     }
                     Err(e) => Response::Error {
@@ -184,9 +278,19 @@ impl GraphStateMachine {
                 );
                 edge.properties = properties;
 
+                if let Err(e) = self.store_watermark(&self.next_edge_id, NEXT_EDGE_ID_KEY, edge_id + 1).await {
+                    return Response::Error {
+                        message: format!("Failed to create edge: {}", e),
+                    };
+                }
+
                 match self.persistence.persist_create_edge(&tenant, &edge) {
                     Ok(_) => {
                         info!("Edge {} created for tenant {}", edge_id, tenant);
+                        let mut graphs = self.graphs.write().await;
+                        if let Err(e) = graphs.entry(tenant).or_default().insert_recovered_edge(edge) {
+                            warn!("Edge {} not materialized: {}", edge_id, e);
+                        }
                         Response::EdgeCreated { edge_id }
                     }
                     Err(e) => Response::Error {
@@ -199,6 +303,9 @@ impl GraphStateMachine {
                 match self.persistence.persist_delete_node(&tenant, node_id) {
                     Ok(_) => {
                         info!("Node {} deleted for tenant {}", node_id, tenant);
+                        if let Some(graph) = self.graphs.write().await.get_mut(&tenant) {
+                            let _ = graph.delete_node(&tenant, NodeId::new(node_id));
+                        }
                         Response::Ok
                     }
                     Err(e) => Response::Error {
@@ -211,6 +318,9 @@ impl GraphStateMachine {
                 match self.persistence.persist_delete_edge(&tenant, edge_id) {
                     Ok(_) => {
                         info!("Edge {} deleted for tenant {}", edge_id, tenant);
+                        if let Some(graph) = self.graphs.write().await.get_mut(&tenant) {
+                            let _ = graph.delete_edge(EdgeId::new(edge_id));
+                        }
                         Response::Ok
                     }
                     Err(e) => Response::Error {
@@ -231,6 +341,11 @@ impl GraphStateMachine {
                 {
                     Ok(_) => {
                         info!("Node {} properties updated for tenant {} (v{})", node_id, tenant, version);
+                        if let Some(graph) = self.graphs.write().await.get_mut(&tenant) {
+                            for (key, value) in properties {
+                                let _ = graph.set_node_property(&tenant, NodeId::new(node_id), key, value);
+                            }
+                        }
                         Response::Ok
                     }
                     Err(e) => Response::Error {
@@ -251,6 +366,13 @@ impl GraphStateMachine {
                 {
                     Ok(_) => {
                         info!("Edge {} properties updated for tenant {} (v{})", edge_id, tenant, version);
+                        if let Some(graph) = self.graphs.write().await.get_mut(&tenant) {
+                            if graph.has_edge(EdgeId::new(edge_id)) {
+                                for (key, value) in properties {
+                                    let _ = graph.set_edge_property(EdgeId::new(edge_id), key, value);
+                                }
+                            }
+                        }
                         Response::Ok
                     }
                     Err(e) => Response::Error {
@@ -262,11 +384,33 @@ impl GraphStateMachine {
             Request::ExecuteQuery { tenant, query } => {
                 // Read-only query - can be executed locally without replication
                 info!("Executing query for tenant {}: {}", tenant, query);
-                Response::QueryResult { rows: 0 }
+                match self.query(&tenant, &query).await {
+                    Ok(batch) => Response::QueryResult { rows: batch.len() },
+                    Err(message) => Response::Error { message },
+                }
             }
         }
     }
 
+    /// Run a read-only Cypher query against the tenant's materialized graph
+    ///
+    /// Goes through `QueryEngine::execute` (the `QueryExecutor` path), so write
+    /// clauses are rejected rather than bypassing replication.
+    pub async fn query(&self, tenant: &str, query: &str) -> Result<RecordBatch, String> {
+        let graphs = self.graphs.read().await;
+        let empty;
+        let graph = match graphs.get(tenant) {
+            Some(graph) => graph,
+            None => {
+                empty = GraphStore::new();
+                &empty
+            }
+        };
+        self.engine
+            .execute(query, graph)
+            .map_err(|e| format!("Query failed: {}", e))
+    }
+
     /// Update last applied log index
     pub async fn set_last_applied(&self, index: u64) {
         let mut last = self.last_applied_log.write().await;
@@ -278,21 +422,129 @@ impl GraphStateMachine {
         *self.last_applied_log.read().await
     }
 
+    /// Next node ID not yet used by any applied request
+    pub async fn next_node_id(&self) -> u64 {
+        *self.next_node_id.read().await
+    }
+
+    /// Next edge ID not yet used by any applied request
+    pub async fn next_edge_id(&self) -> u64 {
+        *self.next_edge_id.read().await
+    }
+
     /// Create a snapshot of the current state
+    ///
+    /// The snapshot carries every tenant's materialized nodes and edges plus
+    /// the ID watermarks and last applied index, so a node that installs it
+    /// can serve reads and resume replication without replaying the log.
     pub async fn create_snapshot(&self) -> Vec<u8> {
-        // For now, return empty snapshot
-        // In production, this would serialize the entire graph state
-        info!("Creating snapshot at log index {}", self.get_last_applied().await);
-        vec![]
+        let last_applied = self.get_last_applied().await;
+        info!("Creating snapshot at log index {}", last_applied);
+
+        let graphs = self.graphs.read().await;
+        let mut tenants = HashMap::new();
+        for (tenant, graph) in graphs.iter() {
+            let mut node_ids: Vec<NodeId> = graph.all_nodes().iter().map(|n| n.id).collect();
+            node_ids.sort();
+            node_ids.dedup();
+            let nodes = node_ids
+                .into_iter()
+                .filter_map(|id| graph.get_node(id).cloned())
+                .collect();
+            tenants.insert(
+                tenant.clone(),
+                TenantSnapshot {
+                    nodes,
+                    edges: graph.all_edges(),
+                },
+            );
+        }
+
+        let snapshot = GraphSnapshot {
+            last_applied,
+            next_node_id: self.next_node_id().await,
+            next_edge_id: self.next_edge_id().await,
+            tenants,
+        };
+        match bincode::serialize(&snapshot) {
+            Ok(bytes) => bytes,
+            Err(e) => {
+                warn!("Failed to encode snapshot: {}", e);
+                vec![]
+            }
+        }
     }
 
     /// Install a snapshot
-    pub async fn install_snapshot(&self, _snapshot: Vec<u8>) {
-        info!("Installing snapshot");
-        // In production, this would deserialize and restore the graph state
+    ///
+    /// Replaces the materialized graphs with the snapshot contents and
+    /// persists them. Returns the log index the snapshot covers, or `None`
+    /// if the bytes are not a valid snapshot (the state is left untouched).
+    pub async fn install_snapshot(&self, snapshot: Vec<u8>) -> Option<u64> {
+        let snapshot: GraphSnapshot = match bincode::deserialize(&snapshot) {
+            Ok(snapshot) => snapshot,
+            Err(e) => {
+                warn!("Ignoring invalid snapshot ({} bytes): {}", snapshot.len(), e);
+                return None;
+            }
+        };
+        info!("Installing snapshot at log index {}", snapshot.last_applied);
+
+        let mut graphs = HashMap::new();
+        for (tenant, contents) in snapshot.tenants {
+            let mut graph = GraphStore::new();
+            for node in contents.nodes {
+                if let Err(e) = self.persistence.persist_create_node(&tenant, &node) {
+                    warn!("Failed to persist snapshot node {:?}: {}", node.id, e);
+                }
+                graph.insert_recovered_node(node);
+            }
+            for edge in contents.edges {
+                if let Err(e) = self.persistence.persist_create_edge(&tenant, &edge) {
+                    warn!("Failed to persist snapshot edge {:?}: {}", edge.id, e);
+                }
+                if let Err(e) = graph.insert_recovered_edge(edge) {
+                    warn!("Snapshot edge not materialized: {}", e);
+                }
+            }
+            graphs.insert(tenant, graph);
+        }
+
+        *self.graphs.write().await = graphs;
+        for (watermark, key, next) in [
+            (&self.next_node_id, NEXT_NODE_ID_KEY, snapshot.next_node_id),
+            (&self.next_edge_id, NEXT_EDGE_ID_KEY, snapshot.next_edge_id),
+        ] {
+            if let Err(e) = self.persistence.storage().put_metadata(key, &next.to_le_bytes()) {
+                warn!("Failed to persist snapshot watermark {}: {}", key, e);
+            }
+            *watermark.write().await = next;
+        }
+        self.set_last_applied(snapshot.last_applied).await;
+        Some(snapshot.last_applied)
     }
 }
 
+/// Serialized state machine contents exchanged during snapshot install
+#[derive(Debug, Serialize, Deserialize)]
+struct GraphSnapshot {
+    /// Log index covered by the snapshot
+    last_applied: u64,
+    /// Node ID watermark
+    next_node_id: u64,
+    /// Edge ID watermark
+    next_edge_id: u64,
+    /// Materialized graph per tenant
+    tenants: HashMap<String, TenantSnapshot>,
+}
+
+/// Nodes and edges of one tenant in a snapshot
+#[derive(Debug, Serialize, Deserialize)]
+struct TenantSnapshot {
+    nodes: Vec<Node>,
+    edges: Vec<Edge>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -568,8 +820,7 @@ mod tests {
 
         sm.set_last_applied(42).await;
         let snapshot = sm.create_snapshot().await;
-        // Currently returns empty vec
-        assert!(snapshot.is_empty());
+        assert!(!snapshot.is_empty());
     }
 
     #[tokio::test]
@@ -579,7 +830,153 @@ mod tests {
         let sm = GraphStateMachine::new(persistence);
 
         // Should not panic with empty snapshot
-        sm.install_snapshot(vec![]).await;
+        assert_eq!(sm.install_snapshot(vec![]).await, None);
+    }
+
+    #[tokio::test]
+    async fn test_snapshot_round_trip() {
+        let dir1 = TempDir::new().unwrap();
+        let dir2 = TempDir::new().unwrap();
+        let source = GraphStateMachine::new(Arc::new(PersistenceManager::new(dir1.path()).unwrap()));
+        let persistence = Arc::new(PersistenceManager::new(dir2.path()).unwrap());
+        let target = GraphStateMachine::new(Arc::clone(&persistence));
+
+        for node_id in [1, 2] {
+            source
+                .apply(Request::CreateNode {
+                    tenant: "default".to_string(),
+                    node_id,
+                    labels: vec!["Person".to_string()],
+                    properties: PropertyMap::new(),
+                })
+                .await;
+        }
+        source
+            .apply(Request::CreateEdge {
+                tenant: "default".to_string(),
+                edge_id: 7,
+                source: 1,
+                target: 2,
+                edge_type: "KNOWS".to_string(),
+                properties: PropertyMap::new(),
+            })
+            .await;
+        source.set_last_applied(3).await;
+
+        let installed = target.install_snapshot(source.create_snapshot().await).await;
+        assert_eq!(installed, Some(3));
+        assert_eq!(target.get_last_applied().await, 3);
+        assert_eq!(target.next_node_id().await, 3);
+        assert_eq!(target.next_edge_id().await, 8);
+        let batch = target
+            .query("default", "MATCH (a:Person)-[:KNOWS]->(b:Person) RETURN a, b")
+            .await
+            .unwrap();
+        assert_eq!(batch.len(), 1);
+        assert!(persistence.storage().get_edge("default", 7).unwrap().is_some());
+    }
+
+    #[tokio::test]
+    async fn test_watermarks_survive_restart() {
+        let temp_dir = TempDir::new().unwrap();
+        {
+            let sm = GraphStateMachine::new(Arc::new(PersistenceManager::new(temp_dir.path()).unwrap()));
+            for node_id in [1, 5] {
+                sm.apply(Request::CreateNode {
+                    tenant: "default".to_string(),
+                    node_id,
+                    labels: vec!["Person".to_string()],
+                    properties: PropertyMap::new(),
+                })
+                .await;
+            }
+            sm.apply(Request::CreateEdge {
+                tenant: "default".to_string(),
+                edge_id: 9,
+                source: 1,
+                target: 5,
+                edge_type: "KNOWS".to_string(),
+                properties: PropertyMap::new(),
+            })
+            .await;
+        }
+
+        let sm = GraphStateMachine::new(Arc::new(PersistenceManager::new(temp_dir.path()).unwrap()));
+        assert_eq!(sm.next_node_id().await, 6);
+        assert_eq!(sm.next_edge_id().await, 10);
+    }
+
+    #[tokio::test]
+    async fn test_graphs_survive_restart() {
+        let temp_dir = TempDir::new().unwrap();
+        {
+            let sm = GraphStateMachine::new(Arc::new(PersistenceManager::new(temp_dir.path()).unwrap()));
+            for node_id in [1, 2] {
+                sm.apply(Request::CreateNode {
+                    tenant: "default".to_string(),
+                    node_id,
+                    labels: vec!["Person".to_string()],
+                    properties: PropertyMap::new(),
+                })
+                .await;
+            }
+            sm.apply(Request::CreateEdge {
+                tenant: "default".to_string(),
+                edge_id: 1,
+                source: 1,
+                target: 2,
+                edge_type: "KNOWS".to_string(),
+                properties: PropertyMap::new(),
+            })
+            .await;
+        }
+
+        let sm = GraphStateMachine::new(Arc::new(PersistenceManager::new(temp_dir.path()).unwrap()));
+        let batch = sm
+            .query("default", "MATCH (a:Person)-[:KNOWS]->(b:Person) RETURN a, b")
+            .await
+            .unwrap();
+        assert_eq!(batch.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_applied_requests_are_queryable() {
+        let temp_dir = TempDir::new().unwrap();
+        let persistence = Arc::new(PersistenceManager::new(temp_dir.path()).unwrap());
+        let sm = GraphStateMachine::new(persistence);
+
+        let mut props = PropertyMap::new();
+        props.insert("name".to_string(), crate::graph::PropertyValue::String("Alice".to_string()));
+        for (node_id, properties) in [(1, props), (2, PropertyMap::new())] {
+            sm.apply(Request::CreateNode {
+                tenant: "default".to_string(),
+                node_id,
+                labels: vec!["Person".to_string()],
+                properties,
+            }).await;
+        }
+        sm.apply(Request::CreateEdge {
+            tenant: "default".to_string(),
+            edge_id: 1,
+            source: 1,
+            target: 2,
+            edge_type: "KNOWS".to_string(),
+            properties: PropertyMap::new(),
+        }).await;
+
+        let batch = sm
+            .query("default", "MATCH (a:Person {name: 'Alice'})-[:KNOWS]->(b) RETURN b")
+            .await
+            .unwrap();
+        assert_eq!(batch.len(), 1);
+
+        sm.apply(Request::DeleteNode { tenant: "default".to_string(), node_id: 2 }).await;
+        let batch = sm.query("default", "MATCH (n:Person) RETURN n").await.unwrap();
+        assert_eq!(batch.len(), 1);
+
+        // Other tenants see an empty graph; writes are refused on the read path
+        assert!(sm.query("other", "MATCH (n) RETURN n").await.unwrap().is_empty());
+        assert!(sm.query("default", "CREATE (n:Person)").await.is_err());
     }
 
     #[tokio::test]
@@ -6,18 +6,26 @@
 //!
 //! ## Property Graph → RDF
 //!
-//! - Node → rdf:type for each label
-//! - Node properties → property triples
-//! - Edge → triple with reified edge properties
+//! - Node → IRI `{base}node/{id}`, with an rdf:type `{base}{Label}` triple per label
+//! - Node properties → `{base}{key}` triples with XSD-typed literals
+//!   (arrays emit one triple per element, nulls are skipped)
+//! - Edge → `{source} {base}{EDGE_TYPE} {target}`; when the edge has properties
+//!   and `use_reification` is set, an `rdf:Statement` at `{base}edge/{id}`
+//!   carries them
 //!
 //! ## RDF → Property Graph
 //!
-//! - rdf:type triples → node labels
-//! - Property triples → node/edge properties
-//! - Reified statements → edges with properties
+//! - Each subject (and each IRI/blank object of a non-literal triple) → node;
+//!   IRIs not minted by this mapper are kept in a `uri` property
+//! - rdf:type triples → node labels (local name of the class IRI)
+//! - Literal-valued triples → node properties (repeated predicates → arrays)
+//! - IRI-valued triples → edges typed by the predicate's local name
+//! - Reified statements → properties on the edge they describe
 
-use crate::graph::{GraphStore, Node, Edge, Label, EdgeType, PropertyValue};
-use super::{RdfStore, Triple, NamedNode, RdfPredicate, RdfObject, Literal, RdfSubject};
+use crate::graph::{GraphStore, Node, NodeId, Edge, Label, EdgeType, PropertyMap, PropertyValue};
+use super::{RdfStore, RdfStoreError, Triple, NamedNode, RdfPredicate, RdfObject, Literal, RdfSubject};
+use oxrdf::vocab::{rdf, xsd};
+use std::collections::HashMap;
 use thiserror::Error;
 
 /// Mapping errors
@@ -34,6 +42,14 @@ pub enum MappingError {
     /// Missing base IRI
     #[error("Missing base IRI")]
     MissingBaseIri,
+
+    /// Graph store rejected a node or edge
+    #[error("Graph error: {0}")]
+    Graph(#[from] crate::graph::GraphError),
+
+    /// RDF store rejected a triple
+    #[error("RDF store error: {0}")]
+    Store(#[from] RdfStoreError),
 }
 
 pub type MappingResult<T> = Result<T, MappingError>;
@@ -80,34 +96,226 @@ impl GraphToRdfMapper {
         Self { config }
     }
 
+    /// IRI minted for a node
+    pub fn node_iri(&self, id: NodeId) -> MappingResult<NamedNode> {
+        self.iri(&format!("node/{}", id.as_u64()))
+    }
+
+    /// IRI minted for a reified edge statement
+    pub fn edge_iri(&self, id: crate::graph::EdgeId) -> MappingResult<NamedNode> {
+        self.iri(&format!("edge/{}", id.as_u64()))
+    }
+
     /// Map a node to RDF triples
     ///
-    /// TODO: Full implementation
-    /// - Convert node ID to IRI
-    /// - Add rdf:type triples for labels
-    /// - Add property triples
-    pub fn map_node(&self, _node: &Node) -> MappingResult<Vec<Triple>> {
-        // TODO: Implement node mapping
-        Ok(Vec::new())
+    /// Emits one rdf:type triple per label and one triple per property value.
+    pub fn map_node(&self, node: &Node) -> MappingResult<Vec<Triple>> {
+        let subject: RdfSubject = self.node_iri(node.id)?.into();
+        let mut triples = Vec::new();
+
+        let mut labels: Vec<&Label> = node.labels.iter().collect();
+        labels.sort_by(|a, b| a.as_str().cmp(b.as_str()));
+        for label in labels {
+            triples.push(Triple::new(
+                subject.clone(),
+                vocab(rdf::TYPE).into(),
+                self.iri(label.as_str())?.into(),
+            ));
+        }
+
+        self.map_properties(&subject, &node.properties, &mut triples)?;
+        Ok(triples)
     }
 
     /// Map an edge to RDF triples
     ///
-    /// TODO: Full implementation
-    /// - Create triple for edge relationship
-    /// - Optionally reify edge properties
-    pub fn map_edge(&self, _edge: &Edge) -> MappingResult<Vec<Triple>> {
-        // TODO: Implement edge mapping
-        Ok(Vec::new())
+    /// The relationship itself becomes a single triple. With `use_reification`
+    /// enabled, an edge that has properties is additionally described by an
+    /// `rdf:Statement` resource holding those properties.
+    pub fn map_edge(&self, edge: &Edge) -> MappingResult<Vec<Triple>> {
+        let source: RdfSubject = self.node_iri(edge.source)?.into();
+        let target = self.node_iri(edge.target)?;
+        let predicate: RdfPredicate = self.edge_type_iri(&edge.edge_type)?.into();
+
+        let mut triples = vec![Triple::new(
+            source.clone(),
+            predicate.clone(),
+            target.clone().into(),
+        )];
+
+        if self.config.use_reification && !edge.properties.is_empty() {
+            let statement: RdfSubject = self.edge_iri(edge.id)?.into();
+            let source_object = match source {
+                RdfSubject::NamedNode(n) => RdfObject::NamedNode(n),
+                RdfSubject::BlankNode(b) => RdfObject::BlankNode(b),
+            };
+            triples.push(Triple::new(statement.clone(), vocab(rdf::TYPE).into(), vocab(rdf::STATEMENT).into()));
+            triples.push(Triple::new(statement.clone(), vocab(rdf::SUBJECT).into(), source_object));
+            triples.push(Triple::new(
+                statement.clone(),
+                vocab(rdf::PREDICATE).into(),
+                predicate.as_named_node().clone().into(),
+            ));
+            triples.push(Triple::new(statement.clone(), vocab(rdf::OBJECT).into(), target.into()));
+            self.map_properties(&statement, &edge.properties, &mut triples)?;
+        }
+
+        Ok(triples)
+    }
+
+    /// Map every node and edge of a graph to RDF triples
+    pub fn map_store(&self, graph: &GraphStore) -> MappingResult<Vec<Triple>> {
+        let mut triples = Vec::new();
+        // all_nodes() yields every MVCC version; map only the current one
+        let mut node_ids: Vec<NodeId> = graph.all_nodes().iter().map(|n| n.id).collect();
+        node_ids.sort();
+        node_ids.dedup();
+        for node in node_ids.into_iter().filter_map(|id| graph.get_node(id)) {
+            triples.extend(self.map_node(node)?);
+        }
+        for edge in graph.all_edges() {
+            triples.extend(self.map_edge(&edge)?);
+        }
+        Ok(triples)
     }
 
     /// Synchronize property graph to RDF store
     ///
-    /// TODO: Full implementation
-    pub fn sync_to_rdf(&self, _graph: &GraphStore, _rdf: &mut RdfStore) -> MappingResult<()> {
-        // TODO: Implement full sync
+    /// Triples already present in the store are left as they are, so syncing
+    /// the same graph twice is idempotent.
+    pub fn sync_to_rdf(&self, graph: &GraphStore, rdf: &mut RdfStore) -> MappingResult<()> {
+        for triple in self.map_store(graph)? {
+            match rdf.insert(triple) {
+                Ok(()) | Err(RdfStoreError::DuplicateTriple) => {}
+                Err(e) => return Err(e.into()),
+            }
+        }
+        Ok(())
+    }
+
+    /// Emit property triples for `subject`
+    fn map_properties(
+        &self,
+        subject: &RdfSubject,
+        properties: &PropertyMap,
+        triples: &mut Vec<Triple>,
+    ) -> MappingResult<()> {
+        let mut keys: Vec<&String> = properties.keys().collect();
+        keys.sort();
+        for key in keys {
+            let predicate: RdfPredicate = self.iri(key)?.into();
+            let values = match &properties[key] {
+                PropertyValue::Array(items) => items.iter().collect(),
+                value => vec![value],
+            };
+            for value in values {
+                if let Some(literal) = property_literal(value)? {
+                    triples.push(Triple::new(subject.clone(), predicate.clone(), literal.into()));
+                }
+            }
+        }
         Ok(())
     }
+
+    /// IRI used as predicate for an edge type
+    fn edge_type_iri(&self, edge_type: &EdgeType) -> MappingResult<NamedNode> {
+        self.iri(edge_type.as_str())
+    }
+
+    /// Mint an IRI under the base IRI
+    fn iri(&self, local: &str) -> MappingResult<NamedNode> {
+        if self.config.base_iri.is_empty() {
+            return Err(MappingError::MissingBaseIri);
+        }
+        let iri = format!("{}{}", self.config.base_iri, local);
+        NamedNode::new(&iri).map_err(|_| MappingError::InvalidIri(iri))
+    }
+}
+
+/// Wrap an oxrdf vocabulary term
+fn vocab(term: oxrdf::NamedNodeRef<'_>) -> NamedNode {
+    term.into_owned().into()
+}
+
+/// Convert a property value to an XSD-typed literal (`None` for nulls)
+fn property_literal(value: &PropertyValue) -> MappingResult<Option<Literal>> {
+    let literal = match value {
+        PropertyValue::String(s) => Literal::new_simple_literal(s.clone()),
+        PropertyValue::Integer(i) => Literal::new_typed_literal(i.to_string(), vocab(xsd::INTEGER)),
+        PropertyValue::Float(f) => Literal::new_typed_literal(xsd_double(*f), vocab(xsd::DOUBLE)),
+        PropertyValue::Boolean(b) => Literal::new_typed_literal(b.to_string(), vocab(xsd::BOOLEAN)),
+        PropertyValue::DateTime(millis) => {
+            let datetime = chrono::DateTime::from_timestamp_millis(*millis).ok_or_else(|| {
+                MappingError::UnsupportedPropertyType(format!("DateTime out of range: {}", millis))
+            })?;
+            Literal::new_typed_literal(xsd_date_time(&datetime), vocab(xsd::DATE_TIME))
+        }
+        PropertyValue::Duration { months, days, seconds, nanos } => Literal::new_typed_literal(
+            xsd_duration(*months, *days, *seconds, *nanos)?,
+            vocab(xsd::DURATION),
+        ),
+        PropertyValue::Null => return Ok(None),
+        PropertyValue::Array(_) | PropertyValue::Map(_) | PropertyValue::Vector(_) => {
+            return Err(MappingError::UnsupportedPropertyType(format!("{:?}", value)));
+        }
+    };
+    Ok(Some(literal))
+}
+
+/// xsd:double lexical form (`INF`, `-INF` and `NaN` for the special values)
+pub(crate) fn xsd_double(f: f64) -> String {
+    if f.is_nan() {
+        "NaN".to_string()
+    } else if f.is_infinite() {
+        if f > 0.0 { "INF" } else { "-INF" }.to_string()
+    } else {
+        f.to_string()
+    }
+}
+
+/// xsd:dateTime lexical form in UTC with millisecond precision
+///
+/// chrono writes years past 9999 with a leading `+`, which XSD does not allow.
+fn xsd_date_time(datetime: &chrono::DateTime<chrono::Utc>) -> String {
+    use chrono::Datelike;
+    let year = datetime.year();
+    let sign = if year < 0 { "-" } else { "" };
+    format!("{}{:04}{}", sign, year.unsigned_abs(), datetime.format("-%m-%dT%H:%M:%S%.3fZ"))
+}
+
+/// xsd:duration lexical form
+///
+/// XSD durations carry a single leading sign, so components of mixed sign
+/// cannot be represented and are rejected.
+fn xsd_duration(months: i64, days: i64, seconds: i64, nanos: i32) -> MappingResult<String> {
+    let total_nanos = seconds as i128 * 1_000_000_000 + nanos as i128;
+    let components = [months as i128, days as i128, total_nanos];
+    let negative = components.iter().any(|c| *c < 0);
+    if negative && components.iter().any(|c| *c > 0) {
+        return Err(MappingError::UnsupportedPropertyType(format!(
+            "Duration with mixed-sign components: {} months, {} days, {}s {}ns",
+            months, days, seconds, nanos
+        )));
+    }
+
+    let mut lexical = String::from(if negative { "-P" } else { "P" });
+    if months != 0 {
+        lexical.push_str(&format!("{}M", months.unsigned_abs()));
+    }
+    if days != 0 {
+        lexical.push_str(&format!("{}D", days.unsigned_abs()));
+    }
+    let total_nanos = total_nanos.unsigned_abs();
+    if total_nanos != 0 || (months == 0 && days == 0) {
+        let (secs, frac) = (total_nanos / 1_000_000_000, total_nanos % 1_000_000_000);
+        if frac == 0 {
+            lexical.push_str(&format!("T{}S", secs));
+        } else {
+            let frac = format!("{:09}", frac);
+            lexical.push_str(&format!("T{}.{}S", secs, frac.trim_end_matches('0')));
+        }
+    }
+    Ok(lexical)
 }
 
 /// RDF → Property Graph mapper
@@ -123,18 +331,247 @@ impl RdfToGraphMapper {
         }
     }
 
-    /// Map RDF triples to property graph
+    /// Create a mapper with custom configuration
+    pub fn with_config(config: MappingConfig) -> Self {
+        Self { config }
+    }
+
+    /// Map the contents of an RDF store to property graph
+    pub fn map_to_graph(&self, rdf: &RdfStore, graph: &mut GraphStore) -> MappingResult<()> {
+        let triples: Vec<Triple> = rdf.iter().cloned().collect();
+        self.map_triples(&triples, graph)
+    }
+
+    /// Import triples into a property graph
     ///
-    /// TODO: Full implementation
-    pub fn map_to_graph(&self, _rdf: &RdfStore, _graph: &mut GraphStore) -> MappingResult<()> {
-        // TODO: Implement RDF to graph mapping
+    /// Triples are grouped by subject and one node is created per distinct
+    /// resource. Blank nodes map to anonymous nodes; the same blank node label
+    /// resolves to the same node within one call. Statements reified with
+    /// `rdf:Statement` become properties of the edge they describe instead of
+    /// nodes of their own.
+    pub fn map_triples(&self, triples: &[Triple], graph: &mut GraphStore) -> MappingResult<()> {
+        let rdf_type = RdfPredicate::from(vocab(rdf::TYPE));
+        let statement_class = RdfObject::NamedNode(vocab(rdf::STATEMENT));
+
+        // Reified statements: subject → (s, p, o) of the edge it describes
+        let statements: Vec<&RdfSubject> = triples
+            .iter()
+            .filter(|t| t.predicate == rdf_type && t.object == statement_class)
+            .map(|t| &t.subject)
+            .collect();
+        let mut reified: HashMap<&RdfSubject, ReifiedStatement> = statements
+            .into_iter()
+            .map(|s| (s, ReifiedStatement::default()))
+            .collect();
+
+        // Group the remaining triples by subject, keeping first-seen order
+        let mut subjects: Vec<&RdfSubject> = Vec::new();
+        let mut by_subject: HashMap<&RdfSubject, Vec<&Triple>> = HashMap::new();
+        for triple in triples {
+            if let Some(statement) = reified.get_mut(&triple.subject) {
+                statement.add(triple, &rdf_type);
+                continue;
+            }
+            by_subject
+                .entry(&triple.subject)
+                .or_insert_with(|| {
+                    subjects.push(&triple.subject);
+                    Vec::new()
+                })
+                .push(triple);
+        }
+
+        // One node per subject, with labels and literal properties
+        let mut nodes: HashMap<RdfSubject, NodeId> = HashMap::new();
+        for subject in subjects {
+            let mut labels = Vec::new();
+            let mut properties = PropertyMap::new();
+            for triple in &by_subject[subject] {
+                match &triple.object {
+                    RdfObject::NamedNode(class) if triple.predicate == rdf_type => {
+                        labels.push(Label::new(self.local_name(class.as_str())));
+                    }
+                    RdfObject::Literal(literal) => {
+                        let key = self.local_name(triple.predicate.as_named_node().as_str());
+                        let value = literal_value(literal);
+                        match properties.remove(&key) {
+                            None => properties.insert(key, value),
+                            Some(PropertyValue::Array(mut items)) => {
+                                items.push(value);
+                                properties.insert(key, PropertyValue::Array(items))
+                            }
+                            Some(first) => properties.insert(key, PropertyValue::Array(vec![first, value])),
+                        };
+                    }
+                    _ => {}
+                }
+            }
+            if let Some(uri) = self.foreign_iri(subject) {
+                properties.entry("uri".to_string()).or_insert(PropertyValue::String(uri));
+            }
+            let id = graph.create_node_with_properties("default", labels, properties);
+            nodes.insert(subject.clone(), id);
+        }
+
+        // IRI/blank-valued triples become edges
+        let mut edges: HashMap<(NodeId, String, NodeId), crate::graph::EdgeId> = HashMap::new();
+        for triple in triples {
+            if triple.predicate == rdf_type || reified.contains_key(&triple.subject) {
+                continue;
+            }
+            let target = match object_as_subject(&triple.object) {
+                Some(target) => target,
+                None => continue,
+            };
+            let source = nodes[&triple.subject];
+            let target = self.resolve_node(&target, &mut nodes, graph);
+            let edge_type = self.local_name(triple.predicate.as_named_node().as_str());
+            let edge = graph.create_edge(source, target, edge_type.as_str())?;
+            edges.insert((source, edge_type, target), edge);
+        }
+
+        // Reified statement properties land on the edge they describe
+        for statement in reified.into_values() {
+            let (subject, predicate, object) = match (statement.subject, statement.predicate, statement.object) {
+                (Some(s), Some(p), Some(o)) => (s, p, o),
+                _ => continue,
+            };
+            let source = self.resolve_node(&subject, &mut nodes, graph);
+            let target = self.resolve_node(&object, &mut nodes, graph);
+            let edge_type = self.local_name(predicate.as_str());
+            let edge = match edges.get(&(source, edge_type.clone(), target)) {
+                Some(edge) => *edge,
+                None => {
+                    let edge = graph.create_edge(source, target, edge_type.as_str())?;
+                    edges.insert((source, edge_type, target), edge);
+                    edge
+                }
+            };
+            for (key, value) in statement.properties {
+                let key = self.local_name(key.as_str());
+                let _ = graph.set_edge_property(edge, key, literal_value(&value));
+            }
+        }
+
         Ok(())
     }
+
+    /// Node for a resource, creating an unlabeled one if it was never a subject
+    fn resolve_node(
+        &self,
+        resource: &RdfSubject,
+        nodes: &mut HashMap<RdfSubject, NodeId>,
+        graph: &mut GraphStore,
+    ) -> NodeId {
+        if let Some(id) = nodes.get(resource) {
+            return *id;
+        }
+        let mut properties = PropertyMap::new();
+        if let Some(uri) = self.foreign_iri(resource) {
+            properties.insert("uri".to_string(), PropertyValue::String(uri));
+        }
+        let id = graph.create_node_with_properties("default", Vec::new(), properties);
+        nodes.insert(resource.clone(), id);
+        id
+    }
+
+    /// IRI of a named resource that was not minted by `GraphToRdfMapper` for this base
+    fn foreign_iri(&self, resource: &RdfSubject) -> Option<String> {
+        match resource {
+            RdfSubject::NamedNode(n) => {
+                let minted = format!("{}node/", self.config.base_iri);
+                if n.as_str().starts_with(&minted) {
+                    None
+                } else {
+                    Some(n.as_str().to_string())
+                }
+            }
+            RdfSubject::BlankNode(_) => None,
+        }
+    }
+
+    /// Label, property key or edge type for an IRI: the part after the base
+    /// IRI, or after the last `#` or `/` for IRIs from other vocabularies
+    fn local_name(&self, iri: &str) -> String {
+        if let Some(local) = iri.strip_prefix(&self.config.base_iri) {
+            if !local.is_empty() {
+                return local.to_string();
+            }
+        }
+        iri.rsplit(['#', '/']).next().unwrap_or(iri).to_string()
+    }
+}
+
+/// Parts of an `rdf:Statement` collected during import
+#[derive(Default)]
+struct ReifiedStatement {
+    subject: Option<RdfSubject>,
+    predicate: Option<NamedNode>,
+    object: Option<RdfSubject>,
+    properties: Vec<(NamedNode, Literal)>,
+}
+
+impl ReifiedStatement {
+    fn add(&mut self, triple: &Triple, rdf_type: &RdfPredicate) {
+        let predicate = triple.predicate.as_named_node();
+        if predicate == &vocab(rdf::SUBJECT) {
+            self.subject = object_as_subject(&triple.object);
+        } else if predicate == &vocab(rdf::PREDICATE) {
+            if let RdfObject::NamedNode(n) = &triple.object {
+                self.predicate = Some(n.clone());
+            }
+        } else if predicate == &vocab(rdf::OBJECT) {
+            self.object = object_as_subject(&triple.object);
+        } else if &triple.predicate != rdf_type {
+            if let RdfObject::Literal(literal) = &triple.object {
+                self.properties.push((predicate.clone(), literal.clone()));
+            }
+        }
+    }
+}
+
+/// An IRI or blank node object viewed as a subject (`None` for literals)
+fn object_as_subject(object: &RdfObject) -> Option<RdfSubject> {
+    match object {
+        RdfObject::NamedNode(n) => Some(RdfSubject::NamedNode(n.clone())),
+        RdfObject::BlankNode(b) => Some(RdfSubject::BlankNode(b.clone())),
+        RdfObject::Literal(_) => None,
+    }
+}
+
+/// Convert a literal to a property value based on its XSD datatype
+fn literal_value(literal: &Literal) -> PropertyValue {
+    let value = literal.value();
+    let datatype = literal.datatype();
+    let parsed = match datatype.as_str() {
+        dt if dt == xsd::INTEGER.as_str()
+            || dt == xsd::INT.as_str()
+            || dt == xsd::LONG.as_str()
+            || dt == xsd::SHORT.as_str() =>
+        {
+            value.parse().ok().map(PropertyValue::Integer)
+        }
+        dt if dt == xsd::DOUBLE.as_str() || dt == xsd::FLOAT.as_str() || dt == xsd::DECIMAL.as_str() => {
+            value.parse().ok().map(PropertyValue::Float)
+        }
+        dt if dt == xsd::BOOLEAN.as_str() => match value {
+            "true" | "1" => Some(PropertyValue::Boolean(true)),
+            "false" | "0" => Some(PropertyValue::Boolean(false)),
+            _ => None,
+        },
+        dt if dt == xsd::DATE_TIME.as_str() => chrono::DateTime::parse_from_rfc3339(value)
+            .ok()
+            .map(|d| PropertyValue::DateTime(d.timestamp_millis())),
+        _ => None,
+    };
+    // Ill-typed or unknown literals keep their lexical form
+    parsed.unwrap_or_else(|| PropertyValue::String(value.to_string()))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::rdf::BlankNode;
 
     #[test]
     fn test_mapper_creation() {
@@ -142,16 +579,170 @@ mod tests {
         assert_eq!(mapper.config.base_iri, "http://example.org/");
     }
 
+    fn knows_graph() -> GraphStore {
+        let mut graph = GraphStore::new();
+        let alice = graph.create_node("Person");
+        graph.set_node_property("default", alice, "name", "Alice").unwrap();
+        graph.set_node_property("default", alice, "age", 30i64).unwrap();
+        let bob = graph.create_node("Person");
+        graph.set_node_property("default", bob, "name", "Bob").unwrap();
+        let knows = graph.create_edge(alice, bob, "KNOWS").unwrap();
+        graph.set_edge_property(knows, "since", 2020i64).unwrap();
+        graph
+    }
+
+    #[test]
+    fn test_node_mapping() {
+        let mapper = GraphToRdfMapper::new("http://example.org/");
+        let graph = knows_graph();
+        let alice = graph.get_nodes_by_label(&Label::new("Person"))
+            .into_iter()
+            .find(|n| n.get_property("name") == Some(&PropertyValue::String("Alice".to_string())))
+            .unwrap();
+
+        let triples = mapper.map_node(alice).unwrap();
+        assert_eq!(triples.len(), 3);
+
+        let subject: RdfSubject = mapper.node_iri(alice.id).unwrap().into();
+        let person = NamedNode::new("http://example.org/Person").unwrap();
+        assert!(triples.contains(&Triple::new(subject.clone(), vocab(rdf::TYPE).into(), person.into())));
+
+        let age = Literal::new_typed_literal("30", vocab(xsd::INTEGER));
+        assert!(triples.contains(&Triple::new(
+            subject,
+            RdfPredicate::new("http://example.org/age").unwrap(),
+            age.into(),
+        )));
+    }
+
+    #[test]
+    fn test_map_store_with_reified_edge() {
+        let graph = knows_graph();
+        let mapper = GraphToRdfMapper::new("http://example.org/");
+        let triples = mapper.map_store(&graph).unwrap();
+
+        // 2 types + 3 node properties + 1 edge + 4 reification + 1 edge property
+        assert_eq!(triples.len(), 11);
+        let knows = RdfPredicate::new("http://example.org/KNOWS").unwrap();
+        assert_eq!(triples.iter().filter(|t| t.predicate == knows).count(), 1);
+
+        let mut config = MappingConfig::new("http://example.org/");
+        config.use_reification = false;
+        let plain = GraphToRdfMapper::with_config(config).map_store(&graph).unwrap();
+        assert_eq!(plain.len(), 6);
+    }
+
     #[test]
-    fn test_node_mapping_stub() {
+    fn test_sync_to_rdf_is_idempotent() {
+        let graph = knows_graph();
         let mapper = GraphToRdfMapper::new("http://example.org/");
+        let mut store = RdfStore::new();
+        mapper.sync_to_rdf(&graph, &mut store).unwrap();
+        mapper.sync_to_rdf(&graph, &mut store).unwrap();
+        assert_eq!(store.len(), 11);
+    }
+
+    #[test]
+    fn test_foaf_round_trip() {
         let mut graph = GraphStore::new();
-        let node_id = graph.create_node("Person");
+        let alice = graph.create_node("Person");
+        graph.set_node_property("default", alice, "name", "Alice").unwrap();
+        let bob = graph.create_node("Person");
+        graph.set_node_property("default", bob, "name", "Bob").unwrap();
+        let carol = graph.create_node("Person");
+        graph.set_node_property("default", carol, "age", 41i64).unwrap();
+        let knows = graph.create_edge(alice, bob, "knows").unwrap();
+        graph.set_edge_property(knows, "since", 2020i64).unwrap();
+        graph.create_edge(bob, carol, "knows").unwrap();
 
-        if let Some(node) = graph.get_node(node_id) {
-            let triples = mapper.map_node(node).unwrap();
-            // TODO: Add assertions once implemented
-            assert!(triples.is_empty()); // Stub returns empty
-        }
+        let triples = GraphToRdfMapper::new("http://xmlns.com/foaf/0.1/").map_store(&graph).unwrap();
+        let mut imported = GraphStore::new();
+        RdfToGraphMapper::new("http://xmlns.com/foaf/0.1/")
+            .map_triples(&triples, &mut imported)
+            .unwrap();
+
+        assert_eq!(imported.node_count(), graph.node_count());
+        assert_eq!(imported.edge_count(), graph.edge_count());
+        assert_eq!(imported.get_nodes_by_label(&Label::new("Person")).len(), 3);
+        let since: Vec<Edge> = imported
+            .all_edges()
+            .into_iter()
+            .filter(|e| e.get_property("since") == Some(&PropertyValue::Integer(2020)))
+            .collect();
+        assert_eq!(since.len(), 1);
+        assert_eq!(since[0].edge_type.as_str(), "knows");
+    }
+
+    #[test]
+    fn test_blank_nodes_and_foreign_iris() {
+        let foaf = |local: &str| RdfPredicate::new(&format!("http://xmlns.com/foaf/0.1/{}", local)).unwrap();
+        let alice: RdfSubject = NamedNode::new("http://people.example/alice").unwrap().into();
+        let anon = BlankNode::from_str("b0").unwrap();
+        let triples = vec![
+            Triple::new(alice.clone(), vocab(rdf::TYPE).into(), NamedNode::new("http://xmlns.com/foaf/0.1/Person").unwrap().into()),
+            Triple::new(alice.clone(), foaf("name"), Literal::new_simple_literal("Alice").into()),
+            Triple::new(alice.clone(), foaf("knows"), anon.clone().into()),
+            Triple::new(anon.clone().into(), foaf("name"), Literal::new_simple_literal("Someone").into()),
+            Triple::new(anon.clone().into(), foaf("nick"), Literal::new_simple_literal("S").into()),
+            Triple::new(anon.clone().into(), foaf("nick"), Literal::new_simple_literal("Anon").into()),
+        ];
+
+        let mut graph = GraphStore::new();
+        RdfToGraphMapper::new("http://example.org/").map_triples(&triples, &mut graph).unwrap();
+
+        // The blank node is referenced three times but maps to one node
+        assert_eq!(graph.node_count(), 2);
+        assert_eq!(graph.edge_count(), 1);
+        let person = &graph.get_nodes_by_label(&Label::new("Person"))[0];
+        assert_eq!(
+            person.get_property("uri"),
+            Some(&PropertyValue::String("http://people.example/alice".to_string()))
+        );
+        let edge = &graph.all_edges()[0];
+        let someone = graph.get_node(edge.target).unwrap();
+        assert_eq!(
+            someone.get_property("nick"),
+            Some(&PropertyValue::Array(vec![
+                PropertyValue::String("S".to_string()),
+                PropertyValue::String("Anon".to_string()),
+            ]))
+        );
+    }
+
+    fn lexical(value: PropertyValue) -> String {
+        property_literal(&value).unwrap().unwrap().value().to_string()
+    }
+
+    #[test]
+    fn test_literal_lexical_forms() {
+        assert_eq!(lexical(PropertyValue::Float(1.5)), "1.5");
+        assert_eq!(lexical(PropertyValue::Float(f64::INFINITY)), "INF");
+        assert_eq!(lexical(PropertyValue::Float(f64::NEG_INFINITY)), "-INF");
+        assert_eq!(lexical(PropertyValue::Float(f64::NAN)), "NaN");
+
+        assert_eq!(lexical(PropertyValue::DateTime(0)), "1970-01-01T00:00:00.000Z");
+        // 10000-01-01T00:00:00Z
+        assert_eq!(lexical(PropertyValue::DateTime(253_402_300_800_000)), "10000-01-01T00:00:00.000Z");
+
+        let duration = |months, days, seconds, nanos| PropertyValue::Duration { months, days, seconds, nanos };
+        assert_eq!(lexical(duration(14, 3, 7261, 0)), "P14M3DT7261S");
+        assert_eq!(lexical(duration(0, 0, 1, 500_000_000)), "PT1.5S");
+        assert_eq!(lexical(duration(-1, -2, 0, 0)), "-P1M2D");
+        assert_eq!(lexical(duration(0, 0, -2, 500_000_000)), "-PT1.5S");
+        assert_eq!(lexical(duration(0, 0, 0, 0)), "PT0S");
+        assert!(matches!(
+            property_literal(&duration(1, -1, 0, 0)),
+            Err(MappingError::UnsupportedPropertyType(_))
+        ));
+    }
+
+    #[test]
+    fn test_unsupported_property_type() {
+        let mapper = GraphToRdfMapper::new("http://example.org/");
+        let mut graph = GraphStore::new();
+        let id = graph.create_node("Doc");
+        graph.set_node_property("default", id, "embedding", PropertyValue::Vector(vec![0.1, 0.2])).unwrap();
+        let err = mapper.map_node(graph.get_node(id).unwrap()).unwrap_err();
+        assert!(matches!(err, MappingError::UnsupportedPropertyType(_)));
     }
 }
@@ -58,6 +58,7 @@ pub use mapping::{
     GraphToRdfMapper, RdfToGraphMapper,
     MappingConfig, MappingError, MappingResult,
 };
+pub(crate) use mapping::xsd_double;
 
 pub use namespace::{
     NamespaceManager, Namespace,
@@ -2,7 +2,9 @@
 //!
 //! Implements basic RDFS entailment rules for inference.
 
-use super::{RdfStore, Triple};
+use super::{NamedNode, RdfObject, RdfPredicate, RdfStore, RdfStoreError, RdfSubject, Triple};
+use oxrdf::vocab::{rdf, rdfs};
+use std::collections::{HashMap, HashSet};
 use thiserror::Error;
 
 /// Reasoning errors
@@ -65,24 +67,163 @@ impl RdfsReasoner {
         }
     }
 
-    /// Materialize all inferences
+    /// Compute every triple entailed by the enabled rules without modifying the store
     ///
-    /// TODO: Implement RDFS entailment rules
+    /// Rules are applied by forward chaining until a fixpoint is reached:
     /// - rdfs:subClassOf transitivity: (A subClassOf B) ∧ (B subClassOf C) → (A subClassOf C)
     /// - rdfs:subPropertyOf transitivity
     /// - rdfs:domain: (P domain C) ∧ (X P Y) → (X type C)
-    /// - rdfs:range: (P range C) ∧ (X P Y) → (Y type C)
+    /// - rdfs:range: (P range C) ∧ (X P Y) → (Y type C), for non-literal Y
     /// - Type inheritance: (X type A) ∧ (A subClassOf B) → (X type B)
-    pub fn materialize(&self, _store: &RdfStore) -> ReasoningResult<Vec<Triple>> {
-        // TODO: Implement materialization
-        Ok(Vec::new())
+    ///
+    /// Only triples not already in the store are returned, in derivation order.
+    pub fn infer(&self, store: &RdfStore) -> ReasoningResult<Vec<Triple>> {
+        let mut known: HashSet<Triple> = store.iter().cloned().collect();
+        let mut inferred = Vec::new();
+
+        loop {
+            let round: Vec<Triple> = self
+                .apply_rules(&known)
+                .into_iter()
+                .filter(|t| !known.contains(t))
+                .collect();
+            if round.is_empty() {
+                break;
+            }
+            for triple in round {
+                if known.insert(triple.clone()) {
+                    inferred.push(triple);
+                }
+            }
+        }
+
+        Ok(inferred)
+    }
+
+    /// Insert all inferred triples into the store, returning how many were added
+    pub fn materialize(&self, store: &mut RdfStore) -> ReasoningResult<usize> {
+        let inferred = self.infer(store)?;
+        let count = inferred.len();
+        for triple in inferred {
+            match store.insert(triple) {
+                Ok(()) | Err(RdfStoreError::DuplicateTriple) => {}
+                Err(e) => return Err(ReasoningError::InferenceError(e.to_string())),
+            }
+        }
+        Ok(count)
+    }
+
+    /// Apply reasoning and add inferred triples to store (same as [`materialize`](Self::materialize))
+    pub fn reason(&self, store: &mut RdfStore) -> ReasoningResult<usize> {
+        self.materialize(store)
+    }
+
+    /// One round of every enabled rule over `triples`
+    fn apply_rules(&self, triples: &HashSet<Triple>) -> Vec<Triple> {
+        let rdf_type = RdfPredicate::from(vocab(rdf::TYPE));
+        let sub_class_of = RdfPredicate::from(vocab(rdfs::SUB_CLASS_OF));
+        let sub_property_of = RdfPredicate::from(vocab(rdfs::SUB_PROPERTY_OF));
+        let domain = RdfPredicate::from(vocab(rdfs::DOMAIN));
+        let range = RdfPredicate::from(vocab(rdfs::RANGE));
+
+        let super_classes = index_by_subject(triples, &sub_class_of);
+        let mut out = Vec::new();
+
+        for rule in &self.enabled_rules {
+            match rule {
+                InferenceRule::SubClassOfTransitive => {
+                    out.extend(transitive_step(triples, &sub_class_of, &super_classes));
+                }
+                InferenceRule::SubPropertyOfTransitive => {
+                    let super_properties = index_by_subject(triples, &sub_property_of);
+                    out.extend(transitive_step(triples, &sub_property_of, &super_properties));
+                }
+                InferenceRule::TypeInheritance => {
+                    for t in triples.iter().filter(|t| t.predicate == rdf_type) {
+                        let supers = as_subject(&t.object).and_then(|class| super_classes.get(&class));
+                        for class in supers.into_iter().flatten() {
+                            out.push(Triple::new(t.subject.clone(), rdf_type.clone(), (*class).clone()));
+                        }
+                    }
+                }
+                InferenceRule::DomainInference => {
+                    let domains = index_by_predicate(triples, &domain);
+                    for t in triples {
+                        for class in domains.get(t.predicate.as_named_node()).into_iter().flatten() {
+                            out.push(Triple::new(t.subject.clone(), rdf_type.clone(), (*class).clone()));
+                        }
+                    }
+                }
+                InferenceRule::RangeInference => {
+                    let ranges = index_by_predicate(triples, &range);
+                    for t in triples {
+                        let Some(object) = as_subject(&t.object) else { continue };
+                        for class in ranges.get(t.predicate.as_named_node()).into_iter().flatten() {
+                            out.push(Triple::new(object.clone(), rdf_type.clone(), (*class).clone()));
+                        }
+                    }
+                }
+            }
+        }
+
+        out
+    }
+}
+
+/// Wrap an oxrdf vocabulary term
+fn vocab(term: oxrdf::NamedNodeRef<'_>) -> NamedNode {
+    term.into_owned().into()
+}
+
+/// A named or blank object viewed as a subject (`None` for literals)
+fn as_subject(object: &RdfObject) -> Option<RdfSubject> {
+    match object {
+        RdfObject::NamedNode(n) => Some(RdfSubject::NamedNode(n.clone())),
+        RdfObject::BlankNode(b) => Some(RdfSubject::BlankNode(b.clone())),
+        RdfObject::Literal(_) => None,
+    }
+}
+
+/// subject → objects for every triple with `predicate`
+fn index_by_subject<'a>(
+    triples: &'a HashSet<Triple>,
+    predicate: &RdfPredicate,
+) -> HashMap<RdfSubject, Vec<&'a RdfObject>> {
+    let mut index: HashMap<RdfSubject, Vec<&RdfObject>> = HashMap::new();
+    for t in triples.iter().filter(|t| &t.predicate == predicate) {
+        index.entry(t.subject.clone()).or_default().push(&t.object);
+    }
+    index
+}
+
+/// property IRI → objects for schema triples like `P rdfs:domain C`
+fn index_by_predicate<'a>(
+    triples: &'a HashSet<Triple>,
+    predicate: &RdfPredicate,
+) -> HashMap<NamedNode, Vec<&'a RdfObject>> {
+    let mut index: HashMap<NamedNode, Vec<&RdfObject>> = HashMap::new();
+    for t in triples.iter().filter(|t| &t.predicate == predicate) {
+        if let RdfSubject::NamedNode(property) = &t.subject {
+            index.entry(property.clone()).or_default().push(&t.object);
+        }
     }
+    index
+}
 
-    /// Apply reasoning and add inferred triples to store
-    pub fn reason(&self, _store: &mut RdfStore) -> ReasoningResult<usize> {
-        // TODO: Implement reasoning
-        Ok(0)
+/// (A p B) ∧ (B p C) → (A p C)
+fn transitive_step(
+    triples: &HashSet<Triple>,
+    predicate: &RdfPredicate,
+    index: &HashMap<RdfSubject, Vec<&RdfObject>>,
+) -> Vec<Triple> {
+    let mut out = Vec::new();
+    for t in triples.iter().filter(|t| &t.predicate == predicate) {
+        let next = as_subject(&t.object).and_then(|b| index.get(&b));
+        for c in next.into_iter().flatten() {
+            out.push(Triple::new(t.subject.clone(), predicate.clone(), (*c).clone()));
+        }
     }
+    out
 }
 
 impl Default for RdfsReasoner {
@@ -107,12 +248,74 @@ mod tests {
         assert_eq!(reasoner.enabled_rules.len(), 1);
     }
 
+    fn iri(local: &str) -> NamedNode {
+        NamedNode::new(&format!("http://example.org/{}", local)).unwrap()
+    }
+
+    fn triple(s: &str, p: NamedNode, o: &str) -> Triple {
+        Triple::new(iri(s).into(), p.into(), iri(o).into())
+    }
+
     #[test]
-    fn test_materialization_stub() {
+    fn test_empty_store_infers_nothing() {
         let reasoner = RdfsReasoner::new();
         let store = RdfStore::new();
 
-        let inferred = reasoner.materialize(&store).unwrap();
-        assert!(inferred.is_empty()); // Stub returns empty
+        let inferred = reasoner.infer(&store).unwrap();
+        assert!(inferred.is_empty());
+    }
+
+    #[test]
+    fn test_type_propagates_up_class_hierarchy() {
+        let mut store = RdfStore::new();
+        store.insert(triple("Dog", vocab(rdfs::SUB_CLASS_OF), "Mammal")).unwrap();
+        store.insert(triple("Mammal", vocab(rdfs::SUB_CLASS_OF), "Animal")).unwrap();
+        store.insert(triple("rex", vocab(rdf::TYPE), "Dog")).unwrap();
+
+        let reasoner = RdfsReasoner::new();
+        let inferred = reasoner.infer(&store).unwrap();
+        assert!(inferred.contains(&triple("Dog", vocab(rdfs::SUB_CLASS_OF), "Animal")));
+        assert!(inferred.contains(&triple("rex", vocab(rdf::TYPE), "Mammal")));
+        assert!(inferred.contains(&triple("rex", vocab(rdf::TYPE), "Animal")));
+        assert_eq!(inferred.len(), 3);
+        // infer() leaves the store untouched
+        assert_eq!(store.len(), 3);
+
+        assert_eq!(reasoner.materialize(&mut store).unwrap(), 3);
+        assert!(store.contains(&triple("rex", vocab(rdf::TYPE), "Animal")));
+        // Already closed: nothing more to add
+        assert_eq!(reasoner.materialize(&mut store).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_domain_range_and_subproperty() {
+        let mut store = RdfStore::new();
+        store.insert(triple("hasOwner", vocab(rdfs::DOMAIN), "Pet")).unwrap();
+        store.insert(triple("hasOwner", vocab(rdfs::RANGE), "Person")).unwrap();
+        store.insert(triple("hasOwner", vocab(rdfs::SUB_PROPERTY_OF), "relatedTo")).unwrap();
+        store.insert(triple("relatedTo", vocab(rdfs::SUB_PROPERTY_OF), "linkedTo")).unwrap();
+        store.insert(triple("rex", iri("hasOwner"), "alice")).unwrap();
+        store.insert(Triple::new(
+            iri("rex").into(),
+            iri("hasOwner").into(),
+            crate::rdf::Literal::new_simple_literal("Alice").into(),
+        )).unwrap();
+
+        let inferred = RdfsReasoner::new().infer(&store).unwrap();
+        assert!(inferred.contains(&triple("rex", vocab(rdf::TYPE), "Pet")));
+        assert!(inferred.contains(&triple("alice", vocab(rdf::TYPE), "Person")));
+        assert!(inferred.contains(&triple("hasOwner", vocab(rdfs::SUB_PROPERTY_OF), "linkedTo")));
+        // Literals never get a type
+        assert_eq!(inferred.len(), 3);
+    }
+
+    #[test]
+    fn test_only_enabled_rules_fire() {
+        let mut store = RdfStore::new();
+        store.insert(triple("Dog", vocab(rdfs::SUB_CLASS_OF), "Animal")).unwrap();
+        store.insert(triple("rex", vocab(rdf::TYPE), "Dog")).unwrap();
+
+        let reasoner = RdfsReasoner::with_rules(vec![InferenceRule::SubClassOfTransitive]);
+        assert!(reasoner.infer(&store).unwrap().is_empty());
     }
 }
@@ -1,22 +1,46 @@
-//! JSON-LD format implementation (Basic)
+//! JSON-LD format implementation
+//!
+//! Output is compacted against a context built from a [`NamespaceManager`]:
+//! one node object per subject in `@graph`, `rdf:type` as an `@type` array,
+//! plain strings for simple literals and value objects for typed and
+//! language-tagged ones.
+//!
+//! The parser expands a document into a flat list of triples. It resolves
+//! inline `@context`s (prefixes, terms, `@vocab`, default `@language` and
+//! `@id`/datatype coercion), `@id`, `@type`, value objects, native numbers and
+//! booleans, and nested node objects; a node without `@id` gets a fresh blank
+//! node. Remote contexts other than schema.org and `@list` are not supported.
 
 use crate::rdf::{
-    Triple, RdfObject
+    Triple, NamedNode, BlankNode, Literal, RdfSubject, RdfPredicate, RdfObject, NamespaceManager
 };
 use super::{ParseResult, SerializeResult, ParseError, SerializeError};
-use serde_json::{json, Value};
-use std::collections::HashMap;
+use serde_json::{json, Map, Value};
+use std::collections::{BTreeMap, HashMap};
+
+const RDF_TYPE: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#type";
+const XSD: &str = "http://www.w3.org/2001/XMLSchema#";
 
 /// JSON-LD parser
 pub struct JsonLdParserWrapper;
 
 impl JsonLdParserWrapper {
     /// Parse JSON-LD string to Triples
-    pub fn parse(_input: &str) -> ParseResult<Vec<Triple>> {
-        // Full JSON-LD parsing requires a complex processor (expansion/compaction).
-        // Without a dedicated crate like json-ld or sophia_jsonld, this is non-trivial.
-        // For now, we return an error indicating it's not yet implemented.
-        Err(ParseError::Parse("JSON-LD parsing not yet supported without external crate".to_string()))
+    pub fn parse(input: &str) -> ParseResult<Vec<Triple>> {
+        let document: Value = serde_json::from_str(input)
+            .map_err(|e| ParseError::Parse(e.to_string()))?;
+
+        let mut triples = Vec::new();
+        let context = Context::default();
+        match &document {
+            Value::Array(nodes) => {
+                for node in nodes {
+                    parse_top_level(node, &context, &mut triples)?;
+                }
+            }
+            node => parse_top_level(node, &context, &mut triples)?,
+        }
+        Ok(triples)
     }
 }
 
@@ -24,70 +48,441 @@ impl JsonLdParserWrapper {
 pub struct JsonLdSerializerWrapper;
 
 impl JsonLdSerializerWrapper {
-    /// Serialize Triples to JSON-LD string
-    ///
-    /// This implements a basic "expanded" JSON-LD serialization.
+    /// Serialize Triples to JSON-LD string, compacting IRIs with the common prefixes
     pub fn serialize(triples: &[Triple]) -> SerializeResult<String> {
-        // Group by subject
-        let mut map: HashMap<String, HashMap<String, Vec<Value>>> = HashMap::new();
+        Self::serialize_with_namespaces(triples, &NamespaceManager::new())
+    }
 
+    /// Serialize Triples to JSON-LD string, compacting IRIs with `namespaces`
+    ///
+    /// Only the prefixes that are actually used appear in `@context`.
+    pub fn serialize_with_namespaces(
+        triples: &[Triple],
+        namespaces: &NamespaceManager,
+    ) -> SerializeResult<String> {
+        let mut compactor = Compactor { namespaces, used: BTreeMap::new() };
+
+        // Subject → (types, predicate → values), ordered for stable output
+        let mut nodes: BTreeMap<String, (Vec<Value>, BTreeMap<String, Vec<Value>>)> = BTreeMap::new();
         for triple in triples {
-            let s_str = triple.subject.to_string();
-            // Basic cleanup: remove < > if named node, keep _: if blank
-            let s_key = if triple.subject.is_named_node() {
-                 triple.subject.to_string().trim_matches(|c| c == '<' || c == '>').to_string()
-            } else {
-                triple.subject.to_string()
+            let subject = match &triple.subject {
+                RdfSubject::NamedNode(n) => compactor.iri(n.as_str()),
+                RdfSubject::BlankNode(b) => format!("_:{}", b.as_str()),
             };
+            let (types, properties) = nodes.entry(subject).or_default();
+            let predicate = triple.predicate.as_named_node().as_str();
 
-            let p_key = triple.predicate.to_string().trim_matches(|c| c == '<' || c == '>').to_string();
+            match &triple.object {
+                RdfObject::NamedNode(n) if predicate == RDF_TYPE => {
+                    types.push(json!(compactor.iri(n.as_str())));
+                }
+                RdfObject::BlankNode(b) if predicate == RDF_TYPE => {
+                    types.push(json!(format!("_:{}", b.as_str())));
+                }
+                object => {
+                    let value = match object {
+                        RdfObject::NamedNode(n) => json!({ "@id": compactor.iri(n.as_str()) }),
+                        RdfObject::BlankNode(b) => json!({ "@id": format!("_:{}", b.as_str()) }),
+                        RdfObject::Literal(l) => compactor.literal(l),
+                    };
+                    properties.entry(compactor.iri(predicate)).or_default().push(value);
+                }
+            }
+        }
 
-            let o_val = match &triple.object {
-                RdfObject::NamedNode(n) => {
-                    json!({ "@id": n.as_str() })
-                },
-                RdfObject::BlankNode(b) => {
-                    json!({ "@id": format!("_:{}", b.as_str()) })
+        let graph: Vec<Value> = nodes
+            .into_iter()
+            .map(|(id, (types, properties))| {
+                let mut node = Map::new();
+                node.insert("@id".to_string(), json!(id));
+                if !types.is_empty() {
+                    node.insert("@type".to_string(), Value::Array(types));
+                }
+                for (predicate, values) in properties {
+                    node.insert(predicate, Value::Array(values));
+                }
+                Value::Object(node)
+            })
+            .collect();
+
+        let context: Map<String, Value> = compactor
+            .used
+            .into_iter()
+            .map(|(prefix, iri)| (prefix, json!(iri)))
+            .collect();
+        let document = json!({ "@context": context, "@graph": graph });
+
+        serde_json::to_string_pretty(&document)
+            .map_err(|e| SerializeError::Serialize(e.to_string()))
+    }
+}
+
+/// IRI compaction that remembers which prefixes it used
+struct Compactor<'a> {
+    namespaces: &'a NamespaceManager,
+    used: BTreeMap<String, String>,
+}
+
+impl Compactor<'_> {
+    fn iri(&mut self, iri: &str) -> String {
+        let Some(compact) = self.namespaces.compact(iri) else {
+            return iri.to_string();
+        };
+        let (prefix, local) = compact.split_once(':').unwrap_or((compact.as_str(), ""));
+        // `p://x` or `_:x` would be read back as an absolute IRI or a blank node
+        if local.starts_with("//") || prefix == "_" {
+            return iri.to_string();
+        }
+        if let Ok(namespace) = self.namespaces.get_iri(prefix) {
+            self.used.insert(prefix.to_string(), namespace.to_string());
+        }
+        compact
+    }
+
+    fn literal(&mut self, literal: &Literal) -> Value {
+        if let Some(lang) = literal.language() {
+            return json!({ "@value": literal.value(), "@language": lang });
+        }
+        let datatype = literal.datatype();
+        if datatype.as_str() == format!("{}string", XSD) {
+            json!(literal.value())
+        } else {
+            json!({ "@value": literal.value(), "@type": self.iri(datatype.as_str()) })
+        }
+    }
+}
+
+/// Well-known contexts that are resolved without fetching them
+///
+/// Pages embedding schema.org markup reference the remote context by URL; it
+/// is treated as `{"@vocab": "https://schema.org/"}` (its term coercions,
+/// such as `url` being an IRI, are not applied).
+const KNOWN_VOCABULARIES: &[(&str, &str)] = &[
+    ("http://schema.org", "http://schema.org/"),
+    ("https://schema.org", "https://schema.org/"),
+];
+
+/// Term definition from a `@context`
+#[derive(Debug, Clone)]
+struct TermDefinition {
+    iri: String,
+    /// `@type` coercion: `@id`, `@vocab` or an expanded datatype IRI
+    coerce: Option<String>,
+    /// `@language` for plain string values of this term
+    language: Option<String>,
+}
+
+/// Active context: terms and prefixes, default vocabulary and language
+#[derive(Debug, Clone, Default)]
+struct Context {
+    terms: HashMap<String, TermDefinition>,
+    vocab: Option<String>,
+    language: Option<String>,
+}
+
+impl Context {
+    /// Apply a local `@context` on top of this one
+    fn with(&self, local: &Value) -> ParseResult<Context> {
+        if local.is_null() {
+            return Ok(Context::default());
+        }
+        let mut context = self.clone();
+        for item in as_list(local) {
+            match item {
+                Value::Null => context = Context::default(),
+                Value::String(url) => {
+                    let url = url.trim_end_matches('/');
+                    let Some((_, vocab)) = KNOWN_VOCABULARIES.iter().find(|(known, _)| *known == url) else {
+                        return Err(ParseError::Parse(format!("Remote JSON-LD context {} is not supported", url)));
+                    };
+                    context.vocab = Some(vocab.to_string());
+                }
+                Value::Object(map) => context.define(map)?,
+                _ => return Err(ParseError::Parse("Invalid JSON-LD @context".to_string())),
+            }
+        }
+        Ok(context)
+    }
+
+    fn define(&mut self, map: &Map<String, Value>) -> ParseResult<()> {
+        match map.get("@vocab") {
+            Some(Value::String(vocab)) => self.vocab = Some(self.expand(vocab, true)),
+            Some(Value::Null) => self.vocab = None,
+            _ => {}
+        }
+        match map.get("@language") {
+            Some(Value::String(lang)) => self.language = Some(lang.clone()),
+            Some(Value::Null) => self.language = None,
+            _ => {}
+        }
+
+        // Terms may refer to each other in any order ("name": "schema:name"
+        // next to "schema"), so record them unexpanded and resolve afterwards
+        let mut defined = Vec::new();
+        for (term, definition) in map {
+            if term.starts_with('@') {
+                continue;
+            }
+            let definition = match definition {
+                Value::Null => {
+                    self.terms.remove(term);
+                    continue;
+                }
+                Value::String(iri) => TermDefinition {
+                    iri: iri.clone(),
+                    coerce: None,
+                    language: self.language.clone(),
                 },
-                RdfObject::Literal(l) => {
-                    if let Some(lang) = l.language() {
-                         json!({ "@value": l.value(), "@language": lang })
-                    } else {
-                        let dt = l.datatype();
-                        if dt.as_str() == "http://www.w3.org/2001/XMLSchema#string" {
-                            json!({ "@value": l.value() })
-                        } else {
-                            json!({ "@value": l.value(), "@type": dt.as_str() })
-                        }
-                    }
+                Value::Object(def) => {
+                    let iri = match def.get("@id") {
+                        Some(Value::String(iri)) => iri.clone(),
+                        _ => term.clone(),
+                    };
+                    let coerce = match def.get("@type") {
+                        Some(Value::String(ty)) => Some(ty.clone()),
+                        _ => None,
+                    };
+                    let language = match def.get("@language") {
+                        Some(Value::String(lang)) => Some(lang.clone()),
+                        Some(Value::Null) => None,
+                        _ => self.language.clone(),
+                    };
+                    TermDefinition { iri, coerce, language }
+                }
+                _ => return Err(ParseError::Parse(format!("Invalid definition for term {}", term))),
+            };
+            self.terms.insert(term.clone(), definition);
+            defined.push(term);
+        }
+
+        let unresolved = self.clone();
+        for term in defined {
+            let definition = &unresolved.terms[term];
+            let iri = unresolved.resolve(&definition.iri, term);
+            let coerce = definition.coerce.as_ref().map(|ty| match ty.as_str() {
+                "@id" | "@vocab" => ty.clone(),
+                ty => unresolved.resolve(ty, term),
+            });
+            let entry = self.terms.get_mut(term).expect("term was just defined");
+            entry.iri = iri;
+            entry.coerce = coerce;
+        }
+        Ok(())
+    }
+
+    /// Expand a term definition's IRI until it no longer refers to other terms
+    fn resolve(&self, value: &str, defining: &str) -> String {
+        let mut current = value.to_string();
+        for _ in 0..=self.terms.len() {
+            let next = if current == defining {
+                // A term defined as itself is vocabulary-relative
+                match &self.vocab {
+                    Some(base) if !current.contains(':') => format!("{}{}", base, current),
+                    _ => current.clone(),
                 }
+            } else {
+                self.expand(&current, true)
             };
+            if next == current {
+                break;
+            }
+            current = next;
+        }
+        current
+    }
 
-            map.entry(s_key)
-                .or_default()
-                .entry(p_key)
-                .or_default()
-                .push(o_val);
+    /// Expand a term, compact IRI or absolute IRI
+    ///
+    /// Property names and `@type` values are vocabulary-relative: a bare
+    /// word falls back to `@vocab`. `@id` values are not.
+    fn expand(&self, value: &str, vocab: bool) -> String {
+        if let Some(term) = self.terms.get(value) {
+            return term.iri.clone();
         }
+        if let Some((prefix, local)) = value.split_once(':') {
+            if !local.starts_with("//") {
+                if let Some(term) = self.terms.get(prefix) {
+                    return format!("{}{}", term.iri, local);
+                }
+            }
+            return value.to_string();
+        }
+        match &self.vocab {
+            Some(base) if vocab => format!("{}{}", base, value),
+            _ => value.to_string(),
+        }
+    }
 
-        let mut output = Vec::new();
-        for (subject, props) in map {
-            let mut node = json!({ "@id": subject });
-            for (pred, objs) in props {
-                node.as_object_mut().unwrap().insert(pred, json!(objs));
+    fn subject(&self, id: &str, vocab: bool) -> ParseResult<RdfSubject> {
+        match id.strip_prefix("_:") {
+            Some(label) => BlankNode::from_str(label)
+                .map(RdfSubject::BlankNode)
+                .map_err(|e| ParseError::Parse(e.to_string())),
+            None => named_node(&self.expand(id, vocab)).map(RdfSubject::NamedNode),
+        }
+    }
+
+    fn object(&self, id: &str, vocab: bool) -> ParseResult<RdfObject> {
+        Ok(match self.subject(id, vocab)? {
+            RdfSubject::NamedNode(n) => RdfObject::NamedNode(n),
+            RdfSubject::BlankNode(b) => RdfObject::BlankNode(b),
+        })
+    }
+}
+
+fn named_node(iri: &str) -> ParseResult<NamedNode> {
+    NamedNode::new(iri).map_err(|e| ParseError::Parse(e.to_string()))
+}
+
+fn parse_top_level(node: &Value, outer: &Context, triples: &mut Vec<Triple>) -> ParseResult<()> {
+    let Value::Object(map) = node else {
+        return Err(ParseError::Parse("Expected a JSON-LD node object".to_string()));
+    };
+    let context = match map.get("@context") {
+        Some(local) => outer.with(local)?,
+        None => outer.clone(),
+    };
+
+    // A document with only @context and @graph is a container, not a node
+    let is_container = map.contains_key("@graph")
+        && map.keys().all(|k| k == "@context" || k == "@graph");
+    if is_container {
+        let graph = match &map["@graph"] {
+            Value::Array(nodes) => nodes.iter().collect(),
+            node => vec![node],
+        };
+        for node in graph {
+            parse_node(node, &context, triples)?;
+        }
+        Ok(())
+    } else {
+        parse_node(node, outer, triples).map(|_| ())
+    }
+}
+
+/// Emit the triples of one node object and return its subject
+fn parse_node(node: &Value, context: &Context, triples: &mut Vec<Triple>) -> ParseResult<RdfSubject> {
+    let Value::Object(map) = node else {
+        return Err(ParseError::Parse("Expected a JSON-LD node object".to_string()));
+    };
+    let context = match map.get("@context") {
+        Some(local) => context.with(local)?,
+        None => context.clone(),
+    };
+
+    let subject = match map.get("@id") {
+        Some(Value::String(id)) => context.subject(id, false)?,
+        Some(_) => return Err(ParseError::Parse("@id must be a string".to_string())),
+        None => RdfSubject::BlankNode(BlankNode::new()),
+    };
+
+    for (key, value) in map {
+        match key.as_str() {
+            "@type" => {
+                let rdf_type = RdfPredicate::new(RDF_TYPE).map_err(|e| ParseError::Parse(e.to_string()))?;
+                for ty in as_list(value) {
+                    let Value::String(ty) = ty else {
+                        return Err(ParseError::Parse("@type must be a string or array of strings".to_string()));
+                    };
+                    triples.push(Triple::new(subject.clone(), rdf_type.clone(), context.object(ty, true)?));
+                }
+            }
+            "@graph" => {
+                for nested in as_list(value) {
+                    parse_node(nested, &context, triples)?;
+                }
+            }
+            key if key.starts_with('@') => {}
+            key => {
+                let predicate = RdfPredicate::new(&context.expand(key, true))
+                    .map_err(|e| ParseError::Parse(e.to_string()))?;
+                let term = context.terms.get(key);
+                for item in as_list(value) {
+                    let object = parse_value(item, term, &context, triples)?;
+                    triples.push(Triple::new(subject.clone(), predicate.clone(), object));
+                }
             }
-            output.push(node);
         }
+    }
+    Ok(subject)
+}
 
-        serde_json::to_string_pretty(&output)
-            .map_err(|e| SerializeError::Serialize(e.to_string()))
+/// Convert one property value, applying the term's coercion and language
+fn parse_value(
+    value: &Value,
+    term: Option<&TermDefinition>,
+    context: &Context,
+    triples: &mut Vec<Triple>,
+) -> ParseResult<RdfObject> {
+    let xsd = |local: &str| named_node(&format!("{}{}", XSD, local));
+    let coerce = term.and_then(|t| t.coerce.as_deref());
+    Ok(match value {
+        Value::String(s) if coerce == Some("@id") => context.object(s, false)?,
+        Value::String(s) if coerce == Some("@vocab") => context.object(s, true)?,
+        Value::String(s) => match (coerce, term.map_or(&context.language, |t| &t.language)) {
+            (Some(datatype), _) => RdfObject::Literal(Literal::new_typed_literal(s.as_str(), named_node(datatype)?)),
+            (None, Some(lang)) => RdfObject::Literal(
+                Literal::new_language_tagged_literal(s.as_str(), lang.as_str())
+                    .map_err(|e| ParseError::Parse(e.to_string()))?,
+            ),
+            (None, None) => RdfObject::Literal(Literal::new_simple_literal(s.as_str())),
+        },
+        Value::Bool(b) => RdfObject::Literal(Literal::new_typed_literal(b.to_string(), xsd("boolean")?)),
+        Value::Number(n) if n.is_f64() => {
+            RdfObject::Literal(Literal::new_typed_literal(n.to_string(), xsd("double")?))
+        }
+        Value::Number(n) => RdfObject::Literal(Literal::new_typed_literal(n.to_string(), xsd("integer")?)),
+        Value::Object(map) if map.contains_key("@value") => {
+            let lexical = match &map["@value"] {
+                Value::String(s) => s.clone(),
+                Value::Null | Value::Array(_) | Value::Object(_) => {
+                    return Err(ParseError::Parse("@value must be a scalar".to_string()));
+                }
+                other => other.to_string(),
+            };
+            if let Some(Value::String(lang)) = map.get("@language") {
+                RdfObject::Literal(
+                    Literal::new_language_tagged_literal(lexical, lang.as_str())
+                        .map_err(|e| ParseError::Parse(e.to_string()))?,
+                )
+            } else if let Some(Value::String(ty)) = map.get("@type") {
+                RdfObject::Literal(Literal::new_typed_literal(lexical, named_node(&context.expand(ty, true))?))
+            } else if map["@value"].is_string() {
+                RdfObject::Literal(Literal::new_simple_literal(lexical))
+            } else {
+                return parse_value(&map["@value"], None, context, triples);
+            }
+        }
+        Value::Object(map) if map.contains_key("@list") => {
+            return Err(ParseError::Parse("JSON-LD @list is not supported".to_string()));
+        }
+        Value::Object(map) if map.len() == 1 && map.contains_key("@id") => match &map["@id"] {
+            Value::String(id) => context.object(id, false)?,
+            _ => return Err(ParseError::Parse("@id must be a string".to_string())),
+        },
+        Value::Object(_) => match parse_node(value, context, triples)? {
+            RdfSubject::NamedNode(n) => RdfObject::NamedNode(n),
+            RdfSubject::BlankNode(b) => RdfObject::BlankNode(b),
+        },
+        Value::Null | Value::Array(_) => {
+            return Err(ParseError::Parse("Unsupported JSON-LD value".to_string()));
+        }
+    })
+}
+
+/// A JSON-LD value that may be a single item or an array of items
+fn as_list(value: &Value) -> Vec<&Value> {
+    match value {
+        Value::Array(items) => items.iter().collect(),
+        Value::Null => Vec::new(),
+        item => vec![item],
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::rdf::{NamedNode, RdfPredicate, Literal};
+    use std::collections::HashSet;
 
     #[test]
     fn test_jsonld_serialization() {
@@ -106,4 +501,76 @@ mod tests {
         assert!(json.contains("http://example.org/alice"));
         assert!(json.contains("Alice"));
     }
+
+    #[test]
+    fn test_jsonld_compacts_and_roundtrips() {
+        let alice = NamedNode::new("http://example.org/alice").unwrap();
+        let bob = NamedNode::new("http://example.org/bob").unwrap();
+        let blank = BlankNode::from_str("addr1").unwrap();
+        let pred = |iri: &str| RdfPredicate::new(iri).unwrap();
+        let integer = NamedNode::new("http://www.w3.org/2001/XMLSchema#integer").unwrap();
+
+        let triples = vec![
+            Triple::new(alice.clone().into(), pred(RDF_TYPE), NamedNode::new("http://xmlns.com/foaf/0.1/Person").unwrap().into()),
+            Triple::new(alice.clone().into(), pred("http://xmlns.com/foaf/0.1/name"), Literal::new_simple_literal("Alice").into()),
+            Triple::new(alice.clone().into(), pred("http://xmlns.com/foaf/0.1/nick"), Literal::new_language_tagged_literal("Ali", "en").unwrap().into()),
+            Triple::new(alice.clone().into(), pred("http://example.org/age"), Literal::new_typed_literal("30", integer).into()),
+            Triple::new(alice.clone().into(), pred("http://xmlns.com/foaf/0.1/knows"), bob.clone().into()),
+            Triple::new(alice.into(), pred("http://example.org/address"), blank.clone().into()),
+            Triple::new(blank.into(), pred("http://example.org/city"), Literal::new_simple_literal("Paris").into()),
+            Triple::new(bob.into(), pred(RDF_TYPE), NamedNode::new("http://xmlns.com/foaf/0.1/Person").unwrap().into()),
+        ];
+
+        let mut namespaces = NamespaceManager::new();
+        namespaces.add_prefix("ex", "http://example.org/");
+        let output = JsonLdSerializerWrapper::serialize_with_namespaces(&triples, &namespaces).unwrap();
+
+        let document: Value = serde_json::from_str(&output).unwrap();
+        let context = document["@context"].as_object().unwrap();
+        assert_eq!(context["ex"], "http://example.org/");
+        assert_eq!(context["foaf"], "http://xmlns.com/foaf/0.1/");
+        assert!(!context.contains_key("owl"));
+        let alice_node = document["@graph"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|n| n["@id"] == "ex:alice")
+            .unwrap();
+        assert_eq!(alice_node["@type"], json!(["foaf:Person"]));
+        assert_eq!(alice_node["foaf:name"], json!(["Alice"]));
+        assert_eq!(alice_node["foaf:nick"], json!([{ "@value": "Ali", "@language": "en" }]));
+        assert_eq!(alice_node["ex:age"], json!([{ "@value": "30", "@type": "xsd:integer" }]));
+        assert_eq!(alice_node["ex:address"], json!([{ "@id": "_:addr1" }]));
+
+        let parsed = JsonLdParserWrapper::parse(&output).unwrap();
+        let expected: HashSet<Triple> = triples.into_iter().collect();
+        assert_eq!(parsed.into_iter().collect::<HashSet<_>>(), expected);
+    }
+
+    #[test]
+    fn test_jsonld_parses_hand_written_document() {
+        let input = r#"{
+            "@context": { "schema": "http://schema.org/", "name": "schema:name" },
+            "@id": "http://example.org/book",
+            "@type": "schema:Book",
+            "name": "Dune",
+            "schema:pages": 412,
+            "schema:author": { "@id": "http://example.org/herbert", "name": "Frank Herbert" }
+        }"#;
+        let triples = JsonLdParserWrapper::parse(input).unwrap();
+        assert_eq!(triples.len(), 5);
+
+        let pages = triples
+            .iter()
+            .find(|t| t.predicate.as_named_node().as_str() == "http://schema.org/pages")
+            .unwrap();
+        match &pages.object {
+            RdfObject::Literal(l) => {
+                assert_eq!(l.value(), "412");
+                assert_eq!(l.datatype().as_str(), "http://www.w3.org/2001/XMLSchema#integer");
+            }
+            other => panic!("unexpected object {:?}", other),
+        }
+        assert!(JsonLdParserWrapper::parse(r#"{"@context": "http://example.org/context.jsonld"}"#).is_err());
+    }
 }
@@ -6,7 +6,7 @@
 //! - RDF/XML
 //! - JSON-LD
 
-use super::{Triple, RdfStore};
+use super::{Triple, RdfStore, NamespaceManager};
 use thiserror::Error;
 use std::fs::File;
 use std::io::{Read, Write};
@@ -128,6 +128,14 @@ impl RdfSerializer {
         }
     }
 
+    /// Serialize triples to JSON-LD, compacting IRIs with the prefixes in `namespaces`
+    ///
+    /// [`serialize`](Self::serialize) with [`RdfFormat::JsonLd`] uses the
+    /// default [`NamespaceManager`] prefixes.
+    pub fn serialize_jsonld(triples: &[Triple], namespaces: &NamespaceManager) -> SerializeResult<String> {
+        JsonLdSerializerWrapper::serialize_with_namespaces(triples, namespaces)
+    }
+
     /// Serialize RDF store to a string
     pub fn serialize_store(store: &RdfStore, format: RdfFormat) -> SerializeResult<String> {
         // Collect all triples from the store
@@ -190,9 +198,51 @@ mod tests {
     fn test_jsonld_serialization() {
         let triples = create_test_triples();
         let output = RdfSerializer::serialize(&triples, RdfFormat::JsonLd).unwrap();
-        assert!(output.contains("@id"));
+        assert!(output.contains("\"foaf:name\""));
+        let parsed = RdfParser::parse(&output, RdfFormat::JsonLd).unwrap();
+        assert_eq!(triples, parsed);
     }
     
+    #[test]
+    fn test_jsonld_schema_org_person() {
+        let input = r#"{
+            "@context": "https://schema.org",
+            "@type": "Person",
+            "@id": "https://example.com/#jane",
+            "name": "Jane Doe",
+            "jobTitle": "Professor",
+            "address": {
+                "@type": "PostalAddress",
+                "addressLocality": "Seattle"
+            }
+        }"#;
+        let triples = RdfParser::parse(input, RdfFormat::JsonLd).unwrap();
+        assert_eq!(triples.len(), 6);
+
+        let jane = crate::rdf::RdfSubject::NamedNode(NamedNode::new("https://example.com/#jane").unwrap());
+        let find = |predicate: &str| {
+            triples
+                .iter()
+                .find(|t| t.predicate.as_named_node().as_str() == predicate)
+                .unwrap_or_else(|| panic!("no {} triple", predicate))
+        };
+        let ty = triples
+            .iter()
+            .find(|t| t.subject == jane && t.predicate.as_named_node().as_str().ends_with("#type"))
+            .unwrap();
+        assert_eq!(ty.object, NamedNode::new("https://schema.org/Person").unwrap().into());
+        assert_eq!(find("https://schema.org/name").object, Literal::new_simple_literal("Jane Doe").into());
+
+        // The address has no @id, so it becomes a blank node linked from Jane
+        let address = find("https://schema.org/address");
+        assert_eq!(address.subject, jane);
+        let crate::rdf::RdfObject::BlankNode(blank) = &address.object else {
+            panic!("address should be a blank node, got {:?}", address.object);
+        };
+        let locality = find("https://schema.org/addressLocality");
+        assert_eq!(locality.subject, crate::rdf::RdfSubject::BlankNode(blank.clone()));
+    }
+
     #[test]
     fn test_format_detection() {
         assert_eq!(RdfFormat::from_extension(Path::new("test.ttl")), Some(RdfFormat::Turtle));
@@ -216,11 +216,54 @@ impl RdfStore {
             .ok_or_else(|| RdfStoreError::GraphNotFound(graph_iri.to_string()))
     }
 
+    /// Query the triples of one named graph matching a pattern
+    ///
+    /// An unknown graph has no triples.
+    pub fn query_graph(&self, graph_iri: &str, pattern: &TriplePattern) -> Vec<Triple> {
+        self.graphs
+            .get(graph_iri)
+            .map(|triples| triples.iter().filter(|triple| pattern.matches(triple)).cloned().collect())
+            .unwrap_or_default()
+    }
+
     /// List all named graphs
     pub fn list_graphs(&self) -> Vec<String> {
         self.graphs.keys().cloned().collect()
     }
 
+    /// Named graphs holding at least one triple, in IRI order
+    pub fn named_graphs(&self) -> Vec<NamedNode> {
+        let mut graphs: Vec<NamedNode> = self
+            .graphs
+            .iter()
+            .filter(|(_, triples)| !triples.is_empty())
+            .filter_map(|(iri, _)| NamedNode::new(iri).ok())
+            .collect();
+        graphs.sort_by(|a, b| a.as_str().cmp(b.as_str()));
+        graphs
+    }
+
+    /// Iterate over the store as quads
+    ///
+    /// A triple yields one quad per named graph containing it, or a single
+    /// default-graph quad (graph `None`) if it is in no named graph.
+    pub fn quads(&self) -> impl Iterator<Item = Quad> + '_ {
+        let named = self.graphs.iter().flat_map(|(iri, triples)| {
+            let graph = NamedNode::new(iri).ok();
+            triples.iter().map(move |triple| {
+                let triple = triple.clone();
+                Quad::new(triple.subject, triple.predicate, triple.object, graph.clone())
+            })
+        });
+        let default = self
+            .triples
+            .iter()
+            .filter(|triple| !self.graphs.values().any(|graph| graph.contains(*triple)))
+            .cloned()
+            .map(Quad::from_triple);
+        default.chain(named)
+    }
+
     /// Get an iterator over all triples
     pub fn iter(&self) -> impl Iterator<Item = &Triple> {
         self.triples.iter()
@@ -624,6 +667,29 @@ mod tests {
         assert_eq!(store.get_graph(graph2.as_str()).unwrap().len(), 1);
     }
 
+    #[test]
+    fn test_named_graphs_and_quads() {
+        let mut store = RdfStore::new();
+        let pred = RdfPredicate::new("http://example.org/p").unwrap();
+        let graph1 = NamedNode::new("http://example.org/g1").unwrap();
+        let graph2 = NamedNode::new("http://example.org/g2").unwrap();
+        let subject = |name: &str| RdfSubject::NamedNode(NamedNode::new(&format!("http://example.org/{}", name)).unwrap());
+        let value = |v: &str| RdfObject::Literal(Literal::new_simple_literal(v));
+
+        store.insert_quad(Quad::new(subject("a"), pred.clone(), value("1"), Some(graph1.clone()))).unwrap();
+        store.insert_quad(Quad::new(subject("b"), pred.clone(), value("2"), Some(graph2.clone()))).unwrap();
+        store.insert_quad(Quad::new(subject("c"), pred.clone(), value("3"), Some(graph2.clone()))).unwrap();
+        store.insert(Triple::new(subject("d"), pred, value("4"))).unwrap();
+
+        assert_eq!(store.named_graphs(), vec![graph1.clone(), graph2.clone()]);
+        assert_eq!(store.len(), 4);
+
+        let quads: Vec<Quad> = store.quads().collect();
+        assert_eq!(quads.len(), 4);
+        assert_eq!(quads.iter().filter(|q| q.graph.as_ref() == Some(&graph2)).count(), 2);
+        assert_eq!(quads.iter().filter(|q| q.graph.is_none()).count(), 1);
+    }
+
     #[test]
     fn test_get_graph_nonexistent() {
         let store = RdfStore::new();
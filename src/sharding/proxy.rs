@@ -1,68 +1,361 @@
 //! Proxy client for forwarding requests
 //!
 //! Handles the networking part of routing: taking a command and sending it
-//! to a remote node's RESP port.
+//! to a remote node's RESP port. Connections are kept open and reused per
+//! backend, and replies are read frame-by-frame so one forward returns
+//! exactly one complete RESP reply.
 
+use crate::protocol::resp::{RespError, RespValue};
+use crate::raft::RaftNodeId;
+use crate::sharding::{RouteResult, Router};
+use bytes::BytesMut;
+use std::collections::HashMap;
+use std::sync::RwLock;
+use std::time::Duration;
 use tokio::io::{AsyncReadExt, AsyncWriteExt};
 use tokio::net::TcpStream;
+use tokio::sync::Mutex;
 use tracing::debug;
 
+/// Retries after a failed forward when none are configured explicitly
+pub const DEFAULT_RETRIES: usize = 1;
+
+/// Delay before the first retry; doubles on each further attempt
+const RETRY_BACKOFF: Duration = Duration::from_millis(50);
+
 /// Proxy manager
 pub struct Proxy {
-    // In a real implementation, we might keep connection pools
+    /// Shard node -> RESP address, consulted before the cluster config
+    addresses: RwLock<HashMap<RaftNodeId, String>>,
+    /// Idle connections per backend address
+    idle: Mutex<HashMap<String, Vec<TcpStream>>>,
+    /// Retries for forwards that failed before the backend saw the command
+    retries: usize,
+}
+
+/// Why a single forward attempt failed
+enum ForwardError {
+    /// The backend never received the command, so it is safe to resend
+    NotSent(String),
+    /// The command may have been executed; resending could apply it twice
+    Failed(String),
 }
 
 impl Proxy {
     pub fn new() -> Self {
-        Self {}
+        Self {
+            addresses: RwLock::new(HashMap::new()),
+            idle: Mutex::new(HashMap::new()),
+            retries: DEFAULT_RETRIES,
+        }
+    }
+
+    /// Set how many times a forward is retried when the backend is unreachable
+    pub fn with_retries(mut self, retries: usize) -> Self {
+        self.retries = retries;
+        self
+    }
+
+    /// Record the RESP address of a shard node
+    pub fn register_shard(&self, node_id: RaftNodeId, address: impl Into<String>) {
+        self.addresses.write().unwrap().insert(node_id, address.into());
+    }
+
+    /// RESP address registered for a shard node
+    pub fn shard_address(&self, node_id: RaftNodeId) -> Option<String> {
+        self.addresses.read().unwrap().get(&node_id).cloned()
+    }
+
+    /// Tenant key of a `GRAPH.*` command (its first argument), if any
+    pub fn routing_key(command: &RespValue) -> Option<String> {
+        let args = command.as_array().ok()?;
+        let name = args.first()?.as_string().ok()??;
+        if !name.to_uppercase().starts_with("GRAPH.") {
+            return None;
+        }
+        args.get(1)?.as_string().ok()?
+    }
+
+    /// Forward `command` to the shard that owns its tenant.
+    ///
+    /// Returns `None` when the command should be handled locally: it is not
+    /// a `GRAPH.*` command, or the router maps its tenant to this node or to
+    /// no node at all. Otherwise returns the backend's raw reply, or an
+    /// encoded RESP error if the shard could not be reached.
+    pub async fn dispatch(&self, router: &Router, command: &RespValue) -> Option<Vec<u8>> {
+        let key = Self::routing_key(command)?;
+        let RouteResult::Remote(node_id) = router.route(&key)? else {
+            return None;
+        };
+        let reply = match self.shard_address(node_id) {
+            None => Err(format!("ERR no address for shard {}", node_id)),
+            Some(address) => {
+                debug!("Routing command for tenant '{}' to node {} ({})", key, node_id, address);
+                let mut bytes = Vec::new();
+                match command.encode(&mut bytes) {
+                    Err(e) => Err(format!("ERR {}", e)),
+                    Ok(()) => self
+                        .forward(&address, &bytes)
+                        .await
+                        .map_err(|e| format!("ERR shard {} unavailable: {}", node_id, e)),
+                }
+            }
+        };
+        Some(reply.unwrap_or_else(|message| {
+            let mut buf = Vec::new();
+            let _ = RespValue::Error(message).encode(&mut buf);
+            buf
+        }))
     }
 
-    /// Forward a raw RESP command to a target address
-    /// 
-    /// Note: This is a simplified implementation. Production would use
-    /// connection pooling and better error handling.
+    /// Forward a raw RESP command to a target address and return the raw
+    /// bytes of its reply
+    ///
+    /// Attempts that fail before any byte of the command is written (the
+    /// backend refused the connection) are retried up to the configured
+    /// number of times. Once writing has started the backend may have
+    /// executed the command, so later failures are returned as they are.
     pub async fn forward(&self, target_addr: &str, command: &[u8]) -> Result<Vec<u8>, String> {
         debug!("Forwarding request to {}", target_addr);
 
-        let mut stream = TcpStream::connect(target_addr).await
-            .map_err(|e| format!("Failed to connect to {}: {}", target_addr, e))?;
+        let mut backoff = RETRY_BACKOFF;
+        let mut attempt = 0;
+        loop {
+            match self.forward_once(target_addr, command).await {
+                Ok(reply) => return Ok(reply),
+                Err(ForwardError::NotSent(e)) if attempt < self.retries => {
+                    debug!("Forward to {} failed ({}), retrying", target_addr, e);
+                    attempt += 1;
+                    tokio::time::sleep(backoff).await;
+                    backoff *= 2;
+                }
+                Err(ForwardError::NotSent(e)) | Err(ForwardError::Failed(e)) => return Err(e),
+            }
+        }
+    }
+
+    async fn forward_once(&self, target_addr: &str, command: &[u8]) -> Result<Vec<u8>, ForwardError> {
+        let pooled = loop {
+            let conn = self.idle.lock().await.get_mut(target_addr).and_then(|conns| conns.pop());
+            match conn {
+                Some(stream) if is_closed(&stream) => continue,
+                conn => break conn,
+            }
+        };
+        let mut stream = match pooled {
+            Some(stream) => stream,
+            None => TcpStream::connect(target_addr).await.map_err(|e| {
+                ForwardError::NotSent(format!("Failed to connect to {}: {}", target_addr, e))
+            })?,
+        };
 
-        // Write command
-        stream.write_all(command).await
-            .map_err(|e| format!("Failed to write to {}: {}", target_addr, e))?;
+        stream.write_all(command).await.map_err(|e| {
+            ForwardError::Failed(format!("Failed to write to {}: {}", target_addr, e))
+        })?;
 
-        // Read response
-        // In a real RESP proxy, we would parse the response to know when it ends.
-        // For MVP, we read until EOF or a reasonable buffer.
-        // Since standard Redis clients keep connection open, reading "until EOF" 
-        // doesn't work for single command forwarding unless we use a framed codec.
-        //
-        // NOTE: This simple proxy assumes one-off connections for MVP.
-        // A full proxy requires a persistent connection and RESP frame parsing.
-        
-        let mut buf = vec![0; 4096];
-        let n = stream.read(&mut buf).await
-            .map_err(|e| format!("Failed to read from {}: {}", target_addr, e))?;
+        let reply = read_reply(&mut stream, target_addr).await?;
+        self.idle.lock().await.entry(target_addr.to_string()).or_default().push(stream);
+        Ok(reply)
+    }
+}
 
-        Ok(buf[..n].to_vec())
+impl Default for Proxy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Whether an idle pooled connection was closed by the backend (or has
+/// unexpected data waiting), so it must not carry another command
+fn is_closed(stream: &TcpStream) -> bool {
+    let mut byte = [0u8; 1];
+    !matches!(stream.try_read(&mut byte), Err(e) if e.kind() == std::io::ErrorKind::WouldBlock)
+}
+
+/// Read exactly one RESP frame from `stream`
+async fn read_reply(stream: &mut TcpStream, target_addr: &str) -> Result<Vec<u8>, ForwardError> {
+    let mut buf = BytesMut::with_capacity(4096);
+    loop {
+        let n = stream.read_buf(&mut buf).await.map_err(|e| {
+            ForwardError::Failed(format!("Failed to read from {}: {}", target_addr, e))
+        })?;
+        if n == 0 {
+            return Err(ForwardError::Failed(format!("Connection to {} closed", target_addr)));
+        }
+
+        let mut probe = buf.clone();
+        match RespValue::decode(&mut probe) {
+            Ok(Some(_)) => {
+                let len = buf.len() - probe.len();
+                return Ok(buf[..len].to_vec());
+            }
+            Ok(None) | Err(RespError::Incomplete) => continue,
+            Err(e) => {
+                return Err(ForwardError::Failed(format!(
+                    "Invalid reply from {}: {}",
+                    target_addr, e
+                )))
+            }
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::graph::{GraphStore, Label};
+    use crate::protocol::{RespServer, ServerConfig};
+    use std::sync::Arc;
 
     #[test]
     fn test_proxy_new() {
         let _proxy = Proxy::new();
     }
 
+    /// An address on this host that nothing is listening on
+    fn unused_address() -> String {
+        let port = std::net::TcpListener::bind("127.0.0.1:0").unwrap().local_addr().unwrap().port();
+        format!("127.0.0.1:{}", port)
+    }
+
     #[tokio::test]
     async fn test_proxy_forward_connection_refused() {
         let proxy = Proxy::new();
         // Connecting to an invalid address should fail
-        let result = proxy.forward("127.0.0.1:19999", b"PING\r\n").await;
+        let result = proxy.forward(&unused_address(), b"PING\r\n").await;
         assert!(result.is_err());
         assert!(result.unwrap_err().contains("Failed to connect"));
     }
+
+    fn graph_query(graph: &str, query: &str) -> RespValue {
+        RespValue::Array(vec![
+            RespValue::BulkString(Some(b"GRAPH.QUERY".to_vec())),
+            RespValue::BulkString(Some(graph.as_bytes().to_vec())),
+            RespValue::BulkString(Some(query.as_bytes().to_vec())),
+        ])
+    }
+
+    /// Start a RESP backend on a free port
+    async fn backend() -> (String, Arc<tokio::sync::RwLock<GraphStore>>) {
+        let port = std::net::TcpListener::bind("127.0.0.1:0").unwrap().local_addr().unwrap().port();
+        let store = Arc::new(tokio::sync::RwLock::new(GraphStore::new()));
+        let config = ServerConfig { port, data_path: None, ..Default::default() };
+        let server = RespServer::new(config, Arc::clone(&store));
+        tokio::spawn(async move {
+            let _ = server.start().await;
+        });
+        let address = format!("127.0.0.1:{}", port);
+        for _ in 0..100 {
+            if TcpStream::connect(&address).await.is_ok() {
+                return (address, store);
+            }
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+        panic!("backend on {} did not start", address);
+    }
+
+    #[test]
+    fn test_routing_key() {
+        assert_eq!(Proxy::routing_key(&graph_query("acme", "RETURN 1")), Some("acme".to_string()));
+        let ping = RespValue::Array(vec![RespValue::BulkString(Some(b"PING".to_vec()))]);
+        assert_eq!(Proxy::routing_key(&ping), None);
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_lands_on_routed_shard() {
+        let (addr_a, store_a) = backend().await;
+        let (addr_b, store_b) = backend().await;
+
+        let router = Router::new(1);
+        router.add_shard(2);
+        router.add_shard(3);
+        let proxy = Proxy::new();
+        proxy.register_shard(2, addr_a);
+        proxy.register_shard(3, addr_b);
+
+        let tenants: Vec<String> = (0..20).map(|i| format!("tenant_{}", i)).collect();
+        for tenant in &tenants {
+            let reply = proxy
+                .dispatch(&router, &graph_query(tenant, "CREATE (n:Doc {name: 'x'})"))
+                .await
+                .expect("remote tenant is forwarded");
+            assert!(!reply.starts_with(b"-"), "{}", String::from_utf8_lossy(&reply));
+        }
+
+        let expected = |shard| {
+            tenants.iter().filter(|t| router.route(t) == Some(RouteResult::Remote(shard))).count()
+        };
+        let docs = |store: &GraphStore| store.get_nodes_by_label(&Label::new("Doc")).len();
+        assert_eq!(docs(&*store_a.read().await), expected(2));
+        assert_eq!(docs(&*store_b.read().await), expected(3));
+        assert!(expected(2) > 0 && expected(3) > 0);
+
+        // Non-graph commands and locally owned tenants are not forwarded
+        let ping = RespValue::Array(vec![RespValue::BulkString(Some(b"PING".to_vec()))]);
+        assert!(proxy.dispatch(&router, &ping).await.is_none());
+        router.update_route("mine".to_string(), 1);
+        assert!(proxy.dispatch(&router, &graph_query("mine", "RETURN 1")).await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_unavailable_shard() {
+        let router = Router::new(1);
+        router.add_shard(2);
+        let proxy = Proxy::new().with_retries(2);
+        proxy.register_shard(2, unused_address());
+
+        let reply = proxy.dispatch(&router, &graph_query("t", "RETURN 1")).await.unwrap();
+        let reply = String::from_utf8(reply).unwrap();
+        assert!(reply.starts_with("-ERR shard 2 unavailable"), "{}", reply);
+    }
+
+    #[tokio::test]
+    async fn test_closed_pooled_connection_is_replaced() {
+        // A backend that answers one command per connection, then hangs up
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap().to_string();
+        tokio::spawn(async move {
+            while let Ok((mut socket, _)) = listener.accept().await {
+                let mut buf = [0u8; 64];
+                let _ = socket.read(&mut buf).await;
+                let _ = socket.write_all(b"+OK\r\n").await;
+            }
+        });
+
+        let proxy = Proxy::new().with_retries(0);
+        assert_eq!(proxy.forward(&address, b"PING\r\n").await.unwrap(), b"+OK\r\n");
+        // Wait until the pooled connection has seen the backend hang up
+        for _ in 0..100 {
+            if is_closed(&proxy.idle.lock().await[&address][0]) {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        // Without retries, the second forward must still get a fresh connection
+        assert_eq!(proxy.forward(&address, b"PING\r\n").await.unwrap(), b"+OK\r\n");
+    }
+
+    #[tokio::test]
+    async fn test_failure_after_write_is_not_retried() {
+        // A backend that reads the command and hangs up without replying:
+        // it may have executed the command, so the proxy must not resend it
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap().to_string();
+        let accepted = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let counter = Arc::clone(&accepted);
+        tokio::spawn(async move {
+            while let Ok((mut socket, _)) = listener.accept().await {
+                counter.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+                let mut buf = [0u8; 64];
+                let _ = socket.read(&mut buf).await;
+            }
+        });
+
+        let proxy = Proxy::new().with_retries(3);
+        let mut command = Vec::new();
+        graph_query("t", "CREATE (n:Doc)").encode(&mut command).unwrap();
+        let err = proxy.forward(&address, &command).await.unwrap_err();
+        assert!(err.contains("closed"), "{}", err);
+        assert_eq!(accepted.load(std::sync::atomic::Ordering::SeqCst), 1);
+    }
 }
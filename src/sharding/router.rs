@@ -1,11 +1,20 @@
 //! Request Router for Tenant Sharding
 //!
 //! Handles routing of requests to the correct Raft group based on Tenant ID.
+//!
+//! Tenants without an explicit route are placed on a consistent-hash ring of
+//! shard nodes. Each shard owns [`VIRTUAL_NODES_PER_SHARD`] points on the
+//! ring so load stays balanced, and adding or removing a shard only moves the
+//! tenants whose ring segment changed owner (about 1/N of them).
 
 use crate::raft::RaftNodeId;
-use std::collections::HashMap;
+use sha2::{Digest, Sha256};
+use std::collections::{BTreeMap, HashMap};
 use std::sync::{Arc, RwLock};
 
+/// Ring points per shard
+pub const VIRTUAL_NODES_PER_SHARD: usize = 128;
+
 /// Result of a routing decision
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum RouteResult {
@@ -23,6 +32,8 @@ pub struct Router {
     /// Map of Tenant ID -> Leader Node ID
     /// In a real implementation, this would be synced via a metadata store or gossip.
     shard_map: Arc<RwLock<HashMap<String, RaftNodeId>>>,
+    /// Consistent-hash ring: point -> owning shard node
+    ring: Arc<RwLock<BTreeMap<u64, RaftNodeId>>>,
 }
 
 impl Router {
@@ -31,9 +42,31 @@ impl Router {
         Self {
             local_node_id,
             shard_map: Arc::new(RwLock::new(HashMap::new())),
+            ring: Arc::new(RwLock::new(BTreeMap::new())),
+        }
+    }
+
+    /// Add a shard node to the hash ring
+    pub fn add_shard(&self, node_id: RaftNodeId) {
+        let mut ring = self.ring.write().unwrap();
+        for i in 0..VIRTUAL_NODES_PER_SHARD {
+            ring.insert(ring_hash(&format!("shard-{}#{}", node_id, i)), node_id);
         }
     }
 
+    /// Remove a shard node from the hash ring
+    pub fn remove_shard(&self, node_id: RaftNodeId) {
+        self.ring.write().unwrap().retain(|_, owner| *owner != node_id);
+    }
+
+    /// Shard nodes currently on the hash ring, in ascending order
+    pub fn shards(&self) -> Vec<RaftNodeId> {
+        let mut shards: Vec<RaftNodeId> = self.ring.read().unwrap().values().copied().collect();
+        shards.sort_unstable();
+        shards.dedup();
+        shards
+    }
+
     /// Add or update a route for a tenant
     pub fn update_route(&self, tenant_id: String, leader_node_id: RaftNodeId) {
         let mut map = self.shard_map.write().unwrap();
@@ -47,23 +80,44 @@ impl Router {
     }
 
     /// Determine where to route a request for a given tenant
+    ///
+    /// An explicit route set with `update_route` wins; otherwise the tenant
+    /// goes to the first shard clockwise from its hash on the ring. Returns
+    /// `None` when there is neither.
     pub fn route(&self, tenant_id: &str) -> Option<RouteResult> {
-        let map = self.shard_map.read().unwrap();
-        map.get(tenant_id).map(|&node_id| {
-            if node_id == self.local_node_id {
-                RouteResult::Local
-            } else {
-                RouteResult::Remote(node_id)
-            }
+        let node_id = self.owner(tenant_id)?;
+        Some(if node_id == self.local_node_id {
+            RouteResult::Local
+        } else {
+            RouteResult::Remote(node_id)
         })
     }
 
+    /// Node owning `tenant_id`, from the explicit routes or the hash ring
+    fn owner(&self, tenant_id: &str) -> Option<RaftNodeId> {
+        if let Some(&node_id) = self.shard_map.read().unwrap().get(tenant_id) {
+            return Some(node_id);
+        }
+        let ring = self.ring.read().unwrap();
+        let hash = ring_hash(tenant_id);
+        ring.range(hash..)
+            .next()
+            .or_else(|| ring.iter().next())
+            .map(|(_, &node_id)| node_id)
+    }
+
     /// Get all known routes (for debugging/status)
     pub fn get_all_routes(&self) -> HashMap<String, RaftNodeId> {
         self.shard_map.read().unwrap().clone()
     }
 }
 
+/// Position on the ring; SHA-256 keeps it stable across processes and releases
+fn ring_hash(key: &str) -> u64 {
+    let digest = Sha256::digest(key.as_bytes());
+    u64::from_be_bytes(digest[..8].try_into().unwrap())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -109,4 +163,64 @@ mod tests {
         assert!(router.route("t1").is_none());
         assert!(router.route("t2").is_some());
     }
+
+    fn owners(router: &Router, tenants: &[String]) -> Vec<RaftNodeId> {
+        tenants.iter().map(|t| router.owner(t).unwrap()).collect()
+    }
+
+    #[test]
+    fn test_hash_ring_routing() {
+        let router = Router::new(1);
+        for shard in 1..=4 {
+            router.add_shard(shard);
+        }
+        assert_eq!(router.shards(), vec![1, 2, 3, 4]);
+
+        let tenants: Vec<String> = (0..10_000).map(|i| format!("tenant_{}", i)).collect();
+        let before = owners(&router, &tenants);
+        assert_eq!(before, owners(&router, &tenants));
+
+        // Virtual nodes keep the load roughly even
+        for shard in 1..=4 {
+            let share = before.iter().filter(|&&o| o == shard).count();
+            assert!((1_500..3_500).contains(&share), "shard {} owns {}", shard, share);
+        }
+
+        // An explicit route overrides the ring
+        router.update_route("tenant_0".to_string(), 9);
+        assert_eq!(router.route("tenant_0"), Some(RouteResult::Remote(9)));
+        router.remove_route("tenant_0");
+    }
+
+    #[test]
+    fn test_hash_ring_minimal_reshuffle() {
+        let router = Router::new(1);
+        for shard in 1..=4 {
+            router.add_shard(shard);
+        }
+        let tenants: Vec<String> = (0..10_000).map(|i| format!("tenant_{}", i)).collect();
+        let before = owners(&router, &tenants);
+
+        // Adding a fifth shard moves ~1/5 of tenants, all onto the new shard
+        router.add_shard(5);
+        let after = owners(&router, &tenants);
+        let moved: Vec<usize> = (0..tenants.len()).filter(|&i| before[i] != after[i]).collect();
+        assert!(moved.iter().all(|&i| after[i] == 5));
+        assert!((1_000..3_000).contains(&moved.len()), "{} tenants moved", moved.len());
+
+        // Removing it again restores the original placement
+        router.remove_shard(5);
+        assert_eq!(owners(&router, &tenants), before);
+
+        // Removing shard 2 only moves the tenants it owned
+        router.remove_shard(2);
+        let after = owners(&router, &tenants);
+        for i in 0..tenants.len() {
+            if before[i] != 2 {
+                assert_eq!(after[i], before[i]);
+            } else {
+                assert_ne!(after[i], 2);
+            }
+        }
+    }
 }
@@ -31,6 +31,7 @@ pub struct SnapshotNode {
     pub t: String,                // Always "n"
     pub id: u64,                  // Original NodeId
     pub labels: Vec<String>,
+    #[serde(serialize_with = "serialize_sorted")]
     pub props: HashMap<String, serde_json::Value>,
 }
 
@@ -43,9 +44,18 @@ pub struct SnapshotEdge {
     pub tgt: u64,                 // Target NodeId
     #[serde(rename = "type")]
     pub edge_type: String,
+    #[serde(serialize_with = "serialize_sorted")]
     pub props: HashMap<String, serde_json::Value>,
 }
 
+/// Writes properties in key order so repeated exports are byte-identical.
+fn serialize_sorted<S: serde::Serializer>(
+    props: &HashMap<String, serde_json::Value>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.collect_map(props.iter().collect::<std::collections::BTreeMap<_, _>>())
+}
+
 /// Stats returned from export
 #[derive(Debug)]
 pub struct ExportStats {
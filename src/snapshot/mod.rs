@@ -30,6 +30,18 @@ use format::{ExportStats, ImportStats, SnapshotEdge, SnapshotHeader, SnapshotNod
 pub fn export_tenant(
     store: &GraphStore,
     writer: impl Write,
+) -> Result<ExportStats, Box<dyn std::error::Error>> {
+    export_tenant_as_of(store, writer, &chrono::Utc::now().to_rfc3339())
+}
+
+/// Like [`export_tenant`], but stamps the header with the given `created_at`.
+///
+/// Exporting an unchanged store twice with the same timestamp yields identical
+/// bytes, which lets an interrupted download resume from a byte offset.
+pub fn export_tenant_as_of(
+    store: &GraphStore,
+    writer: impl Write,
+    created_at: &str,
 ) -> Result<ExportStats, Box<dyn std::error::Error>> {
     let nodes = store.all_nodes();
     let full_edges = store.all_edges(); // Full Edge objects (may be empty for stub-loaded)
@@ -99,7 +111,7 @@ pub fn export_tenant(
         edge_count: total_edge_count,
         labels: labels.clone(),
         edge_types: edge_types.clone(),
-        created_at: chrono::Utc::now().to_rfc3339(),
+        created_at: created_at.to_string(),
         samyama_version: crate::VERSION.to_string(),
     };
     let header_json = serde_json::to_string(&header)?;
@@ -535,6 +547,49 @@ pub fn import_tenant_with_dedup(
     })
 }
 
+/// Deep-copy a store into a new, independent one by round-tripping it through a
+/// snapshot.
+///
+/// Besides nodes and edges, the copy gets the source's property and composite
+/// indexes, vector indexes (same dimensions, metric and HNSW parameters) and
+/// constraints, all populated from the copied data. Node and edge IDs are
+/// reassigned as in [`import_tenant`]. The caller must keep writers off `src`
+/// for the duration (e.g. hold its read lock) to get a point-in-time copy.
+pub fn copy_tenant(src: &GraphStore) -> Result<(GraphStore, ImportStats), Box<dyn std::error::Error>> {
+    let mut dst = GraphStore::new();
+
+    // Vector indexes must exist before import so its rebuild fills them
+    for key in src.vector_index.list_indices() {
+        if let Some(index) = src.vector_index.get_index(&key.label, &key.property_key) {
+            let index = index.read().unwrap();
+            dst.vector_index.create_index_with_config(
+                &key.label,
+                &key.property_key,
+                index.dimensions(),
+                index.metric(),
+                index.config(),
+            )?;
+        }
+    }
+
+    let mut buf = Vec::new();
+    export_tenant(src, &mut buf)?;
+    let stats = import_tenant(&mut dst, buf.as_slice())?;
+
+    for (label, properties) in src.property_index.list_composite_indexes() {
+        dst.property_index.create_composite_index(label, properties);
+    }
+    for (label, property) in src.property_index.list_indexes() {
+        dst.property_index.create_index(label, property);
+    }
+    dst.rebuild_property_index();
+    for (label, property, kind) in src.property_index.list_constraints() {
+        dst.create_constraint(label, property, kind)?;
+    }
+
+    Ok((dst, stats))
+}
+
 /// Convert PropertyValue to serde_json::Value for snapshot serialization
 fn property_to_json(pv: &PropertyValue) -> serde_json::Value {
     match pv {
@@ -944,6 +999,53 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_copy_tenant() {
+        use crate::graph::Label;
+        use crate::index::ConstraintKind;
+        use crate::vector::index::{DistanceMetric, HnswConfig};
+
+        let mut src = GraphStore::new();
+        let config = HnswConfig { m: 8, ..HnswConfig::default() };
+        src.create_vector_index_with_config("Doc", "embedding", 2, DistanceMetric::Euclidean, config).unwrap();
+        src.property_index.create_index(Label::new("Person"), "name".to_string());
+        let mut people = Vec::new();
+        for name in ["Alice", "Bob", "Carol"] {
+            let id = src.create_node("Person");
+            src.set_node_property("default", id, "name", name).unwrap();
+            people.push(id);
+        }
+        src.create_constraint(Label::new("Person"), "name".to_string(), ConstraintKind::Unique).unwrap();
+        let doc = src.create_node("Doc");
+        src.set_node_property("default", doc, "embedding", PropertyValue::Vector(vec![1.0, 0.0])).unwrap();
+        src.create_edge(people[0], people[1], "KNOWS").unwrap();
+        src.create_edge(people[0], doc, "WROTE").unwrap();
+
+        let (mut copy, stats) = copy_tenant(&src).unwrap();
+        assert_eq!((stats.node_count, stats.edge_count), (4, 2));
+        assert_eq!((copy.node_count(), copy.edge_count()), (src.node_count(), src.edge_count()));
+        assert_eq!(copy.stats().indexes, src.stats().indexes);
+        assert_eq!(copy.stats().vector_indexes, src.stats().vector_indexes);
+        assert_eq!(copy.property_index.list_constraints(), src.property_index.list_constraints());
+
+        let bob = copy
+            .property_index
+            .lookup(&Label::new("Person"), "name", &PropertyValue::String("Bob".to_string()))
+            .expect("index copied and populated");
+        assert_eq!(bob.len(), 1);
+        let index = copy.vector_index.get_index("Doc", "embedding").unwrap();
+        let index = index.read().unwrap();
+        assert_eq!((index.dimensions(), index.metric(), index.config().m), (2, DistanceMetric::Euclidean, 8));
+        drop(index);
+        assert_eq!(copy.vector_search("Doc", "embedding", &[1.0, 0.0], 1).unwrap().len(), 1);
+
+        // The copy is independent of the source and keeps its constraints
+        let dave = copy.create_node("Person");
+        copy.set_node_property("default", dave, "name", "Dave").unwrap();
+        assert!(copy.set_node_property("default", dave, "name", "Alice").is_err());
+        assert_eq!((copy.node_count(), src.node_count()), (5, 4));
+    }
+
     #[test]
     fn test_duration_property_roundtrip() {
         let mut store = GraphStore::new();
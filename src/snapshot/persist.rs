@@ -7,7 +7,7 @@
 //! crash mid-flush leaves no partial state in play.
 
 use std::fs::{self, File};
-use std::io::{Cursor, Write};
+use std::io::{BufReader, Write};
 use std::path::{Path, PathBuf};
 
 use crate::graph::store::GraphStore;
@@ -50,14 +50,50 @@ pub fn persist_snapshot(data_path: &str, bytes: &[u8]) -> std::io::Result<()> {
         f.sync_all()?;
     }
     fs::rename(&tmp_path, &final_path)?;
+    write_marker(&marker_path)
+}
 
-    // Drop the committed marker last and fsync it.
-    {
-        let f = File::create(&marker_path)?;
-        f.sync_all()?;
+/// Path for spooling an incoming upload before it is imported. Lives under
+/// `<data_path>/snapshots/` when persistence is enabled, so a successful import
+/// can be committed with a rename instead of a second copy.
+pub fn incoming_snapshot_path(data_path: Option<&str>) -> std::io::Result<PathBuf> {
+    let name = format!("incoming-{}.sgsnap{}", uuid::Uuid::new_v4(), TMP_SUFFIX);
+    match data_path {
+        Some(data_path) => {
+            let dir = snapshot_dir(data_path);
+            fs::create_dir_all(&dir)?;
+            Ok(dir.join(name))
+        }
+        None => Ok(std::env::temp_dir().join(format!("samyama-{}", name))),
     }
+}
+
+/// Like [`persist_snapshot`], but takes ownership of an already-written file
+/// (e.g. a spooled upload) and moves it into place.
+pub fn persist_snapshot_file(data_path: &str, src: &Path) -> std::io::Result<()> {
+    let dir = snapshot_dir(data_path);
+    fs::create_dir_all(&dir)?;
+
+    let final_path = dir.join(DEFAULT_SNAPSHOT_NAME);
+    let marker_path = dir.join(format!("{}{}", DEFAULT_SNAPSHOT_NAME, COMMITTED_SUFFIX));
+    let _ = fs::remove_file(&marker_path);
 
-    Ok(())
+    File::open(src)?.sync_all()?;
+    if fs::rename(src, &final_path).is_err() {
+        // Different filesystem: copy through a tmp file, then rename.
+        let tmp_path = dir.join(format!("{}{}", DEFAULT_SNAPSHOT_NAME, TMP_SUFFIX));
+        fs::copy(src, &tmp_path)?;
+        File::open(&tmp_path)?.sync_all()?;
+        fs::rename(&tmp_path, &final_path)?;
+        let _ = fs::remove_file(src);
+    }
+    write_marker(&marker_path)
+}
+
+/// Drop the committed marker last and fsync it.
+fn write_marker(marker_path: &Path) -> std::io::Result<()> {
+    let f = File::create(marker_path)?;
+    f.sync_all()
 }
 
 /// If a committed snapshot exists under `<data_path>/snapshots/`, import it
@@ -78,9 +114,8 @@ pub fn restore_persisted_snapshots(
         return Ok(None);
     }
 
-    let bytes = fs::read(&snap_path)?;
-    let cursor = Cursor::new(bytes);
-    let stats = crate::snapshot::import_tenant_with_dedup(store, cursor, &[])?;
+    let reader = BufReader::new(File::open(&snap_path)?);
+    let stats = crate::snapshot::import_tenant_with_dedup(store, reader, &[])?;
     Ok(Some(stats))
 }
 
@@ -105,4 +140,18 @@ mod tests {
         assert!(dir.join("default.sgsnap.committed").exists());
         assert!(!dir.join("default.sgsnap.tmp").exists());
     }
+
+    #[test]
+    fn persist_file_moves_spool_into_place() {
+        let tmp = tempfile::tempdir().unwrap();
+        let data_path = tmp.path().to_string_lossy().to_string();
+        let spool = incoming_snapshot_path(Some(&data_path)).unwrap();
+        fs::write(&spool, b"spooled-bytes").unwrap();
+
+        persist_snapshot_file(&data_path, &spool).unwrap();
+        let dir = tmp.path().join("snapshots");
+        assert_eq!(fs::read(dir.join("default.sgsnap")).unwrap(), b"spooled-bytes");
+        assert!(dir.join("default.sgsnap.committed").exists());
+        assert!(!spool.exists());
+    }
 }
@@ -1,18 +1,628 @@
 //! SPARQL algebra evaluation
+//!
+//! Evaluates spargebra [`GraphPattern`]s bottom-up against an [`RdfStore`],
+//! producing a multiset of [`QuerySolution`]s:
+//! - BGP (Basic Graph Pattern) matching, extending solutions one triple pattern at a time
+//! - Join, OPTIONAL (LeftJoin), UNION and MINUS over solution sequences
+//! - FILTER, BIND and OPTIONAL-with-condition, via [`super::expression`]
+//! - VALUES, projection, DISTINCT/REDUCED and LIMIT/OFFSET
+//! - GROUP BY with aggregates; HAVING arrives as a FILTER over the grouped solutions
+//! - `GRAPH <iri> { ... }` and `GRAPH ?g { ... }` over the store's named graphs
+//!
+//! Blank nodes in a query pattern behave like variables that are never projected.
+//!
+//! Without a `FROM`/`FROM NAMED` clause the default graph is every triple in
+//! the store and `GRAPH` ranges over all of its named graphs. A query with a
+//! dataset clause follows SPARQL: the default graph is the merge of the `FROM`
+//! graphs (empty if there are none) and only `FROM NAMED` graphs are visible
+//! to `GRAPH`.
 
-/// SPARQL algebra operators
+use super::executor::ExecutionError;
+use super::expression;
+use super::results::QuerySolution;
+use crate::rdf::{NamedNode, RdfObject, RdfPredicate, RdfStore, RdfSubject, RdfTerm, Triple, TriplePattern};
+use spargebra::algebra::{AggregateExpression, Expression, GraphPattern, QueryDataset};
+use spargebra::term::{GroundTerm, NamedNodePattern, TermPattern, TriplePattern as SparqlTriplePattern};
+use spargebra::term::Variable;
+use std::collections::{HashMap, HashSet};
+
+/// The graphs a query reads, by IRI; `None` means the store's own
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Dataset {
+    default: Option<Vec<String>>,
+    named: Option<Vec<String>>,
+}
+
+impl Dataset {
+    /// The dataset of a query's `FROM`/`FROM NAMED` clauses
+    pub(crate) fn from_query(dataset: Option<&QueryDataset>) -> Self {
+        let iris = |graphs: &[spargebra::term::NamedNode]| graphs.iter().map(|g| g.as_str().to_string()).collect();
+        match dataset {
+            None => Self::default(),
+            Some(dataset) => Self {
+                default: Some(iris(&dataset.default)),
+                named: Some(dataset.named.as_deref().map(iris).unwrap_or_default()),
+            },
+        }
+    }
+}
+
+/// Evaluates graph patterns against a store
+pub struct AlgebraEvaluator<'a> {
+    store: &'a RdfStore,
+    dataset: Dataset,
+    /// Named graph selected by an enclosing `GRAPH`; `None` is the default graph
+    active_graph: Option<String>,
+}
+
+impl<'a> AlgebraEvaluator<'a> {
+    /// Create an evaluator over `store`
+    pub fn new(store: &'a RdfStore) -> Self {
+        Self::with_dataset(store, Dataset::default())
+    }
+
+    /// Create an evaluator over the graphs of `store` named by `dataset`
+    pub(crate) fn with_dataset(store: &'a RdfStore, dataset: Dataset) -> Self {
+        Self { store, dataset, active_graph: None }
+    }
+
+    fn in_graph(&self, graph: &str) -> AlgebraEvaluator<'a> {
+        AlgebraEvaluator {
+            store: self.store,
+            dataset: self.dataset.clone(),
+            active_graph: Some(graph.to_string()),
+        }
+    }
+
+    /// Named graphs a `GRAPH` pattern may select, in IRI order
+    fn named_graphs(&self) -> Vec<String> {
+        let mut graphs = match &self.dataset.named {
+            Some(named) => named.clone(),
+            None => return self.store.named_graphs().iter().map(|g| g.as_str().to_string()).collect(),
+        };
+        graphs.sort();
+        graphs.dedup();
+        graphs
+    }
+
+    /// Triples of the active graph matching `lookup`
+    fn matching(&self, lookup: &TriplePattern) -> Vec<Triple> {
+        match (&self.active_graph, &self.dataset.default) {
+            (Some(graph), _) => self.store.query_graph(graph, lookup),
+            (None, None) => self.store.query(lookup),
+            (None, Some(graphs)) => {
+                let mut seen = HashSet::new();
+                graphs
+                    .iter()
+                    .flat_map(|graph| self.store.query_graph(graph, lookup))
+                    .filter(|triple| seen.insert(triple.clone()))
+                    .collect()
+            }
+        }
+    }
+
+    /// Evaluate a graph pattern to its solution sequence
+    pub fn evaluate(&self, pattern: &GraphPattern) -> Result<Vec<QuerySolution>, ExecutionError> {
+        match pattern {
+            GraphPattern::Bgp { patterns } => Ok(self.evaluate_bgp(patterns)),
+            GraphPattern::Join { left, right } => {
+                let left = self.evaluate(left)?;
+                let right = self.evaluate(right)?;
+                Ok(join(&left, &right))
+            }
+            GraphPattern::LeftJoin { left, right, expression: condition } => {
+                let left = self.evaluate(left)?;
+                let right = self.evaluate(right)?;
+                left_join(&left, &right, condition.as_ref())
+            }
+            GraphPattern::Filter { expr, inner } => {
+                let mut solutions = Vec::new();
+                for solution in self.evaluate(inner)? {
+                    if expression::passes(expr, &solution)? {
+                        solutions.push(solution);
+                    }
+                }
+                Ok(solutions)
+            }
+            GraphPattern::Extend { inner, variable, expression: expr } => Ok(self
+                .evaluate(inner)?
+                .into_iter()
+                .map(|mut solution| {
+                    // An expression error leaves the variable unbound
+                    if let Ok(term) = expression::evaluate(expr, &solution) {
+                        solution.bind(variable.as_str().to_string(), term);
+                    }
+                    solution
+                })
+                .collect()),
+            GraphPattern::Union { left, right } => {
+                let mut solutions = self.evaluate(left)?;
+                solutions.extend(self.evaluate(right)?);
+                Ok(solutions)
+            }
+            GraphPattern::Minus { left, right } => {
+                let left = self.evaluate(left)?;
+                let right = self.evaluate(right)?;
+                Ok(minus(left, &right))
+            }
+            GraphPattern::Values { variables, bindings } => Ok(bindings
+                .iter()
+                .map(|row| {
+                    let mut solution = QuerySolution::new();
+                    for (variable, value) in variables.iter().zip(row) {
+                        if let Some(term) = value.as_ref().map(ground_term) {
+                            solution.bind(variable.as_str().to_string(), term);
+                        }
+                    }
+                    solution
+                })
+                .collect()),
+            GraphPattern::Project { inner, variables } => {
+                let names: Vec<&str> = variables.iter().map(|v| v.as_str()).collect();
+                Ok(self
+                    .evaluate(inner)?
+                    .into_iter()
+                    .map(|solution| project(solution, &names))
+                    .collect())
+            }
+            GraphPattern::Group { inner, variables, aggregates } => {
+                Ok(group(self.evaluate(inner)?, variables, aggregates))
+            }
+            GraphPattern::Graph { name: NamedNodePattern::NamedNode(graph), inner } => {
+                if self.named_graphs().iter().any(|g| g == graph.as_str()) {
+                    self.in_graph(graph.as_str()).evaluate(inner)
+                } else {
+                    Ok(Vec::new())
+                }
+            }
+            GraphPattern::Graph { name: NamedNodePattern::Variable(variable), inner } => {
+                let mut solutions = Vec::new();
+                for graph in self.named_graphs() {
+                    let Ok(iri) = NamedNode::new(&graph) else { continue };
+                    let term = RdfTerm::NamedNode(iri);
+                    for mut solution in self.in_graph(&graph).evaluate(inner)? {
+                        match solution.get(variable.as_str()) {
+                            Some(bound) if bound != &term => continue,
+                            Some(_) => {}
+                            None => solution.bind(variable.as_str().to_string(), term.clone()),
+                        }
+                        solutions.push(solution);
+                    }
+                }
+                Ok(solutions)
+            }
+            GraphPattern::Distinct { inner } => Ok(distinct(self.evaluate(inner)?)),
+            GraphPattern::Reduced { inner } => self.evaluate(inner),
+            GraphPattern::Slice { inner, start, length } => {
+                let solutions = self.evaluate(inner)?.into_iter().skip(*start);
+                Ok(match length {
+                    Some(length) => solutions.take(*length).collect(),
+                    None => solutions.collect(),
+                })
+            }
+            other => Err(ExecutionError::Query(format!(
+                "Unsupported graph pattern: {}",
+                other
+            ))),
+        }
+    }
+
+    /// Match a basic graph pattern, extending solutions one triple pattern at a time
+    fn evaluate_bgp(&self, patterns: &[SparqlTriplePattern]) -> Vec<QuerySolution> {
+        let mut solutions = vec![QuerySolution::new()];
+        for pattern in patterns {
+            let mut next = Vec::new();
+            for solution in &solutions {
+                let subject = resolve(&pattern.subject, solution);
+                let predicate = match &pattern.predicate {
+                    NamedNodePattern::NamedNode(n) => Some(RdfTerm::NamedNode(n.clone().into())),
+                    NamedNodePattern::Variable(v) => solution.get(v.as_str()).cloned(),
+                };
+                let object = resolve(&pattern.object, solution);
+
+                // A bound term that cannot appear in that position matches nothing
+                let subject = match subject.map(term_to_subject) {
+                    Some(None) => continue,
+                    bound => bound.flatten(),
+                };
+                let predicate = match predicate.map(term_to_predicate) {
+                    Some(None) => continue,
+                    bound => bound.flatten(),
+                };
+                let lookup = TriplePattern::new(subject, predicate, object.map(term_to_object));
+
+                for triple in self.matching(&lookup) {
+                    if let Some(extended) = bind_triple(pattern, &triple, solution) {
+                        next.push(extended);
+                    }
+                }
+            }
+            solutions = next;
+        }
+        solutions
+    }
+}
+
+/// Variable name for a term pattern position, if it is a variable or blank node
+pub(crate) fn pattern_variable(pattern: &TermPattern) -> Option<String> {
+    match pattern {
+        TermPattern::Variable(v) => Some(v.as_str().to_string()),
+        TermPattern::BlankNode(b) => Some(format!("_:{}", b.as_str())),
+        _ => None,
+    }
+}
+
+/// Constant term of a pattern position, or its binding in `solution`
+fn resolve(pattern: &TermPattern, solution: &QuerySolution) -> Option<RdfTerm> {
+    match pattern {
+        TermPattern::NamedNode(n) => Some(RdfTerm::NamedNode(n.clone().into())),
+        TermPattern::Literal(l) => Some(RdfTerm::Literal(l.clone().into())),
+        other => pattern_variable(other).and_then(|name| solution.get(&name).cloned()),
+    }
+}
+
+/// Extend `solution` with the variables of `pattern` bound to `triple`
+///
+/// Returns `None` when a variable repeated within the pattern would be bound
+/// to two different terms.
+fn bind_triple(pattern: &SparqlTriplePattern, triple: &Triple, solution: &QuerySolution) -> Option<QuerySolution> {
+    let mut extended = solution.clone();
+    let predicate = RdfTerm::NamedNode(triple.predicate.as_named_node().clone());
+    let positions = [
+        (pattern_variable(&pattern.subject), RdfTerm::from(triple.subject.clone())),
+        (
+            match &pattern.predicate {
+                NamedNodePattern::Variable(v) => Some(v.as_str().to_string()),
+                NamedNodePattern::NamedNode(_) => None,
+            },
+            predicate,
+        ),
+        (pattern_variable(&pattern.object), RdfTerm::from(triple.object.clone())),
+    ];
+    for (variable, term) in positions {
+        if let Some(variable) = variable {
+            match extended.get(&variable) {
+                Some(bound) if bound != &term => return None,
+                Some(_) => {}
+                None => extended.bind(variable, term),
+            }
+        }
+    }
+    Some(extended)
+}
+
+/// Two solutions are compatible if they agree on every shared variable
+fn compatible(left: &QuerySolution, right: &QuerySolution) -> bool {
+    right
+        .bindings
+        .iter()
+        .all(|(variable, term)| left.get(variable).is_none_or(|bound| bound == term))
+}
+
+/// Merge two compatible solutions
+fn merge(left: &QuerySolution, right: &QuerySolution) -> QuerySolution {
+    let mut merged = left.clone();
+    for (variable, term) in &right.bindings {
+        merged.bindings.entry(variable.clone()).or_insert_with(|| term.clone());
+    }
+    merged
+}
+
+fn join(left: &[QuerySolution], right: &[QuerySolution]) -> Vec<QuerySolution> {
+    let mut out = Vec::new();
+    for l in left {
+        for r in right {
+            if compatible(l, r) {
+                out.push(merge(l, r));
+            }
+        }
+    }
+    out
+}
+
+/// OPTIONAL: a right match only extends a left solution if `condition` holds on the merge
+fn left_join(
+    left: &[QuerySolution],
+    right: &[QuerySolution],
+    condition: Option<&Expression>,
+) -> Result<Vec<QuerySolution>, ExecutionError> {
+    let mut out = Vec::new();
+    for l in left {
+        let mut matched = false;
+        for r in right.iter().filter(|r| compatible(l, r)) {
+            let merged = merge(l, r);
+            if let Some(condition) = condition {
+                if !expression::passes(condition, &merged)? {
+                    continue;
+                }
+            }
+            matched = true;
+            out.push(merged);
+        }
+        if !matched {
+            out.push(l.clone());
+        }
+    }
+    Ok(out)
+}
+
+/// Remove left solutions that share a variable with, and are compatible with, some right solution
+fn minus(left: Vec<QuerySolution>, right: &[QuerySolution]) -> Vec<QuerySolution> {
+    left.into_iter()
+        .filter(|l| {
+            !right.iter().any(|r| {
+                let shares = r.bindings.keys().any(|v| l.bindings.contains_key(v));
+                shares && compatible(l, r)
+            })
+        })
+        .collect()
+}
+
+fn project(solution: QuerySolution, variables: &[&str]) -> QuerySolution {
+    let mut projected = QuerySolution::new();
+    for variable in variables {
+        if let Some(term) = solution.get(variable) {
+            projected.bind(variable.to_string(), term.clone());
+        }
+    }
+    projected
+}
+
+/// Partition solutions by the values of `variables` and compute one solution per group
 ///
-/// TODO: Implement algebra evaluation
-/// - BGP (Basic Graph Pattern) matching
-/// - FILTER evaluation
-/// - OPTIONAL, UNION operators
-/// - Property paths
-/// - Aggregation
+/// Groups appear in order of their first solution. Without GROUP BY variables
+/// all solutions form a single group, even when there are none (so
+/// `COUNT(*)` over no matches is 0). An aggregate that errors leaves its
+/// variable unbound.
+fn group(
+    solutions: Vec<QuerySolution>,
+    variables: &[Variable],
+    aggregates: &[(Variable, AggregateExpression)],
+) -> Vec<QuerySolution> {
+    let mut groups: Vec<(Vec<Option<RdfTerm>>, Vec<QuerySolution>)> = Vec::new();
+    let mut index: HashMap<Vec<Option<RdfTerm>>, usize> = HashMap::new();
+    if variables.is_empty() {
+        groups.push((Vec::new(), Vec::new()));
+        index.insert(Vec::new(), 0);
+    }
+    for solution in solutions {
+        let key: Vec<Option<RdfTerm>> = variables.iter().map(|v| solution.get(v.as_str()).cloned()).collect();
+        let slot = *index.entry(key.clone()).or_insert_with(|| {
+            groups.push((key, Vec::new()));
+            groups.len() - 1
+        });
+        groups[slot].1.push(solution);
+    }
+
+    groups
+        .into_iter()
+        .map(|(key, members)| {
+            let mut solution = QuerySolution::new();
+            for (variable, term) in variables.iter().zip(key) {
+                if let Some(term) = term {
+                    solution.bind(variable.as_str().to_string(), term);
+                }
+            }
+            for (variable, aggregate) in aggregates {
+                if let Ok(term) = expression::aggregate(aggregate, &members) {
+                    solution.bind(variable.as_str().to_string(), term);
+                }
+            }
+            solution
+        })
+        .collect()
+}
+
+fn distinct(solutions: Vec<QuerySolution>) -> Vec<QuerySolution> {
+    let mut seen = HashSet::new();
+    solutions
+        .into_iter()
+        .filter(|solution| {
+            let mut key: Vec<(String, RdfTerm)> =
+                solution.bindings.iter().map(|(k, v)| (k.clone(), v.clone())).collect();
+            key.sort_by(|a, b| a.0.cmp(&b.0));
+            seen.insert(key)
+        })
+        .collect()
+}
+
+fn ground_term(term: &GroundTerm) -> RdfTerm {
+    match term {
+        GroundTerm::NamedNode(n) => RdfTerm::NamedNode(n.clone().into()),
+        GroundTerm::Literal(l) => RdfTerm::Literal(l.clone().into()),
+    }
+}
+
+/// A term usable as a triple subject (literals are not)
+pub(crate) fn term_to_subject(term: RdfTerm) -> Option<RdfSubject> {
+    match term {
+        RdfTerm::NamedNode(n) => Some(RdfSubject::NamedNode(n)),
+        RdfTerm::BlankNode(b) => Some(RdfSubject::BlankNode(b)),
+        RdfTerm::Literal(_) => None,
+    }
+}
+
+/// A term usable as a predicate (only IRIs are)
+pub(crate) fn term_to_predicate(term: RdfTerm) -> Option<RdfPredicate> {
+    match term {
+        RdfTerm::NamedNode(n) => Some(RdfPredicate::from(n)),
+        _ => None,
+    }
+}
+
+pub(crate) fn term_to_object(term: RdfTerm) -> RdfObject {
+    match term {
+        RdfTerm::NamedNode(n) => RdfObject::NamedNode(n),
+        RdfTerm::BlankNode(b) => RdfObject::BlankNode(b),
+        RdfTerm::Literal(l) => RdfObject::Literal(l),
+    }
+}
+
+/// Variables a query exposes, in SELECT clause order
+pub(crate) fn projected_variables(pattern: &GraphPattern) -> Vec<String> {
+    match pattern {
+        GraphPattern::Project { variables, .. } => variables.iter().map(|v| v.as_str().to_string()).collect(),
+        GraphPattern::Distinct { inner }
+        | GraphPattern::Reduced { inner }
+        | GraphPattern::Slice { inner, .. }
+        | GraphPattern::OrderBy { inner, .. } => projected_variables(inner),
+        _ => Vec::new(),
+    }
+}
 
 #[cfg(test)]
 mod tests {
+    use super::*;
+    use crate::rdf::{Literal, NamedNode};
+    use crate::sparql::SparqlParser;
+    use spargebra::Query;
+
+    fn store() -> RdfStore {
+        let mut store = RdfStore::new();
+        let foaf = |local: &str| RdfPredicate::new(&format!("http://xmlns.com/foaf/0.1/{}", local)).unwrap();
+        let person = |name: &str| RdfSubject::NamedNode(NamedNode::new(&format!("http://example.org/{}", name)).unwrap());
+        for (name, label) in [("alice", "Alice"), ("bob", "Bob"), ("carol", "Carol")] {
+            store
+                .insert(Triple::new(person(name), foaf("name"), Literal::new_simple_literal(label).into()))
+                .unwrap();
+        }
+        for (a, b) in [("alice", "bob"), ("bob", "carol")] {
+            let object = match person(b) {
+                RdfSubject::NamedNode(n) => RdfObject::NamedNode(n),
+                RdfSubject::BlankNode(b) => RdfObject::BlankNode(b),
+            };
+            store.insert(Triple::new(person(a), foaf("knows"), object)).unwrap();
+        }
+        store
+    }
+
+    fn evaluate(query: &str) -> Vec<QuerySolution> {
+        let store = store();
+        match SparqlParser::parse(query).unwrap() {
+            Query::Select { pattern, .. } => AlgebraEvaluator::new(&store).evaluate(&pattern).unwrap(),
+            _ => panic!("Expected SELECT"),
+        }
+    }
+
+    #[test]
+    fn test_bgp_join_on_shared_variable() {
+        let solutions = evaluate(
+            "PREFIX foaf: <http://xmlns.com/foaf/0.1/>
+             SELECT ?name WHERE { ?a foaf:knows ?b . ?b foaf:name ?name }",
+        );
+        let mut names: Vec<String> = solutions
+            .iter()
+            .map(|s| match s.get("name") {
+                Some(RdfTerm::Literal(l)) => l.value().to_string(),
+                other => panic!("unexpected {:?}", other),
+            })
+            .collect();
+        names.sort();
+        assert_eq!(names, vec!["Bob", "Carol"]);
+        assert!(solutions.iter().all(|s| s.get("a").is_none()));
+    }
+
+    #[test]
+    fn test_optional_keeps_unmatched() {
+        let solutions = evaluate(
+            "PREFIX foaf: <http://xmlns.com/foaf/0.1/>
+             SELECT ?p ?friend WHERE { ?p foaf:name ?n OPTIONAL { ?p foaf:knows ?friend } }",
+        );
+        assert_eq!(solutions.len(), 3);
+        assert_eq!(solutions.iter().filter(|s| s.get("friend").is_none()).count(), 1);
+    }
+
+    #[test]
+    fn test_union_distinct_and_limit() {
+        let solutions = evaluate(
+            "PREFIX foaf: <http://xmlns.com/foaf/0.1/>
+             SELECT DISTINCT ?p WHERE { { ?p foaf:knows ?x } UNION { ?x foaf:knows ?p } }",
+        );
+        assert_eq!(solutions.len(), 3);
+
+        let limited = evaluate("SELECT ?s WHERE { ?s ?p ?o } LIMIT 2");
+        assert_eq!(limited.len(), 2);
+    }
+
+    #[test]
+    fn test_filter_regex() {
+        let solutions = evaluate(
+            "PREFIX foaf: <http://xmlns.com/foaf/0.1/>
+             SELECT ?p WHERE { ?p foaf:name ?n FILTER(regex(?n, \"^[AB]\")) }",
+        );
+        assert_eq!(solutions.len(), 2);
+    }
+
+    fn integer(solution: &QuerySolution, variable: &str) -> i64 {
+        match solution.get(variable) {
+            Some(RdfTerm::Literal(l)) => l.value().parse().unwrap(),
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_group_by_with_aggregates_and_having() {
+        // Triples per predicate: foaf:name 3, foaf:knows 2
+        let solutions = evaluate(
+            "SELECT ?p (COUNT(*) AS ?n) (COUNT(DISTINCT ?s) AS ?subjects) WHERE { ?s ?p ?o } GROUP BY ?p",
+        );
+        let mut counts: Vec<(String, i64, i64)> = solutions
+            .iter()
+            .map(|s| match s.get("p") {
+                Some(RdfTerm::NamedNode(p)) => (p.as_str().to_string(), integer(s, "n"), integer(s, "subjects")),
+                other => panic!("unexpected {:?}", other),
+            })
+            .collect();
+        counts.sort();
+        assert_eq!(counts, vec![
+            ("http://xmlns.com/foaf/0.1/knows".to_string(), 2, 2),
+            ("http://xmlns.com/foaf/0.1/name".to_string(), 3, 3),
+        ]);
+
+        let having = evaluate(
+            "SELECT ?p WHERE { ?s ?p ?o } GROUP BY ?p HAVING (COUNT(?o) > 2)",
+        );
+        assert_eq!(having.len(), 1);
+        assert_eq!(
+            having[0].get("p"),
+            Some(&RdfTerm::NamedNode(NamedNode::new("http://xmlns.com/foaf/0.1/name").unwrap()))
+        );
+
+        // Without GROUP BY the whole (possibly empty) sequence is one group
+        let empty = evaluate("SELECT (COUNT(*) AS ?n) (SUM(?o) AS ?total) WHERE { ?s <http://example.org/none> ?o }");
+        assert_eq!(empty.len(), 1);
+        assert_eq!(integer(&empty[0], "n"), 0);
+        assert_eq!(integer(&empty[0], "total"), 0);
+    }
+
+    #[test]
+    fn test_numeric_aggregates() {
+        let solutions = evaluate(
+            "SELECT (SUM(?x) AS ?sum) (AVG(?x) AS ?avg) (MIN(?x) AS ?min) (MAX(?x) AS ?max)
+                    (GROUP_CONCAT(?name; separator=\",\") AS ?names)
+             WHERE { VALUES (?x ?name) { (1 \"a\") (4 \"b\") (10 \"c\") } }",
+        );
+        let value = |variable: &str| match solutions[0].get(variable) {
+            Some(RdfTerm::Literal(l)) => l.value().to_string(),
+            other => panic!("unexpected {:?}", other),
+        };
+        assert_eq!(value("sum"), "15");
+        assert_eq!(value("avg").parse::<f64>().unwrap(), 5.0);
+        assert_eq!(value("min"), "1");
+        assert_eq!(value("max"), "10");
+        assert_eq!(value("names"), "a,b,c");
+    }
+
     #[test]
-    fn test_placeholder() {
-        // TODO: Add tests once implemented
+    fn test_optional_with_condition() {
+        let solutions = evaluate(
+            "PREFIX foaf: <http://xmlns.com/foaf/0.1/>
+             SELECT ?p ?f WHERE {
+                 ?p foaf:name ?n
+                 OPTIONAL { ?p foaf:knows ?f FILTER(?f = <http://example.org/carol>) }
+             }",
+        );
+        assert_eq!(solutions.len(), 3);
+        assert_eq!(solutions.iter().filter(|s| s.get("f").is_some()).count(), 1);
     }
 }
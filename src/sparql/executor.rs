@@ -1,7 +1,12 @@
 //! SPARQL query executor
 
-use crate::rdf::RdfStore;
-use super::results::SparqlResults;
+use crate::rdf::{BlankNode, RdfStore, RdfTerm, Triple};
+use super::algebra::{pattern_variable, projected_variables, term_to_object, term_to_predicate, term_to_subject, AlgebraEvaluator, Dataset};
+use super::results::{QuerySolution, SparqlResults};
+use spargebra::algebra::{GraphPattern, QueryDataset};
+use spargebra::term::{NamedNodePattern, TermPattern, TriplePattern};
+use spargebra::Query;
+use std::collections::{HashMap, HashSet};
 use thiserror::Error;
 
 /// Execution errors
@@ -18,48 +23,174 @@ pub enum ExecutionError {
 
 /// SPARQL query executor
 pub struct SparqlExecutor {
-    _store: RdfStore,
+    store: RdfStore,
 }
 
 impl SparqlExecutor {
     /// Create a new executor
     pub fn new(store: RdfStore) -> Self {
-        Self { _store: store }
+        Self { store }
     }
 
-    /// Execute a SELECT query
+    /// Execute a parsed query, dispatching on its form
     ///
-    /// TODO: Implement SELECT execution
-    pub fn execute_select(&self) -> Result<SparqlResults, ExecutionError> {
-        Ok(SparqlResults::empty())
+    /// `FROM`/`FROM NAMED` clauses restrict the query to those graphs; see
+    /// [`super::algebra`] for how the dataset is assembled.
+    pub fn execute(&self, query: &Query) -> Result<SparqlResults, ExecutionError> {
+        match query {
+            Query::Select { dataset, pattern, .. } => self.execute_select(pattern, dataset.as_ref()),
+            Query::Construct { template, dataset, pattern, .. } => {
+                self.execute_construct(template, pattern, dataset.as_ref())
+            }
+            Query::Ask { dataset, pattern, .. } => {
+                self.execute_ask(pattern, dataset.as_ref()).map(SparqlResults::Boolean)
+            }
+            Query::Describe { dataset, pattern, .. } => self.execute_describe(pattern, dataset.as_ref()),
+        }
+    }
+
+    fn evaluate(
+        &self,
+        pattern: &GraphPattern,
+        dataset: Option<&QueryDataset>,
+    ) -> Result<Vec<QuerySolution>, ExecutionError> {
+        AlgebraEvaluator::with_dataset(&self.store, Dataset::from_query(dataset)).evaluate(pattern)
+    }
+
+    /// Execute a SELECT query
+    pub fn execute_select(
+        &self,
+        pattern: &GraphPattern,
+        dataset: Option<&QueryDataset>,
+    ) -> Result<SparqlResults, ExecutionError> {
+        let solutions = self.evaluate(pattern, dataset)?;
+        Ok(SparqlResults::Bindings {
+            variables: projected_variables(pattern),
+            solutions,
+        })
     }
 
     /// Execute a CONSTRUCT query
     ///
-    /// TODO: Implement CONSTRUCT execution
-    pub fn execute_construct(&self) -> Result<SparqlResults, ExecutionError> {
-        Ok(SparqlResults::empty())
+    /// Each solution of `pattern` instantiates the template triples. A template
+    /// triple is dropped for a solution when one of its variables is unbound or
+    /// the substituted term is not valid in its position (e.g. a literal
+    /// subject). Template blank nodes are fresh per solution. The resulting
+    /// graph contains no duplicate triples.
+    pub fn execute_construct(
+        &self,
+        template: &[TriplePattern],
+        pattern: &GraphPattern,
+        dataset: Option<&QueryDataset>,
+    ) -> Result<SparqlResults, ExecutionError> {
+        let solutions = self.evaluate(pattern, dataset)?;
+
+        let mut seen = HashSet::new();
+        let mut triples = Vec::new();
+        for solution in &solutions {
+            let mut blank_nodes = HashMap::new();
+            for pattern in template {
+                if let Some(triple) = instantiate(pattern, solution, &mut blank_nodes) {
+                    if seen.insert(triple.clone()) {
+                        triples.push(triple);
+                    }
+                }
+            }
+        }
+
+        Ok(SparqlResults::Graph(triples))
     }
 
     /// Execute an ASK query
-    ///
-    /// TODO: Implement ASK execution
-    pub fn execute_ask(&self) -> Result<bool, ExecutionError> {
-        Ok(false)
+    pub fn execute_ask(&self, pattern: &GraphPattern, dataset: Option<&QueryDataset>) -> Result<bool, ExecutionError> {
+        Ok(!self.evaluate(pattern, dataset)?.is_empty())
     }
 
     /// Execute a DESCRIBE query
     ///
-    /// TODO: Implement DESCRIBE execution
-    pub fn execute_describe(&self) -> Result<SparqlResults, ExecutionError> {
-        Ok(SparqlResults::empty())
+    /// Describes every resource bound in the solutions by the triples it is
+    /// the subject of.
+    pub fn execute_describe(
+        &self,
+        pattern: &GraphPattern,
+        dataset: Option<&QueryDataset>,
+    ) -> Result<SparqlResults, ExecutionError> {
+        let solutions = self.evaluate(pattern, dataset)?;
+
+        let mut resources = HashSet::new();
+        for solution in &solutions {
+            for term in solution.bindings.values() {
+                if let Some(subject) = term_to_subject(term.clone()) {
+                    resources.insert(subject);
+                }
+            }
+        }
+
+        let triples = resources
+            .iter()
+            .flat_map(|subject| self.store.get_triples_with_subject(subject))
+            .collect();
+        Ok(SparqlResults::Graph(triples))
     }
 }
 
+/// Substitute a solution into a template triple
+fn instantiate(
+    pattern: &TriplePattern,
+    solution: &QuerySolution,
+    blank_nodes: &mut HashMap<String, BlankNode>,
+) -> Option<Triple> {
+    let mut term = |position: &TermPattern| -> Option<RdfTerm> {
+        match position {
+            TermPattern::NamedNode(n) => Some(RdfTerm::NamedNode(n.clone().into())),
+            TermPattern::Literal(l) => Some(RdfTerm::Literal(l.clone().into())),
+            TermPattern::BlankNode(b) => Some(RdfTerm::BlankNode(
+                blank_nodes.entry(b.as_str().to_string()).or_default().clone(),
+            )),
+            other => pattern_variable(other).and_then(|name| solution.get(&name).cloned()),
+        }
+    };
+
+    let subject = term_to_subject(term(&pattern.subject)?)?;
+    let object = term_to_object(term(&pattern.object)?);
+    let predicate = match &pattern.predicate {
+        NamedNodePattern::NamedNode(n) => RdfTerm::NamedNode(n.clone().into()),
+        NamedNodePattern::Variable(v) => solution.get(v.as_str())?.clone(),
+    };
+    Some(Triple::new(subject, term_to_predicate(predicate)?, object))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::rdf::RdfStore;
+    use crate::rdf::{NamedNode, RdfObject, RdfPredicate, RdfStore, RdfSubject};
+    use crate::sparql::SparqlParser;
+
+    const FOAF_KNOWS: &str = "http://xmlns.com/foaf/0.1/knows";
+
+    fn person(name: &str) -> NamedNode {
+        NamedNode::new(&format!("http://example.org/{}", name)).unwrap()
+    }
+
+    fn knows(a: &str, b: &str) -> Triple {
+        Triple::new(
+            RdfSubject::NamedNode(person(a)),
+            RdfPredicate::new(FOAF_KNOWS).unwrap(),
+            RdfObject::NamedNode(person(b)),
+        )
+    }
+
+    fn social_store() -> RdfStore {
+        let mut store = RdfStore::new();
+        store.insert(knows("alice", "bob")).unwrap();
+        store.insert(knows("bob", "carol")).unwrap();
+        store
+    }
+
+    fn run(store: RdfStore, query: &str) -> SparqlResults {
+        let query = SparqlParser::parse(query).unwrap();
+        SparqlExecutor::new(store).execute(&query).unwrap()
+    }
 
     #[test]
     fn test_executor_creation() {
@@ -69,34 +200,220 @@ mod tests {
 
     #[test]
     fn test_execute_select() {
-        let store = RdfStore::new();
-        let exec = SparqlExecutor::new(store);
-        let result = exec.execute_select();
-        assert!(result.is_ok());
+        let results = run(social_store(), "SELECT ?a ?b WHERE { ?a <http://xmlns.com/foaf/0.1/knows> ?b }");
+        match results {
+            SparqlResults::Bindings { variables, solutions } => {
+                assert_eq!(variables, vec!["a", "b"]);
+                assert_eq!(solutions.len(), 2);
+            }
+            _ => panic!("Expected bindings"),
+        }
     }
 
     #[test]
     fn test_execute_construct() {
-        let store = RdfStore::new();
-        let exec = SparqlExecutor::new(store);
-        let result = exec.execute_construct();
-        assert!(result.is_ok());
+        let results = run(
+            social_store(),
+            "PREFIX foaf: <http://xmlns.com/foaf/0.1/>
+             CONSTRUCT { ?b foaf:knows ?a } WHERE { ?a foaf:knows ?b }",
+        );
+        match results {
+            SparqlResults::Graph(triples) => {
+                assert_eq!(triples.len(), 2);
+                assert!(triples.contains(&knows("bob", "alice")));
+                assert!(triples.contains(&knows("carol", "bob")));
+            }
+            _ => panic!("Expected graph"),
+        }
+    }
+
+    #[test]
+    fn test_construct_drops_unbound_and_dedups() {
+        let results = run(
+            social_store(),
+            "PREFIX foaf: <http://xmlns.com/foaf/0.1/>
+             CONSTRUCT { ?x foaf:knows ?x . ?x foaf:nick ?nick }
+             WHERE { { ?x foaf:knows ?y } UNION { ?y foaf:knows ?x } OPTIONAL { ?x foaf:nick ?nick } }",
+        );
+        match results {
+            SparqlResults::Graph(triples) => {
+                // alice, bob (twice), carol → three self-loops; ?nick never binds
+                assert_eq!(triples.len(), 3);
+                assert!(triples.contains(&knows("bob", "bob")));
+            }
+            _ => panic!("Expected graph"),
+        }
     }
 
     #[test]
     fn test_execute_ask() {
-        let store = RdfStore::new();
-        let exec = SparqlExecutor::new(store);
-        let result = exec.execute_ask();
-        assert!(result.is_ok());
-        assert!(!result.unwrap()); // Default stub returns false
+        let yes = run(social_store(), "ASK { ?a <http://xmlns.com/foaf/0.1/knows> <http://example.org/carol> }");
+        assert!(matches!(yes, SparqlResults::Boolean(true)));
+        let no = run(social_store(), "ASK { <http://example.org/carol> <http://xmlns.com/foaf/0.1/knows> ?b }");
+        assert!(matches!(no, SparqlResults::Boolean(false)));
     }
 
     #[test]
     fn test_execute_describe() {
-        let store = RdfStore::new();
-        let exec = SparqlExecutor::new(store);
-        let result = exec.execute_describe();
-        assert!(result.is_ok());
+        let results = run(
+            social_store(),
+            "DESCRIBE ?p WHERE { ?p <http://xmlns.com/foaf/0.1/knows> <http://example.org/bob> }",
+        );
+        match results {
+            SparqlResults::Graph(triples) => assert_eq!(triples, vec![knows("alice", "bob")]),
+            _ => panic!("Expected graph"),
+        }
+    }
+
+    #[test]
+    fn test_count_per_predicate_grouped_by_subject_type() {
+        let rdf_type = RdfPredicate::new("http://www.w3.org/1999/02/22-rdf-syntax-ns#type").unwrap();
+        let class = |name: &str| RdfObject::NamedNode(NamedNode::new(&format!("http://example.org/{}", name)).unwrap());
+        let mut store = social_store();
+        store.insert(knows("carol", "alice")).unwrap();
+        for (name, ty) in [("alice", "Person"), ("bob", "Person"), ("carol", "Robot")] {
+            store
+                .insert(Triple::new(RdfSubject::NamedNode(person(name)), rdf_type.clone(), class(ty)))
+                .unwrap();
+        }
+
+        let results = run(
+            store,
+            "PREFIX rdf: <http://www.w3.org/1999/02/22-rdf-syntax-ns#>
+             SELECT ?type ?p (COUNT(?o) AS ?n) WHERE { ?s rdf:type ?type . ?s ?p ?o }
+             GROUP BY ?type ?p HAVING (COUNT(?o) > 1)",
+        );
+        let SparqlResults::Bindings { variables, solutions } = results else {
+            panic!("Expected bindings");
+        };
+        assert_eq!(variables, vec!["type", "p", "n"]);
+
+        let term = |s: &QuerySolution, v: &str| match s.get(v) {
+            Some(RdfTerm::NamedNode(n)) => n.as_str().rsplit(['/', '#']).next().unwrap().to_string(),
+            Some(RdfTerm::Literal(l)) => l.value().to_string(),
+            other => panic!("unexpected {:?}", other),
+        };
+        let mut rows: Vec<(String, String, String)> = solutions
+            .iter()
+            .map(|s| (term(s, "type"), term(s, "p"), term(s, "n")))
+            .collect();
+        rows.sort();
+        // Robots have one triple per predicate, so HAVING drops them
+        assert_eq!(rows, vec![
+            ("Person".to_string(), "knows".to_string(), "2".to_string()),
+            ("Person".to_string(), "type".to_string(), "2".to_string()),
+        ]);
+    }
+
+    #[test]
+    fn test_optional_leaves_missing_values_unbound() {
+        use crate::rdf::Literal;
+
+        let mbox = RdfPredicate::new("http://xmlns.com/foaf/0.1/mbox").unwrap();
+        let mut store = social_store();
+        store
+            .insert(Triple::new(
+                RdfSubject::NamedNode(person("alice")),
+                mbox,
+                Literal::new_simple_literal("alice@example.org").into(),
+            ))
+            .unwrap();
+
+        let results = run(
+            store,
+            "PREFIX foaf: <http://xmlns.com/foaf/0.1/>
+             SELECT ?p ?mbox WHERE { ?p foaf:knows ?friend OPTIONAL { ?p foaf:mbox ?mbox } }",
+        );
+        let SparqlResults::Bindings { variables, solutions } = results else {
+            panic!("Expected bindings");
+        };
+        assert_eq!(variables, vec!["p", "mbox"]);
+        assert_eq!(solutions.len(), 2);
+
+        let alice = solutions.iter().find(|s| s.get("p") == Some(&RdfTerm::NamedNode(person("alice")))).unwrap();
+        assert!(matches!(alice.get("mbox"), Some(RdfTerm::Literal(l)) if l.value() == "alice@example.org"));
+        // bob has no mailbox but is still returned
+        let bob = solutions.iter().find(|s| s.get("p") == Some(&RdfTerm::NamedNode(person("bob")))).unwrap();
+        assert!(bob.get("mbox").is_none());
+    }
+
+    #[test]
+    fn test_graph_scoping_and_dataset_clauses() {
+        use crate::rdf::Quad;
+
+        let crawl = NamedNode::new("http://example.org/graphs/crawl").unwrap();
+        let curated = NamedNode::new("http://example.org/graphs/curated").unwrap();
+        let mut store = RdfStore::new();
+        for (triple, graph) in [(knows("alice", "bob"), &crawl), (knows("bob", "carol"), &curated)] {
+            let Triple { subject, predicate, object } = triple;
+            store.insert_quad(Quad::new(subject, predicate, object, Some(graph.clone()))).unwrap();
+        }
+
+        let bindings = |results: SparqlResults| match results {
+            SparqlResults::Bindings { solutions, .. } => solutions,
+            _ => panic!("Expected bindings"),
+        };
+        let subjects = |solutions: &[QuerySolution]| -> Vec<RdfTerm> {
+            solutions.iter().map(|s| s.get("a").unwrap().clone()).collect()
+        };
+
+        // A fixed graph only sees its own triples
+        let solutions = bindings(run(
+            store.clone(),
+            "SELECT ?a WHERE { GRAPH <http://example.org/graphs/curated> { ?a <http://xmlns.com/foaf/0.1/knows> ?b } }",
+        ));
+        assert_eq!(subjects(&solutions), vec![RdfTerm::NamedNode(person("bob"))]);
+
+        // A graph variable is bound to the graph of each match
+        let solutions = bindings(run(
+            store.clone(),
+            "SELECT ?g ?a WHERE { GRAPH ?g { ?a <http://xmlns.com/foaf/0.1/knows> ?b } }",
+        ));
+        assert_eq!(solutions.len(), 2);
+        let alice = solutions.iter().find(|s| s.get("a") == Some(&RdfTerm::NamedNode(person("alice")))).unwrap();
+        assert_eq!(alice.get("g"), Some(&RdfTerm::NamedNode(crawl.clone())));
+
+        // FROM makes a graph the default graph; FROM NAMED limits what GRAPH sees
+        let solutions = bindings(run(
+            store.clone(),
+            "SELECT ?a FROM <http://example.org/graphs/crawl> WHERE { ?a <http://xmlns.com/foaf/0.1/knows> ?b }",
+        ));
+        assert_eq!(subjects(&solutions), vec![RdfTerm::NamedNode(person("alice"))]);
+        let solutions = bindings(run(
+            store,
+            "SELECT ?a FROM NAMED <http://example.org/graphs/crawl>
+             WHERE { GRAPH ?g { ?a <http://xmlns.com/foaf/0.1/knows> ?b } }",
+        ));
+        assert_eq!(subjects(&solutions), vec![RdfTerm::NamedNode(person("alice"))]);
+    }
+
+    #[test]
+    fn test_select_with_filter() {
+        use crate::rdf::Literal;
+
+        let age = RdfPredicate::new("http://xmlns.com/foaf/0.1/age").unwrap();
+        let integer = NamedNode::new("http://www.w3.org/2001/XMLSchema#integer").unwrap();
+        let mut store = social_store();
+        for (name, value) in [("alice", Literal::new_typed_literal("34", integer.clone())),
+                              ("bob", Literal::new_typed_literal("25", integer)),
+                              ("carol", Literal::new_simple_literal("unknown"))] {
+            store
+                .insert(Triple::new(RdfSubject::NamedNode(person(name)), age.clone(), value.into()))
+                .unwrap();
+        }
+
+        // carol's string age cannot be compared with 30, so her row is excluded
+        let results = run(
+            store,
+            "PREFIX foaf: <http://xmlns.com/foaf/0.1/>
+             SELECT ?p WHERE { ?p foaf:age ?age FILTER(?age > 30 || !bound(?age)) }",
+        );
+        match results {
+            SparqlResults::Bindings { solutions, .. } => {
+                assert_eq!(solutions.len(), 1);
+                assert_eq!(solutions[0].get("p"), Some(&RdfTerm::NamedNode(person("alice"))));
+            }
+            _ => panic!("Expected bindings"),
+        }
     }
 }
@@ -1,6 +1,29 @@
 //! SPARQL HTTP protocol endpoint
+//!
+//! Implements the query operation of the SPARQL 1.1 Protocol:
+//! - `GET /sparql?query=...`
+//! - `POST /sparql` with an `application/x-www-form-urlencoded` body (`query=...`)
+//! - `POST /sparql` with an `application/sparql-query` body holding the query text
+//!
+//! SELECT and ASK results are content-negotiated from the `Accept` header between
+//! SPARQL-JSON (the default), CSV, TSV and XML. CONSTRUCT and DESCRIBE results are
+//! returned as N-Triples. A malformed query yields `400 Bad Request`, an
+//! unsatisfiable `Accept` header `406 Not Acceptable`.
 
+use super::results::{ResultFormat, SparqlResults};
+use super::{SparqlEngine, SparqlError};
+use crate::rdf::RdfStore;
+use axum::{
+    extract::{FromRequest, Query, Request, State},
+    http::{header, HeaderMap, StatusCode},
+    response::{IntoResponse, Response},
+    routing::get,
+    Form, Router,
+};
+use std::collections::HashMap;
+use std::sync::Arc;
 use thiserror::Error;
+use tracing::info;
 
 /// HTTP errors
 #[derive(Error, Debug)]
@@ -15,29 +38,293 @@ pub enum HttpError {
 }
 
 /// SPARQL HTTP endpoint
-///
-/// TODO: Implement using axum web framework
-/// - POST /sparql for queries
-/// - Content negotiation
-/// - Result format handling
-pub struct SparqlHttpEndpoint;
+pub struct SparqlHttpEndpoint {
+    engine: Arc<SparqlEngine>,
+}
 
 impl SparqlHttpEndpoint {
-    /// Create a new HTTP endpoint
-    pub fn new() -> Self {
-        Self
+    /// Create a new HTTP endpoint serving queries over `store`
+    pub fn new(store: RdfStore) -> Self {
+        Self {
+            engine: Arc::new(SparqlEngine::new(store)),
+        }
+    }
+
+    /// Router exposing the query operation at `/sparql`
+    pub fn router(&self) -> Router {
+        Router::new()
+            .route("/sparql", get(query_get).post(query_post))
+            .with_state(Arc::clone(&self.engine))
     }
 
     /// Start the HTTP server
-    ///
-    /// TODO: Implement using axum
-    pub async fn start(&self, _port: u16) -> Result<(), HttpError> {
-        Ok(())
+    pub async fn start(&self, port: u16) -> Result<(), HttpError> {
+        let addr = format!("0.0.0.0:{}", port);
+        let listener = tokio::net::TcpListener::bind(&addr)
+            .await
+            .map_err(|e| HttpError::Server(e.to_string()))?;
+
+        info!("SPARQL endpoint available at http://localhost:{}/sparql", port);
+
+        axum::serve(listener, self.router())
+            .await
+            .map_err(|e| HttpError::Server(e.to_string()))
     }
 }
 
 impl Default for SparqlHttpEndpoint {
     fn default() -> Self {
-        Self::new()
+        Self::new(RdfStore::new())
+    }
+}
+
+impl IntoResponse for HttpError {
+    fn into_response(self) -> Response {
+        let status = match self {
+            HttpError::InvalidRequest(_) => StatusCode::BAD_REQUEST,
+            HttpError::Server(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        };
+        (status, self.to_string()).into_response()
+    }
+}
+
+async fn query_get(
+    State(engine): State<Arc<SparqlEngine>>,
+    Query(params): Query<HashMap<String, String>>,
+    headers: HeaderMap,
+) -> Response {
+    match params.get("query") {
+        Some(query) => respond(&engine, query, &headers),
+        None => HttpError::InvalidRequest("Missing 'query' parameter".to_string()).into_response(),
+    }
+}
+
+async fn query_post(State(engine): State<Arc<SparqlEngine>>, request: Request) -> Response {
+    let headers = request.headers().clone();
+    let content_type = headers
+        .get(header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.split(';').next())
+        .map(|v| v.trim().to_ascii_lowercase())
+        .unwrap_or_default();
+
+    let query = match content_type.as_str() {
+        "application/sparql-query" => match String::from_request(request, &()).await {
+            Ok(body) => body,
+            Err(rejection) => return rejection.into_response(),
+        },
+        "application/x-www-form-urlencoded" => {
+            match Form::<HashMap<String, String>>::from_request(request, &()).await {
+                Ok(Form(mut params)) => match params.remove("query") {
+                    Some(query) => query,
+                    None => {
+                        return HttpError::InvalidRequest("Missing 'query' parameter".to_string())
+                            .into_response()
+                    }
+                },
+                Err(rejection) => return rejection.into_response(),
+            }
+        }
+        other => {
+            return (
+                StatusCode::UNSUPPORTED_MEDIA_TYPE,
+                format!("Unsupported content type '{}'", other),
+            )
+                .into_response()
+        }
+    };
+
+    respond(&engine, &query, &headers)
+}
+
+/// Run `query` and serialize its results in the negotiated format
+fn respond(engine: &SparqlEngine, query: &str, headers: &HeaderMap) -> Response {
+    let accept = headers.get(header::ACCEPT).and_then(|v| v.to_str().ok());
+    let Some(format) = negotiate(accept) else {
+        return (StatusCode::NOT_ACCEPTABLE, "No acceptable result format").into_response();
+    };
+
+    let results = match engine.query(query) {
+        Ok(results) => results,
+        Err(e @ SparqlError::Parse(_)) => return HttpError::InvalidRequest(e.to_string()).into_response(),
+        Err(e) => return HttpError::Server(e.to_string()).into_response(),
+    };
+
+    let media_type = match results {
+        SparqlResults::Graph(_) => "application/n-triples",
+        _ => format.media_type(),
+    };
+    match results.serialize(format) {
+        Ok(body) => ([(header::CONTENT_TYPE, media_type)], body).into_response(),
+        Err(e) => HttpError::Server(e).into_response(),
+    }
+}
+
+/// Pick the result format preferred by an `Accept` header
+///
+/// A missing header selects SPARQL-JSON; `None` means nothing acceptable is offered.
+fn negotiate(accept: Option<&str>) -> Option<ResultFormat> {
+    let Some(accept) = accept else {
+        return Some(ResultFormat::Json);
+    };
+
+    media_ranges(accept).iter().find_map(|(media, _)| match media.as_str() {
+        "application/sparql-results+json" | "application/json" | "application/*" | "*/*" => {
+            Some(ResultFormat::Json)
+        }
+        "text/csv" | "text/*" => Some(ResultFormat::Csv),
+        "text/tab-separated-values" => Some(ResultFormat::Tsv),
+        "application/sparql-results+xml" | "application/xml" => Some(ResultFormat::Xml),
+        _ => None,
+    })
+}
+
+/// Media ranges of an `Accept` header with their q-values, most preferred first
+///
+/// Media types are lowercased; ranges with `q=0` are dropped.
+pub(crate) fn media_ranges(accept: &str) -> Vec<(String, f32)> {
+    let mut ranges: Vec<(String, f32)> = accept
+        .split(',')
+        .filter_map(|range| {
+            let mut parts = range.split(';');
+            let media = parts.next()?.trim().to_ascii_lowercase();
+            let quality = parts
+                .filter_map(|p| p.trim().strip_prefix("q="))
+                .find_map(|q| q.trim().parse::<f32>().ok())
+                .unwrap_or(1.0);
+            Some((media, quality))
+        })
+        .filter(|(media, quality)| !media.is_empty() && *quality > 0.0)
+        .collect();
+    // Stable sort keeps header order among equal qualities
+    ranges.sort_by(|a, b| b.1.total_cmp(&a.1));
+    ranges
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rdf::{Literal, NamedNode, RdfPredicate, RdfSubject, Triple};
+    use axum::body::Body;
+    use http_body_util::BodyExt;
+    use tower::util::ServiceExt;
+
+    const QUERY: &str = "SELECT ?name WHERE { ?p <http://xmlns.com/foaf/0.1/name> ?name }";
+
+    fn endpoint() -> SparqlHttpEndpoint {
+        let mut store = RdfStore::new();
+        store
+            .insert(Triple::new(
+                RdfSubject::NamedNode(NamedNode::new("http://example.org/alice").unwrap()),
+                RdfPredicate::new("http://xmlns.com/foaf/0.1/name").unwrap(),
+                Literal::new_simple_literal("Alice").into(),
+            ))
+            .unwrap();
+        SparqlHttpEndpoint::new(store)
+    }
+
+    async fn send(request: axum::http::Request<Body>) -> (StatusCode, String, String) {
+        let response = endpoint().router().oneshot(request).await.unwrap();
+        let status = response.status();
+        let content_type = response
+            .headers()
+            .get(header::CONTENT_TYPE)
+            .map(|v| v.to_str().unwrap().to_string())
+            .unwrap_or_default();
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        (status, content_type, String::from_utf8(body.to_vec()).unwrap())
+    }
+
+    fn encode(query: &str) -> String {
+        query
+            .bytes()
+            .map(|b| match b {
+                b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => (b as char).to_string(),
+                _ => format!("%{:02X}", b),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_negotiate() {
+        assert_eq!(negotiate(None), Some(ResultFormat::Json));
+        assert_eq!(negotiate(Some("text/csv")), Some(ResultFormat::Csv));
+        assert_eq!(
+            negotiate(Some("text/csv;q=0.5, application/sparql-results+json")),
+            Some(ResultFormat::Json)
+        );
+        assert_eq!(negotiate(Some("image/png, */*;q=0.1")), Some(ResultFormat::Json));
+        assert_eq!(negotiate(Some("image/png")), None);
+    }
+
+    #[tokio::test]
+    async fn test_get_returns_sparql_json() {
+        let request = axum::http::Request::builder()
+            .uri(format!("/sparql?query={}", encode(QUERY)))
+            .header(header::ACCEPT, "application/sparql-results+json")
+            .body(Body::empty())
+            .unwrap();
+        let (status, content_type, body) = send(request).await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(content_type, "application/sparql-results+json");
+        let json: serde_json::Value = serde_json::from_str(&body).unwrap();
+        assert_eq!(json["head"]["vars"], serde_json::json!(["name"]));
+        assert_eq!(
+            json["results"]["bindings"][0]["name"],
+            serde_json::json!({"type": "literal", "value": "Alice"})
+        );
+    }
+
+    #[tokio::test]
+    async fn test_post_forms_and_csv() {
+        let request = axum::http::Request::builder()
+            .method("POST")
+            .uri("/sparql")
+            .header(header::CONTENT_TYPE, "application/sparql-query")
+            .header(header::ACCEPT, "text/csv")
+            .body(Body::from(QUERY))
+            .unwrap();
+        let (status, content_type, body) = send(request).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(content_type, "text/csv");
+        assert_eq!(body, "name\r\nAlice\r\n");
+
+        let request = axum::http::Request::builder()
+            .method("POST")
+            .uri("/sparql")
+            .header(header::CONTENT_TYPE, "application/x-www-form-urlencoded")
+            .body(Body::from(format!("query={}", encode("ASK { ?s ?p ?o }"))))
+            .unwrap();
+        let (status, _, body) = send(request).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body, r#"{"head":{},"boolean":true}"#);
+    }
+
+    #[tokio::test]
+    async fn test_error_status_codes() {
+        let request = axum::http::Request::builder()
+            .uri(format!("/sparql?query={}", encode("SELEKT ?s")))
+            .body(Body::empty())
+            .unwrap();
+        assert_eq!(send(request).await.0, StatusCode::BAD_REQUEST);
+
+        let request = axum::http::Request::builder().uri("/sparql").body(Body::empty()).unwrap();
+        assert_eq!(send(request).await.0, StatusCode::BAD_REQUEST);
+
+        let request = axum::http::Request::builder()
+            .uri(format!("/sparql?query={}", encode(QUERY)))
+            .header(header::ACCEPT, "image/png")
+            .body(Body::empty())
+            .unwrap();
+        assert_eq!(send(request).await.0, StatusCode::NOT_ACCEPTABLE);
+
+        let request = axum::http::Request::builder()
+            .method("POST")
+            .uri("/sparql")
+            .header(header::CONTENT_TYPE, "text/plain")
+            .body(Body::from(QUERY))
+            .unwrap();
+        assert_eq!(send(request).await.0, StatusCode::UNSUPPORTED_MEDIA_TYPE);
     }
 }
@@ -35,6 +35,7 @@
 mod parser;
 mod executor;
 mod algebra;
+mod expression;
 mod optimizer;
 mod results;
 mod http;
@@ -43,6 +44,7 @@ pub use parser::{SparqlParser, ParseError as SparqlParseError};
 pub use executor::{SparqlExecutor, ExecutionError};
 pub use results::{SparqlResults, ResultFormat, QuerySolution};
 pub use http::{SparqlHttpEndpoint, HttpError};
+pub(crate) use http::media_ranges;
 
 use crate::rdf::RdfStore;
 use thiserror::Error;
@@ -85,15 +87,11 @@ impl SparqlEngine {
     }
 
     /// Execute a SPARQL query
-    ///
-    /// TODO: Full implementation
-    pub fn query(&self, _query_str: &str) -> SparqlResult<SparqlResults> {
-        // TODO: Implement query execution
-        // 1. Parse query using SparqlParser
-        // 2. Optimize using optimizer
-        // 3. Execute using SparqlExecutor
-        // 4. Return results
-        Ok(SparqlResults::empty())
+    pub fn query(&self, query_str: &str) -> SparqlResult<SparqlResults> {
+        let query = SparqlParser::parse(query_str).map_err(|e| SparqlError::Parse(e.to_string()))?;
+        self.executor
+            .execute(&query)
+            .map_err(|e| SparqlError::Execution(e.to_string()))
     }
 
     /// Execute a SPARQL UPDATE operation
@@ -1,5 +1,6 @@
 //! SPARQL parser using spargebra library
 
+use spargebra::{Query, Update};
 use thiserror::Error;
 
 /// Parse errors
@@ -18,20 +19,14 @@ pub enum ParseError {
 pub struct SparqlParser;
 
 impl SparqlParser {
-    /// Parse a SPARQL query string
-    ///
-    /// TODO: Implement using spargebra::Query::parse
-    pub fn parse(_query: &str) -> Result<(), ParseError> {
-        // TODO: Implement parsing
-        Ok(())
+    /// Parse a SPARQL query string into its algebra form
+    pub fn parse(query: &str) -> Result<Query, ParseError> {
+        Query::parse(query, None).map_err(|e| ParseError::Syntax(e.to_string()))
     }
 
     /// Parse a SPARQL UPDATE string
-    ///
-    /// TODO: Implement using spargebra::Update::parse
-    pub fn parse_update(_update: &str) -> Result<(), ParseError> {
-        // TODO: Implement update parsing
-        Ok(())
+    pub fn parse_update(update: &str) -> Result<Update, ParseError> {
+        Update::parse(update, None).map_err(|e| ParseError::Syntax(e.to_string()))
     }
 }
 
@@ -40,8 +35,22 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_parse_stub() {
+    fn test_parse_select() {
         let result = SparqlParser::parse("SELECT * WHERE { ?s ?p ?o }");
-        assert!(result.is_ok());
+        assert!(matches!(result, Ok(Query::Select { .. })));
+    }
+
+    #[test]
+    fn test_parse_construct() {
+        let result = SparqlParser::parse(
+            "PREFIX foaf: <http://xmlns.com/foaf/0.1/> CONSTRUCT { ?b foaf:knows ?a } WHERE { ?a foaf:knows ?b }",
+        );
+        assert!(matches!(result, Ok(Query::Construct { .. })));
+    }
+
+    #[test]
+    fn test_parse_syntax_error() {
+        let result = SparqlParser::parse("SELEKT ?s WHERE { ?s ?p ?o }");
+        assert!(matches!(result, Err(ParseError::Syntax(_))));
     }
 }
@@ -1,10 +1,13 @@
 //! SPARQL query results
 
 use crate::rdf::{Triple, RdfTerm};
+use super::algebra::term_to_object;
+use oxrdf::{Term as OxTerm, Variable};
+use sparesults::{QueryResultsFormat, QueryResultsSerializer};
 use std::collections::HashMap;
 
 /// SPARQL result format
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ResultFormat {
     /// JSON results
     Json,
@@ -16,6 +19,29 @@ pub enum ResultFormat {
     Tsv,
 }
 
+impl ResultFormat {
+    /// IANA media type of the format
+    pub fn media_type(&self) -> &'static str {
+        match self {
+            ResultFormat::Json => "application/sparql-results+json",
+            ResultFormat::Xml => "application/sparql-results+xml",
+            ResultFormat::Csv => "text/csv",
+            ResultFormat::Tsv => "text/tab-separated-values",
+        }
+    }
+}
+
+impl From<ResultFormat> for QueryResultsFormat {
+    fn from(format: ResultFormat) -> Self {
+        match format {
+            ResultFormat::Json => QueryResultsFormat::Json,
+            ResultFormat::Xml => QueryResultsFormat::Xml,
+            ResultFormat::Csv => QueryResultsFormat::Csv,
+            ResultFormat::Tsv => QueryResultsFormat::Tsv,
+        }
+    }
+}
+
 /// Query solution (variable bindings)
 #[derive(Debug, Clone)]
 pub struct QuerySolution {
@@ -77,9 +103,60 @@ impl SparqlResults {
 
     /// Serialize results to string
     ///
-    /// TODO: Implement using sparesults library
-    pub fn serialize(&self, _format: ResultFormat) -> Result<String, String> {
-        Ok(String::new())
+    /// SELECT and ASK results use the W3C SPARQL 1.1 results formats; graph
+    /// results from CONSTRUCT/DESCRIBE are written as N-Triples whatever the format.
+    pub fn serialize(&self, format: ResultFormat) -> Result<String, String> {
+        let serializer = QueryResultsSerializer::from_format(format.into());
+        let bytes = match self {
+            SparqlResults::Bindings { variables, solutions } => {
+                let variables = variables
+                    .iter()
+                    .map(|name| Variable::new(name.as_str()).map_err(|e| e.to_string()))
+                    .collect::<Result<Vec<_>, _>>()?;
+                let mut writer = serializer
+                    .serialize_solutions_to_writer(Vec::new(), variables.clone())
+                    .map_err(|e| e.to_string())?;
+                for solution in solutions {
+                    let row: Vec<(&Variable, OxTerm)> = variables
+                        .iter()
+                        .filter_map(|v| {
+                            let term = solution.get(v.as_str())?.clone();
+                            Some((v, OxTerm::from(term_to_object(term))))
+                        })
+                        .collect();
+                    writer
+                        .serialize(row.iter().map(|(v, t)| (v.as_ref(), t.as_ref())))
+                        .map_err(|e| e.to_string())?;
+                }
+                writer.finish().map_err(|e| e.to_string())?
+            }
+            SparqlResults::Boolean(value) => serializer
+                .serialize_boolean_to_writer(Vec::new(), *value)
+                .map_err(|e| e.to_string())?,
+            SparqlResults::Graph(triples) => {
+                return Ok(triples.iter().map(|t| format!("{} .\n", t.to_oxrdf())).collect());
+            }
+        };
+        String::from_utf8(bytes).map_err(|e| e.to_string())
+    }
+
+    /// Serialize to the W3C SPARQL 1.1 Query Results JSON Format
+    ///
+    /// SELECT results carry `head.vars` and one object per solution in
+    /// `results.bindings`, mapping each bound variable to a `uri`, `literal`
+    /// (with `xml:lang` or `datatype` when present) or `bnode` value object.
+    /// ASK results are `{"head":{},"boolean":...}`.
+    pub fn to_json(&self) -> Result<String, String> {
+        self.serialize(ResultFormat::Json)
+    }
+
+    /// Serialize to the W3C SPARQL 1.1 Query Results CSV Format
+    ///
+    /// A header row of variable names followed by one CRLF-terminated row per
+    /// solution holding the plain lexical values; unbound variables are empty
+    /// fields. ASK results serialize as `true` or `false`.
+    pub fn to_csv(&self) -> Result<String, String> {
+        self.serialize(ResultFormat::Csv)
     }
 }
 
@@ -142,4 +219,74 @@ mod tests {
         let output = result.serialize(ResultFormat::Json);
         assert!(output.is_ok());
     }
+
+    /// Two-variable SELECT with an IRI, a language-tagged and a typed literal,
+    /// and an unbound value
+    fn two_variable_results() -> SparqlResults {
+        use crate::rdf::{Literal, NamedNode};
+
+        let x = RdfTerm::NamedNode(NamedNode::new("http://example/x").unwrap());
+        let integer = NamedNode::new("http://www.w3.org/2001/XMLSchema#integer").unwrap();
+        let rows = [
+            Some(RdfTerm::Literal(Literal::new_simple_literal("String-with-dquote\""))),
+            Some(RdfTerm::Literal(Literal::new_language_tagged_literal("String-with-lang", "en").unwrap())),
+            Some(RdfTerm::Literal(Literal::new_typed_literal("123", integer))),
+            None,
+        ];
+        let solutions = rows
+            .into_iter()
+            .map(|literal| {
+                let mut solution = QuerySolution::new();
+                solution.bind("x".to_string(), x.clone());
+                if let Some(literal) = literal {
+                    solution.bind("literal".to_string(), literal);
+                }
+                solution
+            })
+            .collect();
+        SparqlResults::Bindings {
+            variables: vec!["x".to_string(), "literal".to_string()],
+            solutions,
+        }
+    }
+
+    #[test]
+    fn test_to_json_w3c_format() {
+        let json: serde_json::Value = serde_json::from_str(&two_variable_results().to_json().unwrap()).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "head": {"vars": ["x", "literal"]},
+                "results": {"bindings": [
+                    {"x": {"type": "uri", "value": "http://example/x"},
+                     "literal": {"type": "literal", "value": "String-with-dquote\""}},
+                    {"x": {"type": "uri", "value": "http://example/x"},
+                     "literal": {"type": "literal", "value": "String-with-lang", "xml:lang": "en"}},
+                    {"x": {"type": "uri", "value": "http://example/x"},
+                     "literal": {"type": "literal", "value": "123",
+                                 "datatype": "http://www.w3.org/2001/XMLSchema#integer"}},
+                    {"x": {"type": "uri", "value": "http://example/x"}}
+                ]}
+            })
+        );
+    }
+
+    #[test]
+    fn test_to_csv_w3c_format() {
+        assert_eq!(
+            two_variable_results().to_csv().unwrap(),
+            "x,literal\r\n\
+             http://example/x,\"String-with-dquote\"\"\"\r\n\
+             http://example/x,String-with-lang\r\n\
+             http://example/x,123\r\n\
+             http://example/x,\r\n"
+        );
+    }
+
+    #[test]
+    fn test_ask_serialization() {
+        let json: serde_json::Value = serde_json::from_str(&SparqlResults::Boolean(true).to_json().unwrap()).unwrap();
+        assert_eq!(json, serde_json::json!({"head": {}, "boolean": true}));
+        assert_eq!(SparqlResults::Boolean(false).to_csv().unwrap(), "false");
+    }
 }
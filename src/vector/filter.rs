@@ -0,0 +1,109 @@
+//! Property predicates for filtered vector search
+//!
+//! A [`VectorFilter`] constrains the candidates of a k-NN search to nodes whose
+//! properties match, e.g. "nearest documents where department = 'Security'".
+//! Integer and float properties compare numerically with each other; any other
+//! mix of types never matches.
+
+use crate::graph::PropertyValue;
+use std::cmp::Ordering;
+
+/// Predicate on a candidate node's properties
+#[derive(Debug, Clone, PartialEq)]
+pub enum VectorFilter {
+    /// Property equals the value
+    Eq(String, PropertyValue),
+    /// Property lies within inclusive bounds; `None` leaves that side open
+    Range {
+        key: String,
+        min: Option<PropertyValue>,
+        max: Option<PropertyValue>,
+    },
+    /// Every filter matches
+    And(Vec<VectorFilter>),
+}
+
+impl VectorFilter {
+    /// Property equality filter
+    pub fn eq(key: impl Into<String>, value: impl Into<PropertyValue>) -> Self {
+        VectorFilter::Eq(key.into(), value.into())
+    }
+
+    /// Inclusive property range filter
+    pub fn range(key: impl Into<String>, min: Option<PropertyValue>, max: Option<PropertyValue>) -> Self {
+        VectorFilter::Range { key: key.into(), min, max }
+    }
+
+    /// Evaluate the filter, reading properties through `property`
+    ///
+    /// `property` returns `PropertyValue::Null` for a missing key, which matches nothing.
+    pub fn matches(&self, property: &impl Fn(&str) -> PropertyValue) -> bool {
+        match self {
+            VectorFilter::Eq(key, expected) => compare(&property(key), expected) == Some(Ordering::Equal),
+            VectorFilter::Range { key, min, max } => {
+                let value = property(key);
+                if value.is_null() {
+                    return false;
+                }
+                let above = min.as_ref().is_none_or(|min| {
+                    matches!(compare(&value, min), Some(Ordering::Greater | Ordering::Equal))
+                });
+                let below = max.as_ref().is_none_or(|max| {
+                    matches!(compare(&value, max), Some(Ordering::Less | Ordering::Equal))
+                });
+                above && below
+            }
+            VectorFilter::And(filters) => filters.iter().all(|f| f.matches(property)),
+        }
+    }
+}
+
+/// Order two values of comparable type
+fn compare(a: &PropertyValue, b: &PropertyValue) -> Option<Ordering> {
+    use PropertyValue::*;
+    match (a, b) {
+        (Null, _) | (_, Null) => None,
+        (Integer(x), Float(y)) => (*x as f64).partial_cmp(y),
+        (Float(x), Integer(y)) => x.partial_cmp(&(*y as f64)),
+        _ if std::mem::discriminant(a) == std::mem::discriminant(b) => Some(a.cmp(b)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn props() -> HashMap<String, PropertyValue> {
+        let mut props = HashMap::new();
+        props.insert("department".to_string(), PropertyValue::String("Security".to_string()));
+        props.insert("year".to_string(), PropertyValue::Integer(2021));
+        props
+    }
+
+    fn check(filter: &VectorFilter) -> bool {
+        let props = props();
+        filter.matches(&|key: &str| props.get(key).cloned().unwrap_or(PropertyValue::Null))
+    }
+
+    #[test]
+    fn test_equality() {
+        assert!(check(&VectorFilter::eq("department", "Security")));
+        assert!(!check(&VectorFilter::eq("department", "Finance")));
+        assert!(!check(&VectorFilter::eq("missing", "Security")));
+        assert!(check(&VectorFilter::eq("year", PropertyValue::Float(2021.0))));
+    }
+
+    #[test]
+    fn test_range_and_conjunction() {
+        let recent = VectorFilter::range("year", Some(PropertyValue::Integer(2020)), None);
+        assert!(check(&recent));
+        assert!(!check(&VectorFilter::range("year", None, Some(PropertyValue::Float(2020.5)))));
+        // A string bound never matches an integer property
+        assert!(!check(&VectorFilter::range("year", Some(PropertyValue::String("2020".to_string())), None)));
+
+        assert!(check(&VectorFilter::And(vec![recent.clone(), VectorFilter::eq("department", "Security")])));
+        assert!(!check(&VectorFilter::And(vec![recent, VectorFilter::eq("department", "Finance")])));
+    }
+}
@@ -20,12 +20,18 @@
 //!   search. Higher = better recall but slower queries. Must be >= k (number of results).
 //!   This is the main recall-vs-speed knob at query time.
 //!
+//! These are set per index through [`HnswConfig`]. The defaults (`m = 16`,
+//! `ef_construction = 200`, `ef_search = 64`) suit dense embeddings of a few hundred
+//! dimensions; high-dimensional or clustered data usually wants a larger `m`. The
+//! index's `ef_search` can be overridden per query with [`VectorIndex::search_with_ef`].
+//!
 //! ## Distance trait
 //!
 //! Rust's trait system enables polymorphic distance computation. The `hnsw_rs` crate
-//! defines a `Distance<T>` trait, and this module implements it with `CosineDistance`
-//! and `InnerProductDistance` structs. This allows the same HNSW data structure to work
-//! with different distance metrics without runtime dispatch overhead (monomorphization).
+//! defines a `Distance<T>` trait, and this module implements it with `CosineDistance`,
+//! `EuclideanDistance` and `DotProductDistance` structs. Each index holds an HNSW graph
+//! monomorphized for its metric, so there is no runtime dispatch per distance
+//! evaluation — only one match per insert or search.
 //!
 //! ## Cosine distance formula
 //!
@@ -36,6 +42,15 @@
 //! - **1** = orthogonal (perpendicular, no similarity)
 //! - **2** = opposite direction (anti-correlated)
 //!
+//! ## Euclidean and dot product
+//!
+//! Euclidean distance is `||a - b||`; smaller is closer. Dot product is a similarity —
+//! larger is closer — so search results report `-(a . b)` as the distance, keeping
+//! "ascending distance = best first" for every metric. `hnsw_rs` asserts that
+//! distances are non-negative, so inside the graph the dot product goes through a
+//! positive, strictly decreasing transform instead (see [`DotProductDistance`]);
+//! the neighbour ranking is the same.
+//!
 //! ## Persistence strategy
 //!
 //! HNSW indices (from `hnsw_rs`) don't expose an iterator over stored vectors.
@@ -43,9 +58,19 @@
 //! alongside the HNSW structure. On serialization, this vector list is saved via
 //! `bincode`. On load, a fresh HNSW index is constructed and all stored vectors are
 //! re-inserted. This trades load-time speed for implementation simplicity.
+//!
+//! ## Deletion
+//!
+//! `hnsw_rs` cannot remove points from its graph. Each inserted vector gets an
+//! internal slot id (its HNSW data id), and [`VectorIndex::remove`] tombstones every
+//! slot of a node instead. Searches over-fetch by the tombstone count and drop
+//! tombstoned hits, so deleted nodes never surface as neighbours. Once tombstones
+//! make up half the graph, [`VectorIndex::compact`] rebuilds the HNSW from the live
+//! vectors, repairing the neighbourhoods that routed through deleted points.
 
 use crate::graph::NodeId;
 use hnsw_rs::prelude::*;
+use std::collections::{HashMap, HashSet};
 use thiserror::Error;
 
 /// Vector index errors
@@ -62,6 +87,9 @@ pub enum VectorError {
 
     #[error("Search failed: {0}")]
     SearchFailed(String),
+
+    #[error("Unsupported vector index format version {found} (this build reads versions up to {supported})")]
+    UnsupportedFormatVersion { found: u64, supported: u64 },
 }
 
 pub type VectorResult<T> = Result<T, VectorError>;
@@ -69,12 +97,60 @@ pub type VectorResult<T> = Result<T, VectorError>;
 /// Distance metric for vector search
 #[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub enum DistanceMetric {
-    /// L2 (Euclidean) distance
-    L2,
+    /// Euclidean (L2) distance; suits un-normalized embeddings
+    #[serde(alias = "L2")]
+    Euclidean,
     /// Cosine similarity
     Cosine,
-    /// Inner product
-    InnerProduct,
+    /// Dot (inner) product; larger products rank first
+    #[serde(alias = "InnerProduct")]
+    DotProduct,
+}
+
+impl DistanceMetric {
+    /// Distance between two vectors as reported by search: lower is closer
+    ///
+    /// Cosine gives `1 - cos`, Euclidean `||a - b||` and dot product `-(a . b)`.
+    pub fn distance(&self, a: &[f32], b: &[f32]) -> f32 {
+        match self {
+            DistanceMetric::Euclidean => EuclideanDistance.eval(a, b),
+            DistanceMetric::Cosine => CosineDistance.eval(a, b),
+            DistanceMetric::DotProduct => -dot(a, b),
+        }
+    }
+
+    /// Similarity score for a reported distance: higher is closer
+    ///
+    /// Dot product scores are the product itself. Other metrics map through
+    /// `1 / (1 + distance)`, a monotonic similarity proxy in `(0, 1]`.
+    pub fn score(&self, distance: f32) -> f32 {
+        match self {
+            DistanceMetric::DotProduct => -distance,
+            _ => 1.0 / (1.0 + distance),
+        }
+    }
+}
+
+/// HNSW construction and search parameters
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct HnswConfig {
+    /// Max connections per node (layer 0 uses `2 * m`)
+    pub m: usize,
+    /// Candidate list size while inserting
+    pub ef_construction: usize,
+    /// Minimum candidate list size while searching; raised to twice the
+    /// number of results fetched when smaller
+    pub ef_search: usize,
+}
+
+impl Default for HnswConfig {
+    fn default() -> Self {
+        Self {
+            m: 16,
+            ef_construction: 200,
+            ef_search: 64,
+        }
+    }
 }
 
 /// A point in the vector space, associated with a NodeId
@@ -104,27 +180,100 @@ impl Distance<f32> for CosineDistance {
             return 1.0;
         }
         
-        // Cosine distance = 1.0 - cosine similarity
+        // Cosine distance = 1.0 - cosine similarity. Rounding can push the
+        // similarity of parallel vectors just above 1; hnsw_rs rejects negative distances.
         let sim = dot / (norm_a.sqrt() * norm_b.sqrt());
-        1.0 - sim
+        (1.0 - sim).max(0.0)
     }
 }
 
-/// Inner Product distance implementation for hnsw_rs
+/// Euclidean (L2) distance implementation for hnsw_rs
 #[derive(Clone, Copy, Debug, Default)]
-pub struct InnerProductDistance;
+pub struct EuclideanDistance;
 
-impl Distance<f32> for InnerProductDistance {
+impl Distance<f32> for EuclideanDistance {
     fn eval(&self, va: &[f32], vb: &[f32]) -> f32 {
-        let mut dot = 0.0;
-        for (a, b) in va.iter().zip(vb.iter()) {
-            dot += a * b;
+        va.iter()
+            .zip(vb.iter())
+            .map(|(a, b)| (a - b) * (a - b))
+            .sum::<f32>()
+            .sqrt()
+    }
+}
+
+/// Dot product distance implementation for hnsw_rs
+///
+/// Maps the product `p` to `1 - p` when `p <= 0` and `1 / (1 + p)` otherwise: positive,
+/// continuous and strictly decreasing, so larger products are nearer without the
+/// negative distances hnsw_rs rejects. Works for un-normalized vectors.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DotProductDistance;
+
+impl Distance<f32> for DotProductDistance {
+    fn eval(&self, va: &[f32], vb: &[f32]) -> f32 {
+        let p = dot(va, vb);
+        if p <= 0.0 {
+            1.0 - p
+        } else {
+            1.0 / (1.0 + p)
+        }
+    }
+}
+
+fn dot(a: &[f32], b: &[f32]) -> f32 {
+    a.iter().zip(b.iter()).map(|(x, y)| x * y).sum()
+}
+
+/// HNSW graph monomorphized for the index's distance metric
+enum HnswGraph {
+    Euclidean(Hnsw<'static, f32, EuclideanDistance>),
+    Cosine(Hnsw<'static, f32, CosineDistance>),
+    DotProduct(Hnsw<'static, f32, DotProductDistance>),
+}
+
+impl HnswGraph {
+    fn new(metric: DistanceMetric, config: &HnswConfig, max_elements: usize) -> Self {
+        let (m, ef_construction) = (config.m.max(1), config.ef_construction.max(1));
+        match metric {
+            DistanceMetric::Euclidean => {
+                HnswGraph::Euclidean(Hnsw::new(m, max_elements, 16, ef_construction, EuclideanDistance))
+            }
+            DistanceMetric::Cosine => {
+                HnswGraph::Cosine(Hnsw::new(m, max_elements, 16, ef_construction, CosineDistance))
+            }
+            DistanceMetric::DotProduct => {
+                HnswGraph::DotProduct(Hnsw::new(m, max_elements, 16, ef_construction, DotProductDistance))
+            }
+        }
+    }
+
+    fn insert(&self, vector: &Vec<f32>, slot: usize) {
+        match self {
+            HnswGraph::Euclidean(hnsw) => hnsw.insert((vector, slot)),
+            HnswGraph::Cosine(hnsw) => hnsw.insert((vector, slot)),
+            HnswGraph::DotProduct(hnsw) => hnsw.insert((vector, slot)),
+        }
+    }
+
+    fn search(&self, query: &[f32], k: usize, ef_search: usize) -> Vec<Neighbour> {
+        match self {
+            HnswGraph::Euclidean(hnsw) => hnsw.search(query, k, ef_search),
+            HnswGraph::Cosine(hnsw) => hnsw.search(query, k, ef_search),
+            HnswGraph::DotProduct(hnsw) => hnsw.search(query, k, ef_search),
         }
-        // Inner product distance = 1.0 - dot product (for normalized vectors)
-        1.0 - dot
     }
 }
 
+/// Leading bytes of a dumped vector index file, followed by the format version
+const DUMP_MAGIC: &[u8; 4] = b"SVIX";
+
+/// Vector index on-disk format version written by this build
+///
+/// Covers both the per-index dump files and the manager's `metadata.json`.
+/// Version 0 is the untagged layout from before versioning (a bare bincode
+/// vector list and a bare metadata array); it is still read.
+pub const VECTOR_FORMAT_VERSION: u64 = 1;
+
 /// Stored vector entry for persistence
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub struct StoredVector {
@@ -138,10 +287,17 @@ pub struct VectorIndex {
     dimensions: usize,
     /// Distance metric
     metric: DistanceMetric,
+    /// HNSW parameters
+    config: HnswConfig,
     /// The actual HNSW index
-    hnsw: Hnsw<'static, f32, CosineDistance>,
-    /// All inserted vectors (for persistence — HNSW doesn't expose iteration)
+    hnsw: HnswGraph,
+    /// Every vector inserted into the HNSW, indexed by slot (its HNSW data id).
+    /// Kept for persistence and exact search — HNSW doesn't expose iteration.
     stored_vectors: Vec<StoredVector>,
+    /// Slots of each node's vectors (a node may hold several, e.g. one per chunk)
+    slots: HashMap<u64, Vec<usize>>,
+    /// Slots of removed vectors, still present in the HNSW graph until compaction
+    tombstones: HashSet<usize>,
 }
 
 // Implement Debug manually because Hnsw doesn't implement it
@@ -150,25 +306,33 @@ impl std::fmt::Debug for VectorIndex {
         f.debug_struct("VectorIndex")
             .field("dimensions", &self.dimensions)
             .field("metric", &self.metric)
+            .field("config", &self.config)
+            .field("len", &self.len())
+            .field("tombstones", &self.tombstones.len())
             .finish()
     }
 }
 
 impl VectorIndex {
-    /// Create a new vector index
+    /// Create a new vector index with the default [`HnswConfig`]
     pub fn new(dimensions: usize, metric: DistanceMetric) -> Self {
-        // HNSW parameters
-        let max_elements = 100_000;
-        let m = 16;
-        let ef_construction = 200;
+        Self::with_config(dimensions, metric, HnswConfig::default())
+    }
 
-        let hnsw = Hnsw::new(m, max_elements, 16, ef_construction, CosineDistance);
+    /// Create a new vector index with explicit HNSW parameters
+    pub fn with_config(dimensions: usize, metric: DistanceMetric, config: HnswConfig) -> Self {
+        Self::with_capacity(dimensions, metric, config, 100_000)
+    }
 
+    fn with_capacity(dimensions: usize, metric: DistanceMetric, config: HnswConfig, max_elements: usize) -> Self {
         Self {
             dimensions,
             metric,
-            hnsw,
+            config,
+            hnsw: HnswGraph::new(metric, &config, max_elements),
             stored_vectors: Vec::new(),
+            slots: HashMap::new(),
+            tombstones: HashSet::new(),
         }
     }
 
@@ -180,20 +344,83 @@ impl VectorIndex {
                 got: vector.len(),
             });
         }
-        
-        self.hnsw.insert((vector, node_id.0 as usize));
+
+        let slot = self.stored_vectors.len();
+        self.hnsw.insert(vector, slot);
 
         // Store vector for persistence
         self.stored_vectors.push(StoredVector {
             node_id: node_id.0,
             vector: vector.clone(),
         });
+        self.slots.entry(node_id.0).or_default().push(slot);
 
         Ok(())
     }
 
-    /// Search for nearest neighbors
+    /// Remove all vectors of a node from the index
+    ///
+    /// The vectors are tombstoned and filtered out of search results; the HNSW
+    /// graph is rebuilt once tombstones reach half of it. Returns whether the
+    /// node had any vectors.
+    pub fn remove(&mut self, node_id: NodeId) -> bool {
+        let Some(slots) = self.slots.remove(&node_id.0) else {
+            return false;
+        };
+        self.tombstones.extend(slots);
+        if self.tombstones.len() * 2 >= self.stored_vectors.len() {
+            self.compact();
+        }
+        true
+    }
+
+    /// Rebuild the HNSW graph from live vectors, dropping tombstoned points
+    pub fn compact(&mut self) {
+        if self.tombstones.is_empty() {
+            return;
+        }
+        *self = self.rebuilt();
+    }
+
+    /// A fresh index holding this one's live vectors
+    fn rebuilt(&self) -> Self {
+        let live: Vec<&StoredVector> = self.live_vectors().collect();
+        let max_elements = (live.len() + 10_000).max(100_000);
+        let mut rebuilt = Self::with_capacity(self.dimensions, self.metric, self.config, max_elements);
+        for sv in live {
+            let _ = rebuilt.add(NodeId::new(sv.node_id), &sv.vector);
+        }
+        rebuilt
+    }
+
+    /// Number of removed vectors awaiting compaction
+    pub fn tombstone_count(&self) -> usize {
+        self.tombstones.len()
+    }
+
+    fn live_vectors(&self) -> impl Iterator<Item = &StoredVector> {
+        self.stored_vectors
+            .iter()
+            .enumerate()
+            .filter(|(slot, _)| !self.tombstones.contains(slot))
+            .map(|(_, sv)| sv)
+    }
+
+    /// Search for nearest neighbors using the index's `ef_search`
     pub fn search(&self, query: &[f32], k: usize) -> VectorResult<Vec<(NodeId, f32)>> {
+        self.search_with_ef(query, k, None)
+    }
+
+    /// Search for nearest neighbors, optionally overriding `ef_search` for this query
+    ///
+    /// A larger `ef_search` explores more of the graph: higher recall, slower query.
+    /// A node holding several vectors is returned once, at its closest vector.
+    pub fn search_with_ef(
+        &self,
+        query: &[f32],
+        k: usize,
+        ef_search: Option<usize>,
+    ) -> VectorResult<Vec<(NodeId, f32)>> {
         if query.len() != self.dimensions {
             return Err(VectorError::DimensionMismatch {
                 expected: self.dimensions,
@@ -202,22 +429,36 @@ impl VectorIndex {
         }
         
         // ef_search drives recall (HNSW only returns from the ef candidate set), so
-        // floor it for small k. But it must NOT exceed the number of indexed vectors
+        // floor it for small k: twice the fetch size, or the index's ef_search if
+        // larger; an explicit per-query ef_search is used as given (but at least the
+        // fetch size). It must NOT exceed the number of indexed vectors
         // or hnsw_rs panics in search_layer (hnsw_rs 0.2.1 hnsw.rs:938); clamp both ef
         // and k into the index size. An empty index returns no neighbours rather than
-        // searching a malformed graph.
+        // searching a malformed graph. Tombstoned points are still in the graph, and
+        // a node's extra vectors collapse into its best hit, so over-fetch by both
+        // counts to keep k distinct live nodes.
         let n = self.stored_vectors.len();
-        if n == 0 {
+        if self.is_empty() {
             return Ok(Vec::new());
         }
-        let ef_search = (k * 2).max(64).min(n);
+        let fetch = (k + self.tombstones.len() + self.len() - self.node_count()).min(n);
+        let ef_search = match ef_search {
+            Some(ef) => ef.max(fetch),
+            None => (fetch * 2).max(self.config.ef_search),
+        };
+        // A candidate list as large as the index asks for an exhaustive search.
+        // hnsw_rs 0.2.1 can still miss points of such small graphs (e.g. a vector
+        // re-added next to its own tombstone), so scan them exactly instead.
+        if ef_search >= n {
+            return Ok(self.brute_force_search(query, k));
+        }
         // hnsw_rs 0.2.1 can panic deep in search_layer (hnsw.rs:938,
         // `return_points.peek().unwrap()`) on certain graphs. A panic here would
         // unwind across the await point and take the whole server down, so a single
         // HTTP search must never be able to crash the process — contain it and
         // surface a clean error instead.
         let results = match std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
-            self.hnsw.search(query, k.min(n), ef_search)
+            self.hnsw.search(query, fetch, ef_search)
         })) {
             Ok(r) => r,
             Err(_) => {
@@ -228,29 +469,106 @@ impl VectorIndex {
                     "[vector] HNSW search panicked on {}-vector index; using exact brute-force fallback",
                     n
                 );
-                return Ok(self.brute_force_search(query, k.min(n)));
+                return Ok(self.brute_force_search(query, k));
             }
         };
 
-        let mut neighbors = Vec::new();
-        for res in results {
-            neighbors.push((NodeId::new(res.d_id as u64), res.distance));
+        let mut seen = HashSet::new();
+        let neighbors: Vec<(NodeId, f32)> = results
+            .into_iter()
+            .filter(|res| !self.tombstones.contains(&res.d_id))
+            .filter(|res| seen.insert(self.stored_vectors[res.d_id].node_id))
+            .map(|res| {
+                // Report the metric's own distance rather than the graph's internal one
+                let stored = &self.stored_vectors[res.d_id];
+                (NodeId::new(stored.node_id), self.metric.distance(query, &stored.vector))
+            })
+            .take(k)
+            .collect();
+
+        // hnsw_rs 0.2.1 occasionally returns nothing from a graph of one or a few
+        // points, depending on the random levels they were inserted at. Fewer
+        // results than live nodes cannot be right, so answer exactly instead.
+        if neighbors.len() < k.min(self.node_count()) {
+            return Ok(self.brute_force_search(query, k));
         }
-        
         Ok(neighbors)
     }
 
+    /// Search for the nearest neighbours whose node passes `accept`
+    ///
+    /// Over-fetches from the HNSW, growing the candidate set fourfold until k
+    /// accepted neighbours are found. If even the whole graph yields fewer (a very
+    /// selective filter), falls back to an exact scan of the accepted vectors so
+    /// that up to k matches are still returned.
+    pub fn search_filtered(
+        &self,
+        query: &[f32],
+        k: usize,
+        accept: impl Fn(NodeId) -> bool,
+    ) -> VectorResult<Vec<(NodeId, f32)>> {
+        if query.len() != self.dimensions {
+            return Err(VectorError::DimensionMismatch {
+                expected: self.dimensions,
+                got: query.len(),
+            });
+        }
+        let n = self.node_count();
+        if n == 0 || k == 0 {
+            return Ok(Vec::new());
+        }
+
+        let mut fetch = (k * 4).min(n);
+        loop {
+            let accepted: Vec<(NodeId, f32)> = self
+                .search(query, fetch)?
+                .into_iter()
+                .filter(|(id, _)| accept(*id))
+                .take(k)
+                .collect();
+            if accepted.len() == k {
+                return Ok(accepted);
+            }
+            if fetch >= n {
+                break;
+            }
+            fetch = (fetch * 4).min(n);
+        }
+        Ok(self.brute_force_search_where(query, k, accept))
+    }
+
+    /// Exact k-nearest-neighbour search by linear scan over all stored vectors
+    ///
+    /// O(n·d), so meant as ground truth for evaluating the approximate
+    /// [`search`](Self::search) (see [`recall_at_k`]) rather than for serving.
+    pub fn exact_search(&self, query: &[f32], k: usize) -> VectorResult<Vec<(NodeId, f32)>> {
+        if query.len() != self.dimensions {
+            return Err(VectorError::DimensionMismatch {
+                expected: self.dimensions,
+                got: query.len(),
+            });
+        }
+        Ok(self.brute_force_search(query, k))
+    }
+
     /// Exact nearest-neighbour search by linear scan over stored vectors.
-    /// Used as a fallback when the HNSW index search panics. The index uses
-    /// cosine distance, so this matches it; non-finite distances are skipped.
+    /// Used as a fallback when the HNSW index search panics. Scores with the
+    /// index's metric; non-finite distances are skipped.
     fn brute_force_search(&self, query: &[f32], k: usize) -> Vec<(NodeId, f32)> {
+        self.brute_force_search_where(query, k, |_| true)
+    }
+
+    fn brute_force_search_where(&self, query: &[f32], k: usize, accept: impl Fn(NodeId) -> bool) -> Vec<(NodeId, f32)> {
         let mut scored: Vec<(NodeId, f32)> = self
-            .stored_vectors
-            .iter()
-            .map(|sv| (NodeId::new(sv.node_id), CosineDistance.eval(query, &sv.vector)))
+            .live_vectors()
+            .filter(|sv| accept(NodeId::new(sv.node_id)))
+            .map(|sv| (NodeId::new(sv.node_id), self.metric.distance(query, &sv.vector)))
             .filter(|(_, d)| d.is_finite())
             .collect();
         scored.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal));
+        // One hit per node: its closest vector
+        let mut seen = HashSet::new();
+        scored.retain(|(id, _)| seen.insert(*id));
         scored.truncate(k);
         scored
     }
@@ -265,57 +583,104 @@ impl VectorIndex {
         self.metric
     }
 
-    /// Get count of stored vectors
+    /// Get HNSW parameters
+    pub fn config(&self) -> HnswConfig {
+        self.config
+    }
+
+    /// Get count of stored (non-removed) vectors
     pub fn len(&self) -> usize {
-        self.stored_vectors.len()
+        self.stored_vectors.len() - self.tombstones.len()
     }
 
     /// Check if index is empty
     pub fn is_empty(&self) -> bool {
-        self.stored_vectors.is_empty()
+        self.len() == 0
     }
 
-    /// Save index to disk by serializing stored vectors via bincode.
+    /// Number of nodes with at least one stored vector
+    fn node_count(&self) -> usize {
+        self.slots.len()
+    }
+
+    /// Save index to disk: a `SVIX` magic and [`VECTOR_FORMAT_VERSION`]
+    /// header, then the stored vectors via bincode.
     /// On load, vectors are re-inserted into a fresh HNSW index.
     pub fn dump(&self, path: &std::path::Path) -> VectorResult<()> {
+        use std::io::Write;
+
         let file = std::fs::File::create(path)?;
-        let writer = std::io::BufWriter::new(file);
-        bincode::serialize_into(writer, &self.stored_vectors)
+        let mut writer = std::io::BufWriter::new(file);
+        writer.write_all(DUMP_MAGIC)?;
+        writer.write_all(&(VECTOR_FORMAT_VERSION as u32).to_le_bytes())?;
+        let live: Vec<&StoredVector> = self.live_vectors().collect();
+        bincode::serialize_into(&mut writer, &live)
             .map_err(|e| VectorError::IndexError(format!("serialization error: {}", e)))?;
+        writer.flush()?;
         Ok(())
     }
 
     /// Load index from disk: deserialize stored vectors and re-insert into HNSW.
+    ///
+    /// Files without a header are read as version 0; a newer version than
+    /// this build understands is rejected instead of being misread.
     pub fn load(
         path: &std::path::Path,
         dimensions: usize,
         metric: DistanceMetric,
+    ) -> VectorResult<Self> {
+        Self::load_with_config(path, dimensions, metric, HnswConfig::default())
+    }
+
+    /// [`load`](Self::load) into an index with explicit HNSW parameters
+    pub fn load_with_config(
+        path: &std::path::Path,
+        dimensions: usize,
+        metric: DistanceMetric,
+        config: HnswConfig,
     ) -> VectorResult<Self> {
         if !path.exists() {
-            return Ok(Self::new(dimensions, metric));
+            return Ok(Self::with_config(dimensions, metric, config));
+        }
+        let bytes = std::fs::read(path)?;
+        let (version, body) = match bytes.strip_prefix(DUMP_MAGIC) {
+            Some(rest) if rest.len() >= 4 => {
+                let version = u32::from_le_bytes([rest[0], rest[1], rest[2], rest[3]]);
+                (version as u64, &rest[4..])
+            }
+            Some(_) => return Err(VectorError::IndexError("truncated vector index header".to_string())),
+            None => (0, bytes.as_slice()),
+        };
+        // Versions 0 and 1 share the body layout
+        if version > VECTOR_FORMAT_VERSION {
+            return Err(VectorError::UnsupportedFormatVersion { found: version, supported: VECTOR_FORMAT_VERSION });
         }
-        let file = std::fs::File::open(path)?;
-        let reader = std::io::BufReader::new(file);
-        let stored_vectors: Vec<StoredVector> = bincode::deserialize_from(reader)
+        let stored_vectors: Vec<StoredVector> = bincode::deserialize(body)
             .map_err(|e| VectorError::IndexError(format!("deserialization error: {}", e)))?;
 
         let max_elements = (stored_vectors.len() + 10_000).max(100_000);
-        let m = 16;
-        let ef_construction = 200;
-        let mut hnsw = Hnsw::new(m, max_elements, 16, ef_construction, CosineDistance);
+        let mut index = Self::with_capacity(dimensions, metric, config, max_elements);
 
         // Re-insert all vectors
-        for sv in &stored_vectors {
-            hnsw.insert((&sv.vector, sv.node_id as usize));
+        for sv in stored_vectors {
+            let _ = index.add(NodeId::new(sv.node_id), &sv.vector);
         }
 
-        Ok(Self {
-            dimensions,
-            metric,
-            hnsw,
-            stored_vectors,
-        })
+        Ok(index)
+    }
+}
+
+/// Fraction of the exact top-k neighbours that an approximate search returned
+///
+/// Compares node ids only, so ties in distance don't count against the
+/// approximate result. An empty `exact` list gives a recall of 1.0.
+pub fn recall_at_k(exact: &[(NodeId, f32)], approximate: &[(NodeId, f32)]) -> f64 {
+    if exact.is_empty() {
+        return 1.0;
     }
+    let found: HashSet<NodeId> = approximate.iter().map(|(id, _)| *id).collect();
+    let hits = exact.iter().filter(|(id, _)| found.contains(id)).count();
+    hits as f64 / exact.len() as f64
 }
 
 #[cfg(test)]
@@ -363,6 +728,34 @@ mod tests {
         assert_eq!(results[0].0, NodeId::new(1));
     }
 
+    #[test]
+    fn test_dump_format_versions() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("versioned.bin");
+        let load = |path: &std::path::Path| VectorIndex::load(path, 2, DistanceMetric::Cosine);
+
+        let index = grid_index();
+        index.dump(&path).unwrap();
+        let bytes = std::fs::read(&path).unwrap();
+        assert_eq!(&bytes[..4], DUMP_MAGIC);
+        assert_eq!(u32::from_le_bytes([bytes[4], bytes[5], bytes[6], bytes[7]]) as u64, VECTOR_FORMAT_VERSION);
+        assert_eq!(load(&path).unwrap().len(), 20);
+
+        // A dump from before versioning has no header and still loads
+        let legacy: Vec<&StoredVector> = index.live_vectors().collect();
+        std::fs::write(&path, bincode::serialize(&legacy).unwrap()).unwrap();
+        assert_eq!(load(&path).unwrap().len(), 20);
+
+        // A version from a newer build is refused
+        let mut future = bytes.clone();
+        future[4..8].copy_from_slice(&99u32.to_le_bytes());
+        std::fs::write(&path, future).unwrap();
+        assert!(matches!(
+            load(&path),
+            Err(VectorError::UnsupportedFormatVersion { found: 99, supported: VECTOR_FORMAT_VERSION })
+        ));
+    }
+
     #[test]
     fn test_distance_metrics() {
         let v1 = vec![1.0, 0.0];
@@ -375,8 +768,250 @@ mod tests {
         // Same
         assert!((cosine.eval(&v1, &v1) - 0.0).abs() < 1e-6);
         
-        let inner = InnerProductDistance;
+        let inner = DotProductDistance;
         // Dot product = 0
         assert!((inner.eval(&v1, &v2) - 1.0).abs() < 1e-6); // 1.0 - 0.0
+        // Larger products are nearer, and the distance stays positive
+        assert!(inner.eval(&v3, &v3) < inner.eval(&v1, &v3));
+        assert!(inner.eval(&v3, &v3) > 0.0);
+
+        let euclidean = EuclideanDistance;
+        assert!((euclidean.eval(&v1, &v2) - 2.0_f32.sqrt()).abs() < 1e-6);
+        assert!((euclidean.eval(&v1, &v3) - 1.0).abs() < 1e-6);
+
+        assert!((DistanceMetric::DotProduct.distance(&v3, &[2.0, 3.0]) + 5.0).abs() < 1e-6);
+    }
+
+    /// Index of vectors chosen so each metric ranks them differently for query [1, 0]
+    fn metric_index(metric: DistanceMetric) -> VectorIndex {
+        let mut index = VectorIndex::new(2, metric);
+        index.add(NodeId::new(1), &vec![1.0, 0.0]).unwrap(); // exact match
+        index.add(NodeId::new(2), &vec![10.0, 0.0]).unwrap(); // same direction, far away
+        index.add(NodeId::new(3), &vec![3.0, 3.0]).unwrap(); // 45°, dot 3
+        index.add(NodeId::new(4), &vec![-1.0, 0.0]).unwrap(); // opposite
+        index
+    }
+
+    fn ranking(results: &[(NodeId, f32)]) -> Vec<u64> {
+        assert!(results.windows(2).all(|w| w[0].1 <= w[1].1), "{:?} not ascending", results);
+        results.iter().map(|(id, _)| id.as_u64()).collect()
+    }
+
+    // HNSW may not reach every point of a four-node graph, so full orderings are
+    // checked on exact search and the approximate search only for its best hit.
+
+    #[test]
+    fn test_euclidean_ranking() {
+        let index = metric_index(DistanceMetric::Euclidean);
+        let exact = index.exact_search(&[1.0, 0.0], 4).unwrap();
+        assert_eq!(ranking(&exact), vec![1, 4, 3, 2]);
+        assert!(exact[0].1.abs() < 1e-6);
+        assert!((exact[1].1 - 2.0).abs() < 1e-6);
+        assert!((exact[3].1 - 9.0).abs() < 1e-6);
+
+        let results = index.search(&[1.0, 0.0], 4).unwrap();
+        assert_eq!(ranking(&results)[0], 1);
+    }
+
+    #[test]
+    fn test_dot_product_ranking() {
+        let index = metric_index(DistanceMetric::DotProduct);
+        let exact = index.exact_search(&[1.0, 0.0], 4).unwrap();
+        assert_eq!(ranking(&exact), vec![2, 3, 1, 4]);
+        // Reported distance is the negated dot product
+        assert!((exact[0].1 + 10.0).abs() < 1e-6);
+        assert!((exact[3].1 - 1.0).abs() < 1e-6);
+
+        let results = index.search(&[1.0, 0.0], 4).unwrap();
+        assert_eq!(ranking(&results)[0], 2);
+        assert!((results[0].1 + 10.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_cosine_ranking() {
+        let index = metric_index(DistanceMetric::Cosine);
+        let exact = index.exact_search(&[1.0, 0.0], 4).unwrap();
+        // 1 and 2 point the same way and tie at distance 0
+        let mut top = ranking(&exact)[..2].to_vec();
+        top.sort();
+        assert_eq!(top, vec![1, 2]);
+        assert_eq!(ranking(&exact)[2..], [3, 4]);
+        assert!((exact[3].1 - 2.0).abs() < 1e-6);
+
+        let results = index.search(&[1.0, 0.0], 4).unwrap();
+        assert!(results[0].1.abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_legacy_metric_names_deserialize() {
+        let l2: DistanceMetric = serde_json::from_str("\"L2\"").unwrap();
+        let inner: DistanceMetric = serde_json::from_str("\"InnerProduct\"").unwrap();
+        assert_eq!(l2, DistanceMetric::Euclidean);
+        assert_eq!(inner, DistanceMetric::DotProduct);
+    }
+
+    #[test]
+    fn test_score_is_higher_for_closer_vectors() {
+        assert_eq!(DistanceMetric::Euclidean.score(0.0), 1.0);
+        assert!(DistanceMetric::Cosine.score(0.1) > DistanceMetric::Cosine.score(0.5));
+        let distance = DistanceMetric::DotProduct.distance(&[1.0, 2.0], &[3.0, 1.0]);
+        assert_eq!(DistanceMetric::DotProduct.score(distance), 5.0);
+    }
+
+    fn grid_index() -> VectorIndex {
+        let mut index = VectorIndex::new(2, DistanceMetric::Cosine);
+        for i in 0..20u64 {
+            let angle = i as f32 * 0.07;
+            index.add(NodeId::new(i), &vec![angle.cos(), angle.sin()]).unwrap();
+        }
+        index
+    }
+
+    #[test]
+    fn test_remove_filters_deleted_nodes() {
+        let mut index = grid_index();
+        assert_eq!(index.search(&[1.0, 0.0], 1).unwrap()[0].0, NodeId::new(0));
+
+        assert!(index.remove(NodeId::new(0)));
+        assert!(!index.remove(NodeId::new(0)));
+        assert_eq!(index.len(), 19);
+        assert_eq!(index.tombstone_count(), 1);
+
+        let results = index.search(&[1.0, 0.0], 5).unwrap();
+        assert_eq!(results.len(), 5);
+        assert!(results.iter().all(|(id, _)| *id != NodeId::new(0)));
+        assert_eq!(results[0].0, NodeId::new(1));
+
+        // Re-ingesting the node makes it searchable again
+        index.add(NodeId::new(0), &vec![1.0, 0.0]).unwrap();
+        assert_eq!(index.search(&[1.0, 0.0], 1).unwrap()[0].0, NodeId::new(0));
+    }
+
+    #[test]
+    fn test_remove_compacts_graph() {
+        let mut index = grid_index();
+        for i in 0..9u64 {
+            index.remove(NodeId::new(i));
+        }
+        assert_eq!(index.tombstone_count(), 9);
+
+        // The tenth removal reaches half the graph and triggers a rebuild
+        index.remove(NodeId::new(9));
+        assert_eq!(index.tombstone_count(), 0);
+        assert_eq!(index.len(), 10);
+
+        let results = index.search(&[1.0, 0.0], 3).unwrap();
+        let ids: Vec<NodeId> = results.iter().map(|(id, _)| *id).collect();
+        assert_eq!(ids, vec![NodeId::new(10), NodeId::new(11), NodeId::new(12)]);
+    }
+
+    #[test]
+    fn test_dump_skips_removed_vectors() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let dump_path = dir.path().join("removed.bin");
+
+        let mut index = grid_index();
+        index.remove(NodeId::new(3));
+        index.dump(&dump_path).unwrap();
+
+        let loaded = VectorIndex::load(&dump_path, 2, DistanceMetric::Cosine).unwrap();
+        assert_eq!(loaded.len(), 19);
+        assert_eq!(loaded.tombstone_count(), 0);
+        let results = loaded.search(&[0.21_f32.cos(), 0.21_f32.sin()], 3).unwrap();
+        assert!(results.iter().all(|(id, _)| *id != NodeId::new(3)));
+    }
+
+    #[test]
+    fn test_search_returns_each_node_once() {
+        let mut index = grid_index();
+        // Node 0 gains two more vectors next to its first one
+        index.add(NodeId::new(0), &vec![0.03_f32.cos(), 0.03_f32.sin()]).unwrap();
+        index.add(NodeId::new(0), &vec![0.01_f32.cos(), 0.01_f32.sin()]).unwrap();
+
+        let results = index.search(&[1.0, 0.0], 3).unwrap();
+        let ids: Vec<u64> = results.iter().map(|(id, _)| id.as_u64()).collect();
+        assert_eq!(ids, vec![0, 1, 2]);
+        assert!(results[0].1 < 1e-6);
+
+        let exact = index.exact_search(&[1.0, 0.0], 3).unwrap();
+        assert_eq!(exact, results);
+    }
+
+    #[test]
+    fn test_search_filtered_keeps_top_k() {
+        let index = grid_index();
+        // Only odd node ids pass; the unfiltered nearest (0) is excluded
+        let results = index.search_filtered(&[1.0, 0.0], 3, |id| id.as_u64() % 2 == 1).unwrap();
+        let ids: Vec<u64> = results.iter().map(|(id, _)| id.as_u64()).collect();
+        assert_eq!(ids, vec![1, 3, 5]);
+
+        // A filter matching a single far-away node still finds it
+        let results = index.search_filtered(&[1.0, 0.0], 3, |id| id.as_u64() == 19).unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].0, NodeId::new(19));
+    }
+
+    #[test]
+    fn test_exact_search_and_recall() {
+        use rand::{Rng, SeedableRng};
+
+        let mut rng = rand::rngs::StdRng::seed_from_u64(42);
+        let mut random_vector = || (0..16).map(|_| rng.gen_range(-1.0..1.0)).collect::<Vec<f32>>();
+
+        let mut index = VectorIndex::new(16, DistanceMetric::Cosine);
+        for i in 0..500u64 {
+            index.add(NodeId::new(i), &random_vector()).unwrap();
+        }
+
+        let mut total = 0.0;
+        let queries = 20;
+        for _ in 0..queries {
+            let query = random_vector();
+            let exact = index.exact_search(&query, 10).unwrap();
+            assert_eq!(exact.len(), 10);
+            assert!(exact.windows(2).all(|w| w[0].1 <= w[1].1));
+
+            let approximate = index.search(&query, 10).unwrap();
+            total += recall_at_k(&exact, &approximate);
+        }
+        let recall = total / queries as f64;
+        assert!(recall >= 0.9, "recall@10 = {}", recall);
+
+        assert!(matches!(
+            index.exact_search(&[1.0], 10),
+            Err(VectorError::DimensionMismatch { expected: 16, got: 1 })
+        ));
+    }
+
+    #[test]
+    fn test_higher_ef_search_improves_recall() {
+        use rand::{Rng, SeedableRng};
+
+        let mut rng = rand::rngs::StdRng::seed_from_u64(7);
+        let mut random_vector = || (0..32).map(|_| rng.gen_range(-1.0..1.0)).collect::<Vec<f32>>();
+
+        // A sparse graph over uniform high-dimensional data makes greedy search lossy
+        let config = HnswConfig { m: 4, ef_construction: 16, ef_search: 10 };
+        let mut index = VectorIndex::with_config(32, DistanceMetric::Cosine, config);
+        assert_eq!(index.config(), config);
+        for i in 0..2000u64 {
+            index.add(NodeId::new(i), &random_vector()).unwrap();
+        }
+
+        let (mut narrow, mut wide) = (0.0, 0.0);
+        for _ in 0..20 {
+            let query = random_vector();
+            let exact = index.exact_search(&query, 10).unwrap();
+            narrow += recall_at_k(&exact, &index.search(&query, 10).unwrap());
+            wide += recall_at_k(&exact, &index.search_with_ef(&query, 10, Some(400)).unwrap());
+        }
+        assert!(wide > narrow, "ef=400 recall {} <= ef=10 recall {}", wide / 20.0, narrow / 20.0);
+    }
+
+    #[test]
+    fn test_recall_at_k() {
+        let ids = |ids: &[u64]| ids.iter().map(|&i| (NodeId::new(i), 0.0)).collect::<Vec<_>>();
+        assert_eq!(recall_at_k(&ids(&[1, 2, 3, 4]), &ids(&[4, 2, 9, 8])), 0.5);
+        assert_eq!(recall_at_k(&ids(&[]), &ids(&[1])), 1.0);
     }
-}
\ No newline at end of file
+}
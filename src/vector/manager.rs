@@ -3,7 +3,7 @@
 //! Handles indexing for different node labels and property keys.
 
 use crate::graph::NodeId;
-use crate::vector::index::{VectorIndex, DistanceMetric, VectorResult};
+use crate::vector::index::{VectorIndex, DistanceMetric, HnswConfig, VectorError, VectorResult, VECTOR_FORMAT_VERSION};
 use std::collections::HashMap;
 use std::sync::{Arc, RwLock};
 
@@ -28,20 +28,32 @@ impl VectorIndexManager {
         }
     }
 
-    /// Create a new index
+    /// Create a new index with the default HNSW parameters
     pub fn create_index(
         &self,
         label: &str,
         property_key: &str,
         dimensions: usize,
         metric: DistanceMetric,
+    ) -> VectorResult<()> {
+        self.create_index_with_config(label, property_key, dimensions, metric, HnswConfig::default())
+    }
+
+    /// Create a new index with explicit HNSW parameters
+    pub fn create_index_with_config(
+        &self,
+        label: &str,
+        property_key: &str,
+        dimensions: usize,
+        metric: DistanceMetric,
+        config: HnswConfig,
     ) -> VectorResult<()> {
         let key = IndexKey {
             label: label.to_string(),
             property_key: property_key.to_string(),
         };
         
-        let index = VectorIndex::new(dimensions, metric);
+        let index = VectorIndex::with_config(dimensions, metric, config);
         let mut indices = self.indices.write().unwrap();
         indices.insert(key, Arc::new(RwLock::new(index)));
         
@@ -74,6 +86,34 @@ impl VectorIndexManager {
         Ok(())
     }
 
+    /// Remove a node's vectors from an index
+    ///
+    /// Returns whether the index held any vectors for the node.
+    pub fn remove_vector(&self, label: &str, property_key: &str, node_id: NodeId) -> bool {
+        match self.get_index(label, property_key) {
+            Some(index_lock) => index_lock.write().unwrap().remove(node_id),
+            None => false,
+        }
+    }
+
+    /// Remove a node's vectors from every index on `label`
+    ///
+    /// Called on node deletion, where vectors may have come from auto-embedded
+    /// text properties as well as explicit vector properties.
+    pub fn remove_node(&self, label: &str, node_id: NodeId) {
+        let indices: Vec<Arc<RwLock<VectorIndex>>> = {
+            let indices = self.indices.read().unwrap();
+            indices
+                .iter()
+                .filter(|(key, _)| key.label == label)
+                .map(|(_, index)| Arc::clone(index))
+                .collect()
+        };
+        for index_lock in indices {
+            index_lock.write().unwrap().remove(node_id);
+        }
+    }
+
     /// Search an index
     pub fn search(
         &self,
@@ -81,10 +121,53 @@ impl VectorIndexManager {
         property_key: &str,
         query: &[f32],
         k: usize,
+    ) -> VectorResult<Vec<(NodeId, f32)>> {
+        self.search_with_ef(label, property_key, query, k, None)
+    }
+
+    /// Search an index, optionally overriding its `ef_search` for this query
+    pub fn search_with_ef(
+        &self,
+        label: &str,
+        property_key: &str,
+        query: &[f32],
+        k: usize,
+        ef_search: Option<usize>,
     ) -> VectorResult<Vec<(NodeId, f32)>> {
         if let Some(index_lock) = self.get_index(label, property_key) {
             let index = index_lock.read().unwrap();
-            return index.search(query, k);
+            return index.search_with_ef(query, k, ef_search);
+        }
+        Ok(Vec::new())
+    }
+
+    /// Exact (brute-force) k-NN search on an index, for ground truth
+    pub fn exact_search(
+        &self,
+        label: &str,
+        property_key: &str,
+        query: &[f32],
+        k: usize,
+    ) -> VectorResult<Vec<(NodeId, f32)>> {
+        if let Some(index_lock) = self.get_index(label, property_key) {
+            let index = index_lock.read().unwrap();
+            return index.exact_search(query, k);
+        }
+        Ok(Vec::new())
+    }
+
+    /// Search an index, keeping only neighbours whose node passes `accept`
+    pub fn search_filtered(
+        &self,
+        label: &str,
+        property_key: &str,
+        query: &[f32],
+        k: usize,
+        accept: impl Fn(NodeId) -> bool,
+    ) -> VectorResult<Vec<(NodeId, f32)>> {
+        if let Some(index_lock) = self.get_index(label, property_key) {
+            let index = index_lock.read().unwrap();
+            return index.search_filtered(query, k, accept);
         }
         Ok(Vec::new())
     }
@@ -112,13 +195,13 @@ impl VectorIndexManager {
             label: label.to_string(),
             property_key: property_key.to_string(),
         };
-        // Read dims + metric under a short read lock, then release before building.
-        let (dims, metric) = {
+        // Read dims + metric + config under a short read lock, then release before building.
+        let (dims, metric, config) = {
             let indices = self.indices.read().unwrap();
             match indices.get(&key) {
                 Some(idx_lock) => {
                     let idx = idx_lock.read().unwrap();
-                    (idx.dimensions(), idx.metric())
+                    (idx.dimensions(), idx.metric(), idx.config())
                 }
                 None => return Ok(()), // no index registered for this key — nothing to do
             }
@@ -127,7 +210,7 @@ impl VectorIndexManager {
         // Skip individual vectors that don't match the index dimension rather than
         // aborting the whole rebuild — a single malformed embedding must not leave the
         // entire index empty (which then returns 0 results / panics on search).
-        let mut new_index = VectorIndex::new(dims, metric);
+        let mut new_index = VectorIndex::with_config(dims, metric, config);
         let mut skipped = 0usize;
         for (node_id, vec) in vectors {
             if new_index.add(*node_id, vec).is_err() {
@@ -169,12 +252,17 @@ impl VectorIndexManager {
                 "property_key": key.property_key,
                 "dimensions": index.dimensions(),
                 "metric": index.metric(),
+                "hnsw": index.config(),
                 "filename": index_filename,
             }));
         }
 
         let metadata_path = path.join("metadata.json");
         let metadata_file = std::fs::File::create(metadata_path)?;
+        let metadata = serde_json::json!({
+            "format_version": VECTOR_FORMAT_VERSION,
+            "indices": metadata,
+        });
         serde_json::to_writer_pretty(metadata_file, &metadata)
             .map_err(|e| crate::vector::VectorError::IndexError(e.to_string()))?;
 
@@ -193,8 +281,26 @@ impl VectorIndexManager {
         }
 
         let metadata_file = std::fs::File::open(metadata_path)?;
-        let metadata: Vec<serde_json::Value> = serde_json::from_reader(metadata_file)
+        let metadata: serde_json::Value = serde_json::from_reader(metadata_file)
             .map_err(|e| crate::vector::VectorError::IndexError(e.to_string()))?;
+        // Version 0 metadata was a bare array of index entries
+        let metadata = match metadata {
+            serde_json::Value::Array(items) => items,
+            serde_json::Value::Object(mut root) => {
+                let version = root.get("format_version").and_then(|v| v.as_u64()).unwrap_or(0);
+                if version > VECTOR_FORMAT_VERSION {
+                    return Err(VectorError::UnsupportedFormatVersion {
+                        found: version,
+                        supported: VECTOR_FORMAT_VERSION,
+                    });
+                }
+                match root.remove("indices") {
+                    Some(serde_json::Value::Array(items)) => items,
+                    _ => return Err(VectorError::IndexError("vector metadata has no indices list".to_string())),
+                }
+            }
+            _ => return Err(VectorError::IndexError("malformed vector metadata".to_string())),
+        };
 
         let mut indices = self.indices.write().unwrap();
         for item in metadata {
@@ -203,10 +309,16 @@ impl VectorIndexManager {
             let dimensions = item["dimensions"].as_u64().unwrap() as usize;
             let metric: DistanceMetric = serde_json::from_value(item["metric"].clone())
                 .map_err(|e| crate::vector::VectorError::IndexError(e.to_string()))?;
+            // Indices dumped before HNSW parameters were configurable used the defaults
+            let config: HnswConfig = match item.get("hnsw") {
+                Some(hnsw) => serde_json::from_value(hnsw.clone())
+                    .map_err(|e| crate::vector::VectorError::IndexError(e.to_string()))?,
+                None => HnswConfig::default(),
+            };
             let filename = item["filename"].as_str().unwrap();
 
             let index_path = path.join(filename);
-            let index = VectorIndex::load(&index_path, dimensions, metric)?;
+            let index = VectorIndex::load_with_config(&index_path, dimensions, metric, config)?;
             
             let key = IndexKey {
                 label: label.to_string(),
@@ -29,11 +29,13 @@
 //!
 //! ## Distance metrics
 //!
-//! - **L2 (Euclidean)**: straight-line distance in space. Good for dense, normalized vectors.
+//! - **Euclidean (L2)**: straight-line distance in space. Suits un-normalized embeddings
+//!   where magnitude matters.
 //! - **Cosine**: measures the angle between vectors (direction only, ignores magnitude).
 //!   `distance = 1 - cos(theta)`. Most common for text embeddings.
-//! - **Inner Product (dot product)**: combines magnitude and direction. Used when vector
-//!   norms carry meaning (e.g., popularity-weighted embeddings).
+//! - **Dot product (inner product)**: combines magnitude and direction. Used when vector
+//!   norms carry meaning (e.g., popularity-weighted embeddings). Larger products rank
+//!   first; results report the negated product as the distance.
 //!
 //! ## Integration with graph queries
 //!
@@ -44,9 +46,13 @@
 //! RETURN node.name, score
 //! ```
 //! This enables hybrid queries combining graph traversal with semantic similarity.
+//! `GraphStore::vector_search_filtered` additionally restricts candidates by a
+//! [`VectorFilter`] on node properties while keeping the top-k guarantee.
 
+pub mod filter;
 pub mod index;
 pub mod manager;
 
-pub use index::{VectorIndex, DistanceMetric, VectorError, VectorResult};
+pub use filter::VectorFilter;
+pub use index::{recall_at_k, HnswConfig, VectorIndex, DistanceMetric, VectorError, VectorResult, VECTOR_FORMAT_VERSION};
 pub use manager::{VectorIndexManager, IndexKey};
@@ -42,6 +42,8 @@ async fn test_nlq_pipeline_mock() {
         api_key: Some("mock".to_string()),
         api_base_url: None,
         system_prompt: None,
+        retry: Default::default(),
+        fallback: None,
     };
 
     let pipeline = NLQPipeline::new(config).unwrap();
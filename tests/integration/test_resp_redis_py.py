@@ -0,0 +1,86 @@
+#!/usr/bin/env python3
+"""Decode GRAPH.QUERY replies with redis-py's RedisGraph client
+
+The other scripts here read raw RESP by hand; this one checks that the reply
+encoding (header, rows, statistics and compact node/edge cells) parses with a
+real client library. It needs a running server and redis-py 4.1 - 5.x, whose
+`redis.commands.graph` module speaks the RedisGraph wire format:
+
+    pip install 'redis>=4.1,<6'
+    python3 test_resp_redis_py.py [host] [port]
+
+Only nodes labelled RedisPyCheck are created, and they are removed afterwards.
+"""
+
+import sys
+import unittest
+
+import redis
+from redis.commands.graph.edge import Edge
+from redis.commands.graph.node import Node
+
+HOST = sys.argv[1] if len(sys.argv) > 1 else "127.0.0.1"
+PORT = int(sys.argv[2]) if len(sys.argv) > 2 else 6379
+LABEL = "RedisPyCheck"
+
+
+class RedisPyGraphReplies(unittest.TestCase):
+    @classmethod
+    def setUpClass(cls):
+        cls.graph = redis.Redis(host=HOST, port=PORT).graph("default")
+
+    def setUp(self):
+        self.graph.query(f"MATCH (n:{LABEL}) DETACH DELETE n")
+        result = self.graph.query(
+            f"CREATE (:{LABEL} {{name: 'Ann', age: 41}}), (:{LABEL} {{name: 'Bob', age: 29}})"
+        )
+        self.assertEqual(result.nodes_created, 2)
+
+    def tearDown(self):
+        self.graph.query(f"MATCH (n:{LABEL}) DETACH DELETE n")
+
+    def test_statistics(self):
+        result = self.graph.query(f"MATCH (n:{LABEL} {{name: 'Bob'}}) DETACH DELETE n")
+        self.assertEqual(result.nodes_deleted, 1)
+        self.assertEqual(result.result_set, [])
+        self.assertGreaterEqual(result.run_time_ms, 0)
+
+    def test_scalar_rows(self):
+        result = self.graph.query(f"MATCH (p:{LABEL}) RETURN p.name, p.age ORDER BY p.age")
+        self.assertEqual([column[1] for column in result.header], [b"p.name", b"p.age"])
+        self.assertEqual(result.result_set, [["Bob", 29], ["Ann", 41]])
+
+    def test_node_cells(self):
+        result = self.graph.query(f"MATCH (p:{LABEL} {{name: 'Ann'}}) RETURN p")
+        node = result.result_set[0][0]
+        self.assertIsInstance(node, Node)
+        self.assertEqual(node.labels, [LABEL])
+        self.assertEqual(node.properties, {"name": "Ann", "age": 41})
+
+    def test_edge_cells(self):
+        result = self.graph.query(
+            f"MATCH (a:{LABEL} {{name: 'Ann'}}), (b:{LABEL} {{name: 'Bob'}}) "
+            "CREATE (a)-[:CHECKS {since: 2020}]->(b)"
+        )
+        self.assertEqual(result.relationships_created, 1)
+
+        result = self.graph.query(f"MATCH (a:{LABEL})-[r:CHECKS]->(b) RETURN r, b.name")
+        edge, name = result.result_set[0]
+        self.assertIsInstance(edge, Edge)
+        self.assertEqual(edge.relation, "CHECKS")
+        self.assertEqual(edge.properties, {"since": 2020})
+        self.assertEqual(name, "Bob")
+
+    def test_read_only_query(self):
+        result = self.graph.query(f"MATCH (p:{LABEL}) RETURN count(p)", read_only=True)
+        self.assertEqual(result.result_set, [[2]])
+        with self.assertRaises(redis.ResponseError):
+            self.graph.query(f"CREATE (:{LABEL})", read_only=True)
+
+    def test_error_reply(self):
+        with self.assertRaises(redis.ResponseError):
+            self.graph.query("THIS IS NOT CYPHER")
+
+
+if __name__ == "__main__":
+    unittest.main(argv=sys.argv[:1])
@@ -83,7 +83,7 @@ async fn test_request_forwarding() {
         let n = tokio::time::timeout(Duration::from_secs(5), client.read(&mut buf))
             .await.expect("Local read timed out").unwrap();
         let response = String::from_utf8_lossy(&buf[..n]);
-        assert!(response.contains("Node(")); // Success
+        assert!(response.contains("Nodes created: 1")); // Success
 
         // 2. Test Remote Request (Node 1 -> Proxy -> Node 2)
         let cmd_remote = "*3\r\n$11\r\nGRAPH.QUERY\r\n$13\r\ntenant_remote\r\n$19\r\nCREATE (n:Remote)\r\n";
@@ -92,7 +92,7 @@ async fn test_request_forwarding() {
         let n = tokio::time::timeout(Duration::from_secs(5), client.read(&mut buf))
             .await.expect("Remote read timed out").unwrap();
         let response = String::from_utf8_lossy(&buf[..n]);
-        assert!(response.contains("Node("));
+        assert!(response.contains("Nodes created: 1"));
 
         println!("Sharding test passed: Local and Remote requests handled.");
     }).await;
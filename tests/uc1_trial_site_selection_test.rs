@@ -120,6 +120,7 @@ async fn uc1_pareto_front_satisfies_constraints() {
     let solver = NSGA2Solver::new(SolverConfig {
         population_size: 20,
         max_iterations: 20,
+        ..Default::default()
     });
 
     let p = problem.clone();
@@ -186,6 +186,7 @@ async fn uc2_pareto_avoids_contraindicated_pair() {
     let solver = NSGA2Solver::new(SolverConfig {
         population_size: 30,
         max_iterations: 30,
+        ..Default::default()
     });
 
     let p = problem.clone();
@@ -140,6 +140,7 @@ async fn solve_at_budget(client: Arc<EmbeddedClient>, fids: Vec<&'static str>, b
     let solver = BMRSolver::new(SolverConfig {
         population_size: 24,
         max_iterations: 30,
+        ..Default::default()
     });
     let p = problem.clone();
     let res = tokio::task::spawn_blocking(move || solver.solve(&*p))
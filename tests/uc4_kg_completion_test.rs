@@ -198,6 +198,7 @@ async fn uc4_optimizer_beats_uniform_baseline() {
         let solver = QOJayaSolver::new(SolverConfig {
             population_size: 20,
             max_iterations: 40,
+            ..Default::default()
         });
         let p = problem.clone();
         let res = tokio::task::spawn_blocking(move || solver.solve(&*p))
@@ -150,6 +150,7 @@ async fn uc5_pareto_front_is_diverse_and_dup_free() {
     let solver = NSGA2Solver::new(SolverConfig {
         population_size: 30,
         max_iterations: 25,
+        ..Default::default()
     });
     let p = problem.clone();
     let front = tokio::task::spawn_blocking(move || solver.solve(&*p).pareto_front)